@@ -0,0 +1,134 @@
+#ifndef VOICE_STUDIO_CORE_H
+#define VOICE_STUDIO_CORE_H
+
+// Generated with cbindgen from core/src/ffi.rs; do not edit by hand.
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Success
+#define VS_OK 0
+
+// A required pointer was null
+#define VS_ERR_NULL -1
+
+// Unknown parameter id
+#define VS_ERR_PARAM -2
+
+// Channel count other than 1 or 2
+#define VS_ERR_CHANNELS -3
+
+// Engine parameters. The discriminant is the stable id.
+enum VsEngineParam
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  // Adaptive noise reduction (0..1)
+  VS_ENGINE_PARAM_NOISE_REDUCTION = 0,
+  // Room reverb reduction (0..1)
+  VS_ENGINE_PARAM_DE_VERB = 1,
+  // Close-mic low-end shaping (0..1)
+  VS_ENGINE_PARAM_PROXIMITY = 2,
+  // Low-mid mud cut (0..1)
+  VS_ENGINE_PARAM_CLARITY = 3,
+  // Sibilance reduction (0..1)
+  VS_ENGINE_PARAM_DE_ESSER = 4,
+  // Automatic level riding (0..1)
+  VS_ENGINE_PARAM_LEVELER = 5,
+  // Speech level the leveler aims for (dBFS, -36..-12)
+  VS_ENGINE_PARAM_LEVELER_TARGET = 6,
+  // Low cut corner (Hz, 0..200; below 20 Hz = off)
+  VS_ENGINE_PARAM_LOW_CUT = 7,
+  // Output gain after the limiter (dB, -12..12)
+  VS_ENGINE_PARAM_OUTPUT_GAIN = 8,
+};
+#ifndef __cplusplus
+typedef uint32_t VsEngineParam;
+#endif // __cplusplus
+
+typedef struct VsEngine VsEngine;
+
+// Meter readings, updated by every processed block. C layout, so the FFI
+// hands it out as is.
+typedef struct VsEngineMeters {
+  // Input peak (dBFS, 300 ms release)
+  float input_peak_db;
+  // Output peak (dBFS, 300 ms release)
+  float output_peak_db;
+  // Output RMS (dBFS, 300 ms window)
+  float output_rms_db;
+  // Speech confidence of the input (0 = noise or silence, 1 = speech)
+  float speech_confidence;
+  // Average denoiser reduction (0..1)
+  float denoise_reduction;
+  // De-esser, leveler and limiter gain reduction (dB, positive)
+  float de_esser_gr_db;
+  float leveler_gr_db;
+  float limiter_gr_db;
+} VsEngineMeters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Create an engine for `sample_rate` with default parameters. Returns null
+// for a rate that is not positive and finite. Free with
+// `vs_engine_destroy()`.
+VsEngine *vs_engine_create(float sample_rate);
+
+// Free an engine. Null is ignored.
+//
+// # Safety
+// `engine` must come from `vs_engine_create()` and not be used afterwards.
+void vs_engine_destroy(VsEngine *engine);
+
+// Set a parameter by id (clamped to its range).
+//
+// # Safety
+// `engine` must be null or a live engine.
+int32_t vs_engine_set_param(VsEngine *engine, uint32_t id, float value);
+
+// Current value of a parameter; NaN for a null engine or unknown id.
+//
+// # Safety
+// `engine` must be null or a live engine.
+float vs_engine_get_param(const VsEngine *engine, uint32_t id);
+
+// Output delay behind the input (samples); 0 for a null engine.
+//
+// # Safety
+// `engine` must be null or a live engine.
+uint32_t vs_engine_latency_samples(const VsEngine *engine);
+
+// Clear all signal state (parameters are kept). Null is ignored.
+//
+// # Safety
+// `engine` must be null or a live engine.
+void vs_engine_reset(VsEngine *engine);
+
+// Clean `frames` frames of interleaved `channels`-channel (1 or 2) audio
+// in place.
+//
+// # Safety
+// `engine` must be null or a live engine; `samples` must be null or point
+// to `frames * channels` writable floats.
+int32_t vs_engine_process_interleaved(VsEngine *engine,
+                                      float *samples,
+                                      size_t frames,
+                                      uint32_t channels);
+
+// Copy the latest meter readings into `out`.
+//
+// # Safety
+// `engine` must be null or a live engine; `out` must be null or writable.
+int32_t vs_engine_get_meters(const VsEngine *engine, VsEngineMeters *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* VOICE_STUDIO_CORE_H */
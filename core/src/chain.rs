@@ -0,0 +1,2135 @@
+//! Voice Chain (the full per-sample signal path)
+//!
+//! Every stage the vxcleaner plugin runs on the audio, wired in one place:
+//! input utilities and mic alignment, low cut, sidechain analysis, static
+//! noise, hiss/rumble, early reflections, expander, transients, denoise,
+//! plosives and breaths, de-verb, codec repair, proximity, De-Harsh,
+//! clarity, de-esser, leveler, recovery EQ, post cleanup, guardrails, room
+//! tone, comfort noise, dead air, routing, soft clip/limiter, mono fix,
+//! output gain, loudness compensation, Final Output gain and dither.
+//!
+//! # Purpose
+//! The plugin and `Engine` both drive this chain, so a change to the signal
+//! path reaches both, and an engine render (null tests, golden files) says
+//! something about the plugin.
+//!
+//! # Design Notes
+//! - The driver owns the parameters: `ChainSettings` (per buffer) and
+//!   `ChainAmounts` (per `CONTROL_BLOCK_SIZE` sub-block) carry plain values,
+//!   so the chain knows nothing about nih-plug, macros or presets
+//! - Call order per buffer: `configure()`, then per sub-block
+//!   `resolve_controls()`, `process_sample()` + `finish_sample()` for each
+//!   sample and `end_sub_block()`, then `end_block()`
+//! - The STFT stages come in as `SpectralStages`, so the plugin can build a
+//!   new set off the audio thread and swap it in
+//! - The fields are public: the plugin meters read the modules directly and
+//!   its editor-driven actions (calibrations, speaker profiles) call them
+//!
+//! ## Audio Thread Safety
+//! - Everything is allocated in `new()` and `SpectralStages::new()`; no
+//!   other method allocates or locks
+
+use crate::dsp::control_slew::DEFAULT_SPEECH_LOSS_BUDGET_DB;
+use crate::dsp::mic_align::Alignment;
+use crate::dsp::utils::{db_to_lin, lerp, time_constant_coeff};
+use crate::dsp::{
+    BandwidthExtender, Biquad, BreathReducer, ChannelProcessor, ClarityDetector, ComfortNoise,
+    DeEssCalibrator, DeEssEventCounter, DeEsserDetector, DeHarsh, DeadAirDetector, DenoiseConfig,
+    DistanceTracker, Dither, DualSpeakerDetector, EarlyReflectionSuppressor, GainAudit, GuardStage,
+    HissProfile, HissRumble, InputUtility, InputUtilitySettings, Interval, LimitedControls,
+    LimiterRelease, LinkedCompressor, LinkedLimiter, MicAligner, MonoCompat, MusicDetector,
+    NoiseLearnRemove, NoiseLearnRemoveConfig, NumericGuard, ParallelCompressor, PinkRefBias,
+    PlosiveSoftener, PostNoiseCleanup, ProfileAnalyzer, Proximity, PumpGuard, Ramp, RecoveryStage,
+    RoomToneBridge, Rt60Estimator, SoftClipper, SpeakerProfileLearner, SpectralControlLimiters,
+    SpectralGuardrails, SpeechConfidenceEstimator, SpeechExpander, SpeechHpf,
+    StereoStreamingDenoiser, TransientSuppressor,
+};
+use crate::speaker_profile;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Sub-block length (samples) at which amounts are re-resolved and the
+/// loudness compensation and pump guard advance
+pub const CONTROL_BLOCK_SIZE: usize = 32;
+
+/// Default edges of the band the speech-loss protection measures (Hz)
+pub const DEFAULT_SPEECH_BAND_HZ: (f32, f32) = (300.0, 3000.0);
+
+/// User amount (0..1) to DSP amount
+const MAX_GAIN: f32 = 2.0;
+
+/// Control-rate frame the slew limits in `SpectralControlLimiters` are tuned
+/// against (512 samples at 48 kHz)
+const CONTROL_SLEW_FRAME_SEC: f32 = 512.0 / 48_000.0;
+
+const DE_ESS_RMS_TAU_SEC: f32 = 0.050;
+const LOUDNESS_PUMP_DELTA_DB: f32 = 2.0;
+const LIMITER_PUMP_THRESHOLD_DB: f32 = 1.5;
+// Speech-band loss envelopes; long enough to ride over the restoration latency
+const SPEECH_LOSS_TAU_SEC: f32 = 1.0;
+// Pump detection compares gain movement over this interval (one 512-sample
+// buffer at 48 kHz), whatever the buffer size
+const PUMP_DELTA_WINDOW_SEC: f32 = 512.0 / 48_000.0;
+// Chain Order switch: detector taps glide to the new points over this time
+const CHAIN_XFADE_MS: f32 = 50.0;
+// Speech confidence drift maintenance period
+const STABILITY_INTERVAL_MS: f32 = 20.0;
+// Per-stage NaN/Inf checks run on one sample in this many
+const NUMERIC_CHECK_INTERVAL: u32 = 32;
+// Loudness compensation on/off and trim changes glide over this time constant
+const LOUDNESS_COMP_GLIDE_MS: f32 = 50.0;
+// Music Guard: share of denoise/de-verb taken off while music plays, and the
+// crossfade time constant into and out of it
+const MUSIC_GUARD_DEPTH: f32 = 0.8;
+const MUSIC_GUARD_FADE_SEC: f32 = 0.5;
+
+// =============================================================================
+// Settings
+// =============================================================================
+
+/// Where the processed pair goes before the final stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Routing {
+    Stereo,
+    /// Equal-power fold-down on both channels
+    Mono,
+    LeftToBoth,
+}
+
+impl Routing {
+    pub fn apply(self, l: f32, r: f32) -> (f32, f32) {
+        match self {
+            Routing::Stereo => (l, r),
+            Routing::Mono => {
+                let m = (l + r) * std::f32::consts::FRAC_1_SQRT_2;
+                (m, m)
+            }
+            Routing::LeftToBoth => (l, l),
+        }
+    }
+}
+
+/// Buffer-rate settings. `Default` is the plugin with every parameter at
+/// its default.
+#[derive(Debug, Clone, Copy)]
+pub struct ChainSettings {
+    /// Hold every adaptive estimate (Freeze Adaptation)
+    pub freeze: bool,
+
+    // Input
+    pub input: InputUtilitySettings,
+    pub mic_alignment: Alignment,
+    /// Low cut corner (Hz, `None` = off) and 24 dB/oct slope
+    pub low_cut_hz: Option<f32>,
+    pub low_cut_steep: bool,
+    pub linear_phase: bool,
+
+    // Noise
+    pub noise_learn: NoiseLearnRemoveConfig,
+    /// Residual tilt: -1 = darker, +1 = brighter
+    pub noise_character: f32,
+    pub low_end_protect: bool,
+    pub artifact_guard: bool,
+    /// Rescue (spectral gate) instead of the Wiener denoiser
+    pub rescue: bool,
+    pub floor_depth: f32,
+    pub learn_time: f32,
+    pub noise_model_freeze: bool,
+    pub stereo_link: f32,
+    pub coarse_analysis: bool,
+    pub masker_peaks: usize,
+    pub speech_loss_budget_db: f32,
+    pub speech_band_hz: (f32, f32),
+    pub comfort_noise: bool,
+    pub comfort_noise_db: f32,
+    pub room_tone_bridge: bool,
+    pub music_guard: bool,
+    pub transient_amount: f32,
+    pub transient_sensitivity: f32,
+    pub post_noise_hf_bias: bool,
+    pub hidden_tone_bypass: bool,
+
+    // De-verb and repair
+    /// Tail treatment: 0 = Tight, 1 = Natural
+    pub deverb_character: f32,
+    pub tail_release: f32,
+    pub codec_repair: f32,
+
+    // Tone
+    pub clarity_freq: f32,
+    pub clarity_q: f32,
+    pub presence_freq: f32,
+    pub presence_gain_db: f32,
+    pub de_harsh: f32,
+    /// Proximity follows the measured mic distance
+    pub proximity_track_room: bool,
+    /// Speaker profile scale on Proximity
+    pub proximity_scale: f32,
+
+    // Dynamics
+    pub leveler_target_db: f32,
+    /// Leveler target offset of the loaded speaker profile (dB)
+    pub speaker_leveler_offset_db: f32,
+    pub density: f32,
+    /// Attack/release scale of the leveler, expander and breath reducer
+    pub time_scale: f32,
+    /// Breath level in Shape mode (dBFS), `None` = Reduce
+    pub breath_shape_db: Option<f32>,
+    pub de_ess_threshold_offset_db: f32,
+    pub dual_speaker: bool,
+    pub guardrails: bool,
+    pub guardrails_strictness: f32,
+    pub dead_air_sec: f32,
+    pub pause_trim: bool,
+    pub pause_level_db: f32,
+    /// Detector taps (clarity first, de-ess last), see `ChainOrder`
+    pub chain_taps: (f32, f32),
+
+    // Output
+    pub routing: Routing,
+    pub soft_clip: bool,
+    pub limit: bool,
+    pub limiter_ceiling_db: Option<f32>,
+    pub limiter_release: LimiterRelease,
+    pub clip_ceiling_db: f32,
+    pub clip_drive_db: f32,
+    pub mono_fix: bool,
+    pub auto_gain_trim: bool,
+    pub loudness_comp: bool,
+    pub loudness_trim_db: f32,
+    /// Final Output gain after loudness compensation (linear)
+    pub final_gain: f32,
+    pub dither_bits: Option<u32>,
+}
+
+impl Default for ChainSettings {
+    fn default() -> Self {
+        Self {
+            freeze: false,
+            input: InputUtilitySettings::default(),
+            mic_alignment: Alignment::default(),
+            low_cut_hz: Some(90.0),
+            low_cut_steep: false,
+            linear_phase: false,
+            noise_learn: NoiseLearnRemoveConfig {
+                enabled: false,
+                amount: 0.0,
+                learn: false,
+                clear: false,
+                auto_learn: false,
+                freeze: false,
+            },
+            noise_character: 0.0,
+            low_end_protect: true,
+            artifact_guard: true,
+            rescue: false,
+            floor_depth: 0.5,
+            learn_time: 0.5,
+            noise_model_freeze: false,
+            stereo_link: 1.0,
+            coarse_analysis: true,
+            masker_peaks: 64,
+            speech_loss_budget_db: DEFAULT_SPEECH_LOSS_BUDGET_DB,
+            speech_band_hz: DEFAULT_SPEECH_BAND_HZ,
+            comfort_noise: false,
+            comfort_noise_db: -15.0,
+            room_tone_bridge: false,
+            music_guard: false,
+            transient_amount: 0.0,
+            transient_sensitivity: 0.5,
+            post_noise_hf_bias: true,
+            hidden_tone_bypass: false,
+            deverb_character: 0.0,
+            tail_release: 1.0,
+            codec_repair: 0.0,
+            clarity_freq: 250.0,
+            clarity_q: 0.7,
+            presence_freq: 3000.0,
+            presence_gain_db: 0.0,
+            de_harsh: 0.0,
+            proximity_track_room: false,
+            proximity_scale: 1.0,
+            leveler_target_db: -24.0,
+            speaker_leveler_offset_db: 0.0,
+            density: 0.0,
+            time_scale: 1.0,
+            breath_shape_db: None,
+            de_ess_threshold_offset_db: 0.0,
+            dual_speaker: false,
+            guardrails: true,
+            guardrails_strictness: 1.0,
+            dead_air_sec: 2.0,
+            pause_trim: false,
+            pause_level_db: -60.0,
+            chain_taps: (0.0, 0.0),
+            routing: Routing::Stereo,
+            soft_clip: false,
+            limit: true,
+            limiter_ceiling_db: None,
+            limiter_release: LimiterRelease::TRANSPARENT,
+            clip_ceiling_db: -1.0,
+            clip_drive_db: 0.0,
+            mono_fix: false,
+            auto_gain_trim: false,
+            loudness_comp: true,
+            loudness_trim_db: 0.0,
+            final_gain: 1.0,
+            dither_bits: None,
+        }
+    }
+}
+
+/// Sub-block amounts, user scale (0..1) unless noted. `Default` is the
+/// plugin's defaults.
+#[derive(Debug, Clone, Copy)]
+pub struct ChainAmounts {
+    pub noise_reduction: f32,
+    pub reverb_reduction: f32,
+    /// Modulation offsets included
+    pub proximity: f32,
+    pub clarity: f32,
+    pub de_esser: f32,
+    pub leveler: f32,
+    pub breath_control: f32,
+    pub rumble: f32,
+    pub hiss: f32,
+    /// Early-reflection and late-tail shares of De-Verb
+    pub deverb_early: f32,
+    pub deverb_tail: f32,
+    /// Output gain after the final stage (linear)
+    pub output_gain: f32,
+}
+
+impl Default for ChainAmounts {
+    fn default() -> Self {
+        Self {
+            noise_reduction: 0.0,
+            reverb_reduction: 0.0,
+            proximity: 0.0,
+            clarity: 0.0,
+            de_esser: 0.0,
+            leveler: 0.0,
+            breath_control: 0.25,
+            rumble: 0.0,
+            hiss: 0.0,
+            deverb_early: 1.0,
+            deverb_tail: 1.0,
+            output_gain: 1.0,
+        }
+    }
+}
+
+/// Control values for one sub-block, after slew limiting and inter-module
+/// safety clamps.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedControls {
+    // Layer 1: requested, pre-safeguard
+    pub raw_noise: f32,
+    pub raw_reverb: f32,
+    pub raw_clarity: f32,
+    pub raw_de_ess: f32,
+    pub raw_prox: f32,
+
+    // Values applied by the chain
+    pub noise_amt: f32,
+    pub reverb_amt: f32,
+    pub early_reflection_amt: f32,
+    pub expander_amt: f32,
+    pub clarity_amt: f32,
+    pub de_ess_amt: f32,
+    pub prox_amt: f32,
+    pub level_amt: f32,
+    pub breath_amt: f32,
+    pub rumble_val: f32,
+    pub hiss_val: f32,
+    pub total_deverb: f32,
+    pub output_gain: f32,
+    pub denoise_cfg: DenoiseConfig,
+
+    // Layer 2: safeguard interventions
+    pub speech_loss_db: f32,
+    pub limited: LimitedControls,
+}
+
+/// Last leveler pump the chain detected, for logging
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PumpEvent {
+    pub delta_db: f32,
+    pub leveler_amount: f32,
+    pub speech_confidence: f32,
+    pub gain_reduction_db: f32,
+}
+
+// =============================================================================
+// Spectral stages
+// =============================================================================
+
+/// The stages whose buffers follow the STFT window size
+pub struct SpectralStages {
+    pub process_l: ChannelProcessor,
+    pub process_r: ChannelProcessor,
+    pub denoiser: StereoStreamingDenoiser,
+    pub noise_learn_remove: NoiseLearnRemove,
+    pub comfort_noise: ComfortNoise,
+}
+
+impl SpectralStages {
+    /// Fresh stages for a `win`-point STFT (hop `win / 4`)
+    pub fn new(win: usize, sample_rate: f32) -> Self {
+        let hop = win / 4;
+        Self {
+            process_l: ChannelProcessor::new(win, hop, sample_rate),
+            process_r: ChannelProcessor::new(win, hop, sample_rate),
+            denoiser: StereoStreamingDenoiser::new(win, hop, sample_rate),
+            noise_learn_remove: NoiseLearnRemove::new(win, hop, sample_rate),
+            comfort_noise: ComfortNoise::new(win, hop, sample_rate),
+        }
+    }
+}
+
+// =============================================================================
+// Chain
+// =============================================================================
+
+pub struct VoiceChain {
+    pub sample_rate: f32,
+    /// Settings of the last `configure()`
+    pub settings: ChainSettings,
+
+    // STFT stages
+    pub process_l: ChannelProcessor,
+    pub process_r: ChannelProcessor,
+    pub denoiser: StereoStreamingDenoiser,
+    pub noise_learn_remove: NoiseLearnRemove,
+    pub comfort_noise: ComfortNoise,
+
+    // Input stage
+    pub input_utility: InputUtility,
+    pub mic_aligner: MicAligner,
+    pub speech_hpf: SpeechHpf,
+    /// Low cut mode the latency currently includes
+    pub active_linear_phase: bool,
+
+    // Analysis
+    pub speech_confidence: SpeechConfidenceEstimator,
+    /// Speech confidence of the last sample
+    pub prev_speech_conf: f32,
+    pub hiss_profile: HissProfile,
+    pub music_detector: MusicDetector,
+    /// Music Guard crossfade (0 = normal processing, 1 = music)
+    pub music_mix: f32,
+    // INVARIANT: input_profile_analyzer processes ONLY pre-DSP samples
+    pub input_profile_analyzer: ProfileAnalyzer,
+    /// Input profile frame last used for the leveler adaptation
+    pub last_input_profile_frame: u64,
+    pub rt60_estimator: Rt60Estimator,
+    pub speaker_learner: SpeakerProfileLearner,
+    pub distance_tracker: DistanceTracker,
+    /// Dual-speaker detection: per-speaker de-esser center, active speaker
+    pub dual_speaker: DualSpeakerDetector,
+    pub dual_speaker_sibilance_hz: [Option<f32>; 2],
+    pub dual_active: Option<usize>,
+
+    // Early processing and restoration
+    pub hiss_rumble: HissRumble,
+    pub early_reflection_l: EarlyReflectionSuppressor,
+    pub early_reflection_r: EarlyReflectionSuppressor,
+    pub speech_expander: SpeechExpander,
+    pub transient_suppressor: TransientSuppressor,
+    pub pink_ref_bias: PinkRefBias,
+    pub plosive_softener_l: PlosiveSoftener,
+    pub plosive_softener_r: PlosiveSoftener,
+    pub breath_reducer_l: BreathReducer,
+    pub breath_reducer_r: BreathReducer,
+    pub bandwidth_extender: BandwidthExtender,
+
+    // Shaping
+    pub clarity_detector: ClarityDetector,
+    pub de_harsh: DeHarsh,
+    /// Track Room scale on Proximity for this buffer
+    pub prox_scale: f32,
+
+    // Dynamics
+    pub linked_de_esser: DeEsserDetector,
+    pub de_ess_calibrator: DeEssCalibrator,
+    /// De-ess events and depth since the last analysis reset
+    pub de_ess_events: DeEssEventCounter,
+    pub linked_compressor: LinkedCompressor,
+    /// Heavy leveler mixed under the main one (Density)
+    pub parallel_compressor: ParallelCompressor,
+    pub linked_limiter: LinkedLimiter,
+    pub soft_clipper: SoftClipper,
+    pub mono_compat: MonoCompat,
+    /// Final stage the latency currently includes
+    pub active_soft_clip: bool,
+    pub active_limit: bool,
+    pub de_ess_rms_sq_l: f32,
+    pub de_ess_rms_sq_r: f32,
+    /// De-esser reduction of the previous sample (dB), read by the leveler
+    pub de_ess_reduction_db: f32,
+
+    // Late stages
+    pub recovery_stage: RecoveryStage,
+    pub post_noise_cleanup_l: PostNoiseCleanup,
+    pub post_noise_cleanup_r: PostNoiseCleanup,
+    pub spectral_guardrails: SpectralGuardrails,
+    pub room_tone_bridge: RoomToneBridge,
+    pub dead_air: DeadAirDetector,
+    pub dither_l: Dither,
+    pub dither_r: Dither,
+
+    // Speech band energy protection
+    pub speech_band_pre_l: Biquad,
+    pub speech_band_pre_r: Biquad,
+    pub speech_band_post_l: Biquad,
+    pub speech_band_post_r: Biquad,
+    pub speech_band_pre_lpf_l: Biquad,
+    pub speech_band_pre_lpf_r: Biquad,
+    pub speech_band_post_lpf_l: Biquad,
+    pub speech_band_post_lpf_r: Biquad,
+    /// Speech-weighted band power entering and leaving restoration
+    pub speech_band_pre_env: f32,
+    pub speech_band_post_env: f32,
+    /// Band edges the speech-band filters are currently tuned to (Hz)
+    pub active_speech_band: (f32, f32),
+    /// Band loss through restoration (dB, <= 0), fed to the speech protection
+    pub speech_loss_db: f32,
+    pub control_limiters: SpectralControlLimiters,
+
+    // Loudness preservation
+    pub pre_rms_env: f32,
+    pub post_rms_env: f32,
+    pub loudness_comp_gain: f32,
+    /// Gain actually applied: the compensation (when on) times the trim,
+    /// gliding to on/off and trim changes
+    pub loudness_comp_applied: f32,
+    pub prev_loudness_comp_gain: f32,
+
+    // Chain Order switch crossfade
+    pub chain_xfade: Ramp,
+    /// Tap shares when the order changed
+    pub chain_xfade_from: (f32, f32),
+    /// Tap shares in use, 0.0 = standard order, 1.0 = swapped
+    pub chain_mix: (f32, f32),
+    /// Tap shares the crossfade runs to
+    pub chain_target: (f32, f32),
+
+    // Protection
+    /// Backs the leveler off when pump events keep repeating
+    pub pump_guard: PumpGuard,
+    /// Leveler pumps detected since the last reset, and the latest one
+    pub pump_events: u32,
+    pub last_pump: PumpEvent,
+    sub_block_pump_events: u32,
+    /// Per-stage NaN/Inf checks; resets only the stage that blew up
+    pub numeric_guard: NumericGuard,
+    /// Per-stage long-term gain during speech, optional static-offset trim
+    pub gain_audit: GainAudit,
+    /// Speech confidence drift maintenance
+    pub stability_interval: Interval,
+
+    // Per-rate coefficients
+    rms_alpha: f32,
+    speech_band_alpha: f32,
+    de_ess_alpha: f32,
+    loudness_comp_coeff: f32,
+}
+
+impl VoiceChain {
+    /// Chain for `sample_rate` around `stages`, at the default settings.
+    /// Every module starts fresh.
+    pub fn new(sample_rate: f32, stages: SpectralStages) -> Self {
+        let settings = ChainSettings::default();
+        let SpectralStages {
+            process_l,
+            process_r,
+            denoiser,
+            noise_learn_remove,
+            comfort_noise,
+        } = stages;
+        let mut chain = Self {
+            sample_rate,
+            settings,
+            process_l,
+            process_r,
+            denoiser,
+            noise_learn_remove,
+            comfort_noise,
+            input_utility: InputUtility::new(sample_rate),
+            mic_aligner: MicAligner::new(sample_rate),
+            speech_hpf: SpeechHpf::new(sample_rate),
+            active_linear_phase: false,
+            speech_confidence: SpeechConfidenceEstimator::new(sample_rate),
+            prev_speech_conf: 0.0,
+            hiss_profile: HissProfile::new(sample_rate),
+            music_detector: MusicDetector::new(sample_rate),
+            music_mix: 0.0,
+            input_profile_analyzer: ProfileAnalyzer::new(sample_rate),
+            last_input_profile_frame: 0,
+            rt60_estimator: Rt60Estimator::new(sample_rate),
+            speaker_learner: SpeakerProfileLearner::new(sample_rate),
+            distance_tracker: DistanceTracker::new(),
+            dual_speaker: DualSpeakerDetector::new(sample_rate),
+            dual_speaker_sibilance_hz: [None; 2],
+            dual_active: None,
+            hiss_rumble: HissRumble::new(sample_rate),
+            early_reflection_l: EarlyReflectionSuppressor::new(sample_rate),
+            early_reflection_r: EarlyReflectionSuppressor::new(sample_rate),
+            speech_expander: SpeechExpander::new(sample_rate),
+            transient_suppressor: TransientSuppressor::new(sample_rate),
+            pink_ref_bias: PinkRefBias::new(sample_rate),
+            plosive_softener_l: PlosiveSoftener::new(sample_rate),
+            plosive_softener_r: PlosiveSoftener::new(sample_rate),
+            breath_reducer_l: BreathReducer::new(sample_rate),
+            breath_reducer_r: BreathReducer::new(sample_rate),
+            bandwidth_extender: BandwidthExtender::new(sample_rate),
+            clarity_detector: ClarityDetector::new(sample_rate),
+            de_harsh: DeHarsh::new(sample_rate),
+            prox_scale: 1.0,
+            linked_de_esser: DeEsserDetector::new(sample_rate),
+            de_ess_calibrator: DeEssCalibrator::new(sample_rate),
+            de_ess_events: DeEssEventCounter::new(sample_rate),
+            linked_compressor: LinkedCompressor::new(sample_rate),
+            parallel_compressor: ParallelCompressor::new(sample_rate),
+            linked_limiter: LinkedLimiter::new(sample_rate),
+            soft_clipper: SoftClipper::new(sample_rate),
+            mono_compat: MonoCompat::new(sample_rate),
+            active_soft_clip: settings.soft_clip,
+            active_limit: settings.limit,
+            de_ess_rms_sq_l: 0.0,
+            de_ess_rms_sq_r: 0.0,
+            de_ess_reduction_db: 0.0,
+            recovery_stage: RecoveryStage::new(sample_rate),
+            post_noise_cleanup_l: PostNoiseCleanup::new(sample_rate),
+            post_noise_cleanup_r: PostNoiseCleanup::new(sample_rate),
+            spectral_guardrails: SpectralGuardrails::new(sample_rate),
+            room_tone_bridge: RoomToneBridge::new(sample_rate),
+            dead_air: DeadAirDetector::new(sample_rate),
+            dither_l: Dither::new(0x1234_5678),
+            dither_r: Dither::new(0x8765_4321),
+            speech_band_pre_l: Biquad::new(),
+            speech_band_pre_r: Biquad::new(),
+            speech_band_post_l: Biquad::new(),
+            speech_band_post_r: Biquad::new(),
+            speech_band_pre_lpf_l: Biquad::new(),
+            speech_band_pre_lpf_r: Biquad::new(),
+            speech_band_post_lpf_l: Biquad::new(),
+            speech_band_post_lpf_r: Biquad::new(),
+            speech_band_pre_env: 0.0,
+            speech_band_post_env: 0.0,
+            active_speech_band: settings.speech_band_hz,
+            speech_loss_db: 0.0,
+            control_limiters: SpectralControlLimiters::new(),
+            pre_rms_env: 0.0,
+            post_rms_env: 0.0,
+            loudness_comp_gain: 1.0,
+            loudness_comp_applied: 1.0,
+            prev_loudness_comp_gain: 1.0,
+            chain_xfade: Ramp::default(),
+            chain_xfade_from: settings.chain_taps,
+            chain_mix: settings.chain_taps,
+            chain_target: settings.chain_taps,
+            pump_guard: PumpGuard::new(sample_rate),
+            pump_events: 0,
+            last_pump: PumpEvent::default(),
+            sub_block_pump_events: 0,
+            numeric_guard: NumericGuard::new(NUMERIC_CHECK_INTERVAL),
+            gain_audit: GainAudit::new(sample_rate),
+            stability_interval: Interval::new(STABILITY_INTERVAL_MS, sample_rate),
+            rms_alpha: 1.0 - (-1.0 / (2.0 * sample_rate)).exp(),
+            speech_band_alpha: 1.0 - (-1.0 / (SPEECH_LOSS_TAU_SEC * sample_rate)).exp(),
+            de_ess_alpha: 1.0 - (-1.0 / (DE_ESS_RMS_TAU_SEC * sample_rate)).exp(),
+            loudness_comp_coeff: time_constant_coeff(LOUDNESS_COMP_GLIDE_MS, sample_rate),
+        };
+        chain.input_utility.set(settings.input);
+        chain.input_utility.reset();
+        chain.tune_speech_band(settings.speech_band_hz);
+        chain
+    }
+
+    /// Clear signal state: filters, envelopes, detectors and the protection
+    /// state. Learned analysis that `reset_analysis()` clears (static noise
+    /// profile, loudness compensation, speech-band loss) is kept.
+    pub fn reset(&mut self) {
+        self.denoiser.reset();
+        self.pink_ref_bias.reset();
+        self.de_harsh.reset();
+        self.process_l.envelope_tracker.reset();
+        self.process_r.envelope_tracker.reset();
+        self.process_l.restoration_chain.deverber.reset();
+        self.process_r.restoration_chain.deverber.reset();
+        self.linked_compressor.reset();
+        self.parallel_compressor.reset();
+        self.linked_de_esser.reset();
+        self.linked_de_esser.reset_tracking();
+        self.de_ess_calibrator.reset();
+        self.de_ess_events.reset();
+        self.linked_limiter.reset();
+        self.soft_clipper.reset();
+        self.mono_compat.reset();
+        self.speech_confidence.reset();
+        self.early_reflection_l.reset();
+        self.early_reflection_r.reset();
+        self.speech_expander.reset();
+        self.transient_suppressor.reset();
+        self.spectral_guardrails.reset();
+        self.hiss_rumble.reset();
+        self.hiss_profile.reset();
+        self.noise_learn_remove.reset();
+        self.comfort_noise.reset();
+        self.room_tone_bridge.reset();
+        self.music_detector.reset();
+        self.music_mix = 0.0;
+        self.dead_air.reset();
+        self.distance_tracker.reset();
+        self.bandwidth_extender.reset();
+        self.dither_l.reset();
+        self.dither_r.reset();
+        self.recovery_stage.reset();
+        self.post_noise_cleanup_l.reset();
+        self.post_noise_cleanup_r.reset();
+        self.mic_aligner.reset();
+        self.speech_hpf.reset();
+        for band in [
+            &mut self.speech_band_pre_l,
+            &mut self.speech_band_pre_r,
+            &mut self.speech_band_post_l,
+            &mut self.speech_band_post_r,
+            &mut self.speech_band_pre_lpf_l,
+            &mut self.speech_band_pre_lpf_r,
+            &mut self.speech_band_post_lpf_l,
+            &mut self.speech_band_post_lpf_r,
+        ] {
+            band.reset_state();
+        }
+        self.speech_band_pre_env = 0.0;
+        self.speech_band_post_env = 0.0;
+        self.speech_loss_db = 0.0;
+        self.control_limiters.reset();
+        self.plosive_softener_l.reset();
+        self.plosive_softener_r.reset();
+        self.breath_reducer_l.reset();
+        self.breath_reducer_r.reset();
+        self.input_profile_analyzer.reset();
+        self.last_input_profile_frame = 0;
+        self.rt60_estimator.reset();
+        self.speaker_learner.reset();
+        self.dual_speaker.reset();
+        self.dual_speaker_sibilance_hz = [None; 2];
+        self.dual_active = None;
+        self.stability_interval.reset();
+        self.pump_guard.reset();
+        self.pump_events = 0;
+        self.sub_block_pump_events = 0;
+        self.last_pump = PumpEvent::default();
+        self.prev_loudness_comp_gain = 1.0;
+        self.numeric_guard.reset();
+        self.gain_audit.reset();
+    }
+
+    /// Clear adaptive analysis state (noise floor models, learned static
+    /// noise profile, loudness compensation, input analysis) while leaving
+    /// filter state untouched. Used when moving between takes recorded in
+    /// different rooms.
+    pub fn reset_analysis(&mut self) {
+        // Noise floor models
+        self.denoiser.reset();
+        self.speech_confidence.reset();
+        self.process_l.envelope_tracker.reset();
+        self.process_r.envelope_tracker.reset();
+        self.pink_ref_bias.reset();
+
+        // Learned static noise profile
+        self.noise_learn_remove.clear_profile();
+
+        // Input profile and the profile-driven leveler adaptation
+        self.input_profile_analyzer.reset();
+        self.last_input_profile_frame = 0;
+        self.linked_compressor.reset();
+        self.parallel_compressor.reset();
+
+        // Room decay, codec cutoff, hiss and rumble corners, the room tone
+        // the next edit is bridged from, dead-air totals, de-ess events and
+        // the mic distance estimate
+        self.rt60_estimator.reset();
+        self.bandwidth_extender.reset();
+        self.hiss_profile.reset();
+        self.room_tone_bridge.reset();
+        self.dead_air.reset();
+        self.de_ess_events.reset();
+        self.distance_tracker.reset();
+
+        // Per-speaker sibilance center and voice statistics
+        self.linked_de_esser.reset_tracking();
+        self.speaker_learner.reset();
+        self.dual_speaker.reset();
+        self.dual_speaker_sibilance_hz = [None; 2];
+        self.dual_active = None;
+
+        // Loudness compensation and speech-band loss
+        self.pre_rms_env = 0.0;
+        self.post_rms_env = 0.0;
+        self.speech_band_pre_env = 0.0;
+        self.speech_band_post_env = 0.0;
+        self.speech_loss_db = 0.0;
+        self.loudness_comp_gain = 1.0;
+        self.loudness_comp_applied = 1.0;
+        self.prev_loudness_comp_gain = 1.0;
+    }
+
+    /// Jump straight to the Chain Order `taps` (no crossfade)
+    pub fn reset_chain_order(&mut self, taps: (f32, f32)) {
+        self.chain_xfade.clear();
+        self.chain_mix = taps;
+        self.chain_target = taps;
+    }
+
+    /// Swap `stages` in, leaving the replaced ones in `stages`.
+    /// `previous_rate` is the rate the current stages were built for; the
+    /// learned noise fingerprint is carried over from it (no allocation, the
+    /// new stages already hold their buffers).
+    pub fn install_spectral_stages(&mut self, stages: &mut SpectralStages, previous_rate: f32) {
+        std::mem::swap(&mut self.process_l, &mut stages.process_l);
+        std::mem::swap(&mut self.process_r, &mut stages.process_r);
+        std::mem::swap(&mut self.denoiser, &mut stages.denoiser);
+        std::mem::swap(&mut self.noise_learn_remove, &mut stages.noise_learn_remove);
+        std::mem::swap(&mut self.comfort_noise, &mut stages.comfort_noise);
+        self.restore_noise_profile(&stages.noise_learn_remove, previous_rate);
+    }
+
+    /// Take over the static noise fingerprint `previous` learned at
+    /// `previous_rate`, if it has one
+    pub fn restore_noise_profile(&mut self, previous: &NoiseLearnRemove, previous_rate: f32) {
+        if let Some(profile) = previous.learned_profile() {
+            let quality = previous.get_quality();
+            self.noise_learn_remove
+                .restore_profile(profile, previous_rate, quality);
+        }
+    }
+
+    /// Delay of the processed path, summed from the stages that are in it
+    /// right now. Stages that switch off internally (De-Verb at 0%) keep
+    /// their delay, so only structural changes move this.
+    pub fn latency(&self) -> usize {
+        let restoration = if self.process_l.bypass_restoration || self.process_r.bypass_restoration
+        {
+            0
+        } else {
+            self.denoiser.latency() + self.process_l.restoration_chain.deverber.latency()
+        };
+        let clip = if self.active_soft_clip {
+            crate::dsp::soft_clipper::LATENCY
+        } else {
+            0
+        };
+        self.speech_hpf.latency() + self.noise_learn_remove.latency() + restoration + clip
+    }
+
+    /// Run the low cut and safety high-pass as one linear-phase FIR
+    pub fn set_linear_phase(&mut self, on: bool) {
+        if on != self.active_linear_phase {
+            self.active_linear_phase = on;
+            self.speech_hpf.set_linear_phase(on);
+        }
+    }
+
+    /// Final stage: soft clip and/or limiter. The soft clipper's
+    /// oversampling filters add latency.
+    pub fn set_final_stage(&mut self, soft_clip: bool, limit: bool) {
+        if (soft_clip, limit) != (self.active_soft_clip, self.active_limit) {
+            self.active_soft_clip = soft_clip;
+            self.active_limit = limit;
+            self.soft_clipper.reset();
+        }
+    }
+
+    /// Tune the speech-band filters that measure speech loss to `(low, high)` Hz.
+    /// Filter state is kept, so an edge moved during playback does not reset
+    /// the loss measurement.
+    pub fn tune_speech_band(&mut self, (low, high): (f32, f32)) {
+        self.active_speech_band = (low, high);
+        for hpf in [
+            &mut self.speech_band_pre_l,
+            &mut self.speech_band_pre_r,
+            &mut self.speech_band_post_l,
+            &mut self.speech_band_post_r,
+        ] {
+            hpf.update_hpf(low, 0.5, self.sample_rate);
+        }
+        for lpf in [
+            &mut self.speech_band_pre_lpf_l,
+            &mut self.speech_band_pre_lpf_r,
+            &mut self.speech_band_post_lpf_l,
+            &mut self.speech_band_post_lpf_r,
+        ] {
+            lpf.update_lpf(high, 0.5, self.sample_rate);
+        }
+    }
+
+    /// Apply the settings for the next buffer. Structural changes (linear
+    /// phase, final stage) move `latency()`.
+    pub fn configure(&mut self, settings: &ChainSettings) {
+        self.settings = *settings;
+        self.linked_de_esser.set_tracking_frozen(settings.freeze);
+
+        self.set_linear_phase(settings.linear_phase);
+        if settings.speech_band_hz != self.active_speech_band {
+            self.tune_speech_band(settings.speech_band_hz);
+        }
+        self.set_final_stage(settings.soft_clip, settings.limit);
+
+        // Chain Order: the detector taps glide from where they are now
+        if settings.chain_taps != self.chain_target {
+            self.chain_xfade.start(CHAIN_XFADE_MS, self.sample_rate);
+            self.chain_xfade_from = self.chain_mix;
+            self.chain_target = settings.chain_taps;
+        }
+
+        self.linked_limiter
+            .set_ceiling_db(settings.limiter_ceiling_db);
+        self.linked_limiter.set_release(settings.limiter_release);
+        // The clipper's ceiling follows the same true-peak target
+        let clip_ceiling_db = match settings.limiter_ceiling_db {
+            Some(_) => settings
+                .clip_ceiling_db
+                .min(self.linked_limiter.ceiling_db()),
+            None => settings.clip_ceiling_db,
+        };
+        self.soft_clipper
+            .set(clip_ceiling_db, settings.clip_drive_db);
+        self.linked_compressor
+            .set_target_db(settings.leveler_target_db);
+        self.parallel_compressor
+            .set_target_db(settings.leveler_target_db);
+        self.linked_compressor.set_time_scale(settings.time_scale);
+        self.parallel_compressor.set_time_scale(settings.time_scale);
+        self.speech_expander.set_time_scale(settings.time_scale);
+        self.speech_expander.set_release_trim(settings.tail_release);
+        self.breath_reducer_l.set_time_scale(settings.time_scale);
+        self.breath_reducer_r.set_time_scale(settings.time_scale);
+        self.breath_reducer_l.set_shape(settings.breath_shape_db);
+        self.breath_reducer_r.set_shape(settings.breath_shape_db);
+        self.spectral_guardrails
+            .set_strictness(settings.guardrails_strictness);
+
+        let bypass_restoration =
+            self.process_l.bypass_restoration || self.process_r.bypass_restoration;
+        if settings.room_tone_bridge && !bypass_restoration {
+            // The sidechain leads the output by everything after the Low Cut
+            self.room_tone_bridge
+                .set_lead(self.latency().saturating_sub(self.speech_hpf.latency()));
+        } else {
+            self.room_tone_bridge.reset();
+        }
+        if !settings.music_guard {
+            self.music_detector.reset();
+        }
+        self.gain_audit.update_trims(settings.auto_gain_trim);
+
+        // Track Room: Proximity follows the mic distance measured on the input
+        self.prox_scale = if settings.proximity_track_room {
+            self.distance_tracker.amount_scale()
+        } else {
+            1.0
+        };
+
+        self.speech_hpf
+            .set_corner(settings.low_cut_hz, settings.low_cut_steep);
+
+        // Clarity band and presence lift. The presence lift gives way to the
+        // De-Harsh cut so the two never push the same band in opposite
+        // directions
+        let presence_gain = (settings.presence_gain_db - self.de_harsh.cut_db()).max(0.0);
+        self.clarity_detector.set_center_hz(settings.clarity_freq);
+        for chain in [&mut self.process_l, &mut self.process_r] {
+            chain
+                .shaping_chain
+                .clarity
+                .set_band(settings.clarity_freq, settings.clarity_q);
+            chain
+                .shaping_chain
+                .clarity
+                .set_presence(settings.presence_freq, presence_gain);
+        }
+
+        // Input utilities (balance, swap, polarity) glide to the new settings;
+        // a stored mic alignment is crossfaded in
+        self.input_utility.set(settings.input);
+        self.mic_aligner.set_alignment(settings.mic_alignment);
+        self.linked_de_esser
+            .set_threshold_offset_db(settings.de_ess_threshold_offset_db);
+    }
+
+    /// Resolve the control values for the next `sub_block_len` samples from
+    /// the requested `amounts`. Advances the slew limiters and the Music
+    /// Guard crossfade, so this must be called exactly once per sub-block.
+    pub fn resolve_controls(
+        &mut self,
+        amounts: &ChainAmounts,
+        sub_block_len: usize,
+    ) -> ResolvedControls {
+        let settings = &self.settings;
+        let raw_noise = (amounts.noise_reduction * MAX_GAIN).clamp(0.0, MAX_GAIN);
+
+        let rumble_val = amounts.rumble;
+        let hiss_val = amounts.hiss;
+
+        let raw_reverb = (amounts.reverb_reduction * MAX_GAIN).clamp(0.0, 1.0);
+        let raw_prox =
+            (amounts.proximity * MAX_GAIN * settings.proximity_scale).clamp(0.0, MAX_GAIN);
+        let raw_de_ess = (amounts.de_esser * MAX_GAIN).clamp(0.0, MAX_GAIN);
+        let level_amt = (amounts.leveler * MAX_GAIN).clamp(0.0, MAX_GAIN);
+        let raw_clarity = (amounts.clarity * MAX_GAIN).clamp(0.0, MAX_GAIN);
+        let breath_amt = amounts.breath_control.clamp(0.0, 1.0);
+
+        // Whisper/noisy input conditions for the stability guards (not
+        // detected yet)
+        let whisper = false;
+        let noisy = false;
+
+        // Apply spectral control slew limiting (prevents warble/artifacts).
+        // The slew budget is time-based, so it is scaled to the sub-block length.
+        let speech_loss_db = self.speech_loss_db;
+        self.control_limiters
+            .set_speech_loss_budget(settings.speech_loss_budget_db);
+        let slew_frame_samples = CONTROL_SLEW_FRAME_SEC * self.sample_rate;
+        self.control_limiters
+            .set_slew_scale(sub_block_len as f32 / slew_frame_samples.max(1.0));
+        let limited = self.control_limiters.process(
+            raw_noise,
+            raw_clarity,
+            raw_de_ess,
+            raw_reverb,
+            raw_prox,
+            whisper,
+            noisy,
+            speech_loss_db,
+        );
+
+        let mut noise_amt = limited.denoise;
+        let mut clarity_amt = limited.clarity;
+        let de_ess_amt = limited.deesser;
+        let mut reverb_amt = limited.reverb;
+        let prox_amt = limited.proximity;
+
+        // Inter-module safety clamps (DSP stability)
+        // Prevent destructive parameter interactions
+
+        // Rule 1: Reduce clarity when proximity is active (avoid thinning bass-boosted signal)
+        if prox_amt > 0.4 {
+            clarity_amt *= 0.7;
+        }
+
+        // Rule 2: Reduce deverb when proximity or clarity are high (avoid over-processing)
+        if prox_amt > 0.6 || clarity_amt > 0.6 {
+            reverb_amt *= 0.75;
+        }
+
+        // Rule 3: Reduce denoise when clarity is very high (avoid thinning)
+        if clarity_amt > 0.8 {
+            noise_amt *= 0.85;
+        }
+
+        // Music Guard: crossfade to lighter cleanup while music plays, so
+        // intros and stingers are not chewed up as a noisy, roomy voice
+        let music_target = if settings.music_guard && self.music_detector.is_music() {
+            1.0
+        } else {
+            0.0
+        };
+        let music_alpha =
+            1.0 - (-(sub_block_len as f32) / (MUSIC_GUARD_FADE_SEC * self.sample_rate)).exp();
+        if !settings.freeze {
+            self.music_mix += (music_target - self.music_mix) * music_alpha;
+        }
+        let music_scale = 1.0 - MUSIC_GUARD_DEPTH * self.music_mix;
+        noise_amt *= music_scale;
+        reverb_amt *= music_scale;
+        // ...and the noise models do not learn the music as room tone
+        let music_hold = self.music_mix > 0.5;
+
+        // De-Verb acts as a macro over its early/late stages:
+        // early -> EarlyReflectionSuppressor, tail -> SpeechExpander + deverber
+        let early_share = amounts.deverb_early;
+        let tail_share = amounts.deverb_tail;
+        let early_reflection_amt = (reverb_amt * 0.5 * early_share).clamp(0.0, 1.0);
+        let expander_amt = (reverb_amt * 0.6 * tail_share).clamp(0.0, 1.0);
+
+        // Proximity reduces how much de-verb is needed (closer = less room sound)
+        let prox_reduction = Proximity::get_deverb_contribution(prox_amt);
+        let total_deverb = (reverb_amt * tail_share - prox_reduction).clamp(0.0, 1.0);
+
+        let denoise_cfg = DenoiseConfig {
+            amount: noise_amt,
+            sensitivity: (0.2 + 0.8 * noise_amt).clamp(0.2, 1.0),
+            // Darker pushes extra reduction to the highs, Brighter to the lows
+            tone: 0.5 - 0.5 * settings.noise_character,
+            sample_rate: self.sample_rate,
+            speech_confidence: 0.5, // Will be updated per-sample with actual sidechain value
+            low_end_protect: settings.low_end_protect,
+            artifact_guard: settings.artifact_guard,
+            coarse_analysis: settings.coarse_analysis,
+            masker_peaks: settings.masker_peaks,
+            rescue: settings.rescue,
+            floor_depth: settings.floor_depth,
+            learn_time: settings.learn_time,
+            freeze_noise: settings.noise_model_freeze || music_hold || settings.freeze,
+            freeze_artifact_guard: settings.freeze,
+            stereo_link: settings.stereo_link,
+        };
+
+        ResolvedControls {
+            raw_noise,
+            raw_reverb,
+            raw_clarity,
+            raw_de_ess,
+            raw_prox,
+            noise_amt,
+            reverb_amt,
+            early_reflection_amt,
+            expander_amt,
+            clarity_amt,
+            de_ess_amt,
+            prox_amt,
+            level_amt,
+            breath_amt,
+            rumble_val,
+            hiss_val,
+            total_deverb,
+            output_gain: amounts.output_gain,
+            denoise_cfg,
+            speech_loss_db,
+            limited,
+        }
+    }
+
+    /// Run one stereo sample through the chain, up to and including loudness
+    /// compensation. `finish_sample()` applies the Final Output gain and
+    /// dither; the plugin reads the loudness in between.
+    pub fn process_sample(
+        &mut self,
+        input_l: f32,
+        input_r: f32,
+        controls: &ResolvedControls,
+    ) -> (f32, f32) {
+        let settings = self.settings;
+        let freeze = settings.freeze;
+        let ResolvedControls {
+            noise_amt,
+            early_reflection_amt,
+            expander_amt,
+            clarity_amt,
+            de_ess_amt,
+            prox_amt,
+            level_amt,
+            breath_amt,
+            rumble_val,
+            hiss_val,
+            total_deverb,
+            output_gain,
+            denoise_cfg,
+            ..
+        } = *controls;
+        let bypass_restoration =
+            self.process_l.bypass_restoration || self.process_r.bypass_restoration;
+        let bypass_shaping = self.process_l.bypass_shaping || self.process_r.bypass_shaping;
+        let bypass_dynamics = self.process_l.bypass_dynamics || self.process_r.bypass_dynamics;
+        let bypass_hidden_tone = settings.hidden_tone_bypass;
+        let comfort_noise_on = settings.comfort_noise && !bypass_restoration;
+        let room_tone_bridge_on = settings.room_tone_bridge && !bypass_restoration;
+
+        self.numeric_guard.begin_sample();
+
+        // 0. INPUT UTILITIES (balance, swap, polarity) fix the channel
+        // delivery before anything measures it
+        let (input_l, input_r) = self.input_utility.process(input_l, input_r);
+        self.gain_audit
+            .begin_sample(input_l, input_r, self.prev_speech_conf);
+
+        // 0. MIC ALIGNMENT (lav/boom offset and polarity, ahead of all
+        // linked processing). Speech confidence lags by one sample here
+        let (input_l, input_r) = self
+            .mic_aligner
+            .process(input_l, input_r, self.prev_speech_conf);
+        let (input_l, input_r) = self
+            .numeric_guard
+            .contain(GuardStage::MicAlign, input_l, input_r);
+        let (input_l, input_r) = self.gain_audit.tap(GuardStage::MicAlign, input_l, input_r);
+
+        // 0a. SPEECH HPF (Low Cut)
+        // Removes subsonic energy before any analysis or processing
+        let (hpf_l, hpf_r) = self.speech_hpf.process(input_l, input_r);
+        let (hpf_l, hpf_r) = self
+            .numeric_guard
+            .contain(GuardStage::SpeechHpf, hpf_l, hpf_r);
+        let (hpf_l, hpf_r) = self.gain_audit.tap(GuardStage::SpeechHpf, hpf_l, hpf_r);
+
+        // 0d. SPEECH CONFIDENCE (sidechain analysis - no audio modification)
+        // Must be computed from HPF, not noise-reduced audio
+        let sidechain = self.speech_confidence.process(hpf_l, hpf_r);
+        let confidence_slope = sidechain.speech_conf - self.prev_speech_conf;
+        self.prev_speech_conf = sidechain.speech_conf;
+
+        // Hiss and rumble corners of the noise floor, ahead of the
+        // Low Cut so rumble it already removes is still located
+        if !freeze {
+            self.hiss_profile
+                .process(input_l, input_r, sidechain.speech_conf);
+        }
+
+        // Room tone per band, to spot edits between takes
+        if room_tone_bridge_on {
+            self.room_tone_bridge
+                .analyze(hpf_l, hpf_r, sidechain.speech_conf);
+        }
+
+        // Music in the input (Music Guard)
+        if settings.music_guard && !freeze {
+            self.music_detector
+                .process(hpf_l, hpf_r, sidechain.speech_conf);
+        }
+
+        // 0x. NOISE LEARN REMOVE (Static Noise)
+        // Independent of speech, works during silence
+        let nlr_cfg = NoiseLearnRemoveConfig {
+            freeze: settings.noise_learn.freeze || self.music_mix > 0.5 || freeze,
+            ..settings.noise_learn
+        };
+        let (nlr_l, nlr_r) = self
+            .noise_learn_remove
+            .process(hpf_l, hpf_r, nlr_cfg, &sidechain);
+        let (nlr_l, nlr_r) = self
+            .numeric_guard
+            .contain(GuardStage::NoiseLearn, nlr_l, nlr_r);
+        let (nlr_l, nlr_r) = self.gain_audit.tap(GuardStage::NoiseLearn, nlr_l, nlr_r);
+
+        // 0b. ENVELOPE TRACKING (Unified Source of Truth)
+        // Tracks dynamics after static noise removal for better expander/gate behavior
+        let env_l = self.process_l.envelope_tracker.process_sample(nlr_l);
+        let env_r = self.process_r.envelope_tracker.process_sample(nlr_r);
+
+        // 0c. INPUT PROFILE ANALYSIS (for data-driven calibration)
+        // INVARIANT: Only pre-restoration samples are analyzed here
+        // INVARIANT: This feeds condition detection and macro calibration
+        // We use HPF signal to capture true noise floor for environment detection
+        self.input_profile_analyzer
+            .process(hpf_l, hpf_r, sidechain.speech_conf);
+        if !freeze {
+            self.rt60_estimator.process(hpf_l, hpf_r);
+        }
+        if !freeze {
+            self.speaker_learner
+                .process(0.5 * (hpf_l + hpf_r), sidechain.speech_conf);
+            if settings.dual_speaker {
+                self.dual_speaker
+                    .process(0.5 * (hpf_l + hpf_r), sidechain.speech_conf);
+            }
+        }
+
+        // Apply real hiss/rumble shaping here
+        // Uses NLR output as base
+        let (hr_l, hr_r) = self
+            .hiss_rumble
+            .process(nlr_l, nlr_r, rumble_val, hiss_val, &sidechain);
+        let (hr_l, hr_r) = self
+            .numeric_guard
+            .contain(GuardStage::HissRumble, hr_l, hr_r);
+        let (hr_l, hr_r) = self.gain_audit.tap(GuardStage::HissRumble, hr_l, hr_r);
+
+        // Track speech band (300 Hz - 3 kHz) energy entering restoration,
+        // weighted by speech confidence so removed noise is not counted
+        let band_l = self
+            .speech_band_pre_lpf_l
+            .process(self.speech_band_pre_l.process(hr_l));
+        let band_r = self
+            .speech_band_pre_lpf_r
+            .process(self.speech_band_pre_r.process(hr_r));
+        let band_alpha = self.speech_band_alpha * sidechain.speech_conf;
+        let band_pow = (band_l * band_l + band_r * band_r) * 0.5;
+        self.speech_band_pre_env += (band_pow - self.speech_band_pre_env) * band_alpha;
+
+        // Update pre-processing RMS envelope for loudness compensation
+        let pre_rms = (hpf_l * hpf_l + hpf_r * hpf_r) * 0.5;
+        self.pre_rms_env += (pre_rms - self.pre_rms_env) * self.rms_alpha;
+
+        // Periodically maintain stability to prevent numerical drift
+        // over long sessions (every 20 ms, whatever the block size)
+        if self.stability_interval.advance(1) {
+            self.speech_confidence.maintain_stability();
+        }
+
+        // 1. EARLY REFLECTION SUPPRESSION (before denoise)
+        // This handles short-lag reflections that make recordings sound "distant"
+        let (pre_l, pre_r) = if bypass_restoration || early_reflection_amt < 0.001 {
+            (hr_l, hr_r) // Use hiss/rumble processed signal
+        } else {
+            (
+                self.early_reflection_l
+                    .process(hr_l, early_reflection_amt, &sidechain),
+                self.early_reflection_r
+                    .process(hr_r, early_reflection_amt, &sidechain),
+            )
+        };
+        let (pre_l, pre_r) = self
+            .numeric_guard
+            .contain(GuardStage::EarlyReflection, pre_l, pre_r);
+        let (pre_l, pre_r) = self
+            .gain_audit
+            .tap(GuardStage::EarlyReflection, pre_l, pre_r);
+
+        let early_reflection_suppression = self
+            .early_reflection_l
+            .get_suppression()
+            .max(self.early_reflection_r.get_suppression());
+
+        // 2. SPEECH EXPANDER (after early reflection, before denoise)
+        // Controls pauses and room swell without hard gating
+        let (exp_l, exp_r) = if expander_amt < 0.001 {
+            (pre_l, pre_r)
+        } else {
+            self.speech_expander
+                .process(pre_l, pre_r, expander_amt, &sidechain, &env_l, &env_r)
+        };
+        let (exp_l, exp_r) = self
+            .numeric_guard
+            .contain(GuardStage::Expander, exp_l, exp_r);
+        let (exp_l, exp_r) = self.gain_audit.tap(GuardStage::Expander, exp_l, exp_r);
+
+        let expander_gr_db = self.speech_expander.get_gain_reduction_db();
+
+        // 2b. TRANSIENT SUPPRESSOR (keyboard clicks, desk thumps in pauses)
+        // Runs at 0% amount too, so the event counter stays live
+        let (exp_l, exp_r) = if bypass_restoration {
+            (exp_l, exp_r)
+        } else {
+            self.transient_suppressor.process(
+                exp_l,
+                exp_r,
+                settings.transient_sensitivity,
+                settings.transient_amount,
+                sidechain.speech_conf,
+            )
+        };
+        let (exp_l, exp_r) = self
+            .numeric_guard
+            .contain(GuardStage::Transient, exp_l, exp_r);
+        let (exp_l, exp_r) = self.gain_audit.tap(GuardStage::Transient, exp_l, exp_r);
+
+        // 3. PINK REFERENCE BIAS (Hidden Spectral Tonal Conditioning)
+        // Gently nudges speech towards -3dB/oct tilt to improve stability.
+        // Gated by speech confidence, bypassed if restoration disabled.
+        let (bias_l, bias_r) = if bypass_restoration || bypass_hidden_tone {
+            (exp_l, exp_r)
+        } else {
+            self.pink_ref_bias
+                .process(exp_l, exp_r, sidechain.speech_conf, prox_amt, de_ess_amt)
+        };
+        let (bias_l, bias_r) = self
+            .numeric_guard
+            .contain(GuardStage::PinkBias, bias_l, bias_r);
+        let (bias_l, bias_r) = self.gain_audit.tap(GuardStage::PinkBias, bias_l, bias_r);
+
+        // A. RESTORATION STAGE (denoise, de-verb)
+        let (s1_l, s1_r) = if bypass_restoration {
+            (bias_l, bias_r)
+        } else {
+            // Update config with per-sample speech confidence
+            let mut cfg = denoise_cfg;
+            cfg.speech_confidence = sidechain.speech_conf;
+            self.denoiser.process_sample(bias_l, bias_r, &cfg)
+        };
+        let (s1_l, s1_r) = self.numeric_guard.contain(GuardStage::Denoiser, s1_l, s1_r);
+        let (s1_l, s1_r) = self.gain_audit.tap(GuardStage::Denoiser, s1_l, s1_r);
+
+        let denoiser_reduction = if bypass_restoration {
+            0.0
+        } else {
+            self.denoiser.get_current_reduction()
+        };
+
+        // 4. PLOSIVE SOFTENER (after denoise, before breath)
+        let s1b_l = self.plosive_softener_l.process(s1_l);
+        let s1b_r = self.plosive_softener_r.process(s1_r);
+
+        // 5. BREATH REDUCER (after plosive, before deverb)
+        let s1c_l = self
+            .breath_reducer_l
+            .process(s1b_l, breath_amt, &sidechain, &env_l);
+        let s1c_r = self
+            .breath_reducer_r
+            .process(s1b_r, breath_amt, &sidechain, &env_r);
+        let (s1c_l, s1c_r) = self
+            .numeric_guard
+            .contain(GuardStage::PlosiveBreath, s1c_l, s1c_r);
+        let (s1c_l, s1c_r) = self.gain_audit.tap(GuardStage::PlosiveBreath, s1c_l, s1c_r);
+
+        // The safety high-pass is folded into the linear-phase low cut
+        let s2_l = if bypass_restoration || self.active_linear_phase {
+            s1c_l
+        } else {
+            self.process_l.restoration_chain.safety_hpf.process(s1c_l)
+        };
+        let s2_r = if bypass_restoration || self.active_linear_phase {
+            s1c_r
+        } else {
+            self.process_r.restoration_chain.safety_hpf.process(s1c_r)
+        };
+        let s3_l = if bypass_restoration {
+            s2_l
+        } else {
+            self.process_l.restoration_chain.deverber.process_sample(
+                s2_l,
+                total_deverb,
+                self.sample_rate,
+                sidechain.speech_conf,
+                clarity_amt,
+                prox_amt,
+            )
+        };
+        let s3_r = if bypass_restoration {
+            s2_r
+        } else {
+            self.process_r.restoration_chain.deverber.process_sample(
+                s2_r,
+                total_deverb,
+                self.sample_rate,
+                sidechain.speech_conf,
+                clarity_amt,
+                prox_amt,
+            )
+        };
+        let (s3_l, s3_r) = self.numeric_guard.contain(GuardStage::Deverb, s3_l, s3_r);
+        let (s3_l, s3_r) = self.gain_audit.tap(GuardStage::Deverb, s3_l, s3_r);
+
+        // Speech band energy leaving restoration (same weighting)
+        let band_l = self
+            .speech_band_post_lpf_l
+            .process(self.speech_band_post_l.process(s3_l));
+        let band_r = self
+            .speech_band_post_lpf_r
+            .process(self.speech_band_post_r.process(s3_r));
+        let band_pow = (band_l * band_l + band_r * band_r) * 0.5;
+        self.speech_band_post_env += (band_pow - self.speech_band_post_env) * band_alpha;
+
+        // A2. CODEC REPAIR (bandwidth extension + high-band smoothing)
+        // After denoise/deverb so only cleaned speech is extended, and
+        // ahead of the de-esser, which also controls synthesized sibilance.
+        // Runs at 0% too (exact pass-through) to keep the cutoff readout live
+        let (s3_l, s3_r) = if bypass_restoration {
+            (s3_l, s3_r)
+        } else {
+            self.bandwidth_extender.process(
+                s3_l,
+                s3_r,
+                settings.codec_repair,
+                sidechain.speech_conf,
+            )
+        };
+        let (s3_l, s3_r) = self
+            .numeric_guard
+            .contain(GuardStage::CodecRepair, s3_l, s3_r);
+        let (s3_l, s3_r) = self.gain_audit.tap(GuardStage::CodecRepair, s3_l, s3_r);
+
+        // Chain Order: the shaping filters and the de-esser band are
+        // linear, so where they sit in the audio path barely matters;
+        // what the order changes is what the level-dependent detectors
+        // hear. Those feeds move between taps, and a switch crossfades
+        // them instead of re-routing (and clicking) the audio.
+        let (clarity_first, de_ess_last) = self.advance_chain_mix();
+
+        // B. SHAPING STAGE (proximity, clarity)
+        // Proximity: adds low-end warmth (100-300Hz boost) for close-mic effect
+        // Clarity: reduces low-mid mud (120-380Hz cut) for cleaner sound
+        // These effects are now independent - order is proximity first, then clarity
+        // (Clarity First: De-Harsh and the clarity detector hear the
+        // voice before the proximity warmth)
+        let (s4_l, s4_r) = if bypass_shaping {
+            (s3_l, s3_r)
+        } else {
+            let prox_tracked = (prox_amt * self.prox_scale).min(1.0);
+            (
+                self.process_l.shaping_chain.proximity.process(
+                    s3_l,
+                    prox_tracked,
+                    sidechain.speech_conf,
+                    clarity_amt,
+                ),
+                self.process_r.shaping_chain.proximity.process(
+                    s3_r,
+                    prox_tracked,
+                    sidechain.speech_conf,
+                    clarity_amt,
+                ),
+            )
+        };
+        let (s4_l, s4_r) = self
+            .numeric_guard
+            .contain(GuardStage::Proximity, s4_l, s4_r);
+        let (s4_l, s4_r) = self.gain_audit.tap(GuardStage::Proximity, s4_l, s4_r);
+
+        // De-Harsh: dynamic upper-mid glare cut, ahead of the
+        // presence lift
+        let (s4_l, s4_r) = if bypass_shaping {
+            (s4_l, s4_r)
+        } else {
+            let key = lerp(s4_l + s4_r, s3_l + s3_r, clarity_first) * 0.5;
+            self.de_harsh
+                .process_keyed(s4_l, s4_r, key, settings.de_harsh, sidechain.speech_conf)
+        };
+
+        // De-Harsh leaves the body band alone, so the pre-proximity
+        // tap can skip it
+        let clarity_drive = if bypass_shaping {
+            0.0
+        } else {
+            self.clarity_detector.analyze(
+                lerp(s4_l, s3_l, clarity_first),
+                lerp(s4_r, s3_r, clarity_first),
+            )
+        };
+        let (s5_l, s5_r) = if bypass_shaping {
+            (s4_l, s4_r)
+        } else {
+            // Sibilance ducks the presence lift. The de-esser runs
+            // after Clarity, so its reading is one sample old here
+            // (and unused while it is bypassed).
+            let sibilance = if bypass_dynamics {
+                0.0
+            } else {
+                self.linked_de_esser.last_sibilance_weight
+            };
+            (
+                self.process_l.shaping_chain.clarity.process(
+                    s4_l,
+                    clarity_amt,
+                    sidechain.speech_conf,
+                    clarity_drive,
+                    sibilance,
+                ),
+                self.process_r.shaping_chain.clarity.process(
+                    s4_r,
+                    clarity_amt,
+                    sidechain.speech_conf,
+                    clarity_drive,
+                    sibilance,
+                ),
+            )
+        };
+        let (s5_l, s5_r) = self.numeric_guard.contain(GuardStage::Clarity, s5_l, s5_r);
+        let (s5_l, s5_r) = self.gain_audit.tap(GuardStage::Clarity, s5_l, s5_r);
+
+        self.de_ess_rms_sq_l += (s5_l * s5_l - self.de_ess_rms_sq_l) * self.de_ess_alpha;
+        self.de_ess_rms_sq_r += (s5_r * s5_r - self.de_ess_rms_sq_r) * self.de_ess_alpha;
+
+        // C. DYNAMICS STAGE (de-esser, leveler, limiter)
+        // Control interaction safeguard: Apply leveler gain with consideration of de-esser and limiter activity
+        // to prevent multiple systems from fighting each other.
+        // The leveler gain follows the input envelopes, so it is known
+        // before the de-esser runs (De-Ess Last: the de-esser detector
+        // hears the leveled signal)
+        let leveler_gain = if bypass_dynamics {
+            1.0
+        } else {
+            // Get current limiter gain reduction to adjust leveler behavior
+            let limiter_gr_db = self.linked_limiter.get_gain_reduction_db();
+
+            // Adjust leveler behavior based on both de-esser and limiter activity to prevent interaction
+            let mut adjusted_level_amt = level_amt;
+
+            if self.de_ess_reduction_db < -3.0 {
+                // Strong de-esser activity
+                adjusted_level_amt *= 0.7; // Reduce leveler aggression to prevent fight
+            }
+
+            if limiter_gr_db > 2.0 {
+                // Strong limiter activity - reduce leveler aggression to prevent pumping
+                adjusted_level_amt *= 0.8;
+            }
+
+            // Repeated pumping: hold the leveler back until it settles
+            adjusted_level_amt *= self.pump_guard.leveler_scale();
+
+            let leveler_gain = self.linked_compressor.compute_gain(
+                &env_l,
+                &env_r,
+                adjusted_level_amt,
+                sidechain.speech_conf,
+                prox_amt,
+                clarity_amt,
+            );
+
+            // Pump detection, counted for the meters and the pump guard
+            if self.linked_compressor.is_pump_detected() {
+                self.pump_events += 1;
+                self.last_pump = PumpEvent {
+                    delta_db: self.linked_compressor.get_gain_delta_db(),
+                    leveler_amount: adjusted_level_amt,
+                    speech_confidence: sidechain.speech_conf,
+                    gain_reduction_db: self.linked_compressor.get_gain_reduction_db(),
+                };
+            }
+
+            // Density: the heavy path mixed under the main one. Both
+            // are gains on the same signal, so they stay aligned.
+            self.parallel_compressor.process(
+                &env_l,
+                &env_r,
+                leveler_gain,
+                settings.density,
+                sidechain.speech_conf,
+            )
+        };
+
+        let (s6_l, s6_r) = if bypass_dynamics {
+            (s5_l, s5_r)
+        } else {
+            let key_gain = lerp(1.0, leveler_gain, de_ess_last);
+            let de_ess_gain = self.linked_de_esser.compute_gain(
+                s5_l * key_gain,
+                s5_r * key_gain,
+                de_ess_amt,
+                &env_l,
+                &env_r,
+            );
+            self.de_ess_calibrator.process(
+                self.linked_de_esser.last_sib_rel_db,
+                self.linked_de_esser.last_sibilance_weight,
+            );
+            // The detector holds its last reduction while De-Ess is off
+            let de_ess_reduction = if de_ess_amt > 0.001 {
+                self.linked_de_esser.get_gain_reduction_db()
+            } else {
+                0.0
+            };
+            self.de_ess_events
+                .process(de_ess_reduction, sidechain.speech_conf);
+            let sibilance_hz = self.linked_de_esser.get_center_hz();
+            self.process_l
+                .dynamics_chain
+                .de_esser_band
+                .set_center_hz(sibilance_hz);
+            self.process_r
+                .dynamics_chain
+                .de_esser_band
+                .set_center_hz(sibilance_hz);
+            let out_l = self
+                .process_l
+                .dynamics_chain
+                .de_esser_band
+                .apply(s5_l, de_ess_gain);
+            let out_r = self
+                .process_r
+                .dynamics_chain
+                .de_esser_band
+                .apply(s5_r, de_ess_gain);
+            (out_l, out_r)
+        };
+
+        // De-esser reduction, read by the leveler on the next sample
+        self.de_ess_reduction_db = if !bypass_dynamics && de_ess_amt > 0.001 {
+            let input_power = (s5_l * s5_l + s5_r * s5_r) * 0.5;
+            let output_power = (s6_l * s6_l + s6_r * s6_r) * 0.5;
+            if output_power > 0.0 && input_power > 0.0 {
+                10.0f32 * (output_power / input_power).log10()
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+        let (s6_l, s6_r) = self.numeric_guard.contain(GuardStage::DeEsser, s6_l, s6_r);
+        let (s6_l, s6_r) = self.gain_audit.tap(GuardStage::DeEsser, s6_l, s6_r);
+
+        let (s7_l, s7_r) = if bypass_dynamics {
+            (s6_l, s6_r)
+        } else {
+            (s6_l * leveler_gain, s6_r * leveler_gain)
+        };
+        let (s7_l, s7_r) = self.numeric_guard.contain(GuardStage::Leveler, s7_l, s7_r);
+        let (s7_l, s7_r) = self.gain_audit.tap(GuardStage::Leveler, s7_l, s7_r);
+
+        // D. RECOVERY STAGE (speech-gated EQ after all subtractive processing)
+        // Applies presence and air shelving during speech to compensate for losses
+        let integrity_score = Self::integrity_score(
+            sidechain.speech_conf,
+            confidence_slope,
+            denoiser_reduction,
+            early_reflection_suppression,
+            expander_gr_db,
+        );
+
+        let (rec_l, rec_r) = if bypass_hidden_tone {
+            (s7_l, s7_r)
+        } else {
+            self.recovery_stage
+                .process(s7_l, s7_r, sidechain.speech_conf, integrity_score)
+        };
+        let (rec_l, rec_r) = self
+            .numeric_guard
+            .contain(GuardStage::Recovery, rec_l, rec_r);
+        let (rec_l, rec_r) = self.gain_audit.tap(GuardStage::Recovery, rec_l, rec_r);
+
+        // Post-noise cleanup (second-pass, very light)
+        let post_cleanup_amt = (noise_amt * 0.35).clamp(0.0, 1.0);
+        let env_rms = env_l.rms.max(env_r.rms);
+        let env_noise_floor = env_l.noise_floor.max(env_r.noise_floor);
+        let use_hf_bias = settings.post_noise_hf_bias;
+        let (post_l, post_r) = if bypass_dynamics || bypass_hidden_tone {
+            (rec_l, rec_r)
+        } else {
+            (
+                self.post_noise_cleanup_l.process_sample(
+                    rec_l,
+                    sidechain.speech_conf,
+                    env_rms,
+                    env_noise_floor,
+                    post_cleanup_amt,
+                    use_hf_bias,
+                    true,
+                ),
+                self.post_noise_cleanup_r.process_sample(
+                    rec_r,
+                    sidechain.speech_conf,
+                    env_rms,
+                    env_noise_floor,
+                    post_cleanup_amt,
+                    use_hf_bias,
+                    false,
+                ),
+            )
+        };
+        let (post_l, post_r) = self
+            .numeric_guard
+            .contain(GuardStage::PostCleanup, post_l, post_r);
+        let (post_l, post_r) = self.gain_audit.tap(GuardStage::PostCleanup, post_l, post_r);
+
+        // E. SPECTRAL GUARDRAILS (safety layer before limiter)
+        // Prevents extreme settings from breaking sound
+        // Note: Applied after leveler to ensure gain reduction doesn't exceed limiter threshold
+        let (s7g_l, s7g_r) = if bypass_hidden_tone {
+            (post_l, post_r)
+        } else {
+            self.spectral_guardrails.process(
+                post_l,
+                post_r,
+                settings.guardrails,
+                sidechain.speech_conf,
+            )
+        };
+        let (s7g_l, s7g_r) = self
+            .numeric_guard
+            .contain(GuardStage::Guardrails, s7g_l, s7g_r);
+        let (s7g_l, s7g_r) = self.gain_audit.tap(GuardStage::Guardrails, s7g_l, s7g_r);
+
+        // E1. ROOM TONE BRIDGE (new room tone morphed in from the old
+        // one after an edit; pauses only)
+        let (s7g_l, s7g_r) = if room_tone_bridge_on {
+            self.room_tone_bridge
+                .process(s7g_l, s7g_r, sidechain.speech_conf)
+        } else {
+            (s7g_l, s7g_r)
+        };
+        let (s7g_l, s7g_r) = self
+            .numeric_guard
+            .contain(GuardStage::RoomTone, s7g_l, s7g_r);
+        let (s7g_l, s7g_r) = self.gain_audit.tap(GuardStage::RoomTone, s7g_l, s7g_r);
+
+        // E2. COMFORT NOISE (matched room-tone bed in pauses)
+        // Prefers the learned static profile, else the adaptive floor
+        let (s7g_l, s7g_r) = if comfort_noise_on {
+            let profile = self
+                .noise_learn_remove
+                .learned_profile()
+                .unwrap_or_else(|| self.denoiser.noise_floor());
+            let bed = self.comfort_noise.process(
+                profile,
+                settings.comfort_noise_db,
+                sidechain.speech_conf,
+            );
+            (s7g_l + bed, s7g_r + bed)
+        } else {
+            (s7g_l, s7g_r)
+        };
+        let (s7g_l, s7g_r) = self
+            .numeric_guard
+            .contain(GuardStage::ComfortNoise, s7g_l, s7g_r);
+        let (s7g_l, s7g_r) = self.gain_audit.tap(GuardStage::ComfortNoise, s7g_l, s7g_r);
+
+        // E2b. DEAD AIR (always counted; long pauses optionally eased
+        // towards the room tone target, comfort noise included). The
+        // sidechain leads this point by the STFT latency, so the trim
+        // lets go before the next word arrives.
+        let (s7g_l, s7g_r) = self.dead_air.process(
+            s7g_l,
+            s7g_r,
+            sidechain.speech_conf,
+            settings.dead_air_sec,
+            settings.pause_trim,
+            settings.pause_level_db,
+        );
+        let (s7g_l, s7g_r) = self
+            .numeric_guard
+            .contain(GuardStage::DeadAir, s7g_l, s7g_r);
+        let (s7g_l, s7g_r) = self.gain_audit.tap(GuardStage::DeadAir, s7g_l, s7g_r);
+
+        // E3. OUTPUT ROUTING (before the limiter so a mono fold-down
+        // cannot push past the ceiling)
+        let (s7g_l, s7g_r) = settings.routing.apply(s7g_l, s7g_r);
+
+        // Soft clip (oversampled) and/or limiter. A bypassed clipper
+        // still runs its filters so the reported latency holds.
+        let (s8_l, s8_r) = if !self.active_soft_clip {
+            (s7g_l, s7g_r)
+        } else if bypass_dynamics {
+            self.soft_clipper.delay(s7g_l, s7g_r)
+        } else {
+            self.soft_clipper.process(s7g_l, s7g_r)
+        };
+        let (s8_l, s8_r) = if bypass_dynamics || !self.active_limit {
+            (s8_l, s8_r)
+        } else {
+            let limiter_gain = self.linked_limiter.compute_gain(s8_l, s8_r);
+            (s8_l * limiter_gain, s8_r * limiter_gain)
+        };
+        let (s8_l, s8_r) = self.numeric_guard.contain(GuardStage::Limiter, s8_l, s8_r);
+        let (s8_l, s8_r) = self.gain_audit.tap(GuardStage::Limiter, s8_l, s8_r);
+
+        // E4. MONO COMPATIBILITY (post-dynamics: the width fix only
+        // mixes L and R, so it cannot push past the limiter ceiling)
+        let (s8_l, s8_r) = self.mono_compat.process(s8_l, s8_r, settings.mono_fix);
+        let (s8_l, s8_r) = self
+            .numeric_guard
+            .contain(GuardStage::MonoCompat, s8_l, s8_r);
+        let (s8_l, s8_r) = self.gain_audit.tap(GuardStage::MonoCompat, s8_l, s8_r);
+        if let Some(stage) = self.numeric_guard.take_pending() {
+            self.reset_guard_stage(stage);
+        }
+
+        // F. OUTPUT GAIN
+        let s9_l = s8_l * output_gain;
+        let s9_r = s8_r * output_gain;
+
+        // Update post-processing RMS envelope
+        let post_rms = (s9_l * s9_l + s9_r * s9_r) * 0.5;
+        self.post_rms_env += (post_rms - self.post_rms_env) * self.rms_alpha;
+
+        // Apply loudness compensation gain (when on) and its trim
+        let comp_target = if settings.loudness_comp {
+            self.loudness_comp_gain
+        } else {
+            1.0
+        } * db_to_lin(settings.loudness_trim_db);
+        self.loudness_comp_applied =
+            comp_target + self.loudness_comp_coeff * (self.loudness_comp_applied - comp_target);
+        (
+            s9_l * self.loudness_comp_applied,
+            s9_r * self.loudness_comp_applied,
+        )
+    }
+
+    /// Final Output gain, the output safety net and dither, applied to what
+    /// `process_sample()` returned
+    pub fn finish_sample(&mut self, comp_out_l: f32, comp_out_r: f32) -> (f32, f32) {
+        // F. FINAL OUTPUT PRESETS (loudness normalization and true-peak limiting)
+        let mut out_l = comp_out_l * self.settings.final_gain;
+        let mut out_r = comp_out_r * self.settings.final_gain;
+        if !out_l.is_finite() || !out_r.is_finite() {
+            out_l = 0.0;
+            out_r = 0.0;
+            self.pre_rms_env = 0.0;
+            self.post_rms_env = 0.0;
+            self.loudness_comp_gain = 1.0;
+            self.loudness_comp_applied = 1.0;
+        }
+        let abs_peak = out_l.abs().max(out_r.abs());
+        if abs_peak > 4.0 {
+            let scale = 4.0 / abs_peak;
+            out_l *= scale;
+            out_r *= scale;
+        }
+
+        // G. DITHER (last stage, so nothing after it undoes the shaping)
+        if let Some(bits) = self.settings.dither_bits {
+            if self.settings.routing == Routing::Stereo {
+                out_l = self.dither_l.process(out_l, bits);
+                out_r = self.dither_r.process(out_r, bits);
+            } else {
+                // Keep routed mono bit-identical on both channels
+                out_l = self.dither_l.process(out_l, bits);
+                out_r = out_l;
+            }
+        }
+        (out_l, out_r)
+    }
+
+    /// Close a sub-block of `len` samples: the pump guard hears whether the
+    /// leveler pumped in it, and loudness compensation moves
+    pub fn end_sub_block(&mut self, len: usize) {
+        let pumped = self.pump_events != self.sub_block_pump_events;
+        self.sub_block_pump_events = self.pump_events;
+        if !self.settings.freeze {
+            self.pump_guard.process(pumped, len);
+            self.update_loudness_compensation(len);
+        }
+    }
+
+    /// End-of-buffer analysis after `frames` samples: room decay, mic
+    /// distance, hiss corners, speaker tracking, profile-driven leveler
+    /// adaptation and speech-band loss. Returns whether loudness
+    /// compensation or the limiter pumped in this buffer.
+    pub fn end_block(&mut self, frames: usize) -> bool {
+        let freeze = self.settings.freeze;
+        // Latest complete input frame (frames are a fixed 50 ms, so buffer
+        // size does not change the analysis)
+        let input_profile = self.input_profile_analyzer.get_profile();
+
+        // Mic distance from the input reverb metrics (drives Track Room),
+        // moving only as fast as those metrics are reliable
+        if !freeze {
+            self.distance_tracker.update(
+                input_profile.early_late_ratio,
+                input_profile.decay_slope,
+                self.prev_speech_conf,
+                input_profile.reverb_stability * frames as f32 / self.sample_rate,
+            );
+        }
+
+        // Room decay: adapt deverber tail to the measured room
+        let room_rt60_ms = self.rt60_estimator.get_rt60_ms();
+        let tail_character = self.settings.deverb_character;
+        for chain in [&mut self.process_l, &mut self.process_r] {
+            let deverber = &mut chain.restoration_chain.deverber;
+            deverber.set_room_decay_ms(room_rt60_ms);
+            deverber.set_tail_character(tail_character);
+        }
+
+        // Hiss/rumble corners follow the measured noise floor
+        self.hiss_rumble.set_corners(
+            self.hiss_profile.hiss_onset_hz(),
+            self.hiss_profile.rumble_cutoff_hz(),
+        );
+
+        // Speaker statistics (read by the editor when saving a profile)
+        self.speaker_learner.set_context(
+            self.linked_de_esser.get_center_hz(),
+            input_profile.presence_ratio,
+        );
+        if !freeze {
+            self.update_dual_speaker();
+        }
+
+        // Update DSP modules with profile-based adaptation
+        // METRIC OWNERSHIP: Leveler owns RMS, crest factor, RMS variance
+        // Once per new analysis frame: the adaptation is a per-update average
+        let input_frame = self.input_profile_analyzer.frames_analyzed();
+        if input_frame != self.last_input_profile_frame && !freeze {
+            self.last_input_profile_frame = input_frame;
+            self.linked_compressor
+                .update_from_profile(input_profile.crest_factor_db, input_profile.rms_variance);
+            self.parallel_compressor
+                .update_from_profile(input_profile.crest_factor_db, input_profile.rms_variance);
+        }
+
+        // Detect sudden loudness compensation + limiter movement ("pumping")
+        let prev_gain = self.prev_loudness_comp_gain.max(1e-6);
+        let loudness_ratio = (self.loudness_comp_gain / prev_gain).max(1e-6);
+        // Movement since the last buffer, scaled to a fixed window
+        let window_scale = PUMP_DELTA_WINDOW_SEC * self.sample_rate / (frames.max(1) as f32);
+        let loudness_delta_db = 20.0 * loudness_ratio.log10() * window_scale;
+        let limiter_gr_db =
+            self.linked_limiter.get_gain_reduction_db() + self.soft_clipper.gain_reduction_db();
+        let pump_trigger = loudness_delta_db.abs() > LOUDNESS_PUMP_DELTA_DB
+            || limiter_gr_db > LIMITER_PUMP_THRESHOLD_DB;
+        if pump_trigger && !freeze {
+            self.pump_guard.process(true, 0);
+        }
+
+        // Speech-band loss through restoration, used by the next buffer's
+        // speech protection
+        self.speech_loss_db = if self.speech_band_pre_env > 1e-9 {
+            (10.0 * (self.speech_band_post_env.max(1e-12) / self.speech_band_pre_env).log10())
+                .clamp(-24.0, 0.0)
+        } else {
+            0.0
+        };
+        self.prev_loudness_comp_gain = self.loudness_comp_gain;
+        pump_trigger
+    }
+
+    /// Switch per-speaker settings (de-esser center, leveler target) when the
+    /// dual-speaker detector changes speaker. Falls back to the loaded speaker
+    /// profile's settings when disabled or while only one voice has been heard.
+    fn update_dual_speaker(&mut self) {
+        let active = if self.settings.dual_speaker && self.dual_speaker.has_two_speakers() {
+            Some(self.dual_speaker.active_speaker())
+        } else {
+            None
+        };
+
+        if active != self.dual_active {
+            if let Some(prev) = self.dual_active {
+                self.dual_speaker_sibilance_hz[prev] = Some(self.linked_de_esser.get_center_hz());
+            }
+            match active {
+                Some(index) => {
+                    if let Some(hz) = self.dual_speaker_sibilance_hz[index] {
+                        self.linked_de_esser.set_center_hz(hz);
+                    }
+                }
+                None => {
+                    self.linked_compressor
+                        .set_target_offset_db(self.settings.speaker_leveler_offset_db);
+                }
+            }
+            self.dual_active = active;
+        }
+
+        if let Some(index) = active {
+            self.linked_compressor
+                .set_target_offset_db(speaker_profile::leveler_offset_for_rms(
+                    self.dual_speaker.speaker_rms_db(index),
+                ));
+        }
+    }
+
+    /// Advance the Chain Order crossfade by one sample and return the tap
+    /// shares (clarity first, de-ess last) to use for it.
+    fn advance_chain_mix(&mut self) -> (f32, f32) {
+        if let Some(t) = self.chain_xfade.advance(1) {
+            let (from, to) = (self.chain_xfade_from, self.chain_target);
+            self.chain_mix = (lerp(from.0, to.0, t), lerp(from.1, to.1, t));
+        } else {
+            self.chain_mix = self.chain_target;
+        }
+        self.chain_mix
+    }
+
+    /// Update loudness compensation gain based on RMS envelopes after
+    /// `block_len` samples have been processed. It keeps tracking while
+    /// Loudness Comp is off, so switching it back on lands on a current gain.
+    fn update_loudness_compensation(&mut self, block_len: usize) {
+        // Use a much slower slew rate for loudness compensation to prevent pumping
+        // (10 second time constant, scaled to the sub-block length; slower
+        // still while the pump guard is engaged)
+        let time_constant_sec = 10.0 * self.pump_guard.loudness_time_scale();
+        let slow_rms_alpha =
+            1.0 - (-(block_len as f32) / (time_constant_sec * self.sample_rate)).exp();
+
+        let target_gain = if self.post_rms_env > 1e-8 && self.pre_rms_env > 1e-8 {
+            let current_ratio = (self.pre_rms_env / self.post_rms_env).sqrt();
+
+            // Use a more conservative target gain (±10% instead of ±100%)
+            current_ratio.clamp(0.9, 1.1)
+        } else {
+            // Return to unity gain
+            1.0
+        };
+
+        self.loudness_comp_gain += (target_gain - self.loudness_comp_gain) * slow_rms_alpha;
+    }
+
+    /// Clear the state of one stage after it produced a non-finite sample,
+    /// leaving the rest of the chain (and its learned state) untouched
+    pub fn reset_guard_stage(&mut self, stage: GuardStage) {
+        match stage {
+            GuardStage::MicAlign => self.mic_aligner.reset(),
+            GuardStage::SpeechHpf => self.speech_hpf.reset(),
+            GuardStage::NoiseLearn => self.noise_learn_remove.reset(),
+            GuardStage::HissRumble => self.hiss_rumble.reset(),
+            GuardStage::EarlyReflection => {
+                self.early_reflection_l.reset();
+                self.early_reflection_r.reset();
+            }
+            GuardStage::Expander => self.speech_expander.reset(),
+            GuardStage::Transient => self.transient_suppressor.reset(),
+            GuardStage::PinkBias => self.pink_ref_bias.reset(),
+            GuardStage::Denoiser => self.denoiser.reset(),
+            GuardStage::PlosiveBreath => {
+                self.plosive_softener_l.reset();
+                self.plosive_softener_r.reset();
+                self.breath_reducer_l.reset();
+                self.breath_reducer_r.reset();
+            }
+            GuardStage::Deverb => {
+                self.process_l.restoration_chain.safety_hpf.reset();
+                self.process_r.restoration_chain.safety_hpf.reset();
+                self.process_l.restoration_chain.deverber.reset();
+                self.process_r.restoration_chain.deverber.reset();
+            }
+            GuardStage::CodecRepair => self.bandwidth_extender.reset(),
+            GuardStage::Proximity => {
+                self.process_l.shaping_chain.proximity.reset();
+                self.process_r.shaping_chain.proximity.reset();
+            }
+            GuardStage::Clarity => {
+                self.clarity_detector.reset();
+                self.de_harsh.reset();
+                self.process_l.shaping_chain.clarity.reset();
+                self.process_r.shaping_chain.clarity.reset();
+            }
+            GuardStage::DeEsser => {
+                self.linked_de_esser.reset();
+                self.linked_de_esser.reset_tracking();
+                self.process_l.dynamics_chain.de_esser_band.reset();
+                self.process_r.dynamics_chain.de_esser_band.reset();
+                self.de_ess_rms_sq_l = 0.0;
+                self.de_ess_rms_sq_r = 0.0;
+                self.de_ess_reduction_db = 0.0;
+            }
+            GuardStage::Leveler => {
+                self.linked_compressor.reset();
+                self.parallel_compressor.reset();
+            }
+            GuardStage::Recovery => self.recovery_stage.reset(),
+            GuardStage::PostCleanup => {
+                self.post_noise_cleanup_l.reset();
+                self.post_noise_cleanup_r.reset();
+            }
+            GuardStage::Guardrails => self.spectral_guardrails.reset(),
+            GuardStage::RoomTone => self.room_tone_bridge.reset(),
+            GuardStage::ComfortNoise => self.comfort_noise.reset(),
+            GuardStage::DeadAir => self.dead_air.reset(),
+            GuardStage::Limiter => {
+                self.linked_limiter.reset();
+                self.soft_clipper.reset();
+            }
+            GuardStage::MonoCompat => self.mono_compat.reset(),
+        }
+    }
+
+    /// How intact the speech is after the subtractive stages (0..1), which
+    /// sets how much the recovery EQ gives back
+    fn integrity_score(
+        speech_conf: f32,
+        confidence_slope: f32,
+        denoiser_reduction: f32,
+        early_reflection_suppression: f32,
+        expander_gr_db: f32,
+    ) -> f32 {
+        let stability_factor = 1.0 - confidence_slope.abs() * 2.0;
+        let harmonic_proxy = speech_conf * stability_factor.max(0.0);
+
+        let denoise_impact = denoiser_reduction * 0.6;
+        let reflection_impact = early_reflection_suppression * 0.5;
+        let expander_impact = (expander_gr_db.abs() / 10.0).clamp(0.0, 1.0);
+        let combined_reduction_impact =
+            (denoise_impact + reflection_impact + expander_impact).min(1.0);
+
+        let multiple_modules_active = (denoise_impact > 0.3) as i32
+            + (reflection_impact > 0.3) as i32
+            + (expander_impact > 0.3) as i32;
+        let cascade_penalty = (multiple_modules_active - 1).max(0) as f32 * 0.2;
+
+        let base_integrity =
+            (harmonic_proxy - combined_reduction_impact - cascade_penalty).max(0.0);
+        let integrity = if multiple_modules_active > 1 {
+            base_integrity * (1.0 - (multiple_modules_active as f32 - 1.0) * 0.3)
+        } else {
+            base_integrity
+        };
+
+        integrity.clamp(0.0, 1.0)
+    }
+}
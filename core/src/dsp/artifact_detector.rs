@@ -0,0 +1,184 @@
+//! Musical-Noise Artifact Detector
+//!
+//! Measures how much the denoiser's per-bin gains jump from frame to frame in
+//! the attenuated part of the spectrum. Isolated bins flickering open and
+//! closed are what is heard as "warbling" musical noise; a stable gain curve
+//! scores close to zero.
+//!
+//! # Purpose
+//! Drives the Artifact Guard: when the metric indicates likely warbling, the
+//! denoiser backs off its effective amount instead of relying only on the
+//! static inter-module clamps.
+//!
+//! # Design Notes
+//! - Analysis only; the caller decides how to apply `backoff()`
+//! - Fluctuations count less while speech is present (onsets are legitimate
+//!   gain changes and musical noise is most audible in pauses)
+//! - Fast attack, slow release so the back-off holds through a noisy pause
+//!
+//! ## Audio Thread Safety
+//! - Gain history is allocated in `new()`, no allocations in `update()`
+
+use super::utils::smoothstep;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Bins with a gain above this in both frames are treated as open (ignored)
+const ACTIVE_GAIN_MAX: f32 = 0.95;
+
+/// Share of the fluctuation still counted during full speech presence
+const SPEECH_WEIGHT_MIN: f32 = 0.25;
+
+/// Per-frame smoothing of the metric (rising / falling)
+const LEVEL_ATTACK: f32 = 0.2;
+const LEVEL_RELEASE: f32 = 0.02;
+
+/// Metric range over which the back-off ramps in (the meter scales to FULL)
+const BACKOFF_ONSET: f32 = 0.04;
+pub const BACKOFF_FULL: f32 = 0.12;
+
+/// Largest share of the denoise amount the guard removes
+const BACKOFF_MAX: f32 = 0.5;
+
+// =============================================================================
+// Detector
+// =============================================================================
+
+pub struct ArtifactDetector {
+    prev_gains: Vec<f32>,
+    level: f32,
+    primed: bool,
+}
+
+impl ArtifactDetector {
+    pub fn new(bins: usize) -> Self {
+        Self {
+            prev_gains: vec![1.0; bins],
+            level: 0.0,
+            primed: false,
+        }
+    }
+
+    /// Feed one frame of final gains; `speech_prob` is the frame's speech
+    /// presence (0..1). Returns the smoothed metric.
+    pub fn update(&mut self, gains: &[f32], speech_prob: f32) -> f32 {
+        let bins = gains.len().min(self.prev_gains.len());
+        if bins == 0 {
+            return self.level;
+        }
+
+        let mut flux = 0.0;
+        for i in 0..bins {
+            let g = gains[i];
+            let prev = self.prev_gains[i];
+            if g < ACTIVE_GAIN_MAX || prev < ACTIVE_GAIN_MAX {
+                flux += (g - prev).abs();
+            }
+            self.prev_gains[i] = g;
+        }
+
+        // The first frame has no history to compare against
+        if !self.primed {
+            self.primed = true;
+            return self.level;
+        }
+
+        let speech_weight = 1.0 - (1.0 - SPEECH_WEIGHT_MIN) * speech_prob.clamp(0.0, 1.0);
+        let inst = flux / bins as f32 * speech_weight;
+        let coeff = if inst > self.level {
+            LEVEL_ATTACK
+        } else {
+            LEVEL_RELEASE
+        };
+        self.level += (inst - self.level) * coeff;
+        self.level
+    }
+
+    /// Smoothed fluctuation metric (mean absolute gain change per bin)
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+
+    /// Share of the denoise amount to remove (0..`BACKOFF_MAX`)
+    pub fn backoff(&self) -> f32 {
+        smoothstep(BACKOFF_ONSET, BACKOFF_FULL, self.level) * BACKOFF_MAX
+    }
+
+    pub fn reset(&mut self) {
+        self.prev_gains.fill(1.0);
+        self.level = 0.0;
+        self.primed = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BINS: usize = 257;
+
+    fn run(
+        detector: &mut ArtifactDetector,
+        frames: usize,
+        mut gains_for: impl FnMut(usize) -> Vec<f32>,
+    ) {
+        for frame in 0..frames {
+            detector.update(&gains_for(frame), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_stable_gains_score_zero() {
+        let mut detector = ArtifactDetector::new(BINS);
+        run(&mut detector, 200, |_| vec![0.3; BINS]);
+        assert!(detector.level() < 1e-4);
+        assert_eq!(detector.backoff(), 0.0);
+    }
+
+    #[test]
+    fn test_flickering_bins_trigger_backoff() {
+        let mut detector = ArtifactDetector::new(BINS);
+        let mut seed = 12345u32;
+        run(&mut detector, 200, |_| {
+            (0..BINS)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    if (seed >> 16) & 1 == 0 {
+                        0.05
+                    } else {
+                        0.6
+                    }
+                })
+                .collect()
+        });
+        assert!(detector.level() > BACKOFF_FULL);
+        assert!((detector.backoff() - BACKOFF_MAX).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_speech_reduces_metric() {
+        let flicker = |frame: usize| vec![if frame % 2 == 0 { 0.1 } else { 0.5 }; BINS];
+
+        let mut pause = ArtifactDetector::new(BINS);
+        let mut speech = ArtifactDetector::new(BINS);
+        for frame in 0..100 {
+            pause.update(&flicker(frame), 0.0);
+            speech.update(&flicker(frame), 1.0);
+        }
+        assert!(speech.level() < pause.level() * 0.5);
+    }
+
+    #[test]
+    fn test_reset_clears_level() {
+        let mut detector = ArtifactDetector::new(BINS);
+        run(&mut detector, 50, |frame| {
+            vec![if frame % 2 == 0 { 0.1 } else { 0.8 }; BINS]
+        });
+        assert!(detector.level() > 0.0);
+        detector.reset();
+        assert_eq!(detector.level(), 0.0);
+        assert_eq!(detector.backoff(), 0.0);
+    }
+}
@@ -0,0 +1,402 @@
+//! Bandwidth Extension / Codec Repair
+//!
+//! Restores some "air" to band-limited sources (Zoom, Teams, phone-patched
+//! remote guests) and calms the swishy high band typical of speech codecs.
+//!
+//! # Purpose
+//! Remote interview audio is often resampled from 16 kHz (cutoff near 8 kHz)
+//! or narrower. Next to a full-band host mic it sounds dull and distant, and
+//! the top of what remains flutters as the codec drops and restores bands.
+//!
+//! # Design Notes
+//! - Cutoff detection: a bank of narrow band filters is averaged during
+//!   speech; a cliff of more than 12 dB between neighbouring bands (with
+//!   nothing above it) marks the codec cutoff. No cliff = full band, no
+//!   synthesis
+//! - Synthesis: the octave below the cutoff is full-wave rectified, which
+//!   doubles its frequencies, then band-passed to the octave above the
+//!   cutoff and matched to a level well below the source band
+//! - Codec smoothing: the band above half the cutoff may only follow its
+//!   own slow envelope; fast swells (swishes) are pulled back to it
+//! - Both are gated by speech confidence so pauses are never synthesized
+//!   or modulated
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, filters are redesigned in place, no allocations in
+//!   `process()`
+
+use super::biquad::Biquad;
+use super::utils::{db_to_gain, smoothstep, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Cutoff detector band centers (Hz)
+const DETECT_CENTERS_HZ: [f32; 8] = [
+    3500.0, 4500.0, 6000.0, 7500.0, 9000.0, 11000.0, 13000.0, 15000.0,
+];
+const DETECT_Q: f32 = 4.0;
+/// Cascaded band-pass sections per detector band (steep skirts)
+const DETECT_STAGES: usize = 3;
+/// Band energy averaging (seconds of speech)
+const DETECT_TAU_SEC: f32 = 2.0;
+/// Drop between neighbouring bands that marks a codec cutoff
+const CLIFF_DB: f32 = 12.0;
+/// Ignore bands quieter than this (mean square)
+const MIN_BAND_ENERGY: f32 = 1e-10;
+/// Samples between cutoff decisions; a change needs two agreeing decisions
+const DECISION_INTERVAL: u32 = 8192;
+
+/// Smoothing split when no cutoff has been detected
+const DEFAULT_SPLIT_HZ: f32 = 4000.0;
+
+/// Synthesized band level relative to the octave below the cutoff
+const EXT_LEVEL_DB: f32 = -12.0;
+/// Synthesis gain cap (keeps near-silent excitation from being blown up)
+const MAX_SYNTH_GAIN: f32 = 8.0;
+
+/// Envelope followers (ms)
+const ENV_ATTACK_MS: f32 = 5.0;
+const ENV_RELEASE_MS: f32 = 50.0;
+const SWISH_FAST_MS: f32 = 2.0;
+const SWISH_SLOW_MS: f32 = 40.0;
+const GAIN_SMOOTH_MS: f32 = 20.0;
+
+/// Deepest high-band dip the codec smoother applies at 100% amount
+const MAX_SMOOTH_DB: f32 = 6.0;
+
+/// Speech confidence range over which the module fades in
+const SPEECH_LO: f32 = 0.35;
+const SPEECH_HI: f32 = 0.65;
+
+const EPS: f32 = 1e-9;
+
+// =============================================================================
+// Per-channel state
+// =============================================================================
+
+struct ChannelState {
+    src_hpf: Biquad,
+    src_lpf: Biquad,
+    syn_hpf_1: Biquad,
+    syn_hpf_2: Biquad,
+    syn_lpf: Biquad,
+    split_hpf: Biquad,
+
+    env_src: f32,
+    env_syn: f32,
+    synth_gain: f32,
+    swish_fast: f32,
+    swish_slow: f32,
+}
+
+impl ChannelState {
+    fn new() -> Self {
+        Self {
+            src_hpf: Biquad::new(),
+            src_lpf: Biquad::new(),
+            syn_hpf_1: Biquad::new(),
+            syn_hpf_2: Biquad::new(),
+            syn_lpf: Biquad::new(),
+            split_hpf: Biquad::new(),
+            env_src: 0.0,
+            env_syn: 0.0,
+            synth_gain: 0.0,
+            swish_fast: 0.0,
+            swish_slow: 0.0,
+        }
+    }
+
+    fn design(&mut self, cutoff_hz: Option<f32>, sr: f32) {
+        let nyq_safe = 0.45 * sr;
+        let fc = cutoff_hz.unwrap_or(DEFAULT_SPLIT_HZ * 2.0);
+        self.src_hpf.update_hpf(0.5 * fc, 0.707, sr);
+        self.src_lpf.update_lpf(0.95 * fc, 0.707, sr);
+        self.syn_hpf_1.update_hpf(fc, 0.707, sr);
+        self.syn_hpf_2.update_hpf(fc, 0.707, sr);
+        self.syn_lpf.update_lpf((2.0 * fc).min(nyq_safe), 0.707, sr);
+        self.split_hpf.update_hpf(0.5 * fc, 0.707, sr);
+    }
+
+    fn reset(&mut self) {
+        for f in [
+            &mut self.src_hpf,
+            &mut self.src_lpf,
+            &mut self.syn_hpf_1,
+            &mut self.syn_hpf_2,
+            &mut self.syn_lpf,
+            &mut self.split_hpf,
+        ] {
+            f.reset_state();
+        }
+        self.env_src = 0.0;
+        self.env_syn = 0.0;
+        self.synth_gain = 0.0;
+        self.swish_fast = 0.0;
+        self.swish_slow = 0.0;
+    }
+}
+
+// =============================================================================
+// Extender
+// =============================================================================
+
+pub struct BandwidthExtender {
+    sample_rate: f32,
+    left: ChannelState,
+    right: ChannelState,
+
+    detect: [[Biquad; DETECT_STAGES]; DETECT_CENTERS_HZ.len()],
+    detect_energy: [f32; DETECT_CENTERS_HZ.len()],
+    /// Number of detector bands below the safe Nyquist limit
+    detect_bands: usize,
+    decision_countdown: u32,
+    candidate: Option<f32>,
+    cutoff_hz: Option<f32>,
+
+    detect_alpha: f32,
+    env_atk: f32,
+    env_rel: f32,
+    swish_fast: f32,
+    swish_slow: f32,
+    gain_coeff: f32,
+    speech_gate: f32,
+}
+
+impl BandwidthExtender {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut detect = [[Biquad::new(); DETECT_STAGES]; DETECT_CENTERS_HZ.len()];
+        for (stages, &c) in detect.iter_mut().zip(DETECT_CENTERS_HZ.iter()) {
+            if c < 0.45 * sample_rate {
+                for f in stages.iter_mut() {
+                    f.update_bandpass(c, DETECT_Q, sample_rate);
+                }
+            }
+        }
+        let detect_bands = DETECT_CENTERS_HZ
+            .iter()
+            .filter(|&&c| c < 0.45 * sample_rate)
+            .count();
+
+        let mut s = Self {
+            sample_rate,
+            left: ChannelState::new(),
+            right: ChannelState::new(),
+            detect,
+            detect_energy: [0.0; DETECT_CENTERS_HZ.len()],
+            detect_bands,
+            decision_countdown: DECISION_INTERVAL,
+            candidate: None,
+            cutoff_hz: None,
+            detect_alpha: 1.0 - (-1.0 / (DETECT_TAU_SEC * sample_rate)).exp(),
+            env_atk: time_constant_coeff(ENV_ATTACK_MS, sample_rate),
+            env_rel: time_constant_coeff(ENV_RELEASE_MS, sample_rate),
+            swish_fast: time_constant_coeff(SWISH_FAST_MS, sample_rate),
+            swish_slow: time_constant_coeff(SWISH_SLOW_MS, sample_rate),
+            gain_coeff: time_constant_coeff(GAIN_SMOOTH_MS, sample_rate),
+            speech_gate: 0.0,
+        };
+        s.left.design(None, sample_rate);
+        s.right.design(None, sample_rate);
+        s
+    }
+
+    pub fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+        for f in self.detect.iter_mut().flatten() {
+            f.reset_state();
+        }
+        self.detect_energy = [0.0; DETECT_CENTERS_HZ.len()];
+        self.decision_countdown = DECISION_INTERVAL;
+        self.candidate = None;
+        self.set_cutoff(None);
+        self.speech_gate = 0.0;
+    }
+
+    /// Detected codec cutoff in Hz (`None` = full band)
+    pub fn cutoff_hz(&self) -> Option<f32> {
+        self.cutoff_hz
+    }
+
+    fn set_cutoff(&mut self, cutoff: Option<f32>) {
+        if cutoff != self.cutoff_hz {
+            self.cutoff_hz = cutoff;
+            self.left.design(cutoff, self.sample_rate);
+            self.right.design(cutoff, self.sample_rate);
+        }
+    }
+
+    /// Cutoff implied by the current band energies
+    fn find_cliff(&self) -> Option<f32> {
+        let bands = &self.detect_energy[..self.detect_bands];
+        let cliff = db_to_gain(-CLIFF_DB) * db_to_gain(-CLIFF_DB);
+        (1..bands.len()).find_map(|k| {
+            let below = bands[k - 1];
+            let limit = below * cliff;
+            if below > MIN_BAND_ENERGY && bands[k..].iter().all(|&e| e < limit) {
+                Some((DETECT_CENTERS_HZ[k - 1] * DETECT_CENTERS_HZ[k]).sqrt())
+            } else {
+                None
+            }
+        })
+    }
+
+    fn detect(&mut self, mid: f32, speech_conf: f32) {
+        if speech_conf > 0.5 {
+            for k in 0..self.detect_bands {
+                let y = self.detect[k].iter_mut().fold(mid, |x, f| f.process(x));
+                self.detect_energy[k] += (y * y - self.detect_energy[k]) * self.detect_alpha;
+            }
+        }
+
+        self.decision_countdown = self.decision_countdown.saturating_sub(1);
+        if self.decision_countdown == 0 {
+            self.decision_countdown = DECISION_INTERVAL;
+            let found = self.find_cliff();
+            if found == self.candidate {
+                self.set_cutoff(found);
+            }
+            self.candidate = found;
+        }
+    }
+
+    #[inline]
+    fn process_channel(
+        ch: &mut ChannelState,
+        x: f32,
+        synth_amt: f32,
+        smooth_amt: f32,
+        k: (f32, f32, f32, f32, f32),
+    ) -> f32 {
+        let (env_atk, env_rel, swish_fast, swish_slow, gain_coeff) = k;
+        let follow = |env: &mut f32, v: f32| {
+            let c = if v > *env { env_atk } else { env_rel };
+            *env = c * *env + (1.0 - c) * v;
+        };
+
+        // Codec smoothing: hold the high band to its slow envelope
+        let hf = ch.split_hpf.process(x);
+        let level = hf.abs();
+        ch.swish_fast = swish_fast * ch.swish_fast + (1.0 - swish_fast) * level;
+        ch.swish_slow = swish_slow * ch.swish_slow + (1.0 - swish_slow) * level;
+        let dip = (ch.swish_slow / ch.swish_fast.max(EPS)).clamp(db_to_gain(-MAX_SMOOTH_DB), 1.0);
+        let hf_gain = 1.0 - (1.0 - dip) * smooth_amt;
+        let mut y = x + hf * (hf_gain - 1.0);
+
+        // Synthesis: rectified octave below the cutoff, moved above it
+        if synth_amt > 0.0 {
+            let src = ch.src_lpf.process(ch.src_hpf.process(x));
+            let syn = ch
+                .syn_lpf
+                .process(ch.syn_hpf_2.process(ch.syn_hpf_1.process(src.abs())));
+            follow(&mut ch.env_src, src.abs());
+            follow(&mut ch.env_syn, syn.abs());
+            let target = (ch.env_src * db_to_gain(EXT_LEVEL_DB) / ch.env_syn.max(EPS))
+                .min(MAX_SYNTH_GAIN)
+                * synth_amt;
+            ch.synth_gain = gain_coeff * ch.synth_gain + (1.0 - gain_coeff) * target;
+            y += syn * ch.synth_gain;
+        }
+        y
+    }
+
+    /// Process one stereo sample.
+    ///
+    /// `amount` is 0..1 and scales both synthesis and smoothing.
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32, amount: f32, speech_conf: f32) -> (f32, f32) {
+        self.detect(0.5 * (left + right), speech_conf);
+
+        let gate = smoothstep(SPEECH_LO, SPEECH_HI, speech_conf);
+        self.speech_gate = self.gain_coeff * self.speech_gate + (1.0 - self.gain_coeff) * gate;
+        let amt = amount.clamp(0.0, 1.0) * self.speech_gate;
+        let synth_amt = if self.cutoff_hz.is_some() { amt } else { 0.0 };
+
+        let k = (
+            self.env_atk,
+            self.env_rel,
+            self.swish_fast,
+            self.swish_slow,
+            self.gain_coeff,
+        );
+        (
+            Self::process_channel(&mut self.left, left, synth_amt, amt, k),
+            Self::process_channel(&mut self.right, right, synth_amt, amt, k),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    const SR: f32 = 48000.0;
+
+    /// Harmonic "voice" with partials up to `top_hz`
+    fn voice(i: usize, top_hz: f32) -> f32 {
+        let t = i as f32 / SR;
+        (1..)
+            .map(|h| h as f32 * 150.0)
+            .take_while(|&f| f < top_hz)
+            .map(|f| (2.0 * PI * f * t).sin() * 0.1 * (150.0 / f).sqrt())
+            .sum()
+    }
+
+    fn run(top_hz: f32, seconds: f32) -> BandwidthExtender {
+        let mut bwe = BandwidthExtender::new(SR);
+        for i in 0..(seconds * SR) as usize {
+            let x = voice(i, top_hz);
+            bwe.process(x, x, 1.0, 0.9);
+        }
+        bwe
+    }
+
+    #[test]
+    fn test_detects_wideband_codec_cutoff() {
+        let bwe = run(7800.0, 3.0);
+        let fc = bwe.cutoff_hz().expect("cutoff should be detected");
+        assert!((7500.0..9000.0).contains(&fc), "cutoff {fc}");
+    }
+
+    #[test]
+    fn test_full_band_left_alone() {
+        let bwe = run(20000.0, 3.0);
+        assert_eq!(bwe.cutoff_hz(), None);
+    }
+
+    #[test]
+    fn test_synthesizes_above_cutoff() {
+        let mut bwe = run(7800.0, 3.0);
+        let mut probe = [Biquad::new(); 2];
+        for f in probe.iter_mut() {
+            f.update_hpf(9500.0, 0.707, SR);
+        }
+        let (mut added, mut total) = (0.0f32, 0.0f32);
+        for i in 0..SR as usize {
+            let x = voice(i, 7800.0);
+            let (y, _) = bwe.process(x, x, 1.0, 0.9);
+            let hf = probe.iter_mut().fold(y - x, |s, f| f.process(s));
+            added += hf * hf;
+            total += x * x;
+        }
+        assert!(added > 1e-3 * total, "added {added} total {total}");
+    }
+
+    #[test]
+    fn test_pauses_untouched() {
+        let mut bwe = run(7800.0, 3.0);
+        // Let the speech gate close, then check unity
+        for i in 0..SR as usize {
+            let x = voice(i, 7800.0);
+            bwe.process(x, x, 1.0, 0.0);
+        }
+        for i in 0..1000 {
+            let x = voice(i, 7800.0);
+            let (y, _) = bwe.process(x, x, 1.0, 0.0);
+            assert!((y - x).abs() < 1e-4, "{y} vs {x}");
+        }
+    }
+}
@@ -102,6 +102,22 @@ impl Biquad {
         self.b2 = (1.0 - alpha) * inv_a0;
     }
 
+    /// Band-pass with 0 dB peak gain at `center`
+    pub fn update_bandpass(&mut self, center: f32, q: f32, sr: f32) {
+        let w0 = 2.0 * PI * center / sr;
+        let alpha = w0.sin() / (2.0 * q.max(1e-6));
+        let cw0 = w0.cos();
+
+        let a0 = 1.0 + alpha;
+        let inv_a0 = 1.0 / a0;
+
+        self.a0 = alpha * inv_a0;
+        self.a1 = 0.0;
+        self.a2 = -alpha * inv_a0;
+        self.b1 = (-2.0 * cw0) * inv_a0;
+        self.b2 = (1.0 - alpha) * inv_a0;
+    }
+
     pub fn update_low_shelf(&mut self, cutoff: f32, q: f32, gain_db: f32, sr: f32) {
         // Bypass when effectively flat
         if gain_db.abs() < 0.01 {
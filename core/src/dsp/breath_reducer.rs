@@ -0,0 +1,194 @@
+use crate::dsp::envelope::VoiceEnvelope;
+use crate::dsp::speech_confidence::SpeechSidechain;
+use crate::dsp::utils::{lerp, lin_to_db, time_constant_coeff, DB_EPS};
+use std::f32::consts::TAU;
+
+/// Breath Reducer (Advanced Control)
+///
+/// Softens breaths during low speech confidence periods without gating or muting.
+///
+/// In Shape mode (`set_shape()`) breaths are kept but made consistent: any
+/// breath louder than the target level is brought down to it and gently
+/// low-passed, quieter ones are left as they are.
+pub struct BreathReducer {
+    envelope: f32,
+    gain_smooth: f32,
+    sample_rate: f32,
+    /// Gain attack/release multiplier (Response)
+    time_scale: f32,
+    /// Shape mode target (dBFS peak), `None` for plain reduction
+    shape_target_db: Option<f32>,
+    lowpass: f32,
+    lowpass_mix: f32,
+}
+
+impl BreathReducer {
+    // Detection constants
+    const ENV_ATTACK_MS: f32 = 5.0;
+    const ENV_RELEASE_MS: f32 = 40.0;
+
+    // Gain ballistics
+    const GAIN_ATTACK_MS: f32 = 30.0;
+    const GAIN_RELEASE_MS: f32 = 100.0;
+
+    // Thresholds
+    const BREATH_MAX_REDUCTION_DB: f32 = 10.0;
+
+    // Shape mode
+    const SHAPE_MAX_REDUCTION_DB: f32 = 24.0;
+    const SHAPE_LOWPASS_HZ: f32 = 4000.0;
+
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            envelope: 0.0,
+            gain_smooth: 1.0,
+            sample_rate,
+            time_scale: 1.0,
+            shape_target_db: None,
+            lowpass: 0.0,
+            lowpass_mix: 0.0,
+        }
+    }
+
+    pub fn _prepare(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Scale the gain attack/release (1.0 = default); breath detection keeps
+    /// its own fixed envelope.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.clamp(0.25, 4.0);
+    }
+
+    /// Shape breaths to `target_db` (dBFS peak) instead of reducing them;
+    /// `None` returns to plain reduction.
+    pub fn set_shape(&mut self, target_db: Option<f32>) {
+        self.shape_target_db = target_db;
+    }
+
+    #[inline]
+    pub fn process(
+        &mut self,
+        input: f32,
+        amount: f32,
+        sidechain: &SpeechSidechain,
+        _env: &VoiceEnvelope,
+    ) -> f32 {
+        let abs_in = input.abs();
+
+        // 1. Independent envelope tracking for breath detection
+        let atk = time_constant_coeff(Self::ENV_ATTACK_MS, self.sample_rate);
+        let rel = time_constant_coeff(Self::ENV_RELEASE_MS, self.sample_rate);
+
+        if abs_in > self.envelope {
+            self.envelope = atk * self.envelope + (1.0 - atk) * abs_in;
+        } else {
+            self.envelope = rel * self.envelope + (1.0 - rel) * abs_in;
+        }
+
+        // 2. Breath logic: Low speech confidence + significant energy = likely breath
+        // We invert speech confidence to get "silence/breath confidence"
+        let breath_prob = (1.0 - sidechain.speech_conf).powf(4.0); // Bias strongly towards low confidence
+
+        // 3. Compute target gain
+        // Reduce: softly attenuate based on amount and breath probability.
+        // Shape: pull the breath down to the target level and low-pass it.
+        let (target_reduction_db, target_mix) = match self.shape_target_db {
+            None => (amount * breath_prob * Self::BREATH_MAX_REDUCTION_DB, 0.0),
+            Some(target_db) => {
+                let over_db = (lin_to_db(self.envelope.max(DB_EPS)) - target_db)
+                    .clamp(0.0, Self::SHAPE_MAX_REDUCTION_DB);
+                (amount * breath_prob * over_db, amount * breath_prob)
+            }
+        };
+        let target_gain = 10.0f32.powf(-target_reduction_db / 20.0);
+
+        // 4. Smooth gain independently
+        let g_atk = time_constant_coeff(Self::GAIN_ATTACK_MS * self.time_scale, self.sample_rate);
+        let g_rel = time_constant_coeff(Self::GAIN_RELEASE_MS * self.time_scale, self.sample_rate);
+
+        if target_gain < self.gain_smooth {
+            self.gain_smooth = g_atk * self.gain_smooth + (1.0 - g_atk) * target_gain;
+        } else {
+            self.gain_smooth = g_rel * self.gain_smooth + (1.0 - g_rel) * target_gain;
+        }
+
+        // 5. Shape low-pass, faded in with the same ballistics
+        let lp = (-TAU * Self::SHAPE_LOWPASS_HZ / self.sample_rate).exp();
+        self.lowpass = lp * self.lowpass + (1.0 - lp) * input;
+        let c = if target_mix > self.lowpass_mix {
+            g_atk
+        } else {
+            g_rel
+        };
+        self.lowpass_mix = c * self.lowpass_mix + (1.0 - c) * target_mix;
+
+        lerp(input, self.lowpass, self.lowpass_mix) * self.gain_smooth
+    }
+
+    pub fn reset(&mut self) {
+        self.envelope = 0.0;
+        self.gain_smooth = 1.0;
+        self.lowpass = 0.0;
+        self.lowpass_mix = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Gain after 30 ms of a breath (no speech) at full amount
+    fn gain_after_30ms(scale: f32) -> f32 {
+        let mut br = BreathReducer::new(SR);
+        br.set_time_scale(scale);
+        let sidechain = SpeechSidechain {
+            speech_conf: 0.0,
+            noise_floor_db: -60.0,
+        };
+        let mut out = 0.0;
+        for _ in 0..(0.03 * SR) as usize {
+            out = br.process(0.1, 1.0, &sidechain, &VoiceEnvelope::default());
+        }
+        out / 0.1
+    }
+
+    #[test]
+    fn test_time_scale_sets_attack_speed() {
+        let fast = gain_after_30ms(0.5);
+        let natural = gain_after_30ms(1.0);
+        let relaxed = gain_after_30ms(2.0);
+        assert!(
+            fast < natural && natural < relaxed,
+            "{fast} {natural} {relaxed}"
+        );
+    }
+
+    /// Settled gain on a steady breath (no speech) at full amount
+    fn settled_gain(shape_target_db: Option<f32>, level: f32) -> f32 {
+        let mut br = BreathReducer::new(SR);
+        br.set_shape(shape_target_db);
+        let sidechain = SpeechSidechain {
+            speech_conf: 0.0,
+            noise_floor_db: -60.0,
+        };
+        let mut out = 0.0;
+        for _ in 0..(0.5 * SR) as usize {
+            out = br.process(level, 1.0, &sidechain, &VoiceEnvelope::default());
+        }
+        out / level
+    }
+
+    #[test]
+    fn test_shape_holds_breaths_at_target() {
+        let db = |g: f32| 20.0 * g.log10();
+        // Reduce: a fixed 10 dB whatever the level
+        assert!((db(settled_gain(None, 0.1)) + 10.0).abs() < 0.5);
+        // Shape: a -20 dB breath lands on the -40 dB target...
+        assert!((db(settled_gain(Some(-40.0), 0.1)) + 20.0).abs() < 1.0);
+        // ...and one already below it is left alone
+        assert!(db(settled_gain(Some(-40.0), 0.005)).abs() < 0.5);
+    }
+}
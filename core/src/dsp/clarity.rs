@@ -0,0 +1,415 @@
+//! Dynamic Low-Mid Congestion Controller & Detector
+//!
+//! Reduces low-mid congestion (120–380 Hz) during voiced speech to restore
+//! perceived clarity after tonal shaping. Uses voiced speech detection to
+//! apply targeted reduction only when needed, preserving natural voice character.
+//!
+//! # Purpose
+//! Address chesty, congested, or "muddy" speech with low-mid buildup by
+//! dynamically reducing low-mid frequencies during voiced speech segments.
+//! Maintains subtractive-only processing to complement upstream processing.
+//!
+//! # Design Notes
+//! - Targets low-mid congestion range (120-380 Hz)
+//! - Uses voiced speech detection for intelligent application
+//! - Low-mid stage remains subtractive only; band center (120-500 Hz) and Q
+//!   are adjustable, and the detector band moves with the center
+//! - Optional presence lift (2-5 kHz peak, up to +6 dB) follows speech
+//!   confidence so pauses and room tone are not brightened
+//! - The presence lift ducks while the de-esser detector hears sibilance, so
+//!   Clarity does not add the brightness the de-esser then has to take out
+//! - Works in conjunction with pink reference bias for balanced tonal shaping
+
+use crate::dsp::utils::{
+    aggressive_tail, speech_weighted, time_constant_coeff, update_env_sq, DB_EPS,
+};
+use crate::dsp::Biquad;
+
+// Constants for clarity detector tuning
+
+// Vocal body range filters
+// High-pass cutoff (Hz) - bottom of vocal body range.
+// Increasing: tighter focus; decreasing: includes more low mids.
+const DETECTOR_HPF_HZ: f32 = 120.0;
+// Low-pass cutoff (Hz) - top of vocal body range.
+// Increasing: includes more upper mids; decreasing: tighter focus.
+const DETECTOR_LPF_HZ: f32 = 380.0;
+// Filter Q (Butterworth-ish).
+// Increasing: narrower band; decreasing: wider band.
+const DETECTOR_Q: f32 = 0.7;
+// RMS envelope attack time (ms).
+// Increasing: slower tracking; decreasing: faster tracking.
+const RMS_ATTACK_MS: f32 = 30.0;
+// RMS envelope release time (ms).
+// Increasing: smoother decay; decreasing: faster decay.
+const RMS_RELEASE_MS: f32 = 250.0;
+// ZCR smoothing decay factor (per sample).
+// Increasing: smoother ZCR; decreasing: more reactive.
+const ZC_DECAY: f32 = 0.995;
+// ZCR smoothing attack factor (per sample).
+// Increasing: faster ZCR update; decreasing: slower update.
+const ZC_ATTACK: f32 = 0.005;
+// ZCR to voicing scale factor.
+// Increasing: ZCR has more influence; decreasing: less influence.
+const ZC_VOICED_SCALE: f32 = 6.0;
+
+// Constants for clarity shaper tuning
+
+// Low shelf filter frequency (Hz).
+// Increasing: higher crossover; decreasing: lower crossover.
+const SHAPER_FREQ_HZ: f32 = 250.0;
+// Shaper filter Q.
+// Increasing: narrower transition; decreasing: wider transition.
+const SHAPER_Q: f32 = 0.7;
+// Maximum low-mid cut at full clarity (dB).
+// Increasing: stronger cut; decreasing: gentler cut.
+const MAX_CUT_DB: f32 = 64.0;
+// Coefficient smoothing factor.
+// Increasing: faster transitions; decreasing: smoother transitions.
+const SMOOTH_COEFF: f32 = 0.02;
+// Coefficient update threshold (dB).
+// Increasing: fewer updates; decreasing: more frequent updates.
+const COEFF_UPDATE_THRESHOLD: f32 = 0.05;
+// Presence lift peak Q (about 1.5 octaves wide).
+const PRESENCE_Q: f32 = 0.9;
+// Largest presence lift the user can ask for (dB).
+const MAX_PRESENCE_DB: f32 = 6.0;
+// Share of the presence lift removed during full sibilance.
+// Increasing: darker "s" sounds; decreasing: more lift left on sibilants.
+const SIBILANCE_DUCK: f32 = 0.8;
+// Bypass threshold for clarity amount.
+// Increasing: easier to bypass; decreasing: more likely to process.
+const CLARITY_BYPASS_EPS: f32 = 0.001;
+
+/// Shared stereo-linked detector for body energy detection
+pub struct ClarityDetector {
+    hp: Biquad,
+    lp: Biquad,
+
+    env_sq: f32,
+
+    prev_sign: f32,
+    zc_energy: f32,
+
+    center_hz: f32,
+    sample_rate: f32,
+}
+
+impl ClarityDetector {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut hp = Biquad::new();
+        hp.update_hpf(DETECTOR_HPF_HZ, DETECTOR_Q, sample_rate);
+
+        let mut lp = Biquad::new();
+        lp.update_lpf(DETECTOR_LPF_HZ, DETECTOR_Q, sample_rate);
+
+        Self {
+            hp,
+            lp,
+            env_sq: 0.0,
+            prev_sign: 0.0,
+            zc_energy: 0.0,
+            center_hz: SHAPER_FREQ_HZ,
+            sample_rate,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.hp.reset();
+        self.lp.reset();
+        self.env_sq = 0.0;
+        self.prev_sign = 0.0;
+        self.zc_energy = 0.0;
+    }
+
+    /// Move the detector band with the Clarity center (default 250 Hz)
+    pub fn set_center_hz(&mut self, center_hz: f32) {
+        if (center_hz - self.center_hz).abs() < 0.5 {
+            return;
+        }
+        self.center_hz = center_hz;
+        let scale = center_hz / SHAPER_FREQ_HZ;
+        self.hp
+            .update_hpf(DETECTOR_HPF_HZ * scale, DETECTOR_Q, self.sample_rate);
+        self.lp
+            .update_lpf(DETECTOR_LPF_HZ * scale, DETECTOR_Q, self.sample_rate);
+    }
+
+    #[inline]
+    fn coeff(&self, ms: f32) -> f32 {
+        time_constant_coeff(ms, self.sample_rate)
+    }
+
+    /// Returns body energy drive signal [0..1]
+    pub fn analyze(&mut self, l: f32, r: f32) -> f32 {
+        let x = l.abs().max(r.abs());
+
+        // Band-limit to vocal body range
+        let band = self.lp.process(self.hp.process(x));
+
+        // RMS envelope (vowel aware)
+        let atk = self.coeff(RMS_ATTACK_MS);
+        let rel = self.coeff(RMS_RELEASE_MS);
+        let sq = band * band;
+
+        self.env_sq = update_env_sq(self.env_sq, sq, atk, rel);
+
+        let rms = self.env_sq.sqrt().max(DB_EPS);
+
+        // Voicing confidence (linked ZCR)
+        let sign = x.signum();
+        let zc = if sign != self.prev_sign { 1.0 } else { 0.0 };
+        self.prev_sign = sign;
+
+        self.zc_energy = self.zc_energy * ZC_DECAY + zc * ZC_ATTACK;
+        let voiced = (1.0 - self.zc_energy * ZC_VOICED_SCALE).clamp(0.0, 1.0);
+
+        // Final body energy drive
+        (rms * voiced).clamp(0.0, 1.0)
+    }
+}
+
+/// Per-channel Clarity shaper (dynamic low-mid cleanup)
+///
+/// ## Metric Ownership (SHARED with Proximity)
+/// This module OWNS and is responsible for:
+/// - **Presence ratio**: Contributes to target (≤ 0.01) via low-mid cleanup
+/// - **Air ratio**: Contributes to target (≤ 0.005) via reduced low-mid masking
+///
+/// This module must NOT attempt to modify:
+/// - RMS, crest factor, RMS variance (owned by Leveler)
+/// - Noise floor, SNR (owned by Denoiser)
+/// - Early/Late ratio, decay slope (owned by De-reverb)
+/// - HF variance (read-only guardrail metric)
+///
+/// ## Behavioral Rules
+/// - Only active if presence < target
+/// - Strength scales with SNR
+/// - Hard caps: Whisper → 25% max, Noisy → 40% max
+pub struct Clarity {
+    shaper: Biquad,
+    last_cut_db: f32,
+    center_hz: f32,
+    q: f32,
+    band_changed: bool,
+
+    presence: Biquad,
+    presence_hz: f32,
+    presence_db: f32,
+    last_presence_db: f32,
+
+    sample_rate: f32,
+}
+
+impl Clarity {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut shaper = Biquad::new();
+        shaper.update_low_shelf(SHAPER_FREQ_HZ, SHAPER_Q, 0.0, sample_rate);
+
+        let mut presence = Biquad::new();
+        presence.update_peaking(3000.0, PRESENCE_Q, 0.0, sample_rate);
+
+        Self {
+            shaper,
+            last_cut_db: 0.0,
+            center_hz: SHAPER_FREQ_HZ,
+            q: SHAPER_Q,
+            band_changed: false,
+            presence,
+            presence_hz: 3000.0,
+            presence_db: 0.0,
+            last_presence_db: 0.0,
+            sample_rate,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.shaper.reset();
+        self.presence.reset();
+    }
+
+    /// Low-mid band center (Hz) and Q
+    pub fn set_band(&mut self, center_hz: f32, q: f32) {
+        if (center_hz - self.center_hz).abs() >= 0.5 || (q - self.q).abs() >= 0.01 {
+            self.center_hz = center_hz;
+            self.q = q;
+            self.band_changed = true;
+        }
+    }
+
+    /// Presence lift center (Hz) and gain (dB, 0 = off)
+    pub fn set_presence(&mut self, hz: f32, gain_db: f32) {
+        if (hz - self.presence_hz).abs() >= 1.0 {
+            self.presence_hz = hz;
+            // Force a coefficient update at the new frequency
+            self.last_presence_db = f32::NAN;
+        }
+        self.presence_db = gain_db.clamp(0.0, MAX_PRESENCE_DB);
+    }
+
+    /// clarity: user slider (0..1)
+    /// speech_confidence: speech confidence from detector (0..1)
+    /// drive: shared detector output (0..1)
+    /// sibilance: de-esser detector sibilance weight (0..1), sidechain
+    pub fn process(
+        &mut self,
+        input: f32,
+        clarity: f32,
+        speech_confidence: f32,
+        drive: f32,
+        sibilance: f32,
+    ) -> f32 {
+        let shaped = self.cut_low_mids(input, clarity, speech_confidence, drive);
+        self.lift_presence(shaped, speech_confidence, sibilance)
+    }
+
+    fn cut_low_mids(
+        &mut self,
+        input: f32,
+        clarity: f32,
+        speech_confidence: f32,
+        drive: f32,
+    ) -> f32 {
+        // Clarity = reduce low-mid mud (subtractive only)
+        // Uses aggressive_tail curve to preserve usability until ~70%
+
+        if clarity <= CLARITY_BYPASS_EPS {
+            return input;
+        }
+
+        // Apply aggressive tail curve to slider
+        let x = aggressive_tail(clarity);
+
+        // Speech-weighted max cut (reduces max during voiced speech)
+        let max_cut_db = speech_weighted(MAX_CUT_DB, speech_confidence);
+
+        // Calculate effective cut
+        let mut clarity_cut_db = x * max_cut_db * drive;
+
+        // Hard guardrail: never exceed 48dB
+        clarity_cut_db = clarity_cut_db.min(48.0);
+
+        // Additional speech protection: if highly confident speech, limit to 30dB
+        if speech_confidence > 0.6 {
+            clarity_cut_db = clarity_cut_db.min(30.0);
+        }
+
+        let target_cut_db = -clarity_cut_db;
+
+        // Smooth coefficient changes
+        let cut_db = self.last_cut_db + SMOOTH_COEFF * (target_cut_db - self.last_cut_db);
+        self.last_cut_db = cut_db;
+
+        if (cut_db - target_cut_db).abs() > COEFF_UPDATE_THRESHOLD || self.band_changed {
+            self.shaper
+                .update_low_shelf(self.center_hz, self.q, cut_db, self.sample_rate);
+            self.band_changed = false;
+        }
+
+        // The low-mid stage must remain subtractive only.
+        // Air is handled upstream by Pink Reference Bias.
+        self.shaper.process(input)
+    }
+
+    /// Optional presence peak, scaled by speech confidence and ducked on
+    /// sibilants
+    fn lift_presence(&mut self, input: f32, speech_confidence: f32, sibilance: f32) -> f32 {
+        let duck = 1.0 - SIBILANCE_DUCK * sibilance.clamp(0.0, 1.0);
+        let target_db = self.presence_db * speech_confidence.clamp(0.0, 1.0) * duck;
+        if target_db < COEFF_UPDATE_THRESHOLD && self.last_presence_db == 0.0 {
+            return input;
+        }
+
+        let prev = if self.last_presence_db.is_nan() {
+            target_db
+        } else {
+            self.last_presence_db
+        };
+        let mut lift_db = prev + SMOOTH_COEFF * (target_db - prev);
+        if lift_db < COEFF_UPDATE_THRESHOLD && target_db < COEFF_UPDATE_THRESHOLD {
+            lift_db = 0.0;
+        }
+        if (lift_db - prev).abs() > 1e-4 || self.last_presence_db.is_nan() {
+            self.presence
+                .update_peaking(self.presence_hz, PRESENCE_Q, lift_db, self.sample_rate);
+        }
+        self.last_presence_db = lift_db;
+
+        self.presence.process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::utils::db_to_gain;
+
+    const SR: f32 = 48000.0;
+
+    fn peak_out(c: &mut Clarity, hz: f32, speech_confidence: f32) -> f32 {
+        peak_out_sibilant(c, hz, speech_confidence, 0.0)
+    }
+
+    fn peak_out_sibilant(c: &mut Clarity, hz: f32, speech_confidence: f32, sibilance: f32) -> f32 {
+        let mut peak = 0.0f32;
+        for i in 0..SR as usize {
+            let x = (std::f32::consts::TAU * hz * i as f32 / SR).sin();
+            let y = c.process(x, 0.0, speech_confidence, 0.0, sibilance);
+            if i > SR as usize / 2 {
+                peak = peak.max(y.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_presence_lift_follows_speech() {
+        let mut c = Clarity::new(SR);
+        assert_eq!(
+            peak_out(&mut c, 3000.0, 1.0),
+            peak_out(&mut Clarity::new(SR), 3000.0, 0.0)
+        );
+
+        c.set_presence(3000.0, 6.0);
+        let lifted = peak_out(&mut c, 3000.0, 1.0);
+        assert!((lifted - 2.0).abs() < 0.05, "lift {lifted}");
+
+        // No lift in pauses
+        let pause = peak_out(&mut c, 3000.0, 0.0);
+        assert!((pause - 1.0).abs() < 0.01, "pause {pause}");
+    }
+
+    #[test]
+    fn test_presence_lift_ducks_on_sibilance() {
+        let mut c = Clarity::new(SR);
+        c.set_presence(3000.0, 6.0);
+        let ducked = peak_out_sibilant(&mut c, 3000.0, 1.0, 1.0);
+        // 80% of the 6 dB lift comes off
+        let expected = db_to_gain(6.0 * (1.0 - SIBILANCE_DUCK));
+        assert!((ducked - expected).abs() < 0.02, "ducked {ducked}");
+
+        // And comes back once the sibilant has passed
+        let lifted = peak_out_sibilant(&mut c, 3000.0, 1.0, 0.0);
+        assert!((lifted - 2.0).abs() < 0.05, "lift {lifted}");
+    }
+
+    #[test]
+    fn test_band_center_moves_the_cut() {
+        let mut low = Clarity::new(SR);
+        let mut high = Clarity::new(SR);
+        high.set_band(500.0, 0.7);
+        let run = |c: &mut Clarity| {
+            let mut peak = 0.0f32;
+            for i in 0..SR as usize {
+                let x = (std::f32::consts::TAU * 300.0 * i as f32 / SR).sin();
+                let y = c.process(x, 1.0, 0.0, 0.2, 0.0);
+                if i > SR as usize / 2 {
+                    peak = peak.max(y.abs());
+                }
+            }
+            peak
+        };
+        // A 300 Hz tone sits below the 500 Hz corner, so it is cut harder
+        assert!(run(&mut high) < run(&mut low));
+    }
+}
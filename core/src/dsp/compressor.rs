@@ -18,6 +18,11 @@
 //! - Program-dependent release with hold for stability
 //! - Smoothed peak control signal to reduce jitter
 //! - Gated makeup gain that respects silence
+//! - Attack, release and hold times scale together with `set_time_scale()`
+//!   (the Response control); the peak tamer attack stays fixed so peaks
+//!   are still caught
+//! - Adjustable leveler target (-36..-12 dBFS); the peak tamer threshold
+//!   moves with it so the headroom between them stays 12 dB
 
 use crate::dsp::envelope::VoiceEnvelope;
 use crate::dsp::utils::{db_to_lin, lin_to_db, time_constant_coeff, DB_EPS};
@@ -39,6 +44,9 @@ const HYBRID_RMS_WEIGHT: f32 = 0.75;
 const HYBRID_PEAK_WEIGHT: f32 = 0.25;
 
 const LEVELER_TARGET_DB: f32 = -24.0;
+const LEVELER_TARGET_MIN_DB: f32 = -36.0;
+const LEVELER_TARGET_MAX_DB: f32 = -12.0;
+const LEVELER_TARGET_OFFSET_MAX_DB: f32 = 6.0;
 
 const LEVELER_RATIO_LOW_DB: f32 = 3.0;
 const LEVELER_RATIO_MID_DB: f32 = 8.0;
@@ -52,6 +60,8 @@ const LEVELER_KNEE_DB: f32 = 10.0;
 const PEAK_TAMER_THRESHOLD_DB: f32 = -12.0;
 const PEAK_TAMER_RATIO: f32 = 10.0;
 const PEAK_TAMER_KNEE_DB: f32 = 4.0;
+// Highest peak tamer threshold when the target is raised
+const PEAK_TAMER_THRESHOLD_MAX_DB: f32 = -3.0;
 
 const GAIN_REDUCTION_AVG_REL: f32 = 0.995;
 const GAIN_REDUCTION_PEAK_REL: f32 = 0.9997;
@@ -117,6 +127,13 @@ pub struct LinkedCompressor {
     rms_variance: f32,
     adaptation_coeff: f32,
 
+    // Attack/release multiplier (Response)
+    time_scale: f32,
+
+    // User leveler target (dBFS) and per-speaker offset (dB)
+    target_db: f32,
+    target_offset_db: f32,
+
     // Smoothed output gain for bypass/amount transitions
     out_gain_smooth: f32,
 
@@ -155,6 +172,9 @@ impl LinkedCompressor {
             crest_factor_db: 25.0,
             rms_variance: 0.001,
             adaptation_coeff,
+            time_scale: 1.0,
+            target_db: LEVELER_TARGET_DB,
+            target_offset_db: 0.0,
             out_gain_smooth: 1.0,
             reduction_smooth_db: 0.0,
             peak_reduction_smooth_db: 0.0,
@@ -177,6 +197,23 @@ impl LinkedCompressor {
             + (1.0 - self.adaptation_coeff) * rms_variance;
     }
 
+    /// Scale attack, release and hold times (1.0 = default ballistics)
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.clamp(0.25, 4.0);
+    }
+
+    /// Set the leveler target (dBFS, clamped to -36..-12); the peak tamer
+    /// threshold follows it.
+    pub fn set_target_db(&mut self, target_db: f32) {
+        self.target_db = target_db.clamp(LEVELER_TARGET_MIN_DB, LEVELER_TARGET_MAX_DB);
+    }
+
+    /// Shift the leveler target for the current speaker (e.g. from a speaker profile).
+    pub fn set_target_offset_db(&mut self, offset_db: f32) {
+        self.target_offset_db =
+            offset_db.clamp(-LEVELER_TARGET_OFFSET_MAX_DB, LEVELER_TARGET_OFFSET_MAX_DB);
+    }
+
     #[inline]
     fn coeff(&self, time_ms: f32) -> f32 {
         time_constant_coeff(time_ms, self.sample_rate)
@@ -186,7 +223,7 @@ impl LinkedCompressor {
     /// Returns samples for release hold based on sample rate.
     #[inline]
     fn hold_samples(&self) -> u32 {
-        ((RELEASE_HOLD_MS * 0.001 * self.sample_rate) as u32).max(1)
+        ((RELEASE_HOLD_MS * self.time_scale * 0.001 * self.sample_rate) as u32).max(1)
     }
 
     /// Cheap smoothstep approximation: 3t² - 2t³
@@ -339,7 +376,7 @@ impl LinkedCompressor {
         // =====================================================================
         // STAGE 1: LEVELER (gentle, wide knee)
         // =====================================================================
-        let over1 = hybrid_db - LEVELER_TARGET_DB;
+        let over1 = hybrid_db - (self.target_db + self.target_offset_db);
 
         // Crest adaptation: reduce ratio when crest is low (already compressed material)
         let ratio_mult = if self.crest_factor_db < CREST_ADAPTATION_THRESHOLD_DB {
@@ -367,14 +404,16 @@ impl LinkedCompressor {
         // =====================================================================
         // STAGE 2: PEAK TAMER (fast, separate envelope)
         // =====================================================================
-        let over2 = peak_db - PEAK_TAMER_THRESHOLD_DB;
+        let peak_threshold_db = (PEAK_TAMER_THRESHOLD_DB + self.target_db - LEVELER_TARGET_DB)
+            .min(PEAK_TAMER_THRESHOLD_MAX_DB);
+        let over2 = peak_db - peak_threshold_db;
         let red2_raw = Self::soft_knee(over2, PEAK_TAMER_RATIO, PEAK_TAMER_KNEE_DB);
         let red2_db = (red2_raw * detector_weight).min(MAX_PEAK_REDUCTION_DB);
 
         // Peak tamer envelope
         let peak_target_db = red2_db * amount;
         let peak_att = self.coeff(PEAK_TAMER_ATTACK_MS);
-        let peak_rel = self.coeff(PEAK_TAMER_RELEASE_MS);
+        let peak_rel = self.coeff(PEAK_TAMER_RELEASE_MS * self.time_scale);
 
         if peak_target_db > self.peak_reduction_smooth_db {
             self.peak_reduction_smooth_db =
@@ -402,9 +441,10 @@ impl LinkedCompressor {
         // PDR: smooth mapping based on current reduction level
         // t=0 at 2dB reduction, t=1 at 12dB reduction
         let pdr_t = ((self.reduction_smooth_db - 2.0) / 10.0).clamp(0.0, 1.0);
-        let release_ms = Self::lerp(GAIN_RELEASE_MS_FAST, GAIN_RELEASE_MS_SLOW, pdr_t);
+        let release_ms =
+            Self::lerp(GAIN_RELEASE_MS_FAST, GAIN_RELEASE_MS_SLOW, pdr_t) * self.time_scale;
 
-        let att = self.coeff(GAIN_ATTACK_MS);
+        let att = self.coeff(GAIN_ATTACK_MS * self.time_scale);
         let rel = self.coeff(release_ms);
 
         if leveler_target_db > self.reduction_smooth_db {
@@ -518,3 +558,68 @@ impl LinkedCompressor {
         self.pump_detected = false;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::envelope::VoiceEnvelopeTracker;
+
+    const SR: f32 = 48000.0;
+
+    /// Gain reduction after 2 s of a steady -18 dBFS tone at full amount
+    fn reduction_at(target_db: f32) -> f32 {
+        let mut comp = LinkedCompressor::new(SR);
+        comp.set_target_db(target_db);
+        let mut tracker = VoiceEnvelopeTracker::new(SR);
+        let amp = db_to_lin(-18.0) * std::f32::consts::SQRT_2;
+        for i in 0..2 * SR as usize {
+            let x = amp * (std::f32::consts::TAU * 220.0 * i as f32 / SR).sin();
+            let env = tracker.process_sample(x);
+            comp.compute_gain(&env, &env, 1.0, 0.9, 0.0, 0.0);
+        }
+        comp.get_gain_reduction_db()
+    }
+
+    #[test]
+    fn test_higher_target_rides_hotter() {
+        let quiet = reduction_at(-30.0);
+        let default = reduction_at(LEVELER_TARGET_DB);
+        let hot = reduction_at(-12.0);
+        assert!(quiet > default + 1.0, "{quiet} {default}");
+        assert!(hot < default, "{hot} {default}");
+        assert!(hot < 0.5, "{hot}");
+    }
+
+    #[test]
+    fn test_fast_response_engages_sooner() {
+        let engaged_after = |scale: f32| {
+            let mut comp = LinkedCompressor::new(SR);
+            comp.set_time_scale(scale);
+            let mut tracker = VoiceEnvelopeTracker::new(SR);
+            let amp = db_to_lin(-12.0) * std::f32::consts::SQRT_2;
+            (0..SR as usize)
+                .position(|i| {
+                    let x = amp * (std::f32::consts::TAU * 220.0 * i as f32 / SR).sin();
+                    let env = tracker.process_sample(x);
+                    comp.compute_gain(&env, &env, 1.0, 0.9, 0.0, 0.0) < db_to_lin(-3.0)
+                })
+                .unwrap()
+        };
+        let fast = engaged_after(0.5);
+        let natural = engaged_after(1.0);
+        let relaxed = engaged_after(2.0);
+        assert!(
+            fast < natural && natural < relaxed,
+            "{fast} {natural} {relaxed}"
+        );
+    }
+
+    #[test]
+    fn test_target_clamped() {
+        let mut comp = LinkedCompressor::new(SR);
+        comp.set_target_db(0.0);
+        assert_eq!(comp.target_db, LEVELER_TARGET_MAX_DB);
+        comp.set_target_db(-80.0);
+        assert_eq!(comp.target_db, LEVELER_TARGET_MIN_DB);
+    }
+}
@@ -41,6 +41,13 @@ const NOISY_SLEW_MULT: f32 = 0.75;
 /// Absolute maximum slew per frame (hard safety clamp)
 const ABS_MAX_SLEW_PER_FRAME: f32 = 0.05;
 
+/// Speech-band loss allowed before denoise/de-verb are scaled back (dB)
+pub const DEFAULT_SPEECH_LOSS_BUDGET_DB: f32 = 2.0;
+
+/// User range for the speech-band loss budget (dB)
+pub const SPEECH_LOSS_BUDGET_MIN_DB: f32 = 1.0;
+pub const SPEECH_LOSS_BUDGET_MAX_DB: f32 = 4.0;
+
 // =============================================================================
 // Control Slew Limiter
 // =============================================================================
@@ -81,6 +88,16 @@ impl ControlSlewLimiter {
     /// Returns the slew-limited value that moves toward target at a safe rate.
     #[inline]
     pub fn process(&mut self, target: f32, whisper: bool, noisy: bool) -> f32 {
+        self.process_scaled(target, whisper, noisy, 1.0)
+    }
+
+    /// Same as `process`, but with the per-frame slew limit scaled by `scale`.
+    ///
+    /// Used when a frame is split into several control sub-blocks: each
+    /// sub-block passes its share of the frame so the total slew per frame
+    /// stays the same.
+    #[inline]
+    pub fn process_scaled(&mut self, target: f32, whisper: bool, noisy: bool, scale: f32) -> f32 {
         // First call: initialize to target (no slewing on startup)
         if !self.initialized {
             self.current = target;
@@ -89,7 +106,7 @@ impl ControlSlewLimiter {
         }
 
         // Calculate effective slew limit based on conditions
-        let slew_limit = Self::calculate_slew_limit(whisper, noisy);
+        let slew_limit = Self::calculate_slew_limit(whisper, noisy) * scale.max(0.0);
 
         // Calculate desired change
         let delta = target - self.current;
@@ -170,6 +187,10 @@ pub struct SpectralControlLimiters {
     pub reverb_strength: ControlSlewLimiter,
     /// Proximity boost strength (0-1)
     pub proximity_strength: ControlSlewLimiter,
+    /// Fraction of a frame covered by each `process()` call (1.0 = once per frame)
+    slew_scale: f32,
+    /// Speech-band loss allowed before protection engages (dB, positive)
+    speech_loss_budget_db: f32,
 }
 
 impl Default for SpectralControlLimiters {
@@ -186,9 +207,25 @@ impl SpectralControlLimiters {
             deesser_strength: ControlSlewLimiter::new(),
             reverb_strength: ControlSlewLimiter::new(),
             proximity_strength: ControlSlewLimiter::new(),
+            slew_scale: 1.0,
+            speech_loss_budget_db: DEFAULT_SPEECH_LOSS_BUDGET_DB,
         }
     }
 
+    /// Set the fraction of a frame covered by the next `process()` call.
+    #[inline]
+    pub fn set_slew_scale(&mut self, scale: f32) {
+        self.slew_scale = scale.clamp(0.0, 1.0);
+    }
+
+    /// Set the speech-band loss (dB) tolerated before denoise and de-verb
+    /// are scaled back.
+    #[inline]
+    pub fn set_speech_loss_budget(&mut self, budget_db: f32) {
+        self.speech_loss_budget_db =
+            budget_db.clamp(SPEECH_LOSS_BUDGET_MIN_DB, SPEECH_LOSS_BUDGET_MAX_DB);
+    }
+
     /// Process all control values with slew limiting and perceptual safeguards.
     /// Returns struct with limited values.
     #[inline]
@@ -201,7 +238,7 @@ impl SpectralControlLimiters {
         proximity_in: f32,
         whisper: bool,
         noisy: bool,
-        speech_loss_db: f32, // Passed from macro controller via lib.rs
+        speech_loss_db: f32, // Measured speech-band loss, passed from lib.rs
     ) -> LimitedControls {
         // --- 1. Clarity/Noise Knee Safeguard ---
         // Decouple Clarity from Noise Reduction above a knee to prevent speech energy loss.
@@ -219,9 +256,10 @@ impl SpectralControlLimiters {
         };
 
         // --- 3. Speech Band Energy Protection ---
-        // Scale back if loss exceeds 2 dB.
-        let speech_protection_scale = if speech_loss_db < -2.0 {
-            let deficit = -2.0 - speech_loss_db;
+        // Scale back if loss exceeds the budget (2 dB by default).
+        let budget_db = -self.speech_loss_budget_db;
+        let speech_protection_scale = if speech_loss_db < budget_db {
+            let deficit = budget_db - speech_loss_db;
             (1.0 - (deficit / 5.0).min(0.4)).clamp(0.6, 1.0)
         } else {
             1.0
@@ -231,14 +269,24 @@ impl SpectralControlLimiters {
         let final_reverb = reverb_in * reverb_budget_scale * speech_protection_scale;
         let _energy_budget_scale = reverb_budget_scale;
 
+        let scale = self.slew_scale;
+
         LimitedControls {
-            denoise: self.denoise_strength.process(final_denoise, whisper, noisy),
-            clarity: self.clarity_emphasis.process(clarity_in, whisper, noisy),
-            deesser: self.deesser_strength.process(deesser_in, whisper, noisy),
-            reverb: self.reverb_strength.process(final_reverb, whisper, noisy),
+            denoise: self
+                .denoise_strength
+                .process_scaled(final_denoise, whisper, noisy, scale),
+            clarity: self
+                .clarity_emphasis
+                .process_scaled(clarity_in, whisper, noisy, scale),
+            deesser: self
+                .deesser_strength
+                .process_scaled(deesser_in, whisper, noisy, scale),
+            reverb: self
+                .reverb_strength
+                .process_scaled(final_reverb, whisper, noisy, scale),
             proximity: self
                 .proximity_strength
-                .process(proximity_in, whisper, noisy),
+                .process_scaled(proximity_in, whisper, noisy, scale),
             speech_protection_active: speech_protection_scale < 0.99, // Active if scaling down
             speech_protection_scale,
             energy_budget_active: reverb_budget_scale < 0.99, // Active if scaling down
@@ -356,4 +404,39 @@ mod tests {
         assert!((result.denoise - 0.5).abs() < 0.001);
         assert!((result.clarity - 0.3).abs() < 0.001);
     }
+
+    #[test]
+    fn test_speech_loss_budget() {
+        let mut default = SpectralControlLimiters::new();
+        let mut tight = SpectralControlLimiters::new();
+        let mut relaxed = SpectralControlLimiters::new();
+        tight.set_speech_loss_budget(1.0);
+        relaxed.set_speech_loss_budget(4.0);
+
+        // 3 dB of loss: over the default and tight budgets, inside the relaxed one
+        let d = default.process(0.5, 0.0, 0.0, 0.5, 0.0, false, false, -3.0);
+        let t = tight.process(0.5, 0.0, 0.0, 0.5, 0.0, false, false, -3.0);
+        let r = relaxed.process(0.5, 0.0, 0.0, 0.5, 0.0, false, false, -3.0);
+
+        assert!(d.speech_protection_active && t.speech_protection_active);
+        assert!(t.speech_protection_scale < d.speech_protection_scale);
+        assert!(!r.speech_protection_active);
+        assert!((r.denoise - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_scaled_sub_blocks_match_full_frame() {
+        let mut full = ControlSlewLimiter::new();
+        let mut split = ControlSlewLimiter::new();
+        full.process(0.0, false, false);
+        split.process(0.0, false, false);
+
+        let full_result = full.process(1.0, false, false);
+        let mut split_result = 0.0;
+        for _ in 0..4 {
+            split_result = split.process_scaled(1.0, false, false, 0.25);
+        }
+
+        assert!((full_result - split_result).abs() < 1e-6);
+    }
 }
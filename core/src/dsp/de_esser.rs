@@ -0,0 +1,674 @@
+//! De-Esser (Sibilance Reducer)
+//!
+//! A dynamic equalizer designed to reduce harsh sibilant sounds (s, sh, ch) that occur
+//! in vocal recordings. Uses frequency-domain analysis to detect sibilance and applies
+//! targeted reduction in the 4.5-10kHz range.
+//!
+//! # Purpose
+//! Controls harsh sibilant sounds without affecting the overall tonal balance of the voice.
+//! Operates as part of the dynamics processing stage in the signal chain.
+//!
+//! # Design Notes
+//! - Uses dual-band detection to distinguish sibilance from other high-frequency content
+//! - Applies reduction only when sibilance is detected above the threshold
+//! - Maintains natural consonant sounds while reducing harshness
+//! - Tracks where sibilant energy concentrates (5-9kHz) and re-centers the cut band
+//! - Auto threshold: [`DeEssCalibrator`] collects the peak level of each
+//!   sibilant event relative to the voice over 10 s of program and places the
+//!   threshold so a chosen share of those events is treated
+//! - [`DeEssEventCounter`] counts the events the de-esser actually acts on
+//!   and their depth, so a setting can be judged over a whole episode
+
+use crate::dsp::envelope::VoiceEnvelope;
+use crate::dsp::utils::{db_to_gain, lin_to_db, smoothstep, DB_EPS};
+use crate::dsp::Biquad;
+
+// ---------------- Constants ----------------
+
+const SIBILANCE_HPF_HZ: f32 = 4500.0;
+const SIBILANCE_FILTER_Q: f32 = 0.707;
+const SIBILANCE_LPF_HZ: f32 = 10_000.0;
+
+const HF_SPLIT_LPF_HZ: f32 = 3500.0;
+const HF_SPLIT_Q: f32 = 0.707;
+
+const SIB_ATTACK_SEC: f32 = 0.0004;
+const SIB_RELEASE_SEC: f32 = 0.060;
+
+const ZC_SMOOTH_SEC: f32 = 0.030;
+const UNVOICED_ZC_MIN: f32 = 0.02;
+const UNVOICED_ZC_MAX: f32 = 0.10;
+
+const FOCUS_HF_WEIGHT: f32 = 0.6;
+const FOCUS_MIN: f32 = 0.30;
+const FOCUS_MAX: f32 = 0.75;
+
+const LEVEL_THRESH_SCALE: f32 = 0.12;
+const LEVEL_THRESH_MIN: f32 = 1e-6;
+const LEVEL_THRESH_DB_FLOOR: f32 = -45.0;
+
+const DE_ESS_RATIO: f32 = 6.0;
+const MAX_REDUCTION_DB: f32 = 24.0;
+const MIN_GAIN: f32 = 0.10;
+
+const GAIN_ATTACK_SEC: f32 = 0.0015;
+const GAIN_RELEASE_SEC: f32 = 0.080;
+
+const DE_ESS_BAND_HZ: f32 = 7000.0;
+const DE_ESS_BAND_Q: f32 = 1.0;
+
+// Sibilance frequency tracking: analysis band centers (Hz)
+const TRACK_BAND_HZ: [f32; 5] = [5000.0, 6000.0, 7000.0, 8000.0, 9000.0];
+const TRACK_BAND_Q: f32 = 2.5;
+const TRACK_ENV_SEC: f32 = 0.010;
+// Only sibilant moments above this weight move the tracked center
+const TRACK_WEIGHT_GATE: f32 = 0.5;
+// Time constant of accumulated sibilance needed to move the center
+const TRACK_SMOOTH_SEC: f32 = 0.5;
+// Keep the tracked center clear of Nyquist at low sample rates
+const TRACK_MAX_NYQUIST_FRAC: f32 = 0.42;
+// Re-center the cut band only when it moved this far (Hz)
+const BAND_RETUNE_HZ: f32 = 50.0;
+
+const DE_ESSER_BYPASS_EPS: f32 = 0.01;
+const INPUT_FLOOR: f32 = 1e-10;
+
+// Auto threshold: program analysed per calibration (seconds)
+const CALIBRATION_SEC: f32 = 10.0;
+// Sibilance weight hysteresis that delimits one sibilant event
+const EVENT_START_WEIGHT: f32 = 0.5;
+const EVENT_END_WEIGHT: f32 = 0.3;
+// Fewer events than this cannot place a percentile
+const MIN_CALIBRATION_EVENTS: u32 = 10;
+// Histogram of event peaks relative to the voice envelope (0.5 dB bins)
+const HIST_MIN_DB: f32 = -60.0;
+const HIST_BIN_DB: f32 = 0.5;
+const HIST_BINS: usize = 180;
+// Auto threshold may move the built-in threshold this far either way
+const MAX_THRESHOLD_OFFSET_DB: f32 = 18.0;
+
+// Event counter: reduction that opens and closes one de-ess event (dB)
+const COUNT_START_DB: f32 = 1.0;
+const COUNT_END_DB: f32 = 0.5;
+// Speech confidence above which program time counts towards the rate
+const COUNT_SPEECH_CONF: f32 = 0.5;
+
+// ---------------- Detector ----------------
+
+pub struct DeEsserDetector {
+    sib_hpf: Biquad,
+    sib_lpf: Biquad,
+    hf_lpf: Biquad,
+
+    sib_env: f32,
+    gain_smooth: f32,
+
+    prev_hf: f32,
+    zc_env: f32,
+
+    // Sibilance center-frequency tracking
+    track_bands: [Biquad; TRACK_BAND_HZ.len()],
+    track_env: [f32; TRACK_BAND_HZ.len()],
+    center_hz: f32,
+    /// Per-sample band envelope and center smoothing coefficients
+    track_env_coeff: f32,
+    track_smooth_coeff: f32,
+    /// Hold the center where it is (Freeze Adaptation)
+    tracking_frozen: bool,
+
+    sample_rate: f32,
+
+    // Auto threshold correction (dB, 0 = built-in threshold)
+    threshold_offset_db: f32,
+
+    pub last_sibilance_weight: f32,
+    pub last_over_db: f32,
+    pub last_reduction_db: f32,
+    /// Sibilance envelope relative to the voice envelope (dB)
+    pub last_sib_rel_db: f32,
+}
+
+impl DeEsserDetector {
+    pub fn new(sr: f32) -> Self {
+        let mut sib_hpf = Biquad::new();
+        sib_hpf.update_hpf(SIBILANCE_HPF_HZ, SIBILANCE_FILTER_Q, sr);
+
+        let mut sib_lpf = Biquad::new();
+        sib_lpf.update_lpf(SIBILANCE_LPF_HZ, SIBILANCE_FILTER_Q, sr);
+
+        let mut hf_lpf = Biquad::new();
+        hf_lpf.update_lpf(HF_SPLIT_LPF_HZ, HF_SPLIT_Q, sr);
+
+        let max_hz = sr * TRACK_MAX_NYQUIST_FRAC;
+        let mut track_bands = [Biquad::new(); TRACK_BAND_HZ.len()];
+        for (band, &hz) in track_bands.iter_mut().zip(TRACK_BAND_HZ.iter()) {
+            band.update_bandpass(hz.min(max_hz), TRACK_BAND_Q, sr);
+        }
+
+        Self {
+            sib_hpf,
+            sib_lpf,
+            hf_lpf,
+            sib_env: 0.0,
+            gain_smooth: 1.0,
+            prev_hf: 0.0,
+            zc_env: 0.0,
+            track_bands,
+            track_env: [0.0; TRACK_BAND_HZ.len()],
+            center_hz: DE_ESS_BAND_HZ.min(max_hz),
+            track_env_coeff: (-1.0 / (TRACK_ENV_SEC * sr)).exp(),
+            track_smooth_coeff: 1.0 - (-1.0 / (TRACK_SMOOTH_SEC * sr)).exp(),
+            tracking_frozen: false,
+            sample_rate: sr,
+            threshold_offset_db: 0.0,
+            last_sibilance_weight: 0.0,
+            last_over_db: 0.0,
+            last_reduction_db: 0.0,
+            last_sib_rel_db: HIST_MIN_DB,
+        }
+    }
+
+    fn analyze_sibilance_weight(&mut self, x: f32) -> f32 {
+        let sib = self.sib_lpf.process(self.sib_hpf.process(x));
+        let sib_abs = sib.abs();
+
+        let low = self.hf_lpf.process(x);
+        let hf = x - low;
+        let hf_abs = hf.abs();
+
+        let atk = (-1.0 / (SIB_ATTACK_SEC * self.sample_rate)).exp();
+        let rel = (-1.0 / (SIB_RELEASE_SEC * self.sample_rate)).exp();
+        self.sib_env = if sib_abs > self.sib_env {
+            atk * self.sib_env + (1.0 - atk) * sib_abs
+        } else {
+            rel * self.sib_env + (1.0 - rel) * sib_abs
+        };
+
+        let sign = hf.signum();
+        let prev = self.prev_hf.signum();
+        let zc = if sign != prev { 1.0 } else { 0.0 };
+        self.prev_hf = hf;
+
+        let zc_s = (-1.0 / (ZC_SMOOTH_SEC * self.sample_rate)).exp();
+        self.zc_env = zc_s * self.zc_env + (1.0 - zc_s) * zc;
+
+        let unvoiced = smoothstep(UNVOICED_ZC_MIN, UNVOICED_ZC_MAX, self.zc_env).clamp(0.0, 1.0);
+
+        let focus =
+            (self.sib_env / (self.sib_env + hf_abs * FOCUS_HF_WEIGHT + DB_EPS)).clamp(0.0, 1.0);
+        let focus_w = smoothstep(FOCUS_MIN, FOCUS_MAX, focus);
+
+        (unvoiced * focus_w).clamp(0.0, 1.0)
+    }
+
+    /// Follow where sibilant energy concentrates for this speaker.
+    ///
+    /// Band energies are tracked continuously; the energy-weighted center only
+    /// moves while sibilance is detected, so vowels and noise do not drag it.
+    fn track_sibilance_center(&mut self, mono: f32, weight: f32) {
+        let env_coeff = self.track_env_coeff;
+        let mut energy_sum = 0.0;
+        let mut weighted_hz = 0.0;
+        for i in 0..TRACK_BAND_HZ.len() {
+            let y = self.track_bands[i].process(mono);
+            self.track_env[i] = env_coeff * self.track_env[i] + (1.0 - env_coeff) * y * y;
+            energy_sum += self.track_env[i];
+            weighted_hz += self.track_env[i] * TRACK_BAND_HZ[i];
+        }
+
+        if self.tracking_frozen || weight < TRACK_WEIGHT_GATE || energy_sum <= DB_EPS {
+            return;
+        }
+
+        let max_hz = self.sample_rate * TRACK_MAX_NYQUIST_FRAC;
+        let target = (weighted_hz / energy_sum)
+            .clamp(TRACK_BAND_HZ[0], TRACK_BAND_HZ[TRACK_BAND_HZ.len() - 1])
+            .min(max_hz);
+        let alpha = weight * self.track_smooth_coeff;
+        self.center_hz += (target - self.center_hz) * alpha;
+    }
+
+    pub fn compute_gain(
+        &mut self,
+        l: f32,
+        r: f32,
+        amount: f32,
+        env_l: &VoiceEnvelope,
+        env_r: &VoiceEnvelope,
+    ) -> f32 {
+        let amount = amount.clamp(0.0, 1.0);
+        let x = l.abs().max(r.abs()) + INPUT_FLOOR;
+
+        let weight = self.analyze_sibilance_weight(x);
+        self.last_sibilance_weight = weight;
+        self.track_sibilance_center(0.5 * (l + r), weight);
+
+        // Use shared slow envelope (max of L/R) for level threshold
+        let level_env = env_l.slow.max(env_r.slow);
+        let lin_thr = (level_env * LEVEL_THRESH_SCALE).max(LEVEL_THRESH_MIN);
+        let thr_db = (lin_to_db(lin_thr) + self.threshold_offset_db).max(LEVEL_THRESH_DB_FLOOR);
+
+        let env_db = lin_to_db(self.sib_env.max(LEVEL_THRESH_MIN));
+        self.last_sib_rel_db = env_db - lin_to_db(level_env.max(LEVEL_THRESH_MIN));
+        let over_db = (env_db - thr_db).max(0.0);
+        self.last_over_db = over_db;
+
+        if amount < DE_ESSER_BYPASS_EPS {
+            let rel = (-1.0 / (GAIN_RELEASE_SEC * self.sample_rate)).exp();
+            self.gain_smooth = rel * self.gain_smooth + (1.0 - rel);
+            return self.gain_smooth;
+        }
+
+        let knee = smoothstep(0.0, 6.0, over_db);
+        let target_red = (knee * over_db * (DE_ESS_RATIO - 1.0)).min(MAX_REDUCTION_DB * amount);
+
+        let target_gain = db_to_gain(-target_red * weight).clamp(MIN_GAIN, 1.0);
+
+        let atk = (-1.0 / (GAIN_ATTACK_SEC * self.sample_rate)).exp();
+        let rel = (-1.0 / (GAIN_RELEASE_SEC * self.sample_rate)).exp();
+
+        self.gain_smooth = if target_gain < self.gain_smooth {
+            atk * self.gain_smooth + (1.0 - atk) * target_gain
+        } else {
+            rel * self.gain_smooth + (1.0 - rel) * target_gain
+        };
+
+        self.last_reduction_db = -lin_to_db(self.gain_smooth).max(-MAX_REDUCTION_DB);
+
+        self.gain_smooth
+    }
+
+    pub fn get_gain_reduction_db(&self) -> f32 {
+        self.last_reduction_db
+    }
+
+    /// Tracked sibilance center frequency in Hz
+    pub fn get_center_hz(&self) -> f32 {
+        self.center_hz
+    }
+
+    pub fn reset(&mut self) {
+        self.last_reduction_db = 0.0;
+    }
+
+    /// Seed the sibilance center (e.g. from a speaker profile); tracking
+    /// continues from this value
+    pub fn set_center_hz(&mut self, hz: f32) {
+        let max_hz = self.sample_rate * TRACK_MAX_NYQUIST_FRAC;
+        self.center_hz = hz
+            .clamp(TRACK_BAND_HZ[0], TRACK_BAND_HZ[TRACK_BAND_HZ.len() - 1])
+            .min(max_hz);
+    }
+
+    /// Stop (or resume) moving the tracked center; band energies keep
+    /// running so tracking resumes without a settling gap
+    pub fn set_tracking_frozen(&mut self, frozen: bool) {
+        self.tracking_frozen = frozen;
+    }
+
+    /// Shift the detection threshold (from the auto threshold calibration)
+    pub fn set_threshold_offset_db(&mut self, offset_db: f32) {
+        self.threshold_offset_db =
+            offset_db.clamp(-MAX_THRESHOLD_OFFSET_DB, MAX_THRESHOLD_OFFSET_DB);
+    }
+
+    /// Forget the learned sibilance center (new speaker)
+    pub fn reset_tracking(&mut self) {
+        for band in self.track_bands.iter_mut() {
+            band.reset_state();
+        }
+        self.track_env = [0.0; TRACK_BAND_HZ.len()];
+        self.center_hz = DE_ESS_BAND_HZ.min(self.sample_rate * TRACK_MAX_NYQUIST_FRAC);
+    }
+}
+
+// ---------------- Band ----------------
+
+pub struct DeEsserBand {
+    filter: Biquad,
+    last_cut_db: f32,
+    center_hz: f32,
+    sample_rate: f32,
+}
+
+impl DeEsserBand {
+    pub fn new(sr: f32) -> Self {
+        let mut filter = Biquad::new();
+        filter.update_peaking(DE_ESS_BAND_HZ, DE_ESS_BAND_Q, 0.0, sr);
+        Self {
+            filter,
+            last_cut_db: 0.0,
+            center_hz: DE_ESS_BAND_HZ,
+            sample_rate: sr,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.filter.reset();
+    }
+
+    /// Re-center the cut band (from `DeEsserDetector::get_center_hz`)
+    pub fn set_center_hz(&mut self, hz: f32) {
+        if (hz - self.center_hz).abs() > BAND_RETUNE_HZ {
+            self.center_hz = hz;
+            self.filter.update_peaking(
+                self.center_hz,
+                DE_ESS_BAND_Q,
+                self.last_cut_db,
+                self.sample_rate,
+            );
+        }
+    }
+
+    pub fn update(&mut self, gain: f32) {
+        let cut_db = lin_to_db(gain).max(-MAX_REDUCTION_DB);
+        if (cut_db - self.last_cut_db).abs() > 0.1 {
+            self.filter
+                .update_peaking(self.center_hz, DE_ESS_BAND_Q, cut_db, self.sample_rate);
+            self.last_cut_db = cut_db;
+        }
+    }
+
+    pub fn process(&mut self, x: f32) -> f32 {
+        self.filter.process(x)
+    }
+
+    pub fn apply(&mut self, sample: f32, gain: f32) -> f32 {
+        self.update(gain);
+        self.process(sample)
+    }
+}
+
+// ---------------- Auto Threshold ----------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeEssCalibration {
+    /// `offset_db` for `set_threshold_offset_db`; `p95_rel_db` is the 95th
+    /// percentile sibilance peak relative to the voice
+    Done {
+        offset_db: f32,
+        p95_rel_db: f32,
+        events: u32,
+    },
+    /// Too few sibilant events in the analysed program
+    NotEnough,
+}
+
+/// Collects sibilant event peaks for the auto threshold
+pub struct DeEssCalibrator {
+    hist: [u32; HIST_BINS],
+    events: u32,
+    in_event: bool,
+    event_peak_db: f32,
+    treat_share: f32,
+
+    measuring: bool,
+    samples_left: u32,
+    calibration_samples: u32,
+    outcome: Option<DeEssCalibration>,
+}
+
+impl DeEssCalibrator {
+    pub fn new(sr: f32) -> Self {
+        Self {
+            hist: [0; HIST_BINS],
+            events: 0,
+            in_event: false,
+            event_peak_db: HIST_MIN_DB,
+            treat_share: 0.25,
+            measuring: false,
+            samples_left: 0,
+            calibration_samples: (CALIBRATION_SEC * sr) as u32,
+            outcome: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.measuring = false;
+        self.outcome = None;
+    }
+
+    /// Begin a calibration that will treat `treat_share` (0..1) of the
+    /// sibilant events it sees
+    pub fn start(&mut self, treat_share: f32) {
+        self.hist = [0; HIST_BINS];
+        self.events = 0;
+        self.in_event = false;
+        self.event_peak_db = HIST_MIN_DB;
+        self.treat_share = treat_share.clamp(0.01, 0.99);
+        self.samples_left = self.calibration_samples;
+        self.measuring = true;
+        self.outcome = None;
+    }
+
+    pub fn is_measuring(&self) -> bool {
+        self.measuring
+    }
+
+    /// Result of a finished calibration (returned once)
+    pub fn take_outcome(&mut self) -> Option<DeEssCalibration> {
+        self.outcome.take()
+    }
+
+    /// Feed the detector's `last_sib_rel_db` and `last_sibilance_weight`
+    #[inline]
+    pub fn process(&mut self, sib_rel_db: f32, weight: f32) {
+        if !self.measuring {
+            return;
+        }
+
+        if self.in_event {
+            self.event_peak_db = self.event_peak_db.max(sib_rel_db);
+            if weight < EVENT_END_WEIGHT {
+                self.in_event = false;
+                self.record(self.event_peak_db);
+            }
+        } else if weight > EVENT_START_WEIGHT {
+            self.in_event = true;
+            self.event_peak_db = sib_rel_db;
+        }
+
+        self.samples_left = self.samples_left.saturating_sub(1);
+        if self.samples_left == 0 {
+            self.measuring = false;
+            self.outcome = Some(self.finish());
+        }
+    }
+
+    fn record(&mut self, peak_db: f32) {
+        let bin = ((peak_db - HIST_MIN_DB) / HIST_BIN_DB).clamp(0.0, (HIST_BINS - 1) as f32);
+        self.hist[bin as usize] += 1;
+        self.events += 1;
+    }
+
+    /// Level (bin centre) below which `q` of the events fall
+    fn quantile_db(&self, q: f32) -> f32 {
+        let target = (q * self.events as f32).ceil().max(1.0) as u32;
+        let mut seen = 0;
+        for (i, &n) in self.hist.iter().enumerate() {
+            seen += n;
+            if seen >= target {
+                return HIST_MIN_DB + (i as f32 + 0.5) * HIST_BIN_DB;
+            }
+        }
+        HIST_MIN_DB + HIST_BINS as f32 * HIST_BIN_DB
+    }
+
+    fn finish(&self) -> DeEssCalibration {
+        if self.events < MIN_CALIBRATION_EVENTS {
+            return DeEssCalibration::NotEnough;
+        }
+        let threshold_rel_db = self.quantile_db(1.0 - self.treat_share);
+        let builtin_rel_db = lin_to_db(LEVEL_THRESH_SCALE);
+        DeEssCalibration::Done {
+            offset_db: (threshold_rel_db - builtin_rel_db)
+                .clamp(-MAX_THRESHOLD_OFFSET_DB, MAX_THRESHOLD_OFFSET_DB),
+            p95_rel_db: self.quantile_db(0.95),
+            events: self.events,
+        }
+    }
+}
+
+// ---------------- Event Counter ----------------
+
+/// Counts de-ess events (reduction rising over 1 dB and falling back under
+/// 0.5 dB) and their peak depth. The rate is per second of speech, so long
+/// pauses do not dilute it.
+pub struct DeEssEventCounter {
+    events: u32,
+    depth_sum_db: f32,
+    in_event: bool,
+    event_peak_db: f32,
+    speech_samples: u64,
+    sample_rate: f32,
+}
+
+impl DeEssEventCounter {
+    pub fn new(sr: f32) -> Self {
+        Self {
+            events: 0,
+            depth_sum_db: 0.0,
+            in_event: false,
+            event_peak_db: 0.0,
+            speech_samples: 0,
+            sample_rate: sr.max(1.0),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.events = 0;
+        self.depth_sum_db = 0.0;
+        self.in_event = false;
+        self.event_peak_db = 0.0;
+        self.speech_samples = 0;
+    }
+
+    /// Feed the detector's gain reduction (dB, positive) and the sidechain
+    /// speech confidence
+    #[inline]
+    pub fn process(&mut self, reduction_db: f32, speech_conf: f32) {
+        if speech_conf > COUNT_SPEECH_CONF {
+            self.speech_samples += 1;
+        }
+
+        if self.in_event {
+            self.event_peak_db = self.event_peak_db.max(reduction_db);
+            if reduction_db < COUNT_END_DB {
+                self.in_event = false;
+                self.events = self.events.saturating_add(1);
+                self.depth_sum_db += self.event_peak_db;
+            }
+        } else if reduction_db > COUNT_START_DB {
+            self.in_event = true;
+            self.event_peak_db = reduction_db;
+        }
+    }
+
+    /// Finished events since the last reset
+    pub fn events(&self) -> u32 {
+        self.events
+    }
+
+    /// Events per second of speech
+    pub fn rate_per_sec(&self) -> f32 {
+        let speech_sec = self.speech_samples as f32 / self.sample_rate;
+        if speech_sec < 1.0 {
+            return 0.0;
+        }
+        self.events as f32 / speech_sec
+    }
+
+    /// Mean peak reduction of the events (dB, 0 without events)
+    pub fn average_depth_db(&self) -> f32 {
+        if self.events == 0 {
+            return 0.0;
+        }
+        self.depth_sum_db / self.events as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 1000.0;
+
+    /// One sibilant event every 40 samples, peaks spread over `lo..hi` dB
+    fn calibrate(treat: f32, lo: f32, hi: f32) -> Option<DeEssCalibration> {
+        let mut cal = DeEssCalibrator::new(SR);
+        cal.start(treat);
+        let samples = (CALIBRATION_SEC * SR) as usize;
+        let events = samples / 40;
+        for i in 0..samples {
+            let k = i / 40;
+            let level = lo + (hi - lo) * k as f32 / events as f32;
+            let weight = if i % 40 < 10 { 1.0 } else { 0.0 };
+            cal.process(if weight > 0.0 { level } else { -50.0 }, weight);
+        }
+        assert!(!cal.is_measuring());
+        cal.take_outcome()
+    }
+
+    #[test]
+    fn test_threshold_treats_requested_share() {
+        let base = lin_to_db(LEVEL_THRESH_SCALE);
+        let Some(DeEssCalibration::Done {
+            offset_db,
+            p95_rel_db,
+            events,
+        }) = calibrate(0.25, -30.0, -10.0)
+        else {
+            panic!("calibration failed");
+        };
+        assert!(events > 200);
+        // Top quarter of -30..-10 starts at -15 dB
+        assert!((offset_db + base + 15.0).abs() < 0.6, "offset {offset_db}");
+        assert!((p95_rel_db + 11.0).abs() < 0.6, "p95 {p95_rel_db}");
+
+        let Some(DeEssCalibration::Done {
+            offset_db: wide, ..
+        }) = calibrate(0.75, -30.0, -10.0)
+        else {
+            panic!("calibration failed");
+        };
+        assert!(wide < offset_db);
+    }
+
+    #[test]
+    fn test_no_sibilance_reports_not_enough() {
+        let mut cal = DeEssCalibrator::new(SR);
+        cal.start(0.25);
+        for _ in 0..(CALIBRATION_SEC * SR) as usize {
+            cal.process(-40.0, 0.0);
+        }
+        assert_eq!(cal.take_outcome(), Some(DeEssCalibration::NotEnough));
+        assert_eq!(cal.take_outcome(), None);
+    }
+
+    #[test]
+    fn test_event_counter_rate_and_depth() {
+        let mut counter = DeEssEventCounter::new(SR);
+        // 10 s of speech, one 50 ms event every 500 ms alternating 3 and 5 dB
+        for i in 0..(10.0 * SR) as usize {
+            let k = i / 500;
+            let depth = if k % 2 == 0 { 3.0 } else { 5.0 };
+            let reduction = if i % 500 < 50 { depth } else { 0.0 };
+            counter.process(reduction, 1.0);
+        }
+        // 10 s of pause without sibilance
+        for _ in 0..(10.0 * SR) as usize {
+            counter.process(0.0, 0.0);
+        }
+        assert_eq!(counter.events(), 20);
+        assert!((counter.rate_per_sec() - 2.0).abs() < 1e-3);
+        assert!((counter.average_depth_db() - 4.0).abs() < 1e-3);
+
+        // Shallow reduction is not an event
+        counter.reset();
+        for _ in 0..(2.0 * SR) as usize {
+            counter.process(0.8, 1.0);
+        }
+        assert_eq!(counter.events(), 0);
+        assert_eq!(counter.average_depth_db(), 0.0);
+    }
+}
@@ -0,0 +1,284 @@
+//! De-Harsh (upper-mid glare control)
+//!
+//! Dynamic cut of the 2.5–5 kHz band when it stands out against the rest of
+//! the voice, the "glare" of budget USB condensers.
+//!
+//! # Purpose
+//! Cheap capsules add a broad presence peak that makes every vowel sound
+//! pinched. A static EQ cut dulls the voice everywhere; this only pulls the
+//! band down while it is actually louder than a natural tilt allows.
+//!
+//! # Design Notes
+//! - Stereo-linked detector on the mid signal: energy in the glare octave
+//!   (2.5–5 kHz) against a 500 Hz–2 kHz reference band
+//! - A pink (-3 dB/oct) spectrum has equal energy per octave, so the glare
+//!   band is compared with the reference's per-octave energy; speech
+//!   normally sits well below that line up here
+//! - Excess over the threshold is compressed 3:1 into a cut on a broad bell
+//!   at 3.5 kHz, capped and scaled by the single amount control
+//! - Only acts during speech, so pauses and room tone are left alone
+//! - Runs ahead of Clarity; the host lowers the presence lift by the current
+//!   cut so the two never pull the same band in opposite directions
+//! - `process_keyed()` detects on a tap from elsewhere in the chain, so the
+//!   host can move the detection point without moving the bell
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use crate::dsp::utils::{time_constant_coeff, update_env_sq, BYPASS_AMOUNT_EPS, DB_EPS};
+use crate::dsp::Biquad;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Glare band (one octave)
+const HARSH_LO_HZ: f32 = 2500.0;
+const HARSH_HI_HZ: f32 = 5000.0;
+
+/// Reference band (two octaves)
+const REF_LO_HZ: f32 = 500.0;
+const REF_HI_HZ: f32 = 2000.0;
+const REF_OCTAVES: f32 = 2.0;
+
+/// Detector band filter Q
+const BAND_Q: f32 = 0.707;
+
+/// Glare level over the pink line (dB) where reduction starts
+const THRESHOLD_DB: f32 = -4.0;
+
+/// Compression ratio above the threshold
+const RATIO: f32 = 3.0;
+
+/// Deepest cut at full amount (dB)
+const MAX_CUT_DB: f32 = 8.0;
+
+/// Cut bell center and Q (about 1.4 octaves wide)
+const BELL_HZ: f32 = 3500.0;
+const BELL_Q: f32 = 1.0;
+
+/// Detector envelope ballistics
+const ENV_ATTACK_MS: f32 = 10.0;
+const ENV_RELEASE_MS: f32 = 60.0;
+
+/// Cut ballistics
+const CUT_ATTACK_MS: f32 = 5.0;
+const CUT_RELEASE_MS: f32 = 80.0;
+
+/// Reference energy below which the detector holds (silence)
+const MIN_REF_ENERGY: f32 = 1e-8;
+
+/// Cut change (dB) that triggers a coefficient update
+const COEFF_UPDATE_DB: f32 = 0.05;
+
+// =============================================================================
+// Processor
+// =============================================================================
+
+pub struct DeHarsh {
+    harsh_hp: Biquad,
+    harsh_lp: Biquad,
+    ref_hp: Biquad,
+    ref_lp: Biquad,
+    harsh_env: f32,
+    ref_env: f32,
+
+    cut_db: f32,
+    last_cut_db: f32,
+    bell_l: Biquad,
+    bell_r: Biquad,
+
+    env_attack: f32,
+    env_release: f32,
+    cut_attack: f32,
+    cut_release: f32,
+    sample_rate: f32,
+}
+
+impl DeHarsh {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut harsh_hp = Biquad::new();
+        harsh_hp.update_hpf(HARSH_LO_HZ, BAND_Q, sample_rate);
+        let mut harsh_lp = Biquad::new();
+        harsh_lp.update_lpf(HARSH_HI_HZ, BAND_Q, sample_rate);
+        let mut ref_hp = Biquad::new();
+        ref_hp.update_hpf(REF_LO_HZ, BAND_Q, sample_rate);
+        let mut ref_lp = Biquad::new();
+        ref_lp.update_lpf(REF_HI_HZ, BAND_Q, sample_rate);
+
+        let mut bell_l = Biquad::new();
+        bell_l.update_peaking(BELL_HZ, BELL_Q, 0.0, sample_rate);
+        let mut bell_r = Biquad::new();
+        bell_r.update_peaking(BELL_HZ, BELL_Q, 0.0, sample_rate);
+
+        Self {
+            harsh_hp,
+            harsh_lp,
+            ref_hp,
+            ref_lp,
+            harsh_env: 0.0,
+            ref_env: 0.0,
+            cut_db: 0.0,
+            last_cut_db: 0.0,
+            bell_l,
+            bell_r,
+            env_attack: time_constant_coeff(ENV_ATTACK_MS, sample_rate),
+            env_release: time_constant_coeff(ENV_RELEASE_MS, sample_rate),
+            cut_attack: time_constant_coeff(CUT_ATTACK_MS, sample_rate),
+            cut_release: time_constant_coeff(CUT_RELEASE_MS, sample_rate),
+            sample_rate,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.harsh_hp.reset_state();
+        self.harsh_lp.reset_state();
+        self.ref_hp.reset_state();
+        self.ref_lp.reset_state();
+        self.harsh_env = 0.0;
+        self.ref_env = 0.0;
+        self.cut_db = 0.0;
+        self.last_cut_db = 0.0;
+        self.bell_l
+            .update_peaking(BELL_HZ, BELL_Q, 0.0, self.sample_rate);
+        self.bell_r
+            .update_peaking(BELL_HZ, BELL_Q, 0.0, self.sample_rate);
+        self.bell_l.reset_state();
+        self.bell_r.reset_state();
+    }
+
+    /// Current reduction in dB (positive)
+    pub fn cut_db(&self) -> f32 {
+        self.cut_db
+    }
+
+    /// amount: user control (0..1)
+    /// speech_confidence: sidechain speech confidence (0..1)
+    #[inline]
+    pub fn process(
+        &mut self,
+        left: f32,
+        right: f32,
+        amount: f32,
+        speech_confidence: f32,
+    ) -> (f32, f32) {
+        self.process_keyed(left, right, 0.5 * (left + right), amount, speech_confidence)
+    }
+
+    /// As `process()`, detecting on `key` (a mid signal) instead of the
+    /// input, e.g. a tap from another point in the chain
+    #[inline]
+    pub fn process_keyed(
+        &mut self,
+        left: f32,
+        right: f32,
+        key: f32,
+        amount: f32,
+        speech_confidence: f32,
+    ) -> (f32, f32) {
+        if amount <= BYPASS_AMOUNT_EPS && self.last_cut_db == 0.0 {
+            return (left, right);
+        }
+
+        // Linked detection on the mid signal
+        let mid = key;
+        let harsh = self.harsh_lp.process(self.harsh_hp.process(mid));
+        let reference = self.ref_lp.process(self.ref_hp.process(mid));
+        self.harsh_env = update_env_sq(
+            self.harsh_env,
+            harsh * harsh,
+            self.env_attack,
+            self.env_release,
+        );
+        self.ref_env = update_env_sq(
+            self.ref_env,
+            reference * reference,
+            self.env_attack,
+            self.env_release,
+        );
+
+        // Glare over the pink line: equal energy per octave
+        let target_db = if self.ref_env > MIN_REF_ENERGY {
+            let pink = self.ref_env / REF_OCTAVES;
+            let over_db = 10.0 * ((self.harsh_env + DB_EPS) / pink).log10() - THRESHOLD_DB;
+            let cut = (over_db * (1.0 - 1.0 / RATIO)).clamp(0.0, MAX_CUT_DB);
+            cut * amount.clamp(0.0, 1.0) * speech_confidence.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let coeff = if target_db > self.cut_db {
+            self.cut_attack
+        } else {
+            self.cut_release
+        };
+        self.cut_db = target_db + coeff * (self.cut_db - target_db);
+        if self.cut_db < COEFF_UPDATE_DB && target_db == 0.0 {
+            self.cut_db = 0.0;
+        }
+
+        if (self.cut_db - self.last_cut_db).abs() > COEFF_UPDATE_DB
+            || (self.cut_db == 0.0 && self.last_cut_db != 0.0)
+        {
+            self.bell_l
+                .update_peaking(BELL_HZ, BELL_Q, -self.cut_db, self.sample_rate);
+            self.bell_r
+                .update_peaking(BELL_HZ, BELL_Q, -self.cut_db, self.sample_rate);
+            self.last_cut_db = self.cut_db;
+        }
+
+        (self.bell_l.process(left), self.bell_r.process(right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::TAU;
+
+    const SR: f32 = 48000.0;
+
+    /// Settled cut for a 1 kHz voice tone plus a 3.5 kHz glare tone
+    fn settled_cut(glare: f32, amount: f32, speech_confidence: f32) -> f32 {
+        let mut d = DeHarsh::new(SR);
+        for i in 0..SR as usize / 2 {
+            let t = i as f32 / SR;
+            let x = (TAU * 1000.0 * t).sin() + glare * (TAU * 3500.0 * t).sin();
+            d.process(x, x, amount, speech_confidence);
+        }
+        d.cut_db()
+    }
+
+    #[test]
+    fn test_cuts_glare_only() {
+        assert!(settled_cut(1.0, 1.0, 1.0) > 2.0);
+        assert_eq!(settled_cut(0.2, 1.0, 1.0), 0.0);
+        assert!(settled_cut(4.0, 1.0, 1.0) <= MAX_CUT_DB);
+    }
+
+    #[test]
+    fn test_amount_and_speech_scale_the_cut() {
+        let full = settled_cut(1.0, 1.0, 1.0);
+        let half = settled_cut(1.0, 0.5, 1.0);
+        assert!((half - 0.5 * full).abs() < 0.2);
+        assert_eq!(settled_cut(1.0, 1.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_keyed_detection_follows_the_key() {
+        let mut d = DeHarsh::new(SR);
+        for i in 0..SR as usize / 2 {
+            let t = i as f32 / SR;
+            let voice = (TAU * 1000.0 * t).sin();
+            let x = voice + (TAU * 3500.0 * t).sin();
+            d.process_keyed(x, x, voice, 1.0, 1.0);
+        }
+        assert_eq!(d.cut_db(), 0.0);
+    }
+
+    #[test]
+    fn test_off_passes_through() {
+        let mut d = DeHarsh::new(SR);
+        assert_eq!(d.process(0.5, -0.25, 0.0, 1.0), (0.5, -0.25));
+    }
+}
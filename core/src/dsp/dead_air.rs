@@ -0,0 +1,245 @@
+//! Dead Air Detection and Long-Pause Trim
+//!
+//! Tracks pauses longer than a threshold and keeps a running dead-air total;
+//! optionally eases very long pauses down to a target room-tone level.
+//!
+//! # Purpose
+//! Editors cutting podcasts and audiobooks want to know how much dead air a
+//! take holds before they start trimming. Audiobook delivery specs also ask
+//! for a consistent room-tone level in long pauses, which this stage can
+//! approach gently instead of gating.
+//!
+//! # Design Notes
+//! - Pauses come from the speech confidence sidechain with hysteresis
+//!   (enter below 0.3, leave above 0.5), so a single low frame inside a
+//!   word does not split it
+//! - A pause counts as dead air once it passes the threshold; from then on
+//!   its whole length is added to the total
+//! - Trim only acts inside a long pause: the gain glides down over about a
+//!   second towards `target / room tone level` (never below -30 dB) and
+//!   snaps back within milliseconds when speech confidence rises or the
+//!   level jumps 6 dB over the pause average, so word onsets are not eaten
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use super::utils::{db_to_gain, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Speech confidence hysteresis for pause detection
+const PAUSE_ENTER: f32 = 0.3;
+const PAUSE_LEAVE: f32 = 0.5;
+
+/// Room tone level tracking during a pause (mean square)
+const LEVEL_SLOW_MS: f32 = 300.0;
+/// Onset detector (mean square)
+const LEVEL_FAST_MS: f32 = 5.0;
+/// Fast over slow power ratio treated as the start of a word (+6 dB)
+const ONSET_POWER_RATIO: f32 = 4.0;
+
+/// Deepest trim applied to a long pause
+const MAX_TRIM_DB: f32 = 30.0;
+
+/// Trim ballistics: glide down slowly, recover fast
+const TRIM_ATTACK_MS: f32 = 1000.0;
+const TRIM_RELEASE_MS: f32 = 10.0;
+
+// =============================================================================
+// Detector
+// =============================================================================
+
+pub struct DeadAirDetector {
+    in_pause: bool,
+    /// Current pause length in samples
+    pause_samples: u64,
+    /// Current pause has passed the threshold
+    long_pause: bool,
+    dead_air_samples: u64,
+    long_pauses: u32,
+
+    slow_power: f32,
+    fast_power: f32,
+    /// Applied trim (dB, <= 0)
+    trim_db: f32,
+
+    sample_rate: f32,
+    slow_coeff: f32,
+    fast_coeff: f32,
+    trim_atk: f32,
+    trim_rel: f32,
+}
+
+impl DeadAirDetector {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            in_pause: false,
+            pause_samples: 0,
+            long_pause: false,
+            dead_air_samples: 0,
+            long_pauses: 0,
+
+            slow_power: 0.0,
+            fast_power: 0.0,
+            trim_db: 0.0,
+
+            sample_rate,
+            slow_coeff: time_constant_coeff(LEVEL_SLOW_MS, sample_rate),
+            fast_coeff: time_constant_coeff(LEVEL_FAST_MS, sample_rate),
+            trim_atk: time_constant_coeff(TRIM_ATTACK_MS, sample_rate),
+            trim_rel: time_constant_coeff(TRIM_RELEASE_MS, sample_rate),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.in_pause = false;
+        self.pause_samples = 0;
+        self.long_pause = false;
+        self.dead_air_samples = 0;
+        self.long_pauses = 0;
+        self.slow_power = 0.0;
+        self.fast_power = 0.0;
+        self.trim_db = 0.0;
+    }
+
+    /// Process one stereo sample.
+    ///
+    /// Pauses longer than `min_pause_sec` count as dead air. With `trim` on,
+    /// long pauses are eased towards `target_db` (dBFS RMS).
+    #[inline]
+    pub fn process(
+        &mut self,
+        left: f32,
+        right: f32,
+        speech_conf: f32,
+        min_pause_sec: f32,
+        trim: bool,
+        target_db: f32,
+    ) -> (f32, f32) {
+        // Pause tracking
+        if self.in_pause {
+            if speech_conf > PAUSE_LEAVE {
+                self.in_pause = false;
+                self.pause_samples = 0;
+                self.long_pause = false;
+            }
+        } else if speech_conf < PAUSE_ENTER {
+            self.in_pause = true;
+        }
+
+        if self.in_pause {
+            self.pause_samples += 1;
+            if self.long_pause {
+                self.dead_air_samples += 1;
+            } else if self.pause_samples as f32 >= min_pause_sec * self.sample_rate {
+                self.long_pause = true;
+                self.long_pauses = self.long_pauses.saturating_add(1);
+                self.dead_air_samples += self.pause_samples;
+            }
+        }
+
+        // Level tracking
+        let power = 0.5 * (left * left + right * right);
+        self.fast_power = self.fast_coeff * self.fast_power + (1.0 - self.fast_coeff) * power;
+        self.slow_power = self.slow_coeff * self.slow_power + (1.0 - self.slow_coeff) * power;
+        let onset = self.fast_power > ONSET_POWER_RATIO * self.slow_power;
+
+        // Trim
+        let target = if trim && self.long_pause && !onset && self.slow_power > 1e-12 {
+            let room_db = 10.0 * self.slow_power.log10();
+            (target_db - room_db).clamp(-MAX_TRIM_DB, 0.0)
+        } else {
+            0.0
+        };
+        let c = if target < self.trim_db {
+            self.trim_atk
+        } else {
+            self.trim_rel
+        };
+        self.trim_db = c * self.trim_db + (1.0 - c) * target;
+
+        if self.trim_db > -1e-3 {
+            return (left, right);
+        }
+        let g = db_to_gain(self.trim_db);
+        (left * g, right * g)
+    }
+
+    /// Total length of pauses over the threshold since the last reset
+    pub fn dead_air_sec(&self) -> f32 {
+        self.dead_air_samples as f32 / self.sample_rate
+    }
+
+    /// Number of pauses that passed the threshold
+    pub fn long_pauses(&self) -> u32 {
+        self.long_pauses
+    }
+
+    /// Trim currently applied (dB, 0 = none)
+    pub fn trim_db(&self) -> f32 {
+        self.trim_db
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    #[test]
+    fn test_counts_only_long_pauses() {
+        let mut d = DeadAirDetector::new(SR);
+        let run = |d: &mut DeadAirDetector, sec: f32, conf: f32| {
+            for _ in 0..(sec * SR) as usize {
+                d.process(0.001, 0.001, conf, 2.0, false, -60.0);
+            }
+        };
+        run(&mut d, 1.0, 0.9);
+        run(&mut d, 1.5, 0.0); // short pause: not dead air
+        run(&mut d, 1.0, 0.9);
+        run(&mut d, 3.0, 0.0); // long pause: counted in full
+        run(&mut d, 1.0, 0.4); // inside the hysteresis band: still a pause
+        run(&mut d, 1.0, 0.9);
+
+        assert_eq!(d.long_pauses(), 1);
+        assert!(
+            (d.dead_air_sec() - 4.0).abs() < 0.01,
+            "{}",
+            d.dead_air_sec()
+        );
+    }
+
+    #[test]
+    fn test_trim_towards_target_and_recover() {
+        let mut d = DeadAirDetector::new(SR);
+        // -40 dBFS room tone, target -52 dBFS
+        let tone = 0.01f32;
+        let mut out = 0.0;
+        for _ in 0..(6.0 * SR) as usize {
+            out = d.process(tone, tone, 0.0, 1.0, true, -52.0).0;
+        }
+        assert!((d.trim_db() + 12.0).abs() < 1.0, "{}", d.trim_db());
+        assert!(out < 0.5 * tone);
+
+        // Speech returns: trim gone within 100 ms
+        for _ in 0..(0.1 * SR) as usize {
+            out = d.process(0.3, 0.3, 0.9, 1.0, true, -52.0).0;
+        }
+        assert!((out - 0.3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_unity_without_trim() {
+        let mut d = DeadAirDetector::new(SR);
+        for _ in 0..(3.0 * SR) as usize {
+            assert_eq!(
+                d.process(0.01, -0.01, 0.0, 1.0, false, -70.0),
+                (0.01, -0.01)
+            );
+        }
+        assert!(d.dead_air_sec() > 2.9);
+    }
+}
@@ -0,0 +1,180 @@
+//! Denoiser orchestrator (DSP only)
+//!
+//! This module currently exists to keep the existing public API stable while
+//! routing all stereo denoising through the single traditional DSP implementation.
+//! It also meters the stereo width going in and coming out, so the effect of
+//! the stereo link on the image can be checked.
+
+use crate::dsp::dsp_denoiser::{DenoiseConfig as DspDenoiseConfig, DspDenoiser};
+use crate::dsp::utils::time_constant_coeff;
+
+/// Width integration time
+const WIDTH_AVERAGE_MS: f32 = 300.0;
+/// Below this mean energy the width reading is held
+const WIDTH_SILENCE_ENERGY: f32 = 1e-8;
+
+/// Stereo denoiser wrapper exposing the old API surface.
+pub struct StereoStreamingDenoiser {
+    dsp_denoiser: DspDenoiser,
+    width_in: WidthMeter,
+    width_out: WidthMeter,
+}
+
+impl StereoStreamingDenoiser {
+    pub fn new(win_size: usize, hop_size: usize, sample_rate: f32) -> Self {
+        Self {
+            dsp_denoiser: DspDenoiser::new(win_size, hop_size),
+            width_in: WidthMeter::new(sample_rate),
+            width_out: WidthMeter::new(sample_rate),
+        }
+    }
+
+    /// Placeholder for the previous DTLN model loading step.
+    pub fn prepare(&mut self, _sample_rate: f32) {}
+
+    pub fn process_sample(
+        &mut self,
+        input_l: f32,
+        input_r: f32,
+        cfg: &DspDenoiseConfig,
+    ) -> (f32, f32) {
+        self.width_in.process(input_l, input_r);
+        let (out_l, out_r) = self.dsp_denoiser.process_sample(input_l, input_r, cfg);
+        self.width_out.process(out_l, out_r);
+        (out_l, out_r)
+    }
+
+    pub fn reset(&mut self) {
+        self.dsp_denoiser.reset();
+        self.width_in.reset();
+        self.width_out.reset();
+    }
+
+    /// Stereo width of the input (see `WidthMeter::width`)
+    pub fn input_width(&self) -> f32 {
+        self.width_in.width()
+    }
+
+    /// Stereo width of the output (see `WidthMeter::width`)
+    pub fn output_width(&self) -> f32 {
+        self.width_out.width()
+    }
+
+    /// Delay in samples
+    pub fn latency(&self) -> usize {
+        self.dsp_denoiser.latency()
+    }
+
+    /// Returns the current average gain reduction applied by the denoiser.
+    pub fn get_current_reduction(&self) -> f32 {
+        self.dsp_denoiser.get_current_reduction()
+    }
+
+    pub fn get_artifact_level(&self) -> f32 {
+        self.dsp_denoiser.get_artifact_level()
+    }
+
+    pub fn get_artifact_backoff(&self) -> f32 {
+        self.dsp_denoiser.get_artifact_backoff()
+    }
+
+    /// Adaptive noise floor estimate per bin, in analysis-frame magnitude units.
+    pub fn noise_floor(&self) -> &[f32] {
+        self.dsp_denoiser.noise_floor()
+    }
+
+    pub fn coarse_noise_floor(&self) -> &[f32] {
+        self.dsp_denoiser.coarse_noise_floor()
+    }
+
+    /// The noise floor has converged and is worth saving with the project.
+    pub fn noise_floor_settled(&self) -> bool {
+        self.dsp_denoiser.noise_floor_settled()
+    }
+
+    /// Seed the noise floors from a saved, decimated copy.
+    pub fn restore_noise_floors(&mut self, fine: &[f32], coarse: &[f32]) {
+        self.dsp_denoiser.restore_noise_floors(fine, coarse);
+    }
+}
+
+pub use crate::dsp::dsp_denoiser::DenoiseConfig;
+
+/// Smoothed side share of the stereo energy, held through silence
+struct WidthMeter {
+    mid: f32,
+    side: f32,
+    width: f32,
+    avg_coeff: f32,
+}
+
+impl WidthMeter {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            mid: 0.0,
+            side: 0.0,
+            width: 0.0,
+            avg_coeff: time_constant_coeff(WIDTH_AVERAGE_MS, sample_rate),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.mid = 0.0;
+        self.side = 0.0;
+        self.width = 0.0;
+    }
+
+    #[inline]
+    fn process(&mut self, left: f32, right: f32) {
+        let a = self.avg_coeff;
+        let m = 0.5 * (left + right);
+        let s = 0.5 * (left - right);
+        self.mid = a * self.mid + (1.0 - a) * m * m;
+        self.side = a * self.side + (1.0 - a) * s * s;
+        let energy = self.mid + self.side;
+        if energy > WIDTH_SILENCE_ENERGY {
+            self.width = self.side / energy;
+        }
+    }
+
+    /// `E[S²] / (E[M²] + E[S²])`: 0 = mono, 0.5 = uncorrelated L/R,
+    /// 1 = side only
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_meter_reads_mono_and_uncorrelated() {
+        let sr = 48000.0;
+        let mut mono = WidthMeter::new(sr);
+        let mut wide = WidthMeter::new(sr);
+        let (mut seed_l, mut seed_r) = (7u32, 1234u32);
+        let noise = |seed: &mut u32| {
+            *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (*seed >> 8) as f32 / 16_777_216.0 - 0.5
+        };
+        for _ in 0..sr as usize {
+            let x = noise(&mut seed_l);
+            mono.process(x, x);
+            wide.process(x, noise(&mut seed_r));
+        }
+        assert!(mono.width() < 1e-6, "{}", mono.width());
+        assert!((wide.width() - 0.5).abs() < 0.1, "{}", wide.width());
+
+        // Held through silence
+        let held = wide.width();
+        for _ in 0..5 * sr as usize {
+            wide.process(0.0, 0.0);
+        }
+        assert!(
+            (wide.width() - held).abs() < 1e-3,
+            "{} {held}",
+            wide.width()
+        );
+    }
+}
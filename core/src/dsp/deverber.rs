@@ -17,6 +17,11 @@
 //! - Short-lag reflections (0-20ms) - owned by `EarlyReflectionSuppressor`
 //! - Desk/wall coloration - owned by `EarlyReflectionSuppressor`
 //! - Distinct flutter echoes - not modeled
+//! - Tail character (`set_tail_character()`): Tight suppresses late energy
+//!   across the band with a fast gain fall; Natural eases off in the lows
+//!   (HF tail still cut, as a smaller real room would decay), lets the gain
+//!   fall slowly and keeps a higher floor, so the tail is shortened rather
+//!   than gated
 //!
 //! ## Avoiding Double-Reaction
 //!
@@ -43,7 +48,7 @@
 //! - **Learning**: No specific learning phase (instant reaction).
 //! - **Active**: Normal operation.
 //! - **Holding**: Uses `Holding` state implicitly during silence to prevent release envelope drift.
-//! - **Bypassed**: Passes audio through.
+//! - **Bypassed**: Passes audio through, delayed by the same window latency.
 
 use crate::dsp::utils::{
     aggressive_tail, estimate_f0_autocorr, lerp, make_sqrt_hann_window, max3, smoothstep,
@@ -84,6 +89,13 @@ const RISE_GATE_EPS: f32 = 1e-6;
 const LATE_DECAY_LOW: f32 = 0.995;
 // Increasing max: slower decay at HF; decreasing: faster decay at HF.
 const LATE_DECAY_HIGH: f32 = 0.85;
+// Room decay the late decay curve is tuned for (ms).
+// Increasing: measured rooms read as smaller (faster decay); decreasing: as larger.
+const REFERENCE_RT60_MS: f32 = 400.0;
+// Bounds on the room-decay exponent applied to the late decay curve.
+// Widening: stronger adaptation to extreme rooms; narrowing: closer to fixed decay.
+const ROOM_DECAY_EXP_MIN: f32 = 0.25;
+const ROOM_DECAY_EXP_MAX: f32 = 4.0;
 // Late envelope attack (rise).
 // Increasing: slower rise; decreasing: faster rise.
 const LATE_RISE: f32 = 0.9995;
@@ -130,6 +142,16 @@ const HARMONIC_BW_MAX_HZ: f32 = 25.0;
 // Increasing min/max: more protection; decreasing: less protection.
 const HARMONIC_PROTECT_MIN: f32 = 0.55;
 const HARMONIC_PROTECT_MAX: f32 = 0.35;
+// Tail character at Natural (blended in from Tight).
+// Share of the late suppression eased off at DC (tapering to none at Nyquist).
+// Increasing: more LF tail kept; decreasing: flatter suppression.
+const NATURAL_LF_EASE: f32 = 0.6;
+// Gain fall per frame.
+// Increasing: faster fall (more gated); decreasing: smoother decay.
+const NATURAL_GAIN_RELEASE: f32 = 0.02;
+// Lowest gain floor.
+// Increasing: more tail left in pauses; decreasing: deeper suppression.
+const NATURAL_FLOOR_MIN: f32 = 0.15;
 // Masker peak search radius (bins).
 // Increasing: wider masking spread; decreasing: narrower spread.
 const MASKER_RADIUS_BINS: isize = 20;
@@ -232,8 +254,20 @@ impl StreamingDeverber {
         clarity_amount: f32,
         proximity_amount: f32,
     ) -> f32 {
+        let _ = self.input_producer.push(input);
+
+        // Off: the input still passes through the frame buffers so the
+        // latency does not change when the amount reaches zero
         if amount <= BYPASS_AMOUNT_EPS {
-            return input;
+            if self.input_consumer.len() >= self.win_size {
+                for v in self.input_consumer.iter().take(self.hop_size) {
+                    let _ = self.output_producer.push(*v);
+                }
+                self.overlap.fill(0.0);
+                self.ola_norm.fill(0.0);
+                self.input_consumer.discard(self.hop_size);
+            }
+            return self.output_consumer.pop().unwrap_or(0.0);
         }
 
         // Apply aggressive_tail curve to amount
@@ -244,8 +278,6 @@ impl StreamingDeverber {
             strength *= 0.75;
         }
 
-        let _ = self.input_producer.push(input);
-
         if self.input_consumer.len() >= self.win_size {
             // Read frame
             for (i, v) in self.input_consumer.iter().take(self.win_size).enumerate() {
@@ -337,6 +369,25 @@ impl StreamingDeverber {
         self.output_consumer.pop().unwrap_or(0.0)
     }
 
+    /// Delay in samples, the same whether the stage is on or off
+    pub fn latency(&self) -> usize {
+        self.win_size
+    }
+
+    /// Adapt the late-tail decay to the measured room decay time (RT60, ms).
+    ///
+    /// Longer rooms get a slower late envelope so the tail estimate follows the
+    /// real reverb, keeping the same De-Verb setting consistent across rooms.
+    pub fn set_room_decay_ms(&mut self, rt60_ms: f32) {
+        self.detector.set_room_decay_ms(rt60_ms);
+    }
+
+    /// How the late tail is treated: 0 = Tight (suppress), 1 = Natural
+    /// (shape: LF eased, slow gain fall, higher floor).
+    pub fn set_tail_character(&mut self, character: f32) {
+        self.detector.set_tail_character(character);
+    }
+
     pub fn reset(&mut self) {
         self.detector.reset();
         self.overlap.fill(0.0);
@@ -374,6 +425,11 @@ pub struct StereoDeverberDetector {
 
     // Pre-allocated buffer for F0 autocorrelation (avoids audio-thread allocation)
     f0_scratch: Vec<f32>,
+
+    // Exponent applied to the late decay coefficients (1.0 = reference room)
+    decay_exponent: f32,
+    // 0 = Tight, 1 = Natural
+    tail_character: f32,
 }
 
 impl StereoDeverberDetector {
@@ -403,6 +459,8 @@ impl StereoDeverberDetector {
             frame_time: vec![0.0; win_size],
             gain_smooth: vec![1.0; nyq + 1],
             f0_scratch: vec![0.0; win_size], // Changed from Vec::with_capacity to pre-allocated vector
+            decay_exponent: 1.0,
+            tail_character: 0.0,
         }
     }
 
@@ -501,6 +559,11 @@ impl StereoDeverberDetector {
             0.04 // During silence: more aggressive
         };
 
+        // Tail character: Natural raises the floor and slows the gain fall
+        let character = self.tail_character;
+        let floor_clamp_min = lerp(floor_clamp_min, NATURAL_FLOOR_MIN, character);
+        let gain_release = lerp(GAIN_SMOOTH_RELEASE, NATURAL_GAIN_RELEASE, character);
+
         let mut gain_sum: f32 = 0.0;
         let mut min_gain: f32 = 1.0;
         for i in 0..=nyq {
@@ -510,8 +573,8 @@ impl StereoDeverberDetector {
             let freq = i as f32 * bin_width;
             let frac = (freq / (sr * NYQUIST_FRAC)).clamp(0.0, 1.0);
 
-            // Base decay
-            let mut decay = lerp(LATE_DECAY_LOW, LATE_DECAY_HIGH, frac);
+            // Base decay, scaled to the measured room
+            let mut decay = lerp(LATE_DECAY_LOW, LATE_DECAY_HIGH, frac).powf(self.decay_exponent);
 
             // Inter-module clamp: reduce HF decay by 20% if proximity > 0.6
             // This means decay coefficient increases (slower decay = less deverb)
@@ -537,7 +600,9 @@ impl StereoDeverberDetector {
             self.late_env[i] = late;
             self.prev_mag[i] = mag;
 
-            let direct = (mag - late_k * late).max(mag * DIRECT_FLOOR_SCALE);
+            // Spectral decay tilt: Natural keeps more of the LF tail
+            let tilt = 1.0 - character * NATURAL_LF_EASE * (1.0 - frac);
+            let direct = (mag - late_k * tilt * late).max(mag * DIRECT_FLOOR_SCALE);
             let mut gain = (direct / mag).clamp(0.0, 1.0);
 
             let floor = max3(
@@ -565,7 +630,7 @@ impl StereoDeverberDetector {
             self.gain_smooth[i] = if gain > prev_g {
                 prev_g + (gain - prev_g) * GAIN_SMOOTH_ATTACK
             } else {
-                prev_g + (gain - prev_g) * GAIN_SMOOTH_RELEASE
+                prev_g + (gain - prev_g) * gain_release
             };
 
             self.gain_mask[i] = self.gain_smooth[i];
@@ -636,6 +701,17 @@ impl StereoDeverberDetector {
         gain.max(lerp(gain, protect, near))
     }
 
+    pub fn set_room_decay_ms(&mut self, rt60_ms: f32) {
+        if rt60_ms.is_finite() && rt60_ms > 0.0 {
+            self.decay_exponent =
+                (REFERENCE_RT60_MS / rt60_ms).clamp(ROOM_DECAY_EXP_MIN, ROOM_DECAY_EXP_MAX);
+        }
+    }
+
+    pub fn set_tail_character(&mut self, character: f32) {
+        self.tail_character = character.clamp(0.0, 1.0);
+    }
+
     pub fn reset(&mut self) {
         self.mag.fill(0.0);
         self.prev_mag.fill(0.0);
@@ -646,3 +722,68 @@ impl StereoDeverberDetector {
         self.gain_smooth.fill(1.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    #[test]
+    fn test_latency_holds_when_off() {
+        let win = 1024;
+        let mut d = StreamingDeverber::new(win, win / 4);
+        let mut out = Vec::new();
+        for i in 0..3 * win {
+            let x = if i == 0 { 1.0 } else { 0.0 };
+            out.push(d.process_sample(x, 0.0, SR, 1.0, 0.0, 0.0));
+        }
+        assert_eq!(out[d.latency()], 1.0);
+        assert!(out
+            .iter()
+            .enumerate()
+            .all(|(i, &v)| i == d.latency() || v == 0.0));
+    }
+
+    /// Output energy in the decays of the last of four noise bursts, each
+    /// 100 ms followed by 300 ms of exponentially decaying noise, at
+    /// `amount` de-verb
+    fn tail_energy(character: f32, amount: f32) -> f32 {
+        let win = 1024;
+        let mut d = StreamingDeverber::new(win, win / 4);
+        d.set_tail_character(character);
+        let burst = (0.1 * SR) as usize;
+        let cycle = (0.4 * SR) as usize;
+        let mut seed = 7u32;
+        let mut energy = 0.0;
+        for i in 0..4 * cycle + win {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (seed >> 8) as f32 / 16_777_216.0 - 0.5;
+            let t = i % cycle;
+            let level = if t < burst {
+                1.0
+            } else {
+                // About 60 dB down after 300 ms
+                (-6.9 * (t - burst) as f32 / (0.3 * SR)).exp()
+            };
+            let y = d.process_sample(noise * level, amount, SR, 0.0, 0.0, 0.0);
+            // Output is one window late
+            let out_t = (i + 4 * cycle - win) % cycle;
+            if i >= 3 * cycle + win && out_t >= burst {
+                energy += y * y;
+            }
+        }
+        energy
+    }
+
+    #[test]
+    fn test_natural_character_leaves_more_tail() {
+        // Tail energy each character removes (the late envelope is still
+        // building up this early, so the absolute amounts are small)
+        let untouched = tail_energy(0.0, 0.0);
+        let tight = untouched - tail_energy(0.0, 1.0);
+        let natural = untouched - tail_energy(1.0, 1.0);
+        assert!(tight > 0.0, "{tight}");
+        assert!(natural < 0.7 * tight, "{tight} {natural}");
+    }
+}
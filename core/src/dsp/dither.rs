@@ -0,0 +1,122 @@
+//! Output Dither (TPDF + Noise Shaping)
+//!
+//! Requantizes the final output to a fixed-point word length with triangular
+//! (TPDF) dither, so VxCleaner can be the last insert before a 16-bit or
+//! 24-bit bounce without a separate dither plugin.
+//!
+//! # Design Notes
+//! - TPDF dither of ±1 LSB decorrelates the quantization error from the signal
+//! - 16-bit adds 3-tap error-feedback shaping (E-weighted), moving the noise
+//!   floor away from the 2-5 kHz region where hearing is most sensitive
+//! - 24-bit uses plain TPDF: the floor is far below audibility already
+//! - Independent random sequences per channel
+//!
+//! ## Audio Thread Safety
+//! - No allocations; state is a few floats per channel
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Error-feedback filter for 16-bit shaping (noise transfer 1 - H(z))
+const SHAPING_16: [f32; 3] = [1.623, -0.982, 0.109];
+
+// =============================================================================
+// Per-channel ditherer
+// =============================================================================
+
+pub struct Dither {
+    err: [f32; 3],
+    rng: u32,
+}
+
+impl Dither {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            err: [0.0; 3],
+            rng: seed.max(1),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.err = [0.0; 3];
+    }
+
+    /// Quantize `x` (full scale ±1.0) to `bits`, returning the dithered sample
+    #[inline]
+    pub fn process(&mut self, x: f32, bits: u32) -> f32 {
+        let levels = (1u32 << (bits.clamp(8, 24) - 1)) as f32;
+        let lsb = 1.0 / levels;
+
+        let shaped = if bits <= 16 {
+            x - (SHAPING_16[0] * self.err[0]
+                + SHAPING_16[1] * self.err[1]
+                + SHAPING_16[2] * self.err[2])
+        } else {
+            x
+        };
+
+        let tpdf = self.next_random() - self.next_random();
+        let out = ((shaped + tpdf * lsb) * levels).round() / levels;
+        let out = out.clamp(-1.0, 1.0 - lsb);
+
+        // Keep the feedback bounded if the input clips the word length
+        let e = (out - shaped).clamp(-4.0 * lsb, 4.0 * lsb);
+        self.err = [e, self.err[0], self.err[1]];
+
+        out
+    }
+
+    /// Uniform 0..1 (xorshift32)
+    fn next_random(&mut self) -> f32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_on_quantization_grid() {
+        for bits in [16, 24] {
+            let mut dither = Dither::new(1);
+            let levels = (1u32 << (bits - 1)) as f64;
+            for n in 0..4096 {
+                let x = 0.5 * (n as f32 * 0.01).sin();
+                let y = dither.process(x, bits) as f64 * levels;
+                assert!((y - y.round()).abs() < 1e-3, "{bits}-bit sample off grid");
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_stays_small_and_unbiased() {
+        let mut dither = Dither::new(7);
+        let lsb = 1.0 / 32768.0;
+        let n = 48000;
+        let mut sum = 0.0f64;
+        for i in 0..n {
+            let x = 0.25 * (i as f32 * 0.003).sin();
+            let e = dither.process(x, 16) - x;
+            assert!(e.abs() < 8.0 * lsb);
+            sum += e as f64;
+        }
+        assert!((sum / n as f64).abs() < 0.1 * lsb as f64);
+    }
+
+    #[test]
+    fn test_silence_dithers_at_lsb_level() {
+        let mut dither = Dither::new(3);
+        let lsb = 1.0 / 32768.0;
+        let peak = (0..4800)
+            .map(|_| dither.process(0.0, 16).abs())
+            .fold(0.0f32, f32::max);
+        assert!(peak > 0.0 && peak <= 8.0 * lsb);
+    }
+}
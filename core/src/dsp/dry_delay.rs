@@ -0,0 +1,99 @@
+//! Dry Reference Delay
+//!
+//! Delays the unprocessed input by the plugin's reported latency so it lines
+//! up sample-for-sample with the processed output.
+//!
+//! # Purpose
+//! Feeds the "Dry Reference" auxiliary output. Editors record clean and
+//! processed takes in one pass and can swap between them later without a
+//! re-render, which only works if both tracks share the same timing.
+//!
+//! # Design Notes
+//! - Stereo ring buffer sized for the largest latency of any quality mode,
+//!   so a quality switch only moves the read offset
+//! - Changing the delay clears the buffer: the dry track restarts in silence
+//!   rather than jumping through stale audio
+//!
+//! ## Audio Thread Safety
+//! - Buffers are allocated in `new()`, no allocations in `set_delay()` or
+//!   `process()`
+
+// =============================================================================
+// Delay
+// =============================================================================
+
+pub struct DryDelay {
+    buf_l: Vec<f32>,
+    buf_r: Vec<f32>,
+    pos: usize,
+    delay: usize,
+}
+
+impl DryDelay {
+    /// `max_delay` is the largest delay `set_delay()` will accept
+    pub fn new(max_delay: usize) -> Self {
+        Self {
+            buf_l: vec![0.0; max_delay + 1],
+            buf_r: vec![0.0; max_delay + 1],
+            pos: 0,
+            delay: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.buf_l.fill(0.0);
+        self.buf_r.fill(0.0);
+        self.pos = 0;
+    }
+
+    /// Set the delay in samples (clamped to the capacity)
+    pub fn set_delay(&mut self, samples: usize) {
+        let samples = samples.min(self.buf_l.len() - 1);
+        if samples != self.delay {
+            self.delay = samples;
+            self.reset();
+        }
+    }
+
+    pub fn delay(&self) -> usize {
+        self.delay
+    }
+
+    /// Push one stereo sample and return the one from `delay` samples ago
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let len = self.buf_l.len();
+        self.buf_l[self.pos] = left;
+        self.buf_r[self.pos] = right;
+        let read = (self.pos + len - self.delay) % len;
+        let out = (self.buf_l[read], self.buf_r[read]);
+        self.pos = (self.pos + 1) % len;
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delays_by_exact_samples() {
+        let mut d = DryDelay::new(16);
+        d.set_delay(5);
+        let out: Vec<(f32, f32)> = (0..20).map(|i| d.process(i as f32, -(i as f32))).collect();
+        assert!(out[..5].iter().all(|&s| s == (0.0, 0.0)));
+        for (i, &(l, r)) in out.iter().enumerate().skip(5) {
+            assert_eq!(l, (i - 5) as f32);
+            assert_eq!(r, -((i - 5) as f32));
+        }
+    }
+
+    #[test]
+    fn test_zero_delay_and_clamp() {
+        let mut d = DryDelay::new(8);
+        assert_eq!(d.process(1.0, 2.0), (1.0, 2.0));
+
+        d.set_delay(100);
+        assert_eq!(d.delay(), 8);
+    }
+}
@@ -13,6 +13,16 @@
 //! - Uses traditional Wiener filtering approach
 //! - Optional adaptive advisor for improved speech probability estimation
 //! - Focuses on stationary noise reduction
+//! - Optional Rescue engine: per-bin spectral gate (`spectral_gate`) in place of
+//!   the Wiener gain, still subject to the speech floors and guardrails below
+//! - `floor_depth` sets how quickly the psychoacoustic and speech floors
+//!   give way as the amount rises: low keeps them up to full amount (smooth
+//!   residual), high drops them early (deeper reduction, more artifacts)
+//! - `stereo_link` blends the shared gain with per-channel gains: below
+//!   full link, a bin that is much weaker in one channel than in the mono
+//!   proxy is pulled down further in that channel only (smoothed power
+//!   ratio), so signal on one side no longer opens the same bins on the
+//!   other and wide sources keep their image
 //! - Preserves voice characteristics and intelligibility
 //!   - Remove non-stationary noise like dog barks, sirens, or keyboard clicks.
 //!   - De-clip or de-crackle.
@@ -54,6 +64,8 @@
 //! - Speech is characterized by harmonic structure (voiced) or broadband high-frequency transients (unvoiced).
 //! - Impulse noise and non-stationary transients are NOT modeled.
 
+use crate::dsp::artifact_detector::ArtifactDetector;
+use crate::dsp::spectral_gate::SpectralGate;
 use crate::dsp::utils::{
     bell, db_to_gain, estimate_f0_autocorr, frame_rms, lerp, make_sqrt_hann_window,
     perceptual_curve, smoothstep, BYPASS_AMOUNT_EPS, MAG_FLOOR,
@@ -90,6 +102,8 @@ const HUM_REMOVAL_LOW_CUT_HZ: f32 = 25.0;
 const HUM_REMOVAL_FREQS: [f32; 6] = [50.0, 60.0, 100.0, 120.0, 150.0, 180.0];
 // Startup threshold to detect uninitialized noise floor.
 const NOISE_STARTUP_THRESH: f32 = 1e-4;
+// Noise confidence above which a floor past startup counts as settled.
+const NOISE_SETTLED_CONF: f32 = 0.8;
 // Startup noise floor attack/release.
 const NOISE_STARTUP_ATT: f32 = 0.6;
 const NOISE_STARTUP_REL: f32 = 0.90;
@@ -144,6 +158,9 @@ const SPEECH_FLOOR_MAX: f32 = 0.98;
 // Floor scaling with amount.
 const FLOOR_SCALE_MIN: f32 = 0.35;
 const SPEECH_FLOOR_SCALE_MIN: f32 = 0.60;
+// Floor depth: the amount driving the floor scaling is multiplied by
+// FLOOR_DEPTH_RANGE^(2 * floor_depth - 1), so 0.5 keeps the curve above.
+const FLOOR_DEPTH_RANGE: f32 = 4.0;
 // Spectral smoothing strength for voiced/unvoiced.
 const SMOOTH_STRENGTH_VOICED: f32 = 0.55;
 const SMOOTH_STRENGTH_UNVOICED: f32 = 0.75;
@@ -224,6 +241,22 @@ const TRANSIENT_HOLD_FRAMES: i32 = 2;
 // MMSE-LSA numerical guard
 const MMSE_EPS: f32 = 1e-12;
 
+// Rescue engine (spectral gate)
+// Open threshold (bin magnitude / noise floor) at low and high sensitivity.
+const RESCUE_OPEN_RATIO_MIN: f32 = 2.0;
+const RESCUE_OPEN_RATIO_MAX: f32 = 4.0;
+// Closed-bin attenuation over the amount range (dB).
+const RESCUE_DEPTH_MIN_DB: f32 = 12.0;
+const RESCUE_DEPTH_MAX_DB: f32 = 40.0;
+// Gain floor per unit of bin speech probability (speech protection).
+const RESCUE_SPEECH_FLOOR: f32 = 0.5;
+
+// Unlinked stereo: a channel's own gain stays within this factor below the
+// linked gain (avoids gating a channel that is quiet in a bin)
+const UNLINK_MIN_RATIO: f32 = 0.25;
+// Per-frame smoothing of the channel and proxy powers behind that ratio
+const UNLINK_POW_SMOOTH: f32 = 0.7;
+
 fn expint_e1(x: f32) -> f32 {
     let x = x.max(MMSE_EPS);
     if x < 1.0 {
@@ -252,6 +285,25 @@ pub struct DenoiseConfig {
     pub sample_rate: f32,
     pub speech_confidence: f32, // Speech confidence for adaptive behavior
     pub low_end_protect: bool,
+    /// Back off the amount when musical-noise artifacts are likely
+    pub artifact_guard: bool,
+    /// Run the secondary coarse FFT (skipped in Eco quality)
+    pub coarse_analysis: bool,
+    /// Spectral peaks used for the masking curve (capped at `MASKER_MAX_PEAKS`)
+    pub masker_peaks: usize,
+    /// Rescue engine: spectral gate instead of the Wiener gain (destructive)
+    pub rescue: bool,
+    /// Residual floor: 0 = smoothest, 0.5 = default, 1 = deepest reduction
+    pub floor_depth: f32,
+    /// Seconds of fast noise-floor learning after the floor starts fresh
+    pub learn_time: f32,
+    /// Hold both noise floors as they are (no adaptation)
+    pub freeze_noise: bool,
+    /// Hold the Artifact Guard backoff at its current share
+    pub freeze_artifact_guard: bool,
+    /// Stereo link: 1 = one gain for both channels (mono proxy), 0 = gains
+    /// from each channel's own magnitudes
+    pub stereo_link: f32,
 }
 
 /// DSP-based denoiser implementation
@@ -263,6 +315,8 @@ pub struct DspDenoiser {
     frame_l: Vec<f32>,
     frame_r: Vec<f32>,
     frame_mono: Vec<f32>,
+    /// Smoothed mono proxy power per bin (stereo link reference)
+    proxy_pow: Vec<f32>,
 
     win_size: usize,
     hop_size: usize,
@@ -280,6 +334,7 @@ impl DspDenoiser {
             frame_l: vec![0.0; win_size],
             frame_r: vec![0.0; win_size],
             frame_mono: vec![0.0; win_size],
+            proxy_pow: vec![0.0; win_size / 2 + 1],
             win_size,
             hop_size,
         }
@@ -304,10 +359,18 @@ impl DspDenoiser {
                 self.frame_mono[i] = if l.abs() >= r.abs() { l } else { r };
             }
 
-            let gains = self.detector.analyze_frame(&self.frame_mono, cfg);
+            self.detector.analyze_frame(&self.frame_mono, cfg);
+            for (p, m) in self.proxy_pow.iter_mut().zip(&self.detector.mag) {
+                *p = UNLINK_POW_SMOOTH * *p + (1.0 - UNLINK_POW_SMOOTH) * m * m;
+            }
 
-            self.chan_l.process_frame(gains, cfg.sample_rate);
-            self.chan_r.process_frame(gains, cfg.sample_rate);
+            let gains = &self.detector.gain_buf;
+            let link = cfg.stereo_link.clamp(0.0, 1.0);
+            let depth = cfg.amount.clamp(0.0, 1.0);
+            self.chan_l
+                .process_frame(gains, &self.proxy_pow, link, depth);
+            self.chan_r
+                .process_frame(gains, &self.proxy_pow, link, depth);
 
             self.chan_l.discard_input(self.hop_size);
             self.chan_r.discard_input(self.hop_size);
@@ -320,6 +383,16 @@ impl DspDenoiser {
     pub fn get_noise_confidence(&self) -> f32 {
         self.detector.noise_confidence
     }
+
+    /// Musical-noise metric (mean frame-to-frame gain change per bin)
+    pub fn get_artifact_level(&self) -> f32 {
+        self.detector.artifacts.level()
+    }
+
+    /// Share of the denoise amount the Artifact Guard is removing (0 when off)
+    pub fn get_artifact_backoff(&self) -> f32 {
+        self.detector.artifact_backoff
+    }
 }
 
 /// Shared detector for stereo-linked denoising.
@@ -352,9 +425,16 @@ struct DspDenoiserDetector {
     f0_scratch: Vec<f32>,
 
     noise_confidence: f32,
+    /// Frames analysed since the noise floor started fresh
+    learn_frames: u32,
     prev_rms: f32,
     transient_hold: i32,
     current_average_reduction: f32,
+
+    artifacts: ArtifactDetector,
+    artifact_backoff: f32,
+
+    gate: SpectralGate,
 }
 
 impl DspDenoiserDetector {
@@ -404,9 +484,15 @@ impl DspDenoiserDetector {
             frame_time: vec![0.0; win_size],
             f0_scratch: vec![0.0; win_size], // pre-allocated vector
             noise_confidence: 1.0,
+            learn_frames: 0,
             prev_rms: 0.0,
             transient_hold: 0,
             current_average_reduction: 0.0,
+
+            artifacts: ArtifactDetector::new(nyq + 1),
+            artifact_backoff: 0.0,
+
+            gate: SpectralGate::new(nyq + 1),
         }
     }
 
@@ -455,6 +541,7 @@ impl DspDenoiserDetector {
         let amt = (curved_amount * DENOISE_STRENGTH_MULT).clamp(0.0, MAX_DENOISE_AMOUNT);
         let sensitivity = cfg.sensitivity.clamp(0.0, 1.0);
         let tone = cfg.tone.clamp(0.0, 1.0);
+        let floor_depth_mult = FLOOR_DEPTH_RANGE.powf(2.0 * cfg.floor_depth.clamp(0.0, 1.0) - 1.0);
 
         // Reset decision-directed history on bypass (Patch 5)
         if amt <= BYPASS_AMOUNT_EPS {
@@ -495,7 +582,9 @@ impl DspDenoiserDetector {
         }
 
         // 3) Multi-resolution cues
-        self.compute_coarse_fft_and_update_noise(mono);
+        if cfg.coarse_analysis && !cfg.freeze_noise {
+            self.compute_coarse_fft_and_update_noise(mono);
+        }
 
         // 4) DSP speech presence probability + voiced/unvoiced + f0
         let (dsp_speech_prob, voiced_prob, f0_hz) = self.estimate_speech_and_f0(sr);
@@ -514,9 +603,14 @@ impl DspDenoiserDetector {
             self.transient_hold -= 1;
         }
 
-        // 5) Update noise floor
-        let startup_mode =
-            self.noise_floor[nyq.min(self.noise_floor.len() - 1)] < NOISE_STARTUP_THRESH;
+        // 5) Update noise floor (fast while still learning, held while frozen)
+        let learn_period = (cfg.learn_time.max(0.0) * sr / self.hop_size as f32) as u32;
+        let startup_mode = self.noise_floor[nyq.min(self.noise_floor.len() - 1)]
+            < NOISE_STARTUP_THRESH
+            || self.learn_frames < learn_period;
+        if !cfg.freeze_noise {
+            self.learn_frames = self.learn_frames.saturating_add(1);
+        }
         let (alpha_att, alpha_rel) = if startup_mode {
             (NOISE_STARTUP_ATT, NOISE_STARTUP_REL)
         } else {
@@ -528,20 +622,22 @@ impl DspDenoiserDetector {
         };
 
         let mut stability_sum = 0.0;
-        for i in 0..=nyq {
-            let mag = self.mag[i];
-            let nf = self.noise_floor[i];
-            let prev_nf = nf;
+        if !cfg.freeze_noise {
+            for i in 0..=nyq {
+                let mag = self.mag[i];
+                let nf = self.noise_floor[i];
+                let prev_nf = nf;
 
-            self.noise_floor[i] = if mag < nf {
-                nf * alpha_att + mag * (1.0 - alpha_att)
-            } else {
-                nf * alpha_rel + mag * (1.0 - alpha_rel)
-            };
-            self.noise_floor[i] = self.noise_floor[i].max(MAG_FLOOR);
+                self.noise_floor[i] = if mag < nf {
+                    nf * alpha_att + mag * (1.0 - alpha_att)
+                } else {
+                    nf * alpha_rel + mag * (1.0 - alpha_rel)
+                };
+                self.noise_floor[i] = self.noise_floor[i].max(MAG_FLOOR);
 
-            if prev_nf > MAG_FLOOR {
-                stability_sum += (self.noise_floor[i] - prev_nf).abs() / prev_nf;
+                if prev_nf > MAG_FLOOR {
+                    stability_sum += (self.noise_floor[i] - prev_nf).abs() / prev_nf;
+                }
             }
         }
 
@@ -552,11 +648,27 @@ impl DspDenoiserDetector {
         // Scale amount by confidence to prevent artifacts on unstable noise floor
         let effective_amt = amt * (0.2 + 0.8 * self.noise_confidence);
 
+        // Artifact Guard: back off while the previous frames warbled
+        self.artifact_backoff = if !cfg.artifact_guard {
+            0.0
+        } else if cfg.freeze_artifact_guard {
+            self.artifact_backoff
+        } else {
+            self.artifacts.backoff()
+        };
+        let effective_amt = effective_amt * (1.0 - self.artifact_backoff);
+
         // 6) Masker curve
-        self.compute_masker_curve(sr);
+        self.compute_masker_curve(sr, cfg.masker_peaks);
 
-        // 7) Wiener Gain curve build
+        // 7) Wiener Gain curve build (or Rescue gate)
         let voiced = voiced_prob > VOICED_PROB_MIN;
+        let rescue_open_ratio = lerp(RESCUE_OPEN_RATIO_MIN, RESCUE_OPEN_RATIO_MAX, sensitivity);
+        let rescue_closed_gain = db_to_gain(-lerp(
+            RESCUE_DEPTH_MIN_DB,
+            RESCUE_DEPTH_MAX_DB,
+            effective_amt / MAX_DENOISE_AMOUNT,
+        ));
 
         for i in 0..=nyq {
             let mag_p = self.mag[i];
@@ -597,6 +709,21 @@ impl DspDenoiserDetector {
             // Per-bin SPP fusion
             let spp_bin = dsp_speech_prob.clamp(0.0, 1.0) * band_weight;
 
+            // Rescue: near-binary gate, floored where speech is likely
+            if cfg.rescue && effective_amt > BYPASS_AMOUNT_EPS {
+                let ratio = mag_p / nf.max(MAG_FLOOR);
+                let mut gain = self
+                    .gate
+                    .bin_gain(i, ratio, rescue_open_ratio, rescue_closed_gain);
+                gain = gain.max(RESCUE_SPEECH_FLOOR * spp_bin);
+                if self.transient_hold > 0 && freq_fraction >= TRANSIENT_HF_MIN {
+                    gain = gain.max(0.22);
+                }
+                self.gain_buf[i] = gain.min(1.0);
+                self.prev_mag[i] = mag_p;
+                continue;
+            }
+
             // Threshold scaling using fused SPP and sensitivity
             let thresh_scale = (1.0 + sensitivity * THRESH_SENS_SCALE)
                 * bias
@@ -624,8 +751,9 @@ impl DspDenoiserDetector {
             // Psychoacoustic and speech-conditioned floors
             let masker = self.masker_buf[i].max(MAG_FLOOR);
             let mask_ratio = (masker / (masker + nf)).clamp(0.0, 1.0);
-            let floor_scale = lerp(1.0, FLOOR_SCALE_MIN, effective_amt);
-            let speech_floor_scale = lerp(1.0, SPEECH_FLOOR_SCALE_MIN, effective_amt);
+            let floor_amt = effective_amt * floor_depth_mult;
+            let floor_scale = lerp(1.0, FLOOR_SCALE_MIN, floor_amt);
+            let speech_floor_scale = lerp(1.0, SPEECH_FLOOR_SCALE_MIN, floor_amt);
 
             let psycho_floor = (PSYCHO_FLOOR_BASE + PSYCHO_FLOOR_RANGE * (1.0 - mask_ratio))
                 .clamp(PSYCHO_FLOOR_MIN, PSYCHO_FLOOR_MAX)
@@ -722,6 +850,9 @@ impl DspDenoiserDetector {
         }
         self.current_average_reduction = total_reduction / (nyq as f32 + 1.0);
 
+        // Measured with the guard on or off so the meter stays meaningful
+        self.artifacts.update(&self.gain_buf, global_spp);
+
         &self.gain_buf
     }
 
@@ -848,8 +979,9 @@ impl DspDenoiserDetector {
         }
     }
 
-    fn compute_masker_curve(&mut self, sr: f32) {
+    fn compute_masker_curve(&mut self, sr: f32, max_peaks: usize) {
         let nyq = self.win_size / 2;
+        let max_peaks = max_peaks.min(MASKER_MAX_PEAKS);
         self.masker_buf.fill(0.0);
 
         // Find spectral peaks - use a fixed-size array instead of Vec to avoid allocation
@@ -865,7 +997,7 @@ impl DspDenoiserDetector {
                 && m > 1e-6
             // Significant peak
             {
-                if peak_count < MASKER_MAX_PEAKS {
+                if peak_count < max_peaks {
                     peaks_temp[peak_count] = (i, m);
                     peak_count += 1;
                 } else {
@@ -969,12 +1101,78 @@ impl DspDenoiser {
     pub fn reset(&mut self) {
         self.chan_l.reset();
         self.chan_r.reset();
+        self.detector.artifacts.reset();
+        self.detector.artifact_backoff = 0.0;
+        self.detector.gate.reset();
+        self.proxy_pow.fill(0.0);
     }
 
     /// Returns the current average gain reduction applied by the denoiser (0.0 to 1.0)
     pub fn get_current_reduction(&self) -> f32 {
         self.detector.get_current_reduction()
     }
+
+    /// Delay in samples (one window)
+    pub fn latency(&self) -> usize {
+        self.win_size
+    }
+
+    /// Adaptive noise floor magnitude per bin (nyq+1)
+    pub fn noise_floor(&self) -> &[f32] {
+        &self.detector.noise_floor
+    }
+
+    /// Coarse-analysis noise floor magnitude per bin
+    pub fn coarse_noise_floor(&self) -> &[f32] {
+        &self.detector.noise_floor_coarse
+    }
+
+    /// The noise floor has left its startup ramp and stopped moving, so it
+    /// is worth keeping for the next session
+    pub fn noise_floor_settled(&self) -> bool {
+        let nf = &self.detector.noise_floor;
+        nf[nf.len() - 1] >= NOISE_STARTUP_THRESH
+            && self.detector.noise_confidence > NOISE_SETTLED_CONF
+    }
+
+    /// Seed both noise floors from a saved copy taken at the same sample
+    /// rate and window size, as returned by `decimate_floor()`
+    pub fn restore_noise_floors(&mut self, fine: &[f32], coarse: &[f32]) {
+        expand_floor(fine, &mut self.detector.noise_floor);
+        expand_floor(coarse, &mut self.detector.noise_floor_coarse);
+        self.detector.learn_frames = u32::MAX;
+    }
+}
+
+/// Point `j` of `points` of a noise floor reduced for storage: the mean of
+/// the bins nearest to it, points spread evenly from DC to Nyquist
+pub fn decimate_floor(floor: &[f32], j: usize, points: usize) -> f32 {
+    let last = floor.len() - 1;
+    let step = last as f32 / (points - 1).max(1) as f32;
+    let center = j as f32 * step;
+    let lo = (center - 0.5 * step).ceil().max(0.0) as usize;
+    let hi = ((center + 0.5 * step).floor() as usize).clamp(lo, last);
+    floor[lo..=hi].iter().sum::<f32>() / (hi - lo + 1) as f32
+}
+
+/// Inverse of `decimate_floor()`: linear interpolation back onto every bin
+fn expand_floor(points: &[f32], floor: &mut [f32]) {
+    if points.len() < 2 || floor.len() < 2 {
+        return;
+    }
+    let step = (points.len() - 1) as f32 / (floor.len() - 1) as f32;
+    let last = points.len() - 1;
+    for (i, nf) in floor.iter_mut().enumerate() {
+        let pos = i as f32 * step;
+        let k = (pos as usize).min(last - 1);
+        let t = pos - k as f32;
+        let m = points[k] + t * (points[k + 1] - points[k]);
+        *nf = if m.is_finite() {
+            m.max(MAG_FLOOR)
+        } else {
+            NOISE_FLOOR_INIT
+        };
+    }
 }
 
 /// Per-channel streaming denoiser for WOLA processing
@@ -994,6 +1192,8 @@ struct StreamingDenoiserChannel {
     overlap: Vec<f32>,
     ola_norm: Vec<f32>,
     frame_in: Vec<f32>,
+    /// Smoothed power per bin (stereo link)
+    own_pow: Vec<f32>,
 
     fft: Arc<dyn Fft<f32>>,
     ifft: Arc<dyn Fft<f32>>,
@@ -1037,6 +1237,7 @@ impl StreamingDenoiserChannel {
             overlap: vec![0.0; win_size],
             ola_norm: vec![0.0; win_size],
             frame_in: vec![0.0; win_size],
+            own_pow: vec![0.0; win_size / 2 + 1],
             fft,
             ifft,
         }
@@ -1060,7 +1261,10 @@ impl StreamingDenoiserChannel {
         self.input_consumer.discard(n);
     }
 
-    fn process_frame(&mut self, gains: &[f32], _sample_rate: f32) {
+    /// Filter the current frame. Below full `link` each bin's gain moves
+    /// towards this channel's own gain: the linked gain, lowered by `depth`
+    /// where this channel is weaker than the proxy.
+    fn process_frame(&mut self, gains: &[f32], proxy_pow: &[f32], link: f32, depth: f32) {
         if gains.len() != self.win_size / 2 + 1 {
             return;
         }
@@ -1094,7 +1298,20 @@ impl StreamingDenoiserChannel {
         // Apply gains (only to first half, second half is conjugate symmetry)
         let nyq = self.win_size / 2;
         for i in 0..=nyq {
-            self.scratch[i] *= gains[i];
+            let a = UNLINK_POW_SMOOTH;
+            self.own_pow[i] = a * self.own_pow[i] + (1.0 - a) * self.scratch[i].norm_sqr();
+
+            let linked = gains[i];
+            let gain = if link >= 1.0 {
+                linked
+            } else {
+                let ratio = (self.own_pow[i] / proxy_pow[i].max(SNR_EPS))
+                    .sqrt()
+                    .clamp(UNLINK_MIN_RATIO, 1.0);
+                let own = linked * lerp(1.0, ratio, depth);
+                lerp(own, linked, link)
+            };
+            self.scratch[i] *= gain;
         }
 
         // Restore conjugate symmetry
@@ -1161,5 +1378,193 @@ impl StreamingDenoiserChannel {
         // Reset processing state
         self.overlap.fill(0.0);
         self.ola_norm.fill(0.0);
+        self.own_pow.fill(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Average reduction after 3 s of steady white noise at 50% amount
+    fn reduction(floor_depth: f32) -> f32 {
+        let cfg = DenoiseConfig {
+            amount: 0.5,
+            sensitivity: 1.0,
+            tone: 0.5,
+            sample_rate: SR,
+            speech_confidence: 0.0,
+            low_end_protect: true,
+            artifact_guard: false,
+            coarse_analysis: true,
+            masker_peaks: MASKER_MAX_PEAKS,
+            rescue: false,
+            floor_depth,
+            learn_time: 0.5,
+            freeze_noise: false,
+            freeze_artifact_guard: false,
+            stereo_link: 1.0,
+        };
+        let mut dn = DspDenoiser::new(1024, 256);
+        let mut seed = 7u32;
+        for _ in 0..3 * SR as usize {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let x = ((seed >> 8) as f32 / 16_777_216.0 - 0.5) * 0.5;
+            dn.process_sample(x, x, &cfg);
+        }
+        dn.get_current_reduction()
+    }
+
+    #[test]
+    fn test_noise_floor_restore_round_trip() {
+        let cfg = DenoiseConfig {
+            amount: 0.5,
+            sensitivity: 1.0,
+            tone: 0.5,
+            sample_rate: SR,
+            speech_confidence: 0.0,
+            low_end_protect: true,
+            artifact_guard: false,
+            coarse_analysis: true,
+            masker_peaks: MASKER_MAX_PEAKS,
+            rescue: false,
+            floor_depth: 0.5,
+            learn_time: 0.5,
+            freeze_noise: false,
+            freeze_artifact_guard: false,
+            stereo_link: 1.0,
+        };
+        let mut dn = DspDenoiser::new(1024, 256);
+        assert!(!dn.noise_floor_settled());
+        let mut seed = 7u32;
+        for _ in 0..3 * SR as usize {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let x = ((seed >> 8) as f32 / 16_777_216.0 - 0.5) * 0.5;
+            dn.process_sample(x, x, &cfg);
+        }
+        assert!(dn.noise_floor_settled());
+
+        let save = |floor: &[f32], points: usize| -> Vec<f32> {
+            (0..points)
+                .map(|j| decimate_floor(floor, j, points))
+                .collect()
+        };
+        let fine = save(dn.noise_floor(), 128);
+        let coarse = save(dn.coarse_noise_floor(), 64);
+
+        let mut fresh = DspDenoiser::new(1024, 256);
+        fresh.restore_noise_floors(&fine, &coarse);
+        assert!(fresh.noise_floor_settled());
+
+        // White noise: the restored floor sits within 1.5 dB of the original
+        let mean = |v: &[f32]| v[8..v.len() - 8].iter().sum::<f32>() / (v.len() - 16) as f32;
+        let ratio = mean(fresh.noise_floor()) / mean(dn.noise_floor());
+        assert!((ratio.log10() * 20.0).abs() < 1.5, "{ratio}");
+        let ratio = mean(fresh.coarse_noise_floor()) / mean(dn.coarse_noise_floor());
+        assert!((ratio.log10() * 20.0).abs() < 1.5, "{ratio}");
+    }
+
+    #[test]
+    fn test_freeze_holds_noise_floor() {
+        let mut cfg = DenoiseConfig {
+            amount: 0.5,
+            sensitivity: 1.0,
+            tone: 0.5,
+            sample_rate: SR,
+            speech_confidence: 0.0,
+            low_end_protect: true,
+            artifact_guard: false,
+            coarse_analysis: true,
+            masker_peaks: MASKER_MAX_PEAKS,
+            rescue: false,
+            floor_depth: 0.5,
+            learn_time: 0.5,
+            freeze_noise: false,
+            freeze_artifact_guard: false,
+            stereo_link: 1.0,
+        };
+        let mut dn = DspDenoiser::new(1024, 256);
+        let mut seed = 7u32;
+        let mut run = |dn: &mut DspDenoiser, cfg: &DenoiseConfig, level: f32| {
+            for _ in 0..SR as usize {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let x = ((seed >> 8) as f32 / 16_777_216.0 - 0.5) * level;
+                dn.process_sample(x, x, cfg);
+            }
+        };
+        run(&mut dn, &cfg, 0.05);
+        let learned = dn.noise_floor().to_vec();
+        let learned_coarse = dn.coarse_noise_floor().to_vec();
+
+        // 20 dB louder noise while frozen leaves both floors untouched
+        cfg.freeze_noise = true;
+        run(&mut dn, &cfg, 0.5);
+        assert_eq!(dn.noise_floor(), &learned[..]);
+        assert_eq!(dn.coarse_noise_floor(), &learned_coarse[..]);
+    }
+
+    #[test]
+    fn test_floor_depth_trades_reduction() {
+        let smooth = reduction(0.0);
+        let default = reduction(0.5);
+        let deep = reduction(1.0);
+        // Low depth keeps the floors up where the default has dropped them
+        assert!(smooth < default - 0.01, "{smooth} {default}");
+        assert!(deep >= default - 1e-6, "{deep} {default}");
+    }
+
+    /// Output RMS per channel over the last second of 3 s: a 1 kHz tone
+    /// with light noise on the left, independent noise alone on the right
+    fn wide_source_rms(stereo_link: f32) -> (f32, f32) {
+        let cfg = DenoiseConfig {
+            amount: 0.5,
+            sensitivity: 1.0,
+            tone: 0.5,
+            sample_rate: SR,
+            speech_confidence: 0.0,
+            low_end_protect: true,
+            artifact_guard: false,
+            coarse_analysis: true,
+            masker_peaks: MASKER_MAX_PEAKS,
+            rescue: false,
+            floor_depth: 0.5,
+            learn_time: 0.5,
+            freeze_noise: false,
+            freeze_artifact_guard: false,
+            stereo_link,
+        };
+        let mut dn = DspDenoiser::new(1024, 256);
+        let (mut seed_l, mut seed_r) = (7u32, 1234u32);
+        let noise = |seed: &mut u32| {
+            *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            ((*seed >> 8) as f32 / 16_777_216.0 - 0.5) * 0.05
+        };
+        let (mut sum_l, mut sum_r) = (0.0f64, 0.0f64);
+        let total = 3 * SR as usize;
+        for n in 0..total {
+            let tone = 0.3 * (std::f32::consts::TAU * 1000.0 * n as f32 / SR).sin();
+            let l = tone + noise(&mut seed_l);
+            let r = noise(&mut seed_r);
+            let (out_l, out_r) = dn.process_sample(l, r, &cfg);
+            if n >= total - SR as usize {
+                sum_l += (out_l * out_l) as f64;
+                sum_r += (out_r * out_r) as f64;
+            }
+        }
+        let rms = |sum: f64| (sum / SR as f64).sqrt() as f32;
+        (rms(sum_l), rms(sum_r))
+    }
+
+    #[test]
+    fn test_unlinked_stereo_keeps_channels_apart() {
+        let (linked_l, linked_r) = wide_source_rms(1.0);
+        let (own_l, own_r) = wide_source_rms(0.0);
+        // The tone side is untouched either way
+        let db = |a: f32, b: f32| 20.0 * (a / b).log10();
+        assert!(db(own_l, linked_l).abs() < 1.0, "{linked_l} {own_l}");
+        // Linked, the tone opens its bins on the noise-only side too
+        assert!(own_r < linked_r, "{linked_r} {own_r}");
     }
 }
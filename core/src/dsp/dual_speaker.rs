@@ -0,0 +1,345 @@
+//! Dual-Speaker Detector
+//!
+//! Lightweight speaker-change detection for two-person material on one track
+//! (interviews). Voiced frames are described by pitch (F0) and spectral
+//! centroid and clustered online into two speakers; the active speaker only
+//! changes after a sustained run of frames that favour the other cluster.
+//!
+//! # Purpose
+//! Lets per-speaker adaptive settings (de-esser band, leveler target) follow
+//! whoever is talking instead of averaging two voices into one.
+//!
+//! # Design Notes
+//! - Analysis only; never modifies audio
+//! - F0 is estimated on a 4x decimated signal to keep autocorrelation cheap
+//! - Centroid uses the RMS-frequency approximation sqrt(E[x'^2] / E[x^2])
+//! - The second speaker is only created once a frame lands far from the first
+//! - Switching needs `SWITCH_HOLD_FRAMES` consecutive frames (hysteresis)
+//!
+//! ## Audio Thread Safety
+//! - Frame and scratch buffers are allocated in `new()`
+
+use std::f32::consts::PI;
+
+use super::biquad::Biquad;
+use super::utils::{estimate_f0_autocorr, lin_to_db, DB_EPS};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Analysis frame length in milliseconds
+const FRAME_MS: f32 = 40.0;
+
+/// Decimation factor for F0 analysis
+const DECIMATE: usize = 4;
+/// Anti-alias low-pass ahead of decimation (Hz)
+const DECIMATE_LPF_HZ: f32 = 1500.0;
+
+/// Mean speech confidence a frame needs to be clustered
+const SPEECH_CONF_GATE: f32 = 0.6;
+/// Minimum periodicity for a frame to count as voiced
+const F0_PERIODICITY_MIN: f32 = 0.5;
+
+/// Feature scaling: one unit = this many octaves
+const F0_SCALE_OCT: f32 = 0.35;
+const CENTROID_SCALE_OCT: f32 = 0.5;
+
+/// Distance (feature units) at which a frame seeds the second speaker
+const SPLIT_DISTANCE: f32 = 1.5;
+/// Frames far from speaker A needed before speaker B is created
+const SPLIT_HOLD_FRAMES: u32 = 6;
+/// Cluster mean adaptation per assigned frame
+const CLUSTER_RATE: f32 = 0.03;
+/// Consecutive frames favouring the other speaker before switching (~320 ms)
+const SWITCH_HOLD_FRAMES: u32 = 8;
+/// Other speaker must be closer by this margin (feature units)
+const SWITCH_MARGIN: f32 = 0.25;
+
+/// Per-speaker level averaging (frames, ~20 s)
+const LEVEL_AVERAGE_FRAMES: u32 = 500;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Cluster {
+    f0_oct: f32,
+    centroid_oct: f32,
+    frames: u32,
+    rms_db: f32,
+}
+
+impl Cluster {
+    fn distance(&self, f0_oct: f32, centroid_oct: f32) -> f32 {
+        let df = (f0_oct - self.f0_oct) / F0_SCALE_OCT;
+        let dc = (centroid_oct - self.centroid_oct) / CENTROID_SCALE_OCT;
+        (df * df + dc * dc).sqrt()
+    }
+
+    fn seed(&mut self, f0_oct: f32, centroid_oct: f32, rms_db: f32) {
+        *self = Self {
+            f0_oct,
+            centroid_oct,
+            frames: 1,
+            rms_db,
+        };
+    }
+
+    fn update(&mut self, f0_oct: f32, centroid_oct: f32, rms_db: f32) {
+        self.f0_oct += (f0_oct - self.f0_oct) * CLUSTER_RATE;
+        self.centroid_oct += (centroid_oct - self.centroid_oct) * CLUSTER_RATE;
+        self.frames = self.frames.saturating_add(1);
+        let w = 1.0 / self.frames.min(LEVEL_AVERAGE_FRAMES) as f32;
+        self.rms_db += (rms_db - self.rms_db) * w;
+    }
+}
+
+// =============================================================================
+// Detector
+// =============================================================================
+
+pub struct DualSpeakerDetector {
+    sample_rate: f32,
+
+    // Framing
+    decim_lpf: Biquad,
+    decim_phase: usize,
+    frame: Vec<f32>,
+    f0_scratch: Vec<f32>,
+    frame_pos: usize,
+    frame_samples: usize,
+    sample_count: usize,
+    energy_acc: f32,
+    diff_energy_acc: f32,
+    conf_acc: f32,
+    prev_sample: f32,
+
+    // Clustering
+    clusters: [Cluster; 2],
+    cluster_count: usize,
+    split_run: u32,
+
+    // Hysteresis
+    active: usize,
+    switch_run: u32,
+}
+
+impl DualSpeakerDetector {
+    pub fn new(sample_rate: f32) -> Self {
+        let decim_len = ((FRAME_MS * 0.001 * sample_rate) as usize / DECIMATE).max(128);
+        let frame_samples = decim_len * DECIMATE;
+
+        let mut decim_lpf = Biquad::new();
+        decim_lpf.update_lpf(DECIMATE_LPF_HZ, 0.707, sample_rate);
+
+        Self {
+            sample_rate,
+            decim_lpf,
+            decim_phase: 0,
+            frame: vec![0.0; decim_len],
+            f0_scratch: vec![0.0; decim_len],
+            frame_pos: 0,
+            frame_samples,
+            sample_count: 0,
+            energy_acc: 0.0,
+            diff_energy_acc: 0.0,
+            conf_acc: 0.0,
+            prev_sample: 0.0,
+            clusters: [Cluster::default(); 2],
+            cluster_count: 0,
+            split_run: 0,
+            active: 0,
+            switch_run: 0,
+        }
+    }
+
+    /// Process one mono sample with its speech confidence
+    #[inline]
+    pub fn process(&mut self, mono: f32, speech_conf: f32) {
+        let diff = mono - self.prev_sample;
+        self.prev_sample = mono;
+        self.energy_acc += mono * mono;
+        self.diff_energy_acc += diff * diff;
+        self.conf_acc += speech_conf;
+        self.sample_count += 1;
+
+        let lp = self.decim_lpf.process(mono);
+        self.decim_phase += 1;
+        if self.decim_phase >= DECIMATE {
+            self.decim_phase = 0;
+            if self.frame_pos < self.frame.len() {
+                self.frame[self.frame_pos] = lp;
+                self.frame_pos += 1;
+            }
+        }
+
+        if self.sample_count >= self.frame_samples {
+            self.analyze_frame();
+            self.frame_pos = 0;
+            self.sample_count = 0;
+            self.energy_acc = 0.0;
+            self.diff_energy_acc = 0.0;
+            self.conf_acc = 0.0;
+        }
+    }
+
+    fn analyze_frame(&mut self) {
+        let n = self.sample_count as f32;
+        if self.conf_acc / n < SPEECH_CONF_GATE || self.energy_acc <= DB_EPS {
+            return;
+        }
+
+        // frame_samples is a multiple of DECIMATE, so a finished frame is full
+        if self.frame_pos < self.frame.len() {
+            return;
+        }
+        let decim_sr = self.sample_rate / DECIMATE as f32;
+        let (periodicity, f0_hz) =
+            estimate_f0_autocorr(&self.frame, &mut self.f0_scratch, decim_sr);
+        if periodicity < F0_PERIODICITY_MIN || f0_hz <= 0.0 {
+            return;
+        }
+
+        // RMS frequency: for a sinusoid sqrt(E[x'^2]/E[x^2]) = 2*sin(pi*f/sr)
+        let ratio = (self.diff_energy_acc / self.energy_acc).sqrt().min(2.0);
+        let centroid_hz = (ratio * 0.5).asin() * self.sample_rate / PI;
+
+        let f0_oct = f0_hz.max(1.0).log2();
+        let centroid_oct = centroid_hz.max(1.0).log2();
+        let rms_db = lin_to_db((self.energy_acc / n).sqrt());
+
+        self.cluster_frame(f0_oct, centroid_oct, rms_db);
+    }
+
+    fn cluster_frame(&mut self, f0_oct: f32, centroid_oct: f32, rms_db: f32) {
+        if self.cluster_count == 0 {
+            self.clusters[0].seed(f0_oct, centroid_oct, rms_db);
+            self.cluster_count = 1;
+            return;
+        }
+
+        if self.cluster_count == 1 {
+            if self.clusters[0].distance(f0_oct, centroid_oct) > SPLIT_DISTANCE {
+                self.split_run += 1;
+                if self.split_run >= SPLIT_HOLD_FRAMES {
+                    self.clusters[1].seed(f0_oct, centroid_oct, rms_db);
+                    self.cluster_count = 2;
+                    self.active = 1;
+                    self.switch_run = 0;
+                }
+            } else {
+                self.split_run = 0;
+                self.clusters[0].update(f0_oct, centroid_oct, rms_db);
+            }
+            return;
+        }
+
+        let d_active = self.clusters[self.active].distance(f0_oct, centroid_oct);
+        let other = 1 - self.active;
+        let d_other = self.clusters[other].distance(f0_oct, centroid_oct);
+
+        let nearest = if d_other + SWITCH_MARGIN < d_active {
+            self.switch_run += 1;
+            other
+        } else {
+            self.switch_run = 0;
+            self.active
+        };
+        self.clusters[nearest].update(f0_oct, centroid_oct, rms_db);
+
+        if self.switch_run >= SWITCH_HOLD_FRAMES {
+            self.active = other;
+            self.switch_run = 0;
+        }
+    }
+
+    /// Both speakers have been identified
+    #[inline]
+    pub fn has_two_speakers(&self) -> bool {
+        self.cluster_count == 2
+    }
+
+    /// Index (0 or 1) of the currently talking speaker
+    #[inline]
+    pub fn active_speaker(&self) -> usize {
+        self.active
+    }
+
+    /// Typical speech level of a speaker (dBFS)
+    pub fn speaker_rms_db(&self, index: usize) -> f32 {
+        self.clusters[index.min(1)].rms_db
+    }
+
+    pub fn reset(&mut self) {
+        self.decim_lpf.reset_state();
+        self.decim_phase = 0;
+        self.frame_pos = 0;
+        self.sample_count = 0;
+        self.energy_acc = 0.0;
+        self.diff_energy_acc = 0.0;
+        self.conf_acc = 0.0;
+        self.prev_sample = 0.0;
+        self.clusters = [Cluster::default(); 2];
+        self.cluster_count = 0;
+        self.split_run = 0;
+        self.active = 0;
+        self.switch_run = 0;
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Harmonic "voice" with slight pitch jitter; `tilt` sets brightness
+    fn voice(detector: &mut DualSpeakerDetector, sample_rate: f32, f0: f32, tilt: f32, sec: f32) {
+        let mut seed = 7u32;
+        let mut phase = 0.0f32;
+        for _ in 0..(sec * sample_rate) as usize {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let jitter = (seed >> 16) as f32 / 32_768.0 - 1.0;
+            phase += 2.0 * PI * f0 * (1.0 + 0.01 * jitter) / sample_rate;
+
+            let mut x = 0.0;
+            let mut amp = 0.1;
+            for h in 1..20 {
+                x += amp * (phase * h as f32).sin();
+                amp *= tilt;
+            }
+            detector.process(x, 0.9);
+        }
+    }
+
+    #[test]
+    fn test_single_speaker_stays_single() {
+        let sample_rate = 48_000.0;
+        let mut detector = DualSpeakerDetector::new(sample_rate);
+        voice(&mut detector, sample_rate, 120.0, 0.6, 5.0);
+
+        assert!(!detector.has_two_speakers());
+        assert_eq!(detector.active_speaker(), 0);
+    }
+
+    #[test]
+    fn test_alternating_speakers_switch() {
+        let sample_rate = 48_000.0;
+        let mut detector = DualSpeakerDetector::new(sample_rate);
+
+        voice(&mut detector, sample_rate, 110.0, 0.6, 3.0);
+        voice(&mut detector, sample_rate, 210.0, 0.85, 3.0);
+        assert!(detector.has_two_speakers());
+        let second = detector.active_speaker();
+
+        voice(&mut detector, sample_rate, 110.0, 0.6, 3.0);
+        assert_ne!(detector.active_speaker(), second);
+
+        voice(&mut detector, sample_rate, 210.0, 0.85, 3.0);
+        assert_eq!(detector.active_speaker(), second);
+    }
+}
@@ -0,0 +1,210 @@
+//! Gain Audit (per-stage unity-gain check and auto trim)
+//!
+//! Measures the long-term level each stage adds or removes during speech and
+//! can trim static offsets back out, so switching a stage in or out does not
+//! change the perceived loudness.
+//!
+//! # Purpose
+//! Judging a stage by bypassing it only works at matched level: a +2 dB
+//! side effect of Proximity reads as "better" regardless of what it does to
+//! the tone. The audit names the stages that move the level, and the
+//! optional trim removes the static part of it.
+//!
+//! # Design Notes
+//! - Taps sit at the same points as the numeric guard, in signal order; a
+//!   stage's input is the previous tap's (trimmed) output, so each stage is
+//!   charged only for its own gain
+//! - Powers are averaged over a few seconds and only while speech is likely,
+//!   so pause-only processing (gating, expansion) does not count as loss
+//! - Only the slow average is trimmed; dynamic action within a phrase is
+//!   left alone, and the trim is capped
+//! - Stages whose job is level (Leveler, Limiter) and pause-only beds are
+//!   reported but never trimmed; the first two taps feed the analysis
+//!   sidechain and are not trimmed either
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations
+
+use crate::dsp::utils::db_to_lin;
+use crate::dsp::GuardStage;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Averaging time of the stage powers (s)
+const AVERAGE_SEC: f32 = 2.0;
+
+/// Speech confidence above which samples count
+const SPEECH_GATE: f32 = 0.6;
+
+/// Input power below which a stage's gain reads as unity
+const MIN_POWER: f32 = 1e-9;
+
+/// Largest trim either way (dB)
+const MAX_TRIM_DB: f32 = 6.0;
+
+/// Trim glide time (ms), per-buffer targets are smoothed per sample
+const TRIM_GLIDE_MS: f32 = 50.0;
+
+// =============================================================================
+// Audit
+// =============================================================================
+
+pub struct GainAudit {
+    in_pow: [f32; GuardStage::COUNT],
+    out_pow: [f32; GuardStage::COUNT],
+    trim: [f32; GuardStage::COUNT],
+    trim_target: [f32; GuardStage::COUNT],
+
+    /// Power at the previous tap of the current sample
+    prev_pow: f32,
+    /// Current sample counts towards the averages
+    gated: bool,
+
+    average_alpha: f32,
+    trim_glide: f32,
+}
+
+/// Stage may be trimmed back to unity gain
+fn trimmable(stage: GuardStage) -> bool {
+    !matches!(
+        stage,
+        GuardStage::MicAlign
+            | GuardStage::SpeechHpf
+            | GuardStage::Leveler
+            | GuardStage::RoomTone
+            | GuardStage::ComfortNoise
+            | GuardStage::DeadAir
+            | GuardStage::Limiter
+    )
+}
+
+impl GainAudit {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            in_pow: [0.0; GuardStage::COUNT],
+            out_pow: [0.0; GuardStage::COUNT],
+            trim: [1.0; GuardStage::COUNT],
+            trim_target: [1.0; GuardStage::COUNT],
+            prev_pow: 0.0,
+            gated: false,
+            average_alpha: 1.0 - (-1.0 / (AVERAGE_SEC * sample_rate)).exp(),
+            trim_glide: 1.0 - (-1000.0 / (TRIM_GLIDE_MS * sample_rate)).exp(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.in_pow = [0.0; GuardStage::COUNT];
+        self.out_pow = [0.0; GuardStage::COUNT];
+        self.trim = [1.0; GuardStage::COUNT];
+        self.trim_target = [1.0; GuardStage::COUNT];
+        self.prev_pow = 0.0;
+        self.gated = false;
+    }
+
+    /// Long-term gain of a stage during speech (dB, 0 until measured)
+    pub fn gain_db(&self, stage: GuardStage) -> f32 {
+        let i = stage as usize;
+        if self.in_pow[i] < MIN_POWER {
+            return 0.0;
+        }
+        10.0 * (self.out_pow[i].max(MIN_POWER * 1e-3) / self.in_pow[i]).log10()
+    }
+
+    /// Refresh the trim targets (all unity when `auto_trim` is off).
+    /// Call once per buffer.
+    pub fn update_trims(&mut self, auto_trim: bool) {
+        for stage in GuardStage::ALL {
+            self.trim_target[stage as usize] = if auto_trim && trimmable(stage) {
+                db_to_lin(-self.gain_db(stage).clamp(-MAX_TRIM_DB, MAX_TRIM_DB))
+            } else {
+                1.0
+            };
+        }
+    }
+
+    /// Call once per sample with the chain input, before the first `tap()`
+    #[inline]
+    pub fn begin_sample(&mut self, left: f32, right: f32, speech_confidence: f32) {
+        self.prev_pow = 0.5 * (left * left + right * right);
+        self.gated = speech_confidence >= SPEECH_GATE;
+    }
+
+    /// Record a stage's output and return it with the stage's trim applied
+    #[inline]
+    pub fn tap(&mut self, stage: GuardStage, left: f32, right: f32) -> (f32, f32) {
+        let i = stage as usize;
+        let pow = 0.5 * (left * left + right * right);
+        if self.gated {
+            let a = self.average_alpha;
+            self.in_pow[i] += (self.prev_pow - self.in_pow[i]) * a;
+            self.out_pow[i] += (pow - self.out_pow[i]) * a;
+        }
+
+        let target = self.trim_target[i];
+        let trim = &mut self.trim[i];
+        if *trim != target {
+            *trim += (target - *trim) * self.trim_glide;
+            if (*trim - target).abs() < 1e-6 {
+                *trim = target;
+            }
+        }
+        let g = *trim;
+        self.prev_pow = pow * g * g;
+        if g == 1.0 {
+            (left, right)
+        } else {
+            (left * g, right * g)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Run a constant signal through a +6 dB Proximity and a -6 dB Limiter
+    fn run(audit: &mut GainAudit, seconds: f32, auto_trim: bool, speech: f32) -> f32 {
+        let mut out = 0.0;
+        for i in 0..(seconds * SR) as usize {
+            if i % 256 == 0 {
+                audit.update_trims(auto_trim);
+            }
+            let x = if i % 2 == 0 { 0.1 } else { -0.1 };
+            audit.begin_sample(x, x, speech);
+            let (l, r) = audit.tap(GuardStage::Proximity, 2.0 * x, 2.0 * x);
+            let (l, _) = audit.tap(GuardStage::Limiter, 0.5 * l, 0.5 * r);
+            out = l.abs();
+        }
+        out
+    }
+
+    #[test]
+    fn test_reports_stage_gain() {
+        let mut audit = GainAudit::new(SR);
+        run(&mut audit, 10.0, false, 1.0);
+        assert!((audit.gain_db(GuardStage::Proximity) - 6.02).abs() < 0.1);
+        assert!((audit.gain_db(GuardStage::Limiter) + 6.02).abs() < 0.1);
+        assert_eq!(audit.gain_db(GuardStage::Clarity), 0.0);
+    }
+
+    #[test]
+    fn test_auto_trim_removes_static_offset() {
+        let mut audit = GainAudit::new(SR);
+        let out = run(&mut audit, 20.0, true, 1.0);
+
+        // Proximity trimmed to unity; the Limiter's cut is its job and stays
+        assert!((out - 0.05).abs() < 0.005, "out {out}");
+        assert!(audit.gain_db(GuardStage::Limiter) < -5.9);
+    }
+
+    #[test]
+    fn test_ignores_pauses() {
+        let mut audit = GainAudit::new(SR);
+        run(&mut audit, 5.0, true, 0.0);
+        assert_eq!(audit.gain_db(GuardStage::Proximity), 0.0);
+    }
+}
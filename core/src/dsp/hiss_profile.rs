@@ -0,0 +1,300 @@
+//! Hiss Profile Analyzer
+//!
+//! Locates where the noise floor's hiss shelf starts (preamp hiss rising
+//! out of the room noise at 6 kHz on one interface, 10 kHz on another) and
+//! where rumble ends, so `HissRumble` puts its shelf and high-pass corners on
+//! the actual noise instead of fixed guesses.
+//!
+//! # Design Notes
+//! - A bank of band filters on the input is averaged in pauses only, so what
+//!   is measured is the noise floor, not the voice
+//! - Hiss is white: its energy per Hz is flat. Counting down from the top,
+//!   the hiss shelf reaches as low as the energy per Hz stays within
+//!   `PLATEAU_DB` of the top bands; below that the room noise takes over
+//! - Room noise is roughly pink: its energy per band is flat. Rumble is the
+//!   low bands standing `RUMBLE_EXCESS_DB` over the band just below the
+//!   voice; the high-pass corner goes half an octave above the highest one
+//! - Nothing found (too little pause, no plateau, no rumble) = `None`, and
+//!   `HissRumble` keeps its default corners
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use super::biquad::Biquad;
+use super::utils::DB_EPS;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Hiss band centers (Hz, third octaves)
+const HISS_CENTERS_HZ: [f32; 7] = [3150.0, 4000.0, 5000.0, 6300.0, 8000.0, 10000.0, 12500.0];
+/// Top bands that define the hiss plateau
+const PLATEAU_BANDS: usize = 2;
+/// Largest distance from the plateau (energy per Hz) still counted as hiss
+const PLATEAU_DB: f32 = 3.0;
+/// Hiss shelf corner range (Hz)
+const HISS_MIN_HZ: f32 = 4000.0;
+const HISS_MAX_HZ: f32 = 12000.0;
+
+/// Rumble band centers (Hz, half octaves)
+const RUMBLE_CENTERS_HZ: [f32; 6] = [25.0, 35.0, 50.0, 70.0, 100.0, 140.0];
+/// Band just below the voice the rumble bands are compared with (Hz)
+const REFERENCE_HZ: f32 = 250.0;
+/// Excess over the reference band that marks a rumble band
+const RUMBLE_EXCESS_DB: f32 = 6.0;
+/// High-pass corner relative to the highest rumble band (half an octave)
+const RUMBLE_CORNER_SCALE: f32 = std::f32::consts::SQRT_2;
+/// High-pass corner range (Hz)
+const RUMBLE_MIN_HZ: f32 = 40.0;
+const RUMBLE_MAX_HZ: f32 = 120.0;
+
+const BAND_Q: f32 = 3.0;
+/// Cascaded band-pass sections per band (steep skirts)
+const BAND_STAGES: usize = 3;
+/// Band energy averaging (seconds of pause)
+const AVERAGE_TAU_SEC: f32 = 2.0;
+/// Pause needed before the first decision (seconds)
+const MIN_PAUSE_SEC: f32 = 1.0;
+/// Speech confidence below which the input counts as pause
+const PAUSE_CONFIDENCE: f32 = 0.3;
+/// Samples between corner decisions
+const DECISION_INTERVAL: u32 = 8192;
+/// Ignore bands quieter than this (mean square)
+const MIN_BAND_ENERGY: f32 = 1e-12;
+
+// =============================================================================
+// Analyzer
+// =============================================================================
+
+type Band = [Biquad; BAND_STAGES];
+
+fn band(center_hz: f32, sample_rate: f32) -> Band {
+    let mut band = [Biquad::new(); BAND_STAGES];
+    for f in band.iter_mut() {
+        f.update_bandpass(center_hz, BAND_Q, sample_rate);
+    }
+    band
+}
+
+pub struct HissProfile {
+    hiss_bands: [Band; HISS_CENTERS_HZ.len()],
+    hiss_energy: [f32; HISS_CENTERS_HZ.len()],
+    /// Number of hiss bands below the safe Nyquist limit
+    hiss_band_count: usize,
+    rumble_bands: [Band; RUMBLE_CENTERS_HZ.len()],
+    rumble_energy: [f32; RUMBLE_CENTERS_HZ.len()],
+    reference_band: Band,
+    reference_energy: f32,
+
+    alpha: f32,
+    pause_samples: u32,
+    min_pause_samples: u32,
+    decision_countdown: u32,
+
+    hiss_onset_hz: Option<f32>,
+    rumble_cutoff_hz: Option<f32>,
+}
+
+impl HissProfile {
+    pub fn new(sample_rate: f32) -> Self {
+        // Bands too close to Nyquist stay unused
+        let hiss_band_count = HISS_CENTERS_HZ
+            .iter()
+            .filter(|&&c| c < 0.42 * sample_rate)
+            .count();
+
+        Self {
+            hiss_bands: HISS_CENTERS_HZ.map(|c| band(c.min(0.42 * sample_rate), sample_rate)),
+            hiss_energy: [0.0; HISS_CENTERS_HZ.len()],
+            hiss_band_count,
+            rumble_bands: RUMBLE_CENTERS_HZ.map(|c| band(c, sample_rate)),
+            rumble_energy: [0.0; RUMBLE_CENTERS_HZ.len()],
+            reference_band: band(REFERENCE_HZ, sample_rate),
+            reference_energy: 0.0,
+            alpha: 1.0 - (-1.0 / (AVERAGE_TAU_SEC * sample_rate)).exp(),
+            pause_samples: 0,
+            min_pause_samples: (MIN_PAUSE_SEC * sample_rate) as u32,
+            decision_countdown: DECISION_INTERVAL,
+            hiss_onset_hz: None,
+            rumble_cutoff_hz: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for f in self
+            .hiss_bands
+            .iter_mut()
+            .chain(self.rumble_bands.iter_mut())
+            .chain(std::iter::once(&mut self.reference_band))
+            .flatten()
+        {
+            f.reset_state();
+        }
+        self.hiss_energy = [0.0; HISS_CENTERS_HZ.len()];
+        self.rumble_energy = [0.0; RUMBLE_CENTERS_HZ.len()];
+        self.reference_energy = 0.0;
+        self.pause_samples = 0;
+        self.decision_countdown = DECISION_INTERVAL;
+        self.hiss_onset_hz = None;
+        self.rumble_cutoff_hz = None;
+    }
+
+    /// Where the hiss shelf starts (Hz), `None` until a plateau is found
+    pub fn hiss_onset_hz(&self) -> Option<f32> {
+        self.hiss_onset_hz
+    }
+
+    /// High-pass corner that clears the rumble (Hz), `None` without rumble
+    pub fn rumble_cutoff_hz(&self) -> Option<f32> {
+        self.rumble_cutoff_hz
+    }
+
+    pub fn process(&mut self, left: f32, right: f32, speech_conf: f32) {
+        if speech_conf < PAUSE_CONFIDENCE {
+            let mid = 0.5 * (left + right);
+            let alpha = self.alpha;
+            let average = |band: &mut Band, energy: &mut f32| {
+                let y = band.iter_mut().fold(mid, |x, f| f.process(x));
+                *energy += (y * y - *energy) * alpha;
+            };
+            for k in 0..self.hiss_band_count {
+                average(&mut self.hiss_bands[k], &mut self.hiss_energy[k]);
+            }
+            for (band, energy) in self
+                .rumble_bands
+                .iter_mut()
+                .zip(self.rumble_energy.iter_mut())
+            {
+                average(band, energy);
+            }
+            average(&mut self.reference_band, &mut self.reference_energy);
+            self.pause_samples = self.pause_samples.saturating_add(1);
+        }
+
+        self.decision_countdown = self.decision_countdown.saturating_sub(1);
+        if self.decision_countdown == 0 {
+            self.decision_countdown = DECISION_INTERVAL;
+            if self.pause_samples >= self.min_pause_samples {
+                self.hiss_onset_hz = self.find_hiss_onset();
+                self.rumble_cutoff_hz = self.find_rumble_cutoff();
+            }
+        }
+    }
+
+    /// Lowest band of the flat (white) plateau at the top of the spectrum
+    fn find_hiss_onset(&self) -> Option<f32> {
+        let n = self.hiss_band_count;
+        if n < PLATEAU_BANDS || self.hiss_energy[..n].iter().any(|&e| e < MIN_BAND_ENERGY) {
+            return None;
+        }
+        // Constant-Q bands: energy per Hz is band energy over the center
+        let density_db =
+            |k: usize| 10.0 * (self.hiss_energy[k] / HISS_CENTERS_HZ[k] + DB_EPS).log10();
+
+        let top = n - PLATEAU_BANDS..n;
+        let (lo, hi) = top
+            .clone()
+            .map(density_db)
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), d| {
+                (lo.min(d), hi.max(d))
+            });
+        if hi - lo > PLATEAU_DB {
+            return None;
+        }
+        let plateau = 0.5 * (lo + hi);
+
+        let mut onset = top.start;
+        while onset > 0 && (density_db(onset - 1) - plateau).abs() <= PLATEAU_DB {
+            onset -= 1;
+        }
+        Some(HISS_CENTERS_HZ[onset].clamp(HISS_MIN_HZ, HISS_MAX_HZ))
+    }
+
+    /// Half an octave above the highest band standing out over the reference
+    fn find_rumble_cutoff(&self) -> Option<f32> {
+        if self.reference_energy < MIN_BAND_ENERGY {
+            return None;
+        }
+        let excess = 10.0f32.powf(RUMBLE_EXCESS_DB / 10.0) * self.reference_energy;
+        RUMBLE_CENTERS_HZ
+            .iter()
+            .zip(self.rumble_energy.iter())
+            .rev()
+            .find(|(_, &e)| e > excess)
+            .map(|(&c, _)| (c * RUMBLE_CORNER_SCALE).clamp(RUMBLE_MIN_HZ, RUMBLE_MAX_HZ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::{TestSignalGenerator, TestSignalKind};
+
+    const SR: f32 = 48000.0;
+
+    /// Deterministic white noise
+    struct White(u32);
+
+    impl White {
+        fn next(&mut self) -> f32 {
+            self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (self.0 >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0
+        }
+    }
+
+    /// Analyze `seconds` of `signal` as pause (or as speech)
+    fn analyze(mut signal: impl FnMut(usize) -> f32, speech_conf: f32) -> HissProfile {
+        let mut profile = HissProfile::new(SR);
+        for i in 0..(4.0 * SR) as usize {
+            let x = signal(i);
+            profile.process(x, x, speech_conf);
+        }
+        profile
+    }
+
+    #[test]
+    fn test_white_hiss_reaches_down_to_the_lowest_corner() {
+        let mut white = White(1);
+        let profile = analyze(|_| 0.01 * white.next(), 0.0);
+        assert_eq!(profile.hiss_onset_hz(), Some(HISS_MIN_HZ));
+    }
+
+    #[test]
+    fn test_room_noise_pushes_the_hiss_onset_up() {
+        let mut white = White(1);
+        let mut pink = TestSignalGenerator::new(SR);
+        let profile = analyze(
+            |_| 0.3 * pink.next(TestSignalKind::PinkNoise) + 0.003 * white.next(),
+            0.0,
+        );
+        let onset = profile.hiss_onset_hz().expect("hiss plateau");
+        assert!(onset > 5000.0, "onset {onset}");
+    }
+
+    #[test]
+    fn test_rumble_sets_the_high_pass_corner() {
+        let mut pink = TestSignalGenerator::new(SR);
+        let clean = analyze(|_| 0.1 * pink.next(TestSignalKind::PinkNoise), 0.0);
+        assert_eq!(clean.rumble_cutoff_hz(), None);
+
+        let mut pink = TestSignalGenerator::new(SR);
+        let rumble = analyze(
+            |i| {
+                0.1 * pink.next(TestSignalKind::PinkNoise)
+                    + 0.2 * (std::f32::consts::TAU * 50.0 * i as f32 / SR).sin()
+            },
+            0.0,
+        );
+        let cutoff = rumble.rumble_cutoff_hz().expect("rumble");
+        assert!((60.0..=100.0).contains(&cutoff), "cutoff {cutoff}");
+    }
+
+    #[test]
+    fn test_speech_is_not_measured() {
+        let mut white = White(1);
+        let profile = analyze(|_| 0.01 * white.next(), 1.0);
+        assert_eq!(profile.hiss_onset_hz(), None);
+        assert_eq!(profile.rumble_cutoff_hz(), None);
+    }
+}
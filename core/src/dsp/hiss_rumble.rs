@@ -7,6 +7,10 @@
 //! - HISS: applies a high-frequency shelf cut (8kHz, up to -24dB), relaxed during speech
 //!
 //! This guarantees hiss/rumble reduction even during silence.
+//!
+//! Both corners follow `HissProfile` when it finds them (`set_corners()`):
+//! the shelf starts where the hiss does, and the high-pass rises only as far
+//! as the rumble reaches. Without a reading the defaults above apply.
 
 use crate::dsp::biquad::Biquad;
 use crate::dsp::speech_confidence::SpeechSidechain;
@@ -28,16 +32,24 @@ const SMOOTH_COEFF: f32 = 0.02; // ~50 ms time constant
 // -----------------------------
 
 pub struct HissRumble {
-    // Filters
-    rumble_hpf: Biquad,
-    hiss_shelf: Biquad,
+    // Filters, one per channel (same coefficients)
+    rumble_hpf_l: Biquad,
+    rumble_hpf_r: Biquad,
+    hiss_shelf_l: Biquad,
+    hiss_shelf_r: Biquad,
 
     sample_rate: f32,
 
+    // Detected corners (defaults until measured)
+    rumble_max_hz: f32,
+    hiss_shelf_hz_target: f32,
+
     // Smoothed state
     rumble_hz_current: f32,
     rumble_hz_target: f32,
 
+    hiss_shelf_hz_current: f32,
+
     hiss_db_current: f32,
     hiss_db_target: f32,
 
@@ -54,13 +66,20 @@ impl HissRumble {
         rumble_hpf.update_hpf(RUMBLE_MIN_HZ, 0.707, sample_rate);
 
         Self {
-            rumble_hpf,
-            hiss_shelf,
+            rumble_hpf_l: rumble_hpf,
+            rumble_hpf_r: rumble_hpf,
+            hiss_shelf_l: hiss_shelf,
+            hiss_shelf_r: hiss_shelf,
             sample_rate,
 
+            rumble_max_hz: RUMBLE_MAX_HZ,
+            hiss_shelf_hz_target: HISS_SHELF_HZ,
+
             rumble_hz_current: RUMBLE_MIN_HZ,
             rumble_hz_target: RUMBLE_MIN_HZ,
 
+            hiss_shelf_hz_current: HISS_SHELF_HZ,
+
             hiss_db_current: 0.0,
             hiss_db_target: 0.0,
 
@@ -68,6 +87,14 @@ impl HissRumble {
         }
     }
 
+    /// Corners found by `HissProfile` (Hz); `None` keeps the default
+    pub fn set_corners(&mut self, hiss_onset_hz: Option<f32>, rumble_cutoff_hz: Option<f32>) {
+        self.hiss_shelf_hz_target = hiss_onset_hz.unwrap_or(HISS_SHELF_HZ);
+        self.rumble_max_hz = rumble_cutoff_hz
+            .unwrap_or(RUMBLE_MAX_HZ)
+            .clamp(RUMBLE_MIN_HZ, RUMBLE_MAX_HZ);
+    }
+
     #[inline]
     pub fn process(
         &mut self,
@@ -81,9 +108,9 @@ impl HissRumble {
         // Targets
         // -----------------------------
 
-        // Rumble = raise HPF cutoff (20Hz -> 120Hz)
+        // Rumble = raise HPF cutoff (20Hz -> detected cutoff, 120Hz at most)
         self.rumble_hz_target =
-            RUMBLE_MIN_HZ + (RUMBLE_MAX_HZ - RUMBLE_MIN_HZ) * rumble_amt.clamp(0.0, 1.0);
+            RUMBLE_MIN_HZ + (self.rumble_max_hz - RUMBLE_MIN_HZ) * rumble_amt.clamp(0.0, 1.0);
 
         // Hiss = HF shelf cut (0 -> -24dB)
         // Relax during speech to protect sibilance
@@ -98,20 +125,26 @@ impl HissRumble {
 
         self.hiss_db_current += (self.hiss_db_target - self.hiss_db_current) * SMOOTH_COEFF;
 
+        self.hiss_shelf_hz_current +=
+            (self.hiss_shelf_hz_target - self.hiss_shelf_hz_current) * SMOOTH_COEFF;
+
         // -----------------------------
         // Update filters (throttled)
         // -----------------------------
 
         if self.update_counter & 31 == 0 {
-            self.rumble_hpf
-                .update_hpf(self.rumble_hz_current, 0.707, self.sample_rate);
-
-            self.hiss_shelf.update_high_shelf(
-                HISS_SHELF_HZ,
-                0.707,
-                self.hiss_db_current,
-                self.sample_rate,
-            );
+            for hpf in [&mut self.rumble_hpf_l, &mut self.rumble_hpf_r] {
+                hpf.update_hpf(self.rumble_hz_current, 0.707, self.sample_rate);
+            }
+
+            for shelf in [&mut self.hiss_shelf_l, &mut self.hiss_shelf_r] {
+                shelf.update_high_shelf(
+                    self.hiss_shelf_hz_current,
+                    0.707,
+                    self.hiss_db_current,
+                    self.sample_rate,
+                );
+            }
         }
         self.update_counter = self.update_counter.wrapping_add(1);
 
@@ -119,20 +152,27 @@ impl HissRumble {
         // Process audio
         // -----------------------------
 
-        let l = self.hiss_shelf.process(self.rumble_hpf.process(input_l));
-        let r = self.hiss_shelf.process(self.rumble_hpf.process(input_r));
+        let l = self
+            .hiss_shelf_l
+            .process(self.rumble_hpf_l.process(input_l));
+        let r = self
+            .hiss_shelf_r
+            .process(self.rumble_hpf_r.process(input_r));
 
         (l, r)
     }
 
     pub fn reset(&mut self) {
-        self.rumble_hpf.reset();
-        self.hiss_shelf.reset();
+        self.rumble_hpf_l.reset();
+        self.rumble_hpf_r.reset();
+        self.hiss_shelf_l.reset();
+        self.hiss_shelf_r.reset();
 
         self.rumble_hz_current = RUMBLE_MIN_HZ;
         self.rumble_hz_target = RUMBLE_MIN_HZ;
         self.hiss_db_current = 0.0;
         self.hiss_db_target = 0.0;
+        self.hiss_shelf_hz_current = self.hiss_shelf_hz_target;
 
         self.update_counter = 0;
     }
@@ -161,4 +201,8 @@ impl HissRumble {
     pub fn get_rumble_hz_current(&self) -> f32 {
         self.current_rumble_hz()
     }
+
+    pub fn current_hiss_shelf_hz(&self) -> f32 {
+        self.hiss_shelf_hz_current
+    }
 }
@@ -0,0 +1,181 @@
+//! Input Utility (balance, channel swap, polarity)
+//!
+//! Fixes badly delivered stereo before anything else looks at it: a level
+//! trim between the channels, swapped L/R and per-channel polarity flips.
+//!
+//! # Purpose
+//! Interview recorders often hand over one hot and one quiet channel, the
+//! guest on the "host" side, or one mic wired out of phase. Without these
+//! controls the user needs a separate utility plugin ahead of the cleaner.
+//!
+//! # Design Notes
+//! - Each output channel is `a·L + b·R`; swap and polarity only change the
+//!   targets of the four coefficients
+//! - Coefficients glide over a few milliseconds, so flipping polarity or
+//!   swapping channels mid-take crossfades instead of clicking
+//! - Exact pass-through (no multiply) when the controls are at rest
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use super::utils::{db_to_lin, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Coefficient glide time
+const GLIDE_MS: f32 = 5.0;
+
+/// Below this distance from the target the coefficients snap to it. Large
+/// enough that the glide reaches it: near 1.0 a one-pole step smaller than
+/// half an f32 ulp rounds away, which stalls it about 7e-6 short.
+const SNAP_EPS: f32 = 1e-4;
+
+// =============================================================================
+// Settings
+// =============================================================================
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InputUtilitySettings {
+    /// Balance trim in dB: positive raises the right channel and lowers the
+    /// left by half the amount each
+    pub balance_db: f32,
+    /// Exchange left and right
+    pub swap: bool,
+    /// Flip the polarity of the left / right input (before the swap)
+    pub invert_left: bool,
+    pub invert_right: bool,
+}
+
+impl InputUtilitySettings {
+    /// `[l_from_l, l_from_r, r_from_l, r_from_r]`
+    fn matrix(&self) -> [f32; 4] {
+        let sign = |invert: bool| if invert { -1.0 } else { 1.0 };
+        let gain_l = db_to_lin(-0.5 * self.balance_db) * sign(self.invert_left);
+        let gain_r = db_to_lin(0.5 * self.balance_db) * sign(self.invert_right);
+        if self.swap {
+            [0.0, gain_r, gain_l, 0.0]
+        } else {
+            [gain_l, 0.0, 0.0, gain_r]
+        }
+    }
+}
+
+// =============================================================================
+// Processor
+// =============================================================================
+
+pub struct InputUtility {
+    coeffs: [f32; 4],
+    target: [f32; 4],
+    glide: f32,
+    at_rest: bool,
+}
+
+impl InputUtility {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            coeffs: [1.0, 0.0, 0.0, 1.0],
+            target: [1.0, 0.0, 0.0, 1.0],
+            glide: time_constant_coeff(GLIDE_MS, sample_rate),
+            at_rest: true,
+        }
+    }
+
+    /// Jump straight to the current settings (no glide)
+    pub fn reset(&mut self) {
+        self.coeffs = self.target;
+    }
+
+    /// Update the settings; the coefficients glide to them
+    pub fn set(&mut self, settings: InputUtilitySettings) {
+        self.target = settings.matrix();
+        self.at_rest = settings == InputUtilitySettings::default();
+    }
+
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        if self.coeffs == self.target {
+            if self.at_rest {
+                return (left, right);
+            }
+        } else {
+            let g = self.glide;
+            for (c, &t) in self.coeffs.iter_mut().zip(&self.target) {
+                *c = g * *c + (1.0 - g) * t;
+                if (*c - t).abs() < SNAP_EPS {
+                    *c = t;
+                }
+            }
+        }
+        let [ll, lr, rl, rr] = self.coeffs;
+        (ll * left + lr * right, rl * left + rr * right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    fn settled(settings: InputUtilitySettings, l: f32, r: f32) -> (f32, f32) {
+        let mut u = InputUtility::new(SR);
+        u.set(settings);
+        u.reset();
+        u.process(l, r)
+    }
+
+    #[test]
+    fn test_pass_through_at_rest() {
+        assert_eq!(
+            settled(InputUtilitySettings::default(), 0.3, -0.7),
+            (0.3, -0.7)
+        );
+    }
+
+    #[test]
+    fn test_swap_and_polarity() {
+        let swapped = InputUtilitySettings {
+            swap: true,
+            invert_left: true,
+            ..Default::default()
+        };
+        // Left is flipped first, then lands on the right output
+        assert_eq!(settled(swapped, 0.3, -0.7), (-0.7, -0.3));
+    }
+
+    #[test]
+    fn test_balance_splits_trim() {
+        let (l, r) = settled(
+            InputUtilitySettings {
+                balance_db: 6.0,
+                ..Default::default()
+            },
+            0.5,
+            0.5,
+        );
+        assert!((20.0 * (r / l).log10() - 6.0).abs() < 1e-3);
+        assert!((20.0 * (r / 0.5).log10() - 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_polarity_flip_glides() {
+        let mut u = InputUtility::new(SR);
+        u.process(1.0, 1.0);
+        u.set(InputUtilitySettings {
+            invert_right: true,
+            ..Default::default()
+        });
+
+        // No jump from +1 to -1 in one sample
+        let mut prev = 1.0;
+        for _ in 0..(SR * 0.1) as usize {
+            let (_, r) = u.process(1.0, 1.0);
+            assert!((r - prev).abs() < 0.01);
+            prev = r;
+        }
+        assert_eq!(prev, -1.0);
+    }
+}
@@ -0,0 +1,287 @@
+//! Output Limiter
+//!
+//! True peak safety limiter.
+//! Designed to be completely transparent and inert unless the signal
+//! exceeds the ceiling. No loudness riding, no pumping.
+//!
+//! The ceiling defaults to about -0.18 dBFS; a Final Output preset moves it
+//! to its true-peak target (see `set_ceiling_db()`).
+//!
+//! Release is two-stage (see `LimiterRelease`): a fast stage recovers from
+//! short peaks and a slow stage, which only charges under sustained
+//! limiting, holds the gain steady through dense passages. The applied gain
+//! is the lower of the two, so release follows the program: quick after a
+//! plosive, slow after a loud sentence. The default uses one 400 ms release
+//! for both.
+
+use crate::dsp::utils::{db_to_lin, lin_to_db, time_constant_coeff, DB_EPS};
+
+/// Default ceiling (linear, ~ -0.18 dBTP)
+const DEFAULT_CEILING: f32 = 0.98;
+/// Lowest ceiling `set_ceiling_db()` accepts
+const MIN_CEILING_DB: f32 = -30.0;
+/// Gain reduction attack (ms)
+const ATTACK_MS: f32 = 0.5;
+
+/// Release shape of the limiter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LimiterRelease {
+    /// Recovery after short peaks (ms)
+    pub fast_ms: f32,
+    /// Recovery after sustained limiting (ms)
+    pub slow_ms: f32,
+    /// How long limiting has to last before the slow stage takes over (ms)
+    pub sustain_ms: f32,
+}
+
+impl LimiterRelease {
+    /// One slow, even release (the default)
+    pub const TRANSPARENT: Self = Self {
+        fast_ms: 400.0,
+        slow_ms: 400.0,
+        sustain_ms: ATTACK_MS,
+    };
+    /// Quick recovery from isolated peaks, slow after dense passages
+    /// (spoken word)
+    pub const SMOOTH: Self = Self {
+        fast_ms: 80.0,
+        slow_ms: 900.0,
+        sustain_ms: 150.0,
+    };
+    /// Fast release in both stages for a denser, louder result
+    pub const AGGRESSIVE: Self = Self {
+        fast_ms: 40.0,
+        slow_ms: 200.0,
+        sustain_ms: 50.0,
+    };
+}
+
+pub struct LinkedLimiter {
+    // Peak envelope (linear, stereo linked)
+    peak_env_l: f32,
+    peak_env_r: f32,
+
+    // Release stages and the applied gain (the lower of the two)
+    gain_fast: f32,
+    gain_slow: f32,
+    gain_smooth: f32,
+    gain_reduction_db: f32,
+
+    ceiling_db: f32,
+    sample_rate: f32,
+
+    release: LimiterRelease,
+    fast_rel: f32,
+    slow_atk: f32,
+    slow_rel: f32,
+}
+
+impl LinkedLimiter {
+    pub fn new(sr: f32) -> Self {
+        let mut lim = Self {
+            peak_env_l: 0.0,
+            peak_env_r: 0.0,
+            gain_fast: 1.0,
+            gain_slow: 1.0,
+            gain_smooth: 1.0,
+            gain_reduction_db: 0.0,
+            ceiling_db: lin_to_db(DEFAULT_CEILING),
+            sample_rate: sr,
+            release: LimiterRelease::TRANSPARENT,
+            fast_rel: 0.0,
+            slow_atk: 0.0,
+            slow_rel: 0.0,
+        };
+        lim.update_release_coeffs();
+        lim
+    }
+
+    /// Set the release shape; takes effect on the next sample without a jump
+    pub fn set_release(&mut self, release: LimiterRelease) {
+        if release != self.release {
+            self.release = release;
+            self.update_release_coeffs();
+        }
+    }
+
+    fn update_release_coeffs(&mut self) {
+        self.fast_rel = self.coeff(self.release.fast_ms);
+        self.slow_atk = self.coeff(self.release.sustain_ms);
+        self.slow_rel = self.coeff(self.release.slow_ms);
+    }
+
+    /// Set the ceiling at the limiter input (dBFS). `None` restores the
+    /// default; a ceiling above the default is clamped to it.
+    pub fn set_ceiling_db(&mut self, ceiling_db: Option<f32>) {
+        let default_db = lin_to_db(DEFAULT_CEILING);
+        self.ceiling_db = match ceiling_db {
+            Some(db) if db.is_finite() => db.clamp(MIN_CEILING_DB, default_db),
+            _ => default_db,
+        };
+    }
+
+    pub fn ceiling_db(&self) -> f32 {
+        self.ceiling_db
+    }
+
+    #[inline]
+    fn coeff(&self, ms: f32) -> f32 {
+        time_constant_coeff(ms, self.sample_rate)
+    }
+
+    pub fn compute_gain(&mut self, input_l: f32, input_r: f32) -> f32 {
+        let abs_l = input_l.abs();
+        let abs_r = input_r.abs();
+
+        // --------------------------------------------------
+        // 1. True peak detector (fast attack, gentle release)
+        // --------------------------------------------------
+        let peak_atk = self.coeff(0.1); // extremely fast catch
+        let peak_rel = self.coeff(60.0); // smooth envelope decay
+
+        self.peak_env_l = if abs_l > self.peak_env_l {
+            peak_atk * self.peak_env_l + (1.0 - peak_atk) * abs_l
+        } else {
+            peak_rel * self.peak_env_l + (1.0 - peak_rel) * abs_l
+        };
+
+        self.peak_env_r = if abs_r > self.peak_env_r {
+            peak_atk * self.peak_env_r + (1.0 - peak_atk) * abs_r
+        } else {
+            peak_rel * self.peak_env_r + (1.0 - peak_rel) * abs_r
+        };
+
+        let peak = self.peak_env_l.max(self.peak_env_r).max(DB_EPS);
+
+        // --------------------------------------------------
+        // 2. Limiting curve (only engages above ceiling)
+        // --------------------------------------------------
+        let knee_db = 1.0;
+
+        let env_db = lin_to_db(peak);
+        let over_db = env_db - self.ceiling_db;
+
+        let target_gain = if over_db <= -knee_db * 0.5 {
+            1.0
+        } else if over_db >= knee_db * 0.5 {
+            db_to_lin(-over_db)
+        } else {
+            // soft knee
+            let x = over_db + knee_db * 0.5;
+            let y = (x * x) / (2.0 * knee_db);
+            db_to_lin(-y)
+        };
+
+        // --------------------------------------------------
+        // 3. Gain smoothing (limiter-style)
+        // --------------------------------------------------
+        let atk = self.coeff(ATTACK_MS); // fast clamp
+
+        // Fast stage: engages quickly, recovers at the fast release
+        let c = if target_gain < self.gain_fast {
+            atk
+        } else {
+            self.fast_rel
+        };
+        self.gain_fast = c * self.gain_fast + (1.0 - c) * target_gain;
+
+        // Slow stage: only follows reduction that lasts
+        let c = if target_gain < self.gain_slow {
+            self.slow_atk
+        } else {
+            self.slow_rel
+        };
+        self.gain_slow = c * self.gain_slow + (1.0 - c) * target_gain;
+
+        self.gain_smooth = self.gain_fast.min(self.gain_slow);
+
+        self.gain_reduction_db = -lin_to_db(self.gain_smooth.max(DB_EPS));
+
+        self.gain_smooth
+    }
+
+    pub fn get_gain_reduction_db(&self) -> f32 {
+        self.gain_reduction_db
+    }
+
+    pub fn reset(&mut self) {
+        self.peak_env_l = 0.0;
+        self.peak_env_r = 0.0;
+        self.gain_fast = 1.0;
+        self.gain_slow = 1.0;
+        self.gain_smooth = 1.0;
+        self.gain_reduction_db = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    fn peak_after(lim: &mut LinkedLimiter, amp: f32) -> f32 {
+        let mut peak = 0.0f32;
+        for i in 0..SR as usize {
+            let x = amp * (std::f32::consts::TAU * 200.0 * i as f32 / SR).sin();
+            let y = x * lim.compute_gain(x, x);
+            if i > SR as usize / 2 {
+                peak = peak.max(y.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_ceiling_follows_setting() {
+        let mut lim = LinkedLimiter::new(SR);
+        lim.set_ceiling_db(Some(-6.0));
+        let peak_db = lin_to_db(peak_after(&mut lim, 1.0));
+        assert!(peak_db < -5.7 && peak_db > -7.0, "peak {peak_db}");
+    }
+
+    #[test]
+    fn test_ceiling_default_and_clamp() {
+        let mut lim = LinkedLimiter::new(SR);
+        let default_db = lim.ceiling_db();
+        lim.set_ceiling_db(Some(3.0));
+        assert_eq!(lim.ceiling_db(), default_db);
+        lim.set_ceiling_db(Some(-6.0));
+        lim.set_ceiling_db(None);
+        assert_eq!(lim.ceiling_db(), default_db);
+
+        // Below the ceiling the limiter stays inert
+        let peak = peak_after(&mut lim, 0.5);
+        assert!((peak - 0.5).abs() < 1e-3, "peak {peak}");
+    }
+
+    /// Gain 150 ms after `over_ms` of a 200 Hz tone 6 dB over the ceiling,
+    /// followed by a quiet tone
+    fn gain_after(release: LimiterRelease, over_ms: f32) -> f32 {
+        let mut lim = LinkedLimiter::new(SR);
+        lim.set_release(release);
+        let over = (over_ms * 0.001 * SR) as usize;
+        let total = over + (0.15 * SR) as usize;
+        let mut gain = 1.0;
+        for i in 0..total {
+            let amp = if i < over { 2.0 } else { 0.1 };
+            let x = amp * (std::f32::consts::TAU * 200.0 * i as f32 / SR).sin();
+            gain = lim.compute_gain(x, x);
+        }
+        gain
+    }
+
+    #[test]
+    fn test_release_follows_program() {
+        // A short peak: Smooth recovers well ahead of the even release
+        let transparent = gain_after(LimiterRelease::TRANSPARENT, 20.0);
+        let smooth = gain_after(LimiterRelease::SMOOTH, 20.0);
+        assert!(smooth > transparent + 0.05, "{smooth} {transparent}");
+
+        // A long stretch over the ceiling: Smooth holds the gain down,
+        // Aggressive lets go
+        let smooth = gain_after(LimiterRelease::SMOOTH, 1000.0);
+        let aggressive = gain_after(LimiterRelease::AGGRESSIVE, 1000.0);
+        assert!(smooth < aggressive - 0.05, "{smooth} {aggressive}");
+    }
+}
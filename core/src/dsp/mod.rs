@@ -4,79 +4,156 @@
 //!
 //! ## Analysis (Sidechain)
 //! - [`speech_confidence`] - Speech vs noise/silence detection for automation
+//! - [`rt60`] - Blind room decay (RT60) estimation
+//! - [`speaker_learner`] - Long-term voice statistics for speaker profiles
+//! - [`dual_speaker`] - Two-speaker change detection for interview material
+//! - [`hiss_profile`] - Hiss shelf onset and rumble cutoff of the noise floor
+//! - [`music_detector`] - Sustained music (intros, stingers, beds) in the input
+//!
+//! ## Input Stage
+//! - [`input_utility`] - L/R balance trim, channel swap and polarity flips
+//! - [`mic_align`] - Dual-mic (lav + boom) offset and polarity alignment
+//! - [`linear_phase`] - Linear-phase FIR mode for the low cut and safety high-pass
 //!
 //! ## Early Processing Stage
 //! - [`early_reflection`] - Short-lag reflection suppression (micro-deverb)
 //! - [`speech_expander`] - Speech-aware downward expansion
+//! - [`transient_suppressor`] - Keyboard click / desk thump ducking in pauses
 //!
 //! ## Restoration Stage
 //! - [`denoiser`] - Spectral noise reduction with tone control
+//! - [`artifact_detector`] - Musical-noise metric behind the Artifact Guard
+//! - [`spectral_gate`] - Hysteresis/hold gate for the Rescue denoise engine
+//! - [`comfort_noise`] - Matched room-tone bed for denoised pauses
+//! - [`room_tone_bridge`] - Room-tone morph across edits between takes
+//! - [`dead_air`] - Dead-air totals and optional long-pause trim
 //! - [`deverber`] - Envelope-based reverb reduction (late reflections)
+//! - [`bandwidth_extend`] - Codec cutoff detection, high-band synthesis and smoothing
 //!
 //! ## Shaping Stage
 //! - [`proximity`] - Low-end shaping for "close mic" effect
+//! - [`de_harsh`] - Dynamic 2.5–5 kHz glare cut for budget condenser mics
 //! - [`clarity`] - High-frequency enhancement
 //!
 //! ## Dynamics Stage
 //! - [`de_esser`] - Sibilance detection and reduction
 //! - [`compressor`] - Stereo-linked leveling compression
+//! - [`parallel_compressor`] - Heavy leveler mixed under the main one (Density)
+//! - [`pump_guard`] - Backs the leveler off when pumping keeps repeating
 //! - [`spectral_guardrails`] - Safety limits for extreme settings
 //! - [`limiter`] - Output safety limiting
+//! - [`soft_clipper`] - Oversampled soft-clip ceiling (alternative final stage)
+//! - [`mono_compat`] - L/R correlation meter and mono-safe width correction
+//! - [`dither`] - TPDF dither and noise shaping for fixed-point delivery
 //!
 //! ## Utilities
 //! - [`biquad`] - Biquad filter implementations
+//! - [`dry_delay`] - Latency-matched dry signal for the reference output
+//! - [`numeric_guard`] - Per-stage NaN/Inf checks with source attribution
+//! - [`gain_audit`] - Per-stage long-term gain report and static-offset trim
 //! - [`control_slew`] - Control value slew limiting (artifact prevention)
+//! - [`scheduler`] - Cooldowns, intervals and ramps in milliseconds, independent of block size
+//! - [`test_signal`] - Pink noise, sweep and tone for calibration and tests
 //! - [`utils`] - Shared DSP utilities (see ARCHITECTURE.md)
 
+pub mod artifact_detector;
+pub mod bandwidth_extend;
 pub mod biquad;
 pub mod breath_reducer;
 pub mod clarity;
+pub mod comfort_noise;
 pub mod compressor;
 pub mod control_slew;
 pub mod de_esser;
+pub mod de_harsh;
+pub mod dead_air;
 pub mod denoiser;
 pub mod deverber;
+pub mod dither;
+pub mod dry_delay;
 pub mod dsp_denoiser;
+pub mod dual_speaker;
 pub mod early_reflection;
 pub mod envelope;
+pub mod gain_audit;
+pub mod hiss_profile;
 pub mod hiss_rumble;
+pub mod input_utility;
 pub mod limiter;
+pub mod linear_phase;
+pub mod mic_align;
+pub mod mono_compat;
+pub mod music_detector;
 pub mod noise_learn_remove;
+pub mod numeric_guard;
+pub mod parallel_compressor;
 pub mod pink_ref_bias;
 pub mod plosive_softener;
 pub mod post_noise_cleanup;
 pub mod profile_analyzer;
 pub mod proximity;
+pub mod pump_guard;
 pub mod recovery_stage;
+pub mod room_tone_bridge;
+pub mod rt60;
+pub mod scheduler;
+pub mod soft_clipper;
+pub mod speaker_learner;
+pub mod spectral_gate;
 pub mod spectral_guardrails;
 pub mod speech_confidence;
 pub mod speech_expander;
 pub mod speech_hpf;
+pub mod test_signal;
+pub mod transient_suppressor;
 pub mod utils;
 pub use noise_learn_remove::{NoiseLearnRemove, NoiseLearnRemoveConfig};
 
+pub use bandwidth_extend::BandwidthExtender;
 pub use biquad::Biquad;
 pub use breath_reducer::BreathReducer;
 pub use clarity::{Clarity, ClarityDetector};
+pub use comfort_noise::ComfortNoise;
 pub use compressor::LinkedCompressor;
-pub use control_slew::SpectralControlLimiters;
-pub use de_esser::{DeEsserBand, DeEsserDetector};
+pub use control_slew::{LimitedControls, SpectralControlLimiters};
+pub use de_esser::{DeEssCalibrator, DeEssEventCounter, DeEsserBand, DeEsserDetector};
+pub use de_harsh::DeHarsh;
+pub use dead_air::DeadAirDetector;
 pub use denoiser::{DenoiseConfig, StereoStreamingDenoiser};
 pub use deverber::StreamingDeverber;
+pub use dither::Dither;
+pub use dry_delay::DryDelay;
+pub use dual_speaker::DualSpeakerDetector;
 pub use early_reflection::EarlyReflectionSuppressor;
 pub use envelope::VoiceEnvelopeTracker;
+pub use gain_audit::GainAudit;
+pub use hiss_profile::HissProfile;
 pub use hiss_rumble::HissRumble;
-pub use limiter::LinkedLimiter;
+pub use input_utility::{InputUtility, InputUtilitySettings};
+pub use limiter::{LimiterRelease, LinkedLimiter};
+pub use mic_align::MicAligner;
+pub use mono_compat::MonoCompat;
+pub use music_detector::MusicDetector;
+pub use numeric_guard::{GuardStage, NumericGuard};
+pub use parallel_compressor::ParallelCompressor;
 pub use pink_ref_bias::PinkRefBias;
 pub use plosive_softener::PlosiveSoftener;
 pub use post_noise_cleanup::PostNoiseCleanup;
 pub use profile_analyzer::ProfileAnalyzer;
-pub use proximity::Proximity;
+pub use proximity::{DistanceTracker, Proximity};
+pub use pump_guard::PumpGuard;
 pub use recovery_stage::RecoveryStage;
+pub use room_tone_bridge::RoomToneBridge;
+pub use rt60::Rt60Estimator;
+pub use scheduler::{Countdown, Interval, Ramp};
+pub use soft_clipper::SoftClipper;
+pub use speaker_learner::SpeakerProfileLearner;
 pub use spectral_guardrails::SpectralGuardrails;
 pub use speech_confidence::SpeechConfidenceEstimator;
 pub use speech_expander::SpeechExpander;
 pub use speech_hpf::SpeechHpf;
+pub use test_signal::{TestSignalGenerator, TestSignalKind};
+pub use transient_suppressor::TransientSuppressor;
 
 /// Lifecycle state model for DSP modules.
 /// Ensures predictable behavior during training, active processing, and bypassing.
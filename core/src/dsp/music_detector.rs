@@ -0,0 +1,307 @@
+//! Music Detector
+//!
+//! Recognizes sustained music in the input (a musical intro, a stinger or a
+//! bed running through the VO track) so the plugin can stop treating it as
+//! a noisy voice: the denoiser and de-verber would otherwise chew it up, and
+//! the noise models would learn it as room tone.
+//!
+//! # Design Notes
+//! - Three things together mark music: a **tonal** spectrum (sustained
+//!   partials standing out of their neighbours), **wide bandwidth** (real
+//!   energy above `HF_SPLIT_HZ`, unlike hum or a fan) and **low speech
+//!   confidence**. Noise fails the first, hum the second, a voice the third
+//! - Tonality is measured on the spectrum divided by its own smoothed
+//!   envelope, so the spectral tilt does not count: noise of any color comes
+//!   out flat (flatness ≈ 0.56), partials do not
+//! - One hop of evidence says little; a score follows the evidence with
+//!   `SCORE_TAU_SEC` and music is declared above `SCORE_ON` (about three
+//!   seconds of it), released below `SCORE_OFF`. Confident speech clears the
+//!   score at once, so a voice over the intro gets processed again
+//!
+//! ## Audio Thread Safety
+//! - FFT planned and buffers allocated in `new()`, no allocations in
+//!   `process()`
+
+use rustfft::{num_complex::Complex, Fft, FftPlanner};
+use std::sync::Arc;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Analysis window and hop (samples)
+const FFT_SIZE: usize = 2048;
+const HOP_SIZE: usize = 1024;
+
+/// Analyzed band (Hz)
+const BAND_LOW_HZ: f32 = 150.0;
+const BAND_HIGH_HZ: f32 = 10000.0;
+
+/// Half-width of the envelope the spectrum is divided by (bins)
+const ENVELOPE_HALF_WIDTH: usize = 8;
+
+/// Whitened flatness below which a hop counts as tonal (noise ≈ 0.56)
+const TONAL_FLATNESS: f32 = 0.3;
+
+/// Upper part of the band that has to carry energy (Hz)
+const HF_SPLIT_HZ: f32 = 4000.0;
+/// Least energy share above `HF_SPLIT_HZ` for wide bandwidth (-23 dB)
+const HF_SHARE_MIN: f32 = 0.005;
+
+/// Mean speech confidence over a hop above which it is not music evidence
+const MAX_SPEECH_CONF: f32 = 0.35;
+/// Speech confidence that clears the score at once
+const SPEECH_RELEASE_CONF: f32 = 0.6;
+
+/// Band power below which a hop is silence, not evidence (windowed mean
+/// square, -70 dB)
+const MIN_POWER: f32 = 1e-7;
+
+/// Evidence score time constant and hysteresis
+const SCORE_TAU_SEC: f32 = 1.5;
+const SCORE_ON: f32 = 0.85;
+const SCORE_OFF: f32 = 0.5;
+
+// =============================================================================
+// Detector
+// =============================================================================
+
+pub struct MusicDetector {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    input: Vec<f32>,
+    input_pos: usize,
+    hop_countdown: usize,
+    spectrum: Vec<Complex<f32>>,
+    fft_scratch: Vec<Complex<f32>>,
+    power: Vec<f32>,
+
+    band_low_bin: usize,
+    band_high_bin: usize,
+    hf_split_bin: usize,
+
+    speech_conf_sum: f32,
+    score: f32,
+    score_alpha: f32,
+    music: bool,
+}
+
+impl MusicDetector {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut planner = FftPlanner::<f32>::new();
+        let fft = planner.plan_fft_forward(FFT_SIZE);
+        let fft_scratch = vec![Complex::default(); fft.get_inplace_scratch_len()];
+
+        let window = (0..FFT_SIZE)
+            .map(|i| {
+                let phase = std::f32::consts::TAU * i as f32 / FFT_SIZE as f32;
+                0.5 - 0.5 * phase.cos()
+            })
+            .collect();
+
+        let bin = |hz: f32| ((hz * FFT_SIZE as f32 / sample_rate) as usize).min(FFT_SIZE / 2 - 1);
+        let band_high_bin = bin(BAND_HIGH_HZ.min(0.45 * sample_rate));
+
+        Self {
+            fft,
+            window,
+            input: vec![0.0; FFT_SIZE],
+            input_pos: 0,
+            hop_countdown: HOP_SIZE,
+            spectrum: vec![Complex::default(); FFT_SIZE],
+            fft_scratch,
+            power: vec![0.0; FFT_SIZE / 2],
+            band_low_bin: bin(BAND_LOW_HZ).max(1),
+            band_high_bin,
+            hf_split_bin: bin(HF_SPLIT_HZ).min(band_high_bin),
+            speech_conf_sum: 0.0,
+            score: 0.0,
+            score_alpha: 1.0 - (-(HOP_SIZE as f32) / (SCORE_TAU_SEC * sample_rate)).exp(),
+            music: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.input.fill(0.0);
+        self.input_pos = 0;
+        self.hop_countdown = HOP_SIZE;
+        self.speech_conf_sum = 0.0;
+        self.score = 0.0;
+        self.music = false;
+    }
+
+    /// Sustained music is playing
+    pub fn is_music(&self) -> bool {
+        self.music
+    }
+
+    pub fn process(&mut self, left: f32, right: f32, speech_conf: f32) {
+        self.input[self.input_pos] = 0.5 * (left + right);
+        self.input_pos = (self.input_pos + 1) % FFT_SIZE;
+        self.speech_conf_sum += speech_conf;
+
+        self.hop_countdown -= 1;
+        if self.hop_countdown == 0 {
+            self.hop_countdown = HOP_SIZE;
+            let conf = self.speech_conf_sum / HOP_SIZE as f32;
+            self.speech_conf_sum = 0.0;
+            self.analyze_hop(conf);
+        }
+    }
+
+    fn analyze_hop(&mut self, speech_conf: f32) {
+        if speech_conf > SPEECH_RELEASE_CONF {
+            self.score = 0.0;
+            self.music = false;
+            return;
+        }
+
+        let evidence = speech_conf < MAX_SPEECH_CONF && self.music_spectrum();
+        let target = if evidence { 1.0 } else { 0.0 };
+        self.score += (target - self.score) * self.score_alpha;
+        if self.score > SCORE_ON {
+            self.music = true;
+        } else if self.score < SCORE_OFF {
+            self.music = false;
+        }
+    }
+
+    /// The latest window is tonal and wide-band
+    fn music_spectrum(&mut self) -> bool {
+        // Oldest sample first
+        for (i, s) in self.spectrum.iter_mut().enumerate() {
+            let x = self.input[(self.input_pos + i) % FFT_SIZE];
+            *s = Complex::new(x * self.window[i], 0.0);
+        }
+        self.fft
+            .process_with_scratch(&mut self.spectrum, &mut self.fft_scratch);
+        for (p, s) in self.power.iter_mut().zip(self.spectrum.iter()) {
+            *p = s.norm_sqr();
+        }
+
+        let (lo, hi) = (self.band_low_bin, self.band_high_bin);
+        if hi <= lo + 2 * ENVELOPE_HALF_WIDTH {
+            return false;
+        }
+        let band = &self.power[lo..=hi];
+        let total: f32 = band.iter().sum();
+        // Parseval: one-sided bin power over N² is the (windowed) mean square
+        let mean_square = 2.0 * total / (FFT_SIZE * FFT_SIZE) as f32;
+        if mean_square < MIN_POWER {
+            return false;
+        }
+
+        // Wide bandwidth: real energy in the top of the band
+        let high: f32 = self.power[self.hf_split_bin..=hi].iter().sum();
+        if high < HF_SHARE_MIN * total {
+            return false;
+        }
+
+        // Tonality: flatness of the spectrum over its own envelope
+        let mut log_sum = 0.0;
+        let mut lin_sum = 0.0;
+        let mut count = 0;
+        for k in lo + ENVELOPE_HALF_WIDTH..=hi - ENVELOPE_HALF_WIDTH {
+            let neighbourhood = &self.power[k - ENVELOPE_HALF_WIDTH..=k + ENVELOPE_HALF_WIDTH];
+            let envelope = neighbourhood.iter().sum::<f32>() / neighbourhood.len() as f32;
+            if envelope <= 0.0 {
+                continue;
+            }
+            let r = (self.power[k] / envelope).max(1e-12);
+            log_sum += r.ln();
+            lin_sum += r;
+            count += 1;
+        }
+        if count == 0 {
+            return false;
+        }
+        let flatness = (log_sum / count as f32).exp() / (lin_sum / count as f32);
+        flatness < TONAL_FLATNESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::{TestSignalGenerator, TestSignalKind};
+
+    const SR: f32 = 48000.0;
+
+    /// A sustained A minor chord of bright (sawtooth-like) notes
+    fn chord(i: usize) -> f32 {
+        let t = i as f32 / SR;
+        [220.0, 261.63, 329.63]
+            .iter()
+            .map(|&f| {
+                (1..40)
+                    .filter(|&n| f * n as f32 <= 12000.0)
+                    .map(|n| (std::f32::consts::TAU * f * n as f32 * t).sin() / n as f32)
+                    .sum::<f32>()
+            })
+            .sum::<f32>()
+            * 0.05
+    }
+
+    /// Feed `seconds` of `signal` from sample `start` on
+    fn feed(
+        detector: &mut MusicDetector,
+        mut signal: impl FnMut(usize) -> f32,
+        start: usize,
+        seconds: f32,
+        speech_conf: f32,
+    ) -> usize {
+        let end = start + (seconds * SR) as usize;
+        for i in start..end {
+            let x = signal(i);
+            detector.process(x, x, speech_conf);
+        }
+        end
+    }
+
+    #[test]
+    fn test_sustained_music_is_detected_after_a_few_seconds() {
+        let mut detector = MusicDetector::new(SR);
+        let pos = feed(&mut detector, chord, 0, 1.0, 0.1);
+        assert!(!detector.is_music());
+        feed(&mut detector, chord, pos, 4.0, 0.1);
+        assert!(detector.is_music());
+    }
+
+    #[test]
+    fn test_speech_and_noise_are_not_music() {
+        let mut detector = MusicDetector::new(SR);
+        feed(&mut detector, chord, 0, 5.0, 0.9);
+        assert!(!detector.is_music());
+
+        let mut detector = MusicDetector::new(SR);
+        let mut pink = TestSignalGenerator::new(SR);
+        feed(
+            &mut detector,
+            |_| 0.1 * pink.next(TestSignalKind::PinkNoise),
+            0,
+            5.0,
+            0.0,
+        );
+        assert!(!detector.is_music());
+
+        // Hum: tonal but narrow
+        let mut detector = MusicDetector::new(SR);
+        let hum = |i: usize| {
+            let t = i as f32 / SR;
+            (1..8)
+                .map(|n| 0.05 * (std::f32::consts::TAU * 50.0 * n as f32 * t).sin())
+                .sum()
+        };
+        feed(&mut detector, hum, 0, 5.0, 0.0);
+        assert!(!detector.is_music());
+    }
+
+    #[test]
+    fn test_confident_speech_releases_at_once() {
+        let mut detector = MusicDetector::new(SR);
+        let pos = feed(&mut detector, chord, 0, 5.0, 0.1);
+        assert!(detector.is_music());
+        feed(&mut detector, chord, pos, 0.05, 0.9);
+        assert!(!detector.is_music());
+    }
+}
@@ -9,9 +9,15 @@
 //! - Removal works during silence, independent of speech-aware denoisers.
 //! - Deterministic, real-time safe (no alloc in process), never amplifies, never “chases” speech.
 //! - Bounded subtraction: only attenuates, with smoothing to avoid zipper/warble.
+//! - Auto learn: outside the Re-learn window the fingerprint keeps following the
+//!   room, but only after 400 ms of confirmed non-speech and with a much slower
+//!   blend (20 s). Freeze holds the current fingerprint.
+//! - Re-learn captures 2 s of non-speech frames and stops on its own; speech
+//!   detected inside the capture is reported, since frames around a word onset
+//!   slip past the confidence gate and colour the profile.
 //!
 //! Usage (per-sample)
-//!   let cfg = NoiseLearnRemoveConfig { enabled, amount, learn, clear };
+//!   let cfg = NoiseLearnRemoveConfig { enabled, amount, learn, clear, auto_learn, freeze };
 //!   let (l2, r2) = noise_learn_remove.process(l1, r1, cfg, &sidechain);
 //!
 //! Notes
@@ -42,8 +48,17 @@ const QUALITY_EMA_TAU: f32 = 2.0;
 const STABILITY_TIME_SEC: f32 = 0.6;
 const STABILITY_DELTA_THRESHOLD: f32 = 0.18;
 
-// Re-learn latch duration (seconds)
-const RELEARN_TIME_SEC: f32 = 5.0;
+// Re-learn gives up if it cannot collect enough quiet within this time (seconds)
+const RELEARN_TIME_SEC: f32 = 10.0;
+// Non-speech frames a Re-learn capture collects before it stops (seconds)
+const CAPTURE_TARGET_SEC: f32 = 2.0;
+// Speech inside a capture beyond this is reported as contamination (seconds)
+const CAPTURE_SPEECH_WARN_SEC: f32 = 0.1;
+
+// Auto learn: non-speech must last this long before a frame may update the profile
+const AUTO_LEARN_HOLD_SEC: f32 = 0.4;
+// Auto learn blends into an existing profile much more slowly than Re-learn
+const AUTO_LEARNED_EMA_TAU: f32 = 20.0;
 
 // Gain smoothing per frame
 const GAIN_SMOOTH_ALPHA: f32 = 0.2;
@@ -58,9 +73,23 @@ const EPS: f32 = 1e-12;
 #[derive(Debug, Clone, Copy)]
 pub struct NoiseLearnRemoveConfig {
     pub enabled: bool,
-    pub amount: f32, // 0.0 .. 1.0
-    pub learn: bool, // momentary button
-    pub clear: bool, // reset learned profile
+    pub amount: f32,      // 0.0 .. 1.0
+    pub learn: bool,      // momentary button
+    pub clear: bool,      // reset learned profile
+    pub auto_learn: bool, // keep updating the profile in confirmed pauses
+    pub freeze: bool,     // hold the profile (blocks auto learn)
+}
+
+/// State of the last Re-learn capture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseCapture {
+    Idle,
+    Capturing,
+    Done,
+    /// Finished, but speech was detected while capturing
+    SpeechDetected,
+    /// Gave up before collecting enough non-speech
+    TooShort,
 }
 
 pub struct NoiseLearnRemove {
@@ -119,16 +148,49 @@ impl NoiseLearnRemove {
         self.detector.quality
     }
 
-    /// 0..1 progress through the 10s learn budget (useful for UI)
+    /// 0..1 progress towards the 2 s of non-speech a Re-learn captures
     pub fn get_learn_progress(&self) -> f32 {
         self.detector.learn_progress()
     }
 
+    pub fn capture_state(&self) -> NoiseCapture {
+        self.detector.capture
+    }
+
+    /// True while the last frame updated the fingerprint (Re-learn or auto learn)
+    pub fn is_learning(&self) -> bool {
+        self.detector.learning
+    }
+
+    /// Delay in samples (one window, also with nothing learned)
+    pub fn latency(&self) -> usize {
+        self.win_size
+    }
+
     /// True if we have a non-trivial learned profile.
     pub fn has_profile(&self) -> bool {
         self.detector.has_profile()
     }
 
+    /// Learned noise magnitude per bin (nyq+1), if a profile exists.
+    pub fn learned_profile(&self) -> Option<&[f32]> {
+        if self.detector.has_profile() {
+            Some(&self.detector.learned_mag)
+        } else {
+            None
+        }
+    }
+
+    /// Load a fingerprint learned by an instance with a different sample
+    /// rate or FFT size (`profile` holds `nyq + 1` bins at `from_sr`).
+    ///
+    /// Bins are interpolated by frequency and rescaled for the new bin width,
+    /// so the same room tone reads the same noise level. Above the old
+    /// Nyquist the top bin is held.
+    pub fn restore_profile(&mut self, profile: &[f32], from_sr: f32, quality: f32) {
+        self.detector.restore_profile(profile, from_sr, quality);
+    }
+
     #[inline]
     pub fn process(
         &mut self,
@@ -207,6 +269,15 @@ struct NoiseLearnRemoveDetector {
     relearn_frames_total: usize,
     learn_latched: bool,
     relearn_armed: bool,
+    capture: NoiseCapture,
+    capture_frames: usize,
+    capture_target_frames: usize,
+    capture_speech_frames: usize,
+    capture_speech_warn_frames: usize,
+    // Consecutive non-speech frames (auto learn gate)
+    silence_frames: usize,
+    auto_hold_frames: usize,
+    learning: bool,
 
     // Per-bin smoothed gains (nyq+1)
     gain_smooth: Vec<f32>,
@@ -214,6 +285,7 @@ struct NoiseLearnRemoveDetector {
     // EMA coefficients
     candidate_alpha: f32,
     learned_alpha: f32,
+    auto_learned_alpha: f32,
     quality_alpha: f32,
 
     win_size: usize,
@@ -234,10 +306,15 @@ impl NoiseLearnRemoveDetector {
 
         let candidate_alpha = 1.0 - (-frame_dt / CANDIDATE_EMA_TAU).exp();
         let learned_alpha = 1.0 - (-frame_dt / LEARNED_EMA_TAU).exp();
+        let auto_learned_alpha = 1.0 - (-frame_dt / AUTO_LEARNED_EMA_TAU).exp();
         let quality_alpha = 1.0 - (-frame_dt / QUALITY_EMA_TAU).exp();
 
         let stable_frames_required = (STABILITY_TIME_SEC / frame_dt).ceil().max(1.0) as usize;
         let relearn_frames_total = (RELEARN_TIME_SEC / frame_dt).ceil().max(1.0) as usize;
+        let auto_hold_frames = (AUTO_LEARN_HOLD_SEC / frame_dt).ceil().max(1.0) as usize;
+        let capture_target_frames = (CAPTURE_TARGET_SEC / frame_dt).ceil().max(1.0) as usize;
+        let capture_speech_warn_frames =
+            (CAPTURE_SPEECH_WARN_SEC / frame_dt).ceil().max(1.0) as usize;
 
         Self {
             fft,
@@ -259,11 +336,20 @@ impl NoiseLearnRemoveDetector {
             relearn_frames_total,
             learn_latched: false,
             relearn_armed: false,
+            capture: NoiseCapture::Idle,
+            capture_frames: 0,
+            capture_target_frames,
+            capture_speech_frames: 0,
+            capture_speech_warn_frames,
+            silence_frames: 0,
+            auto_hold_frames,
+            learning: false,
 
             gain_smooth: vec![1.0; nyq + 1],
 
             candidate_alpha,
             learned_alpha,
+            auto_learned_alpha,
             quality_alpha,
 
             win_size: win,
@@ -282,6 +368,8 @@ impl NoiseLearnRemoveDetector {
     /// Clears only DSP state (smoothing, history), preserves learned profile.
     fn reset_state(&mut self) {
         self.gain_smooth.fill(1.0);
+        self.silence_frames = 0;
+        self.learning = false;
         // We do NOT clear learned_mag, learned_energy, quality, or stability state
     }
 
@@ -296,6 +384,9 @@ impl NoiseLearnRemoveDetector {
         self.relearn_frames_left = 0;
         self.learn_latched = false;
         self.relearn_armed = false;
+        self.capture = NoiseCapture::Idle;
+        self.capture_frames = 0;
+        self.capture_speech_frames = 0;
         self.gain_smooth.fill(1.0);
     }
 
@@ -303,13 +394,61 @@ impl NoiseLearnRemoveDetector {
         self.learned_energy > 1e-6
     }
 
+    fn restore_profile(&mut self, profile: &[f32], from_sr: f32, quality: f32) {
+        self.clear_profile();
+        if profile.len() < 2 || from_sr <= 0.0 {
+            return;
+        }
+
+        let nyq = self.win_size / 2;
+        let from_win = 2 * (profile.len() - 1);
+        // Per-bin magnitude of a given noise PSD grows with sqrt(sr * win)
+        let scale =
+            ((self.sample_rate * self.win_size as f32) / (from_sr * from_win as f32)).sqrt();
+        // New bin index -> old (fractional) bin index
+        let step = (self.sample_rate / self.win_size as f32) / (from_sr / from_win as f32);
+        let last = profile.len() - 1;
+
+        let mut e = 0.0;
+        for i in 0..=nyq {
+            let pos = i as f32 * step;
+            let k = pos as usize;
+            let m = if k >= last {
+                profile[last]
+            } else {
+                let t = pos - k as f32;
+                profile[k] + t * (profile[k + 1] - profile[k])
+            };
+            let m = (m * scale).max(MAG_FLOOR);
+            self.learned_mag[i] = m;
+            self.candidate_mag[i] = m;
+            e += m;
+        }
+        self.learned_energy = e / (nyq + 1) as f32;
+        self.candidate_energy = self.learned_energy;
+        self.quality = quality.clamp(0.0, 1.0);
+    }
+
     fn learn_progress(&self) -> f32 {
-        (self.stable_frames as f32 / self.stable_frames_required as f32).clamp(0.0, 1.0)
+        (self.capture_frames as f32 / self.capture_target_frames as f32).clamp(0.0, 1.0)
     }
 
     fn trigger_relearn(&mut self) {
         self.clear_profile();
         self.relearn_armed = true;
+        self.capture = NoiseCapture::Capturing;
+    }
+
+    /// Close the Re-learn window and report how the capture went
+    fn finish_capture(&mut self) {
+        self.relearn_frames_left = 0;
+        self.capture = if self.capture_frames < self.capture_target_frames || !self.has_profile() {
+            NoiseCapture::TooShort
+        } else if self.capture_speech_frames > self.capture_speech_warn_frames {
+            NoiseCapture::SpeechDetected
+        } else {
+            NoiseCapture::Done
+        };
     }
 
     fn analyze_frame(
@@ -352,7 +491,27 @@ impl NoiseLearnRemoveDetector {
             false
         };
 
-        let can_learn = relearn_active && (is_silence || relearn_active);
+        // Re-learn captures only non-speech frames and counts the speech it skips
+        let capture_frame = relearn_active && is_silence;
+        if capture_frame {
+            self.capture_frames += 1;
+        } else if relearn_active {
+            self.capture_speech_frames += 1;
+        }
+
+        // Auto learn only once the pause is confirmed, never while frozen
+        self.silence_frames = if is_silence {
+            (self.silence_frames + 1).min(self.auto_hold_frames)
+        } else {
+            0
+        };
+        let auto_active = cfg.auto_learn
+            && !cfg.freeze
+            && !relearn_active
+            && self.silence_frames >= self.auto_hold_frames;
+
+        let can_learn = capture_frame || auto_active;
+        self.learning = can_learn;
 
         if can_learn {
             // Candidate EMA update (fast)
@@ -404,9 +563,14 @@ impl NoiseLearnRemoveDetector {
                         self.learned_mag[i] = self.candidate_mag[i];
                     }
                 } else {
+                    let alpha = if auto_active {
+                        self.auto_learned_alpha
+                    } else {
+                        self.learned_alpha
+                    };
                     for i in 0..=nyq {
                         let v = self.learned_mag[i];
-                        self.learned_mag[i] = v + self.learned_alpha * (self.candidate_mag[i] - v);
+                        self.learned_mag[i] = v + alpha * (self.candidate_mag[i] - v);
                     }
                 }
 
@@ -423,6 +587,14 @@ impl NoiseLearnRemoveDetector {
             }
         }
 
+        // Re-learn stops once the capture is complete (or times out)
+        if relearn_active
+            && ((self.capture_frames >= self.capture_target_frames && self.has_profile())
+                || self.relearn_frames_left == 0)
+        {
+            self.finish_capture();
+        }
+
         // 4) Subtraction (bounded attenuation only)
         let amount = cfg.amount.clamp(0.0, 1.0);
 
@@ -604,3 +776,123 @@ impl StreamingNoiseLearnRemoveChannel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 16000.0;
+
+    /// Steady 100 Hz hum into silence-level speech confidence for `sec` seconds
+    fn run(nlr: &mut NoiseLearnRemove, sec: f32, conf: f32, cfg: NoiseLearnRemoveConfig) {
+        let sidechain = SpeechSidechain {
+            speech_conf: conf,
+            noise_floor_db: -60.0,
+        };
+        for i in 0..(sec * SR) as usize {
+            let x = 0.01 * (std::f32::consts::TAU * 100.0 * i as f32 / SR).sin();
+            nlr.process(x, x, cfg, &sidechain);
+        }
+    }
+
+    fn cfg(auto_learn: bool, freeze: bool) -> NoiseLearnRemoveConfig {
+        NoiseLearnRemoveConfig {
+            enabled: true,
+            amount: 1.0,
+            learn: false,
+            clear: false,
+            auto_learn,
+            freeze,
+        }
+    }
+
+    #[test]
+    fn test_auto_learn_in_confirmed_pauses() {
+        let mut nlr = NoiseLearnRemove::new(512, 128, SR);
+        run(&mut nlr, 3.0, 0.9, cfg(true, false));
+        assert!(!nlr.has_profile(), "learned during speech");
+
+        run(&mut nlr, 0.3, 0.0, cfg(true, false));
+        assert!(!nlr.has_profile(), "learned before the pause was confirmed");
+
+        run(&mut nlr, 2.0, 0.0, cfg(true, false));
+        assert!(nlr.has_profile());
+        assert!(nlr.get_quality() > 0.3);
+    }
+
+    #[test]
+    fn test_relearn_capture_stops_and_reports_speech() {
+        let mut nlr = NoiseLearnRemove::new(512, 128, SR);
+        let learn = NoiseLearnRemoveConfig {
+            learn: true,
+            ..cfg(false, false)
+        };
+        run(&mut nlr, 0.05, 0.0, learn);
+        assert_eq!(nlr.capture_state(), NoiseCapture::Capturing);
+        run(&mut nlr, 1.0, 0.0, cfg(false, false));
+        assert!(nlr.get_learn_progress() > 0.3 && nlr.get_learn_progress() < 0.7);
+
+        run(&mut nlr, 1.5, 0.0, cfg(false, false));
+        assert_eq!(nlr.capture_state(), NoiseCapture::Done);
+        assert!(nlr.has_profile());
+        assert!(!nlr.is_learning(), "capture did not stop");
+
+        // Talking over a second capture is flagged
+        run(&mut nlr, 0.05, 0.0, learn);
+        run(&mut nlr, 0.5, 0.0, cfg(false, false));
+        run(&mut nlr, 0.5, 0.9, cfg(false, false));
+        run(&mut nlr, 2.5, 0.0, cfg(false, false));
+        assert_eq!(nlr.capture_state(), NoiseCapture::SpeechDetected);
+    }
+
+    #[test]
+    fn test_restore_maps_bins_by_frequency() {
+        // Synthetic fingerprint with a bump at bin 32 (1.5 kHz at 48 kHz, 1024 FFT)
+        let src: Vec<f32> = (0..=512)
+            .map(|k| if k == 32 { 1.0 } else { 0.01 })
+            .collect();
+        let mut nlr = NoiseLearnRemove::new(2048, 512, 44100.0);
+        nlr.restore_profile(&src, 48000.0, 0.8);
+        assert!(nlr.has_profile());
+        assert_eq!(nlr.get_quality(), 0.8);
+
+        let profile = nlr.learned_profile().unwrap();
+        assert_eq!(profile.len(), 1025);
+        let peak = (0..profile.len())
+            .max_by(|&a, &b| profile[a].total_cmp(&profile[b]))
+            .unwrap();
+        let peak_hz = peak as f32 * 44100.0 / 2048.0;
+        assert!((peak_hz - 1500.0).abs() < 30.0, "{peak_hz}");
+    }
+
+    #[test]
+    fn test_restore_keeps_noise_level_across_fft_size() {
+        // Same hum learned at two FFT sizes: the restored larger profile
+        // should match a directly learned one
+        let mut small = NoiseLearnRemove::new(512, 128, SR);
+        let mut large = NoiseLearnRemove::new(1024, 256, SR);
+        run(&mut small, 3.0, 0.0, cfg(true, false));
+        run(&mut large, 3.0, 0.0, cfg(true, false));
+
+        let mut restored = NoiseLearnRemove::new(1024, 256, SR);
+        restored.restore_profile(small.learned_profile().unwrap(), SR, small.get_quality());
+
+        let peak = |p: &[f32]| p.iter().cloned().fold(0.0f32, f32::max);
+        let direct = peak(large.learned_profile().unwrap());
+        let moved = peak(restored.learned_profile().unwrap());
+        let ratio_db = 20.0 * (moved / direct).log10();
+        assert!(ratio_db.abs() < 3.0, "{ratio_db} dB");
+    }
+
+    #[test]
+    fn test_freeze_and_manual_mode_do_not_learn() {
+        let mut frozen = NoiseLearnRemove::new(512, 128, SR);
+        run(&mut frozen, 3.0, 0.0, cfg(true, true));
+        assert!(!frozen.has_profile());
+        assert!(!frozen.is_learning());
+
+        let mut manual = NoiseLearnRemove::new(512, 128, SR);
+        run(&mut manual, 3.0, 0.0, cfg(false, false));
+        assert!(!manual.has_profile());
+    }
+}
@@ -0,0 +1,269 @@
+//! Numeric Guard (per-stage NaN/Inf containment)
+//!
+//! Spot-checks the output of every stage in the per-sample chain and names
+//! the first one that produced a non-finite sample.
+//!
+//! # Purpose
+//! The final output guard silences a blown-up chain but cannot tell which
+//! module went wrong, and a NaN caught in a filter's state stays there until
+//! something resets it. Checking each stage lets the plugin reset only the
+//! module that failed and report it, so a field report reads "Deverb" rather
+//! than "the plugin went silent".
+//!
+//! # Design Notes
+//! - Checks run on one sample in every `interval`: a NaN stuck in a
+//!   recursive state shows up on every sample, so a spot check still finds
+//!   it within a fraction of a millisecond, at a fraction of the cost
+//! - The first failing stage of a checked sample is queued for reset;
+//!   later stages see the zeroed output and stay clean
+//! - The first stage ever to fail is kept until `reset()` for diagnosis
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations
+
+// =============================================================================
+// Stages
+// =============================================================================
+
+/// Checked stages, in signal order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum GuardStage {
+    MicAlign,
+    SpeechHpf,
+    NoiseLearn,
+    HissRumble,
+    EarlyReflection,
+    Expander,
+    Transient,
+    PinkBias,
+    Denoiser,
+    PlosiveBreath,
+    Deverb,
+    CodecRepair,
+    Proximity,
+    Clarity,
+    DeEsser,
+    Leveler,
+    Recovery,
+    PostCleanup,
+    Guardrails,
+    RoomTone,
+    ComfortNoise,
+    DeadAir,
+    Limiter,
+    MonoCompat,
+}
+
+impl GuardStage {
+    /// Number of stages
+    pub const COUNT: usize = 24;
+
+    /// Every stage, in signal order
+    pub const ALL: [GuardStage; Self::COUNT] = [
+        GuardStage::MicAlign,
+        GuardStage::SpeechHpf,
+        GuardStage::NoiseLearn,
+        GuardStage::HissRumble,
+        GuardStage::EarlyReflection,
+        GuardStage::Expander,
+        GuardStage::Transient,
+        GuardStage::PinkBias,
+        GuardStage::Denoiser,
+        GuardStage::PlosiveBreath,
+        GuardStage::Deverb,
+        GuardStage::CodecRepair,
+        GuardStage::Proximity,
+        GuardStage::Clarity,
+        GuardStage::DeEsser,
+        GuardStage::Leveler,
+        GuardStage::Recovery,
+        GuardStage::PostCleanup,
+        GuardStage::Guardrails,
+        GuardStage::RoomTone,
+        GuardStage::ComfortNoise,
+        GuardStage::DeadAir,
+        GuardStage::Limiter,
+        GuardStage::MonoCompat,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GuardStage::MicAlign => "Mic Align",
+            GuardStage::SpeechHpf => "Speech HPF",
+            GuardStage::NoiseLearn => "Noise Learn",
+            GuardStage::HissRumble => "Hiss/Rumble",
+            GuardStage::EarlyReflection => "Early Reflection",
+            GuardStage::Expander => "Expander",
+            GuardStage::Transient => "Transient",
+            GuardStage::PinkBias => "Tone Bias",
+            GuardStage::Denoiser => "Denoiser",
+            GuardStage::PlosiveBreath => "Plosive/Breath",
+            GuardStage::Deverb => "De-Verb",
+            GuardStage::CodecRepair => "Codec Repair",
+            GuardStage::Proximity => "Proximity",
+            GuardStage::Clarity => "Clarity",
+            GuardStage::DeEsser => "De-Esser",
+            GuardStage::Leveler => "Leveler",
+            GuardStage::Recovery => "Recovery",
+            GuardStage::PostCleanup => "Post Cleanup",
+            GuardStage::Guardrails => "Guardrails",
+            GuardStage::RoomTone => "Room Tone Bridge",
+            GuardStage::ComfortNoise => "Comfort Noise",
+            GuardStage::DeadAir => "Dead Air",
+            GuardStage::Limiter => "Limiter",
+            GuardStage::MonoCompat => "Mono Compat",
+        }
+    }
+
+    /// Meter code: 0 = none, otherwise stage index + 1
+    pub fn code(stage: Option<GuardStage>) -> u8 {
+        stage.map_or(0, |s| s as u8 + 1)
+    }
+
+    /// Inverse of `code()`
+    pub fn from_code(code: u8) -> Option<GuardStage> {
+        code.checked_sub(1)
+            .and_then(|i| Self::ALL.get(i as usize).copied())
+    }
+}
+
+// =============================================================================
+// Guard
+// =============================================================================
+
+pub struct NumericGuard {
+    interval: u32,
+    countdown: u32,
+    armed: bool,
+
+    /// Failing stage of the current sample, waiting for its reset
+    pending: Option<GuardStage>,
+    /// First and most recent stage to fail since `reset()`
+    first_fault: Option<GuardStage>,
+    last_fault: Option<GuardStage>,
+    fault_count: u32,
+}
+
+impl NumericGuard {
+    /// Check one sample in every `interval`
+    pub fn new(interval: u32) -> Self {
+        Self {
+            interval: interval.max(1),
+            countdown: 0,
+            armed: false,
+
+            pending: None,
+            first_fault: None,
+            last_fault: None,
+            fault_count: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.countdown = 0;
+        self.armed = false;
+        self.pending = None;
+        self.first_fault = None;
+        self.last_fault = None;
+        self.fault_count = 0;
+    }
+
+    /// Call once per sample, before the first `contain()`
+    #[inline]
+    pub fn begin_sample(&mut self) {
+        self.armed = self.countdown == 0;
+        self.countdown = if self.armed {
+            self.interval - 1
+        } else {
+            self.countdown - 1
+        };
+    }
+
+    /// Pass a stage's output through; on a checked sample, a non-finite
+    /// output is zeroed and the stage queued for reset
+    #[inline]
+    pub fn contain(&mut self, stage: GuardStage, left: f32, right: f32) -> (f32, f32) {
+        if !self.armed || (left.is_finite() && right.is_finite()) {
+            return (left, right);
+        }
+        if self.pending.is_none() {
+            self.pending = Some(stage);
+            self.first_fault.get_or_insert(stage);
+            self.last_fault = Some(stage);
+            self.fault_count = self.fault_count.saturating_add(1);
+        }
+        (0.0, 0.0)
+    }
+
+    /// Stage that failed on this sample and needs a reset
+    #[inline]
+    pub fn take_pending(&mut self) -> Option<GuardStage> {
+        self.pending.take()
+    }
+
+    /// First stage to fail since the last reset
+    pub fn first_fault(&self) -> Option<GuardStage> {
+        self.first_fault
+    }
+
+    /// Most recent stage to fail
+    pub fn last_fault(&self) -> Option<GuardStage> {
+        self.last_fault
+    }
+
+    /// Number of stage resets since the last reset
+    pub fn fault_count(&self) -> u32 {
+        self.fault_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checks_every_interval() {
+        let mut g = NumericGuard::new(4);
+        let mut caught = 0;
+        for _ in 0..16 {
+            g.begin_sample();
+            let out = g.contain(GuardStage::Deverb, f32::NAN, 0.0);
+            if out == (0.0, 0.0) {
+                caught += 1;
+                assert_eq!(g.take_pending(), Some(GuardStage::Deverb));
+            }
+            assert_eq!(g.take_pending(), None);
+        }
+        assert_eq!(caught, 4);
+        assert_eq!(g.fault_count(), 4);
+
+        // Finite samples pass untouched
+        g.begin_sample();
+        assert_eq!(g.contain(GuardStage::Limiter, 0.5, -0.5), (0.5, -0.5));
+    }
+
+    #[test]
+    fn test_attributes_first_stage() {
+        let mut g = NumericGuard::new(1);
+        g.begin_sample();
+        g.contain(GuardStage::SpeechHpf, 0.1, 0.1);
+        g.contain(GuardStage::Clarity, f32::INFINITY, 0.1);
+        g.contain(GuardStage::Limiter, f32::NAN, f32::NAN);
+        assert_eq!(g.take_pending(), Some(GuardStage::Clarity));
+
+        g.begin_sample();
+        g.contain(GuardStage::Limiter, f32::NAN, 0.0);
+        assert_eq!(g.take_pending(), Some(GuardStage::Limiter));
+        assert_eq!(g.first_fault(), Some(GuardStage::Clarity));
+        assert_eq!(g.last_fault(), Some(GuardStage::Limiter));
+
+        let code = GuardStage::code(g.first_fault());
+        assert_eq!(GuardStage::from_code(code), Some(GuardStage::Clarity));
+        assert_eq!(GuardStage::from_code(0), None);
+
+        g.reset();
+        assert_eq!(g.first_fault(), None);
+        assert_eq!(g.fault_count(), 0);
+    }
+}
@@ -0,0 +1,180 @@
+//! Parallel Compressor (Density)
+//!
+//! New York style parallel compression for the dynamics stage: a second,
+//! much heavier leveler runs beside the main one and its output is mixed
+//! back under the main path, which lifts the quiet syllables and word
+//! endings the serial chain leaves thin. The result is the dense, close
+//! "broadcast" voice.
+//!
+//! # Perceptual Contract
+//! - **Density 0 is the serial chain**: the main leveler gain comes back
+//!   unchanged and the heavy path is not even computed
+//! - **Upward, not downward**: speech around the leveler target changes
+//!   little; softer speech is raised, louder speech is held a little lower
+//! - **Pauses are left alone**: the heavy path fades out with speech
+//!   confidence, so room noise is not lifted between words
+//!
+//! # Design Notes
+//! - Both paths are the same signal with a different gain, so the mix is done
+//!   on the gains: `(main + w · heavy · makeup) / (1 + w)`. Neither path adds
+//!   delay, so the two are sample aligned by construction and the plugin's
+//!   reported latency does not change
+//! - The heavy path is a `LinkedCompressor` at full amount, its target
+//!   `HEAVY_TARGET_DROP_DB` below the main target and twice as fast; the
+//!   same drop comes back as makeup, so around the target the wet path sits
+//!   near the dry one
+//! - Density up to `MAX_WET` (the wet path 6 dB under the main path)
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations
+
+use crate::dsp::compressor::LinkedCompressor;
+use crate::dsp::envelope::VoiceEnvelope;
+use crate::dsp::utils::{db_to_lin, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Heavy path target below the main target, restored as makeup (dB)
+const HEAVY_TARGET_DROP_DB: f32 = 10.0;
+
+/// Heavy path attack/release relative to the main leveler
+const HEAVY_TIME_SCALE: f32 = 0.5;
+
+/// Wet level at Density 100% relative to the main path (-6 dB)
+const MAX_WET: f32 = 0.5;
+
+/// Glide of the wet level (density and speech gating)
+const WET_SMOOTH_MS: f32 = 50.0;
+
+/// Wet level below which the heavy path is skipped
+const WET_OFF: f32 = 1e-4;
+
+// =============================================================================
+// Parallel Compressor
+// =============================================================================
+
+pub struct ParallelCompressor {
+    heavy: LinkedCompressor,
+    makeup: f32,
+    /// Smoothed wet level (0..MAX_WET)
+    wet: f32,
+    wet_coeff: f32,
+}
+
+impl ParallelCompressor {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut heavy = LinkedCompressor::new(sample_rate);
+        heavy.set_time_scale(HEAVY_TIME_SCALE);
+        let mut compressor = Self {
+            heavy,
+            makeup: db_to_lin(HEAVY_TARGET_DROP_DB),
+            wet: 0.0,
+            wet_coeff: time_constant_coeff(WET_SMOOTH_MS, sample_rate),
+        };
+        compressor.set_target_db(-24.0);
+        compressor
+    }
+
+    /// Follow the main leveler's target (dBFS)
+    pub fn set_target_db(&mut self, target_db: f32) {
+        self.heavy.set_target_db(target_db - HEAVY_TARGET_DROP_DB);
+    }
+
+    /// Follow the main leveler's Response (1.0 = default ballistics)
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.heavy.set_time_scale(scale * HEAVY_TIME_SCALE);
+    }
+
+    /// Profile adaptation, as `LinkedCompressor::update_from_profile()`
+    pub fn update_from_profile(&mut self, crest_factor_db: f32, rms_variance: f32) {
+        self.heavy
+            .update_from_profile(crest_factor_db, rms_variance);
+    }
+
+    /// Gain for the dynamics stage: the main leveler gain `main_gain` with
+    /// the heavy path mixed under it at `density` (0..1)
+    pub fn process(
+        &mut self,
+        env_l: &VoiceEnvelope,
+        env_r: &VoiceEnvelope,
+        main_gain: f32,
+        density: f32,
+        speech_confidence: f32,
+    ) -> f32 {
+        let target = MAX_WET * density.clamp(0.0, 1.0) * speech_confidence.clamp(0.0, 1.0);
+        self.wet = target + self.wet_coeff * (self.wet - target);
+        if self.wet < WET_OFF && target == 0.0 {
+            return main_gain;
+        }
+
+        let heavy_gain = self
+            .heavy
+            .compute_gain(env_l, env_r, 1.0, speech_confidence, 0.0, 0.0);
+        (main_gain + self.wet * heavy_gain * self.makeup) / (1.0 + self.wet)
+    }
+
+    /// Heavy path gain reduction (dB, positive)
+    pub fn get_gain_reduction_db(&self) -> f32 {
+        self.heavy.get_gain_reduction_db()
+    }
+
+    pub fn reset(&mut self) {
+        self.heavy.reset();
+        self.wet = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::envelope::VoiceEnvelopeTracker;
+
+    const SR: f32 = 48000.0;
+
+    /// Combined gain after 2 s of a steady tone at `level_db` (main path at
+    /// unity)
+    fn gain_at(level_db: f32, density: f32) -> f32 {
+        let mut parallel = ParallelCompressor::new(SR);
+        let mut tracker = VoiceEnvelopeTracker::new(SR);
+        let amp = db_to_lin(level_db) * std::f32::consts::SQRT_2;
+        let mut gain = 1.0;
+        for i in 0..2 * SR as usize {
+            let x = amp * (std::f32::consts::TAU * 220.0 * i as f32 / SR).sin();
+            let env = tracker.process_sample(x);
+            gain = parallel.process(&env, &env, 1.0, density, 0.9);
+        }
+        gain
+    }
+
+    #[test]
+    fn test_zero_density_is_the_main_path() {
+        assert_eq!(gain_at(-12.0, 0.0), 1.0);
+        assert_eq!(gain_at(-40.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_density_lifts_soft_speech_more_than_loud() {
+        let soft = gain_at(-36.0, 1.0);
+        let loud = gain_at(-12.0, 1.0);
+        assert!(soft > 1.0, "{soft}");
+        assert!(loud < soft, "{loud} {soft}");
+        // Never more than the full makeup on the wet share
+        let max = (1.0 + MAX_WET * db_to_lin(HEAVY_TARGET_DROP_DB)) / (1.0 + MAX_WET);
+        assert!(soft <= max + 1e-3, "{soft} {max}");
+    }
+
+    #[test]
+    fn test_pauses_are_not_lifted() {
+        let mut parallel = ParallelCompressor::new(SR);
+        let mut tracker = VoiceEnvelopeTracker::new(SR);
+        let mut gain = 0.0;
+        for i in 0..SR as usize {
+            let x = 0.001 * (std::f32::consts::TAU * 220.0 * i as f32 / SR).sin();
+            let env = tracker.process_sample(x);
+            gain = parallel.process(&env, &env, 1.0, 1.0, 0.0);
+        }
+        assert_eq!(gain, 1.0);
+    }
+}
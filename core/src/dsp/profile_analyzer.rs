@@ -11,11 +11,22 @@
 //! # Design Notes
 //! - All buffers are pre-allocated in `new()` for audio thread safety
 //! - No allocations during `process()` to maintain real-time performance
-//! - Frame-based analysis for computational efficiency
+//! - Frame-based analysis for computational efficiency: metrics update once
+//!   per 50 ms frame, independent of how the host splits its buffers
 //! - Used for both input analysis and output validation
+//!
+//! ## Reverb Metrics
+//! The early/late ratio and decay slope gate distant-mic detection, and a
+//! single frame of them jumps with a music bed or a doorbell. Frames enter a
+//! history of the last few seconds only while the caller's speech confidence
+//! says the input is speech, weighted by that confidence; the reported values
+//! are the weighted medians of that history, so a short burst of anything
+//! else does not move them. `reverb_stability` (0..1) says how far to trust
+//! them: how much confident speech fills the history, times how closely the
+//! entries agree with their median.
 
 use super::biquad::Biquad;
-use super::utils::{time_constant_coeff, DB_EPS};
+use super::utils::{smoothstep, time_constant_coeff, DB_EPS};
 
 // =============================================================================
 // Constants
@@ -68,6 +79,85 @@ const DECAY_SLOPE_WINDOW_MS: f32 = 200.0;
 /// Speech activity threshold multiplier (RMS must be > noise_floor * this to be "speech")
 const SPEECH_ACTIVITY_MULT: f32 = 2.5;
 
+/// Early/late ratio history for the median (frames, 3.2 s of speech)
+const EARLY_LATE_HISTORY_FRAMES: usize = 64;
+
+/// Decay slope history for the median (measurement windows, 3.2 s of speech)
+const DECAY_HISTORY_WINDOWS: usize = 16;
+
+/// Speech confidence below which a frame stays out of the reverb history
+const REVERB_MIN_CONFIDENCE: f32 = 0.3;
+
+/// Median absolute deviation of the early/late ratio: full trust .. none
+const EARLY_LATE_SPREAD_STABLE: f32 = 0.05;
+const EARLY_LATE_SPREAD_UNSTABLE: f32 = 0.3;
+
+/// Median absolute deviation of the decay slope: full trust .. none
+const DECAY_SPREAD_STABLE: f32 = 0.1;
+const DECAY_SPREAD_UNSTABLE: f32 = 0.4;
+
+// =============================================================================
+// Reverb Metric History
+// =============================================================================
+
+/// Ring of the latest `(value, weight)` measurements
+#[derive(Clone, Copy)]
+struct WeightedHistory<const N: usize> {
+    entries: [(f32, f32); N],
+    len: usize,
+    next: usize,
+}
+
+impl<const N: usize> WeightedHistory<N> {
+    fn new() -> Self {
+        Self {
+            entries: [(0.0, 0.0); N],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    fn push(&mut self, value: f32, weight: f32) {
+        self.entries[self.next] = (value, weight);
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Total weight relative to a full history of weight 1 entries (0..1)
+    fn coverage(&self) -> f32 {
+        self.entries[..self.len].iter().map(|e| e.1).sum::<f32>() / N as f32
+    }
+
+    /// Weighted median and weighted median absolute deviation, `None` while
+    /// empty
+    fn median_and_spread(&self) -> Option<(f32, f32)> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut scratch = self.entries;
+        let entries = &mut scratch[..self.len];
+        let median = weighted_median(entries);
+        for e in entries.iter_mut() {
+            e.0 = (e.0 - median).abs();
+        }
+        Some((median, weighted_median(entries)))
+    }
+}
+
+/// Weighted median of `(value, weight)` pairs (sorts `entries`)
+fn weighted_median(entries: &mut [(f32, f32)]) -> f32 {
+    entries.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+    let half = 0.5 * entries.iter().map(|e| e.1).sum::<f32>();
+    let mut weight = 0.0;
+    for &(value, w) in entries.iter() {
+        weight += w;
+        if weight >= half {
+            return value;
+        }
+    }
+    entries.last().map_or(0.0, |e| e.0)
+}
+
 // =============================================================================
 // Profile Analyzer
 // =============================================================================
@@ -80,6 +170,7 @@ pub struct ProfileAnalyzer {
 
     // Accumulated samples for frame analysis
     sample_count: usize,
+    speech_conf_sum: f32,
 
     // Band-pass filters for frequency analysis
     // Presence band (2-5 kHz)
@@ -140,15 +231,21 @@ pub struct ProfileAnalyzer {
     decay_slope_delay_samples: usize,
     decay_slope_window_frames: usize,
     decay_accumulator: f32,
+    decay_conf_accumulator: f32,
     decay_count: usize,
 
     // Speech activity gating for decay slope
     speech_active: bool,
     speech_onset_frames: usize, // Frames since speech started
-    stable_decay_slope: f32,    // Last stable measurement (used when gated)
+
+    // Speech-weighted reverb metric histories (reported as medians)
+    early_late_history: WeightedHistory<EARLY_LATE_HISTORY_FRAMES>,
+    decay_history: WeightedHistory<DECAY_HISTORY_WINDOWS>,
 
     // Current computed profile
     current_profile: crate::AudioProfile,
+    // Completed frames since the last reset
+    frames_analyzed: u64,
 }
 
 impl ProfileAnalyzer {
@@ -206,6 +303,7 @@ impl ProfileAnalyzer {
             sample_rate,
             frame_size,
             sample_count: 0,
+            speech_conf_sum: 0.0,
 
             presence_hp_l,
             presence_hp_r,
@@ -255,21 +353,27 @@ impl ProfileAnalyzer {
             decay_slope_delay_samples,
             decay_slope_window_frames,
             decay_accumulator: 0.0,
+            decay_conf_accumulator: 0.0,
             decay_count: 0,
 
             // Speech activity gating for decay slope
             speech_active: false,
             speech_onset_frames: 0,
-            stable_decay_slope: 0.0,
+
+            early_late_history: WeightedHistory::new(),
+            decay_history: WeightedHistory::new(),
 
             current_profile: crate::AudioProfile::default(),
+            frames_analyzed: 0,
         }
     }
 
     /// Process a stereo sample pair and update profile metrics
     /// Call this for every sample in the input buffer (pre-DSP)
+    /// `speech_conf` (0..1) weights the frame in the reverb metric history
     #[inline]
-    pub fn process(&mut self, left: f32, right: f32) {
+    pub fn process(&mut self, left: f32, right: f32, speech_conf: f32) {
+        self.speech_conf_sum += speech_conf.clamp(0.0, 1.0);
         let mono = 0.5 * (left + right);
         let mono_sq = mono * mono;
 
@@ -372,23 +476,36 @@ impl ProfileAnalyzer {
             60.0 // Very clean signal
         };
 
-        // 5. Early/Late ratio
-        let early_late_ratio = if self.late_energy > DB_EPS && self.early_window_samples > 0 {
+        // Speech activity: RMS must be significantly above noise floor
+        let is_speech_frame = rms > noise_floor * SPEECH_ACTIVITY_MULT;
+        // Weight in the reverb history: confident speech only
+        let frame_conf = self.speech_conf_sum / n;
+        let reverb_weight = if is_speech_frame && frame_conf >= REVERB_MIN_CONFIDENCE {
+            frame_conf
+        } else {
+            0.0
+        };
+
+        // 5. Early/Late ratio (median over the speech history)
+        let frame_early_late = if self.late_energy > DB_EPS && self.early_window_samples > 0 {
             (self.early_energy / self.early_window_samples as f32)
                 / (self.late_energy / (self.sample_count - self.early_window_samples).max(1) as f32)
         } else if self.early_energy > DB_EPS {
             1.0 // All energy is early (very dry)
         } else {
             0.5 // No energy
-        };
+        }
+        .clamp(0.0, 2.0);
+        if reverb_weight > 0.0 {
+            self.early_late_history
+                .push(frame_early_late, reverb_weight);
+        }
+        let early_late_spread = self.early_late_history.median_and_spread();
+        let early_late_ratio = early_late_spread.map_or(frame_early_late, |(median, _)| median);
 
         // 6. Decay slope (rate of energy decay) with speech gating
         // This prevents false "distant" detection from plosives, phrase endings, and silence
-        let decay_slope = {
-            // Check speech activity: RMS must be significantly above noise floor
-            let speech_threshold = noise_floor * SPEECH_ACTIVITY_MULT;
-            let is_speech_frame = rms > speech_threshold;
-
+        let decay_spread = {
             // Track speech onset for delay
             if is_speech_frame && !self.speech_active {
                 // Speech just started
@@ -416,29 +533,50 @@ impl ProfileAnalyzer {
             // 1. Speech is active
             // 2. We've waited past the delay period (skip plosives/attack)
             // 3. Both current and previous RMS are valid
+            // 4. The caller's speech confidence agrees
             let should_measure = self.speech_active
                 && self.speech_onset_frames >= delay_frames
                 && self.prev_rms > DB_EPS
-                && rms > DB_EPS;
+                && rms > DB_EPS
+                && reverb_weight > 0.0;
 
             if should_measure {
                 let slope = (rms - self.prev_rms) / self.prev_rms;
                 // Accumulate for smoothing
                 self.decay_accumulator += slope;
+                self.decay_conf_accumulator += reverb_weight;
                 self.decay_count += 1;
 
-                // Update stable value once we have enough measurements
+                // Window complete: into the history, weighted by its confidence
                 if self.decay_count >= self.decay_slope_window_frames {
-                    self.stable_decay_slope = self.decay_accumulator / self.decay_count as f32;
+                    let count = self.decay_count as f32;
+                    self.decay_history.push(
+                        self.decay_accumulator / count,
+                        self.decay_conf_accumulator / count,
+                    );
                     self.decay_accumulator = 0.0;
+                    self.decay_conf_accumulator = 0.0;
                     self.decay_count = 0;
                 }
             }
 
             self.prev_rms = rms;
 
-            // Return stable value (holds last good measurement when gated)
-            self.stable_decay_slope
+            // Median holds through pauses (the history only grows in speech)
+            self.decay_history.median_and_spread()
+        };
+        let decay_slope = decay_spread.map_or(0.0, |(median, _)| median);
+
+        // Trust in the reverb metrics: confident speech in the history times
+        // agreement of the entries with their median
+        let reverb_stability = match (early_late_spread, decay_spread) {
+            (Some((_, el_mad)), Some((_, decay_mad))) => {
+                let agreement = (1.0
+                    - smoothstep(EARLY_LATE_SPREAD_STABLE, EARLY_LATE_SPREAD_UNSTABLE, el_mad))
+                    * (1.0 - smoothstep(DECAY_SPREAD_STABLE, DECAY_SPREAD_UNSTABLE, decay_mad));
+                self.early_late_history.coverage() * agreement
+            }
+            _ => 0.0,
         };
 
         // 7. Presence ratio (presence band energy / fullband energy)
@@ -476,15 +614,18 @@ impl ProfileAnalyzer {
             rms_variance,
             noise_floor,
             snr_db,
-            early_late_ratio: early_late_ratio.clamp(0.0, 2.0),
+            early_late_ratio,
             decay_slope,
+            reverb_stability,
             presence_ratio,
             air_ratio,
             hf_variance,
         };
+        self.frames_analyzed += 1;
 
         // Reset frame accumulators
         self.sample_count = 0;
+        self.speech_conf_sum = 0.0;
         self.energy_total = 0.0;
         self.energy_presence = 0.0;
         self.energy_air = 0.0;
@@ -502,7 +643,19 @@ impl ProfileAnalyzer {
         self.current_profile
     }
 
-    /// Force finalize the current frame (call at end of buffer if needed)
+    /// Completed analysis frames; a change means `get_profile()` is new
+    #[inline]
+    pub fn frames_analyzed(&self) -> u64 {
+        self.frames_analyzed
+    }
+
+    /// Analysis frame length in samples
+    pub fn frame_size(&self) -> usize {
+        self.frame_size
+    }
+
+    /// Force finalize a partial frame. The plugin does not call this per
+    /// buffer: that would tie the frame length to the host buffer size.
     pub fn finalize_frame(&mut self) {
         if self.sample_count > 0 {
             self.analyze_frame();
@@ -513,6 +666,7 @@ impl ProfileAnalyzer {
     #[allow(dead_code)]
     pub fn reset(&mut self) {
         self.sample_count = 0;
+        self.speech_conf_sum = 0.0;
         self.energy_total = 0.0;
         self.energy_presence = 0.0;
         self.energy_air = 0.0;
@@ -529,12 +683,15 @@ impl ProfileAnalyzer {
         self.noise_floor_sq = 1e-8;
         self.prev_rms = 0.0;
         self.decay_accumulator = 0.0;
+        self.decay_conf_accumulator = 0.0;
         self.decay_count = 0;
         self.speech_active = false;
         self.speech_onset_frames = 0;
-        self.stable_decay_slope = 0.0;
+        self.early_late_history = WeightedHistory::new();
+        self.decay_history = WeightedHistory::new();
         self.silence_frame_count = 0;
         self.current_profile = crate::AudioProfile::default();
+        self.frames_analyzed = 0;
 
         // Reset filters
         self.presence_hp_l.reset();
@@ -573,7 +730,7 @@ mod tests {
 
         // Process silence
         for _ in 0..4800 {
-            analyzer.process(0.0, 0.0);
+            analyzer.process(0.0, 0.0, 0.0);
         }
         analyzer.finalize_frame();
 
@@ -589,7 +746,7 @@ mod tests {
         // Process 1kHz sine wave
         for i in 0..4800 {
             let sample = 0.5 * (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 48000.0).sin();
-            analyzer.process(sample, sample);
+            analyzer.process(sample, sample, 1.0);
         }
         analyzer.finalize_frame();
 
@@ -601,4 +758,109 @@ mod tests {
         // Crest factor of sine is ~3 dB
         assert!(profile.crest_factor_db > 2.0 && profile.crest_factor_db < 4.0);
     }
+
+    #[test]
+    fn test_profile_independent_of_block_size() {
+        let signal: Vec<f32> = (0..48000)
+            .map(|i| {
+                let t = i as f32 / 48000.0;
+                // Bursts of tone with gaps, so noise floor and decay move
+                let gate = if (i / 6000) % 2 == 0 { 1.0 } else { 0.02 };
+                gate * 0.4 * (std::f32::consts::TAU * 300.0 * t).sin()
+            })
+            .collect();
+
+        let mut whole = ProfileAnalyzer::new(48000.0);
+        for &x in &signal {
+            whole.process(x, x, 1.0);
+        }
+
+        // Same signal in pseudo-random 1..=97 sample blocks, reading the
+        // profile after each block as the plugin does
+        let mut split = ProfileAnalyzer::new(48000.0);
+        let mut seed = 12345u32;
+        let mut pos = 0;
+        while pos < signal.len() {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let len = (1 + (seed >> 16) as usize % 97).min(signal.len() - pos);
+            for &x in &signal[pos..pos + len] {
+                split.process(x, x, 1.0);
+            }
+            let _ = split.get_profile();
+            pos += len;
+        }
+
+        assert_eq!(whole.frames_analyzed(), split.frames_analyzed());
+        assert_eq!(
+            whole.frames_analyzed(),
+            (signal.len() / whole.frame_size()) as u64
+        );
+        let (a, b) = (whole.get_profile(), split.get_profile());
+        assert_eq!(a.rms, b.rms);
+        assert_eq!(a.rms_variance, b.rms_variance);
+        assert_eq!(a.noise_floor, b.noise_floor);
+        assert_eq!(a.decay_slope, b.decay_slope);
+    }
+
+    /// Syllable-like bursts: a 180 Hz voice 4 times a second over a quiet floor
+    fn speech(i: usize) -> f32 {
+        let t = i as f32 / 48000.0;
+        let syllable = (std::f32::consts::PI * 4.0 * t).sin().powi(2);
+        0.3 * syllable * (std::f32::consts::TAU * 180.0 * t).sin() + 0.001 * (i as f32 * 0.7).sin()
+    }
+
+    /// Doorbell: a bright ding every half second, ringing out
+    fn doorbell(i: usize) -> f32 {
+        let t = i as f32 / 48000.0;
+        0.6 * (-(t % 0.5) * 6.0).exp() * (std::f32::consts::TAU * 1500.0 * t).sin()
+    }
+
+    /// Feed `seconds` of `signal` from `start` seconds on at `speech_conf`
+    fn feed(
+        analyzer: &mut ProfileAnalyzer,
+        signal: fn(usize) -> f32,
+        start: f32,
+        seconds: f32,
+        speech_conf: f32,
+    ) -> crate::AudioProfile {
+        for i in (start * 48000.0) as usize..((start + seconds) * 48000.0) as usize {
+            let x = signal(i);
+            analyzer.process(x, x, speech_conf);
+        }
+        analyzer.get_profile()
+    }
+
+    #[test]
+    fn test_reverb_stability_follows_speech_confidence() {
+        let stability = |conf: f32| {
+            let mut analyzer = ProfileAnalyzer::new(48000.0);
+            feed(&mut analyzer, speech, 0.0, 4.0, conf).reverb_stability
+        };
+        assert_eq!(stability(0.0), 0.0);
+        assert!(stability(0.4) < stability(0.9));
+        assert!(stability(0.9) > 0.8, "{}", stability(0.9));
+    }
+
+    #[test]
+    fn test_doorbell_in_a_pause_leaves_reverb_metrics_alone() {
+        let mut analyzer = ProfileAnalyzer::new(48000.0);
+        let before = feed(&mut analyzer, speech, 0.0, 4.0, 0.9);
+        let after = feed(&mut analyzer, doorbell, 4.0, 1.0, 0.1);
+        assert_eq!(after.early_late_ratio, before.early_late_ratio);
+        assert_eq!(after.decay_slope, before.decay_slope);
+        assert_eq!(after.reverb_stability, before.reverb_stability);
+    }
+
+    #[test]
+    fn test_doorbell_read_as_speech_lowers_reverb_stability() {
+        let mut analyzer = ProfileAnalyzer::new(48000.0);
+        let before = feed(&mut analyzer, speech, 0.0, 4.0, 0.9);
+        let after = feed(&mut analyzer, doorbell, 4.0, 1.0, 0.9);
+        assert!(
+            after.reverb_stability < 0.5 * before.reverb_stability,
+            "{} {}",
+            after.reverb_stability,
+            before.reverb_stability
+        );
+    }
 }
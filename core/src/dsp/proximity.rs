@@ -14,12 +14,15 @@
 //! - Simulates natural proximity effect of directional microphones
 //! - Carefully balanced to avoid boomy or muddy results
 //! - Does not synthesize missing low frequencies, only enhances existing ones
+//! - Track Room: [`DistanceTracker`] turns the input early/late ratio and
+//!   decay slope into a slow 0..1 distance estimate that scales the amount,
+//!   so a distant mic gets more warmth and a close mic less
 //!
 //! # Lifecycle
 //! - **Active**: Normal operation.
 //! - **Bypassed**: Passes audio through.
 
-use crate::dsp::utils::{lerp, perceptual_curve};
+use crate::dsp::utils::{lerp, perceptual_curve, smoothstep};
 use crate::dsp::Biquad;
 
 // Constants for proximity effect tuning
@@ -54,6 +57,21 @@ const COEFF_UPDATE_THRESHOLD: f32 = 0.05;
 // Bypass threshold for proximity amount.
 // Increasing: easier to bypass; decreasing: more likely to process.
 const PROXIMITY_BYPASS_EPS: f32 = 0.001;
+// Track Room: early/late ratio at which the mic reads fully distant / close
+// (the distant-mic condition fires below 0.05, pro VO sits at 0.5-0.7).
+const DISTANT_EARLY_LATE: f32 = 0.05;
+const CLOSE_EARLY_LATE: f32 = 0.6;
+// Track Room: decay slope range from a dry to a clearly reverberant tail
+const DRY_DECAY_SLOPE: f32 = -0.0001;
+const ROOMY_DECAY_SLOPE: f32 = -0.001;
+// Weight of the early/late ratio in the distance estimate (rest: decay slope)
+const EARLY_LATE_WEIGHT: f32 = 0.7;
+// Track Room: distance estimate time constant (seconds), updated during speech only
+const DISTANCE_TAU_SEC: f32 = 3.0;
+const DISTANCE_SPEECH_CONF: f32 = 0.5;
+// Amount scale for a fully close / fully distant mic (1.0 at mid distance)
+const CLOSE_SCALE: f32 = 0.5;
+const DISTANT_SCALE: f32 = 1.5;
 // De-verb contribution scale for proximity.
 // Increasing: more de-verb reduction at high proximity; decreasing: less contribution.
 const DEVERB_CONTRIB_SCALE: f32 = 0.4;
@@ -173,6 +191,12 @@ impl Proximity {
         self.hf_shelf.process(s1)
     }
 
+    /// Clear the filter state; the shelves keep their current settings
+    pub fn reset(&mut self) {
+        self.low_shelf.reset_state();
+        self.hf_shelf.reset_state();
+    }
+
     /// If `reverb_amt` is *de-reverb strength* (reverb reduction):
     /// closer mic should need LESS reduction, not more.
     /// Returns a reduction offset (0..DEVERB_CONTRIB_SCALE).
@@ -181,3 +205,92 @@ impl Proximity {
         p * DEVERB_CONTRIB_SCALE
     }
 }
+
+/// Slow mic distance estimate for Track Room (0 = close, 1 = distant).
+pub struct DistanceTracker {
+    distance: f32,
+}
+
+impl Default for DistanceTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DistanceTracker {
+    pub fn new() -> Self {
+        Self { distance: 0.5 }
+    }
+
+    pub fn reset(&mut self) {
+        self.distance = 0.5;
+    }
+
+    /// Feed one block of input profile metrics covering `dt_sec` seconds.
+    /// Pauses are skipped so room tone does not read as distance.
+    pub fn update(
+        &mut self,
+        early_late_ratio: f32,
+        decay_slope: f32,
+        speech_conf: f32,
+        dt_sec: f32,
+    ) {
+        if speech_conf < DISTANCE_SPEECH_CONF {
+            return;
+        }
+        let from_ratio = 1.0 - smoothstep(DISTANT_EARLY_LATE, CLOSE_EARLY_LATE, early_late_ratio);
+        let from_decay = ((DRY_DECAY_SLOPE - decay_slope) / (DRY_DECAY_SLOPE - ROOMY_DECAY_SLOPE))
+            .clamp(0.0, 1.0);
+        let target = EARLY_LATE_WEIGHT * from_ratio + (1.0 - EARLY_LATE_WEIGHT) * from_decay;
+
+        let alpha = 1.0 - (-dt_sec.max(0.0) / DISTANCE_TAU_SEC).exp();
+        self.distance += (target - self.distance) * alpha;
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Multiplier for the proximity amount (0.5 close .. 1.5 distant)
+    pub fn amount_scale(&self) -> f32 {
+        if self.distance < 0.5 {
+            lerp(CLOSE_SCALE, 1.0, self.distance / 0.5)
+        } else {
+            lerp(1.0, DISTANT_SCALE, (self.distance - 0.5) / 0.5)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(t: &mut DistanceTracker, elr: f32, slope: f32, conf: f32) {
+        for _ in 0..1000 {
+            t.update(elr, slope, conf, 0.01);
+        }
+    }
+
+    #[test]
+    fn test_distance_follows_room_metrics() {
+        let mut t = DistanceTracker::new();
+        assert_eq!(t.amount_scale(), 1.0);
+
+        settle(&mut t, 0.02, -0.002, 0.9);
+        assert!(t.distance() > 0.95);
+        assert!(t.amount_scale() > 1.45);
+
+        settle(&mut t, 0.7, 0.0, 0.9);
+        assert!(t.distance() < 0.05);
+        assert!(t.amount_scale() < 0.55);
+    }
+
+    #[test]
+    fn test_pauses_hold_the_estimate() {
+        let mut t = DistanceTracker::new();
+        settle(&mut t, 0.7, 0.0, 0.9);
+        let close = t.distance();
+        settle(&mut t, 0.0, -0.01, 0.1);
+        assert_eq!(t.distance(), close);
+    }
+}
@@ -0,0 +1,188 @@
+//! Pump Guard (repeated-pump escalation)
+//!
+//! Watches pump events from the leveler and the loudness compensation and,
+//! when they keep coming back, calms both down until the material settles.
+//!
+//! # Purpose
+//! A single pump event is usually a one-off: a shout, a cough, a hard cut.
+//! Several inside a few seconds mean the leveler and the loudness
+//! compensation are chasing each other on this material, and every further
+//! correction makes the breathing worse. Backing off for a while breaks the
+//! loop without the user having to find which setting caused it.
+//!
+//! # Design Notes
+//! - Events are rate-limited (one per 250 ms), so one long pump that trips
+//!   the detector on consecutive samples still counts once
+//! - A leaky event score (5 s decay) engages the guard at 3; it releases
+//!   only after 4 s without an event and once the score has drained below 1
+//! - The guard strength ramps in over 200 ms and relaxes over 3 s, so the
+//!   leveler and loudness compensation never step
+//! - Fully engaged: leveler amount x0.6, loudness compensation time
+//!   constant x3
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Minimum spacing between counted events
+const EVENT_SPACING_MS: f32 = 250.0;
+
+/// Event score decay time constant
+const SCORE_DECAY_SEC: f32 = 5.0;
+/// Score at which the guard engages
+const ENGAGE_SCORE: f32 = 3.0;
+/// Score below which an engaged guard may release
+const RELEASE_SCORE: f32 = 1.0;
+/// Event-free time required before releasing
+const RELEASE_STABLE_SEC: f32 = 4.0;
+
+/// Strength ramps
+const ENGAGE_RAMP_MS: f32 = 200.0;
+const RELAX_RAMP_SEC: f32 = 3.0;
+
+/// Leveler amount reduction at full strength
+const LEVELER_CUT: f32 = 0.4;
+/// Extra loudness compensation time constant at full strength (x1 + this)
+const LOUDNESS_SLOWDOWN: f32 = 2.0;
+
+// =============================================================================
+// Guard
+// =============================================================================
+
+pub struct PumpGuard {
+    score: f32,
+    engaged: bool,
+    /// Samples since the last counted event
+    since_event: u64,
+    /// Smoothed guard strength (0 = idle, 1 = fully engaged)
+    strength: f32,
+
+    sample_rate: f32,
+}
+
+impl PumpGuard {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            score: 0.0,
+            engaged: false,
+            since_event: u64::MAX,
+            strength: 0.0,
+
+            sample_rate,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.score = 0.0;
+        self.engaged = false;
+        self.since_event = u64::MAX;
+        self.strength = 0.0;
+    }
+
+    /// Advance by `samples`, with `pump_detected` set if a pump event was
+    /// seen in that span. `samples` may be 0 to report an event only.
+    pub fn process(&mut self, pump_detected: bool, samples: usize) {
+        let sr = self.sample_rate;
+        let span = samples as f32;
+
+        self.score *= (-span / (SCORE_DECAY_SEC * sr)).exp();
+        self.since_event = self.since_event.saturating_add(samples as u64);
+
+        if pump_detected && self.since_event as f32 >= EVENT_SPACING_MS * 0.001 * sr {
+            self.score += 1.0;
+            self.since_event = 0;
+        }
+
+        if self.score >= ENGAGE_SCORE {
+            self.engaged = true;
+        } else if self.engaged
+            && self.score < RELEASE_SCORE
+            && self.since_event as f32 >= RELEASE_STABLE_SEC * sr
+        {
+            self.engaged = false;
+        }
+
+        self.strength = if self.engaged {
+            (self.strength + span / (ENGAGE_RAMP_MS * 0.001 * sr)).min(1.0)
+        } else {
+            (self.strength - span / (RELAX_RAMP_SEC * sr)).max(0.0)
+        };
+    }
+
+    /// Guard is holding the leveler back (drives the UI notice)
+    pub fn is_engaged(&self) -> bool {
+        self.engaged
+    }
+
+    /// Smoothed guard strength (0..1)
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    /// Multiplier for the leveler amount
+    pub fn leveler_scale(&self) -> f32 {
+        1.0 - LEVELER_CUT * self.strength
+    }
+
+    /// Multiplier for the loudness compensation time constant
+    pub fn loudness_time_scale(&self) -> f32 {
+        1.0 + LOUDNESS_SLOWDOWN * self.strength
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+    const BLOCK: usize = 64;
+
+    /// Run for `sec`, flagging an event every `every_sec` (0 = none)
+    fn run(g: &mut PumpGuard, sec: f32, every_sec: f32) {
+        let blocks = (sec * SR) as usize / BLOCK;
+        let every = (every_sec * SR) as usize / BLOCK;
+        for i in 0..blocks {
+            g.process(every > 0 && i % every == 0, BLOCK);
+        }
+    }
+
+    #[test]
+    fn test_isolated_and_clustered_events() {
+        // One event every 3 s never builds up
+        let mut g = PumpGuard::new(SR);
+        run(&mut g, 20.0, 3.0);
+        assert!(!g.is_engaged());
+        assert_eq!(g.strength(), 0.0);
+
+        // A burst on consecutive blocks counts once
+        let mut g = PumpGuard::new(SR);
+        run(&mut g, 0.1, BLOCK as f32 / SR);
+        assert!(!g.is_engaged());
+
+        // Events every 0.5 s engage within a couple of seconds
+        run(&mut g, 2.0, 0.5);
+        assert!(g.is_engaged());
+        assert!(g.leveler_scale() < 0.7);
+        assert!(g.loudness_time_scale() > 2.5);
+    }
+
+    #[test]
+    fn test_relaxes_once_stable() {
+        let mut g = PumpGuard::new(SR);
+        run(&mut g, 3.0, 0.5);
+        assert!(g.is_engaged());
+
+        // Still held shortly after the last event
+        run(&mut g, 2.0, 0.0);
+        assert!(g.is_engaged());
+
+        // Settles, then ramps back to idle
+        run(&mut g, 12.0, 0.0);
+        assert!(!g.is_engaged());
+        assert_eq!(g.strength(), 0.0);
+        assert_eq!(g.leveler_scale(), 1.0);
+    }
+}
@@ -0,0 +1,487 @@
+//! Room Tone Bridge
+//!
+//! Smooths the room-tone step at an edit between two takes. When the noise
+//! floor jumps from one room to another, the new floor is EQ'd to sound like
+//! the old one and then morphed across to its own character over a second
+//! and a half, so the cut is heard as the room gently changing rather than
+//! switching - without laying room tone by hand.
+//!
+//! # Design Notes
+//! - The floor is tracked in pauses only, per octave band from 125 Hz to
+//!   8 kHz: a slow average is the room tone, a fast one what the room sounds
+//!   like right now. The spread of the fast levels around the slow ones is
+//!   the floor's own variance
+//! - A cut is the fast levels leaving the room tone by more than `JUMP_DB`
+//!   or `JUMP_SIGMAS` standard deviations (whichever is larger) for
+//!   `JUMP_HOLD_MS`. A floor that wanders slowly (HVAC ramping up) is
+//!   followed by the slow average and never counts
+//! - After a cut the new floor is measured for `SETTLE_MS` and then becomes
+//!   the room tone; the correction is old minus new per band (at most
+//!   `MAX_CORRECTION_DB`), gliding to 0 dB over `MORPH_SEC` on a cascade of
+//!   peaking filters at the band centers (shelves for the outer two)
+//! - `analyze()` runs on the sidechain, which leads the output; `set_lead()`
+//!   holds the correction back by that lead so it lands on the cut
+//! - Pauses only: the correction fades out under speech (20 ms) so the voice
+//!   is never re-EQ'd, and returns in the next pause (100 ms)
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `analyze()` / `process()`
+
+use super::biquad::Biquad;
+use super::utils::{smoothstep, time_constant_coeff, DB_EPS};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Band centers (Hz, octaves)
+const BAND_CENTERS_HZ: [f32; 7] = [125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0];
+const BANDS: usize = BAND_CENTERS_HZ.len();
+/// Measurement band-pass Q (one octave) and sections per band
+const BAND_Q: f32 = 1.41;
+const BAND_STAGES: usize = 2;
+/// Correction peaking filter Q (neighbours overlap into a smooth curve)
+const PEAK_Q: f32 = 1.0;
+/// Shelf Q for the lowest and highest band
+const SHELF_Q: f32 = 0.707;
+
+/// Speech confidence below which the input counts as pause
+const PAUSE_CONFIDENCE: f32 = 0.3;
+/// Room tone (slow) and current floor (fast) averaging
+const FLOOR_TAU_SEC: f32 = 1.0;
+const FAST_TAU_MS: f32 = 25.0;
+/// Averaging of the floor's own variance (seconds of pause)
+const VARIANCE_TAU_SEC: f32 = 5.0;
+/// Pause needed before the room tone is trusted (seconds)
+const MIN_FLOOR_SEC: f32 = 1.5;
+
+/// Smallest floor change that counts as a cut (dB, RMS over the bands)
+const JUMP_DB: f32 = 4.0;
+/// Floor change in standard deviations of its own variance that counts
+const JUMP_SIGMAS: f32 = 3.0;
+/// Time the change must hold for (pause)
+const JUMP_HOLD_MS: f32 = 30.0;
+/// New floor measurement after a cut before it becomes the room tone
+const SETTLE_MS: f32 = 150.0;
+
+/// Largest correction per band (dB)
+const MAX_CORRECTION_DB: f32 = 12.0;
+/// Glide of the correction to 0 dB (seconds)
+const MORPH_SEC: f32 = 1.5;
+/// Smoothing of the applied band gains
+const GAIN_SMOOTH_MS: f32 = 10.0;
+
+/// Speech confidence range over which the correction fades out
+const GATE_SPEECH_LO: f32 = 0.25;
+const GATE_SPEECH_HI: f32 = 0.55;
+const GATE_FADE_OUT_MS: f32 = 20.0;
+const GATE_FADE_IN_MS: f32 = 100.0;
+
+/// Samples between floor decisions and filter updates
+const CONTROL_INTERVAL: usize = 32;
+
+// =============================================================================
+// Bridge
+// =============================================================================
+
+type Band = [Biquad; BAND_STAGES];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FloorState {
+    /// Following the room tone
+    Tracking,
+    /// Measuring the new floor after a cut (pause samples left)
+    Settling(u32),
+}
+
+pub struct RoomToneBridge {
+    sample_rate: f32,
+    /// Bands below the safe Nyquist limit
+    band_count: usize,
+
+    // Analysis (sidechain)
+    bands: [Band; BANDS],
+    fast_power: [f32; BANDS],
+    floor_power: [f32; BANDS],
+    variance: f32,
+    pause_samples: u32,
+    min_floor_samples: u32,
+    hold_samples: u32,
+    state: FloorState,
+    analysis_pos: usize,
+
+    fast_alpha: f32,
+    floor_alpha: f32,
+    variance_alpha: f32,
+    jump_hold_samples: u32,
+    settle_samples: u32,
+
+    // Correction (output)
+    /// Old floor minus new floor per band (dB)
+    correction_db: [f32; BANDS],
+    /// Samples until a detected cut reaches the output
+    pending: Option<u32>,
+    /// Remaining share of the correction (1 at the cut, 0 when done)
+    morph: f32,
+    morph_step: f32,
+    applied_db: [f32; BANDS],
+    peaks_l: [Biquad; BANDS],
+    peaks_r: [Biquad; BANDS],
+    active: bool,
+    gate: f32,
+    gate_in: f32,
+    gate_out: f32,
+    gain_alpha: f32,
+    lead_samples: u32,
+    process_pos: usize,
+    bridges: u32,
+}
+
+impl RoomToneBridge {
+    pub fn new(sample_rate: f32) -> Self {
+        let sr = sample_rate.max(1.0);
+        let band_count = BAND_CENTERS_HZ.iter().filter(|&&c| c < 0.42 * sr).count();
+        let band = |c: f32| {
+            let mut band = [Biquad::new(); BAND_STAGES];
+            for f in band.iter_mut() {
+                f.update_bandpass(c.min(0.42 * sr), BAND_Q, sr);
+            }
+            band
+        };
+        let ms_to_samples = |ms: f32| (ms * 0.001 * sr) as u32;
+        let interval = CONTROL_INTERVAL as f32;
+
+        Self {
+            sample_rate: sr,
+            band_count,
+
+            bands: BAND_CENTERS_HZ.map(band),
+            fast_power: [0.0; BANDS],
+            floor_power: [0.0; BANDS],
+            variance: 0.0,
+            pause_samples: 0,
+            min_floor_samples: (MIN_FLOOR_SEC * sr) as u32,
+            hold_samples: 0,
+            state: FloorState::Tracking,
+            analysis_pos: 0,
+
+            fast_alpha: 1.0 - time_constant_coeff(FAST_TAU_MS, sr),
+            floor_alpha: 1.0 - time_constant_coeff(FLOOR_TAU_SEC * 1000.0, sr),
+            variance_alpha: 1.0 - time_constant_coeff(VARIANCE_TAU_SEC * 1000.0, sr / interval),
+            jump_hold_samples: ms_to_samples(JUMP_HOLD_MS),
+            settle_samples: ms_to_samples(SETTLE_MS),
+
+            correction_db: [0.0; BANDS],
+            pending: None,
+            morph: 0.0,
+            morph_step: interval / (MORPH_SEC * sr),
+            applied_db: [0.0; BANDS],
+            peaks_l: [Biquad::new(); BANDS],
+            peaks_r: [Biquad::new(); BANDS],
+            active: false,
+            gate: 0.0,
+            gate_in: time_constant_coeff(GATE_FADE_IN_MS, sr),
+            gate_out: time_constant_coeff(GATE_FADE_OUT_MS, sr),
+            gain_alpha: 1.0 - time_constant_coeff(GAIN_SMOOTH_MS, sr / interval),
+            lead_samples: 0,
+            process_pos: 0,
+            bridges: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for f in self.bands.iter_mut().flatten() {
+            f.reset_state();
+        }
+        self.fast_power = [0.0; BANDS];
+        self.floor_power = [0.0; BANDS];
+        self.variance = 0.0;
+        self.pause_samples = 0;
+        self.hold_samples = 0;
+        self.state = FloorState::Tracking;
+        self.analysis_pos = 0;
+        self.correction_db = [0.0; BANDS];
+        self.pending = None;
+        self.morph = 0.0;
+        self.deactivate();
+        self.gate = 0.0;
+        self.process_pos = 0;
+        self.bridges = 0;
+    }
+
+    /// How far the `analyze()` input leads the `process()` input (samples)
+    pub fn set_lead(&mut self, samples: usize) {
+        self.lead_samples = samples as u32;
+    }
+
+    /// Cuts bridged since the last reset
+    pub fn bridges(&self) -> u32 {
+        self.bridges
+    }
+
+    /// A correction is being applied
+    pub fn is_bridging(&self) -> bool {
+        self.active
+    }
+
+    // -------------------------------------------------------------------------
+    // Analysis
+    // -------------------------------------------------------------------------
+
+    /// Track the floor on the sidechain input and detect cuts
+    pub fn analyze(&mut self, left: f32, right: f32, speech_conf: f32) {
+        let pause = speech_conf < PAUSE_CONFIDENCE;
+        if pause {
+            let mid = 0.5 * (left + right);
+            for k in 0..self.band_count {
+                let y = self.bands[k].iter_mut().fold(mid, |x, f| f.process(x));
+                let p = y * y;
+                self.fast_power[k] += (p - self.fast_power[k]) * self.fast_alpha;
+                if self.state == FloorState::Tracking {
+                    self.floor_power[k] += (p - self.floor_power[k]) * self.floor_alpha;
+                }
+            }
+            self.pause_samples = self.pause_samples.saturating_add(1);
+        }
+
+        self.analysis_pos += 1;
+        if self.analysis_pos < CONTROL_INTERVAL {
+            return;
+        }
+        self.analysis_pos = 0;
+        if !pause || self.pause_samples < self.min_floor_samples {
+            return;
+        }
+
+        let interval = CONTROL_INTERVAL as u32;
+        match self.state {
+            FloorState::Tracking => {
+                let distance = self.floor_distance_db();
+                let threshold = JUMP_DB.max(JUMP_SIGMAS * self.variance.sqrt());
+                if distance > threshold {
+                    self.hold_samples += interval;
+                    if self.hold_samples >= self.jump_hold_samples {
+                        self.start_bridge();
+                    }
+                } else {
+                    self.hold_samples = 0;
+                    self.variance += (distance * distance - self.variance) * self.variance_alpha;
+                }
+            }
+            FloorState::Settling(left) => {
+                self.update_correction();
+                if left > interval {
+                    self.state = FloorState::Settling(left - interval);
+                } else {
+                    // The new floor is the room tone from here on
+                    self.floor_power = self.fast_power;
+                    self.state = FloorState::Tracking;
+                }
+            }
+        }
+    }
+
+    /// RMS over the bands of the current floor against the room tone (dB)
+    fn floor_distance_db(&self) -> f32 {
+        let n = self.band_count.max(1);
+        let sum: f32 = (0..self.band_count)
+            .map(|k| {
+                let d = band_db(self.fast_power[k]) - band_db(self.floor_power[k]);
+                d * d
+            })
+            .sum();
+        (sum / n as f32).sqrt()
+    }
+
+    fn start_bridge(&mut self) {
+        self.hold_samples = 0;
+        self.state = FloorState::Settling(self.settle_samples);
+        self.update_correction();
+        // The cut was `JUMP_HOLD_MS` plus about one fast time constant ago
+        let detection_delay = ((JUMP_HOLD_MS + FAST_TAU_MS) * 0.001 * self.sample_rate) as u32;
+        self.pending = Some(self.lead_samples.saturating_sub(detection_delay));
+        self.bridges = self.bridges.saturating_add(1);
+    }
+
+    fn update_correction(&mut self) {
+        for k in 0..self.band_count {
+            self.correction_db[k] = (band_db(self.floor_power[k]) - band_db(self.fast_power[k]))
+                .clamp(-MAX_CORRECTION_DB, MAX_CORRECTION_DB);
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Correction
+    // -------------------------------------------------------------------------
+
+    /// Apply the bridge to one stereo output sample
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32, speech_conf: f32) -> (f32, f32) {
+        let target = 1.0 - smoothstep(GATE_SPEECH_LO, GATE_SPEECH_HI, speech_conf);
+        let coeff = if target > self.gate {
+            self.gate_in
+        } else {
+            self.gate_out
+        };
+        self.gate = target + coeff * (self.gate - target);
+
+        self.process_pos += 1;
+        if self.process_pos >= CONTROL_INTERVAL {
+            self.process_pos = 0;
+            self.update_filters();
+        }
+        if !self.active {
+            return (left, right);
+        }
+
+        let l = self.peaks_l.iter_mut().fold(left, |x, f| f.process(x));
+        let r = self.peaks_r.iter_mut().fold(right, |x, f| f.process(x));
+        (l, r)
+    }
+
+    fn update_filters(&mut self) {
+        if let Some(left) = self.pending {
+            let interval = CONTROL_INTERVAL as u32;
+            if left > interval {
+                self.pending = Some(left - interval);
+            } else {
+                self.pending = None;
+                self.morph = 1.0;
+            }
+        }
+        if self.morph == 0.0 && !self.active {
+            return;
+        }
+        self.morph = (self.morph - self.morph_step).max(0.0);
+
+        let weight = self.morph * self.gate;
+        let mut settled = self.morph == 0.0;
+        for k in 0..self.band_count {
+            let target = self.correction_db[k] * weight;
+            self.applied_db[k] += (target - self.applied_db[k]) * self.gain_alpha;
+            settled &= self.applied_db[k].abs() < 0.01;
+        }
+        if settled {
+            self.deactivate();
+            return;
+        }
+
+        self.active = true;
+        let top = self.band_count.saturating_sub(1);
+        for k in 0..self.band_count {
+            let (center, gain, sr) = (BAND_CENTERS_HZ[k], self.applied_db[k], self.sample_rate);
+            for f in [&mut self.peaks_l[k], &mut self.peaks_r[k]] {
+                // The outer bands carry on to the ends of the spectrum
+                if k == 0 {
+                    f.update_low_shelf(center, SHELF_Q, gain, sr);
+                } else if k == top {
+                    f.update_high_shelf(center, SHELF_Q, gain, sr);
+                } else {
+                    f.update_peaking(center, PEAK_Q, gain, sr);
+                }
+            }
+        }
+    }
+
+    fn deactivate(&mut self) {
+        self.active = false;
+        self.applied_db = [0.0; BANDS];
+        for f in self.peaks_l.iter_mut().chain(self.peaks_r.iter_mut()) {
+            f.reset();
+        }
+    }
+}
+
+fn band_db(power: f32) -> f32 {
+    10.0 * (power + DB_EPS).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::{TestSignalGenerator, TestSignalKind};
+
+    const SR: f32 = 48000.0;
+
+    fn rms(x: &[f32]) -> f32 {
+        (x.iter().map(|v| v * v).sum::<f32>() / x.len() as f32).sqrt()
+    }
+
+    /// Pink noise pause: `first_sec` at `first_gain`, then `second_gain`
+    fn edit(first_sec: f32, first_gain: f32, second_gain: f32, total_sec: f32) -> Vec<f32> {
+        let mut pink = TestSignalGenerator::new(SR);
+        let cut = (first_sec * SR) as usize;
+        (0..(total_sec * SR) as usize)
+            .map(|i| {
+                let g = if i < cut { first_gain } else { second_gain };
+                g * pink.next(TestSignalKind::PinkNoise)
+            })
+            .collect()
+    }
+
+    fn run(bridge: &mut RoomToneBridge, input: &[f32], speech_conf: f32) -> Vec<f32> {
+        input
+            .iter()
+            .map(|&x| {
+                bridge.analyze(x, x, speech_conf);
+                bridge.process(x, x, speech_conf).0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_steady_room_tone_is_untouched() {
+        let input = edit(4.0, 0.05, 0.05, 4.0);
+        let mut bridge = RoomToneBridge::new(SR);
+        let out = run(&mut bridge, &input, 0.0);
+        assert_eq!(bridge.bridges(), 0);
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn test_cut_is_bridged_then_released() {
+        // +12 dB room tone step at 2 s
+        let input = edit(2.0, 0.02, 0.08, 5.0);
+        let mut bridge = RoomToneBridge::new(SR);
+        let out = run(&mut bridge, &input, 0.0);
+        assert_eq!(bridge.bridges(), 1);
+
+        let window = |s: f32| {
+            let start = (s * SR) as usize;
+            rms(&out[start..start + (0.1 * SR) as usize])
+                / rms(&input[start..start + (0.1 * SR) as usize])
+        };
+        // Shortly after the cut the new floor is pulled back towards the old
+        let early = 20.0 * window(2.3).log10();
+        assert!(early < -6.0, "early {early} dB");
+        // Then morphs across to its own level
+        let late = 20.0 * window(4.5).log10();
+        assert!(late.abs() < 0.5, "late {late} dB");
+        assert!(!bridge.is_bridging());
+    }
+
+    #[test]
+    fn test_slow_drift_is_followed() {
+        // +8 dB over 4 s
+        let mut pink = TestSignalGenerator::new(SR);
+        let len = (6.0 * SR) as usize;
+        let input: Vec<f32> = (0..len)
+            .map(|i| {
+                let t = (i as f32 / SR - 2.0).clamp(0.0, 4.0) / 4.0;
+                0.02 * 10f32.powf(8.0 * t / 20.0) * pink.next(TestSignalKind::PinkNoise)
+            })
+            .collect();
+        let mut bridge = RoomToneBridge::new(SR);
+        run(&mut bridge, &input, 0.0);
+        assert_eq!(bridge.bridges(), 0);
+    }
+
+    #[test]
+    fn test_speech_is_never_corrected() {
+        let input = edit(2.0, 0.02, 0.08, 4.0);
+        let mut bridge = RoomToneBridge::new(SR);
+        let out = run(&mut bridge, &input, 1.0);
+        assert_eq!(bridge.bridges(), 0);
+        assert_eq!(out, input);
+    }
+}
@@ -0,0 +1,282 @@
+//! Room Decay (RT60) Estimator
+//!
+//! Blind estimate of the room reverberation time from the free decays that
+//! follow phrase endings. Energy is tracked in short frames; whenever the level
+//! falls steadily from well above the noise floor, the decay run is fitted with
+//! a straight line (dB vs time) and extrapolated to 60 dB.
+//!
+//! # Purpose
+//! Gives the deverber a sense of room size so the same De-Verb setting behaves
+//! consistently in small booths and large reverberant rooms, and reports the
+//! estimate to the UI.
+//!
+//! # Design Notes
+//! - Analysis only; never modifies audio
+//! - Uses pre-restoration (HPF) input so processing does not bias the estimate
+//! - Only steady, monotonic decays spanning enough range are accepted
+//! - Estimates are smoothed across decays; the first good decay seeds the value
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size decay buffer, no allocations in `process()`
+
+use super::utils::lin_to_db;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Energy frame length in milliseconds
+const FRAME_MS: f32 = 10.0;
+
+/// Maximum number of frames in one decay run (640 ms)
+const MAX_DECAY_FRAMES: usize = 64;
+
+/// Minimum number of frames for a decay run to be fitted (80 ms)
+const MIN_DECAY_FRAMES: usize = 8;
+
+/// Minimum level range a decay run must cover to be fitted (dB)
+const MIN_DECAY_RANGE_DB: f32 = 10.0;
+
+/// Minimum per-frame drop to count as "still decaying" (dB)
+const DECAY_STEP_MIN_DB: f32 = 0.1;
+
+/// A decay run must start this far above the noise floor (dB)
+const ONSET_MARGIN_DB: f32 = 20.0;
+
+/// A decay run ends once the level is within this margin of the noise floor (dB)
+const NOISE_MARGIN_DB: f32 = 6.0;
+
+/// Noise floor rise rate per frame (fall is instant)
+const NOISE_FLOOR_RISE: f32 = 0.002;
+
+/// Weight of each accepted decay in the running estimate
+const ESTIMATE_SMOOTHING: f32 = 0.2;
+
+/// Estimate bounds (ms)
+const RT60_MIN_MS: f32 = 100.0;
+const RT60_MAX_MS: f32 = 3000.0;
+
+/// Value reported before any decay has been measured (ms)
+pub const DEFAULT_RT60_MS: f32 = 400.0;
+
+// =============================================================================
+// RT60 Estimator
+// =============================================================================
+
+/// Stereo-linked blind RT60 estimator
+pub struct Rt60Estimator {
+    frame_size: usize,
+
+    // Frame accumulation
+    energy_acc: f32,
+    sample_count: usize,
+
+    // Level tracking
+    prev_frame_db: f32,
+    noise_floor_db: f32,
+
+    // Current decay run (frame levels in dB)
+    decay_run: [f32; MAX_DECAY_FRAMES],
+    decay_len: usize,
+
+    // Output
+    rt60_ms: f32,
+    decay_count: u32,
+}
+
+impl Rt60Estimator {
+    pub fn new(sample_rate: f32) -> Self {
+        let frame_size = ((FRAME_MS * 0.001 * sample_rate) as usize).max(1);
+
+        Self {
+            frame_size,
+            energy_acc: 0.0,
+            sample_count: 0,
+            prev_frame_db: -120.0,
+            noise_floor_db: -120.0,
+            decay_run: [0.0; MAX_DECAY_FRAMES],
+            decay_len: 0,
+            rt60_ms: DEFAULT_RT60_MS,
+            decay_count: 0,
+        }
+    }
+
+    /// Process a stereo sample pair
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32) {
+        let mono = 0.5 * (left + right);
+        self.energy_acc += mono * mono;
+        self.sample_count += 1;
+
+        if self.sample_count >= self.frame_size {
+            let rms = (self.energy_acc / self.sample_count as f32).sqrt();
+            self.energy_acc = 0.0;
+            self.sample_count = 0;
+            self.analyze_frame(lin_to_db(rms));
+        }
+    }
+
+    fn analyze_frame(&mut self, frame_db: f32) {
+        // Noise floor: instant fall, slow rise
+        if frame_db < self.noise_floor_db {
+            self.noise_floor_db = frame_db;
+        } else {
+            self.noise_floor_db += (frame_db - self.noise_floor_db) * NOISE_FLOOR_RISE;
+        }
+
+        let falling = frame_db < self.prev_frame_db - DECAY_STEP_MIN_DB;
+        let above_floor = frame_db > self.noise_floor_db + NOISE_MARGIN_DB;
+
+        if falling && above_floor {
+            if self.decay_len == 0 && self.prev_frame_db > self.noise_floor_db + ONSET_MARGIN_DB {
+                // Start a run from the last level before the fall
+                self.decay_run[0] = self.prev_frame_db;
+                self.decay_len = 1;
+            }
+
+            if self.decay_len > 0 {
+                if self.decay_len < MAX_DECAY_FRAMES {
+                    self.decay_run[self.decay_len] = frame_db;
+                    self.decay_len += 1;
+                } else {
+                    self.finish_decay();
+                }
+            }
+        } else if self.decay_len > 0 {
+            self.finish_decay();
+        }
+
+        self.prev_frame_db = frame_db;
+    }
+
+    /// Fit the current decay run and fold it into the estimate
+    fn finish_decay(&mut self) {
+        let n = self.decay_len;
+        self.decay_len = 0;
+
+        if n < MIN_DECAY_FRAMES {
+            return;
+        }
+
+        // Skip the first step: it contains the direct-sound offset, not the room
+        let run = &self.decay_run[1..n];
+        let range_db = run[0] - run[run.len() - 1];
+        if range_db < MIN_DECAY_RANGE_DB {
+            return;
+        }
+
+        // Least-squares slope in dB per frame
+        let count = run.len() as f32;
+        let mean_x = (count - 1.0) * 0.5;
+        let mean_y = run.iter().sum::<f32>() / count;
+        let mut num = 0.0f32;
+        let mut den = 0.0f32;
+        for (i, &y) in run.iter().enumerate() {
+            let dx = i as f32 - mean_x;
+            num += dx * (y - mean_y);
+            den += dx * dx;
+        }
+        if den <= 0.0 {
+            return;
+        }
+        let slope_db_per_frame = num / den;
+        if slope_db_per_frame >= -1e-3 {
+            return;
+        }
+
+        let measured = (-60.0 / slope_db_per_frame * FRAME_MS).clamp(RT60_MIN_MS, RT60_MAX_MS);
+
+        if self.decay_count == 0 {
+            self.rt60_ms = measured;
+        } else {
+            self.rt60_ms += (measured - self.rt60_ms) * ESTIMATE_SMOOTHING;
+        }
+        self.decay_count = self.decay_count.saturating_add(1);
+    }
+
+    /// Current RT60 estimate in milliseconds
+    #[inline]
+    pub fn get_rt60_ms(&self) -> f32 {
+        self.rt60_ms
+    }
+
+    /// Number of decays that contributed to the estimate
+    #[inline]
+    pub fn get_decay_count(&self) -> u32 {
+        self.decay_count
+    }
+
+    /// Reset all state
+    pub fn reset(&mut self) {
+        self.energy_acc = 0.0;
+        self.sample_count = 0;
+        self.prev_frame_db = -120.0;
+        self.noise_floor_db = -120.0;
+        self.decay_len = 0;
+        self.rt60_ms = DEFAULT_RT60_MS;
+        self.decay_count = 0;
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bursts of noise followed by an exponential decay with the given RT60
+    fn run_bursts(estimator: &mut Rt60Estimator, sample_rate: f32, rt60_ms: f32) {
+        let decay_per_sample = 10.0f32.powf(-3.0 / (rt60_ms * 0.001 * sample_rate));
+        let mut seed = 12345u32;
+        let mut noise = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as f32 / 32_768.0 - 1.0
+        };
+
+        for _ in 0..6 {
+            // 300 ms burst
+            for _ in 0..(0.3 * sample_rate) as usize {
+                let x = 0.5 * noise();
+                estimator.process(x, x);
+            }
+            // 1.5 s decay
+            let mut env = 0.5;
+            for _ in 0..(1.5 * sample_rate) as usize {
+                env *= decay_per_sample;
+                let x = env * noise() + 1e-5 * noise();
+                estimator.process(x, x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_before_decay() {
+        let estimator = Rt60Estimator::new(48_000.0);
+        assert_eq!(estimator.get_rt60_ms(), DEFAULT_RT60_MS);
+        assert_eq!(estimator.get_decay_count(), 0);
+    }
+
+    #[test]
+    fn test_estimates_synthetic_decay() {
+        let sample_rate = 48_000.0;
+        let mut estimator = Rt60Estimator::new(sample_rate);
+        run_bursts(&mut estimator, sample_rate, 600.0);
+
+        assert!(estimator.get_decay_count() > 0);
+        let rt60 = estimator.get_rt60_ms();
+        assert!(rt60 > 400.0 && rt60 < 800.0, "rt60 = {}", rt60);
+    }
+
+    #[test]
+    fn test_longer_room_gives_longer_estimate() {
+        let sample_rate = 48_000.0;
+        let mut small = Rt60Estimator::new(sample_rate);
+        let mut large = Rt60Estimator::new(sample_rate);
+        run_bursts(&mut small, sample_rate, 250.0);
+        run_bursts(&mut large, sample_rate, 900.0);
+
+        assert!(large.get_rt60_ms() > small.get_rt60_ms());
+    }
+}
@@ -0,0 +1,184 @@
+//! Time-Based Scheduling
+//!
+//! Cooldowns, holds, periodic maintenance and crossfades measured in
+//! milliseconds, advanced by however many samples each call covers.
+//!
+//! # Purpose
+//! Counting buffers or fixed sample strides ties timing to the host: a
+//! "1000-sample" interval checked once per buffer runs every 64 samples at a
+//! 64-sample block size and every 2048 at 2048. These helpers convert from
+//! time with the actual sample rate, so behaviour is the same at any rate
+//! and block size.
+//!
+//! # Design Notes
+//! - Everything counts samples internally; `advance()` takes the span just
+//!   processed (a buffer, a control sub-block or a single sample)
+//! - `Interval` keeps its phase across calls, so a period that does not
+//!   divide the block size still fires at the right average rate (periods
+//!   longer than the longest span it is advanced by)
+//!
+//! ## Audio Thread Safety
+//! - Plain counters, no allocations
+
+// =============================================================================
+// Conversions
+// =============================================================================
+
+/// Milliseconds to samples at `sample_rate` (rounded)
+pub fn ms_to_samples(ms: f32, sample_rate: f32) -> u32 {
+    (ms * 0.001 * sample_rate).round().max(0.0) as u32
+}
+
+/// Milliseconds to whole buffers of `block_size` samples (rounded up, at
+/// least one), for work that can only happen once per buffer
+pub fn ms_to_buffers(ms: f32, sample_rate: f32, block_size: usize) -> u32 {
+    let samples = ms_to_samples(ms, sample_rate) as usize;
+    samples.div_ceil(block_size.max(1)).max(1) as u32
+}
+
+// =============================================================================
+// Countdown
+// =============================================================================
+
+/// One-shot countdown (cooldowns, holds)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Countdown {
+    remaining: u32,
+}
+
+impl Countdown {
+    /// (Re)start for `ms` milliseconds
+    pub fn start(&mut self, ms: f32, sample_rate: f32) {
+        self.remaining = ms_to_samples(ms, sample_rate);
+    }
+
+    pub fn clear(&mut self) {
+        self.remaining = 0;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.remaining > 0
+    }
+
+    pub fn advance(&mut self, samples: usize) {
+        self.remaining = self.remaining.saturating_sub(samples as u32);
+    }
+}
+
+// =============================================================================
+// Interval
+// =============================================================================
+
+/// Periodic trigger (maintenance work every `ms` milliseconds)
+#[derive(Debug, Clone, Copy)]
+pub struct Interval {
+    period: u32,
+    elapsed: u32,
+}
+
+impl Interval {
+    pub fn new(ms: f32, sample_rate: f32) -> Self {
+        Self {
+            period: ms_to_samples(ms, sample_rate).max(1),
+            elapsed: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = 0;
+    }
+
+    /// Advance by `samples`; true if a period ended inside that span. Fires
+    /// at most once per call, so spans must be shorter than the period.
+    pub fn advance(&mut self, samples: usize) -> bool {
+        let total = self.elapsed as u64 + samples as u64;
+        self.elapsed = (total % self.period as u64) as u32;
+        total >= self.period as u64
+    }
+}
+
+// =============================================================================
+// Ramp
+// =============================================================================
+
+/// Linear 0 → 1 ramp over a fixed time (crossfades)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ramp {
+    total: u32,
+    left: u32,
+}
+
+impl Ramp {
+    /// Start from 0, reaching 1 after `ms` milliseconds
+    pub fn start(&mut self, ms: f32, sample_rate: f32) {
+        self.total = ms_to_samples(ms, sample_rate).max(1);
+        self.left = self.total;
+    }
+
+    pub fn clear(&mut self) {
+        self.left = 0;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.left > 0
+    }
+
+    /// Position at the start of the next `samples`, then advance past them;
+    /// `None` once the ramp has finished (or was never started)
+    pub fn advance(&mut self, samples: usize) -> Option<f32> {
+        if self.left == 0 {
+            return None;
+        }
+        let t = ((self.total - self.left) as f32 / self.total as f32).clamp(0.0, 1.0);
+        self.left = self.left.saturating_sub(samples as u32);
+        Some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_follow_rate_and_block_size() {
+        assert_eq!(ms_to_samples(1000.0, 48000.0), 48000);
+        assert_eq!(ms_to_samples(10.0, 44100.0), 441);
+        assert_eq!(ms_to_buffers(1000.0, 48000.0, 512), 94);
+        assert_eq!(ms_to_buffers(1000.0, 48000.0, 64), 750);
+        assert_eq!(ms_to_buffers(1.0, 48000.0, 4096), 1);
+    }
+
+    #[test]
+    fn test_interval_rate_is_independent_of_block_size() {
+        let sr = 48000.0;
+        for block in [1, 32, 64, 441, 512, 2048] {
+            let mut interval = Interval::new(50.0, sr);
+            let mut fired = 0;
+            for _ in 0..(sr as usize / block) {
+                if interval.advance(block) {
+                    fired += 1;
+                }
+            }
+            // One second: 20 periods, one fewer if the last one straddles the end
+            assert!((19..=20).contains(&fired), "block {block}: {fired}");
+        }
+    }
+
+    #[test]
+    fn test_countdown_and_ramp() {
+        let mut cooldown = Countdown::default();
+        assert!(!cooldown.is_running());
+        cooldown.start(10.0, 48000.0);
+        cooldown.advance(400);
+        assert!(cooldown.is_running());
+        cooldown.advance(80);
+        assert!(!cooldown.is_running());
+
+        let mut ramp = Ramp::default();
+        assert_eq!(ramp.advance(32), None);
+        ramp.start(1.0, 64000.0);
+        assert_eq!(ramp.advance(32), Some(0.0));
+        assert_eq!(ramp.advance(32), Some(0.5));
+        assert_eq!(ramp.advance(32), None);
+    }
+}
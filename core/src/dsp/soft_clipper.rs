@@ -0,0 +1,271 @@
+//! Soft Clipper (oversampled final stage)
+//!
+//! A coloured alternative to the transparent limiter: peaks are rounded off
+//! by a `tanh` curve under a ceiling instead of being turned down.
+//!
+//! # Purpose
+//! Streamers often prefer the dense, slightly driven sound of a clipper to a
+//! limiter's gain riding. Clipping at the base rate would fold harmonics of
+//! loud sibilants back below Nyquist, so the curve runs at 4x.
+//!
+//! # Design Notes
+//! - `y = c · tanh(drive · x / c)`: unity slope for quiet signals, never
+//!   above the ceiling `c` at the oversampled rate
+//! - 4x polyphase interpolation and decimation with one windowed-sinc
+//!   lowpass (129 taps at the 4x rate, cut at 0.4 × base rate)
+//! - The decimated output can overshoot the ceiling slightly (filter
+//!   ripple), which is why the "Both" final stage follows it with the
+//!   limiter
+//! - Latency is a whole number of base-rate samples (`LATENCY`)
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use crate::dsp::utils::{db_to_lin, lin_to_db, time_constant_coeff};
+use std::f32::consts::PI;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Oversampling factor
+const FACTOR: usize = 4;
+
+/// Lowpass length at the oversampled rate (odd, `TAPS - 1` divisible by
+/// `FACTOR` so the latency is whole base-rate samples)
+const TAPS: usize = 129;
+
+/// Taps per interpolation phase
+const PHASE_TAPS: usize = TAPS.div_ceil(FACTOR);
+
+/// Lowpass cutoff as a fraction of the oversampled rate (0.4 × base rate)
+const CUTOFF: f32 = 0.4 / FACTOR as f32;
+
+/// Interpolation plus decimation delay in base-rate samples
+pub const LATENCY: usize = (TAPS - 1) / FACTOR;
+
+/// Gain reduction meter release
+const METER_RELEASE_MS: f32 = 150.0;
+
+// =============================================================================
+// Filter
+// =============================================================================
+
+/// Blackman-windowed sinc lowpass, unity DC gain
+fn lowpass_taps() -> [f32; TAPS] {
+    let center = (TAPS - 1) as f32 / 2.0;
+    let mut taps: [f32; TAPS] = std::array::from_fn(|i| {
+        let n = i as f32 - center;
+        let sinc = if n == 0.0 {
+            2.0 * CUTOFF
+        } else {
+            (2.0 * PI * CUTOFF * n).sin() / (PI * n)
+        };
+        let x = i as f32 / (TAPS - 1) as f32;
+        let w = 0.42 - 0.5 * (2.0 * PI * x).cos() + 0.08 * (4.0 * PI * x).cos();
+        sinc * w
+    });
+    let sum: f32 = taps.iter().sum();
+    for t in &mut taps {
+        *t /= sum;
+    }
+    taps
+}
+
+/// One channel's up- and down-sampling history (doubled rings so the
+/// latest samples are contiguous)
+struct ClipChannel {
+    up_hist: [f32; 2 * PHASE_TAPS],
+    up_pos: usize,
+    down_hist: [f32; 2 * TAPS],
+    down_pos: usize,
+}
+
+impl ClipChannel {
+    fn new() -> Self {
+        Self {
+            up_hist: [0.0; 2 * PHASE_TAPS],
+            up_pos: 0,
+            down_hist: [0.0; 2 * TAPS],
+            down_pos: 0,
+        }
+    }
+
+    fn push_down(&mut self, v: f32) {
+        self.down_hist[self.down_pos] = v;
+        self.down_hist[self.down_pos + TAPS] = v;
+        self.down_pos = (self.down_pos + 1) % TAPS;
+    }
+}
+
+// =============================================================================
+// Clipper
+// =============================================================================
+
+/// Filters and curve shared by both channels
+struct ClipKernel {
+    /// Interpolation phases, oldest-first to match the history slices
+    phases: [[f32; PHASE_TAPS]; FACTOR],
+    taps: [f32; TAPS],
+    ceiling: f32,
+    drive: f32,
+}
+
+impl ClipKernel {
+    #[inline]
+    fn clip(&self, x: f32) -> f32 {
+        let c = self.ceiling;
+        c * (self.drive * x / c).tanh()
+    }
+
+    /// Upsample, clip (unless `linear`) and downsample one channel; returns
+    /// the output and the largest oversampled input / output magnitudes
+    #[inline]
+    fn process(&self, ch: &mut ClipChannel, x: f32, linear: bool) -> (f32, f32, f32) {
+        ch.up_hist[ch.up_pos] = x;
+        ch.up_hist[ch.up_pos + PHASE_TAPS] = x;
+        ch.up_pos = (ch.up_pos + 1) % PHASE_TAPS;
+
+        let mut out = 0.0;
+        let mut peak_in = 0.0f32;
+        let mut peak_out = 0.0f32;
+        for (p, phase) in self.phases.iter().enumerate() {
+            let recent = &ch.up_hist[ch.up_pos..ch.up_pos + PHASE_TAPS];
+            let up: f32 = phase.iter().zip(recent).map(|(h, v)| h * v).sum();
+            let clipped = if linear { up } else { self.clip(up) };
+            peak_in = peak_in.max(self.drive * up.abs());
+            peak_out = peak_out.max(clipped.abs());
+            ch.push_down(clipped);
+
+            // Decimate on phase 0 so the delay is a whole number of samples
+            if p == 0 {
+                let hist = &ch.down_hist[ch.down_pos..ch.down_pos + TAPS];
+                out = self.taps.iter().zip(hist).map(|(h, v)| h * v).sum();
+            }
+        }
+        (out, peak_in, peak_out)
+    }
+}
+
+pub struct SoftClipper {
+    kernel: ClipKernel,
+    left: ClipChannel,
+    right: ClipChannel,
+
+    gain_reduction_db: f32,
+    meter_release: f32,
+}
+
+impl SoftClipper {
+    pub fn new(sample_rate: f32) -> Self {
+        let taps = lowpass_taps();
+        // phase p, newest-first tap j is h[FACTOR * j + p]; stored reversed
+        let phases = std::array::from_fn(|p| {
+            std::array::from_fn(|i| {
+                let k = FACTOR * (PHASE_TAPS - 1 - i) + p;
+                if k < TAPS {
+                    taps[k] * FACTOR as f32
+                } else {
+                    0.0
+                }
+            })
+        });
+        Self {
+            kernel: ClipKernel {
+                phases,
+                taps,
+                ceiling: db_to_lin(-1.0),
+                drive: 1.0,
+            },
+            left: ClipChannel::new(),
+            right: ClipChannel::new(),
+            gain_reduction_db: 0.0,
+            meter_release: time_constant_coeff(METER_RELEASE_MS, sample_rate),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.left = ClipChannel::new();
+        self.right = ClipChannel::new();
+        self.gain_reduction_db = 0.0;
+    }
+
+    /// Ceiling (dBFS) and drive into the curve (dB)
+    pub fn set(&mut self, ceiling_db: f32, drive_db: f32) {
+        self.kernel.ceiling = db_to_lin(ceiling_db);
+        self.kernel.drive = db_to_lin(drive_db);
+    }
+
+    /// Peak reduction the curve applied, with a short release (dB, positive)
+    pub fn gain_reduction_db(&self) -> f32 {
+        self.gain_reduction_db
+    }
+
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let (out_l, in_l, clip_l) = self.kernel.process(&mut self.left, left, false);
+        let (out_r, in_r, clip_r) = self.kernel.process(&mut self.right, right, false);
+
+        let peak_in = in_l.max(in_r);
+        let reduction_db = if peak_in > 1e-6 {
+            (lin_to_db(peak_in) - lin_to_db(clip_l.max(clip_r).max(1e-9))).max(0.0)
+        } else {
+            0.0
+        };
+        self.gain_reduction_db = if reduction_db > self.gain_reduction_db {
+            reduction_db
+        } else {
+            reduction_db + self.meter_release * (self.gain_reduction_db - reduction_db)
+        };
+        (out_l, out_r)
+    }
+
+    /// Same delay without the curve, for a bypassed final stage
+    #[inline]
+    pub fn delay(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let (out_l, ..) = self.kernel.process(&mut self.left, left, true);
+        let (out_r, ..) = self.kernel.process(&mut self.right, right, true);
+        self.gain_reduction_db *= self.meter_release;
+        (out_l, out_r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    #[test]
+    fn test_quiet_signal_is_delayed_not_coloured() {
+        let mut c = SoftClipper::new(SR);
+        c.set(-1.0, 0.0);
+        let mut out = Vec::new();
+        for i in 0..4 * LATENCY {
+            out.push(c.process(if i == 0 { 0.01 } else { 0.0 }, 0.0).0);
+        }
+        let peak = (0..out.len())
+            .max_by(|&a, &b| out[a].abs().total_cmp(&out[b].abs()))
+            .unwrap();
+        assert_eq!(peak, LATENCY);
+        assert!((out.iter().sum::<f32>() - 0.01).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_holds_ceiling() {
+        let mut c = SoftClipper::new(SR);
+        c.set(-6.0, 0.0);
+        let mut peak = 0.0f32;
+        for i in 0..SR as usize / 2 {
+            let x = (std::f32::consts::TAU * 220.0 * i as f32 / SR).sin();
+            let (l, _) = c.process(x, x);
+            if i > SR as usize / 4 {
+                peak = peak.max(l.abs());
+            }
+        }
+        // Within filter ripple of the ceiling, and reduction reported
+        assert!(peak < db_to_lin(-6.0) * 1.1, "peak {peak}");
+        assert!(peak > db_to_lin(-6.0) * 0.9, "peak {peak}");
+        assert!(c.gain_reduction_db() > 5.0);
+    }
+}
@@ -0,0 +1,187 @@
+//! Speaker Profile Learner
+//!
+//! Accumulates long-term statistics of the current voice for
+//! `crate::speaker_profile`: voiced F0 range, sibilance center, spectral tilt
+//! and typical speech level.
+//!
+//! # Purpose
+//! Lets recurring speakers be captured once and recalled later so per-voice
+//! adaptation does not have to be relearned every session.
+//!
+//! # Design Notes
+//! - Analysis only; never modifies audio
+//! - Only frames with high speech confidence contribute
+//! - F0 is collected into a fixed histogram so percentiles need no sorting
+//! - Averages become exponential after `AVERAGE_MAX_FRAMES` so long sessions
+//!   still follow slow drift
+//!
+//! ## Audio Thread Safety
+//! - Frame and scratch buffers are allocated in `new()`
+
+use super::utils::{estimate_f0_autocorr, lin_to_db, DB_EPS};
+use crate::speaker_profile::SpeakerStats;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Analysis frame length in milliseconds
+const FRAME_MS: f32 = 40.0;
+
+/// Mean speech confidence a frame needs to contribute
+const SPEECH_CONF_GATE: f32 = 0.6;
+
+/// Minimum periodicity for an F0 estimate to be counted
+const F0_PERIODICITY_MIN: f32 = 0.5;
+
+/// F0 histogram range and resolution (Hz)
+const F0_HIST_MIN_HZ: f32 = 60.0;
+const F0_HIST_MAX_HZ: f32 = 400.0;
+const F0_HIST_BIN_HZ: f32 = 5.0;
+const F0_HIST_BINS: usize = ((F0_HIST_MAX_HZ - F0_HIST_MIN_HZ) / F0_HIST_BIN_HZ) as usize;
+
+/// Percentiles reported as the voiced F0 range
+const F0_LOW_PERCENTILE: f32 = 0.10;
+const F0_HIGH_PERCENTILE: f32 = 0.90;
+
+/// Frames after which running averages turn exponential (60 s)
+const AVERAGE_MAX_FRAMES: u32 = 1500;
+
+// =============================================================================
+// Learner
+// =============================================================================
+
+pub struct SpeakerProfileLearner {
+    frame: Vec<f32>,
+    f0_scratch: Vec<f32>,
+    frame_pos: usize,
+    frame_sec: f32,
+    sample_rate: f32,
+
+    // Per-frame accumulation
+    energy_acc: f32,
+    conf_acc: f32,
+
+    // Context supplied once per buffer by other analyzers
+    sibilance_hz: f32,
+    presence_ratio: f32,
+
+    // Long-term statistics
+    f0_hist: [u32; F0_HIST_BINS],
+    f0_count: u32,
+    speech_frames: u32,
+    sibilance_avg_hz: f32,
+    tilt_avg_db: f32,
+    rms_avg_db: f32,
+}
+
+impl SpeakerProfileLearner {
+    pub fn new(sample_rate: f32) -> Self {
+        let frame_len = ((FRAME_MS * 0.001 * sample_rate) as usize).max(128);
+        let defaults = SpeakerStats::default();
+
+        Self {
+            frame: vec![0.0; frame_len],
+            f0_scratch: vec![0.0; frame_len],
+            frame_pos: 0,
+            frame_sec: frame_len as f32 / sample_rate,
+            sample_rate,
+            energy_acc: 0.0,
+            conf_acc: 0.0,
+            sibilance_hz: defaults.sibilance_hz,
+            presence_ratio: 0.0,
+            f0_hist: [0; F0_HIST_BINS],
+            f0_count: 0,
+            speech_frames: 0,
+            sibilance_avg_hz: defaults.sibilance_hz,
+            tilt_avg_db: defaults.spectral_tilt_db,
+            rms_avg_db: defaults.rms_db,
+        }
+    }
+
+    /// Update context owned by other analyzers (call once per buffer)
+    pub fn set_context(&mut self, sibilance_hz: f32, presence_ratio: f32) {
+        self.sibilance_hz = sibilance_hz;
+        self.presence_ratio = presence_ratio;
+    }
+
+    /// Process one mono sample with its speech confidence
+    #[inline]
+    pub fn process(&mut self, mono: f32, speech_conf: f32) {
+        self.frame[self.frame_pos] = mono;
+        self.frame_pos += 1;
+        self.energy_acc += mono * mono;
+        self.conf_acc += speech_conf;
+
+        if self.frame_pos >= self.frame.len() {
+            self.analyze_frame();
+            self.frame_pos = 0;
+            self.energy_acc = 0.0;
+            self.conf_acc = 0.0;
+        }
+    }
+
+    fn analyze_frame(&mut self) {
+        let n = self.frame.len() as f32;
+        if self.conf_acc / n < SPEECH_CONF_GATE {
+            return;
+        }
+
+        self.speech_frames = self.speech_frames.saturating_add(1);
+        let w = 1.0 / self.speech_frames.min(AVERAGE_MAX_FRAMES) as f32;
+
+        let rms_db = lin_to_db((self.energy_acc / n).sqrt());
+        self.rms_avg_db += (rms_db - self.rms_avg_db) * w;
+        self.sibilance_avg_hz += (self.sibilance_hz - self.sibilance_avg_hz) * w;
+        let tilt_db = 10.0 * self.presence_ratio.max(DB_EPS).log10();
+        self.tilt_avg_db += (tilt_db - self.tilt_avg_db) * w;
+
+        let (periodicity, f0_hz) =
+            estimate_f0_autocorr(&self.frame, &mut self.f0_scratch, self.sample_rate);
+        if periodicity >= F0_PERIODICITY_MIN && (F0_HIST_MIN_HZ..F0_HIST_MAX_HZ).contains(&f0_hz) {
+            let bin = ((f0_hz - F0_HIST_MIN_HZ) / F0_HIST_BIN_HZ) as usize;
+            self.f0_hist[bin.min(F0_HIST_BINS - 1)] += 1;
+            self.f0_count += 1;
+        }
+    }
+
+    fn f0_percentile(&self, p: f32) -> f32 {
+        if self.f0_count == 0 {
+            return 0.0;
+        }
+        let target = (p * self.f0_count as f32).ceil().max(1.0) as u32;
+        let mut seen = 0u32;
+        for (i, &count) in self.f0_hist.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return F0_HIST_MIN_HZ + (i as f32 + 0.5) * F0_HIST_BIN_HZ;
+            }
+        }
+        F0_HIST_MAX_HZ
+    }
+
+    /// Statistics learned so far
+    pub fn stats(&self) -> SpeakerStats {
+        SpeakerStats {
+            f0_low_hz: self.f0_percentile(F0_LOW_PERCENTILE),
+            f0_high_hz: self.f0_percentile(F0_HIGH_PERCENTILE),
+            sibilance_hz: self.sibilance_avg_hz,
+            spectral_tilt_db: self.tilt_avg_db,
+            rms_db: self.rms_avg_db,
+            voiced_sec: self.speech_frames as f32 * self.frame_sec,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        let defaults = SpeakerStats::default();
+        self.frame_pos = 0;
+        self.energy_acc = 0.0;
+        self.conf_acc = 0.0;
+        self.f0_hist = [0; F0_HIST_BINS];
+        self.f0_count = 0;
+        self.speech_frames = 0;
+        self.sibilance_avg_hz = defaults.sibilance_hz;
+        self.tilt_avg_db = defaults.spectral_tilt_db;
+        self.rms_avg_db = defaults.rms_db;
+    }
+}
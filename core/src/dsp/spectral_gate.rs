@@ -0,0 +1,136 @@
+//! Spectral Gate (Rescue Engine)
+//!
+//! Per-bin gate used by the denoiser's Rescue engine: a bin is either open
+//! (unity gain) or closed (deep fixed attenuation), decided by its magnitude
+//! relative to the tracked noise floor.
+//!
+//! # Purpose
+//! Last resort for recordings where the Wiener engine leaves too much residual
+//! (construction sites, street noise). The mask is deliberately close to
+//! binary, which removes far more noise at the cost of a processed,
+//! "gated" sound.
+//!
+//! # Design Notes
+//! - Hysteresis: a bin opens above the open ratio and only starts closing
+//!   below half of it, so bins near the threshold do not chatter
+//! - Hold: an opened bin stays open for a few frames after the last crossing
+//!   (keeps word endings and short gaps intact)
+//! - Gains jump up instantly and release over a few frames (no clicks)
+//! - Speech protection is the caller's job (floors, harmonic and transient
+//!   guardrails apply on top of the gate gains)
+//!
+//! ## Audio Thread Safety
+//! - Per-bin state is allocated in `new()`, no allocations in `bin_gain()`
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Close threshold as a fraction of the open threshold (-6 dB hysteresis)
+const CLOSE_FRAC: f32 = 0.5;
+
+/// Frames a bin stays open after it last exceeded the open threshold
+pub const HOLD_FRAMES: u16 = 4;
+
+/// Per-frame gain multiplier while a closed bin releases towards its floor
+const RELEASE_PER_FRAME: f32 = 0.5;
+
+// =============================================================================
+// Gate
+// =============================================================================
+
+pub struct SpectralGate {
+    hold: Vec<u16>,
+    gains: Vec<f32>,
+}
+
+impl SpectralGate {
+    pub fn new(bins: usize) -> Self {
+        Self {
+            hold: vec![0; bins],
+            gains: vec![1.0; bins],
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.hold.fill(0);
+        self.gains.fill(1.0);
+    }
+
+    /// Gate gain for bin `i` this frame.
+    ///
+    /// `ratio` is the bin magnitude over its noise floor, `open_ratio` the
+    /// threshold that opens the gate and `closed_gain` the gain of a closed bin.
+    #[inline]
+    pub fn bin_gain(&mut self, i: usize, ratio: f32, open_ratio: f32, closed_gain: f32) -> f32 {
+        if i >= self.gains.len() {
+            return 1.0;
+        }
+
+        if ratio >= open_ratio {
+            self.hold[i] = HOLD_FRAMES;
+        } else if ratio < open_ratio * CLOSE_FRAC && self.hold[i] > 0 {
+            self.hold[i] -= 1;
+        }
+
+        let target = if self.hold[i] > 0 { 1.0 } else { closed_gain };
+        let g = if target >= self.gains[i] {
+            target
+        } else {
+            target.max(self.gains[i] * RELEASE_PER_FRAME)
+        };
+        self.gains[i] = g;
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPEN: f32 = 4.0;
+    const CLOSED: f32 = 0.01;
+
+    #[test]
+    fn test_noise_bins_close() {
+        let mut gate = SpectralGate::new(1);
+        let mut g = 1.0;
+        for _ in 0..20 {
+            g = gate.bin_gain(0, 1.0, OPEN, CLOSED);
+        }
+        assert_eq!(g, CLOSED);
+    }
+
+    #[test]
+    fn test_hold_then_release() {
+        let mut gate = SpectralGate::new(1);
+        assert_eq!(gate.bin_gain(0, 10.0, OPEN, CLOSED), 1.0);
+
+        // Open through the hold, then a gradual (not instant) release
+        let gains: Vec<f32> = (0..12)
+            .map(|_| gate.bin_gain(0, 1.0, OPEN, CLOSED))
+            .collect();
+        let held = HOLD_FRAMES as usize - 1;
+        assert!(gains[..held].iter().all(|&g| g == 1.0));
+        assert!(gains[held] < 1.0 && gains[held] > CLOSED);
+        assert_eq!(*gains.last().unwrap(), CLOSED);
+    }
+
+    #[test]
+    fn test_hysteresis_keeps_bin_open() {
+        let mut gate = SpectralGate::new(1);
+        gate.bin_gain(0, 10.0, OPEN, CLOSED);
+
+        // Between the close and open thresholds the hold is not consumed
+        for _ in 0..50 {
+            assert_eq!(gate.bin_gain(0, 0.75 * OPEN, OPEN, CLOSED), 1.0);
+        }
+
+        // A closed bin needs the full open ratio to reopen
+        let mut closed = SpectralGate::new(1);
+        for _ in 0..20 {
+            closed.bin_gain(0, 1.0, OPEN, CLOSED);
+        }
+        assert_eq!(closed.bin_gain(0, 0.75 * OPEN, OPEN, CLOSED), CLOSED);
+    }
+}
@@ -12,6 +12,10 @@
 //! - Monitors band energy ratios continuously
 //! - Applies corrections only when thresholds are exceeded
 //! - Enforces maximum gain slew rate to prevent artifacts
+//! - Strictness scales the behaviour: thresholds are divided by it and the
+//!   maximum cuts multiplied, so Strict (1.5) steps in earlier and deeper
+//! - Disabling lets the current cuts release instead of dropping them, so
+//!   switching the guardrails off mid-program does not click
 //! - Gently guides extreme settings back to safe operating ranges
 //!   - Dullness if triggers on legitimate bright voices.
 //!   - Volume dips if slew limiting is triggered aggressively.
@@ -124,6 +128,8 @@ pub struct SpectralGuardrails {
     low_mid_cut_db: f32,
     high_cut_db: f32,
     correction_coeff: f32,
+    /// Threshold divisor and cut multiplier (1.0 = normal)
+    strictness: f32,
 
     // Gain slew limiting (reserved for future use)
     last_output_gain: f32,
@@ -203,15 +209,22 @@ impl SpectralGuardrails {
             low_mid_cut_db: 0.0,
             high_cut_db: 0.0,
             correction_coeff: time_constant_coeff(CORRECTION_SMOOTH_MS, sample_rate),
+            strictness: 1.0,
             last_output_gain: 1.0,
             max_slew_per_sample: MAX_GAIN_SLEW_DB_PER_SEC / sample_rate,
         }
     }
 
+    /// Set how early and how far the guardrails step in (1.0 = normal)
+    pub fn set_strictness(&mut self, strictness: f32) {
+        self.strictness = strictness.max(0.1);
+    }
+
     /// Process a stereo sample pair with spectral protection
     ///
     /// * `left`, `right` - Input samples
-    /// * `enabled` - Whether guardrails are active
+    /// * `enabled` - Whether guardrails are active (when off, any current
+    ///   correction releases before the signal passes through untouched)
     ///
     /// Returns (processed_left, processed_right)
     #[inline]
@@ -225,12 +238,18 @@ impl SpectralGuardrails {
         // Always track band energies (for monitoring)
         self.update_band_energy(left, right);
 
-        if !enabled {
+        if !enabled && self.low_mid_cut_db <= 0.1 && self.high_cut_db <= 0.1 {
+            self.low_mid_cut_db = 0.0;
+            self.high_cut_db = 0.0;
             return (left, right);
         }
 
         // Calculate target corrections based on ratios
-        let (target_low_cut, target_high_cut) = self.calculate_corrections(speech_confidence);
+        let (target_low_cut, target_high_cut) = if enabled {
+            self.calculate_corrections(speech_confidence)
+        } else {
+            (0.0, 0.0)
+        };
 
         // Adaptive smoothing: Fast Attack (to protect), Slow Release (to prevent pumping/oscillation)
         // If target > current (more cut needed), use fast attack.
@@ -238,14 +257,20 @@ impl SpectralGuardrails {
         let attack_coeff = time_constant_coeff(20.0, self.sample_rate); // 20ms attack
         let release_coeff = time_constant_coeff(400.0, self.sample_rate); // 400ms release (slower)
 
-        let low_coeff = if target_low_cut > self.low_mid_cut_db { attack_coeff } else { release_coeff };
-        let high_coeff = if target_high_cut > self.high_cut_db { attack_coeff } else { release_coeff };
+        let low_coeff = if target_low_cut > self.low_mid_cut_db {
+            attack_coeff
+        } else {
+            release_coeff
+        };
+        let high_coeff = if target_high_cut > self.high_cut_db {
+            attack_coeff
+        } else {
+            release_coeff
+        };
 
         // Smooth corrections
-        self.low_mid_cut_db = low_coeff * self.low_mid_cut_db
-            + (1.0 - low_coeff) * target_low_cut;
-        self.high_cut_db = high_coeff * self.high_cut_db
-            + (1.0 - high_coeff) * target_high_cut;
+        self.low_mid_cut_db = low_coeff * self.low_mid_cut_db + (1.0 - low_coeff) * target_low_cut;
+        self.high_cut_db = high_coeff * self.high_cut_db + (1.0 - high_coeff) * target_high_cut;
 
         // Safety clamp to prevent instability
         self.low_mid_cut_db = self.low_mid_cut_db.clamp(0.0, 12.0);
@@ -336,18 +361,23 @@ impl SpectralGuardrails {
         let low_mid_ratio = self.rms_low_mid_sq.sqrt() / speech_rms;
         let high_ratio = self.rms_high_sq.sqrt() / speech_rms;
 
+        let low_mid_threshold = LOW_MID_RATIO_THRESHOLD / self.strictness;
+        let high_threshold = HIGH_RATIO_THRESHOLD / self.strictness;
+        let max_low_mid_cut = MAX_LOW_MID_CUT_DB * self.strictness;
+        let max_high_cut = MAX_HIGH_CUT_DB * self.strictness;
+
         // Low-mid correction
-        let low_cut = if low_mid_ratio > LOW_MID_RATIO_THRESHOLD {
-            let excess = (low_mid_ratio - LOW_MID_RATIO_THRESHOLD) / LOW_MID_RATIO_THRESHOLD;
-            (excess * MAX_LOW_MID_CUT_DB).min(MAX_LOW_MID_CUT_DB)
+        let low_cut = if low_mid_ratio > low_mid_threshold {
+            let excess = (low_mid_ratio - low_mid_threshold) / low_mid_threshold;
+            (excess * max_low_mid_cut).min(max_low_mid_cut)
         } else {
             0.0
         };
 
         // High correction
-        let base_high_cut = if high_ratio > HIGH_RATIO_THRESHOLD {
-            let excess = (high_ratio - HIGH_RATIO_THRESHOLD) / HIGH_RATIO_THRESHOLD;
-            (excess * MAX_HIGH_CUT_DB).min(MAX_HIGH_CUT_DB)
+        let base_high_cut = if high_ratio > high_threshold {
+            let excess = (high_ratio - high_threshold) / high_threshold;
+            (excess * max_high_cut).min(max_high_cut)
         } else {
             0.0
         };
@@ -465,4 +495,37 @@ mod tests {
         assert!(guardrails.get_low_mid_cut_db() < 1.0);
         assert!(guardrails.get_high_cut_db() < 1.0);
     }
+
+    #[test]
+    fn test_strict_cuts_deeper_and_off_releases() {
+        // Muddy voice: strong 250 Hz under a weaker 1 kHz
+        let muddy = |i: usize| {
+            let t = i as f32 / 48000.0;
+            0.4 * (std::f32::consts::TAU * 250.0 * t).sin()
+                + 0.1 * (std::f32::consts::TAU * 1000.0 * t).sin()
+        };
+
+        let mut normal = SpectralGuardrails::new(48000.0);
+        let mut strict = SpectralGuardrails::new(48000.0);
+        strict.set_strictness(1.5);
+        for i in 0..24000 {
+            let x = muddy(i);
+            normal.process(x, x, true, 0.8);
+            strict.process(x, x, true, 0.8);
+        }
+        assert!(
+            strict.get_low_mid_cut_db() > normal.get_low_mid_cut_db() + 0.5,
+            "strict {} normal {}",
+            strict.get_low_mid_cut_db(),
+            normal.get_low_mid_cut_db()
+        );
+
+        // Switched off: the cut releases, then audio passes untouched
+        for i in 24000..72000 {
+            let x = muddy(i);
+            strict.process(x, x, false, 0.8);
+        }
+        assert_eq!(strict.get_low_mid_cut_db(), 0.0);
+        assert_eq!(strict.process(0.25, -0.25, false, 0.8), (0.25, -0.25));
+    }
 }
@@ -36,6 +36,18 @@
 //! The Leveler is the authoritative long-term gain controller. The expander
 //! only shapes the dynamic range within phrases, not across them.
 //!
+//! `set_time_scale()` (the Response control) stretches all three times
+//! together with the leveler's, so the separation holds in every mode.
+//!
+//! # Program-Dependent Release
+//!
+//! Release here is the gate sense: the hold and the fall into attenuation
+//! after a word. It follows the speech cadence: the pauses between words
+//! are timed from speech confidence, and short ones (fast dialogue) stretch
+//! the release so word tails are not chopped, long ones (slow narration)
+//! shorten it so the room does not swell back in. `set_release_trim()` (the
+//! Tail Release control) scales the result.
+//!
 //! ## Audio Thread Safety
 //! - No allocations during `process()`
 //! - All state pre-initialized in `new()`
@@ -82,13 +94,31 @@ const MAX_THRESHOLD_DB: f32 = -30.0;
 /// RMS threshold below which the expander stays transparent during silence
 const SILENCE_EXPAND_RMS: f32 = 0.0012;
 
+/// Pause between words at which the release keeps its default length (ms)
+const CADENCE_REFERENCE_MS: f32 = 400.0;
+
+/// Pauses outside this range are not word spacing (ms): shorter ones are
+/// confidence flicker, longer ones paragraph breaks
+const CADENCE_MIN_GAP_MS: f32 = 50.0;
+const CADENCE_MAX_GAP_MS: f32 = 2000.0;
+
+/// Weight of each new pause in the running average
+const CADENCE_WEIGHT: f32 = 0.2;
+
+/// Speech confidence hysteresis for timing the pauses
+const CADENCE_SPEECH_ON: f32 = 0.5;
+const CADENCE_SPEECH_OFF: f32 = 0.3;
+
+/// Range of the cadence release scale
+const CADENCE_SCALE_MIN: f32 = 0.5;
+const CADENCE_SCALE_MAX: f32 = 2.0;
+
 // =============================================================================
 // Speech Expander
 // =============================================================================
 
 /// Stereo-linked speech-aware downward expander
 pub struct SpeechExpander {
-    #[allow(dead_code)] // Stored for potential sample rate change support
     sample_rate: f32,
 
     // Gain reduction state
@@ -101,6 +131,16 @@ pub struct SpeechExpander {
     hold_counter: usize,
     hold_samples: usize,
 
+    // Attack/release/hold multiplier
+    time_scale: f32,
+
+    // Program-dependent release (hold and fall into attenuation)
+    release_trim: f32,
+    cadence_scale: f32,
+    in_speech: bool,
+    gap_samples: usize,
+    average_gap_ms: f32,
+
     // Current threshold (adaptive to noise floor)
     threshold_db: f32,
 
@@ -120,11 +160,77 @@ impl SpeechExpander {
             fast_release_coeff: time_constant_coeff(FAST_RELEASE_MS, sample_rate),
             hold_counter: 0,
             hold_samples,
+            time_scale: 1.0,
+            release_trim: 1.0,
+            cadence_scale: 1.0,
+            in_speech: false,
+            gap_samples: 0,
+            average_gap_ms: CADENCE_REFERENCE_MS,
             threshold_db: MIN_THRESHOLD_DB,
             current_gain: 1.0,
         }
     }
 
+    /// Scale attack, release and hold times (1.0 = default ballistics).
+    /// Coefficients are only recomputed when the scale changes.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        let scale = scale.clamp(0.25, 4.0);
+        if scale == self.time_scale {
+            return;
+        }
+        let sr = self.sample_rate;
+        self.time_scale = scale;
+        self.release_coeff = time_constant_coeff(RELEASE_MS * scale, sr);
+        self.fast_release_coeff = time_constant_coeff(FAST_RELEASE_MS * scale, sr);
+        self.update_release();
+    }
+
+    /// Scale the program-dependent release (0.5 - 2.0, 1.0 = as measured)
+    pub fn set_release_trim(&mut self, trim: f32) {
+        let trim = trim.clamp(0.5, 2.0);
+        if trim != self.release_trim {
+            self.release_trim = trim;
+            self.update_release();
+        }
+    }
+
+    /// Current release relative to the default (cadence times trim)
+    pub fn release_scale(&self) -> f32 {
+        self.cadence_scale * self.release_trim
+    }
+
+    /// Hold and fall into attenuation from the time scale, cadence and trim
+    fn update_release(&mut self) {
+        let scale = self.time_scale * self.release_scale();
+        let sr = self.sample_rate;
+        self.attack_coeff = time_constant_coeff(ATTACK_MS * scale, sr);
+        self.hold_samples = ((HOLD_MS * scale * 0.001 * sr) as usize).max(1);
+    }
+
+    /// Time the pauses between words and follow their average
+    fn track_cadence(&mut self, speech_conf: f32) {
+        if self.in_speech {
+            if speech_conf < CADENCE_SPEECH_OFF {
+                self.in_speech = false;
+                self.gap_samples = 0;
+            }
+            return;
+        }
+        if speech_conf <= CADENCE_SPEECH_ON {
+            self.gap_samples = self.gap_samples.saturating_add(1);
+            return;
+        }
+
+        self.in_speech = true;
+        let gap_ms = self.gap_samples as f32 * 1000.0 / self.sample_rate;
+        if (CADENCE_MIN_GAP_MS..=CADENCE_MAX_GAP_MS).contains(&gap_ms) {
+            self.average_gap_ms += (gap_ms - self.average_gap_ms) * CADENCE_WEIGHT;
+            self.cadence_scale = (CADENCE_REFERENCE_MS / self.average_gap_ms)
+                .clamp(CADENCE_SCALE_MIN, CADENCE_SCALE_MAX);
+            self.update_release();
+        }
+    }
+
     /// Process a stereo sample pair
     ///
     /// * `left`, `right` - Input samples
@@ -147,6 +253,8 @@ impl SpeechExpander {
             return (left, right);
         }
 
+        self.track_cadence(sidechain.speech_conf);
+
         // Use shared RMS envelope (stereo-linked)
         let rms_l = env_l.rms;
         let rms_r = env_r.rms;
@@ -222,6 +330,11 @@ impl SpeechExpander {
         self.hold_counter = 0;
         self.threshold_db = MIN_THRESHOLD_DB;
         self.current_gain = 1.0;
+        self.in_speech = false;
+        self.gap_samples = 0;
+        self.average_gap_ms = CADENCE_REFERENCE_MS;
+        self.cadence_scale = 1.0;
+        self.update_release();
     }
 
     /// Get current gain reduction in dB (for metering)
@@ -325,4 +438,61 @@ mod tests {
         // Should see some gain reduction
         assert!(expander.get_gain_reduction_db() > 0.1);
     }
+
+    #[test]
+    fn test_time_scale_stretches_hold() {
+        let mut expander = SpeechExpander::new(48000.0);
+        let hold = expander.hold_samples;
+        expander.set_time_scale(2.0);
+        assert_eq!(expander.hold_samples, 2 * hold);
+        expander.set_time_scale(1.0);
+        assert_eq!(expander.hold_samples, hold);
+        assert_eq!(
+            expander.attack_coeff,
+            time_constant_coeff(ATTACK_MS, 48000.0)
+        );
+    }
+
+    /// Release scale after alternating 200 ms words and `gap_ms` pauses
+    fn release_after_cadence(gap_ms: f32) -> f32 {
+        let sr = 48000.0;
+        let mut expander = SpeechExpander::new(sr);
+        let env = VoiceEnvelope::default();
+        let word = (0.2 * sr) as usize;
+        let gap = (gap_ms * 0.001 * sr) as usize;
+        for _ in 0..20 {
+            for (n, conf) in [(word, 1.0), (gap, 0.0)] {
+                let sidechain = SpeechSidechain {
+                    speech_conf: conf,
+                    noise_floor_db: -60.0,
+                };
+                for _ in 0..n {
+                    expander.process(0.1, 0.1, 1.0, &sidechain, &env, &env);
+                }
+            }
+        }
+        expander.release_scale()
+    }
+
+    #[test]
+    fn test_release_follows_cadence() {
+        let dialogue = release_after_cadence(150.0);
+        let narration = release_after_cadence(900.0);
+        assert!(dialogue > 1.5, "dialogue {dialogue}");
+        assert!(narration < 0.6, "narration {narration}");
+
+        // Paragraph breaks do not count as word spacing
+        assert_eq!(release_after_cadence(3000.0), 1.0);
+    }
+
+    #[test]
+    fn test_release_trim_scales_hold() {
+        let mut expander = SpeechExpander::new(48000.0);
+        let hold = expander.hold_samples;
+        expander.set_release_trim(2.0);
+        assert_eq!(expander.release_scale(), 2.0);
+        assert_eq!(expander.hold_samples, 2 * hold);
+        expander.reset();
+        assert_eq!(expander.hold_samples, 2 * hold);
+    }
 }
@@ -0,0 +1,208 @@
+use crate::dsp::biquad::Biquad;
+use crate::dsp::linear_phase::LinearPhaseFir;
+
+/// Speech HPF (Low Cut)
+///
+/// Removes subsonic energy below the human voice range to prevent
+/// contamination of downstream analysis and processing. The corner
+/// (default 90 Hz) and slope are user-selectable: a lower corner keeps the
+/// chest of deep voices, 24 dB/oct clears heavy rumble, and Off hands the
+/// full low end to the rest of the chain.
+///
+/// In linear-phase mode the low cut and the restoration chain's 80 Hz safety
+/// high-pass are replaced by one symmetric FIR with their combined
+/// magnitude: no phase rotation around the corners, at the cost of the FIR's
+/// group delay (see `latency()`).
+pub struct SpeechHpf {
+    filter_l: Biquad,
+    filter_r: Biquad,
+    // Second section for 24 dB/oct (idle at 12 dB/oct)
+    filter2_l: Biquad,
+    filter2_r: Biquad,
+    cutoff_hz: Option<f32>,
+    steep: bool,
+    fir: LinearPhaseFir,
+    linear_phase: bool,
+    _sample_rate: f32,
+}
+
+impl SpeechHpf {
+    const CUTOFF_HZ: f32 = 90.0;
+    const Q: f32 = 0.707;
+    // 4th-order Butterworth as two biquads
+    const STEEP_Q: [f32; 2] = [0.541, 1.307];
+    // Restoration chain safety high-pass, folded into the FIR
+    const SAFETY_HZ: f32 = 80.0;
+
+    pub fn new(sample_rate: f32) -> Self {
+        let mut hpf = Self {
+            filter_l: Biquad::new(),
+            filter_r: Biquad::new(),
+            filter2_l: Biquad::new(),
+            filter2_r: Biquad::new(),
+            cutoff_hz: Some(Self::CUTOFF_HZ),
+            steep: false,
+            fir: LinearPhaseFir::new(sample_rate),
+            linear_phase: false,
+            _sample_rate: sample_rate,
+        };
+        hpf.update_filters();
+        hpf
+    }
+
+    pub fn _prepare(&mut self, sample_rate: f32) {
+        self._sample_rate = sample_rate;
+        self.update_filters();
+    }
+
+    /// Corner in Hz (`None` = off) and slope (`steep` = 24 dB/oct)
+    pub fn set_corner(&mut self, cutoff_hz: Option<f32>, steep: bool) {
+        if cutoff_hz != self.cutoff_hz || steep != self.steep {
+            self.cutoff_hz = cutoff_hz;
+            self.steep = steep;
+            self.update_filters();
+        }
+    }
+
+    /// Switch between the biquads and the linear-phase FIR
+    pub fn set_linear_phase(&mut self, on: bool) {
+        if on != self.linear_phase {
+            self.linear_phase = on;
+            self.fir.reset();
+            self.update_filters();
+        }
+    }
+
+    /// Extra delay in samples (0 unless linear-phase)
+    pub fn latency(&self) -> usize {
+        if self.linear_phase {
+            self.fir.latency()
+        } else {
+            0
+        }
+    }
+
+    /// Analog second-order high-pass magnitude
+    fn hpf_mag(hz: f32, cutoff: f32, q: f32) -> f32 {
+        let x = hz / cutoff;
+        x * x / ((1.0 - x * x).powi(2) + (x / q).powi(2)).sqrt()
+    }
+
+    fn update_filters(&mut self) {
+        if self.linear_phase {
+            let cutoff_hz = self.cutoff_hz;
+            let steep = self.steep;
+            self.fir.design(|f| {
+                let safety = Self::hpf_mag(f, Self::SAFETY_HZ, Self::Q);
+                match cutoff_hz {
+                    None => safety,
+                    Some(hz) if steep => {
+                        safety
+                            * Self::hpf_mag(f, hz, Self::STEEP_Q[0])
+                            * Self::hpf_mag(f, hz, Self::STEEP_Q[1])
+                    }
+                    Some(hz) => safety * Self::hpf_mag(f, hz, Self::Q),
+                }
+            });
+            return;
+        }
+        let Some(hz) = self.cutoff_hz else {
+            return;
+        };
+        let sr = self._sample_rate;
+        if self.steep {
+            self.filter_l.update_hpf(hz, Self::STEEP_Q[0], sr);
+            self.filter_r.update_hpf(hz, Self::STEEP_Q[0], sr);
+            self.filter2_l.update_hpf(hz, Self::STEEP_Q[1], sr);
+            self.filter2_r.update_hpf(hz, Self::STEEP_Q[1], sr);
+        } else {
+            self.filter_l.update_hpf(hz, Self::Q, sr);
+            self.filter_r.update_hpf(hz, Self::Q, sr);
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        if self.linear_phase {
+            return self.fir.process(left, right);
+        }
+        if self.cutoff_hz.is_none() {
+            return (left, right);
+        }
+        let l = self.filter_l.process(left);
+        let r = self.filter_r.process(right);
+        if self.steep {
+            (self.filter2_l.process(l), self.filter2_r.process(r))
+        } else {
+            (l, r)
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.filter_l.reset_state();
+        self.filter_r.reset_state();
+        self.filter2_l.reset_state();
+        self.filter2_r.reset_state();
+        self.fir.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Steady-state output level of a sine through the filter
+    fn gain_at(hpf: &mut SpeechHpf, hz: f32) -> f32 {
+        hpf.reset();
+        let mut peak = 0.0f32;
+        for i in 0..SR as usize {
+            let x = (std::f32::consts::TAU * hz * i as f32 / SR).sin();
+            let (l, _) = hpf.process(x, x);
+            if i > SR as usize / 2 {
+                peak = peak.max(l.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_slope_and_corner() {
+        let mut gentle = SpeechHpf::new(SR);
+        let mut steep = SpeechHpf::new(SR);
+        steep.set_corner(Some(90.0), true);
+
+        // Both -3 dB at the corner, an octave below 12 vs 24 dB down
+        assert!((gain_at(&mut gentle, 90.0) - 0.707).abs() < 0.03);
+        assert!((gain_at(&mut steep, 90.0) - 0.707).abs() < 0.03);
+        assert!(gain_at(&mut gentle, 45.0) < 0.27);
+        assert!(gain_at(&mut steep, 45.0) < 0.07);
+        assert!(gain_at(&mut steep, 1000.0) > 0.99);
+
+        gentle.set_corner(Some(60.0), false);
+        assert!(gain_at(&mut gentle, 90.0) > 0.85);
+    }
+
+    #[test]
+    fn test_off_passes_through() {
+        let mut hpf = SpeechHpf::new(SR);
+        hpf.set_corner(None, true);
+        assert_eq!(hpf.process(0.5, -0.25), (0.5, -0.25));
+    }
+
+    #[test]
+    fn test_linear_phase_mode() {
+        let mut hpf = SpeechHpf::new(SR);
+        assert_eq!(hpf.latency(), 0);
+        hpf.set_linear_phase(true);
+        assert!(hpf.latency() > 0);
+
+        // Same corner region as the biquads, safety high-pass included
+        assert!(gain_at(&mut hpf, 45.0) < 0.2);
+        assert!(gain_at(&mut hpf, 1000.0) > 0.98);
+        hpf.set_corner(None, false);
+        assert!(gain_at(&mut hpf, 80.0) < 0.8);
+        assert!(gain_at(&mut hpf, 300.0) > 0.9);
+    }
+}
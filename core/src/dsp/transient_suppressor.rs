@@ -0,0 +1,332 @@
+//! Transient Noise Suppressor (keyboard clicks, desk thumps)
+//!
+//! Ducks short impulsive noises that land in pauses: keyboard clacks,
+//! mouse clicks, desk knocks and mic-stand thumps.
+//!
+//! # Purpose
+//! The denoiser tracks steady noise and lets impulses through untouched,
+//! and the expander is too slow to catch a 5 ms click. This stage looks for
+//! sudden jumps of a band's level over its recent average while speech
+//! confidence is low, and pulls only that band down for the length of the
+//! click.
+//!
+//! # Design Notes
+//! - Three-band split (thump / body / clack) that sums back to the input
+//!   bit-for-bit at unity gain: the middle band is the residual
+//! - Detection per band: fast envelope over slow envelope. The slow
+//!   envelope freezes while a band is ducked so a burst of typing does not
+//!   raise its own threshold
+//! - Stereo-linked: detection on the louder channel, same gains on both
+//! - Only arms in pauses (speech confidence gate); onsets of words are
+//!   never ducked
+//! - Counts events over a rolling minute for the meters, even at 0% amount,
+//!   so the counter can be used to judge whether the stage is needed
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations in `process()`
+
+use super::biquad::Biquad;
+use super::utils::{db_to_gain, lerp, smoothstep, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Band split points: desk thumps below, keyboard clacks above
+const LOW_SPLIT_HZ: f32 = 250.0;
+const HIGH_SPLIT_HZ: f32 = 2500.0;
+
+const BANDS: usize = 3;
+
+/// Detector envelopes (linear magnitude)
+const FAST_ATTACK_MS: f32 = 1.0;
+const FAST_RELEASE_MS: f32 = 8.0;
+const SLOW_MS: f32 = 150.0;
+
+/// Fast-over-slow jump that counts as a transient at 0% / 100% sensitivity
+const THRESHOLD_DB_LOW_SENS: f32 = 24.0;
+const THRESHOLD_DB_HIGH_SENS: f32 = 12.0;
+
+/// Ignore "transients" below this level (dither, digital silence)
+const MIN_LEVEL: f32 = 1e-4;
+
+/// Speech confidence range over which detection is disarmed
+const PAUSE_SPEECH_LO: f32 = 0.3;
+const PAUSE_SPEECH_HI: f32 = 0.5;
+
+/// Band attenuation at 100% amount
+const MAX_DUCK_DB: f32 = 24.0;
+
+/// Ducking ballistics
+const DUCK_ATTACK_MS: f32 = 0.5;
+const DUCK_HOLD_MS: f32 = 40.0;
+const DUCK_RELEASE_MS: f32 = 60.0;
+
+/// A click and its rebound count as one event
+const EVENT_REARM_MS: f32 = 100.0;
+
+/// Rolling event window: one bucket per second
+const RATE_BUCKETS: usize = 60;
+
+// =============================================================================
+// Suppressor
+// =============================================================================
+
+pub struct TransientSuppressor {
+    lpf_l: Biquad,
+    lpf_r: Biquad,
+    hpf_l: Biquad,
+    hpf_r: Biquad,
+
+    fast_env: [f32; BANDS],
+    slow_env: [f32; BANDS],
+    /// Samples left before a ducked band starts releasing
+    hold: [u32; BANDS],
+    /// Gain a ducked band is heading for
+    duck_gain: [f32; BANDS],
+    gain: [f32; BANDS],
+
+    fast_atk: f32,
+    fast_rel: f32,
+    slow_coeff: f32,
+    duck_atk: f32,
+    duck_rel: f32,
+    hold_samples: u32,
+    /// Samples until the slow envelopes have settled (detection disarmed)
+    warmup_left: u32,
+    warmup_samples: u32,
+
+    rearm_samples: u32,
+    rearm_left: u32,
+    buckets: [u16; RATE_BUCKETS],
+    bucket: usize,
+    bucket_samples: u32,
+    samples_per_bucket: u32,
+}
+
+impl TransientSuppressor {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut lpf = Biquad::new();
+        lpf.update_lpf(LOW_SPLIT_HZ, 0.707, sample_rate);
+        let mut hpf = Biquad::new();
+        hpf.update_hpf(HIGH_SPLIT_HZ, 0.707, sample_rate);
+
+        Self {
+            lpf_l: lpf,
+            lpf_r: lpf,
+            hpf_l: hpf,
+            hpf_r: hpf,
+
+            fast_env: [0.0; BANDS],
+            slow_env: [0.0; BANDS],
+            hold: [0; BANDS],
+            duck_gain: [1.0; BANDS],
+            gain: [1.0; BANDS],
+
+            fast_atk: time_constant_coeff(FAST_ATTACK_MS, sample_rate),
+            fast_rel: time_constant_coeff(FAST_RELEASE_MS, sample_rate),
+            slow_coeff: time_constant_coeff(SLOW_MS, sample_rate),
+            duck_atk: time_constant_coeff(DUCK_ATTACK_MS, sample_rate),
+            duck_rel: time_constant_coeff(DUCK_RELEASE_MS, sample_rate),
+            hold_samples: (DUCK_HOLD_MS * 0.001 * sample_rate) as u32,
+            warmup_left: (SLOW_MS * 0.001 * sample_rate) as u32,
+            warmup_samples: (SLOW_MS * 0.001 * sample_rate) as u32,
+
+            rearm_samples: (EVENT_REARM_MS * 0.001 * sample_rate) as u32,
+            rearm_left: 0,
+            buckets: [0; RATE_BUCKETS],
+            bucket: 0,
+            bucket_samples: 0,
+            samples_per_bucket: sample_rate.max(1.0) as u32,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for f in [
+            &mut self.lpf_l,
+            &mut self.lpf_r,
+            &mut self.hpf_l,
+            &mut self.hpf_r,
+        ] {
+            f.reset_state();
+        }
+        self.fast_env = [0.0; BANDS];
+        self.slow_env = [0.0; BANDS];
+        self.hold = [0; BANDS];
+        self.duck_gain = [1.0; BANDS];
+        self.gain = [1.0; BANDS];
+        self.warmup_left = self.warmup_samples;
+        self.rearm_left = 0;
+        self.buckets = [0; RATE_BUCKETS];
+        self.bucket = 0;
+        self.bucket_samples = 0;
+    }
+
+    /// Process one stereo sample.
+    ///
+    /// `sensitivity` and `amount` are 0..1; `speech_conf` comes from the
+    /// speech confidence sidechain.
+    #[inline]
+    pub fn process(
+        &mut self,
+        left: f32,
+        right: f32,
+        sensitivity: f32,
+        amount: f32,
+        speech_conf: f32,
+    ) -> (f32, f32) {
+        let low_l = self.lpf_l.process(left);
+        let low_r = self.lpf_r.process(right);
+        let high_l = self.hpf_l.process(left);
+        let high_r = self.hpf_r.process(right);
+        let bands_l = [low_l, left - low_l - high_l, high_l];
+        let bands_r = [low_r, right - low_r - high_r, high_r];
+
+        let pause = if self.warmup_left > 0 {
+            self.warmup_left -= 1;
+            0.0
+        } else {
+            1.0 - smoothstep(PAUSE_SPEECH_LO, PAUSE_SPEECH_HI, speech_conf)
+        };
+        let threshold = db_to_gain(lerp(
+            THRESHOLD_DB_LOW_SENS,
+            THRESHOLD_DB_HIGH_SENS,
+            sensitivity,
+        ));
+        let duck_db = amount.clamp(0.0, 1.0) * pause * MAX_DUCK_DB;
+
+        let mut triggered = false;
+        for b in 0..BANDS {
+            let level = bands_l[b].abs().max(bands_r[b].abs());
+
+            let fc = if level > self.fast_env[b] {
+                self.fast_atk
+            } else {
+                self.fast_rel
+            };
+            self.fast_env[b] = fc * self.fast_env[b] + (1.0 - fc) * level;
+
+            if self.hold[b] == 0 {
+                self.slow_env[b] =
+                    self.slow_coeff * self.slow_env[b] + (1.0 - self.slow_coeff) * level;
+            }
+
+            let jump = self.fast_env[b] > threshold * self.slow_env[b];
+            if pause > 0.0 && jump && self.fast_env[b] > MIN_LEVEL {
+                if self.hold[b] == 0 {
+                    triggered = true;
+                }
+                self.hold[b] = self.hold_samples;
+                self.duck_gain[b] = self.duck_gain[b].min(db_to_gain(-duck_db));
+            }
+
+            let target = if self.hold[b] > 0 {
+                self.hold[b] -= 1;
+                self.duck_gain[b]
+            } else {
+                self.duck_gain[b] = 1.0;
+                1.0
+            };
+            let gc = if target < self.gain[b] {
+                self.duck_atk
+            } else {
+                self.duck_rel
+            };
+            self.gain[b] = gc * self.gain[b] + (1.0 - gc) * target;
+        }
+
+        self.count_event(triggered);
+
+        if self.gain.iter().all(|&g| g >= 1.0 - 1e-6) {
+            return (left, right);
+        }
+        let [g0, g1, g2] = self.gain;
+        (
+            bands_l[0] * g0 + bands_l[1] * g1 + bands_l[2] * g2,
+            bands_r[0] * g0 + bands_r[1] * g1 + bands_r[2] * g2,
+        )
+    }
+
+    fn count_event(&mut self, triggered: bool) {
+        if self.rearm_left > 0 {
+            self.rearm_left -= 1;
+        } else if triggered {
+            self.rearm_left = self.rearm_samples;
+            self.buckets[self.bucket] = self.buckets[self.bucket].saturating_add(1);
+        }
+
+        self.bucket_samples += 1;
+        if self.bucket_samples >= self.samples_per_bucket {
+            self.bucket_samples = 0;
+            self.bucket = (self.bucket + 1) % RATE_BUCKETS;
+            self.buckets[self.bucket] = 0;
+        }
+    }
+
+    /// Transient events detected during the last minute
+    pub fn events_per_minute(&self) -> f32 {
+        self.buckets.iter().map(|&n| n as f32).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Quiet noise bed with a 2 ms click every `period` samples
+    fn clicks(len: usize, period: usize) -> Vec<f32> {
+        let mut seed = 1u32;
+        (0..len)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let noise = ((seed >> 8) as f32 / 16_777_216.0 - 0.5) * 0.002;
+                let t = (i + period / 2) % period;
+                if t < 96 {
+                    noise + 0.5 * (1.0 - t as f32 / 96.0) * (t as f32 * 2.0).sin()
+                } else {
+                    noise
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unity_when_idle() {
+        let mut ts = TransientSuppressor::new(SR);
+        let input = clicks(SR as usize, 1 << 30);
+        for &x in &input {
+            let (l, r) = ts.process(x, x, 0.5, 1.0, 0.0);
+            assert_eq!(l, x);
+            assert_eq!(r, x);
+        }
+    }
+
+    #[test]
+    fn test_clicks_ducked_in_pauses() {
+        let mut ts = TransientSuppressor::new(SR);
+        let input = clicks(2 * SR as usize, 12000);
+        let (mut e_in, mut e_out) = (0.0f32, 0.0f32);
+        for (i, &x) in input.iter().enumerate() {
+            let (y, _) = ts.process(x, x, 0.5, 1.0, 0.0);
+            if i > SR as usize / 2 {
+                e_in += x * x;
+                e_out += y * y;
+            }
+        }
+        assert!(e_out < 0.5 * e_in, "in {e_in} out {e_out}");
+        assert!(ts.events_per_minute() >= 7.0);
+    }
+
+    #[test]
+    fn test_speech_disarms_detection() {
+        let mut ts = TransientSuppressor::new(SR);
+        let input = clicks(SR as usize, 12000);
+        for &x in &input {
+            let (y, _) = ts.process(x, x, 1.0, 1.0, 0.9);
+            assert_eq!(y, x);
+        }
+        assert_eq!(ts.events_per_minute(), 0.0);
+    }
+}
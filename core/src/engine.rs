@@ -0,0 +1,570 @@
+//! Embeddable Engine (stable API)
+//!
+//! The plugin's voice cleaning chain without a plugin host: set parameters
+//! by id, process blocks of stereo samples in place, read meters.
+//!
+//! The engine drives the same `VoiceChain` as the vxcleaner plugin, so a
+//! change to the signal path reaches both, and engine tests (`null_test`,
+//! `tests/golden.rs`) cover the plugin's stages. The plugin's render tests
+//! (its `src/tests.rs`) check that the two stay sample-identical at default
+//! settings.
+//!
+//! # Purpose
+//! Hosts, command-line tools and telephony services want the cleaner, not
+//! the plugin. `Engine` wraps the chain behind a small surface that does not
+//! change when the plugin's parameter list does.
+//!
+//! # Design Notes
+//! - Every stage of the plugin chain runs (see `chain`); the engine sets
+//!   the nine amounts below and leaves every plugin-only control (static
+//!   noise, hiss and rumble, expander, guardrails, loudness compensation,
+//!   Final Output presets, ...) at the plugin's default
+//! - Parameter ids are stable: new parameters get new ids, ids are never
+//!   reused or renumbered
+//! - Amounts glide over `PARAM_SMOOTH_MS`, stepped per `CONTROL_BLOCK_SIZE`
+//!   sub-block as the plugin steps its smoothers
+//! - Control sub-blocks and the end-of-block analysis are counted from the
+//!   stream start, not from the call, so output does not depend on how the
+//!   host splits its blocks; with `ANALYSIS_BLOCK_SIZE` blocks it matches
+//!   the plugin's
+//! - Fixed latency (`latency_samples()`): the low cut, static noise,
+//!   denoise and de-verb windows
+//!
+//! ## Audio Thread Safety
+//! - Everything is allocated in `new()`; `set_param()`, `process_block()`,
+//!   `meters()` and `reset()` do not allocate or lock (checked by
+//!   `tests/realtime.rs`)
+
+use crate::chain::{
+    ChainAmounts, ChainSettings, ResolvedControls, SpectralStages, VoiceChain, CONTROL_BLOCK_SIZE,
+};
+use crate::dsp::utils::{db_to_lin, lin_to_db, time_constant_coeff};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// STFT size of the spectral stages (the plugin's Normal quality)
+const WIN_SIZE: usize = 2048;
+
+/// Samples between the per-buffer settings update and end-of-block
+/// analysis (a common host buffer)
+pub const ANALYSIS_BLOCK_SIZE: usize = 512;
+
+/// Parameter glide time
+const PARAM_SMOOTH_MS: f32 = 50.0;
+
+/// Peak meter release and RMS meter window
+const PEAK_RELEASE_MS: f32 = 300.0;
+const RMS_WINDOW_MS: f32 = 300.0;
+
+/// Low cut settings below this read as off (Hz)
+const LOW_CUT_MIN_HZ: f32 = 20.0;
+
+// =============================================================================
+// Parameters
+// =============================================================================
+
+/// Engine parameters. The discriminant is the stable id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum EngineParam {
+    /// Adaptive noise reduction (0..1)
+    NoiseReduction = 0,
+    /// Room reverb reduction (0..1)
+    DeVerb = 1,
+    /// Close-mic low-end shaping (0..1)
+    Proximity = 2,
+    /// Low-mid mud cut (0..1)
+    Clarity = 3,
+    /// Sibilance reduction (0..1)
+    DeEsser = 4,
+    /// Automatic level riding (0..1)
+    Leveler = 5,
+    /// Speech level the leveler aims for (dBFS, -36..-12)
+    LevelerTarget = 6,
+    /// Low cut corner (Hz, 0..200; below 20 Hz = off)
+    LowCut = 7,
+    /// Output gain after the limiter (dB, -12..12)
+    OutputGain = 8,
+}
+
+impl EngineParam {
+    pub const COUNT: usize = 9;
+
+    /// All parameters, in id order
+    pub const ALL: [EngineParam; Self::COUNT] = [
+        EngineParam::NoiseReduction,
+        EngineParam::DeVerb,
+        EngineParam::Proximity,
+        EngineParam::Clarity,
+        EngineParam::DeEsser,
+        EngineParam::Leveler,
+        EngineParam::LevelerTarget,
+        EngineParam::LowCut,
+        EngineParam::OutputGain,
+    ];
+
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EngineParam::NoiseReduction => "Noise Reduction",
+            EngineParam::DeVerb => "De-Verb",
+            EngineParam::Proximity => "Proximity",
+            EngineParam::Clarity => "Clarity",
+            EngineParam::DeEsser => "De-Esser",
+            EngineParam::Leveler => "Leveler",
+            EngineParam::LevelerTarget => "Leveler Target",
+            EngineParam::LowCut => "Low Cut",
+            EngineParam::OutputGain => "Output Gain",
+        }
+    }
+
+    /// Accepted range (min, max); `set_param()` clamps to it
+    pub fn range(self) -> (f32, f32) {
+        match self {
+            EngineParam::LevelerTarget => (-36.0, -12.0),
+            EngineParam::LowCut => (0.0, 200.0),
+            EngineParam::OutputGain => (-12.0, 12.0),
+            _ => (0.0, 1.0),
+        }
+    }
+
+    /// Value a new engine starts with (no processing beyond the low cut)
+    pub fn default_value(self) -> f32 {
+        match self {
+            EngineParam::LevelerTarget => -24.0,
+            EngineParam::LowCut => 90.0,
+            _ => 0.0,
+        }
+    }
+}
+
+// =============================================================================
+// Meters
+// =============================================================================
+
+/// Meter readings, updated by every processed block. C layout, so the FFI
+/// hands it out as is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct EngineMeters {
+    /// Input peak (dBFS, 300 ms release)
+    pub input_peak_db: f32,
+    /// Output peak (dBFS, 300 ms release)
+    pub output_peak_db: f32,
+    /// Output RMS (dBFS, 300 ms window)
+    pub output_rms_db: f32,
+    /// Speech confidence of the input (0 = noise or silence, 1 = speech)
+    pub speech_confidence: f32,
+    /// Average denoiser reduction (0..1)
+    pub denoise_reduction: f32,
+    /// De-esser, leveler and limiter gain reduction (dB, positive)
+    pub de_esser_gr_db: f32,
+    pub leveler_gr_db: f32,
+    pub limiter_gr_db: f32,
+}
+
+impl Default for EngineMeters {
+    fn default() -> Self {
+        let floor = lin_to_db(0.0);
+        Self {
+            input_peak_db: floor,
+            output_peak_db: floor,
+            output_rms_db: floor,
+            speech_confidence: 0.0,
+            denoise_reduction: 0.0,
+            de_esser_gr_db: 0.0,
+            leveler_gr_db: 0.0,
+            limiter_gr_db: 0.0,
+        }
+    }
+}
+
+// =============================================================================
+// Engine
+// =============================================================================
+
+pub struct Engine {
+    /// Requested values, indexed by id
+    targets: [f32; EngineParam::COUNT],
+    /// Gliding values the chain uses
+    values: [f32; EngineParam::COUNT],
+
+    chain: VoiceChain,
+    settings: ChainSettings,
+    /// Controls of the current sub-block
+    controls: ResolvedControls,
+    /// Position in the current analysis block
+    block_pos: usize,
+
+    input_peak: f32,
+    output_peak: f32,
+    output_ms: f32,
+    meters: EngineMeters,
+
+    param_smooth: f32,
+    peak_release: f32,
+    rms_alpha: f32,
+    sample_rate: f32,
+}
+
+impl Engine {
+    /// Engine for `sample_rate` with every parameter at its default
+    pub fn new(sample_rate: f32) -> Self {
+        let defaults = EngineParam::ALL.map(EngineParam::default_value);
+        let mut chain = VoiceChain::new(sample_rate, SpectralStages::new(WIN_SIZE, sample_rate));
+        // Replaced before the first sample; `reset()` below undoes what
+        // resolving them advanced
+        let controls = chain.resolve_controls(&ChainAmounts::default(), CONTROL_BLOCK_SIZE);
+        let mut engine = Self {
+            targets: defaults,
+            values: defaults,
+            chain,
+            settings: ChainSettings::default(),
+            controls,
+            block_pos: 0,
+            input_peak: 0.0,
+            output_peak: 0.0,
+            output_ms: 0.0,
+            meters: EngineMeters::default(),
+            // Stepped once per control sub-block
+            param_smooth: time_constant_coeff(
+                PARAM_SMOOTH_MS,
+                sample_rate / CONTROL_BLOCK_SIZE as f32,
+            ),
+            peak_release: time_constant_coeff(PEAK_RELEASE_MS, sample_rate),
+            rms_alpha: 1.0 - time_constant_coeff(RMS_WINDOW_MS, sample_rate),
+            sample_rate,
+        };
+        engine.reset();
+        engine
+    }
+
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Delay of the output behind the input (samples)
+    pub fn latency_samples(&self) -> usize {
+        self.chain.latency()
+    }
+
+    /// Set a parameter (clamped to its range). Amounts glide to the new
+    /// value; the low cut and leveler target apply at the next analysis
+    /// block.
+    pub fn set_param(&mut self, param: EngineParam, value: f32) {
+        let (min, max) = param.range();
+        if value.is_finite() {
+            self.targets[param as usize] = value.clamp(min, max);
+        }
+    }
+
+    /// Current requested value of a parameter
+    pub fn param(&self, param: EngineParam) -> f32 {
+        self.targets[param as usize]
+    }
+
+    /// Clear all signal state and adaptive analysis (e.g. between files);
+    /// parameters are kept
+    pub fn reset(&mut self) {
+        self.chain.reset();
+        self.chain.reset_analysis();
+        self.chain.reset_chain_order(self.settings.chain_taps);
+        self.block_pos = 0;
+        self.values = self.targets;
+        self.input_peak = 0.0;
+        self.output_peak = 0.0;
+        self.output_ms = 0.0;
+        self.meters = EngineMeters::default();
+    }
+
+    /// Latest meter readings
+    pub fn meters(&self) -> EngineMeters {
+        self.meters
+    }
+
+    /// Clean one block in place. Only the first `min(left.len(),
+    /// right.len())` samples are processed; pass the same slice contents to
+    /// both sides for mono.
+    pub fn process_block(&mut self, left: &mut [f32], right: &mut [f32]) {
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            (*l, *r) = self.process_sample(*l, *r);
+        }
+
+        self.update_meters();
+    }
+
+    /// Clean an interleaved block in place. `channels` is 1 (mono: both
+    /// sides of the chain get the same sample) or 2; returns false and
+    /// leaves `samples` untouched for any other count. A trailing partial
+    /// frame is left as is.
+    pub fn process_interleaved(&mut self, samples: &mut [f32], channels: usize) -> bool {
+        if channels != 1 && channels != 2 {
+            return false;
+        }
+
+        for frame in samples.chunks_exact_mut(channels) {
+            if let [l, r] = frame {
+                (*l, *r) = self.process_sample(*l, *r);
+            } else {
+                frame[0] = self.process_sample(frame[0], frame[0]).0;
+            }
+        }
+
+        self.update_meters();
+        true
+    }
+
+    fn update_meters(&mut self) {
+        self.meters = EngineMeters {
+            input_peak_db: lin_to_db(self.input_peak),
+            output_peak_db: lin_to_db(self.output_peak),
+            output_rms_db: lin_to_db(self.output_ms.sqrt()),
+            speech_confidence: self.chain.speech_confidence.get_output().speech_conf,
+            denoise_reduction: self.chain.denoiser.get_current_reduction(),
+            de_esser_gr_db: self.chain.linked_de_esser.get_gain_reduction_db(),
+            leveler_gr_db: self.chain.linked_compressor.get_gain_reduction_db(),
+            limiter_gr_db: self.chain.linked_limiter.get_gain_reduction_db(),
+        };
+    }
+
+    /// Block-rate parameters: the low cut corner and the leveler target
+    fn configure(&mut self) {
+        for param in [EngineParam::LowCut, EngineParam::LevelerTarget] {
+            self.values[param as usize] = self.targets[param as usize];
+        }
+        let low_cut = self.values[EngineParam::LowCut as usize];
+        self.settings.low_cut_hz = (low_cut >= LOW_CUT_MIN_HZ).then_some(low_cut);
+        self.settings.leveler_target_db = self.values[EngineParam::LevelerTarget as usize];
+        self.chain.configure(&self.settings);
+    }
+
+    /// Glide the amounts one sub-block towards their targets and resolve
+    /// the chain controls for it
+    fn resolve_controls(&mut self) {
+        for (value, &target) in self.values.iter_mut().zip(&self.targets) {
+            *value = target + self.param_smooth * (*value - target);
+        }
+        let value = |p: EngineParam| self.values[p as usize];
+        let amounts = ChainAmounts {
+            noise_reduction: value(EngineParam::NoiseReduction),
+            reverb_reduction: value(EngineParam::DeVerb),
+            proximity: value(EngineParam::Proximity),
+            clarity: value(EngineParam::Clarity),
+            de_esser: value(EngineParam::DeEsser),
+            leveler: value(EngineParam::Leveler),
+            output_gain: db_to_lin(value(EngineParam::OutputGain)),
+            ..ChainAmounts::default()
+        };
+        self.controls = self.chain.resolve_controls(&amounts, CONTROL_BLOCK_SIZE);
+    }
+
+    #[inline]
+    fn process_sample(&mut self, input_l: f32, input_r: f32) -> (f32, f32) {
+        if self.block_pos == 0 {
+            self.configure();
+        }
+        if self.block_pos.is_multiple_of(CONTROL_BLOCK_SIZE) {
+            self.resolve_controls();
+        }
+
+        self.input_peak =
+            (input_l.abs().max(input_r.abs())).max(self.input_peak * self.peak_release);
+
+        let (comp_l, comp_r) = self.chain.process_sample(input_l, input_r, &self.controls);
+        let (out_l, out_r) = self.chain.finish_sample(comp_l, comp_r);
+
+        self.block_pos += 1;
+        if self.block_pos.is_multiple_of(CONTROL_BLOCK_SIZE) {
+            self.chain.end_sub_block(CONTROL_BLOCK_SIZE);
+        }
+        if self.block_pos == ANALYSIS_BLOCK_SIZE {
+            self.chain.end_block(ANALYSIS_BLOCK_SIZE);
+            self.block_pos = 0;
+        }
+
+        self.output_peak = (out_l.abs().max(out_r.abs())).max(self.output_peak * self.peak_release);
+        let ms = 0.5 * (out_l * out_l + out_r * out_r);
+        self.output_ms += (ms - self.output_ms) * self.rms_alpha;
+        (out_l, out_r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::test_signal::LEVEL_DB;
+    use crate::dsp::{TestSignalGenerator, TestSignalKind};
+
+    const SR: f32 = 48000.0;
+    const BLOCK: usize = 512;
+
+    /// Run `seconds` of a test signal through the engine in blocks
+    fn run(engine: &mut Engine, kind: TestSignalKind, seconds: f32) -> Vec<f32> {
+        let mut generator = TestSignalGenerator::new(SR);
+        let mut out = Vec::new();
+        let mut left = [0.0; BLOCK];
+        let mut right = [0.0; BLOCK];
+        for _ in 0..(seconds * SR) as usize / BLOCK {
+            for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+                *l = generator.next(kind);
+                *r = *l;
+            }
+            engine.process_block(&mut left, &mut right);
+            out.extend_from_slice(&left);
+        }
+        out
+    }
+
+    #[test]
+    fn test_param_ids_are_stable() {
+        for (i, param) in EngineParam::ALL.iter().enumerate() {
+            assert_eq!(param.id(), i as u32);
+            assert_eq!(EngineParam::from_id(param.id()), Some(*param));
+        }
+        assert_eq!(EngineParam::from_id(EngineParam::COUNT as u32), None);
+        assert_eq!(EngineParam::OutputGain.id(), 8);
+    }
+
+    #[test]
+    fn test_set_param_clamps() {
+        let mut engine = Engine::new(SR);
+        engine.set_param(EngineParam::NoiseReduction, 3.0);
+        assert_eq!(engine.param(EngineParam::NoiseReduction), 1.0);
+        engine.set_param(EngineParam::OutputGain, f32::NAN);
+        assert_eq!(engine.param(EngineParam::OutputGain), 0.0);
+    }
+
+    #[test]
+    fn test_defaults_pass_a_tone() {
+        let mut engine = Engine::new(SR);
+        let latency = engine.latency_samples();
+        // Static noise, denoise and de-verb windows (the default low cut is
+        // minimum phase)
+        assert_eq!(latency, 3 * WIN_SIZE);
+
+        let out = run(&mut engine, TestSignalKind::Tone, 1.0);
+        let peak = out[SR as usize / 2..]
+            .iter()
+            .fold(0.0f32, |m, v| m.max(v.abs()));
+        let peak_db = lin_to_db(peak);
+        // The level moves by the chain's small corrections at most (loudness
+        // compensation, recovery EQ)
+        assert!((peak_db - LEVEL_DB).abs() < 1.5, "{peak_db}");
+
+        let meters = engine.meters();
+        assert!((meters.input_peak_db - LEVEL_DB).abs() < 0.1);
+        assert!((meters.output_rms_db - (LEVEL_DB - 3.0)).abs() < 1.5);
+    }
+
+    #[test]
+    fn test_interleaved_matches_split_blocks() {
+        let mut split = Engine::new(SR);
+        let mut interleaved = Engine::new(SR);
+        split.set_param(EngineParam::NoiseReduction, 0.6);
+        interleaved.set_param(EngineParam::NoiseReduction, 0.6);
+
+        let mut generator = TestSignalGenerator::new(SR);
+        let mut left = [0.0; BLOCK];
+        let mut right = [0.0; BLOCK];
+        let mut frames = [0.0; 2 * BLOCK];
+        for _ in 0..20 {
+            for i in 0..BLOCK {
+                left[i] = generator.next(TestSignalKind::PinkNoise);
+                right[i] = -0.5 * left[i];
+                frames[2 * i] = left[i];
+                frames[2 * i + 1] = right[i];
+            }
+            split.process_block(&mut left, &mut right);
+            assert!(interleaved.process_interleaved(&mut frames, 2));
+        }
+        for i in 0..BLOCK {
+            assert_eq!(frames[2 * i], left[i]);
+            assert_eq!(frames[2 * i + 1], right[i]);
+        }
+        assert_eq!(split.meters(), interleaved.meters());
+        assert!(!interleaved.process_interleaved(&mut frames, 3));
+    }
+
+    #[test]
+    fn test_random_block_sizes_match_one_block() {
+        let settings = [
+            (EngineParam::NoiseReduction, 0.6),
+            (EngineParam::DeVerb, 0.4),
+            (EngineParam::Proximity, 0.3),
+            (EngineParam::Clarity, 0.4),
+            (EngineParam::DeEsser, 0.5),
+            (EngineParam::Leveler, 0.6),
+            (EngineParam::LowCut, 80.0),
+        ];
+        let engine = || {
+            let mut engine = Engine::new(SR);
+            // Left gliding: the glide has to land the same way too
+            for &(param, value) in &settings {
+                engine.set_param(param, value);
+            }
+            engine
+        };
+        let mut generator = TestSignalGenerator::with_seed(SR, 7);
+        let left: Vec<f32> = (0..SR as usize)
+            .map(|_| generator.next(TestSignalKind::PinkNoise))
+            .collect();
+        let right: Vec<f32> = left.iter().map(|v| -0.5 * v).collect();
+
+        let (mut one_l, mut one_r) = (left.clone(), right.clone());
+        engine().process_block(&mut one_l, &mut one_r);
+
+        // Host block sizes from 1 to 2048 samples (xorshift32)
+        let mut rng = 0x1234_5678u32;
+        let mut block_size = move || {
+            rng ^= rng << 13;
+            rng ^= rng >> 17;
+            rng ^= rng << 5;
+            1 + rng as usize % 2048
+        };
+        let (mut split_l, mut split_r) = (left.clone(), right.clone());
+        let mut split = engine();
+        let mut frames: Vec<f32> = left
+            .iter()
+            .zip(&right)
+            .flat_map(|(&l, &r)| [l, r])
+            .collect();
+        let mut interleaved = engine();
+        let mut start = 0;
+        while start < left.len() {
+            let end = (start + block_size()).min(left.len());
+            split.process_block(&mut split_l[start..end], &mut split_r[start..end]);
+            assert!(interleaved.process_interleaved(&mut frames[2 * start..2 * end], 2));
+            start = end;
+        }
+
+        assert_eq!(split_l, one_l);
+        assert_eq!(split_r, one_r);
+        for (i, frame) in frames.chunks_exact(2).enumerate() {
+            assert_eq!(frame, [one_l[i], one_r[i]]);
+        }
+    }
+
+    #[test]
+    fn test_full_settings_stay_finite_and_under_ceiling() {
+        let mut engine = Engine::new(SR);
+        for param in EngineParam::ALL {
+            engine.set_param(param, param.range().1);
+        }
+        let out = run(&mut engine, TestSignalKind::PinkNoise, 2.0);
+        // Limiter ceiling plus the output gain, with room for overshoot
+        let ceiling = db_to_lin(EngineParam::OutputGain.range().1 + 1.0);
+        assert!(out.iter().all(|v| v.is_finite() && v.abs() <= ceiling));
+
+        engine.reset();
+        assert_eq!(engine.meters(), EngineMeters::default());
+    }
+}
@@ -0,0 +1,205 @@
+//! C FFI (`ffi` feature)
+//!
+//! `extern "C"` wrappers around `Engine` for C, C++ and Python (ctypes /
+//! cffi) pipelines, e.g. cleanup before speech recognition. The matching
+//! header is `include/voice_studio_core.h`.
+//!
+//! # Design Notes
+//! - The engine is an opaque heap pointer: `vs_engine_create()` /
+//!   `vs_engine_destroy()`
+//! - Parameters are addressed by their stable `EngineParam` id
+//! - Fallible calls return `VS_OK` (0) or a negative `VS_ERR_*` code; null
+//!   pointers are reported, never dereferenced
+//! - One engine must not be used from two threads at once; separate engines
+//!   are independent
+//!
+//! The header is generated with cbindgen (config in `cbindgen.toml`):
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --crate voice-studio-core --output include/voice_studio_core.h
+//! ```
+//!
+//! ## Audio Thread Safety
+//! - Only `vs_engine_create()` allocates; processing and metering calls do
+//!   not
+
+use crate::engine::{Engine, EngineMeters, EngineParam};
+
+/// Success
+pub const VS_OK: i32 = 0;
+/// A required pointer was null
+pub const VS_ERR_NULL: i32 = -1;
+/// Unknown parameter id
+pub const VS_ERR_PARAM: i32 = -2;
+/// Channel count other than 1 or 2
+pub const VS_ERR_CHANNELS: i32 = -3;
+
+/// Create an engine for `sample_rate` with default parameters. Returns null
+/// for a rate that is not positive and finite. Free with
+/// `vs_engine_destroy()`.
+#[no_mangle]
+pub extern "C" fn vs_engine_create(sample_rate: f32) -> *mut Engine {
+    if !(sample_rate.is_finite() && sample_rate > 0.0) {
+        return std::ptr::null_mut();
+    }
+    Box::into_raw(Box::new(Engine::new(sample_rate)))
+}
+
+/// Free an engine. Null is ignored.
+///
+/// # Safety
+/// `engine` must come from `vs_engine_create()` and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_destroy(engine: *mut Engine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Set a parameter by id (clamped to its range).
+///
+/// # Safety
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_set_param(engine: *mut Engine, id: u32, value: f32) -> i32 {
+    let Some(engine) = engine.as_mut() else {
+        return VS_ERR_NULL;
+    };
+    match EngineParam::from_id(id) {
+        Some(param) => {
+            engine.set_param(param, value);
+            VS_OK
+        }
+        None => VS_ERR_PARAM,
+    }
+}
+
+/// Current value of a parameter; NaN for a null engine or unknown id.
+///
+/// # Safety
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_get_param(engine: *const Engine, id: u32) -> f32 {
+    match (engine.as_ref(), EngineParam::from_id(id)) {
+        (Some(engine), Some(param)) => engine.param(param),
+        _ => f32::NAN,
+    }
+}
+
+/// Output delay behind the input (samples); 0 for a null engine.
+///
+/// # Safety
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_latency_samples(engine: *const Engine) -> u32 {
+    engine.as_ref().map_or(0, |e| e.latency_samples() as u32)
+}
+
+/// Clear all signal state (parameters are kept). Null is ignored.
+///
+/// # Safety
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_reset(engine: *mut Engine) {
+    if let Some(engine) = engine.as_mut() {
+        engine.reset();
+    }
+}
+
+/// Clean `frames` frames of interleaved `channels`-channel (1 or 2) audio
+/// in place.
+///
+/// # Safety
+/// `engine` must be null or a live engine; `samples` must be null or point
+/// to `frames * channels` writable floats.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_process_interleaved(
+    engine: *mut Engine,
+    samples: *mut f32,
+    frames: usize,
+    channels: u32,
+) -> i32 {
+    let Some(engine) = engine.as_mut() else {
+        return VS_ERR_NULL;
+    };
+    if channels != 1 && channels != 2 {
+        return VS_ERR_CHANNELS;
+    }
+    if frames == 0 {
+        return VS_OK;
+    }
+    if samples.is_null() {
+        return VS_ERR_NULL;
+    }
+    let samples = std::slice::from_raw_parts_mut(samples, frames * channels as usize);
+    engine.process_interleaved(samples, channels as usize);
+    VS_OK
+}
+
+/// Copy the latest meter readings into `out`.
+///
+/// # Safety
+/// `engine` must be null or a live engine; `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn vs_engine_get_meters(
+    engine: *const Engine,
+    out: *mut EngineMeters,
+) -> i32 {
+    match (engine.as_ref(), out.as_mut()) {
+        (Some(engine), Some(out)) => {
+            *out = engine.meters();
+            VS_OK
+        }
+        _ => VS_ERR_NULL,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp::{TestSignalGenerator, TestSignalKind};
+
+    const SR: f32 = 48000.0;
+
+    #[test]
+    fn test_round_trip_through_the_c_api() {
+        assert!(vs_engine_create(0.0).is_null());
+        let engine = vs_engine_create(SR);
+        assert!(!engine.is_null());
+
+        unsafe {
+            let id = EngineParam::NoiseReduction.id();
+            assert_eq!(vs_engine_set_param(engine, id, 0.4), VS_OK);
+            assert_eq!(vs_engine_get_param(engine, id), 0.4);
+            assert_eq!(vs_engine_set_param(engine, 99, 0.4), VS_ERR_PARAM);
+            assert!(vs_engine_get_param(engine, 99).is_nan());
+            assert_eq!(vs_engine_latency_samples(engine), 4096);
+
+            let mut generator = TestSignalGenerator::new(SR);
+            let mut block = [0.0f32; 2 * 256];
+            for _ in 0..100 {
+                for frame in block.chunks_exact_mut(2) {
+                    frame.fill(generator.next(TestSignalKind::Tone));
+                }
+                let status = vs_engine_process_interleaved(engine, block.as_mut_ptr(), 256, 2);
+                assert_eq!(status, VS_OK);
+            }
+            let status = vs_engine_process_interleaved(engine, block.as_mut_ptr(), 256, 6);
+            assert_eq!(status, VS_ERR_CHANNELS);
+
+            let mut meters = EngineMeters::default();
+            assert_eq!(vs_engine_get_meters(engine, &mut meters), VS_OK);
+            assert!(meters.output_peak_db > -40.0);
+            assert_eq!(
+                vs_engine_get_meters(engine, std::ptr::null_mut()),
+                VS_ERR_NULL
+            );
+
+            vs_engine_destroy(engine);
+            assert_eq!(
+                vs_engine_set_param(std::ptr::null_mut(), 0, 0.0),
+                VS_ERR_NULL
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Voice Studio core: the cleaning engine behind the vxcleaner plugin.
+//!
+//! Everything here runs without a plugin host, so the chain can be embedded
+//! in other hosts, command-line tools or telephony services.
+//!
+//! - [`engine`] - Stable embedding API: process blocks, set parameters by
+//!   id, fetch meters
+//! - [`chain`] - The plugin's full signal path, shared by the plugin and
+//!   `engine`
+//! - [`dsp`] - The individual processing stages and analysis modules
+//! - [`profile`] - Target and measured audio profiles, condition detection
+//! - [`macros`] - Simple-mode macro calibration and amount mapping
+//! - [`null_test`] - Deterministic rendering and residual measurement for
+//!   regression tests
+//! - [`speaker_profile`] - Learned per-speaker statistics and adaptation
+//! - `ffi` - C bindings for `engine` (`ffi` feature, header in
+//!   `include/voice_studio_core.h`)
+//! - `wasm` - JavaScript bindings for `engine` (`wasm` feature, for the
+//!   in-browser preview)
+//!
+//! `engine` is the stable surface; `chain` and the `dsp` modules follow the plugin and
+//! may change between releases.
+//!
+//! ```no_run
+//! use voice_studio_core::{Engine, EngineParam};
+//!
+//! let mut engine = Engine::new(48000.0);
+//! engine.set_param(EngineParam::NoiseReduction, 0.5);
+//! engine.set_param(EngineParam::Leveler, 0.7);
+//!
+//! let mut left = vec![0.0f32; 512];
+//! let mut right = vec![0.0f32; 512];
+//! engine.process_block(&mut left, &mut right);
+//! println!("output {:.1} dBFS", engine.meters().output_peak_db);
+//! ```
+
+pub mod chain;
+pub mod dsp;
+pub mod engine;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod macros;
+pub mod null_test;
+pub mod profile;
+pub mod speaker_profile;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use engine::{Engine, EngineMeters, EngineParam};
+pub use profile::{AudioProfile, ConditionStats, DetectedConditions, TargetProfile};
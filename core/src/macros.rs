@@ -0,0 +1,206 @@
+//! Simple-mode Macro Mapping
+//!
+//! The parameter-free half of the macro controller: the three macros, their
+//! calibration from an `AudioProfile`, and the mapping from macro amounts to
+//! the advanced amounts they push. The plugin layers its parameters, locks
+//! and host automation on top (`macro_controller` in the plugin crate).
+//!
+//! Auto macros: with a macro's Auto switch on, its amount comes from the
+//! calibration (distance of the input profile from `TargetProfile`) and the
+//! dial becomes an offset around that amount, centered at 50%.
+
+use crate::dsp::utils::{lerp, smoothstep};
+use crate::{AudioProfile, TargetProfile};
+
+// Auto calibration ranges
+/// Input SNR that needs no cleaning (dB); Clean reaches 100% at 0 dB
+const AUTO_CLEAN_SNR_CLEAN_DB: f32 = 30.0;
+/// Noise floor above the target maximum that maps to full Clean
+const AUTO_CLEAN_FLOOR_EXCESS_FULL: f32 = 0.05;
+/// RMS variance above the target maximum (in multiples) for full Control
+const AUTO_CONTROL_VARIANCE_EXCESS_FULL: f32 = 4.0;
+/// Crest factor above the target maximum (dB) for full Control
+const AUTO_CONTROL_CREST_EXCESS_FULL_DB: f32 = 10.0;
+/// Dial position meaning "no offset" while Auto is on
+pub const AUTO_OFFSET_CENTER: f32 = 0.5;
+
+/// The three Simple-mode macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacroKind {
+    Clean,
+    Enhance,
+    Control,
+}
+
+/// Calibration-chosen macro amounts (0..1)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AutoMacroAmounts {
+    pub clean: f32,
+    pub enhance: f32,
+    pub control: f32,
+}
+
+impl AutoMacroAmounts {
+    /// Amounts that would bring `profile` towards `target`
+    pub fn calibrate(profile: &AudioProfile, target: &TargetProfile) -> Self {
+        // CLEAN: low SNR or a noise floor above target
+        let snr_need =
+            ((AUTO_CLEAN_SNR_CLEAN_DB - profile.snr_db) / AUTO_CLEAN_SNR_CLEAN_DB).clamp(0.0, 1.0);
+        let floor_excess = TargetProfile::distance_from_range(
+            profile.noise_floor,
+            target.noise_floor_min,
+            target.noise_floor_max,
+        )
+        .max(0.0);
+        let floor_need = (floor_excess / AUTO_CLEAN_FLOOR_EXCESS_FULL).clamp(0.0, 1.0);
+
+        // ENHANCE: late energy dominating early (distant, roomy voice)
+        let early_late_deficit = -TargetProfile::distance_from_range(
+            profile.early_late_ratio,
+            target.early_late_ratio_min,
+            target.early_late_ratio_max,
+        )
+        .min(0.0);
+        let enhance = (early_late_deficit / target.early_late_ratio_min).clamp(0.0, 1.0);
+
+        // CONTROL: level swings or peaky dynamics beyond the target
+        let variance_excess = (profile.rms_variance / target.rms_variance_max - 1.0).max(0.0);
+        let variance_need = (variance_excess / AUTO_CONTROL_VARIANCE_EXCESS_FULL).clamp(0.0, 1.0);
+        let crest_excess = (profile.crest_factor_db - target.crest_factor_db_max).max(0.0);
+        let crest_need = (crest_excess / AUTO_CONTROL_CREST_EXCESS_FULL_DB).clamp(0.0, 1.0);
+
+        Self {
+            clean: snr_need.max(floor_need),
+            enhance,
+            control: variance_need.max(crest_need),
+        }
+    }
+
+    /// One-pole follow towards `target` (`alpha` = per-update coefficient).
+    /// Enhance is judged from the reverb metrics, so it follows only as fast
+    /// as `reverb_stability` (0..1) trusts them.
+    pub fn follow(&mut self, target: &Self, alpha: f32, reverb_stability: f32) {
+        self.clean += (target.clean - self.clean) * alpha;
+        self.enhance += (target.enhance - self.enhance) * alpha * reverb_stability.clamp(0.0, 1.0);
+        self.control += (target.control - self.control) * alpha;
+    }
+
+    pub fn get(&self, kind: MacroKind) -> f32 {
+        match kind {
+            MacroKind::Clean => self.clean,
+            MacroKind::Enhance => self.enhance,
+            MacroKind::Control => self.control,
+        }
+    }
+
+    /// Calibrated amount of `kind` shifted by a dial centered at
+    /// `AUTO_OFFSET_CENTER`
+    pub fn offset(&self, kind: MacroKind, dial: f32) -> f32 {
+        (self.get(kind) + dial - AUTO_OFFSET_CENTER).clamp(0.0, 1.0)
+    }
+}
+
+/// Artifact-safe ceilings for the resolved amounts in Safe Live Mode
+pub const SAFE_LIVE_LIMITS: SimpleMacroTargets = SimpleMacroTargets {
+    noise_reduction: 0.7,
+    reverb_reduction: 0.6,
+    proximity: 0.7,
+    clarity: 0.7,
+    de_esser: 0.8,
+    leveler: 0.8,
+    breath_control: 0.6,
+    rumble: 1.0,
+    hiss: 0.7,
+};
+
+#[derive(Clone, Copy, Default)]
+pub struct SimpleMacroTargets {
+    pub noise_reduction: f32,
+    pub reverb_reduction: f32,
+    pub proximity: f32,
+    pub clarity: f32,
+    pub de_esser: f32,
+    pub leveler: f32,
+    pub breath_control: f32,
+    pub rumble: f32,
+    pub hiss: f32,
+}
+
+impl SimpleMacroTargets {
+    /// Per-field blend from `self` (t = 0) to `to` (t = 1)
+    pub fn mix(&self, to: &Self, t: f32) -> Self {
+        let m = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            noise_reduction: m(self.noise_reduction, to.noise_reduction),
+            reverb_reduction: m(self.reverb_reduction, to.reverb_reduction),
+            proximity: m(self.proximity, to.proximity),
+            clarity: m(self.clarity, to.clarity),
+            de_esser: m(self.de_esser, to.de_esser),
+            leveler: m(self.leveler, to.leveler),
+            breath_control: m(self.breath_control, to.breath_control),
+            rumble: m(self.rumble, to.rumble),
+            hiss: m(self.hiss, to.hiss),
+        }
+    }
+
+    /// Per-field minimum of `self` and `limits`
+    pub fn limited_to(&self, limits: &Self) -> Self {
+        Self {
+            noise_reduction: self.noise_reduction.min(limits.noise_reduction),
+            reverb_reduction: self.reverb_reduction.min(limits.reverb_reduction),
+            proximity: self.proximity.min(limits.proximity),
+            clarity: self.clarity.min(limits.clarity),
+            de_esser: self.de_esser.min(limits.de_esser),
+            leveler: self.leveler.min(limits.leveler),
+            breath_control: self.breath_control.min(limits.breath_control),
+            rumble: self.rumble.min(limits.rumble),
+            hiss: self.hiss.min(limits.hiss),
+        }
+    }
+}
+
+/// Advanced amounts for the resolved Clean, Enhance and Control macro
+/// amounts (0..1 each)
+pub fn macro_targets(x_clean: f32, x_enhance: f32, x_control: f32) -> SimpleMacroTargets {
+    // --- CLEAN macro mappings ---
+    // Rumble: 20 -> 60 Hz. Norm: (60-20)/(120-20) = 0.4
+    let rumble_norm = ((x_clean - 0.1) / 0.5).clamp(0.0, 1.0);
+    let rumble_param = rumble_norm * rumble_norm * 0.4;
+
+    // Hiss: 0 -> -6 dB. Norm: -6/-24 = 0.25
+    let hiss_norm = ((x_clean - 0.35) / 0.45).clamp(0.0, 1.0);
+    let hiss_param = hiss_norm * 0.25;
+
+    // Main Denoiser: 0 -> 70%
+    let denoise_amt = ((x_clean - 0.45) / 0.55).clamp(0.0, 1.0) * 0.7;
+
+    // --- ENHANCE macro mappings ---
+    // Proximity: smooth ramp
+    let proximity = smoothstep(0.0, 0.6, x_enhance);
+    // Clarity: capped at 40%
+    let clarity = smoothstep(0.3, 0.9, x_enhance) * 0.4;
+
+    // --- CONTROL macro mappings ---
+    // De-esser
+    let deesser = smoothstep(0.2, 0.7, x_control);
+    // Leveler
+    let leveler = smoothstep(0.0, 1.0, x_control);
+
+    SimpleMacroTargets {
+        noise_reduction: denoise_amt,
+        reverb_reduction: 0.0,
+        proximity,
+        clarity,
+        de_esser: deesser,
+        leveler,
+        breath_control: lerp(x_control, 0.0, 0.5),
+        rumble: rumble_param,
+        hiss: hiss_param,
+    }
+}
+
+/// Static noise removal amount driven by the Clean macro (0 -> 100% over
+/// the top 40% of the dial)
+pub fn static_noise_amount(x_clean: f32) -> f32 {
+    ((x_clean - 0.6) / 0.4).clamp(0.0, 1.0)
+}
@@ -0,0 +1,148 @@
+//! Null Tests (deterministic rendering for regression checks)
+//!
+//! A fixed way to run audio through the engine, and a measure of how far two
+//! renders differ, so a DSP refactor can be checked against stored output
+//! instead of by ear.
+//!
+//! # Purpose
+//! A refactor that should not change the sound (the shared-FFT redesign,
+//! moving a stage, a faster filter) is verified by rendering the same input
+//! before and after and subtracting: what is left is the audible change.
+//! `tests/golden.rs` does this for every stage against golden files.
+//!
+//! # Design Notes
+//! - `render()` starts a fresh engine with the settings applied at once (no
+//!   parameter glide), processes in `BLOCK_SIZE` blocks and removes the
+//!   latency, so the output lines up with the input sample for sample
+//! - The engine itself has no random sources; generated inputs come from
+//!   `TestSignalGenerator` with the fixed `SEED`
+//! - `residual_db()` is the difference relative to the reference level, so
+//!   a tolerance reads the same for loud and quiet material
+
+use crate::dsp::{TestSignalGenerator, TestSignalKind};
+use crate::engine::{Engine, EngineParam};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Block size `render()` processes in
+pub const BLOCK_SIZE: usize = 512;
+
+/// Noise seed for generated inputs
+pub const SEED: u32 = 0x5eed_0001;
+
+/// Residual reported for a render identical to its reference (dB)
+pub const IDENTICAL_DB: f32 = -200.0;
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+/// Process a stereo input through a fresh engine at `sample_rate` with
+/// `settings` (all other parameters at their defaults) and return the output,
+/// latency removed and the same length as the input. Only the first
+/// `min(left.len(), right.len())` samples are used.
+pub fn render(
+    sample_rate: f32,
+    settings: &[(EngineParam, f32)],
+    left: &[f32],
+    right: &[f32],
+) -> (Vec<f32>, Vec<f32>) {
+    let mut engine = Engine::new(sample_rate);
+    for &(param, value) in settings {
+        engine.set_param(param, value);
+    }
+    // Start on the settings rather than gliding to them
+    engine.reset();
+
+    let len = left.len().min(right.len());
+    let latency = engine.latency_samples();
+    let mut out_l = Vec::with_capacity(len + latency);
+    let mut out_r = Vec::with_capacity(len + latency);
+    out_l.extend_from_slice(&left[..len]);
+    out_r.extend_from_slice(&right[..len]);
+    // Flush the tail out through the latency
+    out_l.resize(len + latency, 0.0);
+    out_r.resize(len + latency, 0.0);
+
+    for (l, r) in out_l
+        .chunks_mut(BLOCK_SIZE)
+        .zip(out_r.chunks_mut(BLOCK_SIZE))
+    {
+        engine.process_block(l, r);
+    }
+
+    out_l.drain(..latency);
+    out_r.drain(..latency);
+    (out_l, out_r)
+}
+
+/// `seconds` of a test signal from the seeded generator (mono)
+pub fn test_input(kind: TestSignalKind, sample_rate: f32, seconds: f32) -> Vec<f32> {
+    let mut generator = TestSignalGenerator::with_seed(sample_rate, SEED);
+    (0..(seconds * sample_rate) as usize)
+        .map(|_| generator.next(kind))
+        .collect()
+}
+
+// =============================================================================
+// Comparison
+// =============================================================================
+
+/// Level of `output - reference` relative to the reference (dB, over the
+/// common length). `IDENTICAL_DB` when they match exactly; 0 dB when the
+/// reference is silent and the output is not.
+pub fn residual_db(output: &[f32], reference: &[f32]) -> f32 {
+    let mut diff = 0.0f64;
+    let mut level = 0.0f64;
+    for (&out, &want) in output.iter().zip(reference) {
+        let d = (out - want) as f64;
+        diff += d * d;
+        level += want as f64 * want as f64;
+    }
+    if diff == 0.0 {
+        return IDENTICAL_DB;
+    }
+    if level == 0.0 {
+        return 0.0;
+    }
+    (10.0 * (diff / level).log10()).max(IDENTICAL_DB as f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    #[test]
+    fn test_render_is_repeatable_and_aligned() {
+        let input = test_input(TestSignalKind::PinkNoise, SR, 0.5);
+        let settings = [
+            (EngineParam::NoiseReduction, 0.6),
+            (EngineParam::Leveler, 0.5),
+        ];
+
+        let (a_l, a_r) = render(SR, &settings, &input, &input);
+        let (b_l, b_r) = render(SR, &settings, &input, &input);
+        assert_eq!(a_l.len(), input.len());
+        assert_eq!(a_r.len(), input.len());
+        assert_eq!(residual_db(&a_l, &b_l), IDENTICAL_DB);
+        assert_eq!(residual_db(&a_r, &b_r), IDENTICAL_DB);
+        assert!(a_l.iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn test_residual_level() {
+        let reference = test_input(TestSignalKind::Tone, SR, 0.1);
+        let scaled: Vec<f32> = reference.iter().map(|v| v * 1.01).collect();
+        let silent = vec![0.0; reference.len()];
+
+        // 1% error is -40 dB
+        assert!((residual_db(&scaled, &reference) + 40.0).abs() < 0.01);
+        assert!((residual_db(&silent, &reference)).abs() < 1e-3);
+        assert_eq!(residual_db(&reference, &reference), IDENTICAL_DB);
+        assert_eq!(residual_db(&reference, &silent), 0.0);
+    }
+}
@@ -0,0 +1,244 @@
+//! Calibration Profiles
+//!
+//! The target envelope for professional voice-over (`TargetProfile`), the
+//! measured profile of a signal (`AudioProfile`, produced by
+//! `dsp::ProfileAnalyzer`) and the hard-rule conditions derived from it
+//! (`DetectedConditions`). All DSP calibration is driven by the distance of
+//! the measured profile from the target.
+
+// =============================================================================
+// TASK 1: CANONICAL DATA STRUCTURES (Data-Driven Calibration)
+// =============================================================================
+
+/// Static target envelope for professional voice-over audio.
+/// These ranges define what "good" sounds like - all DSP decisions
+/// are driven by distance from these targets.
+///
+/// IMPORTANT: This struct is immutable at runtime.
+#[derive(Clone, Copy, Debug)]
+pub struct TargetProfile {
+    // Dynamics targets
+    pub rms_min: f32,
+    pub rms_max: f32,
+    pub crest_factor_db_min: f32,
+    pub crest_factor_db_max: f32,
+    pub rms_variance_max: f32,
+
+    // Noise targets
+    pub noise_floor_min: f32,
+    pub noise_floor_max: f32,
+    pub snr_db_min: f32,
+
+    // Reverb targets
+    pub early_late_ratio_min: f32,
+    pub early_late_ratio_max: f32,
+    pub decay_slope_min: f32,
+    pub decay_slope_max: f32,
+
+    // Frequency balance targets
+    pub presence_ratio_max: f32,
+    pub air_ratio_max: f32,
+    pub hf_variance_max: f32,
+}
+
+impl Default for TargetProfile {
+    fn default() -> Self {
+        Self::PROFESSIONAL_VO
+    }
+}
+
+impl TargetProfile {
+    /// Professional voice-over target envelope (from measured reference recordings)
+    pub const PROFESSIONAL_VO: TargetProfile = TargetProfile {
+        // Dynamics: consistent, moderate level
+        rms_min: 0.045,
+        rms_max: 0.060,
+        crest_factor_db_min: 23.0,
+        crest_factor_db_max: 27.0,
+        rms_variance_max: 0.0015,
+
+        // Noise: clean but natural
+        noise_floor_min: 0.010,
+        noise_floor_max: 0.015,
+        snr_db_min: 10.0,
+
+        // Reverb: present but controlled
+        early_late_ratio_min: 0.50,
+        early_late_ratio_max: 0.70,
+        decay_slope_min: -0.0001,
+        decay_slope_max: 0.0001,
+
+        // Frequency: natural presence, no harshness
+        presence_ratio_max: 0.01,
+        air_ratio_max: 0.005,
+        hf_variance_max: 3e-7,
+    };
+
+    /// Check if a value is within a target range
+    #[inline]
+    pub fn in_range(value: f32, min: f32, max: f32) -> bool {
+        value >= min && value <= max
+    }
+
+    /// Compute distance from target (negative = below, positive = above, 0 = in range)
+    #[inline]
+    pub fn distance_from_range(value: f32, min: f32, max: f32) -> f32 {
+        if value < min {
+            value - min // negative
+        } else if value > max {
+            value - max // positive
+        } else {
+            0.0 // in range
+        }
+    }
+}
+
+/// Audio profile computed from signal analysis.
+/// Used for both InputProfile (pre-DSP) and OutputProfile (post-DSP).
+///
+/// IMPORTANT: InputProfile is computed pre-DSP, OutputProfile post-DSP, each
+/// once per 50 ms analysis frame (not per host buffer).
+/// No mid-chain re-analysis for control decisions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AudioProfile {
+    // Dynamics metrics
+    pub rms: f32,
+    pub peak: f32,
+    pub crest_factor_db: f32,
+    pub rms_variance: f32,
+
+    // Noise metrics
+    pub noise_floor: f32,
+    pub snr_db: f32,
+
+    // Reverb metrics
+    pub early_late_ratio: f32,
+    pub decay_slope: f32,
+    /// Trust in the two reverb metrics (0 = no confident speech measured or
+    /// entries scattered, 1 = a full, consistent history)
+    pub reverb_stability: f32,
+
+    // Frequency balance metrics
+    pub presence_ratio: f32,
+    pub air_ratio: f32,
+    pub hf_variance: f32,
+}
+
+impl AudioProfile {
+    /// Check if this profile is fully within target bounds (clean audio detection)
+    pub fn is_within_target(&self, target: &TargetProfile) -> bool {
+        TargetProfile::in_range(self.rms, target.rms_min, target.rms_max)
+            && TargetProfile::in_range(
+                self.crest_factor_db,
+                target.crest_factor_db_min,
+                target.crest_factor_db_max,
+            )
+            && self.rms_variance <= target.rms_variance_max
+            && self.snr_db >= target.snr_db_min
+            && TargetProfile::in_range(
+                self.early_late_ratio,
+                target.early_late_ratio_min,
+                target.early_late_ratio_max,
+            )
+            && TargetProfile::in_range(
+                self.decay_slope,
+                target.decay_slope_min,
+                target.decay_slope_max,
+            )
+            && self.presence_ratio <= target.presence_ratio_max
+            && self.air_ratio <= target.air_ratio_max
+            && self.hf_variance <= target.hf_variance_max
+    }
+}
+
+// =============================================================================
+// TASK 2: CONDITION DETECTION (Hard Rules)
+// =============================================================================
+
+/// Detected audio conditions based on measured metrics.
+/// These are read-only signals used for caps and guards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DetectedConditions {
+    /// Whisper: HF variance > 1e-6 AND SNR < 15 dB
+    pub whisper: bool,
+
+    /// Distant mic: Early/Late ratio < 0.05 AND Decay slope < -0.0005, with
+    /// reverb stability >= 0.5
+    pub distant_mic: bool,
+
+    /// Noisy environment: Noise floor > 0.05 AND SNR < 6 dB
+    pub noisy_environment: bool,
+
+    /// Clean/already-good: SNR >= 10 dB AND Early/Late >= 0.4 AND HF variance <= 3e-7
+    pub clean_audio: bool,
+}
+
+impl DetectedConditions {
+    /// Detect conditions from an audio profile using hard threshold rules
+    pub fn detect(profile: &AudioProfile) -> Self {
+        Self {
+            // Whisper detection: breathy HF content with low SNR
+            whisper: profile.hf_variance > 1e-6 && profile.snr_db < 15.0,
+
+            // Distant mic detection: diffuse reverb field, measured reliably
+            distant_mic: profile.early_late_ratio < 0.05
+                && profile.decay_slope < -0.0005
+                && profile.reverb_stability >= 0.5,
+
+            // Noisy environment detection: high noise floor
+            noisy_environment: profile.noise_floor > 0.05 && profile.snr_db < 6.0,
+
+            // Clean audio detection: already professional quality
+            clean_audio: profile.snr_db >= 10.0
+                && profile.early_late_ratio >= 0.4
+                && profile.hf_variance <= 3e-7,
+        }
+    }
+}
+
+/// How long each condition was detected over the session, so a condition
+/// that persists (a distant mic most of the time) points at the recording
+/// setup rather than at more processing
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConditionStats {
+    /// Analyzed input time (s)
+    pub total_sec: f64,
+    /// Time each condition was detected (s)
+    pub whisper_sec: f64,
+    pub distant_mic_sec: f64,
+    pub noisy_environment_sec: f64,
+    pub clean_audio_sec: f64,
+}
+
+impl ConditionStats {
+    /// Count `dt_sec` of input with `conditions` detected
+    pub fn update(&mut self, conditions: &DetectedConditions, dt_sec: f32) {
+        let dt = dt_sec.max(0.0) as f64;
+        self.total_sec += dt;
+        let mut count = |active: bool, sec: &mut f64| {
+            if active {
+                *sec += dt;
+            }
+        };
+        count(conditions.whisper, &mut self.whisper_sec);
+        count(conditions.distant_mic, &mut self.distant_mic_sec);
+        count(
+            conditions.noisy_environment,
+            &mut self.noisy_environment_sec,
+        );
+        count(conditions.clean_audio, &mut self.clean_audio_sec);
+    }
+
+    /// Share of the analyzed time `active_sec` covers (0..1, 0 before any)
+    pub fn share(&self, active_sec: f64) -> f32 {
+        if self.total_sec > 0.0 {
+            (active_sec / self.total_sec) as f32
+        } else {
+            0.0
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
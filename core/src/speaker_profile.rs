@@ -0,0 +1,195 @@
+//! Speaker Profiles
+//!
+//! Long-term voice statistics (F0 range, sibilance center, spectral tilt,
+//! typical speech level) captured by `dsp::SpeakerProfileLearner` and stored
+//! under a name so recurring hosts can be recalled in later sessions.
+//!
+//! The store is persisted with the plugin state. Loading a profile derives a
+//! `SpeakerAdaptation` that re-centers the de-esser band, scales proximity
+//! shaping and offsets the leveler target for that voice.
+
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// Adaptation Constants
+// =============================================================================
+
+/// Speech level the leveler target is tuned for (dBFS RMS)
+const REFERENCE_SPEECH_RMS_DB: f32 = -24.0;
+/// Share of the speaker's level offset passed to the leveler target
+const LEVELER_OFFSET_SHARE: f32 = 0.5;
+const LEVELER_OFFSET_MAX_DB: f32 = 6.0;
+
+/// Voice pitch range mapped onto proximity scaling (Hz, geometric F0 center).
+/// Deep voices already carry low end, so they get less proximity boost.
+const PROX_F0_LOW_HZ: f32 = 100.0;
+const PROX_F0_HIGH_HZ: f32 = 220.0;
+const PROX_SCALE_LOW_F0: f32 = 0.8;
+const PROX_SCALE_HIGH_F0: f32 = 1.2;
+
+/// Minimum voiced time before a learned profile can be saved (seconds)
+pub const MIN_VOICED_SEC: f32 = 10.0;
+
+// =============================================================================
+// Statistics
+// =============================================================================
+
+/// Long-term statistics of one voice
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerStats {
+    /// 10th percentile of voiced F0 (Hz)
+    pub f0_low_hz: f32,
+    /// 90th percentile of voiced F0 (Hz)
+    pub f0_high_hz: f32,
+    /// Where sibilant energy concentrates (Hz)
+    pub sibilance_hz: f32,
+    /// Presence (2-5 kHz) to full-band energy ratio during speech (dB)
+    pub spectral_tilt_db: f32,
+    /// Typical speech RMS (dBFS)
+    pub rms_db: f32,
+    /// Amount of speech the statistics were learned from (seconds)
+    pub voiced_sec: f32,
+}
+
+impl Default for SpeakerStats {
+    fn default() -> Self {
+        Self {
+            f0_low_hz: 0.0,
+            f0_high_hz: 0.0,
+            sibilance_hz: 7000.0,
+            spectral_tilt_db: 0.0,
+            rms_db: REFERENCE_SPEECH_RMS_DB,
+            voiced_sec: 0.0,
+        }
+    }
+}
+
+impl SpeakerStats {
+    /// Enough speech has been heard for the statistics to be meaningful
+    pub fn is_usable(&self) -> bool {
+        self.voiced_sec >= MIN_VOICED_SEC
+    }
+
+    /// Geometric center of the voiced F0 range (Hz), 0.0 if unknown
+    pub fn f0_center_hz(&self) -> f32 {
+        if self.f0_low_hz > 0.0 && self.f0_high_hz > 0.0 {
+            (self.f0_low_hz * self.f0_high_hz).sqrt()
+        } else {
+            0.0
+        }
+    }
+
+    /// Processing adjustments for this voice
+    pub fn adaptation(&self) -> SpeakerAdaptation {
+        let f0 = self.f0_center_hz();
+        let proximity_scale = if f0 > 0.0 {
+            let t = ((f0 - PROX_F0_LOW_HZ) / (PROX_F0_HIGH_HZ - PROX_F0_LOW_HZ)).clamp(0.0, 1.0);
+            PROX_SCALE_LOW_F0 + (PROX_SCALE_HIGH_F0 - PROX_SCALE_LOW_F0) * t
+        } else {
+            1.0
+        };
+
+        SpeakerAdaptation {
+            sibilance_hz: Some(self.sibilance_hz),
+            proximity_scale,
+            leveler_target_offset_db: leveler_offset_for_rms(self.rms_db),
+        }
+    }
+}
+
+/// Leveler target offset that centers leveling on a speaker's typical level
+pub fn leveler_offset_for_rms(rms_db: f32) -> f32 {
+    ((rms_db - REFERENCE_SPEECH_RMS_DB) * LEVELER_OFFSET_SHARE)
+        .clamp(-LEVELER_OFFSET_MAX_DB, LEVELER_OFFSET_MAX_DB)
+}
+
+/// Per-speaker processing adjustments
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeakerAdaptation {
+    /// De-esser band center; `None` leaves automatic tracking alone
+    pub sibilance_hz: Option<f32>,
+    /// Multiplier on the resolved proximity amount
+    pub proximity_scale: f32,
+    /// Offset added to the leveler target (dB)
+    pub leveler_target_offset_db: f32,
+}
+
+impl Default for SpeakerAdaptation {
+    fn default() -> Self {
+        Self {
+            sibilance_hz: None,
+            proximity_scale: 1.0,
+            leveler_target_offset_db: 0.0,
+        }
+    }
+}
+
+// =============================================================================
+// Store
+// =============================================================================
+
+/// A named set of speaker statistics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerProfile {
+    pub name: String,
+    pub stats: SpeakerStats,
+}
+
+/// Saved speaker profiles plus the one currently applied
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpeakerProfileStore {
+    pub profiles: Vec<SpeakerProfile>,
+    pub active: Option<usize>,
+}
+
+impl SpeakerProfileStore {
+    /// Save (or overwrite by name) a profile and make it active
+    pub fn save(&mut self, name: &str, stats: SpeakerStats) -> usize {
+        let name = name.trim();
+        let index = match self.profiles.iter().position(|p| p.name == name) {
+            Some(index) => {
+                self.profiles[index].stats = stats;
+                index
+            }
+            None => {
+                self.profiles.push(SpeakerProfile {
+                    name: name.to_string(),
+                    stats,
+                });
+                self.profiles.len() - 1
+            }
+        };
+        self.active = Some(index);
+        index
+    }
+
+    pub fn load(&mut self, index: usize) {
+        if index < self.profiles.len() {
+            self.active = Some(index);
+        }
+    }
+
+    pub fn unload(&mut self) {
+        self.active = None;
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index >= self.profiles.len() {
+            return;
+        }
+        self.profiles.remove(index);
+        self.active = match self.active {
+            Some(a) if a == index => None,
+            Some(a) if a > index => Some(a - 1),
+            other => other,
+        };
+    }
+
+    pub fn active_profile(&self) -> Option<&SpeakerProfile> {
+        self.active.and_then(|i| self.profiles.get(i))
+    }
+
+    pub fn active_stats(&self) -> Option<SpeakerStats> {
+        self.active_profile().map(|p| p.stats)
+    }
+}
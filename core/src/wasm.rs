@@ -0,0 +1,116 @@
+//! WebAssembly Bindings (`wasm` feature)
+//!
+//! `Engine` for JavaScript via wasm-bindgen, so the web page can let users
+//! preview the cleaning chain (denoise, de-verb, leveler, ...) on their own
+//! audio before installing the plugin.
+//!
+//! # Design Notes
+//! - The core crate does no file IO and starts no threads (the FFT plans
+//!   are single-threaded), so it builds for `wasm32-unknown-unknown` as is;
+//!   decoding the upload and playback stay in the page (Web Audio)
+//! - Blocks cross as `Float32Array`s and are copied in and out; process a
+//!   few thousand samples per call to keep the copies cheap
+//! - Parameters use the stable `EngineParam` ids, as in the C API
+//!
+//! Build with wasm-pack into the web page's `pkg` directory:
+//!
+//! ```text
+//! wasm-pack build core --target web --out-dir ../web/pkg -- --features wasm
+//! ```
+
+use crate::engine::{Engine, EngineMeters, EngineParam};
+use wasm_bindgen::prelude::*;
+
+/// Meter readings (see `EngineMeters`)
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct WasmMeters {
+    pub input_peak_db: f32,
+    pub output_peak_db: f32,
+    pub output_rms_db: f32,
+    pub speech_confidence: f32,
+    pub denoise_reduction: f32,
+    pub de_esser_gr_db: f32,
+    pub leveler_gr_db: f32,
+    pub limiter_gr_db: f32,
+}
+
+impl From<EngineMeters> for WasmMeters {
+    fn from(m: EngineMeters) -> Self {
+        Self {
+            input_peak_db: m.input_peak_db,
+            output_peak_db: m.output_peak_db,
+            output_rms_db: m.output_rms_db,
+            speech_confidence: m.speech_confidence,
+            denoise_reduction: m.denoise_reduction,
+            de_esser_gr_db: m.de_esser_gr_db,
+            leveler_gr_db: m.leveler_gr_db,
+            limiter_gr_db: m.limiter_gr_db,
+        }
+    }
+}
+
+/// Cleaning engine for JavaScript (`new VxEngine(sampleRate)`)
+#[wasm_bindgen(js_name = VxEngine)]
+pub struct WasmEngine {
+    engine: Engine,
+}
+
+#[wasm_bindgen(js_class = VxEngine)]
+impl WasmEngine {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: f32) -> WasmEngine {
+        WasmEngine {
+            engine: Engine::new(sample_rate),
+        }
+    }
+
+    /// Set a parameter by id; false for an unknown id
+    #[wasm_bindgen(js_name = setParam)]
+    pub fn set_param(&mut self, id: u32, value: f32) -> bool {
+        match EngineParam::from_id(id) {
+            Some(param) => {
+                self.engine.set_param(param, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Current value of a parameter; NaN for an unknown id
+    #[wasm_bindgen(js_name = getParam)]
+    pub fn param(&self, id: u32) -> f32 {
+        EngineParam::from_id(id).map_or(f32::NAN, |p| self.engine.param(p))
+    }
+
+    /// Display name of a parameter id (empty for an unknown id)
+    #[wasm_bindgen(js_name = paramName)]
+    pub fn param_name(id: u32) -> String {
+        EngineParam::from_id(id).map_or(String::new(), |p| p.name().to_string())
+    }
+
+    #[wasm_bindgen(js_name = latencySamples)]
+    pub fn latency_samples(&self) -> u32 {
+        self.engine.latency_samples() as u32
+    }
+
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+
+    /// Clean a stereo block in place (pass the same data twice for mono)
+    pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+        self.engine.process_block(left, right);
+    }
+
+    /// Clean an interleaved mono or stereo block in place; false for any
+    /// other channel count
+    #[wasm_bindgen(js_name = processInterleaved)]
+    pub fn process_interleaved(&mut self, samples: &mut [f32], channels: u32) -> bool {
+        self.engine.process_interleaved(samples, channels as usize)
+    }
+
+    pub fn meters(&self) -> WasmMeters {
+        self.engine.meters().into()
+    }
+}
@@ -0,0 +1,168 @@
+//! Golden-file regression suite
+//!
+//! Each of the engine's six amount stages (noise reduction, de-verb,
+//! proximity, clarity, de-esser, leveler) on its own, all six together and
+//! the bypassed engine render each fixture in `tests/fixtures` (plus seeded
+//! pink noise) through `null_test::render()`, and the output is nulled
+//! against the stored golden render in `tests/golden`. A residual above
+//! `TOLERANCE_DB` is an audible change and fails the test.
+//!
+//! `Engine` runs the plugin's `VoiceChain`, so every stage is in these
+//! renders; controls the engine does not expose (static noise, hiss and
+//! rumble, Final Output, ...) sit at the plugin's defaults.
+//!
+//! A missing golden file fails the test. To record missing files or accept
+//! an intended change, re-record them all and commit `tests/golden`:
+//!
+//! ```text
+//! VX_BLESS=1 cargo test -p voice-studio-core --test golden
+//! ```
+
+use std::path::{Path, PathBuf};
+use voice_studio_core::dsp::TestSignalKind;
+use voice_studio_core::null_test::{render, residual_db, test_input};
+use voice_studio_core::EngineParam;
+
+/// Largest residual against the golden render (dB relative to it); well
+/// under audibility, well over float differences between platforms
+const TOLERANCE_DB: f32 = -60.0;
+
+/// Stage amount for the single-stage renders
+const AMOUNT: f32 = 0.7;
+
+/// Name and settings of each render
+fn cases() -> Vec<(&'static str, Vec<(EngineParam, f32)>)> {
+    let single = |param: EngineParam| vec![(param, AMOUNT)];
+    vec![
+        ("bypass", Vec::new()),
+        ("noise_reduction", single(EngineParam::NoiseReduction)),
+        ("de_verb", single(EngineParam::DeVerb)),
+        ("proximity", single(EngineParam::Proximity)),
+        ("clarity", single(EngineParam::Clarity)),
+        ("de_esser", single(EngineParam::DeEsser)),
+        ("leveler", single(EngineParam::Leveler)),
+        (
+            "full_chain",
+            vec![
+                (EngineParam::NoiseReduction, 0.6),
+                (EngineParam::DeVerb, 0.4),
+                (EngineParam::Proximity, 0.3),
+                (EngineParam::Clarity, 0.4),
+                (EngineParam::DeEsser, 0.5),
+                (EngineParam::Leveler, 0.6),
+            ],
+        ),
+    ]
+}
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+/// Mono fixture (channels are averaged) and its sample rate
+fn read_fixture(name: &str) -> (Vec<f32>, f32) {
+    let path = tests_dir().join("fixtures").join(name);
+    let mut reader =
+        hound::WavReader::open(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    let spec = reader.spec();
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().map(Result::unwrap).collect(),
+        hound::SampleFormat::Int => {
+            let scale = (1u32 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.unwrap() as f32 / scale)
+                .collect()
+        }
+    };
+    let channels = spec.channels as usize;
+    let mono = interleaved
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect();
+    (mono, spec.sample_rate as f32)
+}
+
+fn read_golden(path: &Path) -> Option<Vec<f32>> {
+    let mut reader = hound::WavReader::open(path).ok()?;
+    Some(reader.samples::<f32>().map(Result::unwrap).collect())
+}
+
+fn write_golden(path: &Path, samples: &[f32], sample_rate: f32) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: sample_rate as u32,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for &s in samples {
+        writer.write_sample(s).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+/// Render every case on `input` and null it against its golden file
+fn check(input_name: &str, input: &[f32], sample_rate: f32) {
+    let bless = std::env::var_os("VX_BLESS").is_some();
+    let mut failures = Vec::new();
+
+    for (case, settings) in cases() {
+        let (left, right) = render(sample_rate, &settings, input, input);
+        // Linked stages: a mono input stays mono
+        assert_eq!(left, right, "{input_name}/{case}: channels differ");
+        assert!(
+            left.iter().all(|v| v.is_finite()),
+            "{input_name}/{case}: non-finite output"
+        );
+
+        let path = tests_dir()
+            .join("golden")
+            .join(format!("{input_name}.{case}.wav"));
+        match read_golden(&path) {
+            Some(golden) if !bless => {
+                let residual = residual_db(&left, &golden);
+                if golden.len() != left.len() || residual > TOLERANCE_DB {
+                    failures.push(format!(
+                        "{case}: residual {residual:.1} dB, {} vs {} samples",
+                        left.len(),
+                        golden.len()
+                    ));
+                }
+            }
+            None if !bless => {
+                failures.push(format!("{case}: no golden file (record with VX_BLESS=1)"))
+            }
+            _ => {
+                write_golden(&path, &left, sample_rate);
+                eprintln!("recorded {}", path.display());
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{input_name} does not match its golden renders (tolerance {TOLERANCE_DB} dB):\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_close_mic_matches_golden() {
+    let (input, sample_rate) = read_fixture("close_mic.wav");
+    check("close_mic", &input, sample_rate);
+}
+
+#[test]
+fn test_noisy_speech_matches_golden() {
+    let (input, sample_rate) = read_fixture("noisy_speech.wav");
+    check("noisy_speech", &input, sample_rate);
+}
+
+#[test]
+fn test_pink_noise_matches_golden() {
+    let sample_rate = 48000.0;
+    let input = test_input(TestSignalKind::PinkNoise, sample_rate, 1.0);
+    check("pink_noise", &input, sample_rate);
+}
@@ -0,0 +1,62 @@
+//! Real-time safety harness
+//!
+//! `AllocDisabler` is this test binary's global allocator, so any allocation
+//! or free inside an `assert_no_alloc` scope aborts the run. Everything the
+//! audio thread calls on the engine runs inside one.
+
+use assert_no_alloc::{assert_no_alloc, AllocDisabler};
+use voice_studio_core::dsp::{TestSignalGenerator, TestSignalKind};
+use voice_studio_core::{Engine, EngineParam};
+
+#[global_allocator]
+static ALLOCATOR: AllocDisabler = AllocDisabler;
+
+const SR: f32 = 48000.0;
+/// Host block sizes, including odd ones that straddle STFT hops
+const BLOCK_SIZES: [usize; 5] = [1, 64, 441, 1024, 4096];
+
+#[test]
+fn test_processing_does_not_allocate() {
+    let mut engine = Engine::new(SR);
+    let mut generator = TestSignalGenerator::new(SR);
+    let mut left = vec![0.0f32; 4096];
+    let mut right = vec![0.0f32; 4096];
+    let mut interleaved = vec![0.0f32; 2 * 4096];
+
+    assert_no_alloc(|| {
+        // Every stage switched fully on, then parameter moves mid-stream
+        for param in EngineParam::ALL {
+            engine.set_param(param, param.range().1);
+        }
+        for (round, kind) in [
+            TestSignalKind::PinkNoise,
+            TestSignalKind::Sweep,
+            TestSignalKind::Tone,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for &size in &BLOCK_SIZES {
+                for _ in 0..(SR as usize / 4) / size {
+                    for (l, r) in left[..size].iter_mut().zip(&mut right[..size]) {
+                        *l = generator.next(kind);
+                        *r = *l;
+                    }
+                    engine.process_block(&mut left[..size], &mut right[..size]);
+
+                    for frame in interleaved[..2 * size].chunks_exact_mut(2) {
+                        frame.fill(generator.next(kind));
+                    }
+                    engine.process_interleaved(&mut interleaved[..2 * size], 2);
+                    engine.process_interleaved(&mut interleaved[..size], 1);
+                    let _ = engine.meters();
+                }
+                engine.set_param(EngineParam::NoiseReduction, 0.25 * round as f32);
+                engine.set_param(EngineParam::LowCut, 0.0);
+            }
+        }
+        engine.reset();
+    });
+
+    assert!(left.iter().all(|v| v.is_finite()));
+}
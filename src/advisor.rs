@@ -0,0 +1,142 @@
+//! Over-Processing Advisor
+//!
+//! Rules over the polled meters that spot settings working against the
+//! voice (a de-esser stuck in heavy reduction, full noise reduction on an
+//! already clean input, ...) and raise a short warning for the editor to
+//! show as a toast.
+//!
+//! # Design Notes
+//! - Runs on the GUI thread from the meter poll; the audio thread only
+//!   publishes the meters it already had
+//! - A rule counts only while someone is talking, and must hold for most of
+//!   the last few seconds before it is raised, so a single loud "s" or a
+//!   burst of limiting never warns; it clears once it has mostly stopped
+//! - Dismissing a warning hides it until its rule clears; if the problem
+//!   comes back later it is raised again
+
+use crate::meters::MeterSnapshot;
+use crate::ui::locale::tr;
+use nih_plug_vizia::vizia::prelude::Data;
+
+/// Time constant of the share of speech a rule has held for (s)
+const HOLD_TAU_SEC: f32 = 3.0;
+/// Share of speech a rule must hold for before it is raised, and the share
+/// it has to fall under to clear
+const RAISE_SHARE: f32 = 0.8;
+const CLEAR_SHARE: f32 = 0.2;
+
+/// Speech confidence above which the rules are evaluated
+const SPEECH_CONFIDENCE: f32 = 0.5;
+
+/// One warning the advisor can raise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum Advice {
+    DeEsserHeavy,
+    NoiseReductionOnCleanInput,
+    LimiterHeavy,
+    SpeechBandLoss,
+    DenoiseArtifacts,
+    DeVerbOnCloseMic,
+}
+
+impl Advice {
+    pub const COUNT: usize = 6;
+
+    pub const ALL: [Advice; Self::COUNT] = [
+        Advice::DeEsserHeavy,
+        Advice::NoiseReductionOnCleanInput,
+        Advice::LimiterHeavy,
+        Advice::SpeechBandLoss,
+        Advice::DenoiseArtifacts,
+        Advice::DeVerbOnCloseMic,
+    ];
+
+    /// Whether the rule holds for this meter frame (engine amounts run 0..2)
+    fn holds(self, m: &MeterSnapshot) -> bool {
+        match self {
+            Advice::DeEsserHeavy => m.debug_deesser_gr_db > 6.0,
+            Advice::NoiseReductionOnCleanInput => {
+                m.noise_reduction_resolved >= 1.8 && m.input_snr_db >= 30.0
+            }
+            Advice::LimiterHeavy => m.debug_limiter_gr_db > 6.0,
+            Advice::SpeechBandLoss => m.speech_protection_active,
+            Advice::DenoiseArtifacts => m.artifact_backoff > 0.25,
+            Advice::DeVerbOnCloseMic => m.deverb_resolved >= 1.2 && m.mic_distance < 0.25,
+        }
+    }
+
+    /// The warning text, translated
+    pub fn message(self) -> String {
+        tr(match self {
+            Advice::DeEsserHeavy => {
+                "De-esser is removing more than 6 dB continuously – the voice may sound lispy. Try lowering De-Esser."
+            }
+            Advice::NoiseReductionOnCleanInput => {
+                "Noise Reduction is near 100% with the input SNR already above 30 dB – consider lowering it."
+            }
+            Advice::LimiterHeavy => {
+                "The limiter is taking off more than 6 dB most of the time – the voice may sound squashed. Turn the input down or relax the Leveler."
+            }
+            Advice::SpeechBandLoss => {
+                "Restoration keeps hitting the speech-band loss budget – the voice may sound thin. Try lowering Noise Reduction or De-Verb."
+            }
+            Advice::DenoiseArtifacts => {
+                "The Artifact Guard keeps backing Noise Reduction off – expect watery or musical noise. Lower Noise Reduction or learn the noise profile."
+            }
+            Advice::DeVerbOnCloseMic => {
+                "De-Verb is high on a close, dry mic – it may thin the voice. Consider lowering it."
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RuleState {
+    /// Smoothed share of speech the rule held for (0.0 - 1.0)
+    held: f32,
+    raised: bool,
+    dismissed: bool,
+}
+
+/// Rule state between meter polls
+#[derive(Debug, Clone, Default)]
+pub struct Advisor {
+    rules: [RuleState; Advice::COUNT],
+}
+
+impl Advisor {
+    /// Evaluate every rule on a meter frame `dt` seconds after the last one
+    pub fn update(&mut self, m: &MeterSnapshot, dt: f32) {
+        if m.debug_speech_confidence < SPEECH_CONFIDENCE {
+            return;
+        }
+        let coeff = 1.0 - (-dt / HOLD_TAU_SEC).exp();
+        for (rule, advice) in self.rules.iter_mut().zip(Advice::ALL) {
+            let target = if advice.holds(m) { 1.0 } else { 0.0 };
+            rule.held += (target - rule.held) * coeff;
+            if !rule.raised && rule.held > RAISE_SHARE {
+                rule.raised = true;
+            } else if rule.raised && rule.held < CLEAR_SHARE {
+                rule.raised = false;
+                rule.dismissed = false;
+            }
+        }
+    }
+
+    /// Hide `advice` until its rule clears
+    pub fn dismiss(&mut self, advice: Advice) {
+        if let Some(i) = Advice::ALL.iter().position(|&a| a == advice) {
+            self.rules[i].dismissed = true;
+        }
+    }
+
+    /// Raised warnings that were not dismissed, in rule order
+    pub fn warnings(&self) -> Vec<Advice> {
+        self.rules
+            .iter()
+            .zip(Advice::ALL)
+            .filter(|(rule, _)| rule.raised && !rule.dismissed)
+            .map(|(_, advice)| advice)
+            .collect()
+    }
+}
@@ -0,0 +1,62 @@
+//! Persisted De-Ess Auto Threshold
+//!
+//! The threshold correction found by the de-esser's Auto button is saved with
+//! the project so a reopened session keeps its calibration. Correction and the
+//! measured 95th-percentile sibilance level are packed in hundredths of a dB
+//! into one atomic word, which the audio thread reads every buffer.
+
+use nih_plug::params::persist::PersistentField;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const VALUE_MASK: u64 = 0xFFFF;
+const P95_SHIFT: u32 = 16;
+const CALIBRATED_BIT: u64 = 1 << 32;
+
+/// `(offset_db, p95_rel_db)` of the last calibration, `None` until Auto has run
+#[derive(Debug, Default)]
+pub struct DeEssThresholdStore(AtomicU64);
+
+impl DeEssThresholdStore {
+    fn pack(value: Option<(f32, f32)>) -> u64 {
+        let centi = |db: f32| (db * 100.0).round() as i16 as u16 as u64;
+        match value {
+            Some((offset_db, p95_rel_db)) => {
+                CALIBRATED_BIT | centi(offset_db) | (centi(p95_rel_db) << P95_SHIFT)
+            }
+            None => 0,
+        }
+    }
+
+    /// Stored `(offset_db, p95_rel_db)`
+    pub fn get(&self) -> Option<(f32, f32)> {
+        let bits = self.0.load(Ordering::Relaxed);
+        if bits & CALIBRATED_BIT == 0 {
+            return None;
+        }
+        let db = |v: u64| (v & VALUE_MASK) as u16 as i16 as f32 * 0.01;
+        Some((db(bits), db(bits >> P95_SHIFT)))
+    }
+
+    /// Threshold correction for the detector (0 dB when not calibrated)
+    pub fn offset_db(&self) -> f32 {
+        self.get().map_or(0.0, |(offset_db, _)| offset_db)
+    }
+
+    pub fn store(&self, offset_db: f32, p95_rel_db: f32) {
+        self.0
+            .store(Self::pack(Some((offset_db, p95_rel_db))), Ordering::Relaxed);
+    }
+}
+
+impl<'a> PersistentField<'a, Option<(f32, f32)>> for DeEssThresholdStore {
+    fn set(&self, new_value: Option<(f32, f32)>) {
+        self.0.store(Self::pack(new_value), Ordering::Relaxed);
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&Option<(f32, f32)>) -> R,
+    {
+        f(&self.get())
+    }
+}
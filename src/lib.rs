@@ -1,219 +1,96 @@
+mod advisor;
+mod de_ess_threshold;
 mod debug;
-pub mod dsp;
 mod macro_controller;
 mod meters;
+mod mic_alignment;
+mod modulation;
+mod noise_floor;
+mod param_groups;
+mod param_locks;
+mod preset_override;
 mod presets;
+mod profile_history;
+#[cfg(feature = "remote")]
+mod remote;
+mod settings_file;
+mod tasks;
+#[cfg(test)]
+mod tests;
+mod track_profiles;
 mod ui;
 mod version;
 
+use crate::de_ess_threshold::DeEssThresholdStore;
+use crate::dsp::control_slew::{
+    DEFAULT_SPEECH_LOSS_BUDGET_DB, SPEECH_LOSS_BUDGET_MAX_DB, SPEECH_LOSS_BUDGET_MIN_DB,
+};
+use crate::dsp::de_esser::DeEssCalibration;
+use crate::dsp::mic_align::{AlignOutcome, Alignment};
+use crate::dsp::noise_learn_remove::NoiseCapture;
+use crate::dsp::utils::{db_to_lin, time_constant_coeff};
 use crate::dsp::{
-    Biquad, BreathReducer, ChannelProcessor, ClarityDetector, DeEsserDetector, DenoiseConfig,
-    EarlyReflectionSuppressor, HissRumble, LinkedCompressor, LinkedLimiter, NoiseLearnRemove,
-    NoiseLearnRemoveConfig, PinkRefBias, PlosiveSoftener, PostNoiseCleanup, ProfileAnalyzer,
-    RecoveryStage, SpectralGuardrails, SpeechConfidenceEstimator, SpeechExpander, SpeechHpf,
-    StereoStreamingDenoiser,
+    Countdown, DryDelay, GuardStage, InputUtilitySettings, Interval, NoiseLearnRemoveConfig,
+    ProfileAnalyzer, Ramp, TestSignalGenerator,
+};
+use crate::macro_controller::{
+    compute_simple_macro_targets_at, lock_targets, AutoMacroAmounts, SimpleMacroTargets,
+    SAFE_LIVE_LIMITS,
+};
+use crate::meters::MeterWriter;
+use crate::mic_alignment::MicAlignmentStore;
+use crate::modulation::ModulationMatrix;
+use crate::noise_floor::NoiseFloorStore;
+use crate::param_locks::ParamLocks;
+use crate::preset_override::PresetOverride;
+use crate::profile_history::{ProfileHistory, ProfileRow};
+use crate::speaker_profile::{SpeakerAdaptation, SpeakerProfileStore, SpeakerStats};
+use crate::tasks::{Handover, SpectralStages, Task, TaskQueue};
+use chain::{
+    ChainAmounts, ChainSettings, ResolvedControls, VoiceChain, CONTROL_BLOCK_SIZE,
+    DEFAULT_SPEECH_BAND_HZ,
 };
-use crate::macro_controller::{compute_simple_macro_targets, SimpleMacroTargets};
-use crate::meters::Meters;
-use assert_no_alloc::permit_alloc;
 use ebur128::{EbuR128, Mode};
 use nih_plug::prelude::*;
-use nih_plug_vizia::vizia::prelude::ContextProxy;
 use nih_plug_vizia::{create_vizia_editor, ViziaState, ViziaTheming};
-use std::sync::atomic::Ordering;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, RwLock};
 use ui::build_ui;
+use voice_studio_core::chain;
+pub use voice_studio_core::dsp;
+pub use voice_studio_core::{AudioProfile, ConditionStats, DetectedConditions, TargetProfile};
 
-const DE_ESS_RMS_TAU_SEC: f32 = 0.050;
 const DEFAULT_SAMPLE_RATE: f32 = 44100.0;
 
-const LOUDNESS_PUMP_DELTA_DB: f32 = 2.0;
-const LIMITER_PUMP_THRESHOLD_DB: f32 = 1.5;
-const PUMP_LOG_COOLDOWN_BUFFERS: u32 = 50;
-
-// =============================================================================
-// TASK 1: CANONICAL DATA STRUCTURES (Data-Driven Calibration)
-// =============================================================================
-
-/// Static target envelope for professional voice-over audio.
-/// These ranges define what "good" sounds like - all DSP decisions
-/// are driven by distance from these targets.
-///
-/// IMPORTANT: This struct is immutable at runtime.
-#[derive(Clone, Copy, Debug)]
-pub struct TargetProfile {
-    // Dynamics targets
-    pub rms_min: f32,
-    pub rms_max: f32,
-    pub crest_factor_db_min: f32,
-    pub crest_factor_db_max: f32,
-    pub rms_variance_max: f32,
-
-    // Noise targets
-    pub noise_floor_min: f32,
-    pub noise_floor_max: f32,
-    pub snr_db_min: f32,
-
-    // Reverb targets
-    pub early_late_ratio_min: f32,
-    pub early_late_ratio_max: f32,
-    pub decay_slope_min: f32,
-    pub decay_slope_max: f32,
-
-    // Frequency balance targets
-    pub presence_ratio_max: f32,
-    pub air_ratio_max: f32,
-    pub hf_variance_max: f32,
-}
-
-impl Default for TargetProfile {
-    fn default() -> Self {
-        Self::PROFESSIONAL_VO
-    }
-}
-
-impl TargetProfile {
-    /// Professional voice-over target envelope (from measured reference recordings)
-    pub const PROFESSIONAL_VO: TargetProfile = TargetProfile {
-        // Dynamics: consistent, moderate level
-        rms_min: 0.045,
-        rms_max: 0.060,
-        crest_factor_db_min: 23.0,
-        crest_factor_db_max: 27.0,
-        rms_variance_max: 0.0015,
-
-        // Noise: clean but natural
-        noise_floor_min: 0.010,
-        noise_floor_max: 0.015,
-        snr_db_min: 10.0,
-
-        // Reverb: present but controlled
-        early_late_ratio_min: 0.50,
-        early_late_ratio_max: 0.70,
-        decay_slope_min: -0.0001,
-        decay_slope_max: 0.0001,
-
-        // Frequency: natural presence, no harshness
-        presence_ratio_max: 0.01,
-        air_ratio_max: 0.005,
-        hf_variance_max: 3e-7,
-    };
-
-    /// Check if a value is within a target range
-    #[inline]
-    pub fn in_range(value: f32, min: f32, max: f32) -> bool {
-        value >= min && value <= max
-    }
-
-    /// Compute distance from target (negative = below, positive = above, 0 = in range)
-    #[inline]
-    pub fn distance_from_range(value: f32, min: f32, max: f32) -> f32 {
-        if value < min {
-            value - min // negative
-        } else if value > max {
-            value - max // positive
-        } else {
-            0.0 // in range
-        }
-    }
-}
-
-/// Audio profile computed from signal analysis.
-/// Used for both InputProfile (pre-DSP) and OutputProfile (post-DSP).
-///
-/// IMPORTANT: InputProfile is computed ONCE at block start, pre-DSP.
-/// OutputProfile is computed ONCE at block end, post-DSP.
-/// No mid-chain re-analysis for control decisions.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct AudioProfile {
-    // Dynamics metrics
-    pub rms: f32,
-    pub peak: f32,
-    pub crest_factor_db: f32,
-    pub rms_variance: f32,
-
-    // Noise metrics
-    pub noise_floor: f32,
-    pub snr_db: f32,
-
-    // Reverb metrics
-    pub early_late_ratio: f32,
-    pub decay_slope: f32,
-
-    // Frequency balance metrics
-    pub presence_ratio: f32,
-    pub air_ratio: f32,
-    pub hf_variance: f32,
-}
-
-impl AudioProfile {
-    /// Check if this profile is fully within target bounds (clean audio detection)
-    pub fn is_within_target(&self, target: &TargetProfile) -> bool {
-        TargetProfile::in_range(self.rms, target.rms_min, target.rms_max)
-            && TargetProfile::in_range(
-                self.crest_factor_db,
-                target.crest_factor_db_min,
-                target.crest_factor_db_max,
-            )
-            && self.rms_variance <= target.rms_variance_max
-            && self.snr_db >= target.snr_db_min
-            && TargetProfile::in_range(
-                self.early_late_ratio,
-                target.early_late_ratio_min,
-                target.early_late_ratio_max,
-            )
-            && TargetProfile::in_range(
-                self.decay_slope,
-                target.decay_slope_min,
-                target.decay_slope_max,
-            )
-            && self.presence_ratio <= target.presence_ratio_max
-            && self.air_ratio <= target.air_ratio_max
-            && self.hf_variance <= target.hf_variance_max
-    }
-}
-
-// =============================================================================
-// TASK 2: CONDITION DETECTION (Hard Rules)
-// =============================================================================
-
-/// Detected audio conditions based on measured metrics.
-/// These are read-only signals used for caps and guards.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct DetectedConditions {
-    /// Whisper: HF variance > 1e-6 AND SNR < 15 dB
-    pub whisper: bool,
-
-    /// Distant mic: Early/Late ratio < 0.05 AND Decay slope < -0.0005
-    pub distant_mic: bool,
-
-    /// Noisy environment: Noise floor > 0.05 AND SNR < 6 dB
-    pub noisy_environment: bool,
-
-    /// Clean/already-good: SNR >= 10 dB AND Early/Late >= 0.4 AND HF variance <= 3e-7
-    pub clean_audio: bool,
-}
-
-impl DetectedConditions {
-    /// Detect conditions from an audio profile using hard threshold rules
-    pub fn detect(profile: &AudioProfile) -> Self {
-        Self {
-            // Whisper detection: breathy HF content with low SNR
-            whisper: profile.hf_variance > 1e-6 && profile.snr_db < 15.0,
-
-            // Distant mic detection: diffuse reverb field
-            distant_mic: profile.early_late_ratio < 0.05 && profile.decay_slope < -0.0005,
-
-            // Noisy environment detection: high noise floor
-            noisy_environment: profile.noise_floor > 0.05 && profile.snr_db < 6.0,
-
-            // Clean audio detection: already professional quality
-            clean_audio: profile.snr_db >= 10.0
-                && profile.early_late_ratio >= 0.4
-                && profile.hf_variance <= 3e-7,
-        }
-    }
-}
+// Headroom under a Final Output true-peak target for the (sample peak) limiter
+const LIMITER_TP_MARGIN_DB: f32 = 0.5;
+const PUMP_LOG_COOLDOWN_MS: f32 = 1000.0;
+// How often a settled denoiser noise floor is copied into the saved state
+const NOISE_FLOOR_SAVE_MS: f32 = 2000.0;
+// DSP preset switch: amounts ramp from the old values over this time
+const PRESET_XFADE_MS: f32 = 100.0;
+// Easy/Advanced mode switch crossfade
+const MACRO_XFADE_MS: f32 = 46.0;
+// After a reset the processed output fades in from dry over this time, while
+// envelopes and noise floors settle
+const SOFT_START_MS: f32 = 400.0;
+// Level meter peak hold time
+const PEAK_HOLD_SEC: f32 = 3.0;
+// Bypass crossfade time constant
+const BYPASS_XFADE_MS: f32 = 10.0;
+// Largest correction a level-matched bypass applies to the dry path
+const BYPASS_MATCH_MAX_DB: f32 = 12.0;
+
+/// Auto macro amounts follow the calibration with this time constant (s)
+const AUTO_MACRO_TAU_SEC: f32 = 3.0;
+/// Input RMS below which the calibration holds (silence says nothing)
+const AUTO_MACRO_MIN_RMS: f32 = 0.003;
+
+/// Largest reported latency: three STFT windows at High quality (static
+/// noise, denoise, de-verb at 4096 points) plus the longest linear-phase low
+/// cut and the soft clipper
+const MAX_LATENCY_SAMPLES: usize =
+    3 * 4096 + dsp::linear_phase::MAX_TAPS / 2 + dsp::soft_clipper::LATENCY;
 
 // -----------------------------------------------------------------------------
 // PARAMETERS
@@ -223,6 +100,18 @@ pub struct VoiceParams {
     #[id = "noise_reduction"]
     pub noise_reduction: FloatParam,
 
+    /// Speech HPF corner ahead of all analysis (default 90 Hz)
+    #[id = "low_cut"]
+    pub low_cut: EnumParam<presets::LowCut>,
+
+    #[id = "low_cut_slope"]
+    pub low_cut_slope: EnumParam<presets::LowCutSlope>,
+
+    /// Run the low cut and safety high-pass as one linear-phase FIR (adds
+    /// the FIR's group delay to the reported latency)
+    #[id = "low_cut_linear_phase"]
+    pub low_cut_linear_phase: BoolParam,
+
     #[id = "rumble_amount"]
     pub rumble_amount: FloatParam,
 
@@ -238,6 +127,14 @@ pub struct VoiceParams {
     #[id = "noise_learn_clear"]
     pub noise_learn_clear: BoolParam,
 
+    /// Keep refining the static noise profile in confirmed pauses
+    #[id = "noise_learn_auto"]
+    pub noise_learn_auto: BoolParam,
+
+    /// Hold the static noise profile (stops auto learning)
+    #[id = "noise_learn_freeze"]
+    pub noise_learn_freeze: BoolParam,
+
     #[id = "post_noise_hf_bias"]
     pub post_noise_hf_bias: BoolParam,
 
@@ -247,27 +144,187 @@ pub struct VoiceParams {
     #[id = "low_end_protect"]
     pub low_end_protect: BoolParam,
 
+    /// Standard (Wiener) or Rescue (spectral gate, last resort) denoising
+    #[id = "denoise_engine"]
+    pub denoise_engine: EnumParam<presets::DenoiseEngine>,
+
+    /// Residual noise tilt: -1 = darker (rumble left), +1 = brighter (hiss left)
+    #[id = "noise_character"]
+    pub noise_character: FloatParam,
+
+    /// Denoiser residual floor: 0 = smoothest, 1 = deepest reduction
+    #[id = "noise_floor_depth"]
+    pub noise_floor_depth: FloatParam,
+
+    /// Seconds of fast noise-floor learning after the denoiser starts fresh
+    #[id = "noise_learn_time"]
+    pub noise_learn_time: FloatParam,
+
+    /// Stop all denoiser noise-floor adaptation (music beds, long non-speech)
+    #[id = "noise_model_freeze"]
+    pub noise_model_freeze: BoolParam,
+
+    /// Denoiser stereo link: 1 = one gain for both channels, 0 = per-channel
+    #[id = "denoise_stereo_link"]
+    pub denoise_stereo_link: FloatParam,
+
+    /// Speech-band loss (dB) allowed before denoise and de-verb are scaled back
+    #[id = "speech_loss_budget"]
+    pub speech_loss_budget: FloatParam,
+
+    /// Lower and upper edge of the band the speech-loss protection measures (Hz)
+    #[id = "speech_band_low"]
+    pub speech_band_low: FloatParam,
+    #[id = "speech_band_high"]
+    pub speech_band_high: FloatParam,
+
+    /// Back off noise reduction when musical-noise artifacts are detected
+    #[id = "artifact_guard"]
+    pub artifact_guard: BoolParam,
+
+    /// Fill denoised pauses with a faint bed matched to the noise profile
+    #[id = "comfort_noise"]
+    pub comfort_noise: BoolParam,
+
+    /// Comfort noise level relative to the original noise floor (dB)
+    #[id = "comfort_noise_level"]
+    pub comfort_noise_level: FloatParam,
+
+    /// Morph the room tone across edits between takes
+    #[id = "room_tone_bridge"]
+    pub room_tone_bridge: BoolParam,
+
+    /// Ease cleanup off and hold the noise models while music plays
+    #[id = "music_guard"]
+    pub music_guard: BoolParam,
+
+    /// Keyboard/impulse ducking in pauses (0 = detect and count only)
+    #[id = "transient_amount"]
+    pub transient_amount: FloatParam,
+
+    /// Smallest level jump that counts as a transient
+    #[id = "transient_sensitivity"]
+    pub transient_sensitivity: FloatParam,
+
     #[id = "reverb_reduction"]
     pub reverb_reduction: FloatParam,
 
+    /// Early-reflection share of De-Verb (boxiness, 3-18 ms)
+    #[id = "deverb_early"]
+    pub deverb_early: FloatParam,
+
+    /// Late-tail share of De-Verb (expander + deverber)
+    #[id = "deverb_tail"]
+    pub deverb_tail: FloatParam,
+
+    /// Deverber tail treatment: 0 = Tight (suppress), 1 = Natural (shape)
+    #[id = "deverb_character"]
+    pub deverb_character: FloatParam,
+
+    /// Pause expander release trim on top of the cadence-driven release
+    /// (0.5 - 2.0, 1.0 = as measured)
+    #[id = "tail_release"]
+    pub tail_release: FloatParam,
+
+    /// Codec repair: high-band synthesis above a detected cutoff + smoothing
+    #[id = "bandwidth_extend"]
+    pub bandwidth_extend: FloatParam,
+
     #[id = "clarity"]
     pub clarity: FloatParam,
 
+    /// Clarity low-mid band center (Hz)
+    #[id = "clarity_freq"]
+    pub clarity_freq: FloatParam,
+
+    /// Clarity low-mid band Q
+    #[id = "clarity_q"]
+    pub clarity_q: FloatParam,
+
+    /// Dynamic 2.5-5 kHz glare reduction for budget condenser mics
+    #[id = "de_harsh"]
+    pub de_harsh: FloatParam,
+
+    /// Presence lift gain (dB, 0 = off), 2-5 kHz peak following speech
+    #[id = "presence_gain"]
+    pub presence_gain: FloatParam,
+
+    /// Presence lift center (Hz)
+    #[id = "presence_freq"]
+    pub presence_freq: FloatParam,
+
     #[id = "proximity"]
     pub proximity: FloatParam,
 
+    /// Scale Proximity by the detected mic distance (more warmth when distant)
+    #[id = "proximity_track_room"]
+    pub proximity_track_room: BoolParam,
+
     #[id = "de_esser"]
     pub de_esser: FloatParam,
 
+    /// Share of sibilant events the Auto threshold places above the threshold
+    #[id = "de_ess_treat"]
+    pub de_ess_treat: FloatParam,
+
+    /// Analyse the next 10 s of program and set the de-ess threshold
+    #[id = "de_ess_auto_trigger"]
+    pub de_ess_auto_trigger: BoolParam,
+
     #[id = "leveler"]
     pub leveler: FloatParam,
 
+    /// Attack/release of the leveler, expander and breath reducer
+    #[id = "dynamics_response"]
+    pub dynamics_response: EnumParam<presets::DynamicsResponse>,
+
+    /// Spectral guardrails: off, normal or strict
+    #[id = "guardrails"]
+    pub guardrails: EnumParam<presets::GuardrailStrength>,
+
+    /// Level the leveler rides the voice towards (dBFS, RMS/peak hybrid)
+    #[id = "leveler_target"]
+    pub leveler_target: FloatParam,
+
+    /// Heavy parallel leveler mixed under the main one (0 = serial chain only)
+    #[id = "density"]
+    pub density: FloatParam,
+
     #[id = "output_gain"]
     pub output_gain: FloatParam,
 
+    /// Slow ±10% gain that keeps the output at the input's loudness
+    #[id = "loudness_comp"]
+    pub loudness_comp: BoolParam,
+
+    /// Static gain after the loudness compensation (dB), applied with it on
+    /// or off
+    #[id = "loudness_comp_trim"]
+    pub loudness_comp_trim: FloatParam,
+
     #[id = "breath_control"]
     pub breath_control: FloatParam,
 
+    /// Reduce breaths, or shape them to `breath_level` (audiobooks)
+    #[id = "breath_mode"]
+    pub breath_mode: EnumParam<presets::BreathMode>,
+
+    /// Level breaths are shaped to in Shape mode (dBFS peak)
+    #[id = "breath_level"]
+    pub breath_level: FloatParam,
+
+    /// Pauses longer than this count as dead air (seconds)
+    #[id = "dead_air_threshold"]
+    pub dead_air_threshold: FloatParam,
+
+    /// Ease long pauses towards `pause_level` (audiobook room tone)
+    #[id = "pause_trim"]
+    pub pause_trim: BoolParam,
+
+    /// Room tone target for trimmed pauses (dBFS RMS)
+    #[id = "pause_level"]
+    pub pause_level: FloatParam,
+
     #[id = "use_ml"]
     pub use_ml: BoolParam,
 
@@ -286,10 +343,54 @@ pub struct VoiceParams {
     #[id = "macro_control"]
     pub macro_control: FloatParam,
 
+    /// Let the calibration set each macro; the dial becomes an offset
+    #[id = "macro_clean_auto"]
+    pub macro_clean_auto: BoolParam,
+
+    #[id = "macro_enhance_auto"]
+    pub macro_enhance_auto: BoolParam,
+
+    #[id = "macro_control_auto"]
+    pub macro_control_auto: BoolParam,
+
+    /// Periodically write the macro-resolved sliders back to the host, so
+    /// automation lanes carry the values Easy Mode actually used
+    #[id = "macro_write_back"]
+    pub macro_write_back: BoolParam,
+
+    // -------------------------------------------------------------------------
+    // MODULATION (source + bipolar depth per target)
+    // -------------------------------------------------------------------------
+    #[id = "mod_clarity_source"]
+    pub mod_clarity_source: EnumParam<presets::ModSource>,
+
+    #[id = "mod_clarity_depth"]
+    pub mod_clarity_depth: FloatParam,
+
+    #[id = "mod_proximity_source"]
+    pub mod_proximity_source: EnumParam<presets::ModSource>,
+
+    #[id = "mod_proximity_depth"]
+    pub mod_proximity_depth: FloatParam,
+
+    #[id = "mod_deess_source"]
+    pub mod_deess_source: EnumParam<presets::ModSource>,
+
+    #[id = "mod_deess_depth"]
+    pub mod_deess_depth: FloatParam,
+
     /// Trigger a full plugin reset (internal buffers and state)
     #[id = "reset_all"]
     pub reset_all: BoolParam,
 
+    /// Clear learned/adaptive analysis state only (parameter values are kept)
+    #[id = "reset_analysis"]
+    pub reset_analysis: BoolParam,
+
+    /// Rebuild the DSP after a caught panic and leave the bypass fallback
+    #[id = "dsp_reinit"]
+    pub dsp_reinit: BoolParam,
+
     // -------------------------------------------------------------------------
     // DSP FACTORY PRESETS
     // -------------------------------------------------------------------------
@@ -301,6 +402,143 @@ pub struct VoiceParams {
     // -------------------------------------------------------------------------
     #[id = "final_output_preset"]
     pub final_output_preset: EnumParam<presets::OutputPreset>,
+
+    /// Dither to a fixed-point word length after the final preset gain
+    #[id = "output_dither"]
+    pub output_dither: EnumParam<presets::DitherMode>,
+
+    /// Stereo, mono fold-down or left channel to both outputs
+    #[id = "output_routing"]
+    pub output_routing: EnumParam<presets::OutputRouting>,
+
+    /// Last stage: transparent limiter, oversampled soft clip, or both
+    #[id = "final_stage"]
+    pub final_stage: EnumParam<presets::FinalStage>,
+
+    /// Limiter release: Transparent, Smooth (dual-stage) or Aggressive
+    #[id = "limiter_character"]
+    pub limiter_character: EnumParam<presets::LimiterCharacter>,
+
+    /// Order of the shaping (Proximity/Clarity) and dynamics
+    /// (De-Esser/Leveler) stages
+    #[id = "chain_order"]
+    pub chain_order: EnumParam<presets::ChainOrder>,
+
+    /// Soft clip drive into the curve (dB)
+    #[id = "clip_drive"]
+    pub clip_drive: FloatParam,
+
+    /// Soft clip ceiling (dBFS); a Final Output true-peak target lowers it
+    #[id = "clip_ceiling"]
+    pub clip_ceiling: FloatParam,
+
+    /// Narrow the stereo image after the limiter when L/R would cancel in mono
+    #[id = "mono_fix"]
+    pub mono_fix: BoolParam,
+
+    /// Streaming: artifact-safe amounts, no resets, learning or latency changes
+    #[id = "safe_live_mode"]
+    pub safe_live_mode: BoolParam,
+
+    /// Hold every adaptive state (noise floors, loudness compensation,
+    /// calibration smoothing) so a section re-renders bit-identically
+    #[id = "freeze_adaptation"]
+    pub freeze_adaptation: BoolParam,
+
+    /// Host bypass: play the latency-aligned dry input
+    #[id = "bypass"]
+    pub bypass: BoolParam,
+
+    /// Bypass plays the dry input at the processed loudness (A/B by quality)
+    #[id = "bypass_level_match"]
+    pub bypass_level_match: BoolParam,
+
+    /// Trim each stage's long-term speech gain back to unity so switching
+    /// stages does not change the loudness
+    #[id = "auto_gain_trim"]
+    pub auto_gain_trim: BoolParam,
+
+    /// Eco / Normal / High: FFT sizes and analysis effort (CPU vs fidelity)
+    #[id = "quality"]
+    pub quality: EnumParam<presets::QualityMode>,
+
+    /// Input level trim between the channels (dB, + = right louder)
+    #[id = "input_balance"]
+    pub input_balance: FloatParam,
+
+    /// Exchange the left and right inputs
+    #[id = "input_swap"]
+    pub input_swap: BoolParam,
+
+    /// Flip the polarity of the left input
+    #[id = "input_invert_l"]
+    pub input_invert_l: BoolParam,
+
+    /// Flip the polarity of the right input
+    #[id = "input_invert_r"]
+    pub input_invert_r: BoolParam,
+
+    /// Feed a built-in test signal into the chain instead of the input
+    #[id = "test_signal"]
+    pub test_signal: EnumParam<presets::TestSignal>,
+
+    /// Apply the stored lav/boom offset and polarity to the input
+    #[id = "mic_align"]
+    pub mic_align: BoolParam,
+
+    /// Measure the L/R offset and polarity over the next few seconds of speech
+    #[id = "mic_align_trigger"]
+    pub mic_align_trigger: BoolParam,
+
+    // -------------------------------------------------------------------------
+    // SPEAKER PROFILES (saved with the project, edited from the GUI)
+    // -------------------------------------------------------------------------
+    /// Track two alternating speakers and switch adaptive settings between them
+    #[id = "dual_speaker"]
+    pub dual_speaker: BoolParam,
+
+    /// Window size and user scale factor
+    #[persist = "editor-state"]
+    pub editor_state: Arc<ViziaState>,
+
+    /// Last editor view (Advanced tab)
+    #[persist = "editor-view"]
+    pub editor_view: Arc<RwLock<ui::state::EditorView>>,
+
+    /// Selected UI theme name
+    #[persist = "ui-theme"]
+    pub ui_theme: Arc<RwLock<String>>,
+
+    /// Selected UI language name
+    #[persist = "ui-language"]
+    pub ui_language: Arc<RwLock<String>>,
+
+    /// The first-run walkthrough was finished or skipped
+    #[persist = "onboarding-seen"]
+    pub onboarding_seen: Arc<AtomicBool>,
+
+    #[persist = "speaker-profiles"]
+    pub speaker_profiles: Arc<RwLock<SpeakerProfileStore>>,
+
+    /// Sliders exempt from macro sync, DSP presets and Reset
+    #[persist = "param-locks"]
+    pub param_locks: Arc<ParamLocks>,
+
+    /// Offset and polarity found by the Align button
+    #[persist = "mic-alignment"]
+    pub mic_alignment: Arc<MicAlignmentStore>,
+
+    /// Threshold correction found by the de-esser's Auto button
+    #[persist = "de-ess-threshold"]
+    pub de_ess_threshold: Arc<DeEssThresholdStore>,
+
+    /// Converged denoiser noise floor, seeded back in on reopen
+    #[persist = "noise-floor"]
+    pub noise_floor: Arc<NoiseFloorStore>,
+
+    /// Track this instance sits on, key into the per-user track profiles
+    #[persist = "track-name"]
+    pub track_name: Arc<RwLock<String>>,
 }
 
 // Helper to format values as "50%" for the DAW display
@@ -313,80 +551,139 @@ fn format_db(v: f32) -> String {
     format!("{:.1} dB", v)
 }
 
+// Parse typed entries ("50", "50 %") back to 0.0-1.0
+fn parse_percent(s: &str) -> Option<f32> {
+    s.trim()
+        .trim_end_matches('%')
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .map(|v| v / 100.0)
+}
+
+// Parse typed gain entries ("-3", "-3 dB")
+fn parse_db(s: &str) -> Option<f32> {
+    let s = s.trim();
+    let s = s
+        .strip_suffix("dB")
+        .or_else(|| s.strip_suffix("db"))
+        .unwrap_or(s);
+    s.trim().parse::<f32>().ok()
+}
+
+// Helper to format durations as "2.0 s"
+fn format_seconds(v: f32) -> String {
+    format!("{:.1} s", v)
+}
+
+// Parse typed durations ("2", "2 s")
+fn parse_seconds(s: &str) -> Option<f32> {
+    s.trim().trim_end_matches('s').trim().parse::<f32>().ok()
+}
+
+// Helper to format frequencies as "250 Hz" / "3.0 kHz"
+fn format_hz(v: f32) -> String {
+    if v >= 1000.0 {
+        format!("{:.1} kHz", v / 1000.0)
+    } else {
+        format!("{:.0} Hz", v)
+    }
+}
+
+// Parse typed frequencies ("250", "250 Hz", "3k", "3 kHz")
+fn parse_hz(s: &str) -> Option<f32> {
+    let s = s.trim().to_ascii_lowercase();
+    let s = s.strip_suffix("hz").unwrap_or(&s).trim();
+    match s.strip_suffix('k') {
+        Some(k) => k.trim().parse::<f32>().ok().map(|v| v * 1000.0),
+        None => s.parse::<f32>().ok(),
+    }
+}
+
+// Helper to format a -1..1 tilt as "Darker 40%" / "Neutral" / "Brighter 40%"
+fn format_character(v: f32) -> String {
+    let pct = (v.abs() * 100.0).round();
+    if pct < 1.0 {
+        "Neutral".to_string()
+    } else if v < 0.0 {
+        format!("Darker {:.0}%", pct)
+    } else {
+        format!("Brighter {:.0}%", pct)
+    }
+}
+
+// Parse tilt entries ("-40", "Darker 40%", "brighter 20", "neutral")
+fn parse_character(s: &str) -> Option<f32> {
+    let s = s.trim().to_ascii_lowercase();
+    if s == "neutral" {
+        return Some(0.0);
+    }
+    if let Some(rest) = s.strip_prefix("darker") {
+        return parse_percent(rest).map(|v| -v.abs());
+    }
+    if let Some(rest) = s.strip_prefix("brighter") {
+        return parse_percent(rest).map(f32::abs);
+    }
+    parse_percent(&s)
+}
+
 // -----------------------------------------------------------------------------
 // PLUGIN STRUCT
 // -----------------------------------------------------------------------------
 struct VoiceStudioPlugin {
     params: Arc<VoiceParams>,
-    editor_state: Arc<ViziaState>,
-    process_l: ChannelProcessor,
-    process_r: ChannelProcessor,
+    /// The signal path; everything around it (macros, presets, meters,
+    /// bypass) is the plugin's
+    chain: VoiceChain,
     sample_rate: f32,
-    ui_proxy: Arc<Mutex<Option<ContextProxy>>>,
     max_supported_block_size: usize,
     current_block_size: usize,
-    prev_speech_conf: f32,
-
-    // Core DSP modules
-    denoiser: StereoStreamingDenoiser,
-    pink_ref_bias: PinkRefBias,
-    clarity_detector: ClarityDetector,
-    linked_de_esser: DeEsserDetector,
-    linked_compressor: LinkedCompressor,
-    linked_limiter: LinkedLimiter,
-
-    // New Easy Mode DSP modules
-    speech_confidence: SpeechConfidenceEstimator,
-    early_reflection_l: EarlyReflectionSuppressor,
-    early_reflection_r: EarlyReflectionSuppressor,
-    speech_expander: SpeechExpander,
-    spectral_guardrails: SpectralGuardrails,
-    hiss_rumble: HissRumble,
-    noise_learn_remove: NoiseLearnRemove,
-    recovery_stage: RecoveryStage,
-    post_noise_cleanup_l: PostNoiseCleanup,
-    post_noise_cleanup_r: PostNoiseCleanup,
-
-    // Hidden hygiene and automatic protection
-    speech_hpf: SpeechHpf,
-    plosive_softener_l: PlosiveSoftener,
-    plosive_softener_r: PlosiveSoftener,
-    breath_reducer_l: BreathReducer,
-    breath_reducer_r: BreathReducer,
-
-    // Speech band energy protection (300Hz - 3kHz)
-    speech_band_pre_l: Biquad,
-    speech_band_pre_r: Biquad,
-    speech_band_post_l: Biquad,
-    speech_band_post_r: Biquad,
-    speech_band_pre_lpf_l: Biquad,
-    speech_band_pre_lpf_r: Biquad,
-    speech_band_post_lpf_l: Biquad,
-    speech_band_post_lpf_r: Biquad,
-
-    // Loudness preservation trackers
-    pre_rms_env: f32,
-    post_rms_env: f32,
-    loudness_comp_gain: f32,
-
-    // Profile analyzers for data-driven calibration
-    // INVARIANT: input_profile_analyzer processes ONLY pre-DSP samples
+    mic_align_trigger_prev: bool,
+    de_ess_auto_trigger_prev: bool,
+
+    /// Session share of each detected input condition
+    condition_stats: ConditionStats,
+
+    // Test signal in place of the input
+    test_signal: TestSignalGenerator,
+    active_test_signal: presets::TestSignal,
+
+    // Bypass crossfade (0.0 = processed, 1.0 = dry)
+    bypass_mix: f32,
+
     // INVARIANT: output_profile_analyzer processes ONLY post-DSP samples
     // INVARIANT: No mid-chain re-measurement feeds control logic
-    input_profile_analyzer: ProfileAnalyzer,
     output_profile_analyzer: ProfileAnalyzer,
+    modulation: ModulationMatrix,
+    /// Sample position in the output analysis cycle (Eco analyses one frame
+    /// in every `output_analysis_stride()`)
+    output_analysis_pos: usize,
+
+    // Quality mode the spectral stages are currently built for
+    active_quality: presets::QualityMode,
+    // Quality of the spectral stages being built on the background thread
+    pending_quality: Option<presets::QualityMode>,
+    // Finished spectral stages from the background thread
+    spectral_handover: Arc<Handover<SpectralStages>>,
 
-    // Spectral control slew limiters (artifact prevention)
-    control_limiters: dsp::SpectralControlLimiters,
+    // Latency last reported to the host (and applied to the dry delay)
+    reported_latency: u32,
+
+    // Adaptation from the loaded speaker profile
+    speaker_adaptation: SpeakerAdaptation,
+    applied_speaker: Option<SpeakerStats>,
 
     // Metering
-    meters: Arc<Meters>,
+    meters: MeterWriter,
     peak_input_l: f32,
     peak_input_r: f32,
     peak_output_l: f32,
     peak_output_r: f32,
-    de_ess_rms_sq_l: f32,
-    de_ess_rms_sq_r: f32,
+    /// Held peaks (in L, in R, out L, out R, dBFS) and how long they are held
+    peak_hold: [f32; 4],
+    peak_hold_age_sec: [f32; 4],
+    /// RMS meter mean squares (in L, in R, out L, out R)
+    meter_rms_sq: [f32; 4],
 
     // Preset manager
     preset_manager: presets::PresetManager,
@@ -398,15 +695,46 @@ struct VoiceStudioPlugin {
     last_output_preset: presets::OutputPreset,
     preset_interleaved_buffer: Vec<f32>,
 
+    // Unprocessed input delayed by the reported latency (aux output)
+    dry_delay: DryDelay,
+    /// A panic was caught in `process()`: play the dry input until reinit
+    dsp_fault: bool,
+    /// Wet fade-in after initialize, reset or Reset Analysis
+    soft_start: Ramp,
+
     // Mode switch crossfade
-    macro_xfade_samples_left: u32,
-    macro_xfade_samples_total: u32,
+    macro_xfade: Ramp,
     macro_xfade_to_macro: bool,
     last_macro_mode: bool,
 
+    // DSP preset switch crossfade (same kind of ramp as the mode switch)
+    preset_xfade: Ramp,
+    /// Amounts captured when the preset changed
+    preset_xfade_from: SimpleMacroTargets,
+    /// Amounts resolved for the previous sub-block
+    last_targets: SimpleMacroTargets,
+    last_dsp_preset: presets::DspPreset,
+    /// Amounts of a host-picked DSP preset the parameters do not show yet
+    preset_override: PresetOverride,
+    /// Calibration-driven macro amounts (smoothed, used by Auto macros)
+    macro_auto: AutoMacroAmounts,
+
     // Pump detection cooldown
-    pump_log_cooldown: u32,
-    prev_loudness_comp_gain: f32,
+    pump_log_cooldown: Countdown,
+    /// Time until the settled noise floor is copied into the project state
+    noise_floor_save_countdown: Countdown,
+    /// Input profile log for the CSV export (shared with the background
+    /// thread)
+    profile_history: Arc<ProfileHistory>,
+    profile_history_interval: Interval,
+    /// Audio processed since the plugin was loaded (s), the log's time axis
+    profile_history_time_s: f64,
+
+    // Remote control server (status + parameter changes)
+    #[cfg(feature = "remote")]
+    remote: Option<remote::RemoteServer>,
+    #[cfg(feature = "remote")]
+    remote_inbox: Arc<remote::RemoteInbox>,
 }
 
 impl Default for VoiceStudioPlugin {
@@ -419,14 +747,23 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                low_cut: EnumParam::new("Low Cut", presets::LowCut::Hz90),
+
+                low_cut_slope: EnumParam::new("Low Cut Slope", presets::LowCutSlope::Db12),
+
+                low_cut_linear_phase: BoolParam::new("Linear-Phase Low Cut", false)
+                    .non_automatable(),
+
                 rumble_amount: FloatParam::new(
                     "Rumble",
                     0.0,
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
                 hiss_amount: FloatParam::new(
@@ -435,6 +772,7 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
                 noise_learn_amount: FloatParam::new(
@@ -443,18 +781,127 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_smoother(SmoothingStyle::Linear(100.0))
-                .with_value_to_string(Arc::new(format_percent)),
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
 
                 noise_learn_trigger: BoolParam::new("Re-learn Noise", false).non_automatable(),
 
                 noise_learn_clear: BoolParam::new("Clear Noise", false).non_automatable(),
 
+                noise_learn_auto: BoolParam::new("Auto Learn Noise", false).non_automatable(),
+
+                noise_learn_freeze: BoolParam::new("Freeze Noise", false),
+
                 post_noise_hf_bias: BoolParam::new("Post Noise HF Bias", true).non_automatable(),
 
                 hidden_tone_fx_bypass: BoolParam::new("Bypass Hidden Tone FX", false)
                     .non_automatable(),
 
                 low_end_protect: BoolParam::new("Low-End Protect", true).non_automatable(),
+                denoise_engine: EnumParam::new("Denoise Engine", presets::DenoiseEngine::Standard)
+                    .non_automatable(),
+
+                artifact_guard: BoolParam::new("Artifact Guard", true).non_automatable(),
+                noise_character: FloatParam::new(
+                    "Noise Character",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -1.0,
+                        max: 1.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_character))
+                .with_string_to_value(Arc::new(parse_character)),
+                noise_floor_depth: FloatParam::new(
+                    "Noise Floor Depth",
+                    0.5,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+                noise_learn_time: FloatParam::new(
+                    "Noise Learning Time",
+                    0.5,
+                    FloatRange::Linear { min: 0.0, max: 5.0 },
+                )
+                .with_value_to_string(Arc::new(format_seconds))
+                .with_string_to_value(Arc::new(parse_seconds)),
+                noise_model_freeze: BoolParam::new("Freeze Noise Model", false),
+                denoise_stereo_link: FloatParam::new(
+                    "Denoise Stereo Link",
+                    1.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+                speech_loss_budget: FloatParam::new(
+                    "Speech Loss Budget",
+                    DEFAULT_SPEECH_LOSS_BUDGET_DB,
+                    FloatRange::Linear {
+                        min: SPEECH_LOSS_BUDGET_MIN_DB,
+                        max: SPEECH_LOSS_BUDGET_MAX_DB,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db))
+                .non_automatable(),
+
+                speech_band_low: FloatParam::new(
+                    "Speech Band Low",
+                    DEFAULT_SPEECH_BAND_HZ.0,
+                    FloatRange::Linear {
+                        min: 80.0,
+                        max: 500.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_hz))
+                .with_string_to_value(Arc::new(parse_hz))
+                .non_automatable(),
+
+                speech_band_high: FloatParam::new(
+                    "Speech Band High",
+                    DEFAULT_SPEECH_BAND_HZ.1,
+                    FloatRange::Linear {
+                        min: 2000.0,
+                        max: 5000.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_hz))
+                .with_string_to_value(Arc::new(parse_hz))
+                .non_automatable(),
+
+                comfort_noise: BoolParam::new("Comfort Noise", false),
+
+                comfort_noise_level: FloatParam::new(
+                    "Comfort Noise Level",
+                    -15.0,
+                    FloatRange::Linear {
+                        min: -24.0,
+                        max: -6.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                room_tone_bridge: BoolParam::new("Room Tone Bridge", false),
+
+                music_guard: BoolParam::new("Music Guard", false),
+
+                transient_amount: FloatParam::new(
+                    "Transient Suppression",
+                    0.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+
+                transient_sensitivity: FloatParam::new(
+                    "Transient Sensitivity",
+                    0.5,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
 
                 reverb_reduction: FloatParam::new(
                     "De-Verb (Room)",
@@ -462,36 +909,168 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
+                .with_smoother(SmoothingStyle::Linear(50.0)),
+
+                deverb_early: FloatParam::new(
+                    "Early Reflections",
+                    1.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
+                .with_smoother(SmoothingStyle::Linear(50.0)),
+
+                deverb_tail: FloatParam::new(
+                    "Room Tail",
+                    1.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                deverb_character: FloatParam::new(
+                    "Tail Character",
+                    0.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+
+                tail_release: FloatParam::new(
+                    "Tail Release",
+                    1.0,
+                    FloatRange::SymmetricalSkewed {
+                        min: 0.5,
+                        max: 2.0,
+                        factor: 1.0,
+                        center: 1.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+
+                bandwidth_extend: FloatParam::new(
+                    "Codec Repair",
+                    0.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+
                 clarity: FloatParam::new("Clarity", 0.0, FloatRange::Linear { min: 0.0, max: 1.0 })
                     .with_value_to_string(Arc::new(format_percent))
+                    .with_string_to_value(Arc::new(parse_percent))
                     .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                clarity_freq: FloatParam::new(
+                    "Clarity Freq",
+                    250.0,
+                    FloatRange::Linear {
+                        min: 120.0,
+                        max: 500.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_hz))
+                .with_string_to_value(Arc::new(parse_hz)),
+
+                clarity_q: FloatParam::new(
+                    "Clarity Q",
+                    0.7,
+                    FloatRange::Linear { min: 0.4, max: 2.0 },
+                )
+                .with_value_to_string(Arc::new(|v| format!("{:.2}", v))),
+
+                de_harsh: FloatParam::new(
+                    "De-Harsh",
+                    0.0,
+                    FloatRange::Linear { min: 0.0, max: 1.0 },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+
+                presence_gain: FloatParam::new(
+                    "Presence",
+                    0.0,
+                    FloatRange::Linear { min: 0.0, max: 6.0 },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                presence_freq: FloatParam::new(
+                    "Presence Freq",
+                    3000.0,
+                    FloatRange::Linear {
+                        min: 2000.0,
+                        max: 5000.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_hz))
+                .with_string_to_value(Arc::new(parse_hz)),
+
                 proximity: FloatParam::new(
                     "Proximity (Closeness)",
                     0.0,
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                proximity_track_room: BoolParam::new("Track Room", false),
+
                 de_esser: FloatParam::new(
                     "De-Esser",
                     0.0,
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                de_ess_treat: FloatParam::new(
+                    "De-Ess Treat",
+                    0.25,
+                    FloatRange::Linear {
+                        min: 0.05,
+                        max: 0.8,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
+                .non_automatable(),
+
+                de_ess_auto_trigger: BoolParam::new("De-Ess Auto", false).non_automatable(),
+
                 leveler: FloatParam::new(
                     "Leveler (Auto Volume)",
                     0.0,
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                dynamics_response: EnumParam::new("Response", presets::DynamicsResponse::Natural),
+
+                guardrails: EnumParam::new("Guardrails", presets::GuardrailStrength::Normal),
+
+                leveler_target: FloatParam::new(
+                    "Leveler Target",
+                    -24.0,
+                    FloatRange::Linear {
+                        min: -36.0,
+                        max: -12.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                density: FloatParam::new("Density", 0.0, FloatRange::Linear { min: 0.0, max: 1.0 })
+                    .with_value_to_string(Arc::new(format_percent))
+                    .with_string_to_value(Arc::new(parse_percent)),
+
                 output_gain: FloatParam::new(
                     "Output Gain",
                     0.0,
@@ -501,15 +1080,63 @@ impl Default for VoiceStudioPlugin {
                     },
                 )
                 .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
 
+                loudness_comp: BoolParam::new("Loudness Comp", true),
+
+                loudness_comp_trim: FloatParam::new(
+                    "Loudness Trim",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -6.0,
+                        max: 6.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
                 breath_control: FloatParam::new(
                     "Breath Control",
                     0.25,
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
+                breath_mode: EnumParam::new("Breath Mode", presets::BreathMode::Reduce),
+                breath_level: FloatParam::new(
+                    "Breath Level",
+                    -40.0,
+                    FloatRange::Linear {
+                        min: -60.0,
+                        max: -20.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                dead_air_threshold: FloatParam::new(
+                    "Dead Air After",
+                    2.0,
+                    FloatRange::Linear {
+                        min: 0.5,
+                        max: 10.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_seconds))
+                .with_string_to_value(Arc::new(parse_seconds)),
+                pause_trim: BoolParam::new("Pause Trim", false),
+                pause_level: FloatParam::new(
+                    "Pause Level",
+                    -60.0,
+                    FloatRange::Linear {
+                        min: -75.0,
+                        max: -40.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
 
                 use_ml: BoolParam::new("Use ML Advisor", true),
 
@@ -521,6 +1148,7 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
                 macro_enhance: FloatParam::new(
                     "Enhance",
@@ -528,6 +1156,7 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
                 macro_control: FloatParam::new(
                     "Control",
@@ -535,76 +1164,178 @@ impl Default for VoiceStudioPlugin {
                     FloatRange::Linear { min: 0.0, max: 1.0 },
                 )
                 .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent))
                 .with_smoother(SmoothingStyle::Linear(50.0)),
+                macro_clean_auto: BoolParam::new("Clean Auto", false),
+                macro_enhance_auto: BoolParam::new("Enhance Auto", false),
+                macro_control_auto: BoolParam::new("Control Auto", false),
+                macro_write_back: BoolParam::new("Write to DAW", false).non_automatable(),
+
+                mod_clarity_source: EnumParam::new(
+                    "Clarity Mod Source",
+                    presets::ModSource::SpeechConfidence,
+                ),
+                mod_clarity_depth: FloatParam::new(
+                    "Clarity Mod Depth",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -1.0,
+                        max: 1.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+                mod_proximity_source: EnumParam::new(
+                    "Proximity Mod Source",
+                    presets::ModSource::Loudness,
+                ),
+                mod_proximity_depth: FloatParam::new(
+                    "Proximity Mod Depth",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -1.0,
+                        max: 1.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
+                mod_deess_source: EnumParam::new("De-Ess Mod Source", presets::ModSource::Loudness),
+                mod_deess_depth: FloatParam::new(
+                    "De-Ess Mod Depth",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -1.0,
+                        max: 1.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_percent))
+                .with_string_to_value(Arc::new(parse_percent)),
 
                 reset_all: BoolParam::new("Reset Plugin", false),
+                reset_analysis: BoolParam::new("Reset Analysis", false).non_automatable(),
+                dsp_reinit: BoolParam::new("Reinitialize DSP", false).non_automatable(),
 
                 dsp_preset: EnumParam::new("DSP Preset", presets::DspPreset::Manual),
 
                 final_output_preset: EnumParam::new("Final Output", presets::OutputPreset::None),
+
+                output_dither: EnumParam::new("Dither", presets::DitherMode::Off),
+
+                output_routing: EnumParam::new("Output Routing", presets::OutputRouting::Stereo),
+
+                final_stage: EnumParam::new("Final Stage", presets::FinalStage::Limiter)
+                    .non_automatable(),
+
+                limiter_character: EnumParam::new(
+                    "Limiter Character",
+                    presets::LimiterCharacter::Transparent,
+                ),
+
+                chain_order: EnumParam::new("Chain Order", presets::ChainOrder::Standard),
+
+                clip_drive: FloatParam::new(
+                    "Clip Drive",
+                    0.0,
+                    FloatRange::Linear {
+                        min: 0.0,
+                        max: 12.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                clip_ceiling: FloatParam::new(
+                    "Clip Ceiling",
+                    -1.0,
+                    FloatRange::Linear {
+                        min: -12.0,
+                        max: 0.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db)),
+
+                mono_fix: BoolParam::new("Mono Fix", false),
+
+                safe_live_mode: BoolParam::new("Safe Live Mode", false),
+
+                freeze_adaptation: BoolParam::new("Freeze Adaptation", false),
+
+                bypass: BoolParam::new("Bypass", false).make_bypass(),
+
+                bypass_level_match: BoolParam::new("Level-Matched Bypass", false).non_automatable(),
+
+                auto_gain_trim: BoolParam::new("Auto Gain Trim", false).non_automatable(),
+
+                quality: EnumParam::new("Quality", presets::QualityMode::Normal).non_automatable(),
+
+                input_balance: FloatParam::new(
+                    "Input Balance",
+                    0.0,
+                    FloatRange::Linear {
+                        min: -12.0,
+                        max: 12.0,
+                    },
+                )
+                .with_value_to_string(Arc::new(format_db))
+                .with_string_to_value(Arc::new(parse_db))
+                .non_automatable(),
+                input_swap: BoolParam::new("Swap L/R", false).non_automatable(),
+                input_invert_l: BoolParam::new("Invert L", false).non_automatable(),
+                input_invert_r: BoolParam::new("Invert R", false).non_automatable(),
+
+                test_signal: EnumParam::new("Test Signal", presets::TestSignal::Off)
+                    .non_automatable(),
+
+                mic_align: BoolParam::new("Mic Align", false).non_automatable(),
+                mic_align_trigger: BoolParam::new("Align Mics", false).non_automatable(),
+
+                dual_speaker: BoolParam::new("Dual Speaker", false).non_automatable(),
+                editor_state: ViziaState::new(|| ui::EDITOR_DEFAULT_SIZE),
+                editor_view: Arc::new(RwLock::new(ui::state::EditorView::default())),
+                ui_theme: Arc::new(RwLock::new(ui::theme::DEFAULT_THEME.to_string())),
+                ui_language: Arc::new(RwLock::new(ui::locale::DEFAULT_LANGUAGE.to_string())),
+                onboarding_seen: Arc::new(AtomicBool::new(false)),
+                speaker_profiles: Arc::new(RwLock::new(SpeakerProfileStore::default())),
+                param_locks: Arc::new(ParamLocks::default()),
+                mic_alignment: Arc::new(MicAlignmentStore::default()),
+                de_ess_threshold: Arc::new(DeEssThresholdStore::default()),
+                noise_floor: Arc::new(NoiseFloorStore::default()),
+                track_name: Arc::new(RwLock::new(String::new())),
             }),
-            editor_state: ViziaState::new(|| (900, 550)),
-            process_l: ChannelProcessor::new(2048, 512, DEFAULT_SAMPLE_RATE),
-            process_r: ChannelProcessor::new(2048, 512, DEFAULT_SAMPLE_RATE),
+            chain: VoiceChain::new(
+                DEFAULT_SAMPLE_RATE,
+                chain::SpectralStages::new(2048, DEFAULT_SAMPLE_RATE),
+            ),
             sample_rate: DEFAULT_SAMPLE_RATE,
-            ui_proxy: Arc::new(Mutex::new(None)),
-
-            // Core DSP modules
-            denoiser: StereoStreamingDenoiser::new(2048, 512, DEFAULT_SAMPLE_RATE),
-            pink_ref_bias: PinkRefBias::new(DEFAULT_SAMPLE_RATE),
-            clarity_detector: ClarityDetector::new(DEFAULT_SAMPLE_RATE),
-            linked_de_esser: DeEsserDetector::new(DEFAULT_SAMPLE_RATE),
-            linked_compressor: LinkedCompressor::new(DEFAULT_SAMPLE_RATE),
-            linked_limiter: LinkedLimiter::new(DEFAULT_SAMPLE_RATE),
-
-            // New Easy Mode DSP modules
-            speech_confidence: SpeechConfidenceEstimator::new(DEFAULT_SAMPLE_RATE),
-            early_reflection_l: EarlyReflectionSuppressor::new(DEFAULT_SAMPLE_RATE),
-            early_reflection_r: EarlyReflectionSuppressor::new(DEFAULT_SAMPLE_RATE),
-            speech_expander: SpeechExpander::new(DEFAULT_SAMPLE_RATE),
-            spectral_guardrails: SpectralGuardrails::new(DEFAULT_SAMPLE_RATE),
-            hiss_rumble: HissRumble::new(DEFAULT_SAMPLE_RATE),
-            noise_learn_remove: NoiseLearnRemove::new(2048, 512, DEFAULT_SAMPLE_RATE),
-            recovery_stage: RecoveryStage::new(DEFAULT_SAMPLE_RATE),
-            post_noise_cleanup_l: PostNoiseCleanup::new(DEFAULT_SAMPLE_RATE),
-            post_noise_cleanup_r: PostNoiseCleanup::new(DEFAULT_SAMPLE_RATE),
-
-            speech_hpf: SpeechHpf::new(DEFAULT_SAMPLE_RATE),
-            plosive_softener_l: PlosiveSoftener::new(DEFAULT_SAMPLE_RATE),
-            plosive_softener_r: PlosiveSoftener::new(DEFAULT_SAMPLE_RATE),
-            breath_reducer_l: BreathReducer::new(DEFAULT_SAMPLE_RATE),
-            breath_reducer_r: BreathReducer::new(DEFAULT_SAMPLE_RATE),
-
-            speech_band_pre_l: Biquad::new(),
-            speech_band_pre_r: Biquad::new(),
-            speech_band_post_l: Biquad::new(),
-            speech_band_post_r: Biquad::new(),
-            speech_band_pre_lpf_l: Biquad::new(),
-            speech_band_pre_lpf_r: Biquad::new(),
-            speech_band_post_lpf_l: Biquad::new(),
-            speech_band_post_lpf_r: Biquad::new(),
-
-            pre_rms_env: 0.0,
-            post_rms_env: 0.0,
-            loudness_comp_gain: 1.0,
-
-            // Profile analyzers for data-driven calibration
-            input_profile_analyzer: ProfileAnalyzer::new(DEFAULT_SAMPLE_RATE),
-            output_profile_analyzer: ProfileAnalyzer::new(DEFAULT_SAMPLE_RATE),
-
-            // Macro controller
+            max_supported_block_size: 0,
+            current_block_size: 0,
+            mic_align_trigger_prev: false,
+            de_ess_auto_trigger_prev: false,
+            condition_stats: ConditionStats::default(),
+            test_signal: TestSignalGenerator::new(DEFAULT_SAMPLE_RATE),
+            active_test_signal: presets::TestSignal::Off,
+            bypass_mix: 0.0,
 
-            // Spectral control slew limiters (artifact prevention)
-            control_limiters: dsp::SpectralControlLimiters::new(),
+            output_profile_analyzer: ProfileAnalyzer::new(DEFAULT_SAMPLE_RATE),
+            modulation: ModulationMatrix::new(),
+            output_analysis_pos: 0,
+            active_quality: presets::QualityMode::Normal,
+            pending_quality: None,
+            spectral_handover: Arc::new(Handover::new()),
+            reported_latency: 0,
+            speaker_adaptation: SpeakerAdaptation::default(),
+            applied_speaker: None,
 
             // Metering
-            meters: Arc::new(Meters::new()),
+            meters: MeterWriter::new(),
             peak_input_l: 0.0,
             peak_input_r: 0.0,
             peak_output_l: 0.0,
             peak_output_r: 0.0,
-            de_ess_rms_sq_l: 0.0,
-            de_ess_rms_sq_r: 0.0,
+            peak_hold: [-80.0; 4],
+            peak_hold_age_sec: [0.0; 4],
+            meter_rms_sq: [0.0; 4],
 
             // Preset manager (lightweight initialization)
             preset_manager: presets::PresetManager::empty(),
@@ -615,15 +1346,31 @@ impl Default for VoiceStudioPlugin {
             last_output_preset: presets::OutputPreset::None,
             preset_interleaved_buffer: Vec::new(),
 
-            macro_xfade_samples_left: 0,
-            macro_xfade_samples_total: 0,
+            dry_delay: DryDelay::new(MAX_LATENCY_SAMPLES),
+            dsp_fault: false,
+            soft_start: Ramp::default(),
+
+            macro_xfade: Ramp::default(),
             macro_xfade_to_macro: false,
+            preset_xfade: Ramp::default(),
+            preset_xfade_from: SimpleMacroTargets::default(),
+            last_targets: SimpleMacroTargets::default(),
+            last_dsp_preset: presets::DspPreset::Manual,
+            preset_override: PresetOverride::default(),
             last_macro_mode: true,
-            pump_log_cooldown: 0,
-            prev_loudness_comp_gain: 1.0,
-            max_supported_block_size: 0,
-            current_block_size: 0,
-            prev_speech_conf: 0.0,
+            macro_auto: AutoMacroAmounts::default(),
+            pump_log_cooldown: Countdown::default(),
+            noise_floor_save_countdown: Countdown::default(),
+            profile_history: Arc::new(ProfileHistory::default()),
+            profile_history_interval: Interval::new(
+                profile_history::RECORD_INTERVAL_MS,
+                DEFAULT_SAMPLE_RATE,
+            ),
+            profile_history_time_s: 0.0,
+            #[cfg(feature = "remote")]
+            remote: None,
+            #[cfg(feature = "remote")]
+            remote_inbox: Arc::new(remote::RemoteInbox::default()),
         }
     }
 }
@@ -635,20 +1382,36 @@ impl Plugin for VoiceStudioPlugin {
     const EMAIL: &'static str = "";
     const VERSION: &'static str = "0.6.5";
 
-    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[AudioIOLayout {
-        main_input_channels: NonZeroU32::new(2),
-        main_output_channels: NonZeroU32::new(2),
-        ..AudioIOLayout::const_default()
-    }];
+    // Plain stereo stays first: it is the default layout and the one every
+    // existing session was saved with
+    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
+        AudioIOLayout {
+            main_input_channels: NonZeroU32::new(2),
+            main_output_channels: NonZeroU32::new(2),
+            ..AudioIOLayout::const_default()
+        },
+        // Stereo plus the latency-aligned dry input on an auxiliary pair
+        AudioIOLayout {
+            main_input_channels: NonZeroU32::new(2),
+            main_output_channels: NonZeroU32::new(2),
+            aux_output_ports: &[new_nonzero_u32(2)],
+            names: PortNames {
+                aux_outputs: &["Dry Reference"],
+                ..PortNames::const_default()
+            },
+            ..AudioIOLayout::const_default()
+        },
+    ];
 
     const MIDI_INPUT: MidiConfig = MidiConfig::None;
     const SAMPLE_ACCURATE_AUTOMATION: bool = true;
 
     type SysExMessage = ();
-    type BackgroundTask = ();
+    type BackgroundTask = Task;
 
     fn params(&self) -> Arc<dyn Params> {
-        self.params.clone()
+        // Same parameters, reported to the host in Clean/Shape/... groups
+        Arc::new(param_groups::GroupedParams(self.params.clone()))
     }
 
     fn initialize(
@@ -662,86 +1425,40 @@ impl Plugin for VoiceStudioPlugin {
         crate::debug::logger::init_logger();
 
         std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            self.sample_rate = buffer_config.sample_rate;
             self.max_supported_block_size = buffer_config.max_buffer_size as usize;
             self.current_block_size = buffer_config.max_buffer_size as usize;
-            self.prev_speech_conf = 0.0;
-            self.process_l = ChannelProcessor::new(2048, 512, self.sample_rate);
-            self.process_r = ChannelProcessor::new(2048, 512, self.sample_rate);
-
-            // Core DSP modules
-            self.denoiser = StereoStreamingDenoiser::new(2048, 512, self.sample_rate);
-
-            self.pink_ref_bias = PinkRefBias::new(self.sample_rate);
-            self.clarity_detector = ClarityDetector::new(self.sample_rate);
-            self.linked_de_esser = DeEsserDetector::new(self.sample_rate);
-            self.linked_compressor = LinkedCompressor::new(self.sample_rate);
-            self.linked_limiter = LinkedLimiter::new(self.sample_rate);
-
-            // New Easy Mode DSP modules
-            self.speech_confidence = SpeechConfidenceEstimator::new(self.sample_rate);
-            self.early_reflection_l = EarlyReflectionSuppressor::new(self.sample_rate);
-            self.early_reflection_r = EarlyReflectionSuppressor::new(self.sample_rate);
-            self.speech_expander = SpeechExpander::new(self.sample_rate);
-            self.spectral_guardrails = SpectralGuardrails::new(self.sample_rate);
-            self.hiss_rumble = HissRumble::new(self.sample_rate);
-            self.noise_learn_remove = NoiseLearnRemove::new(2048, 512, self.sample_rate);
-            self.recovery_stage = RecoveryStage::new(self.sample_rate);
-            self.post_noise_cleanup_l = PostNoiseCleanup::new(self.sample_rate);
-            self.post_noise_cleanup_r = PostNoiseCleanup::new(self.sample_rate);
-
-            self.speech_hpf = SpeechHpf::new(self.sample_rate);
-            self.plosive_softener_l = PlosiveSoftener::new(self.sample_rate);
-            self.plosive_softener_r = PlosiveSoftener::new(self.sample_rate);
-            self.breath_reducer_l = BreathReducer::new(self.sample_rate);
-            self.breath_reducer_r = BreathReducer::new(self.sample_rate);
-
-            // Speech band: 300Hz HPF + 3kHz LPF
-            self.speech_band_pre_l
-                .update_hpf(300.0, 0.5, self.sample_rate);
-            self.speech_band_pre_r
-                .update_hpf(300.0, 0.5, self.sample_rate);
-            self.speech_band_post_l
-                .update_hpf(300.0, 0.5, self.sample_rate);
-            self.speech_band_post_r
-                .update_hpf(300.0, 0.5, self.sample_rate);
-            self.speech_band_pre_lpf_l
-                .update_lpf(3000.0, 0.5, self.sample_rate);
-            self.speech_band_pre_lpf_r
-                .update_lpf(3000.0, 0.5, self.sample_rate);
-            self.speech_band_post_lpf_l
-                .update_lpf(3000.0, 0.5, self.sample_rate);
-            self.speech_band_post_lpf_r
-                .update_lpf(3000.0, 0.5, self.sample_rate);
-
-            self.pre_rms_env = 0.0;
-            self.post_rms_env = 0.0;
-            self.loudness_comp_gain = 1.0;
-
-            // Profile analyzers for data-driven calibration
-            self.input_profile_analyzer = ProfileAnalyzer::new(self.sample_rate);
-            self.output_profile_analyzer = ProfileAnalyzer::new(self.sample_rate);
-
-            self.de_ess_rms_sq_l = 0.0;
-            self.de_ess_rms_sq_r = 0.0;
+            self.prepare(buffer_config.sample_rate);
 
             // Initialize preset manager (non-fatal)
             self.preset_manager = presets::PresetManager::new();
-            self.preset_interleaved_buffer =
-                permit_alloc(|| vec![0.0; self.max_supported_block_size * 2]);
-            self.recreate_loudness_meter();
+            self.preset_interleaved_buffer = vec![0.0; self.max_supported_block_size * 2];
             self.preset_gain_db = 0.0;
             self.preset_gain_lin = 1.0;
             self.last_output_preset = self.params.final_output_preset.value();
 
-            self.macro_xfade_samples_left = 0;
-            self.macro_xfade_samples_total = 0;
+            self.macro_xfade.clear();
             self.macro_xfade_to_macro = self.params.macro_mode.value();
             self.last_macro_mode = self.params.macro_mode.value();
-
-            // Latency: Denoise (1 win) + Deverb (1 win) = 2 windows
-            // Window size is 2048
-            _context.set_latency_samples(2048 * 2);
+            self.preset_xfade.clear();
+            self.last_dsp_preset = self.params.dsp_preset.value();
+            self.preset_override.clear();
+            self.chain
+                .reset_chain_order(self.params.chain_order.value().taps());
+            self.soft_start.start(SOFT_START_MS, self.sample_rate);
+
+            // Latency: static noise, denoise and de-verb windows plus the
+            // linear-phase low cut and soft clipper when they are in use
+            _context.set_latency_samples(self.sync_latency());
+
+            // Remote control runs on its own threads; start it once per instance
+            #[cfg(feature = "remote")]
+            if self.remote.is_none() {
+                self.remote = remote::RemoteServer::start(
+                    self.params.clone(),
+                    self.meters.shared(),
+                    self.remote_inbox.clone(),
+                );
+            }
 
             // Flush any initialization log messages to file
             #[cfg(feature = "debug")]
@@ -752,27 +1469,81 @@ impl Plugin for VoiceStudioPlugin {
         .unwrap_or(false)
     }
 
-    fn editor(&mut self, _async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
+    fn editor(&mut self, async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
         let params = self.params.clone();
-        let meters = self.meters.clone();
-        let ui_proxy = self.ui_proxy.clone();
+        let meters = self.meters.shared();
+        let tasks: TaskQueue = Arc::new(move |task| async_executor.execute_background(task));
+        #[cfg(feature = "remote")]
+        let remote_inbox = self.remote_inbox.clone();
         create_vizia_editor(
-            self.editor_state.clone(),
+            self.params.editor_state.clone(),
             ViziaTheming::default(),
             move |cx, gui_context| {
+                // Remote changes are applied here, on the GUI thread
+                #[cfg(feature = "remote")]
+                {
+                    use nih_plug_vizia::vizia::prelude::TimerAction;
+                    remote_inbox.clear();
+                    let inbox = remote_inbox.clone();
+                    let params = params.clone();
+                    let gui = gui_context.clone();
+                    let timer = cx.add_timer(remote::APPLY_INTERVAL, None, move |_, action| {
+                        if let TimerAction::Tick(_) = action {
+                            inbox.apply(&params, &ParamSetter::new(gui.as_ref()));
+                        }
+                    });
+                    cx.start_timer(timer);
+                }
                 build_ui(
                     cx,
                     params.clone(),
                     meters.clone(),
-                    ui_proxy.clone(),
                     gui_context,
+                    tasks.clone(),
                 );
             },
         )
     }
 
     fn task_executor(&mut self) -> TaskExecutor<Self> {
-        Box::new(move |_| {})
+        let params = self.params.clone();
+        let spectral_handover = self.spectral_handover.clone();
+        let profile_history = self.profile_history.clone();
+        Box::new(move |task| match task {
+            Task::BuildSpectralStages {
+                quality,
+                sample_rate,
+            } => {
+                let stages = SpectralStages::new(quality, sample_rate, &params.noise_floor);
+                // A build the audio thread never picked up is freed here
+                drop(spectral_handover.post(Box::new(stages)));
+            }
+            Task::DropSpectralStages(stages) => drop(stages),
+            Task::CheckVersion(proxy) => {
+                version::run_version_check(proxy, || params.editor_state.is_open())
+            }
+            Task::ExportSettings(state) => ui::layout::export_settings(state),
+            Task::ExportProfileHistory => ui::layout::export_profile_history(&profile_history),
+            Task::SaveTrackProfile {
+                track,
+                state,
+                proxy,
+            } => ui::layout::save_track_profile(state, &track, proxy),
+            // Reads only the editor uses are dropped once it has closed
+            Task::ImportSettings { .. }
+            | Task::RecallTrackProfile { .. }
+            | Task::FindTrackProfile { .. }
+                if !params.editor_state.is_open() => {}
+            Task::ImportSettings { name, proxy } => ui::layout::import_settings(&name, proxy),
+            Task::RecallTrackProfile { track, proxy } => {
+                ui::layout::recall_track_profile(&track, proxy)
+            }
+            Task::FindTrackProfile { track, proxy } => {
+                ui::layout::find_track_profile(&track, proxy)
+            }
+            #[cfg(feature = "debug")]
+            Task::WriteStylesheet { path, open } => ui::layout::write_stylesheet(&path, open),
+        })
     }
 
     fn process(
@@ -781,85 +1552,580 @@ impl Plugin for VoiceStudioPlugin {
         _aux: &mut AuxiliaryBuffers,
         _context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
-        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        // After a caught panic the DSP state cannot be trusted: stay on the
+        // dry fallback until the editor asks for a reinit
+        if self.dsp_fault && !self.reinitialize(_context) {
+            return self.process_fault_bypass(buffer, _aux);
+        }
+
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             self.process_internal(buffer, _aux, _context)
-        }))
-        .unwrap_or(ProcessStatus::Normal)
+        })) {
+            Ok(status) => status,
+            Err(_) => {
+                // This buffer holds processed and unprocessed samples side by
+                // side; drop it and restart the dry path from silence
+                self.dsp_fault = true;
+                for channel in buffer.as_slice() {
+                    channel.fill(0.0);
+                }
+                for bus in _aux.outputs.iter_mut() {
+                    for channel in bus.as_slice() {
+                        channel.fill(0.0);
+                    }
+                }
+                self.dry_delay.reset();
+                self.meters.frame.dsp_fault = true;
+                self.meters.publish();
+                ProcessStatus::Normal
+            }
+        }
     }
 
     fn reset(&mut self) {
         std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            self.denoiser.reset();
-            self.pink_ref_bias.reset();
-            self.process_l.envelope_tracker.reset();
-            self.process_r.envelope_tracker.reset();
-            self.process_l.restoration_chain.deverber.reset();
-            self.process_r.restoration_chain.deverber.reset();
-            self.linked_compressor.reset();
-            self.linked_de_esser.reset();
-            self.linked_limiter.reset();
-            self.speech_confidence.reset();
-            self.early_reflection_l.reset();
-            self.early_reflection_r.reset();
-            self.speech_expander.reset();
-            self.spectral_guardrails.reset();
-            self.hiss_rumble.reset();
-            self.noise_learn_remove.reset();
-            self.recovery_stage.reset();
-            self.post_noise_cleanup_l.reset();
-            self.post_noise_cleanup_r.reset();
-            self.speech_hpf.reset();
-            self.plosive_softener_l.reset();
-            self.plosive_softener_r.reset();
-            self.breath_reducer_l.reset();
-            self.breath_reducer_r.reset();
-            self.input_profile_analyzer.reset();
+            self.chain.reset();
+            self.dry_delay.reset();
             self.output_profile_analyzer.reset();
+            self.output_analysis_pos = 0;
+            self.modulation.reset();
+            self.applied_speaker = None;
             self.meters.reset();
 
             self.preset_gain_db = 0.0;
             self.preset_gain_lin = 1.0;
             self.last_output_preset = self.params.final_output_preset.value();
-            self.macro_xfade_samples_left = 0;
-            self.macro_xfade_samples_total = 0;
+            self.macro_xfade.clear();
             self.macro_xfade_to_macro = self.params.macro_mode.value();
             self.last_macro_mode = self.params.macro_mode.value();
+            self.preset_xfade.clear();
+            self.last_dsp_preset = self.params.dsp_preset.value();
+            self.preset_override.clear();
+            self.chain
+                .reset_chain_order(self.params.chain_order.value().taps());
+            self.soft_start.start(SOFT_START_MS, self.sample_rate);
 
             // Reset local peak trackers
             self.peak_input_l = -80.0;
             self.peak_input_r = -80.0;
             self.peak_output_l = -80.0;
             self.peak_output_r = -80.0;
-            self.pump_log_cooldown = 0;
-            self.prev_loudness_comp_gain = 1.0;
+            self.peak_hold = [-80.0; 4];
+            self.peak_hold_age_sec = [0.0; 4];
+            self.meter_rms_sq = [0.0; 4];
+            self.pump_log_cooldown.clear();
         }))
         .unwrap_or(());
     }
 }
 
 impl VoiceStudioPlugin {
-    fn recreate_loudness_meter(&mut self) {
-        permit_alloc(|| {
-            self.loudness_meter =
-                EbuR128::new(2, self.sample_rate as u32, Mode::I | Mode::TRUE_PEAK).ok();
+    /// Resolve the control values for the next `sub_block_len` samples.
+    ///
+    /// Advances the smoothers of the amount parameters by `sub_block_len`
+    /// steps, so this must be called exactly once per sub-block.
+    fn resolve_controls(
+        &mut self,
+        macro_targets: &SimpleMacroTargets,
+        macro_blend: f32,
+        sub_block_len: usize,
+    ) -> ResolvedControls {
+        let steps = sub_block_len as u32;
+        let advanced_targets = self.preset_override.targets(SimpleMacroTargets {
+            noise_reduction: self.params.noise_reduction.smoothed.next_step(steps),
+            reverb_reduction: self.params.reverb_reduction.smoothed.next_step(steps),
+            proximity: self.params.proximity.smoothed.next_step(steps),
+            clarity: self.params.clarity.smoothed.next_step(steps),
+            de_esser: self.params.de_esser.smoothed.next_step(steps),
+            leveler: self.params.leveler.smoothed.next_step(steps),
+            breath_control: self.params.breath_control.value(),
+            rumble: self.params.rumble_amount.value(),
+            hiss: self.params.hiss_amount.value(),
+        });
+
+        // Locked sliders ignore the macros
+        let macro_targets =
+            lock_targets(macro_targets, &advanced_targets, &self.params.param_locks);
+        let mut targets = advanced_targets.mix(&macro_targets, macro_blend);
+        // A DSP preset switch eases from the amounts in use before it
+        if let Some(t) = self.advance_preset_xfade(sub_block_len) {
+            targets = self.preset_xfade_from.mix(&targets, t);
+        }
+        // Safe Live Mode caps every amount below where artifacts start; the
+        // chain's control slew limiters ease into the cap
+        if self.params.safe_live_mode.value() {
+            targets = targets.limited_to(&SAFE_LIVE_LIMITS);
+        }
+        self.last_targets = targets;
+        // Modulation routes act on top of the macro/slider amount
+        let mod_offsets = self.modulation.offsets(&self.params);
+
+        let amounts = ChainAmounts {
+            noise_reduction: targets.noise_reduction,
+            reverb_reduction: targets.reverb_reduction,
+            proximity: targets.proximity + mod_offsets.proximity,
+            clarity: targets.clarity + mod_offsets.clarity,
+            de_esser: targets.de_esser + mod_offsets.de_esser,
+            leveler: targets.leveler,
+            breath_control: targets.breath_control,
+            rumble: targets.rumble,
+            hiss: targets.hiss,
+            deverb_early: self.params.deverb_early.smoothed.next_step(steps),
+            deverb_tail: self.params.deverb_tail.smoothed.next_step(steps),
+            output_gain: db_to_lin(self.params.output_gain.value()),
+        };
+        self.chain.resolve_controls(&amounts, sub_block_len)
+    }
+
+    /// Advance the macro/advanced crossfade by `block_len` samples and return
+    /// the blend (0.0 = advanced, 1.0 = macro) for that sub-block.
+    fn advance_macro_blend(&mut self, macro_mode: bool, block_len: usize) -> f32 {
+        let Some(t) = self.macro_xfade.advance(block_len) else {
+            return if macro_mode { 1.0 } else { 0.0 };
+        };
+
+        if self.macro_xfade_to_macro {
+            t
+        } else {
+            1.0 - t
+        }
+    }
+
+    /// Advance the DSP preset crossfade by `block_len` samples and return the
+    /// share (0.0 = pre-switch amounts, 1.0 = new amounts), or `None` when no
+    /// switch is in progress.
+    fn advance_preset_xfade(&mut self, block_len: usize) -> Option<f32> {
+        self.preset_xfade.advance(block_len)
+    }
+
+    /// Stage Layer 1 (resolved) and Layer 2 (safeguard) meters.
+    fn store_resolved_meters(&mut self, controls: &ResolvedControls) {
+        let limited = &controls.limited;
+
+        // --- Layer 2: Safeguard Interventions ---
+        self.meters.frame.speech_band_loss_db = controls.speech_loss_db;
+        self.meters.frame.speech_loss_budget_db = self.params.speech_loss_budget.value();
+        self.meters.frame.speech_protection_active = limited.speech_protection_active;
+        self.meters.frame.speech_protection_scale = limited.speech_protection_scale;
+        self.meters.frame.energy_budget_active = limited.energy_budget_active;
+        self.meters.frame.energy_budget_scale = limited.energy_budget_scale;
+
+        // --- Layer 1: Resolved Parameters (Post-Macro, Pre-Safeguard) ---
+        // These are the values the engine *attempts* to apply before any safeguards
+        self.meters.frame.noise_reduction_resolved = controls.raw_noise;
+        self.meters.frame.deverb_resolved = controls.raw_reverb;
+        self.meters.frame.clarity_resolved = controls.raw_clarity;
+        self.meters.frame.deesser_resolved = controls.raw_de_ess;
+        self.meters.frame.proximity_resolved = controls.raw_prox;
+        self.meters.frame.leveler_resolved = controls.level_amt;
+        self.meters.frame.breath_reduction_resolved = controls.breath_amt;
+        self.meters.frame.noise_tone_resolved = controls.denoise_cfg.tone;
+
+        // --- Layer 1b: Applied Parameters (Post-Safeguard) ---
+        self.meters.frame.noise_reduction_applied = controls.noise_amt;
+        self.meters.frame.deverb_applied = controls.reverb_amt;
+        self.meters.frame.clarity_applied = controls.clarity_amt;
+        self.meters.frame.deesser_applied = controls.de_ess_amt;
+        self.meters.frame.proximity_applied = controls.prox_amt;
+    }
+
+    /// Apply (or clear) the adaptation derived from a loaded speaker profile.
+    fn apply_speaker_profile(&mut self, stats: Option<SpeakerStats>) {
+        self.speaker_adaptation = stats.map(|s| s.adaptation()).unwrap_or_default();
+        if let Some(hz) = self.speaker_adaptation.sibilance_hz {
+            self.chain.linked_de_esser.set_center_hz(hz);
+        }
+        self.chain
+            .linked_compressor
+            .set_target_offset_db(self.speaker_adaptation.leveler_target_offset_db);
+        self.applied_speaker = stats;
+    }
+
+    /// Bypass fallback after a caught panic: the latency-aligned dry input on
+    /// the main and dry reference outputs, no DSP at all
+    fn process_fault_bypass(
+        &mut self,
+        buffer: &mut Buffer,
+        aux: &mut AuxiliaryBuffers,
+    ) -> ProcessStatus {
+        let channels = buffer.as_slice();
+        if let [left, right, ..] = channels {
+            let mut dry_out = aux.outputs.get_mut(0).map(|bus| bus.as_slice());
+            for (idx, (l, r)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
+                let (dry_l, dry_r) = self.dry_delay.process(*l, *r);
+                *l = dry_l;
+                *r = dry_r;
+                if let Some([dry_out_l, dry_out_r, ..]) = dry_out.as_deref_mut() {
+                    dry_out_l[idx] = dry_l;
+                    dry_out_r[idx] = dry_r;
+                }
+            }
+        }
+
+        self.meters.frame.dsp_fault = true;
+        self.meters.publish();
+        ProcessStatus::Normal
+    }
+
+    /// Recover while the editor's Reinitialize is set (it stays set until the
+    /// fault clears): every stage is reset in place and fresh STFT stages are
+    /// built on the background thread. Returns `true` once the DSP is back; a
+    /// reset that panics again stays faulted and is retried next buffer.
+    fn reinitialize(&mut self, context: &mut impl ProcessContext<Self>) -> bool {
+        if !self.params.dsp_reinit.value() {
+            return false;
+        }
+        let recovered = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.reset();
+        }));
+        if recovered.is_err() {
+            return false;
+        }
+        self.request_spectral_stages(self.active_quality, context);
+
+        self.dsp_fault = false;
+        self.meters.frame.dsp_fault = false;
+        context.set_latency_samples(self.sync_latency());
+        true
+    }
+
+    /// Clear adaptive analysis state (noise floor models, learned NLR profile,
+    /// loudness compensation, profile analyzers) while leaving parameter values
+    /// and filter state untouched. Used when moving between takes recorded in
+    /// different rooms.
+    fn reset_analysis(&mut self) {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.chain.reset_analysis();
+
+            // The saved noise floor
+            self.params.noise_floor.clear();
+
+            // Output analysis, the input profile log and modulation sources
+            self.output_profile_analyzer.reset();
+            self.output_analysis_pos = 0;
+            self.profile_history.clear();
+            self.modulation.reset();
+
+            // Session condition statistics
+            self.condition_stats.reset();
+
+            // A loaded speaker profile is re-applied on the next buffer
+            self.applied_speaker = None;
+
+            // Auto macro calibration
+            self.macro_auto = AutoMacroAmounts::default();
+
+            // Output preset loudness history
+            if let Some(meter) = self.loudness_meter.as_mut() {
+                meter.reset();
+            }
+            self.preset_gain_db = 0.0;
+            self.preset_gain_lin = 1.0;
+        }))
+        .unwrap_or(());
+    }
+
+    /// Rebuild every rate-dependent stage for `sample_rate`.
+    ///
+    /// Every module is recreated, so filter state, envelopes and adaptive
+    /// estimates start over on each call, even at an unchanged rate. The one
+    /// piece of learned state carried across is the static noise
+    /// fingerprint, resampled onto the new bin grid.
+    fn prepare(&mut self, sample_rate: f32) {
+        let previous_rate = self.sample_rate;
+        self.sample_rate = sample_rate;
+
+        // STFT stages (channel processors, denoiser, static noise, comfort noise)
+        let quality = self.params.quality.value();
+        let stages = SpectralStages::new(quality, sample_rate, &self.params.noise_floor);
+        self.active_quality = stages.quality;
+        self.pending_quality = None;
+        let previous =
+            std::mem::replace(&mut self.chain, VoiceChain::new(sample_rate, stages.stft));
+        self.chain
+            .restore_noise_profile(&previous.noise_learn_remove, previous_rate);
+
+        // Structural settings start as the parameters are; the rest follows
+        // the first `configure()`
+        self.chain.input_utility.set(self.input_utility_settings());
+        self.chain.input_utility.reset();
+        self.chain
+            .set_linear_phase(self.params.low_cut_linear_phase.value());
+        let final_stage = self.params.final_stage.value();
+        self.chain
+            .set_final_stage(final_stage.clips(), final_stage.limits());
+        self.chain.tune_speech_band((
+            self.params.speech_band_low.value(),
+            self.params.speech_band_high.value(),
+        ));
+
+        self.profile_history_interval =
+            Interval::new(profile_history::RECORD_INTERVAL_MS, self.sample_rate);
+        self.test_signal = TestSignalGenerator::new(self.sample_rate);
+        self.output_profile_analyzer = ProfileAnalyzer::new(self.sample_rate);
+        self.output_analysis_pos = 0;
+        self.applied_speaker = None;
+        self.recreate_loudness_meter();
+    }
+
+    /// Swap `stages` in, leaving the replaced ones in `stages`.
+    /// `previous_rate` is the rate the current stages were built for.
+    fn install_spectral_stages(&mut self, stages: &mut SpectralStages, previous_rate: f32) {
+        std::mem::swap(&mut self.active_quality, &mut stages.quality);
+        self.chain
+            .install_spectral_stages(&mut stages.stft, previous_rate);
+    }
+
+    /// Queue a rebuild of the STFT stages for `quality`; the result is
+    /// picked up by `adopt_spectral_stages()`
+    fn request_spectral_stages(
+        &mut self,
+        quality: presets::QualityMode,
+        context: &mut impl ProcessContext<Self>,
+    ) {
+        self.pending_quality = Some(quality);
+        context.execute_background(Task::BuildSpectralStages {
+            quality,
+            sample_rate: self.sample_rate,
         });
     }
 
+    /// Swap in STFT stages finished on the background thread. A build that
+    /// no longer matches the Quality setting (it was changed again while
+    /// building) is sent back unused. Old stages are freed on the background
+    /// thread.
+    fn adopt_spectral_stages(&mut self, context: &mut impl ProcessContext<Self>) {
+        let Some(mut stages) = self.spectral_handover.take() else {
+            return;
+        };
+        if self.pending_quality == Some(stages.quality) {
+            self.pending_quality = None;
+        }
+        if stages.quality != self.params.quality.value() || stages.sample_rate != self.sample_rate {
+            context.execute_background(Task::DropSpectralStages(stages));
+            return;
+        }
+
+        let rate = self.sample_rate;
+        self.install_spectral_stages(&mut stages, rate);
+        context.execute_background(Task::DropSpectralStages(stages));
+    }
+
+    fn input_utility_settings(&self) -> InputUtilitySettings {
+        InputUtilitySettings {
+            balance_db: self.params.input_balance.value(),
+            swap: self.params.input_swap.value(),
+            invert_left: self.params.input_invert_l.value(),
+            invert_right: self.params.input_invert_r.value(),
+        }
+    }
+
+    /// Chain settings for the next buffer from the parameters. Safe Live Mode
+    /// holds the structural ones (low cut mode, final stage) and noise
+    /// learning where they are.
+    fn chain_settings(&self, safe_live: bool) -> ChainSettings {
+        let params = &self.params;
+        let freeze = params.freeze_adaptation.value();
+        let output_gain_db = params.output_gain.value();
+
+        // Limiter ceiling follows the Final Output true-peak target, referred
+        // back through the gain stages that follow the limiter
+        let output_preset = params.final_output_preset.value();
+        let limiter_ceiling_db = if output_preset == presets::OutputPreset::None {
+            None
+        } else {
+            self.preset_manager
+                .get_true_peak_ceiling(output_preset)
+                .map(|tp_db| {
+                    let post_gain_db = output_gain_db
+                        + 20.0 * self.chain.loudness_comp_applied.max(1e-6).log10()
+                        + self.preset_gain_db;
+                    tp_db - LIMITER_TP_MARGIN_DB - post_gain_db
+                })
+        };
+
+        let (linear_phase, soft_clip, limit) = if safe_live {
+            (
+                self.chain.active_linear_phase,
+                self.chain.active_soft_clip,
+                self.chain.active_limit,
+            )
+        } else {
+            let final_stage = params.final_stage.value();
+            (
+                params.low_cut_linear_phase.value(),
+                final_stage.clips(),
+                final_stage.limits(),
+            )
+        };
+
+        let noise_learn_amount = params.noise_learn_amount.value();
+        let guardrails = params.guardrails.value();
+
+        ChainSettings {
+            freeze,
+            input: self.input_utility_settings(),
+            mic_alignment: if params.mic_align.value() {
+                params.mic_alignment.alignment(self.sample_rate)
+            } else {
+                Alignment::default()
+            },
+            low_cut_hz: params.low_cut.value().hz(),
+            low_cut_steep: params.low_cut_slope.value() == presets::LowCutSlope::Db24,
+            linear_phase,
+            noise_learn: NoiseLearnRemoveConfig {
+                enabled: noise_learn_amount > 0.001,
+                amount: noise_learn_amount,
+                learn: params.noise_learn_trigger.value() && !safe_live,
+                clear: params.noise_learn_clear.value() && !safe_live,
+                auto_learn: params.noise_learn_auto.value(),
+                freeze: params.noise_learn_freeze.value(),
+            },
+            noise_character: params.noise_character.value(),
+            low_end_protect: params.low_end_protect.value(),
+            artifact_guard: params.artifact_guard.value(),
+            rescue: params.denoise_engine.value() == presets::DenoiseEngine::Rescue,
+            floor_depth: params.noise_floor_depth.value(),
+            learn_time: params.noise_learn_time.value(),
+            noise_model_freeze: params.noise_model_freeze.value(),
+            stereo_link: params.denoise_stereo_link.value(),
+            coarse_analysis: self.active_quality.coarse_analysis(),
+            masker_peaks: self.active_quality.masker_peaks(),
+            speech_loss_budget_db: params.speech_loss_budget.value(),
+            speech_band_hz: (
+                params.speech_band_low.value(),
+                params.speech_band_high.value(),
+            ),
+            comfort_noise: params.comfort_noise.value(),
+            comfort_noise_db: params.comfort_noise_level.value(),
+            room_tone_bridge: params.room_tone_bridge.value(),
+            music_guard: params.music_guard.value(),
+            transient_amount: params.transient_amount.value(),
+            transient_sensitivity: params.transient_sensitivity.value(),
+            post_noise_hf_bias: params.post_noise_hf_bias.value(),
+            hidden_tone_bypass: params.hidden_tone_fx_bypass.value(),
+            deverb_character: params.deverb_character.value(),
+            tail_release: params.tail_release.value(),
+            codec_repair: params.bandwidth_extend.value(),
+            clarity_freq: params.clarity_freq.value(),
+            clarity_q: params.clarity_q.value(),
+            presence_freq: params.presence_freq.value(),
+            presence_gain_db: params.presence_gain.value(),
+            de_harsh: params.de_harsh.value(),
+            proximity_track_room: params.proximity_track_room.value(),
+            proximity_scale: self.speaker_adaptation.proximity_scale,
+            leveler_target_db: params.leveler_target.value(),
+            speaker_leveler_offset_db: self.speaker_adaptation.leveler_target_offset_db,
+            density: params.density.value(),
+            time_scale: params.dynamics_response.value().time_scale(),
+            breath_shape_db: (params.breath_mode.value() == presets::BreathMode::Shape)
+                .then(|| params.breath_level.value()),
+            de_ess_threshold_offset_db: params.de_ess_threshold.offset_db(),
+            dual_speaker: params.dual_speaker.value(),
+            guardrails: guardrails != presets::GuardrailStrength::Off,
+            guardrails_strictness: guardrails.strictness(),
+            dead_air_sec: params.dead_air_threshold.value(),
+            pause_trim: params.pause_trim.value(),
+            pause_level_db: params.pause_level.value(),
+            chain_taps: params.chain_order.value().taps(),
+            routing: params.output_routing.value().routing(),
+            soft_clip,
+            limit,
+            limiter_ceiling_db,
+            limiter_release: params.limiter_character.value().release(),
+            clip_ceiling_db: params.clip_ceiling.value(),
+            clip_drive_db: params.clip_drive.value(),
+            mono_fix: params.mono_fix.value(),
+            auto_gain_trim: params.auto_gain_trim.value(),
+            loudness_comp: params.loudness_comp.value(),
+            loudness_trim_db: params.loudness_comp_trim.value(),
+            final_gain: if output_preset == presets::OutputPreset::None {
+                1.0
+            } else {
+                self.preset_gain_lin
+            },
+            dither_bits: params.output_dither.value().bits(),
+        }
+    }
+
+    /// Re-align the dry path to the current latency and return it for the
+    /// host report
+    fn sync_latency(&mut self) -> u32 {
+        self.reported_latency = self.chain.latency() as u32;
+        self.dry_delay.set_delay(self.reported_latency as usize);
+        self.reported_latency
+    }
+
+    /// Only called from `prepare()` (initialize); the audio thread resets
+    /// the meter instead
+    fn recreate_loudness_meter(&mut self) {
+        self.loudness_meter = EbuR128::new(
+            2,
+            self.sample_rate as u32,
+            // Short-term (S) feeds the Loudness modulation source, which can
+            // be routed at any time, and the remote status
+            Mode::I | Mode::S | Mode::TRUE_PEAK,
+        )
+        .ok();
+    }
+
     fn process_internal(
         &mut self,
         buffer: &mut Buffer,
         _aux: &mut AuxiliaryBuffers,
         _context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
-        if self.params.reset_all.value() {
+        // Safe Live Mode: nothing that would glitch the stream on air. Resets,
+        // noise learning, mic alignment, de-ess calibration and quality
+        // changes wait until it is switched off.
+        let safe_live = self.params.safe_live_mode.value();
+        // Freeze Adaptation: noise floors, loudness compensation, the
+        // calibration and every detector that steers the chain (music, pump
+        // guard, speakers, sibilance center, Artifact Guard) hold where they
+        // are, so a re-render nulls
+        let freeze = self.params.freeze_adaptation.value();
+
+        if self.params.reset_all.value() && !safe_live {
             self.reset();
         }
 
-        const MAX_GAIN: f32 = 2.0;
+        if self.params.reset_analysis.value() && !safe_live {
+            self.reset_analysis();
+        }
+
+        // Quality change: the STFT stages are built on the background thread
+        // (they allocate) and swapped in once ready; the old ones keep
+        // running meanwhile. The new latency is reported below.
+        let quality = self.params.quality.value();
+        if quality != self.active_quality && self.pending_quality != Some(quality) && !safe_live {
+            self.request_spectral_stages(quality, _context);
+        }
+        if !safe_live {
+            self.adopt_spectral_stages(_context);
+        }
 
-        // Note: DSP preset parameter changes are handled in the UI thread
-        // when the user selects a preset from the dropdown. The preset
-        // selection itself is stored as a parameter for DAW automation.
+        // Speaker profile load/unload from the editor (non-blocking; a busy
+        // lock just defers the change to the next buffer)
+        if let Ok(store) = self.params.speaker_profiles.try_read() {
+            let active = store.active_stats();
+            if active != self.applied_speaker {
+                self.apply_speaker_profile(active);
+            }
+        }
+
+        // DSP preset switch: the preset's amounts run from here on, with the
+        // editor closed too, until the parameters catch up (the editor
+        // mirrors them onto the sliders). A ramp from the amounts in use lets
+        // the many values the preset moves at once glide instead of jumping.
+        self.preset_override.update(&self.params);
+        let dsp_preset = self.params.dsp_preset.value();
+        if dsp_preset != self.last_dsp_preset {
+            self.preset_xfade.start(PRESET_XFADE_MS, self.sample_rate);
+            self.preset_xfade_from = self.last_targets;
+            self.last_dsp_preset = dsp_preset;
+            self.preset_override.start(dsp_preset, &self.params);
+        }
+        self.meters.frame.dsp_preset_pending = self.preset_override.is_pending();
 
         // =====================================================================
         // MACRO MODE HANDLING
@@ -867,8 +2133,7 @@ impl VoiceStudioPlugin {
         let macro_mode = self.params.macro_mode.value();
 
         if macro_mode != self.last_macro_mode {
-            self.macro_xfade_samples_total = (0.046 * self.sample_rate).round().max(1.0) as u32;
-            self.macro_xfade_samples_left = self.macro_xfade_samples_total;
+            self.macro_xfade.start(MACRO_XFADE_MS, self.sample_rate);
             self.macro_xfade_to_macro = macro_mode;
             self.last_macro_mode = macro_mode;
         }
@@ -876,195 +2141,62 @@ impl VoiceStudioPlugin {
         // INVARIANT:
         // Macro mode MUST NOT alter DSP topology. It may only change parameter values.
 
-        // Conditions are updated at end-of-buffer via update_input_profile().
-        // We read the last-known values here for stability guards.
-        let whisper = false;
-        let noisy = false;
-
         // Compute macro targets once per buffer and reuse them.
         let frame_count_est = buffer.samples() as usize;
         self.current_block_size = frame_count_est;
 
-        let macro_targets = compute_simple_macro_targets(&self.params);
-        let advanced_targets = SimpleMacroTargets {
-            noise_reduction: self.params.noise_reduction.value(),
-            reverb_reduction: self.params.reverb_reduction.value(),
-            proximity: self.params.proximity.value(),
-            clarity: self.params.clarity.value(),
-            de_esser: self.params.de_esser.value(),
-            leveler: self.params.leveler.value(),
-            breath_control: self.params.breath_control.value(),
-            rumble: self.params.rumble_amount.value(),
-            hiss: self.params.hiss_amount.value(),
-        };
-
-        let mut macro_blend = if macro_mode { 1.0 } else { 0.0 };
-        if self.macro_xfade_samples_left > 0 {
-            let elapsed = (self.macro_xfade_samples_total - self.macro_xfade_samples_left) as f32;
-            let t = (elapsed / self.macro_xfade_samples_total as f32).clamp(0.0, 1.0);
-            macro_blend = if self.macro_xfade_to_macro {
-                t
-            } else {
-                1.0 - t
-            };
-            self.macro_xfade_samples_left = self
-                .macro_xfade_samples_left
-                .saturating_sub(frame_count_est as u32);
+        let macro_targets =
+            compute_simple_macro_targets_at(&self.params, &self.macro_auto, |kind| {
+                self.preset_override.dial(kind, &self.params)
+            });
+
+        // Test signal: a newly selected signal starts from the top (sweep
+        // from 20 Hz, tone from phase zero)
+        let test_signal = self.params.test_signal.value();
+        if test_signal != self.active_test_signal {
+            self.active_test_signal = test_signal;
+            self.test_signal.reset();
         }
+        let test_kind = test_signal.kind();
 
-        let blend = |a: f32, b: f32| a + (b - a) * macro_blend;
-
-        let raw_noise = (blend(
-            advanced_targets.noise_reduction,
-            macro_targets.noise_reduction,
-        ) * MAX_GAIN)
-            .clamp(0.0, MAX_GAIN);
-
-        let rumble_val = blend(self.params.rumble_amount.value(), macro_targets.rumble);
-        let hiss_val = blend(self.params.hiss_amount.value(), macro_targets.hiss);
-
-        let raw_reverb = (blend(
-            advanced_targets.reverb_reduction,
-            macro_targets.reverb_reduction,
-        ) * MAX_GAIN)
-            .clamp(0.0, 1.0);
-        let raw_prox = (blend(advanced_targets.proximity, macro_targets.proximity) * MAX_GAIN)
-            .clamp(0.0, MAX_GAIN);
-        let raw_de_ess = (blend(advanced_targets.de_esser, macro_targets.de_esser) * MAX_GAIN)
-            .clamp(0.0, MAX_GAIN);
-        let level_amt = (blend(advanced_targets.leveler, macro_targets.leveler) * MAX_GAIN)
-            .clamp(0.0, MAX_GAIN);
-        let raw_clarity = (blend(advanced_targets.clarity, macro_targets.clarity) * MAX_GAIN)
-            .clamp(0.0, MAX_GAIN);
-        let breath_amt = blend(
-            advanced_targets.breath_control,
-            macro_targets.breath_control,
-        )
-        .clamp(0.0, 1.0);
-
-        // Apply spectral control slew limiting (prevents warble/artifacts)
-        let speech_loss_db = 0.0;
-        let limited = self.control_limiters.process(
-            raw_noise,
-            raw_clarity,
-            raw_de_ess,
-            raw_reverb,
-            raw_prox,
-            whisper,
-            noisy,
-            speech_loss_db,
-        );
-
-        // --- Layer 2: Safeguard Interventions ---
-        self.meters
-            .speech_band_loss_db
-            .store(speech_loss_db, Ordering::Relaxed);
-        self.meters.speech_protection_active.store(
-            if limited.speech_protection_active {
-                1
-            } else {
-                0
-            },
-            Ordering::Relaxed,
-        );
-        self.meters
-            .speech_protection_scale
-            .store(limited.speech_protection_scale, Ordering::Relaxed);
-        self.meters.energy_budget_active.store(
-            if limited.energy_budget_active { 1 } else { 0 },
-            Ordering::Relaxed,
-        );
-        self.meters
-            .energy_budget_scale
-            .store(limited.energy_budget_scale, Ordering::Relaxed);
-
-        let mut noise_amt = limited.denoise;
-        let mut clarity_amt = limited.clarity;
-        let de_ess_amt = limited.deesser;
-        let mut reverb_amt = limited.reverb;
-        let prox_amt = limited.proximity;
-
-        // Inter-module safety clamps (DSP stability)
-        // Prevent destructive parameter interactions
-
-        // Rule 1: Reduce clarity when proximity is active (avoid thinning bass-boosted signal)
-        if prox_amt > 0.4 {
-            clarity_amt *= 0.7;
+        // Mic alignment: Align starts a measurement (the stored result is
+        // applied by the chain while Mic Align is on)
+        let align_trigger = self.params.mic_align_trigger.value() && !safe_live;
+        if align_trigger && !self.mic_align_trigger_prev {
+            self.chain.mic_aligner.start_measure();
         }
-
-        // Rule 2: Reduce deverb when proximity or clarity are high (avoid over-processing)
-        if prox_amt > 0.6 || clarity_amt > 0.6 {
-            reverb_amt *= 0.75;
+        self.mic_align_trigger_prev = align_trigger;
+
+        // De-ess auto threshold: Auto starts a calibration (the stored
+        // correction shifts the detector threshold)
+        let de_ess_auto_trigger = self.params.de_ess_auto_trigger.value() && !safe_live;
+        if de_ess_auto_trigger && !self.de_ess_auto_trigger_prev {
+            self.chain
+                .de_ess_calibrator
+                .start(self.params.de_ess_treat.value());
         }
+        self.de_ess_auto_trigger_prev = de_ess_auto_trigger;
 
-        // Rule 3: Reduce denoise when clarity is very high (avoid thinning)
-        if clarity_amt > 0.8 {
-            noise_amt *= 0.85;
-        }
+        let settings = self.chain_settings(safe_live);
+        self.chain.configure(&settings);
 
-        let output_gain_db = self.params.output_gain.value();
-        let output_gain_lin = 10.0f32.powf(output_gain_db / 20.0);
+        // A Quality, low cut mode or final stage change (or a stage dropping
+        // out of the chain) moves the latency: report it and re-align the
+        // dry path
+        if self.chain.latency() as u32 != self.reported_latency {
+            _context.set_latency_samples(self.sync_latency());
+        }
 
-        // --- Layer 1: Resolved Parameters (Post-Macro, Pre-Safeguard) ---
-        // These are the values the engine *attempts* to apply before any safeguards
-        self.meters
-            .noise_reduction_resolved
-            .store(raw_noise, Ordering::Relaxed);
-        self.meters
-            .deverb_resolved
-            .store(raw_reverb, Ordering::Relaxed);
-        self.meters
-            .clarity_resolved
-            .store(raw_clarity, Ordering::Relaxed);
-        self.meters
-            .deesser_resolved
-            .store(raw_de_ess, Ordering::Relaxed);
-        self.meters
-            .proximity_resolved
-            .store(raw_prox, Ordering::Relaxed);
-        self.meters
-            .leveler_resolved
-            .store(level_amt, Ordering::Relaxed);
-        self.meters
-            .breath_reduction_resolved
-            .store(breath_amt, Ordering::Relaxed);
-        // noise_tone_resolved deprecated
-        self.meters
-            .noise_tone_resolved
-            .store(0.0, Ordering::Relaxed);
-
-        // --- NEW: Loudness Compensation Logic ---
-        // Target preservation of pre-processing RMS within ±2 dB (Always on)
-        // Slow smoothing for gain compensation (approx 2 second time constant)
-        let rms_alpha = 1.0 - (-1.0 / (2.0 * self.sample_rate)).exp();
-        // Removed unused energy tracking variables
-
-        let bypass_restoration =
-            self.process_l.bypass_restoration || self.process_r.bypass_restoration;
-        let bypass_shaping = self.process_l.bypass_shaping || self.process_r.bypass_shaping;
-        let bypass_dynamics = self.process_l.bypass_dynamics || self.process_r.bypass_dynamics;
-        let bypass_hidden_tone = self.params.hidden_tone_fx_bypass.value();
-
-        // Proximity contributes to deverb (closer = more deverb = less room sound)
-        use crate::dsp::Proximity;
-
-        // Proximity reduces how much de-verb is needed
-        let prox_reduction = Proximity::get_deverb_contribution(prox_amt);
-        let total_deverb = (reverb_amt - prox_reduction).clamp(0.0, 1.0);
-
-        // Configs
-        let denoise_cfg = DenoiseConfig {
-            amount: noise_amt,
-            sensitivity: (0.2 + 0.8 * noise_amt).clamp(0.2, 1.0),
-            tone: 0.5, // Neutral, cleanup handled by HissRumble
-            sample_rate: self.sample_rate,
-            speech_confidence: 0.5, // Will be updated per-sample with actual sidechain value
-            low_end_protect: self.params.low_end_protect.value(),
-        };
+        let bypass_target = if self.params.bypass.value() { 1.0 } else { 0.0 };
+        let bypass_match = self.params.bypass_level_match.value();
+        let bypass_coeff = time_constant_coeff(BYPASS_XFADE_MS, self.sample_rate);
+        let output_frame_len = self.output_profile_analyzer.frame_size();
+        let output_cycle_len =
+            output_frame_len * self.active_quality.output_analysis_stride() as usize;
 
         // Peak decay rate: 13 dB/sec (typical for DAW meters)
         let decay_per_sample = 13.0 / self.sample_rate;
-        let de_ess_alpha = 1.0 - (-1.0 / (DE_ESS_RMS_TAU_SEC * self.sample_rate)).exp();
+        let meter_rms_alpha = 1.0 - (-1.0 / (meters::RMS_TAU_SEC * self.sample_rate)).exp();
 
         let channels = buffer.as_slice();
         if channels.len() < 2 {
@@ -1080,439 +2212,133 @@ impl VoiceStudioPlugin {
 
         let frame_count = self.current_block_size;
 
-        for idx in 0..frame_count {
-            let input_l = left[idx];
-            let input_r = right[idx];
-
-            let input_db_l = 20.0 * input_l.abs().max(1e-6).log10();
-            let input_db_r = 20.0 * input_r.abs().max(1e-6).log10();
-            self.peak_input_l = self.peak_input_l.max(input_db_l);
-            self.peak_input_r = self.peak_input_r.max(input_db_r);
-
-            // 0a. SPEECH HPF (Hidden hygiene)
-            // Removes subsonic energy before any analysis or processing
-            let (hpf_l, hpf_r) = self.speech_hpf.process(input_l, input_r);
-
-            // 0d. SPEECH CONFIDENCE (sidechain analysis - no audio modification)
-            // Must be computed from HPF, not noise-reduced audio
-            let sidechain = self.speech_confidence.process(hpf_l, hpf_r);
-            let confidence_slope = sidechain.speech_conf - self.prev_speech_conf;
-            self.prev_speech_conf = sidechain.speech_conf;
-
-            // 0x. NOISE LEARN REMOVE (Static Noise)
-            // Independent of speech, works during silence
-            let nlr_cfg = NoiseLearnRemoveConfig {
-                enabled: self.params.noise_learn_amount.value() > 0.001,
-                amount: self.params.noise_learn_amount.value(),
-                learn: self.params.noise_learn_trigger.value(),
-                clear: self.params.noise_learn_clear.value(),
-            };
-            let (nlr_l, nlr_r) = self
-                .noise_learn_remove
-                .process(hpf_l, hpf_r, nlr_cfg, &sidechain);
-
-            // 0b. ENVELOPE TRACKING (Unified Source of Truth)
-            // Tracks dynamics after static noise removal for better expander/gate behavior
-            let env_l = self.process_l.envelope_tracker.process_sample(nlr_l);
-            let env_r = self.process_r.envelope_tracker.process_sample(nlr_r);
-
-            // 0c. INPUT PROFILE ANALYSIS (for data-driven calibration)
-            // INVARIANT: Only pre-restoration samples are analyzed here
-            // INVARIANT: This feeds condition detection and macro calibration
-            // We use HPF signal to capture true noise floor for environment detection
-            self.input_profile_analyzer.process(hpf_l, hpf_r);
-
-            // Apply real hiss/rumble shaping here
-            // Uses NLR output as base
-            let (hr_l, hr_r) = self
-                .hiss_rumble
-                .process(nlr_l, nlr_r, rumble_val, hiss_val, &sidechain);
-
-            // Track pre-processed speech band energy - Removed unused calculation
-
-            // Update pre-processing RMS envelope for loudness compensation
-            let pre_rms = (hpf_l * hpf_l + hpf_r * hpf_r) * 0.5;
-            self.pre_rms_env += (pre_rms - self.pre_rms_env) * rms_alpha;
-
-            // Periodically maintain stability to prevent long-term drift
-            // Call every ~1000 samples to prevent numerical drift over long sessions
-            if idx % 1000 == 0 {
-                self.speech_confidence.maintain_stability();
-            }
-
-            // 1. EARLY REFLECTION SUPPRESSION (before denoise)
-            // This handles short-lag reflections that make recordings sound "distant"
-            let early_reflection_amt = (reverb_amt * 0.5).clamp(0.0, 1.0);
-
-            let (pre_l, pre_r) = if bypass_restoration || early_reflection_amt < 0.001 {
-                (hr_l, hr_r) // Use hiss/rumble processed signal
-            } else {
-                (
-                    self.early_reflection_l
-                        .process(hr_l, early_reflection_amt, &sidechain),
-                    self.early_reflection_r
-                        .process(hr_r, early_reflection_amt, &sidechain),
-                )
-            };
-
-            let early_reflection_suppression = self
-                .early_reflection_l
-                .get_suppression()
-                .max(self.early_reflection_r.get_suppression());
-
-            // 2. SPEECH EXPANDER (after early reflection, before denoise)
-            // Controls pauses and room swell without hard gating
-            let expander_amt = (reverb_amt * 0.6).clamp(0.0, 1.0);
-
-            let (exp_l, exp_r) = if expander_amt < 0.001 {
-                (pre_l, pre_r)
-            } else {
-                self.speech_expander
-                    .process(pre_l, pre_r, expander_amt, &sidechain, &env_l, &env_r)
-            };
-
-            let expander_gr_db = self.speech_expander.get_gain_reduction_db();
-
-            // 3. PINK REFERENCE BIAS (Hidden Spectral Tonal Conditioning)
-            // Gently nudges speech towards -3dB/oct tilt to improve stability.
-            // Gated by speech confidence, bypassed if restoration disabled.
-            let (bias_l, bias_r) = if bypass_restoration || bypass_hidden_tone {
-                (exp_l, exp_r)
-            } else {
-                self.pink_ref_bias.process(
-                    exp_l,
-                    exp_r,
-                    sidechain.speech_conf,
-                    prox_amt,
-                    de_ess_amt,
-                )
-            };
-
-            // A. RESTORATION STAGE (denoise, de-verb)
-            let (s1_l, s1_r) = if bypass_restoration {
-                (bias_l, bias_r)
-            } else {
-                // Update config with per-sample speech confidence
-                let mut cfg = denoise_cfg;
-                cfg.speech_confidence = sidechain.speech_conf;
-                // Denoiser tone is now just 0.5 (neutral) as Hiss/Rumble handles bias
-                cfg.tone = 0.5;
-                self.denoiser.process_sample(bias_l, bias_r, &cfg)
-            };
-
-            let denoiser_reduction = if bypass_restoration {
-                0.0
-            } else {
-                self.denoiser.get_current_reduction()
-            };
-
-            // 4. PLOSIVE SOFTENER (after denoise, before breath)
-            let s1b_l = self.plosive_softener_l.process(s1_l);
-            let s1b_r = self.plosive_softener_r.process(s1_r);
-
-            // 5. BREATH REDUCER (after plosive, before deverb)
-            let s1c_l = self
-                .breath_reducer_l
-                .process(s1b_l, breath_amt, &sidechain, &env_l);
-            let s1c_r = self
-                .breath_reducer_r
-                .process(s1b_r, breath_amt, &sidechain, &env_r);
-
-            let s2_l = if bypass_restoration {
-                s1c_l
-            } else {
-                self.process_l.restoration_chain.safety_hpf.process(s1c_l)
-            };
-            let s2_r = if bypass_restoration {
-                s1c_r
-            } else {
-                self.process_r.restoration_chain.safety_hpf.process(s1c_r)
-            };
-            let s3_l = if bypass_restoration {
-                s2_l
-            } else {
-                self.process_l.restoration_chain.deverber.process_sample(
-                    s2_l,
-                    total_deverb,
-                    self.sample_rate,
-                    sidechain.speech_conf,
-                    clarity_amt,
-                    prox_amt,
-                )
-            };
-            let s3_r = if bypass_restoration {
-                s2_r
-            } else {
-                self.process_r.restoration_chain.deverber.process_sample(
-                    s2_r,
-                    total_deverb,
-                    self.sample_rate,
-                    sidechain.speech_conf,
-                    clarity_amt,
-                    prox_amt,
-                )
-            };
-
-            // B. SHAPING STAGE (proximity, clarity)
-            // Proximity: adds low-end warmth (100-300Hz boost) for close-mic effect
-            // Clarity: reduces low-mid mud (120-380Hz cut) for cleaner sound
-            // These effects are now independent - order is proximity first, then clarity
-            let (s4_l, s4_r) = if bypass_shaping {
-                (s3_l, s3_r)
-            } else {
-                (
-                    self.process_l.shaping_chain.proximity.process(
-                        s3_l,
-                        prox_amt,
-                        sidechain.speech_conf,
-                        clarity_amt,
-                    ),
-                    self.process_r.shaping_chain.proximity.process(
-                        s3_r,
-                        prox_amt,
-                        sidechain.speech_conf,
-                        clarity_amt,
-                    ),
-                )
-            };
-
-            let clarity_drive = if bypass_shaping {
-                0.0
-            } else {
-                self.clarity_detector.analyze(s4_l, s4_r)
-            };
-            let (s5_l, s5_r) = if bypass_shaping {
-                (s4_l, s4_r)
-            } else {
-                (
-                    self.process_l.shaping_chain.clarity.process(
-                        s4_l,
-                        clarity_amt,
-                        sidechain.speech_conf,
-                        clarity_drive,
-                    ),
-                    self.process_r.shaping_chain.clarity.process(
-                        s4_r,
-                        clarity_amt,
-                        sidechain.speech_conf,
-                        clarity_drive,
-                    ),
-                )
-            };
-
-            self.de_ess_rms_sq_l += (s5_l * s5_l - self.de_ess_rms_sq_l) * de_ess_alpha;
-            self.de_ess_rms_sq_r += (s5_r * s5_r - self.de_ess_rms_sq_r) * de_ess_alpha;
+        // Auxiliary "Dry Reference" pair, absent when the host picked the
+        // plain stereo layout
+        let mut dry_out = _aux.outputs.get_mut(0).map(|bus| bus.as_slice());
 
-            // C. DYNAMICS STAGE (de-esser, leveler, limiter)
-            let (s6_l, s6_r) = if bypass_dynamics {
-                (s5_l, s5_r)
-            } else {
-                let de_ess_gain = self
-                    .linked_de_esser
-                    .compute_gain(s5_l, s5_r, de_ess_amt, &env_l, &env_r);
-                let out_l = self
-                    .process_l
-                    .dynamics_chain
-                    .de_esser_band
-                    .apply(s5_l, de_ess_gain);
-                let out_r = self
-                    .process_r
-                    .dynamics_chain
-                    .de_esser_band
-                    .apply(s5_r, de_ess_gain);
-                (out_l, out_r)
-            };
-
-            // Control interaction safeguard: Apply leveler gain with consideration of de-esser and limiter activity
-            // to prevent multiple systems from fighting each other
-            let (s7_l, s7_r) = if bypass_dynamics {
-                (s6_l, s6_r)
-            } else {
-                // Calculate de-esser reduction amount to adjust leveler behavior
-                let de_ess_reduction_db = if de_ess_amt > 0.001 {
-                    let input_power = (s5_l * s5_l + s5_r * s5_r) * 0.5;
-                    let output_power = (s6_l * s6_l + s6_r * s6_r) * 0.5;
-                    if output_power > 0.0 && input_power > 0.0 {
-                        10.0f32 * (output_power / input_power as f32).log10()
-                    } else {
-                        0.0
+        // =====================================================================
+        // CONTROL SUB-BLOCKS
+        // =====================================================================
+        // The host buffer is split into CONTROL_BLOCK_SIZE sub-blocks. Macro
+        // blending, parameter smoothing, slew limiting and loudness compensation
+        // all advance per sub-block, so control behaviour does not depend on the
+        // host buffer size.
+        for block_start in (0..frame_count).step_by(CONTROL_BLOCK_SIZE) {
+            let block_len = CONTROL_BLOCK_SIZE.min(frame_count - block_start);
+            let pump_events_at_start = self.chain.pump_events;
+            let macro_blend = self.advance_macro_blend(macro_mode, block_len);
+            let controls = self.resolve_controls(&macro_targets, macro_blend, block_len);
+            self.store_resolved_meters(&controls);
+
+            for idx in block_start..block_start + block_len {
+                // Test signal replaces the input for the whole chain, the
+                // dry reference and the meters included
+                let (input_l, input_r) = match test_kind {
+                    Some(kind) => {
+                        let x = self.test_signal.next(kind);
+                        (x, x)
                     }
-                } else {
-                    0.0
+                    None => (left[idx], right[idx]),
                 };
 
-                // Get current limiter gain reduction to adjust leveler behavior
-                let limiter_gr_db = self.linked_limiter.get_gain_reduction_db();
-
-                // Adjust leveler behavior based on both de-esser and limiter activity to prevent interaction
-                let mut adjusted_level_amt = level_amt;
-
-                if de_ess_reduction_db < -3.0 {
-                    // Strong de-esser activity
-                    adjusted_level_amt *= 0.7; // Reduce leveler aggression to prevent fight
+                // Dry reference: the untouched input, aligned with the output
+                let (dry_l, dry_r) = self.dry_delay.process(input_l, input_r);
+                if let Some([dry_out_l, dry_out_r, ..]) = dry_out.as_deref_mut() {
+                    dry_out_l[idx] = dry_l;
+                    dry_out_r[idx] = dry_r;
                 }
 
-                if limiter_gr_db > 2.0 {
-                    // Strong limiter activity - reduce leveler aggression to prevent pumping
-                    adjusted_level_amt *= 0.8;
+                let input_db_l = 20.0 * input_l.abs().max(1e-6).log10();
+                let input_db_r = 20.0 * input_r.abs().max(1e-6).log10();
+                self.peak_input_l = self.peak_input_l.max(input_db_l);
+                self.peak_input_r = self.peak_input_r.max(input_db_r);
+                self.meter_rms_sq[0] +=
+                    (input_l * input_l - self.meter_rms_sq[0]) * meter_rms_alpha;
+                self.meter_rms_sq[1] +=
+                    (input_r * input_r - self.meter_rms_sq[1]) * meter_rms_alpha;
+
+                // The chain, up to and including loudness compensation
+                let (comp_out_l, comp_out_r) =
+                    self.chain.process_sample(input_l, input_r, &controls);
+
+                let idx2 = idx * 2;
+                if idx2 + 1 < frame_count * 2 && idx2 + 1 < self.preset_interleaved_buffer.len() {
+                    self.preset_interleaved_buffer[idx2] = comp_out_l;
+                    self.preset_interleaved_buffer[idx2 + 1] = comp_out_r;
                 }
 
-                let leveler_gain = self.linked_compressor.compute_gain(
-                    &env_l,
-                    &env_r,
-                    adjusted_level_amt,
-                    sidechain.speech_conf,
-                    prox_amt,
-                    clarity_amt,
-                );
+                // Final Output preset gain, output safety net and dither
+                let (mut out_l, mut out_r) = self.chain.finish_sample(comp_out_l, comp_out_r);
 
-                // Report pump detection to meters
-                self.meters
-                    .set_compressor_gain_delta_db(self.linked_compressor.get_gain_delta_db());
-                if self.linked_compressor.is_pump_detected() {
-                    self.meters.increment_pump_event();
-                    self.meters
-                        .set_pump_severity_db(self.linked_compressor.get_gain_delta_db());
-
-                    // Log pump event (rate-limited by pump_log_cooldown)
-                    if self.pump_log_cooldown == 0 {
-                        vs_log!(
-                            "[PUMP] delta={:.2}dB leveler_amt={:.2} speech={:.2} comp_gr={:.2}dB",
-                            self.linked_compressor.get_gain_delta_db(),
-                            adjusted_level_amt,
-                            sidechain.speech_conf,
-                            self.linked_compressor.get_gain_reduction_db()
-                        );
-                        self.pump_log_cooldown = 50; // ~1 second at 48kHz/512 buffer
-                    }
+                // H. BYPASS (the chain keeps running so switching back is
+                // seamless; the dry input is already aligned to the latency)
+                self.bypass_mix = bypass_target + bypass_coeff * (self.bypass_mix - bypass_target);
+                // Soft start: the wet path fades in from dry after a reset
+                let dry_mix = match self.soft_start.advance(1) {
+                    Some(t) => 1.0 - (1.0 - self.bypass_mix) * t,
+                    None => self.bypass_mix,
+                };
+                if dry_mix > 1e-4 {
+                    // Level match: the dry path takes the chain's average gain
+                    // (post/pre RMS, loudness compensation and preset gain)
+                    let (pre_rms_env, post_rms_env) =
+                        (self.chain.pre_rms_env, self.chain.post_rms_env);
+                    let dry_gain = if bypass_match && post_rms_env > 1e-8 && pre_rms_env > 1e-8 {
+                        let max_gain = db_to_lin(BYPASS_MATCH_MAX_DB);
+                        ((post_rms_env / pre_rms_env).sqrt()
+                            * self.chain.loudness_comp_applied
+                            * self.preset_gain_lin)
+                            .clamp(1.0 / max_gain, max_gain)
+                    } else {
+                        1.0
+                    };
+                    let m = if dry_mix > 1.0 - 1e-4 { 1.0 } else { dry_mix };
+                    out_l += (dry_l * dry_gain - out_l) * m;
+                    out_r += (dry_r * dry_gain - out_r) * m;
                 }
 
-                (s6_l * leveler_gain, s6_r * leveler_gain)
-            };
-
-            // D. RECOVERY STAGE (speech-gated EQ after all subtractive processing)
-            // Applies presence and air shelving during speech to compensate for losses
-            let integrity_score = self.calculate_integrity_score(
-                sidechain.speech_conf,
-                confidence_slope,
-                denoiser_reduction,
-                early_reflection_suppression,
-                expander_gr_db,
-            );
-
-            let (rec_l, rec_r) = if bypass_hidden_tone {
-                (s7_l, s7_r)
-            } else {
-                self.recovery_stage
-                    .process(s7_l, s7_r, sidechain.speech_conf, integrity_score)
-            };
-
-            // Post-noise cleanup (second-pass, very light)
-            let post_cleanup_amt = (noise_amt * 0.35).clamp(0.0, 1.0);
-            let env_rms = env_l.rms.max(env_r.rms);
-            let env_noise_floor = env_l.noise_floor.max(env_r.noise_floor);
-            let use_hf_bias = self.params.post_noise_hf_bias.value();
-            let (post_l, post_r) = if bypass_dynamics || bypass_hidden_tone {
-                (rec_l, rec_r)
-            } else {
-                (
-                    self.post_noise_cleanup_l.process_sample(
-                        rec_l,
-                        sidechain.speech_conf,
-                        env_rms,
-                        env_noise_floor,
-                        post_cleanup_amt,
-                        use_hf_bias,
-                        true,
-                    ),
-                    self.post_noise_cleanup_r.process_sample(
-                        rec_r,
-                        sidechain.speech_conf,
-                        env_rms,
-                        env_noise_floor,
-                        post_cleanup_amt,
-                        use_hf_bias,
-                        false,
-                    ),
-                )
-            };
-
-            // E. SPECTRAL GUARDRAILS (safety layer before limiter)
-            // Prevents extreme settings from breaking sound
-            // Note: Applied after leveler to ensure gain reduction doesn't exceed limiter threshold
-            let (s7g_l, s7g_r) = if bypass_hidden_tone {
-                (post_l, post_r)
-            } else {
-                self.spectral_guardrails
-                    .process(post_l, post_r, true, sidechain.speech_conf)
-            };
-
-            let (s8_l, s8_r) = if bypass_dynamics {
-                (s7g_l, s7g_r)
-            } else {
-                let limiter_gain = self.linked_limiter.compute_gain(s7g_l, s7g_r);
-                (s7g_l * limiter_gain, s7g_r * limiter_gain)
-            };
-
-            // F. OUTPUT GAIN
-            let s9_l = s8_l * output_gain_lin;
-            let s9_r = s8_r * output_gain_lin;
-
-            // Track post-processed speech band energy - Removed unused calculation
-
-            // Update post-processing RMS envelope
-            let post_rms = (s9_l * s9_l + s9_r * s9_r) * 0.5;
-            self.post_rms_env += (post_rms - self.post_rms_env) * rms_alpha;
-
-            // Apply loudness compensation gain (Always on)
-            let comp_out_l = s9_l * self.loudness_comp_gain;
-            let comp_out_r = s9_r * self.loudness_comp_gain;
+                let output_db_l = 20.0 * out_l.abs().max(1e-6).log10();
+                let output_db_r = 20.0 * out_r.abs().max(1e-6).log10();
+                self.peak_output_l = self.peak_output_l.max(output_db_l);
+                self.peak_output_r = self.peak_output_r.max(output_db_r);
+                self.meter_rms_sq[2] += (out_l * out_l - self.meter_rms_sq[2]) * meter_rms_alpha;
+                self.meter_rms_sq[3] += (out_r * out_r - self.meter_rms_sq[3]) * meter_rms_alpha;
+
+                // OUTPUT PROFILE ANALYSIS (for validation/debugging)
+                // INVARIANT: Only post-DSP samples are analyzed here
+                // INVARIANT: This is NOT used for control decisions
+                if self.output_analysis_pos < output_frame_len {
+                    self.output_profile_analyzer
+                        .process(out_l, out_r, self.chain.prev_speech_conf);
+                }
+                self.output_analysis_pos = (self.output_analysis_pos + 1) % output_cycle_len;
 
-            let idx2 = idx * 2;
-            if idx2 + 1 < frame_count * 2 && idx2 + 1 < self.preset_interleaved_buffer.len() {
-                self.preset_interleaved_buffer[idx2] = comp_out_l;
-                self.preset_interleaved_buffer[idx2 + 1] = comp_out_r;
+                left[idx] = out_l;
+                right[idx] = out_r;
             }
 
-            // F. FINAL OUTPUT PRESETS (loudness normalization and true-peak limiting)
-            let preset = self.params.final_output_preset.value();
-            let (out_l, out_r) = if preset == presets::OutputPreset::None {
-                (comp_out_l, comp_out_r)
-            } else {
-                (
-                    comp_out_l * self.preset_gain_lin,
-                    comp_out_r * self.preset_gain_lin,
-                )
-            };
-
-            let mut out_l = out_l;
-            let mut out_r = out_r;
-            if !out_l.is_finite() || !out_r.is_finite() {
-                out_l = 0.0;
-                out_r = 0.0;
-                self.pre_rms_env = 0.0;
-                self.post_rms_env = 0.0;
-                self.loudness_comp_gain = 1.0;
-            }
-            let abs_peak = out_l.abs().max(out_r.abs());
-            if abs_peak > 4.0 {
-                let scale = 4.0 / abs_peak;
-                out_l *= scale;
-                out_r *= scale;
+            self.chain.end_sub_block(block_len);
+
+            // Report pump detection to meters, and log it (rate-limited by
+            // pump_log_cooldown)
+            self.meters.frame.compressor_gain_delta_db =
+                self.chain.linked_compressor.get_gain_delta_db();
+            if self.chain.pump_events != pump_events_at_start {
+                let pump = self.chain.last_pump;
+                self.meters.frame.pump_event_count = self.chain.pump_events;
+                self.meters.frame.pump_severity_db = pump.delta_db;
+                if !self.pump_log_cooldown.is_running() {
+                    vs_log!(
+                        "[PUMP] delta={:.2}dB leveler_amt={:.2} speech={:.2} comp_gr={:.2}dB",
+                        pump.delta_db,
+                        pump.leveler_amount,
+                        pump.speech_confidence,
+                        pump.gain_reduction_db
+                    );
+                    self.pump_log_cooldown
+                        .start(PUMP_LOG_COOLDOWN_MS, self.sample_rate);
+                }
             }
-
-            let output_db_l = 20.0 * out_l.abs().max(1e-6).log10();
-            let output_db_r = 20.0 * out_r.abs().max(1e-6).log10();
-            self.peak_output_l = self.peak_output_l.max(output_db_l);
-            self.peak_output_r = self.peak_output_r.max(output_db_r);
-
-            // OUTPUT PROFILE ANALYSIS (for validation/debugging)
-            // INVARIANT: Only post-DSP samples are analyzed here
-            // INVARIANT: This is NOT used for control decisions
-            self.output_profile_analyzer.process(out_l, out_r);
-
-            left[idx] = out_l;
-            right[idx] = out_r;
         }
 
         // =====================================================================
@@ -1533,6 +2359,18 @@ impl VoiceStudioPlugin {
             {
                 let _ = meter.add_frames_f32(&self.preset_interleaved_buffer[..needed]);
             }
+            // Short-term loudness only has readers in the remote status and
+            // the Loudness modulation source
+            if cfg!(feature = "remote")
+                || ModulationMatrix::uses_source(&self.params, presets::ModSource::Loudness)
+            {
+                let lufs_short = meter
+                    .loudness_shortterm()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .map_or(-70.0, |v| (v as f32).max(-70.0));
+                self.meters.frame.output_lufs_short = lufs_short;
+            }
         }
 
         if preset != presets::OutputPreset::None {
@@ -1585,12 +2423,79 @@ impl VoiceStudioPlugin {
         // INVARIANT: Only InputProfile feeds control logic
         // INVARIANT: All condition flags derived from InputProfile only
 
-        // Finalize input profile analysis
-        self.input_profile_analyzer.finalize_frame();
-        let input_profile = self.input_profile_analyzer.get_profile();
+        // Latest complete input frame (frames are a fixed 50 ms, so buffer
+        // size does not change the analysis)
+        let input_profile = self.chain.input_profile_analyzer.get_profile();
+
+        // Input profile log, about ten rows a second
+        self.profile_history_time_s += frame_count_est as f64 / self.sample_rate as f64;
+        if self.profile_history_interval.advance(frame_count_est) {
+            self.profile_history.record(ProfileRow {
+                time_s: self.profile_history_time_s,
+                speech_confidence: self.chain.prev_speech_conf,
+                profile: input_profile,
+            });
+        }
+
+        // Room decay, mic distance, hiss corners, speaker tracking, leveler
+        // adaptation and speech-band loss
+        let pump_trigger = self.chain.end_block(frame_count);
+        self.meters.frame.mic_distance = self.chain.distance_tracker.distance();
+        self.meters.frame.room_rt60_ms = if self.chain.rt60_estimator.get_decay_count() > 0 {
+            self.chain.rt60_estimator.get_rt60_ms()
+        } else {
+            0.0
+        };
+
+        // Mic alignment result: store it with the project (0 idle, 1 measuring,
+        // 2 no match)
+        match self.chain.mic_aligner.take_outcome() {
+            Some(AlignOutcome::Aligned(alignment)) => {
+                self.params.mic_alignment.store(alignment, self.sample_rate);
+                self.meters.frame.mic_align_status = 0;
+            }
+            Some(AlignOutcome::NoMatch) => self.meters.frame.mic_align_status = 2,
+            None if self.chain.mic_aligner.is_measuring() => self.meters.frame.mic_align_status = 1,
+            None => {}
+        }
+
+        // De-ess calibration result (0 idle, 1 analysing, 2 not enough sibilance)
+        match self.chain.de_ess_calibrator.take_outcome() {
+            Some(DeEssCalibration::Done {
+                offset_db,
+                p95_rel_db,
+                ..
+            }) => {
+                self.params.de_ess_threshold.store(offset_db, p95_rel_db);
+                self.meters.frame.de_ess_auto_status = 0;
+            }
+            Some(DeEssCalibration::NotEnough) => self.meters.frame.de_ess_auto_status = 2,
+            None if self.chain.de_ess_calibrator.is_measuring() => {
+                self.meters.frame.de_ess_auto_status = 1
+            }
+            None => {}
+        }
+
+        // Codec cutoff of band-limited sources (0 = full band)
+        self.meters.frame.codec_cutoff_hz =
+            self.chain.bandwidth_extender.cutoff_hz().unwrap_or(0.0);
+
+        // Hiss/rumble corners of the measured noise floor (0 = not found)
+        self.meters.frame.hiss_onset_hz = self.chain.hiss_profile.hiss_onset_hz().unwrap_or(0.0);
+        self.meters.frame.rumble_cutoff_hz =
+            self.chain.hiss_profile.rumble_cutoff_hz().unwrap_or(0.0);
+
+        // Keyboard/impulse events over the rolling minute
+        self.meters.frame.transient_events_per_min =
+            self.chain.transient_suppressor.events_per_minute();
+
+        // Speaker statistics (read by the editor when saving a profile)
+        self.meters.frame.speaker_stats = self.chain.speaker_learner.stats();
+        if !freeze {
+            self.meters.frame.active_speaker = self.chain.dual_active.map_or(-1, |i| i as i32);
+        }
 
-        // Finalize output profile analysis (for validation/debugging only)
-        self.output_profile_analyzer.finalize_frame();
+        // Output profile (for validation/debugging only)
         let output_profile = self.output_profile_analyzer.get_profile();
 
         // --- Layer 3: Audible Outcome Metrics ---
@@ -1604,66 +2509,91 @@ impl VoiceStudioPlugin {
         } else {
             -80.0
         };
-        let total_gr_db = self.linked_compressor.get_gain_reduction_db()
-            + self.linked_limiter.get_gain_reduction_db();
-
-        self.meters
-            .output_rms_db
-            .store(output_rms_db, Ordering::Relaxed);
-        self.meters
-            .output_peak_db
-            .store(output_peak_db, Ordering::Relaxed);
-        self.meters
-            .output_crest_db
-            .store(output_profile.crest_factor_db, Ordering::Relaxed);
-        self.meters
-            .total_gain_reduction_db
-            .store(total_gr_db, Ordering::Relaxed);
-
-        // Update loudness compensation gain based on RMS envelopes (Always on)
-        // Use more conservative approach to prevent pumping
-        if self.post_rms_env > 1e-8 && self.pre_rms_env > 1e-8 {
-            let current_ratio = (self.pre_rms_env / self.post_rms_env).sqrt();
-
-            // Use a more conservative target gain (±10% instead of ±100%)
-            let target_gain = current_ratio.clamp(0.9, 1.1);
-
-            // Use a much slower slew rate for loudness compensation to prevent pumping
-            let slow_rms_alpha = 1.0 - (-1.0 / (10.0 * self.sample_rate)).exp(); // 10 second time constant
-
-            self.loudness_comp_gain += (target_gain - self.loudness_comp_gain) * slow_rms_alpha;
-        } else {
-            // Use a slower rate to return to unity gain
-            let slow_rms_alpha = 1.0 - (-1.0 / (10.0 * self.sample_rate)).exp(); // 10 second time constant
-            self.loudness_comp_gain += (1.0 - self.loudness_comp_gain) * slow_rms_alpha;
+        let total_gr_db = self.chain.linked_compressor.get_gain_reduction_db()
+            + self.chain.linked_limiter.get_gain_reduction_db()
+            + self.chain.soft_clipper.gain_reduction_db();
+
+        self.meters.frame.output_rms_db = output_rms_db;
+        self.meters.frame.output_peak_db = output_peak_db;
+        self.meters.frame.output_crest_db = output_profile.crest_factor_db;
+        self.meters.frame.total_gain_reduction_db = total_gr_db;
+        self.meters.frame.input_snr_db = input_profile.snr_db;
+        let input_conditions = DetectedConditions::detect(&input_profile);
+        self.meters.frame.input_conditions = input_conditions;
+        // Session statistics over input with signal (silence says nothing)
+        if input_profile.rms > AUTO_MACRO_MIN_RMS {
+            self.condition_stats
+                .update(&input_conditions, frame_count as f32 / self.sample_rate);
         }
+        self.meters.frame.condition_stats = self.condition_stats;
 
-        let loudness_error_db = if self.post_rms_env > 1e-8 && self.pre_rms_env > 1e-8 {
-            10.0 * (self.pre_rms_env / self.post_rms_env).log10()
+        let (pre_rms_env, post_rms_env) = (self.chain.pre_rms_env, self.chain.post_rms_env);
+        let loudness_error_db = if post_rms_env > 1e-8 && pre_rms_env > 1e-8 {
+            10.0 * (pre_rms_env / post_rms_env).log10()
         } else {
             0.0
         };
-        let loudness_comp_db = if self.loudness_comp_gain > 1e-8 {
-            20.0 * self.loudness_comp_gain.log10()
+        let loudness_comp_applied = self.chain.loudness_comp_applied;
+        let loudness_comp_db = if loudness_comp_applied > 1e-8 {
+            20.0 * loudness_comp_applied.log10()
         } else {
             0.0
         };
         let loudness_active = loudness_comp_db.abs() > 0.1;
 
-        self.meters
-            .loudness_error_db
-            .store(loudness_error_db, Ordering::Relaxed);
-        self.meters
-            .loudness_comp_db
-            .store(loudness_comp_db, Ordering::Relaxed);
-        self.meters
-            .loudness_active
-            .store(if loudness_active { 1 } else { 0 }, Ordering::Relaxed);
-
-        // Update DSP modules with profile-based adaptation
-        // METRIC OWNERSHIP: Leveler owns RMS, crest factor, RMS variance
-        self.linked_compressor
-            .update_from_profile(input_profile.crest_factor_db, input_profile.rms_variance);
+        self.meters.frame.loudness_error_db = loudness_error_db;
+        self.meters.frame.loudness_comp_db = loudness_comp_db;
+        self.meters.frame.loudness_active = loudness_active;
+
+        // Auto macros: follow the calibration while there is signal to judge
+        if input_profile.rms > AUTO_MACRO_MIN_RMS && !freeze {
+            let target = AutoMacroAmounts::calibrate(&input_profile, &TargetProfile::default());
+            let alpha =
+                1.0 - (-(frame_count as f32) / (AUTO_MACRO_TAU_SEC * self.sample_rate)).exp();
+            self.macro_auto
+                .follow(&target, alpha, input_profile.reverb_stability);
+        }
+        self.meters.frame.macro_auto = self.macro_auto;
+
+        // Modulation sources (smoothed inside the matrix)
+        self.modulation.update(
+            self.chain.speech_confidence.get_output().speech_conf,
+            input_profile.snr_db,
+            self.meters.frame.output_lufs_short,
+            frame_count as f32 / self.sample_rate,
+        );
+
+        // Peak hold: a new maximum holds for PEAK_HOLD_SEC, then the hold
+        // follows the decaying peak down. A click on a meter clears it.
+        if self.meters.take_peak_hold_reset() {
+            self.peak_hold = [-80.0; 4];
+        }
+        let block_sec = frame_count as f32 / self.sample_rate;
+        let peaks = [
+            self.peak_input_l,
+            self.peak_input_r,
+            self.peak_output_l,
+            self.peak_output_r,
+        ];
+        for ((hold, age), peak) in self
+            .peak_hold
+            .iter_mut()
+            .zip(self.peak_hold_age_sec.iter_mut())
+            .zip(peaks)
+        {
+            *age += block_sec;
+            if peak > *hold {
+                *hold = peak;
+                *age = 0.0;
+            } else if *age >= PEAK_HOLD_SEC {
+                *hold = peak;
+            }
+        }
+        let [hold_in_l, hold_in_r, hold_out_l, hold_out_r] = self.peak_hold;
+        self.meters.frame.input_hold_l = hold_in_l;
+        self.meters.frame.input_hold_r = hold_in_r;
+        self.meters.frame.output_hold_l = hold_out_l;
+        self.meters.frame.output_hold_r = hold_out_r;
 
         let decay = decay_per_sample * frame_count as f32;
         self.peak_input_l = (self.peak_input_l - decay).max(-80.0);
@@ -1671,145 +2601,166 @@ impl VoiceStudioPlugin {
         self.peak_output_l = (self.peak_output_l - decay).max(-80.0);
         self.peak_output_r = (self.peak_output_r - decay).max(-80.0);
 
-        // Update meter values atomically (done once per buffer for efficiency)
-        self.meters.set_input_peak_l(self.peak_input_l);
-        self.meters.set_input_peak_r(self.peak_input_r);
-        self.meters.set_output_peak_l(self.peak_output_l);
-        self.meters.set_output_peak_r(self.peak_output_r);
+        // Decayed peak levels (published with the rest of the frame below)
+        self.meters.frame.input_peak_l = self.peak_input_l;
+        self.meters.frame.input_peak_r = self.peak_input_r;
+        self.meters.frame.output_peak_l = self.peak_output_l;
+        self.meters.frame.output_peak_r = self.peak_output_r;
+        let [rms_in_l, rms_in_r, rms_out_l, rms_out_r] = self
+            .meter_rms_sq
+            .map(|ms| (10.0 * ms.max(1e-8).log10()).max(-80.0));
+        self.meters.frame.input_rms_l = rms_in_l;
+        self.meters.frame.input_rms_r = rms_in_r;
+        self.meters.frame.output_rms_l = rms_out_l;
+        self.meters.frame.output_rms_r = rms_out_r;
 
         // Get gain reduction from both channel compressors for true stereo metering
-        let gr_db = self.linked_compressor.get_gain_reduction_db();
-        self.meters.set_gain_reduction_l(gr_db);
-        self.meters.set_gain_reduction_r(gr_db);
+        let gr_db = self.chain.linked_compressor.get_gain_reduction_db();
+        self.meters.frame.gain_reduction_l = gr_db;
+        self.meters.frame.gain_reduction_r = gr_db;
 
         // Update Quality Meter
-        self.meters
-            .set_noise_learn_quality(self.noise_learn_remove.get_quality());
+        self.meters.frame.noise_learn_quality = self.chain.noise_learn_remove.get_quality();
+        self.meters.frame.noise_learn_active = self.chain.noise_learn_remove.is_learning();
+        self.meters.frame.noise_capture_progress =
+            self.chain.noise_learn_remove.get_learn_progress();
+        self.meters.frame.noise_capture_status = match self.chain.noise_learn_remove.capture_state()
+        {
+            NoiseCapture::Idle => 0,
+            NoiseCapture::Capturing => 1,
+            NoiseCapture::Done => 2,
+            NoiseCapture::SpeechDetected => 3,
+            NoiseCapture::TooShort => 4,
+        };
+
+        // Artifact Guard activity
+        self.meters.frame.artifact_level = self.chain.denoiser.get_artifact_level();
+        self.meters.frame.artifact_backoff = self.chain.denoiser.get_artifact_backoff();
+
+        // Stereo width into and out of the denoiser
+        self.meters.frame.denoise_width_in = self.chain.denoiser.input_width();
+        self.meters.frame.denoise_width_out = self.chain.denoiser.output_width();
+
+        // Dead air since the last analysis reset
+        self.meters.frame.dead_air_sec = self.chain.dead_air.dead_air_sec();
+        self.meters.frame.music_detected = self.chain.music_detector.is_music();
+        self.meters.frame.long_pauses = self.chain.dead_air.long_pauses();
+        self.meters.frame.de_ess_events = self.chain.de_ess_events.events();
+        self.meters.frame.de_ess_rate = self.chain.de_ess_events.rate_per_sec();
+        self.meters.frame.de_ess_depth_db = self.chain.de_ess_events.average_depth_db();
+
+        // Output phase correlation and mono fix activity
+        self.meters.frame.stereo_correlation = self.chain.mono_compat.correlation();
+        self.meters.frame.mono_fix_width = self.chain.mono_compat.width();
 
         // =====================================================================
         // DEBUG METERS - for DSP analysis and tuning
         // =====================================================================
         // Speech confidence from the last sample
-        let last_sidechain = self.speech_confidence.get_output();
-        self.meters
-            .set_debug_speech_confidence(last_sidechain.speech_conf);
-        self.meters
-            .set_debug_noise_floor_db(last_sidechain.noise_floor_db);
+        let last_sidechain = self.chain.speech_confidence.get_output();
+        self.meters.frame.debug_speech_confidence = last_sidechain.speech_conf;
+        self.meters.frame.debug_noise_floor_db = last_sidechain.noise_floor_db;
 
         // De-esser gain reduction
-        self.meters
-            .set_debug_deesser_gr_db(self.linked_de_esser.get_gain_reduction_db());
+        self.meters.frame.debug_deesser_gr_db = self.chain.linked_de_esser.get_gain_reduction_db();
+        self.meters.frame.debug_deesser_freq_hz = self.chain.linked_de_esser.get_center_hz();
 
-        // Limiter gain reduction
-        let limiter_gr_db = self.linked_limiter.get_gain_reduction_db();
-        self.meters.set_debug_limiter_gr_db(limiter_gr_db);
+        // Limiter gain reduction (soft clipper included)
+        let limiter_gr_db = self.chain.linked_limiter.get_gain_reduction_db()
+            + self.chain.soft_clipper.gain_reduction_db();
+        self.meters.frame.debug_limiter_gr_db = limiter_gr_db;
 
         // Early reflection suppression (average of L/R)
         let early_refl_avg = 0.5
-            * (self.early_reflection_l.get_suppression()
-                + self.early_reflection_r.get_suppression());
-        self.meters.set_debug_early_reflection(early_refl_avg);
+            * (self.chain.early_reflection_l.get_suppression()
+                + self.chain.early_reflection_r.get_suppression());
+        self.meters.frame.debug_early_reflection = early_refl_avg;
 
         // Spectral guardrails corrections
-        self.meters
-            .set_debug_guardrails_low_cut(self.spectral_guardrails.get_low_mid_cut_db());
-        self.meters
-            .set_debug_guardrails_high_cut(self.spectral_guardrails.get_high_cut_db());
+        self.meters.frame.debug_guardrails_low_cut =
+            self.chain.spectral_guardrails.get_low_mid_cut_db();
+        self.meters.frame.debug_guardrails_high_cut =
+            self.chain.spectral_guardrails.get_high_cut_db();
 
         // Speech expander attenuation
-        self.meters
-            .set_debug_expander_atten_db(self.speech_expander.get_gain_reduction_db());
+        self.meters.frame.debug_expander_atten_db =
+            self.chain.speech_expander.get_gain_reduction_db();
 
         // Hiss/Rumble processor debug meters
-        self.meters
-            .set_hiss_db_current(self.hiss_rumble.get_hiss_db_current());
-        self.meters
-            .set_rumble_hz_current(self.hiss_rumble.get_rumble_hz_current());
-
-        // Detect sudden loudness compensation + limiter movement ("pumping")
-        let prev_gain = self.prev_loudness_comp_gain.max(1e-6);
-        let loudness_ratio = (self.loudness_comp_gain / prev_gain).max(1e-6);
-        let loudness_delta_db = 20.0 * loudness_ratio.log10();
-
-        // Enhanced pump detection with multiple indicators
-        let pump_trigger = loudness_delta_db.abs() > LOUDNESS_PUMP_DELTA_DB
-            || limiter_gr_db > LIMITER_PUMP_THRESHOLD_DB;
+        self.meters.frame.hiss_db_current = self.chain.hiss_rumble.get_hiss_db_current();
+        self.meters.frame.rumble_hz_current = self.chain.hiss_rumble.get_rumble_hz_current();
 
-        // Additional pump detection: rapid changes in multiple systems
-        let leveler_gr_db = self.linked_compressor.get_gain_reduction_db();
-
-        // Check for correlated gain movements across systems
-        let gain_movement_correlation = (leveler_gr_db - self.meters.get_gain_reduction_l()).abs()
-            + (limiter_gr_db - self.meters.get_debug_limiter_gr_db()).abs();
-
-        let enhanced_pump_trigger =
-            pump_trigger || (gain_movement_correlation > 5.0 && loudness_delta_db.abs() > 1.0);
-
-        // Pump detection - just track cooldown, no audio-thread logging
-        if enhanced_pump_trigger && self.pump_log_cooldown == 0 {
-            self.pump_log_cooldown = PUMP_LOG_COOLDOWN_BUFFERS;
+        self.meters.frame.pump_guard_active = self.chain.pump_guard.is_engaged();
+        if pump_trigger && !self.pump_log_cooldown.is_running() {
+            self.pump_log_cooldown
+                .start(PUMP_LOG_COOLDOWN_MS, self.sample_rate);
+        }
+        self.pump_log_cooldown.advance(frame_count);
+
+        // Keep the project's copy of the noise floor current once it settles
+        // (a frozen one is left as saved, so the next load starts the same)
+        self.noise_floor_save_countdown.advance(frame_count);
+        if !self.noise_floor_save_countdown.is_running()
+            && self.chain.denoiser.noise_floor_settled()
+            && !freeze
+        {
+            self.params.noise_floor.store(
+                self.sample_rate,
+                self.active_quality.fft_size(),
+                self.chain.denoiser.noise_floor(),
+                self.chain.denoiser.coarse_noise_floor(),
+            );
+            self.noise_floor_save_countdown
+                .start(NOISE_FLOOR_SAVE_MS, self.sample_rate);
         }
 
-        if self.pump_log_cooldown > 0 {
-            self.pump_log_cooldown -= 1;
+        // Numeric containment: log each new stage reset, keep the first culprit
+        let numeric_faults = self.chain.numeric_guard.fault_count();
+        if numeric_faults > self.meters.frame.numeric_fault_count {
+            vs_log!(
+                "[NUMERIC] non-finite output, reset {} (first: {}, total {})",
+                self.chain
+                    .numeric_guard
+                    .last_fault()
+                    .map_or("-", GuardStage::name),
+                self.chain
+                    .numeric_guard
+                    .first_fault()
+                    .map_or("-", GuardStage::name),
+                numeric_faults
+            );
+        }
+        self.meters.frame.numeric_fault_stage =
+            GuardStage::code(self.chain.numeric_guard.first_fault());
+        self.meters.frame.numeric_fault_count = numeric_faults;
+        for (db, stage) in self
+            .meters
+            .frame
+            .stage_gain_db
+            .iter_mut()
+            .zip(GuardStage::ALL)
+        {
+            *db = self.chain.gain_audit.gain_db(stage);
         }
-        self.prev_loudness_comp_gain = self.loudness_comp_gain;
 
         // Mode transition event handling (no audio-thread logging)
         #[cfg(feature = "debug")]
         {
-            let m = &self.meters;
+            let m = &mut self.meters.frame;
             const AUDIBLE_CHANGE_TOLERANCE_DB: f32 = 0.1;
-            let mode_trans_event = m.mode_transition_event.load(Ordering::Relaxed);
-            if mode_trans_event != 0 {
-                let current_rms = m.output_rms_db.load(Ordering::Relaxed);
-                let pre_switch_rms = m.pre_switch_audible_rms.load(Ordering::Relaxed);
-                let audible_change_detected =
-                    (current_rms - pre_switch_rms).abs() > AUDIBLE_CHANGE_TOLERANCE_DB;
-                m.audible_change_detected.store(
-                    if audible_change_detected { 1 } else { 0 },
-                    Ordering::Relaxed,
-                );
-                m.mode_transition_event.store(0, Ordering::Relaxed);
-                m.audible_change_detected.store(0, Ordering::Relaxed);
-                m.pre_switch_audible_rms.store(-80.0, Ordering::Relaxed);
+            if m.mode_transition_event != 0 {
+                m.audible_change_detected = (m.output_rms_db - m.pre_switch_audible_rms).abs()
+                    > AUDIBLE_CHANGE_TOLERANCE_DB;
+                m.mode_transition_event = 0;
+                m.audible_change_detected = false;
+                m.pre_switch_audible_rms = -80.0;
             }
         }
 
-        ProcessStatus::Normal
-    }
-
-    fn calculate_integrity_score(
-        &self,
-        speech_conf: f32,
-        confidence_slope: f32,
-        denoiser_reduction: f32,
-        early_reflection_suppression: f32,
-        expander_gr_db: f32,
-    ) -> f32 {
-        let stability_factor = 1.0 - confidence_slope.abs() * 2.0;
-        let harmonic_proxy = speech_conf * stability_factor.max(0.0);
-
-        let denoise_impact = denoiser_reduction * 0.6;
-        let reflection_impact = early_reflection_suppression * 0.5;
-        let expander_impact = (expander_gr_db.abs() / 10.0).clamp(0.0, 1.0);
-        let combined_reduction_impact =
-            (denoise_impact + reflection_impact + expander_impact).min(1.0);
-
-        let multiple_modules_active = (denoise_impact > 0.3) as i32
-            + (reflection_impact > 0.3) as i32
-            + (expander_impact > 0.3) as i32;
-        let cascade_penalty = (multiple_modules_active - 1).max(0) as f32 * 0.2;
-
-        let base_integrity =
-            (harmonic_proxy - combined_reduction_impact - cascade_penalty).max(0.0);
-        let integrity = if multiple_modules_active > 1 {
-            base_integrity * (1.0 - (multiple_modules_active as f32 - 1.0) * 0.3)
-        } else {
-            base_integrity
-        };
+        // Hand the whole frame to the editor in one swap
+        self.meters.publish();
 
-        integrity.clamp(0.0, 1.0)
+        ProcessStatus::Normal
     }
 }
 
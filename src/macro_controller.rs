@@ -5,106 +5,273 @@
 //! - No reverse mapping
 //! - No state
 //! - Safe at control/audio rate
+//!
+//! The macro kinds, calibration and amount mapping live in the core crate
+//! (`voice_studio_core::macros`); this module binds them to `VoiceParams`,
+//! parameter locks and the host's `ParamSetter`.
 
-use crate::dsp::utils::{lerp, smoothstep};
+use crate::param_locks::{set_unlocked, LockId, ParamLocks};
+use crate::ui::locale::tr;
 use crate::VoiceParams;
-use nih_plug::prelude::ParamSetter;
-
-#[derive(Clone, Copy)]
-pub struct SimpleMacroTargets {
-    pub noise_reduction: f32,
-    pub reverb_reduction: f32,
-    pub proximity: f32,
-    pub clarity: f32,
-    pub de_esser: f32,
-    pub leveler: f32,
-    pub breath_control: f32,
-    pub rumble: f32,
-    pub hiss: f32,
+use nih_plug::prelude::{BoolParam, FloatParam, Param, ParamSetter};
+pub use voice_studio_core::macros::{
+    macro_targets, static_noise_amount, AutoMacroAmounts, MacroKind, SimpleMacroTargets,
+    AUTO_OFFSET_CENTER, SAFE_LIVE_LIMITS,
+};
+
+/// The plugin parameters behind each macro
+pub trait MacroParams {
+    fn dial<'a>(&self, params: &'a VoiceParams) -> &'a FloatParam;
+    fn auto<'a>(&self, params: &'a VoiceParams) -> &'a BoolParam;
+    /// Amount the macro drives: the dial, or calibration plus dial offset
+    fn effective(&self, params: &VoiceParams, auto: &AutoMacroAmounts) -> f32;
+    /// As `effective()`, with the dial at `dial`
+    fn effective_at(&self, params: &VoiceParams, auto: &AutoMacroAmounts, dial: f32) -> f32;
 }
 
-pub fn compute_simple_macro_targets(params: &VoiceParams) -> SimpleMacroTargets {
-    let x_clean = params.macro_clean.value();
-    let x_enhance = params.macro_enhance.value();
-    let x_control = params.macro_control.value();
+impl MacroParams for MacroKind {
+    fn dial<'a>(&self, params: &'a VoiceParams) -> &'a FloatParam {
+        match self {
+            MacroKind::Clean => &params.macro_clean,
+            MacroKind::Enhance => &params.macro_enhance,
+            MacroKind::Control => &params.macro_control,
+        }
+    }
+
+    fn auto<'a>(&self, params: &'a VoiceParams) -> &'a BoolParam {
+        match self {
+            MacroKind::Clean => &params.macro_clean_auto,
+            MacroKind::Enhance => &params.macro_enhance_auto,
+            MacroKind::Control => &params.macro_control_auto,
+        }
+    }
 
-    // --- CLEAN macro mappings ---
-    // Rumble: 20 -> 60 Hz. Norm: (60-20)/(120-20) = 0.4
-    let rumble_norm = ((x_clean - 0.1) / 0.5).clamp(0.0, 1.0);
-    let rumble_param = rumble_norm * rumble_norm * 0.4;
+    fn effective(&self, params: &VoiceParams, auto: &AutoMacroAmounts) -> f32 {
+        self.effective_at(params, auto, self.dial(params).value())
+    }
+
+    fn effective_at(&self, params: &VoiceParams, auto: &AutoMacroAmounts, dial: f32) -> f32 {
+        if self.auto(params).value() {
+            auto.offset(*self, dial)
+        } else {
+            dial
+        }
+    }
+}
 
-    // Hiss: 0 -> -6 dB. Norm: -6/-24 = 0.25
-    let hiss_norm = ((x_clean - 0.35) / 0.45).clamp(0.0, 1.0);
-    let hiss_param = hiss_norm * 0.25;
+pub fn compute_simple_macro_targets(
+    params: &VoiceParams,
+    auto: &AutoMacroAmounts,
+) -> SimpleMacroTargets {
+    compute_simple_macro_targets_at(params, auto, |kind| kind.dial(params).value())
+}
 
-    // Main Denoiser: 0 -> 70%
-    let denoise_amt = ((x_clean - 0.45) / 0.55).clamp(0.0, 1.0) * 0.7;
+/// As `compute_simple_macro_targets()`, with each dial read from `dial`
+pub fn compute_simple_macro_targets_at(
+    params: &VoiceParams,
+    auto: &AutoMacroAmounts,
+    dial: impl Fn(MacroKind) -> f32,
+) -> SimpleMacroTargets {
+    let effective = |kind: MacroKind| kind.effective_at(params, auto, dial(kind));
+    macro_targets(
+        effective(MacroKind::Clean),
+        effective(MacroKind::Enhance),
+        effective(MacroKind::Control),
+    )
+}
 
-    // --- ENHANCE macro mappings ---
-    // Proximity: smooth ramp
-    let proximity = smoothstep(0.0, 0.6, x_enhance);
-    // Clarity: capped at 40%
-    let clarity = smoothstep(0.3, 0.9, x_enhance) * 0.4;
+/// The advanced parameters `kind` drives, each with the value it sets now
+/// (plain), as `apply_simple_macros()` would push them
+pub fn driven_values<'a>(
+    params: &'a VoiceParams,
+    auto: &AutoMacroAmounts,
+    kind: MacroKind,
+) -> Vec<(LockId, &'a FloatParam, f32)> {
+    let targets = compute_simple_macro_targets(params, auto);
+    match kind {
+        MacroKind::Clean => vec![
+            (
+                LockId::NoiseReduction,
+                &params.noise_reduction,
+                targets.noise_reduction,
+            ),
+            (
+                LockId::DeVerb,
+                &params.reverb_reduction,
+                targets.reverb_reduction,
+            ),
+            (
+                LockId::StaticNoise,
+                &params.noise_learn_amount,
+                static_noise_amount(kind.effective(params, auto)),
+            ),
+            (LockId::Rumble, &params.rumble_amount, targets.rumble),
+            (LockId::Hiss, &params.hiss_amount, targets.hiss),
+        ],
+        MacroKind::Enhance => vec![
+            (LockId::Proximity, &params.proximity, targets.proximity),
+            (LockId::Clarity, &params.clarity, targets.clarity),
+        ],
+        MacroKind::Control => vec![
+            (LockId::DeEsser, &params.de_esser, targets.de_esser),
+            (LockId::Leveler, &params.leveler, targets.leveler),
+            (
+                LockId::BreathControl,
+                &params.breath_control,
+                targets.breath_control,
+            ),
+        ],
+    }
+}
 
-    // --- CONTROL macro mappings ---
-    // De-esser
-    let deesser = smoothstep(0.2, 0.7, x_control);
-    // Leveler
-    let leveler = smoothstep(0.0, 1.0, x_control);
+/// One "Name: value" line per parameter `kind` drives, formatted like the
+/// sliders; locked parameters show the value they keep
+pub fn driven_value_lines(
+    params: &VoiceParams,
+    auto: &AutoMacroAmounts,
+    kind: MacroKind,
+) -> Vec<String> {
+    let locks = params.param_locks.as_ref();
+    driven_values(params, auto, kind)
+        .into_iter()
+        .map(|(lock, param, value)| {
+            if locks.is_locked(lock) {
+                format!("{}: {} ({})", param.name(), param, tr("locked"))
+            } else {
+                let value = param.normalized_value_to_string(param.preview_normalized(value), true);
+                format!("{}: {}", param.name(), value)
+            }
+        })
+        .collect()
+}
 
+/// Macro targets with locked parameters replaced by their manual values
+pub fn lock_targets(
+    targets: &SimpleMacroTargets,
+    manual: &SimpleMacroTargets,
+    locks: &ParamLocks,
+) -> SimpleMacroTargets {
+    let pick = |id: LockId, target: f32, manual: f32| {
+        if locks.is_locked(id) {
+            manual
+        } else {
+            target
+        }
+    };
     SimpleMacroTargets {
-        noise_reduction: denoise_amt,
-        reverb_reduction: 0.0,
-        proximity,
-        clarity,
-        de_esser: deesser,
-        leveler,
-        breath_control: lerp(x_control, 0.0, 0.5),
-        rumble: rumble_param,
-        hiss: hiss_param,
+        noise_reduction: pick(
+            LockId::NoiseReduction,
+            targets.noise_reduction,
+            manual.noise_reduction,
+        ),
+        reverb_reduction: pick(
+            LockId::DeVerb,
+            targets.reverb_reduction,
+            manual.reverb_reduction,
+        ),
+        proximity: pick(LockId::Proximity, targets.proximity, manual.proximity),
+        clarity: pick(LockId::Clarity, targets.clarity, manual.clarity),
+        de_esser: pick(LockId::DeEsser, targets.de_esser, manual.de_esser),
+        leveler: pick(LockId::Leveler, targets.leveler, manual.leveler),
+        breath_control: pick(
+            LockId::BreathControl,
+            targets.breath_control,
+            manual.breath_control,
+        ),
+        rumble: pick(LockId::Rumble, targets.rumble, manual.rumble),
+        hiss: pick(LockId::Hiss, targets.hiss, manual.hiss),
     }
 }
 
 /// Apply Simple-mode macros to the advanced parameters.
+/// Locked parameters are left untouched.
 /// This must be called ONLY when `macro_mode == true` from the GUI thread.
-pub fn apply_simple_macros(params: &VoiceParams, setter: &ParamSetter<'_>) {
-    let x_clean = params.macro_clean.value();
-    let targets = compute_simple_macro_targets(params);
+pub fn apply_simple_macros(
+    params: &VoiceParams,
+    auto: &AutoMacroAmounts,
+    setter: &ParamSetter<'_>,
+) {
+    let x_clean = MacroKind::Clean.effective(params, auto);
+    let targets = compute_simple_macro_targets(params, auto);
 
-    // 1. CLEAN mappings
-    setter.begin_set_parameter(&params.rumble_amount);
-    setter.set_parameter(&params.rumble_amount, targets.rumble);
-    setter.end_set_parameter(&params.rumble_amount);
+    let locks = params.param_locks.as_ref();
 
-    setter.begin_set_parameter(&params.hiss_amount);
-    setter.set_parameter(&params.hiss_amount, targets.hiss);
-    setter.end_set_parameter(&params.hiss_amount);
+    // 1. CLEAN mappings
+    set_unlocked(
+        setter,
+        locks,
+        LockId::Rumble,
+        &params.rumble_amount,
+        targets.rumble,
+    );
+    set_unlocked(
+        setter,
+        locks,
+        LockId::Hiss,
+        &params.hiss_amount,
+        targets.hiss,
+    );
 
     // Static Noise: 0 -> 100%
-    let static_noise_amt = ((x_clean - 0.6) / 0.4).clamp(0.0, 1.0);
-    setter.begin_set_parameter(&params.noise_learn_amount);
-    setter.set_parameter(&params.noise_learn_amount, static_noise_amt);
-    setter.end_set_parameter(&params.noise_learn_amount);
+    set_unlocked(
+        setter,
+        locks,
+        LockId::StaticNoise,
+        &params.noise_learn_amount,
+        static_noise_amount(x_clean),
+    );
 
-    setter.begin_set_parameter(&params.noise_reduction);
-    setter.set_parameter(&params.noise_reduction, targets.noise_reduction);
-    setter.end_set_parameter(&params.noise_reduction);
+    set_unlocked(
+        setter,
+        locks,
+        LockId::NoiseReduction,
+        &params.noise_reduction,
+        targets.noise_reduction,
+    );
+    set_unlocked(
+        setter,
+        locks,
+        LockId::DeVerb,
+        &params.reverb_reduction,
+        targets.reverb_reduction,
+    );
 
     // 2. ENHANCE mappings
-    setter.begin_set_parameter(&params.proximity);
-    setter.set_parameter(&params.proximity, targets.proximity);
-    setter.end_set_parameter(&params.proximity);
-
-    setter.begin_set_parameter(&params.clarity);
-    setter.set_parameter(&params.clarity, targets.clarity);
-    setter.end_set_parameter(&params.clarity);
+    set_unlocked(
+        setter,
+        locks,
+        LockId::Proximity,
+        &params.proximity,
+        targets.proximity,
+    );
+    set_unlocked(
+        setter,
+        locks,
+        LockId::Clarity,
+        &params.clarity,
+        targets.clarity,
+    );
 
     // 3. CONTROL mappings
-    setter.begin_set_parameter(&params.de_esser);
-    setter.set_parameter(&params.de_esser, targets.de_esser);
-    setter.end_set_parameter(&params.de_esser);
-
-    setter.begin_set_parameter(&params.leveler);
-    setter.set_parameter(&params.leveler, targets.leveler);
-    setter.end_set_parameter(&params.leveler);
+    set_unlocked(
+        setter,
+        locks,
+        LockId::DeEsser,
+        &params.de_esser,
+        targets.de_esser,
+    );
+    set_unlocked(
+        setter,
+        locks,
+        LockId::Leveler,
+        &params.leveler,
+        targets.leveler,
+    );
+    set_unlocked(
+        setter,
+        locks,
+        LockId::BreathControl,
+        &params.breath_control,
+        targets.breath_control,
+    );
 }
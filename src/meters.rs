@@ -1,370 +1,495 @@
-//! Thread-safe metering utilities for real-time audio processing.
+//! Lock-free meter transport between the audio thread and the UI.
 //!
-//! This module provides atomic float storage for sharing meter data between
-//! the audio thread and UI thread without locks. Some getters are currently
-//! unused but are kept for debugging and future UI integration.
-
-use std::sync::atomic::{AtomicI32, AtomicU32, AtomicU64, Ordering};
-
-#[derive(Debug)]
-pub struct AtomicF32 {
-    inner: AtomicU32,
+//! The audio thread fills a plain [`MeterSnapshot`] while it processes a
+//! buffer and publishes it once at the end through a triple buffer: one
+//! atomic index swap hands the whole frame over, so readers always see
+//! values that belong to the same buffer. Adding a meter means adding a
+//! field to the snapshot (and its reset value to `Default`).
+//!
+//! The level meters carry both a decaying peak and an RMS level per channel;
+//! [`MeterMode`] picks which one is shown and on what scale (full scale or
+//! one of the K-System scales).
+//!
+//! ## Audio Thread Safety
+//! - `MeterWriter::publish()` copies the snapshot into a preallocated slot
+//!   and performs a single atomic swap; it never blocks or allocates
+//! - Readers (editor, remote control) serialize among themselves with a
+//!   mutex the audio thread never touches
+
+use crate::dsp::GuardStage;
+use crate::macro_controller::AutoMacroAmounts;
+use crate::speaker_profile::SpeakerStats;
+use crate::{ConditionStats, DetectedConditions};
+use nih_plug_vizia::vizia::prelude::Data;
+use serde::{Deserialize, Serialize};
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Slot index bits of the shared `middle` word
+const INDEX_MASK: usize = 0b11;
+/// Set in `middle` when it holds a frame the reader has not picked up yet
+const FRESH: usize = 0b100;
+
+/// Time constant of the RMS level (s)
+pub const RMS_TAU_SEC: f32 = 0.3;
+/// K-System meters are sine-calibrated: a sine reads its peak level (+3 dB)
+const SINE_RMS_OFFSET_DB: f32 = 3.01;
+
+/// Level meter ballistics and scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Data, Serialize, Deserialize)]
+pub enum MeterMode {
+    /// Decaying sample peaks, 0 dB = full scale
+    #[default]
+    Peak,
+    /// RMS level, 0 dB = full scale
+    Rms,
+    /// K-System: sine-calibrated RMS, 0 dB = -12 dBFS (music, broadcast)
+    K12,
+    /// K-System: sine-calibrated RMS, 0 dB = -14 dBFS (mastering)
+    K14,
 }
 
-impl AtomicF32 {
-    pub const fn new(value: f32) -> Self {
-        Self {
-            inner: AtomicU32::new(value.to_bits()),
+impl MeterMode {
+    pub const ALL: [MeterMode; 4] = [Self::Peak, Self::Rms, Self::K12, Self::K14];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Peak => "PEAK",
+            Self::Rms => "RMS",
+            Self::K12 => "K-12",
+            Self::K14 => "K-14",
         }
     }
 
-    pub fn store(&self, value: f32, order: Ordering) {
-        self.inner.store(value.to_bits(), order);
+    /// The mode after this one (right-click cycles through them)
+    pub fn next(self) -> Self {
+        Self::ALL[(self as usize + 1) % Self::ALL.len()]
     }
 
-    #[allow(dead_code)]
-    pub fn load(&self, order: Ordering) -> f32 {
-        f32::from_bits(self.inner.load(order))
+    /// Headroom above the scale's 0 dB mark (0 for the full-scale modes)
+    pub fn headroom_db(self) -> f32 {
+        match self {
+            Self::Peak | Self::Rms => 0.0,
+            Self::K12 => 12.0,
+            Self::K14 => 14.0,
+        }
     }
-}
 
-impl Default for AtomicF32 {
-    fn default() -> Self {
-        Self::new(0.0)
+    /// Reading on this mode's scale (dB) from a peak and an RMS level (dBFS)
+    pub fn reading(self, peak_db: f32, rms_db: f32) -> f32 {
+        match self {
+            Self::Peak => peak_db,
+            Self::Rms => rms_db,
+            Self::K12 | Self::K14 => rms_db + SINE_RMS_OFFSET_DB + self.headroom_db(),
+        }
+    }
+
+    fn from_index(index: u8) -> Self {
+        Self::ALL.get(index as usize).copied().unwrap_or_default()
     }
 }
 
-/// Thread-safe metering for input/output levels and gain reduction.
-#[derive(Default)]
-pub struct Meters {
-    input_peak_l: AtomicU32,
-    input_peak_r: AtomicU32,
-    output_peak_l: AtomicU32,
-    output_peak_r: AtomicU32,
-    gain_reduction_l: AtomicU32,
-    gain_reduction_r: AtomicU32,
+/// Everything the audio thread reports for one buffer.
+///
+/// Some debug metrics have no reader yet; they are kept for tuning sessions.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeterSnapshot {
+    // Levels (dBFS, decaying peaks) and leveler gain reduction (dB)
+    pub input_peak_l: f32,
+    pub input_peak_r: f32,
+    pub output_peak_l: f32,
+    pub output_peak_r: f32,
+    /// Held peaks (dBFS, 3 s hold)
+    pub input_hold_l: f32,
+    pub input_hold_r: f32,
+    pub output_hold_l: f32,
+    pub output_hold_r: f32,
+    /// RMS levels (dBFS, `RMS_TAU_SEC`)
+    pub input_rms_l: f32,
+    pub input_rms_r: f32,
+    pub output_rms_l: f32,
+    pub output_rms_r: f32,
+    pub gain_reduction_l: f32,
+    pub gain_reduction_r: f32,
 
     // Debug meters for DSP analysis
     /// Speech confidence from estimator (0.0 - 1.0)
-    debug_speech_confidence: AtomicU32,
+    pub debug_speech_confidence: f32,
     /// De-esser gain reduction in dB (0.0 to ~18.0)
-    debug_deesser_gr_db: AtomicU32,
+    pub debug_deesser_gr_db: f32,
+    /// De-esser tracked sibilance center frequency in Hz
+    pub debug_deesser_freq_hz: f32,
     /// Limiter gain reduction in dB
-    debug_limiter_gr_db: AtomicU32,
+    pub debug_limiter_gr_db: f32,
     /// Early reflection suppression amount (0.0 - 0.35)
-    debug_early_reflection: AtomicU32,
+    pub debug_early_reflection: f32,
     /// Spectral guardrails low-mid cut in dB
-    debug_guardrails_low_cut: AtomicU32,
+    pub debug_guardrails_low_cut: f32,
     /// Spectral guardrails high cut in dB
-    debug_guardrails_high_cut: AtomicU32,
+    pub debug_guardrails_high_cut: f32,
     /// Denoise noise floor estimate in dB
-    debug_noise_floor_db: AtomicU32,
+    pub debug_noise_floor_db: f32,
     /// Speech expander attenuation in dB
-    debug_expander_atten_db: AtomicU32,
+    pub debug_expander_atten_db: f32,
     /// Current hiss reduction in dB
-    hiss_db_current: AtomicU32,
+    pub hiss_db_current: f32,
     /// Current rumble frequency in Hz
-    rumble_hz_current: AtomicU32,
+    pub rumble_hz_current: f32,
+    /// Detected hiss shelf onset in Hz, 0.0 until found
+    pub hiss_onset_hz: f32,
+    /// Detected rumble high-pass corner in Hz, 0.0 without rumble
+    pub rumble_cutoff_hz: f32,
     /// Static noise learn quality (0.0 - 1.0)
-    noise_learn_quality: AtomicU32,
+    pub noise_learn_quality: f32,
+    /// Static noise profile was updated in the last frame (Re-learn or auto learn)
+    pub noise_learn_active: bool,
+    /// Re-learn capture progress towards 2 s of non-speech (0.0 - 1.0)
+    pub noise_capture_progress: f32,
+    /// Re-learn capture: 0 = idle, 1 = capturing, 2 = done, 3 = speech detected,
+    /// 4 = not enough quiet
+    pub noise_capture_status: i32,
+    /// Mic distance estimate from the input reverb metrics (0 close - 1 distant)
+    pub mic_distance: f32,
+    /// Estimated room decay (RT60) in ms, 0.0 until measured
+    pub room_rt60_ms: f32,
+    /// Denoiser musical-noise metric (mean gain change per bin)
+    pub artifact_level: f32,
+    /// Share of the denoise amount removed by the Artifact Guard (0.0 - 0.5)
+    pub artifact_backoff: f32,
+    /// Side share of the stereo energy into the denoiser (0 mono, 0.5 uncorrelated)
+    pub denoise_width_in: f32,
+    /// Side share of the stereo energy out of the denoiser
+    pub denoise_width_out: f32,
+    /// Keyboard/impulse events detected in pauses over the last minute
+    pub transient_events_per_min: f32,
+    /// Detected codec cutoff in Hz, 0.0 for full-band sources
+    pub codec_cutoff_hz: f32,
+    /// Mic alignment: 0 = idle, 1 = measuring, 2 = last attempt found no match
+    pub mic_align_status: i32,
+    /// De-ess auto threshold: 0 = idle, 1 = analysing, 2 = not enough sibilance
+    pub de_ess_auto_status: i32,
+    /// L/R correlation after the limiter, before the mono fix (-1.0 - 1.0)
+    pub stereo_correlation: f32,
+    /// Side gain applied by the mono fix (1.0 = untouched)
+    pub mono_fix_width: f32,
+    /// Total length of pauses over the dead-air threshold (seconds)
+    pub dead_air_sec: f32,
+    /// Pauses that passed the dead-air threshold
+    pub long_pauses: u32,
+    /// Music Guard has detected music in the input
+    pub music_detected: bool,
+    /// De-ess events since the last Reset Analysis
+    pub de_ess_events: u32,
+    /// De-ess events per second of speech
+    pub de_ess_rate: f32,
+    /// Average peak reduction of the de-ess events (dB, positive)
+    pub de_ess_depth_db: f32,
 
     // Layer 1: Resolved Parameters
-    pub(crate) noise_reduction_resolved: AtomicF32,
-    pub(crate) noise_tone_resolved: AtomicF32,
-    pub(crate) deverb_resolved: AtomicF32,
-    pub(crate) clarity_resolved: AtomicF32,
-    pub(crate) deesser_resolved: AtomicF32,
-    pub(crate) proximity_resolved: AtomicF32,
-    pub(crate) leveler_resolved: AtomicF32,
-    pub(crate) breath_reduction_resolved: AtomicF32,
+    pub noise_reduction_resolved: f32,
+    pub noise_tone_resolved: f32,
+    pub deverb_resolved: f32,
+    pub clarity_resolved: f32,
+    pub deesser_resolved: f32,
+    pub proximity_resolved: f32,
+    pub leveler_resolved: f32,
+    pub breath_reduction_resolved: f32,
+
+    // Layer 1b: Applied Parameters (Post-Safeguard)
+    pub noise_reduction_applied: f32,
+    pub deverb_applied: f32,
+    pub clarity_applied: f32,
+    pub deesser_applied: f32,
+    pub proximity_applied: f32,
 
     // Layer 2: Safeguard Interventions
-    pub(crate) loudness_comp_db: AtomicF32,
-    pub(crate) loudness_error_db: AtomicF32,
-    pub(crate) loudness_active: AtomicI32,
-    pub(crate) speech_band_loss_db: AtomicF32,
-    pub(crate) speech_protection_active: AtomicI32,
-    pub(crate) speech_protection_scale: AtomicF32,
-    pub(crate) energy_budget_active: AtomicI32,
-    pub(crate) energy_budget_scale: AtomicF32,
+    pub loudness_comp_db: f32,
+    pub loudness_error_db: f32,
+    pub loudness_active: bool,
+    pub speech_band_loss_db: f32,
+    /// Speech band loss allowed before protection engages (dB, positive)
+    pub speech_loss_budget_db: f32,
+    pub speech_protection_active: bool,
+    pub speech_protection_scale: f32,
+    pub energy_budget_active: bool,
+    pub energy_budget_scale: f32,
 
     // Layer 3: Audible Outcome Metrics
-    pub(crate) output_rms_db: AtomicF32,
-    pub(crate) output_peak_db: AtomicF32,
-    pub(crate) output_crest_db: AtomicF32,
-    pub(crate) total_gain_reduction_db: AtomicF32,
+    pub output_rms_db: f32,
+    pub output_peak_db: f32,
+    pub output_crest_db: f32,
+    pub total_gain_reduction_db: f32,
+    pub input_snr_db: f32,
+    /// Short-term (3 s) output loudness in LUFS
+    pub output_lufs_short: f32,
 
     // Layer 4: Mode Switch Integrity
-    pub(crate) mode_transition_event: AtomicI32,
-    pub(crate) params_hash_before: AtomicU64,
-    pub(crate) params_hash_after: AtomicU64,
-    pub(crate) audible_change_detected: AtomicI32,
-    pub(crate) pre_switch_audible_rms: AtomicF32,
+    pub mode_transition_event: i32,
+    pub params_hash_before: u64,
+    pub params_hash_after: u64,
+    pub audible_change_detected: bool,
+    pub pre_switch_audible_rms: f32,
 
     // Pump detection meters
-    pub(crate) pump_event_count: AtomicI32,
-    pub(crate) pump_severity_db: AtomicF32,
-    pub(crate) compressor_gain_delta_db: AtomicF32,
+    pub pump_event_count: i32,
+    pub pump_severity_db: f32,
+    pub compressor_gain_delta_db: f32,
+    /// Pump guard is holding the leveler back after repeated pumping
+    pub pump_guard_active: bool,
+
+    /// A panic was caught: output is the dry bypass until reinitialized
+    pub dsp_fault: bool,
+    /// Conditions detected in the latest input profile (the editor's badges)
+    pub input_conditions: DetectedConditions,
+    /// Time each condition was detected since load or Reset Analysis
+    pub condition_stats: ConditionStats,
+    /// First stage that produced a non-finite sample (`GuardStage::code`,
+    /// 0 = none) and the number of single-stage resets since
+    pub numeric_fault_stage: u8,
+    pub numeric_fault_count: u32,
+    /// Long-term gain of each stage during speech (dB, `GuardStage` order)
+    pub stage_gain_db: [f32; GuardStage::COUNT],
+
+    /// Speaker profile learning (statistics of the current voice)
+    pub speaker_stats: SpeakerStats,
+
+    /// Dual-speaker detection: -1 = off/one speaker, 0 = A, 1 = B
+    pub active_speaker: i32,
+
+    /// Auto macros: calibration-chosen amounts (0..1)
+    pub macro_auto: AutoMacroAmounts,
+
+    /// A host-picked DSP preset runs amounts the sliders do not show yet
+    pub dsp_preset_pending: bool,
 }
 
-impl Meters {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn set_input_peak_l(&self, val: f32) {
-        self.input_peak_l.store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn set_input_peak_r(&self, val: f32) {
-        self.input_peak_r.store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn set_output_peak_l(&self, val: f32) {
-        self.output_peak_l.store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn set_output_peak_r(&self, val: f32) {
-        self.output_peak_r.store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn set_gain_reduction_l(&self, val: f32) {
-        self.gain_reduction_l
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn set_gain_reduction_r(&self, val: f32) {
-        self.gain_reduction_r
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    pub fn get_input_peak_l(&self) -> f32 {
-        f32::from_bits(self.input_peak_l.load(Ordering::Relaxed))
-    }
-
-    pub fn get_input_peak_r(&self) -> f32 {
-        f32::from_bits(self.input_peak_r.load(Ordering::Relaxed))
-    }
-
-    pub fn get_output_peak_l(&self) -> f32 {
-        f32::from_bits(self.output_peak_l.load(Ordering::Relaxed))
-    }
-
-    pub fn get_output_peak_r(&self) -> f32 {
-        f32::from_bits(self.output_peak_r.load(Ordering::Relaxed))
-    }
-
-    pub fn get_gain_reduction_l(&self) -> f32 {
-        f32::from_bits(self.gain_reduction_l.load(Ordering::Relaxed))
-    }
-
-    pub fn get_gain_reduction_r(&self) -> f32 {
-        f32::from_bits(self.gain_reduction_r.load(Ordering::Relaxed))
-    }
-
-    // =========================================================================
-    // Debug Meters - for DSP analysis and tuning
-    // =========================================================================
-
-    pub fn set_debug_speech_confidence(&self, val: f32) {
-        self.debug_speech_confidence
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_speech_confidence(&self) -> f32 {
-        f32::from_bits(self.debug_speech_confidence.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_deesser_gr_db(&self, val: f32) {
-        self.debug_deesser_gr_db
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_deesser_gr_db(&self) -> f32 {
-        f32::from_bits(self.debug_deesser_gr_db.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_limiter_gr_db(&self, val: f32) {
-        self.debug_limiter_gr_db
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_limiter_gr_db(&self) -> f32 {
-        f32::from_bits(self.debug_limiter_gr_db.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_early_reflection(&self, val: f32) {
-        self.debug_early_reflection
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_early_reflection(&self) -> f32 {
-        f32::from_bits(self.debug_early_reflection.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_guardrails_low_cut(&self, val: f32) {
-        self.debug_guardrails_low_cut
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_guardrails_low_cut(&self) -> f32 {
-        f32::from_bits(self.debug_guardrails_low_cut.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_guardrails_high_cut(&self, val: f32) {
-        self.debug_guardrails_high_cut
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_guardrails_high_cut(&self) -> f32 {
-        f32::from_bits(self.debug_guardrails_high_cut.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_noise_floor_db(&self, val: f32) {
-        self.debug_noise_floor_db
-            .store(val.to_bits(), Ordering::Relaxed);
-    }
-
-    #[allow(dead_code)]
-    pub fn get_debug_noise_floor_db(&self) -> f32 {
-        f32::from_bits(self.debug_noise_floor_db.load(Ordering::Relaxed))
-    }
-
-    pub fn set_debug_expander_atten_db(&self, val: f32) {
-        self.debug_expander_atten_db
-            .store(val.to_bits(), Ordering::Relaxed);
+impl Default for MeterSnapshot {
+    fn default() -> Self {
+        Self {
+            input_peak_l: 0.0,
+            input_peak_r: 0.0,
+            output_peak_l: 0.0,
+            output_peak_r: 0.0,
+            input_hold_l: -80.0,
+            input_hold_r: -80.0,
+            output_hold_l: -80.0,
+            output_hold_r: -80.0,
+            input_rms_l: -80.0,
+            input_rms_r: -80.0,
+            output_rms_l: -80.0,
+            output_rms_r: -80.0,
+            gain_reduction_l: 0.0,
+            gain_reduction_r: 0.0,
+
+            debug_speech_confidence: 0.0,
+            debug_deesser_gr_db: 0.0,
+            debug_deesser_freq_hz: 0.0,
+            debug_limiter_gr_db: 0.0,
+            debug_early_reflection: 0.0,
+            debug_guardrails_low_cut: 0.0,
+            debug_guardrails_high_cut: 0.0,
+            debug_noise_floor_db: 0.0,
+            debug_expander_atten_db: 0.0,
+            hiss_db_current: 0.0,
+            rumble_hz_current: 0.0,
+            hiss_onset_hz: 0.0,
+            rumble_cutoff_hz: 0.0,
+            noise_learn_quality: 0.0,
+            noise_learn_active: false,
+            noise_capture_progress: 0.0,
+            noise_capture_status: 0,
+            mic_distance: 0.5,
+            room_rt60_ms: 0.0,
+            artifact_level: 0.0,
+            artifact_backoff: 0.0,
+            denoise_width_in: 0.0,
+            denoise_width_out: 0.0,
+            transient_events_per_min: 0.0,
+            codec_cutoff_hz: 0.0,
+            mic_align_status: 0,
+            de_ess_auto_status: 0,
+            stereo_correlation: 1.0,
+            mono_fix_width: 1.0,
+            dead_air_sec: 0.0,
+            music_detected: false,
+            long_pauses: 0,
+            de_ess_events: 0,
+            de_ess_rate: 0.0,
+            de_ess_depth_db: 0.0,
+
+            noise_reduction_resolved: 0.0,
+            noise_tone_resolved: 0.0,
+            deverb_resolved: 0.0,
+            clarity_resolved: 0.0,
+            deesser_resolved: 0.0,
+            proximity_resolved: 0.0,
+            leveler_resolved: 0.0,
+            breath_reduction_resolved: 0.0,
+
+            noise_reduction_applied: 0.0,
+            deverb_applied: 0.0,
+            clarity_applied: 0.0,
+            deesser_applied: 0.0,
+            proximity_applied: 0.0,
+
+            loudness_comp_db: 0.0,
+            loudness_error_db: 0.0,
+            loudness_active: false,
+            speech_band_loss_db: 0.0,
+            speech_loss_budget_db: 2.0,
+            speech_protection_active: false,
+            speech_protection_scale: 1.0,
+            energy_budget_active: false,
+            energy_budget_scale: 1.0,
+
+            output_rms_db: -80.0,
+            output_peak_db: -80.0,
+            output_crest_db: 0.0,
+            total_gain_reduction_db: 0.0,
+            input_snr_db: 0.0,
+            output_lufs_short: -70.0,
+
+            mode_transition_event: 0,
+            params_hash_before: 0,
+            params_hash_after: 0,
+            audible_change_detected: false,
+            pre_switch_audible_rms: -80.0,
+
+            pump_event_count: 0,
+            pump_severity_db: 0.0,
+            pump_guard_active: false,
+            dsp_fault: false,
+            input_conditions: DetectedConditions::default(),
+            condition_stats: ConditionStats::default(),
+            numeric_fault_stage: 0,
+            numeric_fault_count: 0,
+            stage_gain_db: [0.0; GuardStage::COUNT],
+            compressor_gain_delta_db: 0.0,
+
+            speaker_stats: SpeakerStats::default(),
+            active_speaker: -1,
+            macro_auto: AutoMacroAmounts::default(),
+            dsp_preset_pending: false,
+        }
     }
+}
 
-    #[allow(dead_code)]
-    pub fn get_debug_expander_atten_db(&self) -> f32 {
-        f32::from_bits(self.debug_expander_atten_db.load(Ordering::Relaxed))
-    }
+/// Shared (reader) side of the meter transport.
+///
+/// Three slots rotate between the writer (back), the hand-over position
+/// (middle) and the reader (front). Each side only ever touches the slot it
+/// owns; ownership changes through swaps of the `middle` index.
+pub struct Meters {
+    slots: [UnsafeCell<MeterSnapshot>; 3],
+    /// Middle slot index, plus `FRESH` when it holds an unread frame
+    middle: AtomicUsize,
+    /// Front slot index (reader-owned)
+    front: Mutex<usize>,
+    /// A reader asked for the peak holds to be cleared
+    peak_hold_reset: AtomicBool,
+    /// Level meter mode (`MeterMode` index), set by the editor
+    mode: AtomicU8,
+}
 
-    #[allow(dead_code)]
-    pub fn set_hiss_db_current(&self, val: f32) {
-        self.hiss_db_current.store(val.to_bits(), Ordering::Relaxed);
-    }
+// SAFETY: a slot is only accessed by the side whose index currently names it.
+// The single `MeterWriter` owns the back index, readers hold the `front`
+// mutex, and the middle slot is never accessed until it is swapped out
+// (Release on hand-over, Acquire on pick-up).
+unsafe impl Sync for Meters {}
 
-    #[allow(dead_code)]
-    pub fn get_hiss_db_current(&self) -> f32 {
-        f32::from_bits(self.hiss_db_current.load(Ordering::Relaxed))
+impl Meters {
+    fn new() -> Self {
+        Self {
+            slots: [
+                UnsafeCell::new(MeterSnapshot::default()),
+                UnsafeCell::new(MeterSnapshot::default()),
+                UnsafeCell::new(MeterSnapshot::default()),
+            ],
+            middle: AtomicUsize::new(1),
+            front: Mutex::new(2),
+            peak_hold_reset: AtomicBool::new(false),
+            mode: AtomicU8::new(MeterMode::Peak as u8),
+        }
     }
 
-    #[allow(dead_code)]
-    pub fn set_rumble_hz_current(&self, val: f32) {
-        self.rumble_hz_current
-            .store(val.to_bits(), Ordering::Relaxed);
+    /// Latest published frame (a coherent copy).
+    pub fn snapshot(&self) -> MeterSnapshot {
+        let mut front = self.front.lock().unwrap_or_else(PoisonError::into_inner);
+        if self.middle.load(Ordering::Relaxed) & FRESH != 0 {
+            let prev = self.middle.swap(*front, Ordering::AcqRel);
+            *front = prev & INDEX_MASK;
+        }
+        // SAFETY: the front slot is owned by the reader holding the lock
+        unsafe { *self.slots[*front].get() }
     }
 
-    #[allow(dead_code)]
-    pub fn get_rumble_hz_current(&self) -> f32 {
-        f32::from_bits(self.rumble_hz_current.load(Ordering::Relaxed))
+    /// Ask the audio thread to clear the level meters' peak holds
+    pub fn reset_peak_hold(&self) {
+        self.peak_hold_reset.store(true, Ordering::Relaxed);
     }
 
-    pub fn set_noise_learn_quality(&self, val: f32) {
-        self.noise_learn_quality
-            .store(val.to_bits(), Ordering::Relaxed);
+    /// Mode the level meters draw in
+    pub fn mode(&self) -> MeterMode {
+        MeterMode::from_index(self.mode.load(Ordering::Relaxed))
     }
 
-    pub fn get_noise_learn_quality(&self) -> f32 {
-        f32::from_bits(self.noise_learn_quality.load(Ordering::Relaxed))
+    pub fn set_mode(&self, mode: MeterMode) {
+        self.mode.store(mode as u8, Ordering::Relaxed);
     }
+}
 
-    // =========================================================================
-    // Pump Detection Meters
-    // =========================================================================
-
-    pub fn increment_pump_event(&self) {
-        self.pump_event_count.fetch_add(1, Ordering::Relaxed);
+impl Default for MeterWriter {
+    fn default() -> Self {
+        Self {
+            shared: Arc::new(Meters::new()),
+            back: 0,
+            frame: MeterSnapshot::default(),
+        }
     }
+}
 
-    #[allow(dead_code)]
-    pub fn get_pump_event_count(&self) -> i32 {
-        self.pump_event_count.load(Ordering::Relaxed)
-    }
+/// Audio-thread side of the meter transport.
+///
+/// Stages the current frame in `frame` and hands it to the readers with
+/// `publish()`. There is exactly one writer per `Meters`.
+pub struct MeterWriter {
+    shared: Arc<Meters>,
+    back: usize,
+    /// Frame being filled for the current buffer (values persist until
+    /// overwritten, like the meters they feed)
+    pub frame: MeterSnapshot,
+}
 
-    pub fn set_pump_severity_db(&self, val: f32) {
-        self.pump_severity_db.store(val, Ordering::Relaxed);
+impl MeterWriter {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    #[allow(dead_code)]
-    pub fn get_pump_severity_db(&self) -> f32 {
-        self.pump_severity_db.load(Ordering::Relaxed)
+    /// Reader handle for the editor and the remote control server
+    pub fn shared(&self) -> Arc<Meters> {
+        self.shared.clone()
     }
 
-    pub fn set_compressor_gain_delta_db(&self, val: f32) {
-        self.compressor_gain_delta_db.store(val, Ordering::Relaxed);
+    /// Hand the staged frame to the readers (one atomic swap).
+    pub fn publish(&mut self) {
+        // SAFETY: the back slot is owned by this (only) writer
+        unsafe {
+            *self.shared.slots[self.back].get() = self.frame;
+        }
+        let prev = self.shared.middle.swap(self.back | FRESH, Ordering::AcqRel);
+        self.back = prev & INDEX_MASK;
     }
 
-    #[allow(dead_code)]
-    pub fn get_compressor_gain_delta_db(&self) -> f32 {
-        self.compressor_gain_delta_db.load(Ordering::Relaxed)
+    /// A reader asked for the peak holds to be cleared since the last call
+    pub fn take_peak_hold_reset(&self) -> bool {
+        self.shared.peak_hold_reset.swap(false, Ordering::Relaxed)
     }
 
-    pub fn reset(&self) {
-        self.input_peak_l.store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.input_peak_r.store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.output_peak_l
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.output_peak_r
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.gain_reduction_l
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.gain_reduction_r
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-
-        self.debug_speech_confidence
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_deesser_gr_db
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_limiter_gr_db
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_early_reflection
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_guardrails_low_cut
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_guardrails_high_cut
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_noise_floor_db
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.debug_expander_atten_db
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-        self.noise_learn_quality
-            .store(0.0f32.to_bits(), Ordering::Relaxed);
-
-        self.noise_reduction_resolved.store(0.0, Ordering::Relaxed);
-        self.noise_tone_resolved.store(0.0, Ordering::Relaxed);
-        self.deverb_resolved.store(0.0, Ordering::Relaxed);
-        self.clarity_resolved.store(0.0, Ordering::Relaxed);
-        self.deesser_resolved.store(0.0, Ordering::Relaxed);
-        self.proximity_resolved.store(0.0, Ordering::Relaxed);
-        self.leveler_resolved.store(0.0, Ordering::Relaxed);
-        self.breath_reduction_resolved.store(0.0, Ordering::Relaxed);
-
-        self.loudness_comp_db.store(0.0, Ordering::Relaxed);
-        self.loudness_error_db.store(0.0, Ordering::Relaxed);
-        self.loudness_active.store(0, Ordering::Relaxed);
-        self.speech_band_loss_db.store(0.0, Ordering::Relaxed);
-        self.speech_protection_active.store(0, Ordering::Relaxed);
-        self.speech_protection_scale.store(1.0, Ordering::Relaxed);
-        self.energy_budget_active.store(0, Ordering::Relaxed);
-        self.energy_budget_scale.store(1.0, Ordering::Relaxed);
-
-        self.output_rms_db.store(-80.0, Ordering::Relaxed);
-        self.output_peak_db.store(-80.0, Ordering::Relaxed);
-        self.output_crest_db.store(0.0, Ordering::Relaxed);
-        self.total_gain_reduction_db.store(0.0, Ordering::Relaxed);
-
-        self.mode_transition_event.store(0, Ordering::Relaxed);
-        self.params_hash_before.store(0, Ordering::Relaxed);
-        self.params_hash_after.store(0, Ordering::Relaxed);
-        self.audible_change_detected.store(0, Ordering::Relaxed);
-        self.pre_switch_audible_rms.store(-80.0, Ordering::Relaxed);
+    /// Restore every meter to its idle value and publish it
+    pub fn reset(&mut self) {
+        self.frame = MeterSnapshot::default();
+        self.publish();
     }
 }
@@ -0,0 +1,61 @@
+//! Persisted Mic Alignment
+//!
+//! The offset and polarity found by the Align button are saved with the
+//! project, so a session reopens already aligned. The offset is kept in
+//! microseconds to survive sample-rate changes, and offset and polarity share
+//! one atomic word so the audio thread can write a result and read it back
+//! every buffer without locking.
+
+use crate::dsp::mic_align::Alignment;
+use nih_plug::params::persist::PersistentField;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const OFFSET_MASK: u64 = 0xFFFF_FFFF;
+const INVERT_BIT: u64 = 1 << 32;
+
+/// Offset in µs (positive = right channel delayed) and right-channel flip
+#[derive(Debug, Default)]
+pub struct MicAlignmentStore(AtomicU64);
+
+impl MicAlignmentStore {
+    fn pack(offset_us: i32, invert_right: bool) -> u64 {
+        (offset_us as u32 as u64) | if invert_right { INVERT_BIT } else { 0 }
+    }
+
+    /// Stored `(offset_us, invert_right)`
+    pub fn get(&self) -> (i32, bool) {
+        let bits = self.0.load(Ordering::Relaxed);
+        ((bits & OFFSET_MASK) as u32 as i32, bits & INVERT_BIT != 0)
+    }
+
+    pub fn store(&self, alignment: Alignment, sample_rate: f32) {
+        let offset_us = (alignment.delay_samples as f32 * 1.0e6 / sample_rate).round() as i32;
+        self.0.store(
+            Self::pack(offset_us, alignment.invert_right),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Stored alignment in samples at `sample_rate`
+    pub fn alignment(&self, sample_rate: f32) -> Alignment {
+        let (offset_us, invert_right) = self.get();
+        Alignment {
+            delay_samples: (offset_us as f32 * 1.0e-6 * sample_rate).round() as i32,
+            invert_right,
+        }
+    }
+}
+
+impl<'a> PersistentField<'a, (i32, bool)> for MicAlignmentStore {
+    fn set(&self, new_value: (i32, bool)) {
+        self.0
+            .store(Self::pack(new_value.0, new_value.1), Ordering::Relaxed);
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&(i32, bool)) -> R,
+    {
+        f(&self.get())
+    }
+}
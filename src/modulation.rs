@@ -0,0 +1,131 @@
+//! Parameter Modulation Matrix
+//!
+//! Contract:
+//! - Each target (Clarity, Proximity, De-Ess) has one route: a source and a
+//!   bipolar depth, both plain parameters
+//! - Offsets are added to the resolved amounts ahead of the safeguards, so
+//!   slew limiting and the inter-module clamps still apply
+//! - Sources update once per buffer and are heavily smoothed; modulation is
+//!   a slow drift, never an envelope follower on individual syllables
+//!
+//! Every source is normalized so that +1 means "the voice needs more help"
+//! (low speech confidence, low SNR, quiet output) and -1 the opposite. With a
+//! positive depth, Clarity therefore rises in uncertain, noisy passages and
+//! relaxes during loud close speech.
+
+use crate::presets::ModSource;
+use crate::VoiceParams;
+use nih_plug::prelude::{EnumParam, FloatParam};
+
+// Source normalization: value at -1 .. value at +1
+/// Speech confidence: confident speech -> -1, uncertain -> +1
+const SPEECH_CONF_HI: f32 = 0.9;
+const SPEECH_CONF_LO: f32 = 0.3;
+/// Input SNR (dB): clean -> -1, noisy -> +1
+const SNR_CLEAN_DB: f32 = 30.0;
+const SNR_NOISY_DB: f32 = 10.0;
+/// Short-term output loudness (LUFS): loud -> -1, quiet -> +1
+const LUFS_LOUD: f32 = -12.0;
+const LUFS_QUIET: f32 = -28.0;
+
+/// Target amount change at 100% depth and full-scale source (slider units)
+const MOD_RANGE: f32 = 0.25;
+/// Source smoothing time constant in seconds
+const SOURCE_TAU_SEC: f32 = 1.5;
+
+/// Parameters that can be modulated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModTarget {
+    Clarity,
+    Proximity,
+    DeEsser,
+}
+
+impl ModTarget {
+    pub fn source<'a>(&self, params: &'a VoiceParams) -> &'a EnumParam<ModSource> {
+        match self {
+            ModTarget::Clarity => &params.mod_clarity_source,
+            ModTarget::Proximity => &params.mod_proximity_source,
+            ModTarget::DeEsser => &params.mod_deess_source,
+        }
+    }
+
+    pub fn depth<'a>(&self, params: &'a VoiceParams) -> &'a FloatParam {
+        match self {
+            ModTarget::Clarity => &params.mod_clarity_depth,
+            ModTarget::Proximity => &params.mod_proximity_depth,
+            ModTarget::DeEsser => &params.mod_deess_depth,
+        }
+    }
+}
+
+/// Amount offsets for the modulated targets (slider units, 0..1 scale)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModOffsets {
+    pub clarity: f32,
+    pub proximity: f32,
+    pub de_esser: f32,
+}
+
+/// Smoothed, normalized modulation sources
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModulationMatrix {
+    speech: f32,
+    snr: f32,
+    loudness: f32,
+}
+
+/// Map `x` from `from` (-1) .. `to` (+1), clamped
+fn normalize(x: f32, from: f32, to: f32) -> f32 {
+    (2.0 * (x - from) / (to - from) - 1.0).clamp(-1.0, 1.0)
+}
+
+impl ModulationMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Feed the end-of-buffer analysis values; `dt_sec` is the buffer length
+    pub fn update(&mut self, speech_conf: f32, snr_db: f32, lufs_short: f32, dt_sec: f32) {
+        let alpha = 1.0 - (-dt_sec.max(0.0) / SOURCE_TAU_SEC).exp();
+        let speech = normalize(speech_conf, SPEECH_CONF_HI, SPEECH_CONF_LO);
+        let snr = normalize(snr_db, SNR_CLEAN_DB, SNR_NOISY_DB);
+        let loudness = normalize(lufs_short, LUFS_LOUD, LUFS_QUIET);
+        self.speech += (speech - self.speech) * alpha;
+        self.snr += (snr - self.snr) * alpha;
+        self.loudness += (loudness - self.loudness) * alpha;
+    }
+
+    /// Current normalized value of `source` (-1..1, 0 when Off)
+    pub fn value(&self, source: ModSource) -> f32 {
+        match source {
+            ModSource::Off => 0.0,
+            ModSource::SpeechConfidence => self.speech,
+            ModSource::Snr => self.snr,
+            ModSource::Loudness => self.loudness,
+        }
+    }
+
+    /// Some route listens to `source`
+    pub fn uses_source(params: &VoiceParams, source: ModSource) -> bool {
+        [ModTarget::Clarity, ModTarget::Proximity, ModTarget::DeEsser]
+            .iter()
+            .any(|target| target.source(params).value() == source)
+    }
+
+    /// Offsets for every target from the routes set in `params`
+    pub fn offsets(&self, params: &VoiceParams) -> ModOffsets {
+        let offset = |target: ModTarget| {
+            self.value(target.source(params).value()) * target.depth(params).value() * MOD_RANGE
+        };
+        ModOffsets {
+            clarity: offset(ModTarget::Clarity),
+            proximity: offset(ModTarget::Proximity),
+            de_esser: offset(ModTarget::DeEsser),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Persisted Denoiser Noise Floor
+//!
+//! The denoiser's adaptive noise floors take a second or two to converge, so
+//! a reopened project would under-process its first sentence. Once the floors
+//! have settled, a decimated copy is saved with the project and seeded back
+//! into the denoiser when it is rebuilt at the same sample rate and window
+//! size. Points are individual atomics behind a seqlock, so the audio thread
+//! can refresh the copy without locking or allocating and a reader never
+//! sees half of one save and half of the next.
+
+use crate::dsp::dsp_denoiser::decimate_floor;
+use nih_plug::params::persist::PersistentField;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{fence, AtomicU32, AtomicU64, Ordering};
+
+/// Saved points across the fine noise floor, DC to Nyquist
+pub const FINE_POINTS: usize = 128;
+/// Saved points across the coarse-analysis noise floor
+pub const COARSE_POINTS: usize = 64;
+
+const RATE_SHIFT: u32 = 32;
+const WIN_MASK: u64 = 0xFFFF_FFFF;
+/// Reads retried while a save is in progress before giving up
+const READ_ATTEMPTS: usize = 64;
+
+/// Noise floor as written to the project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedNoiseFloor {
+    pub sample_rate_hz: u32,
+    pub win_size: u32,
+    pub fine: Vec<f32>,
+    pub coarse: Vec<f32>,
+}
+
+/// Last settled noise floor, empty until the denoiser has converged once
+#[derive(Debug)]
+pub struct NoiseFloorStore {
+    /// Seqlock generation, odd while a save is rewriting the header and points
+    generation: AtomicU32,
+    /// Sample rate (Hz) and window size it was measured at, 0 when empty
+    header: AtomicU64,
+    fine: [AtomicU32; FINE_POINTS],
+    coarse: [AtomicU32; COARSE_POINTS],
+}
+
+impl Default for NoiseFloorStore {
+    fn default() -> Self {
+        Self {
+            generation: AtomicU32::new(0),
+            header: AtomicU64::new(0),
+            fine: std::array::from_fn(|_| AtomicU32::new(0)),
+            coarse: std::array::from_fn(|_| AtomicU32::new(0)),
+        }
+    }
+}
+
+impl NoiseFloorStore {
+    fn header(sample_rate_hz: u32, win_size: u32) -> u64 {
+        ((sample_rate_hz as u64) << RATE_SHIFT) | win_size as u64
+    }
+
+    /// Enter the write side; `false` when another save holds it
+    fn try_begin_write(&self) -> bool {
+        let generation = self.generation.load(Ordering::Relaxed);
+        if generation & 1 == 1
+            || self
+                .generation
+                .compare_exchange(
+                    generation,
+                    generation.wrapping_add(1),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                )
+                .is_err()
+        {
+            return false;
+        }
+        fence(Ordering::Release);
+        true
+    }
+
+    fn end_write(&self) {
+        self.generation.fetch_add(1, Ordering::Release);
+    }
+
+    /// Header and points from one save, `None` when empty or when saves
+    /// kept overlapping the copy
+    fn read(&self) -> Option<(u64, [f32; FINE_POINTS], [f32; COARSE_POINTS])> {
+        let read = |p: &AtomicU32| f32::from_bits(p.load(Ordering::Relaxed));
+        for _ in 0..READ_ATTEMPTS {
+            let before = self.generation.load(Ordering::Acquire);
+            if before & 1 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let header = self.header.load(Ordering::Relaxed);
+            let fine = std::array::from_fn(|j| read(&self.fine[j]));
+            let coarse = std::array::from_fn(|j| read(&self.coarse[j]));
+            fence(Ordering::Acquire);
+            if self.generation.load(Ordering::Relaxed) == before {
+                return (header != 0).then_some((header, fine, coarse));
+            }
+        }
+        None
+    }
+
+    /// Save settled floors measured at `sample_rate` with `win_size` windows.
+    /// Skipped if the host is restoring a saved floor at the same moment; the
+    /// next settled save catches up.
+    pub fn store(&self, sample_rate: f32, win_size: usize, fine: &[f32], coarse: &[f32]) {
+        if !self.try_begin_write() {
+            return;
+        }
+        for (j, point) in self.fine.iter().enumerate() {
+            point.store(
+                decimate_floor(fine, j, FINE_POINTS).to_bits(),
+                Ordering::Relaxed,
+            );
+        }
+        for (j, point) in self.coarse.iter().enumerate() {
+            point.store(
+                decimate_floor(coarse, j, COARSE_POINTS).to_bits(),
+                Ordering::Relaxed,
+            );
+        }
+        self.header.store(
+            Self::header(sample_rate.round() as u32, win_size as u32),
+            Ordering::Relaxed,
+        );
+        self.end_write();
+    }
+
+    /// Saved `(fine, coarse)` points if they were taken at this sample rate
+    /// and window size
+    pub fn load(
+        &self,
+        sample_rate: f32,
+        win_size: usize,
+    ) -> Option<([f32; FINE_POINTS], [f32; COARSE_POINTS])> {
+        let (header, fine, coarse) = self.read()?;
+        (header == Self::header(sample_rate.round() as u32, win_size as u32))
+            .then_some((fine, coarse))
+    }
+
+    /// Forget the saved floor (the room changed)
+    pub fn clear(&self) {
+        self.header.store(0, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> Option<SavedNoiseFloor> {
+        let (header, fine, coarse) = self.read()?;
+        Some(SavedNoiseFloor {
+            sample_rate_hz: (header >> RATE_SHIFT) as u32,
+            win_size: (header & WIN_MASK) as u32,
+            fine: fine.to_vec(),
+            coarse: coarse.to_vec(),
+        })
+    }
+}
+
+impl<'a> PersistentField<'a, Option<SavedNoiseFloor>> for NoiseFloorStore {
+    fn set(&self, new_value: Option<SavedNoiseFloor>) {
+        // Not the audio thread: wait out a save in progress
+        while !self.try_begin_write() {
+            std::hint::spin_loop();
+        }
+        self.header.store(0, Ordering::Relaxed);
+        // Older or hand-edited state with a different point count is ignored
+        let Some(saved) = new_value
+            .filter(|saved| saved.fine.len() == FINE_POINTS && saved.coarse.len() == COARSE_POINTS)
+        else {
+            self.end_write();
+            return;
+        };
+        for (point, v) in self.fine.iter().zip(&saved.fine) {
+            point.store(v.to_bits(), Ordering::Relaxed);
+        }
+        for (point, v) in self.coarse.iter().zip(&saved.coarse) {
+            point.store(v.to_bits(), Ordering::Relaxed);
+        }
+        self.header.store(
+            Self::header(saved.sample_rate_hz, saved.win_size),
+            Ordering::Relaxed,
+        );
+        self.end_write();
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&Option<SavedNoiseFloor>) -> R,
+    {
+        f(&self.get())
+    }
+}
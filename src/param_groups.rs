@@ -0,0 +1,154 @@
+//! Host Parameter Groups
+//!
+//! Hosts with generic editors and control surfaces sort parameters by the
+//! group each one reports in `Params::param_map()`. `VoiceParams` stays a flat
+//! struct, so IDs, saved sessions and every `params.x` access are unchanged;
+//! `GroupedParams` is what the plugin hands to the wrapper and fills in the
+//! group for each ID (Clean, Shape, Dynamics, Output, Macros). A new
+//! parameter needs its ID in `group_for` too; the plugin tests fail on any
+//! ID that reaches the host without a group.
+
+use crate::VoiceParams;
+use nih_plug::prelude::{ParamPtr, Params};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+pub struct GroupedParams(pub Arc<VoiceParams>);
+
+// SAFETY: every `ParamPtr` comes from the wrapped `VoiceParams`, which the
+// `Arc` keeps alive and in place for as long as this struct exists.
+unsafe impl Params for GroupedParams {
+    fn param_map(&self) -> Vec<(String, ParamPtr, String)> {
+        self.0
+            .param_map()
+            .into_iter()
+            .map(|(id, ptr, group)| {
+                let group = group_for(&id).map_or(group, str::to_string);
+                (id, ptr, group)
+            })
+            .collect()
+    }
+
+    fn serialize_fields(&self) -> BTreeMap<String, String> {
+        self.0.serialize_fields()
+    }
+
+    fn deserialize_fields(&self, serialized: &BTreeMap<String, String>) {
+        self.0.deserialize_fields(serialized)
+    }
+}
+
+/// Host group for a parameter ID; unknown IDs stay at the top level
+fn group_for(id: &str) -> Option<&'static str> {
+    Some(match id {
+        "noise_reduction"
+        | "low_cut"
+        | "low_cut_slope"
+        | "low_cut_linear_phase"
+        | "rumble_amount"
+        | "hiss_amount"
+        | "noise_learn_amount"
+        | "noise_learn_trigger"
+        | "noise_learn_clear"
+        | "noise_learn_auto"
+        | "noise_learn_freeze"
+        | "post_noise_hf_bias"
+        | "hidden_tone_fx_bypass"
+        | "low_end_protect"
+        | "denoise_engine"
+        | "noise_character"
+        | "noise_floor_depth"
+        | "noise_learn_time"
+        | "noise_model_freeze"
+        | "denoise_stereo_link"
+        | "speech_loss_budget"
+        | "speech_band_low"
+        | "speech_band_high"
+        | "artifact_guard"
+        | "comfort_noise"
+        | "comfort_noise_level"
+        | "room_tone_bridge"
+        | "music_guard"
+        | "transient_amount"
+        | "transient_sensitivity"
+        | "reverb_reduction"
+        | "deverb_early"
+        | "deverb_tail"
+        | "deverb_character"
+        | "tail_release"
+        | "bandwidth_extend"
+        | "input_balance"
+        | "input_swap"
+        | "input_invert_l"
+        | "input_invert_r"
+        | "test_signal"
+        | "mic_align"
+        | "mic_align_trigger"
+        | "use_ml" => "Clean",
+
+        "clarity"
+        | "clarity_freq"
+        | "clarity_q"
+        | "de_harsh"
+        | "presence_gain"
+        | "presence_freq"
+        | "proximity"
+        | "proximity_track_room" => "Shape",
+
+        "de_esser"
+        | "de_ess_treat"
+        | "de_ess_auto_trigger"
+        | "leveler"
+        | "leveler_target"
+        | "density"
+        | "dynamics_response"
+        | "chain_order"
+        | "guardrails"
+        | "breath_control"
+        | "breath_mode"
+        | "breath_level"
+        | "dead_air_threshold"
+        | "pause_trim"
+        | "pause_level"
+        | "dual_speaker" => "Dynamics",
+
+        "output_gain"
+        | "loudness_comp"
+        | "loudness_comp_trim"
+        | "final_output_preset"
+        | "output_dither"
+        | "output_routing"
+        | "final_stage"
+        | "limiter_character"
+        | "clip_drive"
+        | "clip_ceiling"
+        | "mono_fix"
+        | "safe_live_mode"
+        | "freeze_adaptation"
+        | "bypass"
+        | "bypass_level_match"
+        | "auto_gain_trim"
+        | "quality"
+        | "reset_all"
+        | "reset_analysis"
+        | "dsp_reinit" => "Output",
+
+        "macro_mode"
+        | "macro_clean"
+        | "macro_enhance"
+        | "macro_control"
+        | "macro_clean_auto"
+        | "macro_enhance_auto"
+        | "macro_control_auto"
+        | "macro_write_back"
+        | "dsp_preset"
+        | "mod_clarity_source"
+        | "mod_clarity_depth"
+        | "mod_proximity_source"
+        | "mod_proximity_depth"
+        | "mod_deess_source"
+        | "mod_deess_depth" => "Macros",
+
+        _ => return None,
+    })
+}
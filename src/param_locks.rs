@@ -0,0 +1,91 @@
+//! Parameter Locks
+//!
+//! A locked parameter keeps its hand-set value: macro sync, DSP preset
+//! application and the Reset button skip it, and the audio-thread macro blend
+//! uses the slider value instead of the macro target. Typical use is setting
+//! the de-esser by hand while Easy Mode drives everything else.
+//!
+//! Locks are a bitmask in one atomic so the audio thread can read them
+//! without locking; the mask is persisted with the plugin state.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use nih_plug::params::persist::PersistentField;
+use nih_plug::prelude::{Param, ParamSetter};
+
+/// Parameters that can be locked (the Advanced sliders)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockId {
+    NoiseReduction,
+    Rumble,
+    Hiss,
+    StaticNoise,
+    DeVerb,
+    DeverbEarly,
+    DeverbTail,
+    Clarity,
+    Proximity,
+    DeEsser,
+    Leveler,
+    OutputGain,
+    BreathControl,
+}
+
+impl LockId {
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ParamLocks(AtomicU32);
+
+impl ParamLocks {
+    pub fn is_locked(&self, id: LockId) -> bool {
+        self.bits() & id.bit() != 0
+    }
+
+    pub fn toggle(&self, id: LockId) {
+        self.0.fetch_xor(id.bit(), Ordering::Relaxed);
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl<'a> PersistentField<'a, u32> for ParamLocks {
+    fn set(&self, new_value: u32) {
+        self.0.store(new_value, Ordering::Relaxed);
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&u32) -> R,
+    {
+        f(&self.bits())
+    }
+}
+
+/// Write `param` as one gesture unless `id` is locked or already at `value`
+///
+/// Skipping unchanged values keeps periodic writers (Easy Mode write-back)
+/// from recording a gesture on every tick.
+pub fn set_unlocked<P: Param>(
+    setter: &ParamSetter<'_>,
+    locks: &ParamLocks,
+    id: LockId,
+    param: &P,
+    value: P::Plain,
+) {
+    if locks.is_locked(id) {
+        return;
+    }
+    let normalized = param.preview_normalized(value);
+    if (normalized - param.unmodulated_normalized_value()).abs() < 1e-6 {
+        return;
+    }
+    setter.begin_set_parameter(param);
+    setter.set_parameter_normalized(param, normalized);
+    setter.end_set_parameter(param);
+}
@@ -0,0 +1,148 @@
+//! DSP Preset Override
+//!
+//! A DSP preset picked by the host (automation, or a program change mapped to
+//! the DSP Preset parameter) has to sound with the editor closed and in
+//! offline renders, where nothing can write the sliders: parameter values
+//! only change through the host. The audio thread therefore resolves the
+//! preset's amounts itself, the same way it blends the macro targets, and
+//! the editor only mirrors them onto the sliders when it is open.
+//!
+//! Contract:
+//! - Each field holds the preset value from the switch until its parameter
+//!   moves (the editor's mirror, automation or a hand on the slider); from
+//!   then on the parameter is what runs
+//! - Locked sliders keep their own value, as with the dropdown
+//! - Manual releases every field
+//!
+//! ## Audio Thread Safety
+//! - Fixed-size state, no allocations
+
+use crate::macro_controller::{MacroKind, MacroParams, SimpleMacroTargets};
+use crate::param_locks::LockId;
+use crate::presets::DspPreset;
+use crate::VoiceParams;
+use nih_plug::prelude::{FloatParam, Param};
+
+/// Slider fields, then the three macro dials
+const FIELDS: usize = 10;
+const MACRO_FIELD: usize = 7;
+
+fn field_params(params: &VoiceParams) -> [&FloatParam; FIELDS] {
+    [
+        &params.noise_reduction,
+        &params.reverb_reduction,
+        &params.proximity,
+        &params.clarity,
+        &params.de_esser,
+        &params.leveler,
+        &params.breath_control,
+        &params.macro_clean,
+        &params.macro_enhance,
+        &params.macro_control,
+    ]
+}
+
+/// Lock of each slider field (the macro dials have none)
+const FIELD_LOCKS: [LockId; MACRO_FIELD] = [
+    LockId::NoiseReduction,
+    LockId::DeVerb,
+    LockId::Proximity,
+    LockId::Clarity,
+    LockId::DeEsser,
+    LockId::Leveler,
+    LockId::BreathControl,
+];
+
+fn macro_field(kind: MacroKind) -> usize {
+    MACRO_FIELD
+        + match kind {
+            MacroKind::Clean => 0,
+            MacroKind::Enhance => 1,
+            MacroKind::Control => 2,
+        }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PresetOverride {
+    values: [f32; FIELDS],
+    /// Parameter values when the preset was picked
+    at_switch: [f32; FIELDS],
+    active: [bool; FIELDS],
+}
+
+impl PresetOverride {
+    pub fn clear(&mut self) {
+        self.active = [false; FIELDS];
+    }
+
+    /// Some field still runs a preset value the parameters do not show yet
+    pub fn is_pending(&self) -> bool {
+        self.active.iter().any(|&a| a)
+    }
+
+    /// The host switched to `preset`
+    pub fn start(&mut self, preset: DspPreset, params: &VoiceParams) {
+        self.clear();
+        let Some(v) = preset.get_values() else {
+            return;
+        };
+        self.values = [
+            v.noise_reduction,
+            v.reverb_reduction,
+            v.proximity,
+            v.clarity,
+            v.de_esser,
+            v.leveler,
+            v.breath_control,
+            v.macro_clean,
+            v.macro_enhance,
+            v.macro_control,
+        ];
+        let locks = params.param_locks.as_ref();
+        for (i, param) in field_params(params).iter().enumerate() {
+            self.at_switch[i] = param.value();
+            let locked = i < MACRO_FIELD && locks.is_locked(FIELD_LOCKS[i]);
+            self.active[i] = !locked && self.values[i] != self.at_switch[i];
+        }
+    }
+
+    /// Release the fields whose parameter has moved since the switch
+    pub fn update(&mut self, params: &VoiceParams) {
+        for (i, param) in field_params(params).iter().enumerate() {
+            if self.active[i] && param.value() != self.at_switch[i] {
+                self.active[i] = false;
+            }
+        }
+    }
+
+    /// Slider targets with the preset values still in force
+    pub fn targets(&self, targets: SimpleMacroTargets) -> SimpleMacroTargets {
+        let v = |i: usize, value: f32| {
+            if self.active[i] {
+                self.values[i]
+            } else {
+                value
+            }
+        };
+        SimpleMacroTargets {
+            noise_reduction: v(0, targets.noise_reduction),
+            reverb_reduction: v(1, targets.reverb_reduction),
+            proximity: v(2, targets.proximity),
+            clarity: v(3, targets.clarity),
+            de_esser: v(4, targets.de_esser),
+            leveler: v(5, targets.leveler),
+            breath_control: v(6, targets.breath_control),
+            ..targets
+        }
+    }
+
+    /// Dial value of `kind`, the preset's while it is in force
+    pub fn dial(&self, kind: MacroKind, params: &VoiceParams) -> f32 {
+        let i = macro_field(kind);
+        if self.active[i] {
+            self.values[i]
+        } else {
+            kind.dial(params).value()
+        }
+    }
+}
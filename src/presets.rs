@@ -1,6 +1,8 @@
+use crate::dsp::LimiterRelease;
 use nih_plug::prelude::Enum;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use voice_studio_core::chain::Routing;
 
 // =============================================================================
 // DSP FACTORY PRESETS
@@ -225,6 +227,578 @@ impl OutputPreset {
     }
 }
 
+/// Gain estimator used by the adaptive denoiser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum DenoiseEngine {
+    #[serde(rename = "Standard")]
+    #[name = "Standard"]
+    Standard,
+    /// Last-resort spectral gate for extreme noise (destructive)
+    #[serde(rename = "Rescue")]
+    #[name = "Rescue"]
+    Rescue,
+}
+
+/// Ballistics of the dynamics stage (leveler, expander, breath reducer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum DynamicsResponse {
+    /// Quick, conversational delivery
+    #[serde(rename = "Fast")]
+    #[name = "Fast"]
+    Fast,
+    #[serde(rename = "Natural")]
+    #[name = "Natural"]
+    Natural,
+    /// Slow narration (audiobooks)
+    #[serde(rename = "Relaxed")]
+    #[name = "Relaxed"]
+    Relaxed,
+}
+
+impl DynamicsResponse {
+    pub fn all_modes() -> [DynamicsResponse; 3] {
+        [
+            DynamicsResponse::Fast,
+            DynamicsResponse::Natural,
+            DynamicsResponse::Relaxed,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DynamicsResponse::Fast => "Fast",
+            DynamicsResponse::Natural => "Natural",
+            DynamicsResponse::Relaxed => "Relaxed",
+        }
+    }
+
+    /// Multiplier for attack/release times
+    pub fn time_scale(&self) -> f32 {
+        match self {
+            DynamicsResponse::Fast => 0.5,
+            DynamicsResponse::Natural => 1.0,
+            DynamicsResponse::Relaxed => 2.0,
+        }
+    }
+}
+
+/// How early and how hard the spectral guardrails correct the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum GuardrailStrength {
+    #[serde(rename = "Off")]
+    #[name = "Off"]
+    Off,
+    #[serde(rename = "Normal")]
+    #[name = "Normal"]
+    Normal,
+    /// Steps in earlier and cuts deeper
+    #[serde(rename = "Strict")]
+    #[name = "Strict"]
+    Strict,
+}
+
+impl GuardrailStrength {
+    pub fn all_modes() -> [GuardrailStrength; 3] {
+        [
+            GuardrailStrength::Off,
+            GuardrailStrength::Normal,
+            GuardrailStrength::Strict,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GuardrailStrength::Off => "Off",
+            GuardrailStrength::Normal => "Normal",
+            GuardrailStrength::Strict => "Strict",
+        }
+    }
+
+    /// Threshold divisor and cut multiplier for the guardrails (Off: unused)
+    pub fn strictness(&self) -> f32 {
+        match self {
+            GuardrailStrength::Off | GuardrailStrength::Normal => 1.0,
+            GuardrailStrength::Strict => 1.5,
+        }
+    }
+}
+
+/// Analysis signal driving a modulation route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum ModSource {
+    #[serde(rename = "Off")]
+    #[name = "Off"]
+    Off,
+    #[serde(rename = "Speech Confidence")]
+    #[name = "Speech Confidence"]
+    SpeechConfidence,
+    #[serde(rename = "SNR")]
+    #[name = "SNR"]
+    Snr,
+    #[serde(rename = "Output Loudness")]
+    #[name = "Output Loudness"]
+    Loudness,
+}
+
+impl ModSource {
+    pub fn all_sources() -> [ModSource; 4] {
+        [
+            ModSource::Off,
+            ModSource::SpeechConfidence,
+            ModSource::Snr,
+            ModSource::Loudness,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModSource::Off => "Off",
+            ModSource::SpeechConfidence => "Speech",
+            ModSource::Snr => "SNR",
+            ModSource::Loudness => "Loudness",
+        }
+    }
+}
+
+/// CPU / fidelity trade-off for the spectral stages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum QualityMode {
+    #[serde(rename = "Eco")]
+    #[name = "Eco"]
+    Eco,
+    #[serde(rename = "Normal")]
+    #[name = "Normal"]
+    Normal,
+    #[serde(rename = "High")]
+    #[name = "High"]
+    High,
+}
+
+impl QualityMode {
+    pub fn all_modes() -> [QualityMode; 3] {
+        [QualityMode::Eco, QualityMode::Normal, QualityMode::High]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            QualityMode::Eco => "Eco",
+            QualityMode::Normal => "Normal",
+            QualityMode::High => "High",
+        }
+    }
+
+    /// STFT window of the denoiser, de-verb and static noise stages
+    /// (hop is a quarter window)
+    pub fn fft_size(&self) -> usize {
+        match self {
+            QualityMode::Eco => 1024,
+            QualityMode::Normal => 2048,
+            QualityMode::High => 4096,
+        }
+    }
+
+    /// Secondary coarse-resolution FFT in the denoiser
+    pub fn coarse_analysis(&self) -> bool {
+        !matches!(self, QualityMode::Eco)
+    }
+
+    /// Spectral peaks used to build the denoiser's masking curve
+    pub fn masker_peaks(&self) -> usize {
+        match self {
+            QualityMode::Eco => 16,
+            QualityMode::Normal | QualityMode::High => 64,
+        }
+    }
+
+    /// Output profile analysis runs on one buffer in this many
+    pub fn output_analysis_stride(&self) -> u32 {
+        match self {
+            QualityMode::Eco => 4,
+            QualityMode::Normal | QualityMode::High => 1,
+        }
+    }
+}
+
+/// Output word length for the final dither stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum DitherMode {
+    #[serde(rename = "Off")]
+    #[name = "Off"]
+    Off,
+    #[serde(rename = "16-bit")]
+    #[name = "16-bit"]
+    Bit16,
+    #[serde(rename = "24-bit")]
+    #[name = "24-bit"]
+    Bit24,
+}
+
+impl DitherMode {
+    pub fn all_modes() -> [DitherMode; 3] {
+        [DitherMode::Off, DitherMode::Bit16, DitherMode::Bit24]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DitherMode::Off => "Off",
+            DitherMode::Bit16 => "16-bit",
+            DitherMode::Bit24 => "24-bit",
+        }
+    }
+
+    /// Target word length, `None` when dither is off
+    pub fn bits(&self) -> Option<u32> {
+        match self {
+            DitherMode::Off => None,
+            DitherMode::Bit16 => Some(16),
+            DitherMode::Bit24 => Some(24),
+        }
+    }
+}
+
+/// Speech HPF corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum LowCut {
+    #[serde(rename = "Off")]
+    #[name = "Off"]
+    Off,
+    #[serde(rename = "60 Hz")]
+    #[name = "60 Hz"]
+    Hz60,
+    #[serde(rename = "80 Hz")]
+    #[name = "80 Hz"]
+    Hz80,
+    #[serde(rename = "90 Hz")]
+    #[name = "90 Hz"]
+    Hz90,
+    #[serde(rename = "100 Hz")]
+    #[name = "100 Hz"]
+    Hz100,
+    #[serde(rename = "120 Hz")]
+    #[name = "120 Hz"]
+    Hz120,
+}
+
+impl LowCut {
+    pub fn all_modes() -> [LowCut; 6] {
+        [
+            LowCut::Off,
+            LowCut::Hz60,
+            LowCut::Hz80,
+            LowCut::Hz90,
+            LowCut::Hz100,
+            LowCut::Hz120,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LowCut::Off => "Off",
+            LowCut::Hz60 => "60 Hz",
+            LowCut::Hz80 => "80 Hz",
+            LowCut::Hz90 => "90 Hz",
+            LowCut::Hz100 => "100 Hz",
+            LowCut::Hz120 => "120 Hz",
+        }
+    }
+
+    /// Corner frequency, `None` when the filter is off
+    pub fn hz(&self) -> Option<f32> {
+        match self {
+            LowCut::Off => None,
+            LowCut::Hz60 => Some(60.0),
+            LowCut::Hz80 => Some(80.0),
+            LowCut::Hz90 => Some(90.0),
+            LowCut::Hz100 => Some(100.0),
+            LowCut::Hz120 => Some(120.0),
+        }
+    }
+}
+
+/// Speech HPF slope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum LowCutSlope {
+    #[serde(rename = "12 dB/oct")]
+    #[name = "12 dB/oct"]
+    Db12,
+    #[serde(rename = "24 dB/oct")]
+    #[name = "24 dB/oct"]
+    Db24,
+}
+
+impl LowCutSlope {
+    pub fn all_modes() -> [LowCutSlope; 2] {
+        [LowCutSlope::Db12, LowCutSlope::Db24]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LowCutSlope::Db12 => "12 dB/oct",
+            LowCutSlope::Db24 => "24 dB/oct",
+        }
+    }
+}
+
+/// Last stage before the output gain: the transparent limiter, an
+/// oversampled soft clipper, or the clipper followed by the limiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum FinalStage {
+    #[serde(rename = "Limiter")]
+    #[name = "Limiter"]
+    Limiter,
+    #[serde(rename = "Soft Clip")]
+    #[name = "Soft Clip"]
+    SoftClip,
+    #[serde(rename = "Both")]
+    #[name = "Both"]
+    Both,
+}
+
+impl FinalStage {
+    pub fn all_modes() -> [FinalStage; 3] {
+        [FinalStage::Limiter, FinalStage::SoftClip, FinalStage::Both]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FinalStage::Limiter => "Limiter",
+            FinalStage::SoftClip => "Soft Clip",
+            FinalStage::Both => "Both",
+        }
+    }
+
+    /// The soft clipper runs
+    pub fn clips(&self) -> bool {
+        matches!(self, FinalStage::SoftClip | FinalStage::Both)
+    }
+
+    /// The limiter runs
+    pub fn limits(&self) -> bool {
+        matches!(self, FinalStage::Limiter | FinalStage::Both)
+    }
+}
+
+/// What Breath Control does to breaths between words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum BreathMode {
+    #[serde(rename = "Reduce")]
+    #[name = "Reduce"]
+    Reduce,
+    /// Keep breaths audible at a fixed level, gently low-passed
+    #[serde(rename = "Shape")]
+    #[name = "Shape"]
+    Shape,
+}
+
+/// Release behaviour of the output limiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum LimiterCharacter {
+    /// One slow, even release
+    #[serde(rename = "Transparent")]
+    #[name = "Transparent"]
+    Transparent,
+    /// Dual-stage: quick after isolated peaks, slow after dense passages
+    #[serde(rename = "Smooth")]
+    #[name = "Smooth"]
+    Smooth,
+    /// Fast dual-stage release, denser and louder
+    #[serde(rename = "Aggressive")]
+    #[name = "Aggressive"]
+    Aggressive,
+}
+
+impl LimiterCharacter {
+    pub fn all_modes() -> [LimiterCharacter; 3] {
+        [
+            LimiterCharacter::Transparent,
+            LimiterCharacter::Smooth,
+            LimiterCharacter::Aggressive,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LimiterCharacter::Transparent => "Transparent",
+            LimiterCharacter::Smooth => "Smooth",
+            LimiterCharacter::Aggressive => "Aggressive",
+        }
+    }
+
+    pub fn release(&self) -> LimiterRelease {
+        match self {
+            LimiterCharacter::Transparent => LimiterRelease::TRANSPARENT,
+            LimiterCharacter::Smooth => LimiterRelease::SMOOTH,
+            LimiterCharacter::Aggressive => LimiterRelease::AGGRESSIVE,
+        }
+    }
+}
+
+/// Allowed orders of the shaping and dynamics stages.
+///
+/// Only these combinations exist, so every setting is a tested chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum ChainOrder {
+    /// Proximity before Clarity, De-Esser before Leveler
+    #[serde(rename = "Standard")]
+    #[name = "Standard"]
+    Standard,
+    /// De-Esser after the Leveler, so it follows the leveled sibilance
+    #[serde(rename = "De-Ess Last")]
+    #[name = "De-Ess Last"]
+    DeEssLast,
+    /// Clarity (with De-Harsh) before Proximity, judging the unwarmed voice
+    #[serde(rename = "Clarity First")]
+    #[name = "Clarity First"]
+    ClarityFirst,
+    /// Both swaps
+    #[serde(rename = "Clarity First, De-Ess Last")]
+    #[name = "Clarity First, De-Ess Last"]
+    ClarityFirstDeEssLast,
+}
+
+impl ChainOrder {
+    pub fn all_modes() -> [ChainOrder; 4] {
+        [
+            ChainOrder::Standard,
+            ChainOrder::DeEssLast,
+            ChainOrder::ClarityFirst,
+            ChainOrder::ClarityFirstDeEssLast,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChainOrder::Standard => "Standard",
+            ChainOrder::DeEssLast => "De-Ess Last",
+            ChainOrder::ClarityFirst => "Clarity First",
+            ChainOrder::ClarityFirstDeEssLast => "Clarity First, De-Ess Last",
+        }
+    }
+
+    pub fn clarity_first(&self) -> bool {
+        matches!(
+            self,
+            ChainOrder::ClarityFirst | ChainOrder::ClarityFirstDeEssLast
+        )
+    }
+
+    pub fn de_ess_last(&self) -> bool {
+        matches!(
+            self,
+            ChainOrder::DeEssLast | ChainOrder::ClarityFirstDeEssLast
+        )
+    }
+
+    /// Detector tap shares (clarity first, de-ess last): 0.0 for the
+    /// standard point, 1.0 for the swapped one
+    pub fn taps(&self) -> (f32, f32) {
+        let share = |swapped: bool| if swapped { 1.0 } else { 0.0 };
+        (share(self.clarity_first()), share(self.de_ess_last()))
+    }
+}
+
+/// Built-in signal fed into the chain in place of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum TestSignal {
+    #[serde(rename = "Off")]
+    #[name = "Off"]
+    Off,
+    #[serde(rename = "Pink Noise")]
+    #[name = "Pink Noise"]
+    PinkNoise,
+    #[serde(rename = "Sweep")]
+    #[name = "Sweep"]
+    Sweep,
+    #[serde(rename = "1 kHz Tone")]
+    #[name = "1 kHz Tone"]
+    Tone1k,
+}
+
+impl TestSignal {
+    pub fn all_modes() -> [TestSignal; 4] {
+        [
+            TestSignal::Off,
+            TestSignal::PinkNoise,
+            TestSignal::Sweep,
+            TestSignal::Tone1k,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TestSignal::Off => "Off",
+            TestSignal::PinkNoise => "Pink Noise",
+            TestSignal::Sweep => "Sweep",
+            TestSignal::Tone1k => "1 kHz Tone",
+        }
+    }
+
+    /// Generator signal, `None` when the input plays
+    pub fn kind(&self) -> Option<crate::dsp::TestSignalKind> {
+        match self {
+            TestSignal::Off => None,
+            TestSignal::PinkNoise => Some(crate::dsp::TestSignalKind::PinkNoise),
+            TestSignal::Sweep => Some(crate::dsp::TestSignalKind::Sweep),
+            TestSignal::Tone1k => Some(crate::dsp::TestSignalKind::Tone),
+        }
+    }
+}
+
+/// Output channel routing applied ahead of the limiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[repr(usize)]
+pub enum OutputRouting {
+    #[serde(rename = "Stereo")]
+    #[name = "Stereo"]
+    Stereo,
+    #[serde(rename = "Mono")]
+    #[name = "Mono"]
+    Mono,
+    #[serde(rename = "Left to Both")]
+    #[name = "Left to Both"]
+    LeftToBoth,
+}
+
+impl OutputRouting {
+    pub fn all_routings() -> [OutputRouting; 3] {
+        [
+            OutputRouting::Stereo,
+            OutputRouting::Mono,
+            OutputRouting::LeftToBoth,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputRouting::Stereo => "Stereo",
+            OutputRouting::Mono => "Mono",
+            OutputRouting::LeftToBoth => "Left to Both",
+        }
+    }
+
+    /// The chain routing this runs (Mono uses a -3 dB pan law per channel)
+    pub fn routing(&self) -> Routing {
+        match self {
+            OutputRouting::Stereo => Routing::Stereo,
+            OutputRouting::Mono => Routing::Mono,
+            OutputRouting::LeftToBoth => Routing::LeftToBoth,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PresetValues {
     pub integrated_loudness: Option<f32>,
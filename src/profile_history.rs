@@ -0,0 +1,144 @@
+//! Input Profile History
+//!
+//! Records the input `AudioProfile` about ten times a second into a fixed
+//! ring (the last ten minutes) that the editor can export as CSV. Real
+//! material logged this way is what new `TargetProfile` ranges should be
+//! derived from, instead of tuning the single built-in envelope by ear.
+//!
+//! # Design Notes
+//! - The ring is allocated once; the audio thread only copies a row in,
+//!   under `try_lock()`, and drops the row if the exporter holds the lock
+//! - Export copies the rows out and writes the file on the background
+//!   thread, oldest row first
+//! - Speech confidence goes with each row so pauses can be filtered out
+
+use crate::ui::theme::user_config_dir;
+use crate::AudioProfile;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Time between recorded rows (ms)
+pub const RECORD_INTERVAL_MS: f32 = 100.0;
+
+/// Rows kept (ten minutes at the record interval)
+const CAPACITY: usize = 6000;
+
+const HEADER: &str = "time_s,speech_confidence,rms,peak,crest_factor_db,rms_variance,\
+noise_floor,snr_db,early_late_ratio,decay_slope,reverb_stability,presence_ratio,air_ratio,\
+hf_variance";
+
+/// One recorded input profile
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProfileRow {
+    /// Audio processed since the plugin was loaded (s)
+    pub time_s: f64,
+    pub speech_confidence: f32,
+    pub profile: AudioProfile,
+}
+
+struct Ring {
+    rows: Vec<ProfileRow>,
+    /// Slot the next row goes to
+    next: usize,
+    len: usize,
+}
+
+/// Shared between the audio thread (recording) and the background thread
+/// (export)
+pub struct ProfileHistory {
+    ring: Mutex<Ring>,
+}
+
+impl Default for ProfileHistory {
+    fn default() -> Self {
+        Self {
+            ring: Mutex::new(Ring {
+                rows: vec![ProfileRow::default(); CAPACITY],
+                next: 0,
+                len: 0,
+            }),
+        }
+    }
+}
+
+impl ProfileHistory {
+    /// Append a row, overwriting the oldest when full (audio thread: never
+    /// blocks or allocates)
+    pub fn record(&self, row: ProfileRow) {
+        let Ok(mut ring) = self.ring.try_lock() else {
+            return;
+        };
+        let next = ring.next;
+        ring.rows[next] = row;
+        ring.next = (next + 1) % CAPACITY;
+        ring.len = (ring.len + 1).min(CAPACITY);
+    }
+
+    /// Drop every row (audio thread, as `record()`)
+    pub fn clear(&self) {
+        if let Ok(mut ring) = self.ring.try_lock() {
+            ring.next = 0;
+            ring.len = 0;
+        }
+    }
+
+    /// The recorded rows, oldest first
+    pub fn rows(&self) -> Vec<ProfileRow> {
+        let Ok(ring) = self.ring.lock() else {
+            return Vec::new();
+        };
+        let start = (ring.next + CAPACITY - ring.len) % CAPACITY;
+        (0..ring.len)
+            .map(|i| ring.rows[(start + i) % CAPACITY])
+            .collect()
+    }
+
+    /// The recorded rows as CSV, one header line then one line per row
+    pub fn to_csv(&self) -> String {
+        let rows = self.rows();
+        let mut csv = String::with_capacity(HEADER.len() + 1 + rows.len() * 160);
+        csv.push_str(HEADER);
+        csv.push('\n');
+        for row in &rows {
+            let p = &row.profile;
+            let _ = writeln!(
+                csv,
+                "{:.2},{:.3},{:e},{:e},{:.3},{:e},{:e},{:.3},{:e},{:e},{:.3},{:e},{:e},{:e}",
+                row.time_s,
+                row.speech_confidence,
+                p.rms,
+                p.peak,
+                p.crest_factor_db,
+                p.rms_variance,
+                p.noise_floor,
+                p.snr_db,
+                p.early_late_ratio,
+                p.decay_slope,
+                p.reverb_stability,
+                p.presence_ratio,
+                p.air_ratio,
+                p.hf_variance,
+            );
+        }
+        csv
+    }
+}
+
+/// Per-user folder for exported histories, next to the settings folder
+pub fn history_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("profile history"))
+}
+
+/// Write the history to the next free `Input Profile N.csv`
+pub fn export(history: &ProfileHistory) -> anyhow::Result<PathBuf> {
+    let dir = history_dir().ok_or_else(|| anyhow::anyhow!("No profile history folder"))?;
+    std::fs::create_dir_all(&dir)?;
+
+    let path = (1..)
+        .map(|n| dir.join(format!("Input Profile {}.csv", n)))
+        .find(|p| !p.exists())
+        .expect("unbounded range");
+    std::fs::write(&path, history.to_csv())?;
+    Ok(path)
+}
@@ -0,0 +1,353 @@
+//! Remote Control (feature `remote`)
+//!
+//! Local WebSocket server for broadcast installs: an operator dashboard or a
+//! Stream Deck companion can watch SNR/LUFS and tweak the macros without the
+//! plugin window.
+//!
+//! Protocol (JSON text frames on `ws://127.0.0.1:9470`, port overridable with
+//! `VXCLEANER_REMOTE_PORT`):
+//! - The server pushes a `Status` snapshot every 100 ms
+//! - `{"set": "<param id>", "value": <plain value>}` changes one of the
+//!   parameters listed in `float_param`, answered with `{"ok": true}` once
+//!   queued or `{"error": "..."}`
+//!
+//! # Threading
+//! The server runs on dedicated threads; the audio thread only writes the
+//! atomic meters it already publishes. Parameter changes go through the
+//! host's `GuiContext` so automation, undo and the editor stay in sync, and
+//! that context may only be used on the GUI thread: the client threads
+//! validate a change and queue it in the `RemoteInbox`, and the editor's
+//! timer applies the queue. Changes are therefore accepted while the plugin
+//! window is open; status streaming works at all times.
+//!
+//! Only one instance per machine can own the port; later instances log the
+//! bind failure and run without remote control.
+
+use std::io::ErrorKind;
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use nih_plug::prelude::{nih_log, BoolParam, FloatParam, ParamSetter};
+use serde::{Deserialize, Serialize};
+use tungstenite::{Message, WebSocket};
+
+use crate::meters::Meters;
+use crate::VoiceParams;
+
+pub const DEFAULT_PORT: u16 = 9470;
+const PORT_ENV: &str = "VXCLEANER_REMOTE_PORT";
+
+const STATUS_INTERVAL: Duration = Duration::from_millis(100);
+const ACCEPT_POLL: Duration = Duration::from_millis(100);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Read timeout doubles as the client loop tick
+const READ_TIMEOUT: Duration = Duration::from_millis(20);
+const MAX_CLIENTS: usize = 4;
+/// How often the editor applies queued changes
+pub const APPLY_INTERVAL: Duration = Duration::from_millis(20);
+
+// =============================================================================
+// Inbox
+// =============================================================================
+
+/// Validated changes from the clients, waiting for the editor's timer
+#[derive(Default)]
+pub struct RemoteInbox {
+    pending: Mutex<Vec<(String, f32)>>,
+}
+
+impl RemoteInbox {
+    fn push(&self, id: String, value: f32) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.push((id, value));
+        }
+    }
+
+    /// Drop changes queued while no editor was applying them
+    pub fn clear(&self) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.clear();
+        }
+    }
+
+    /// Apply the queued changes in arrival order. GUI thread only.
+    pub fn apply(&self, params: &VoiceParams, setter: &ParamSetter<'_>) {
+        let pending = match self.pending.lock() {
+            Ok(mut pending) if !pending.is_empty() => std::mem::take(&mut *pending),
+            _ => return,
+        };
+        for (id, value) in pending {
+            if id == "macro_mode" {
+                set_bool(setter, &params.macro_mode, value >= 0.5);
+            } else if let Some(param) = float_param(params, &id) {
+                setter.begin_set_parameter(param);
+                setter.set_parameter(param, value);
+                setter.end_set_parameter(param);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Messages
+// =============================================================================
+
+#[derive(Serialize)]
+struct Status {
+    input_snr_db: f32,
+    output_lufs_short: f32,
+    output_rms_db: f32,
+    output_peak_db: f32,
+    gain_reduction_db: f32,
+    speech_confidence: f32,
+    /// -1 = dual-speaker off or one voice, 0 = A, 1 = B
+    active_speaker: i32,
+    /// Pauses over the dead-air threshold: total seconds and count
+    dead_air_sec: f32,
+    long_pauses: u32,
+    easy_mode: bool,
+    macro_clean: f32,
+    macro_enhance: f32,
+    macro_control: f32,
+    /// Parameter changes are currently accepted
+    can_set: bool,
+}
+
+impl Status {
+    fn capture(params: &VoiceParams, meters: &Meters, can_set: bool) -> Self {
+        let m = meters.snapshot();
+        Self {
+            input_snr_db: m.input_snr_db,
+            output_lufs_short: m.output_lufs_short,
+            output_rms_db: m.output_rms_db,
+            output_peak_db: m.output_peak_db,
+            gain_reduction_db: m.total_gain_reduction_db,
+            speech_confidence: m.debug_speech_confidence,
+            active_speaker: m.active_speaker,
+            dead_air_sec: m.dead_air_sec,
+            long_pauses: m.long_pauses,
+            easy_mode: params.macro_mode.value(),
+            macro_clean: params.macro_clean.value(),
+            macro_enhance: params.macro_enhance.value(),
+            macro_control: params.macro_control.value(),
+            can_set,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Command {
+    set: String,
+    value: f32,
+}
+
+// =============================================================================
+// Server
+// =============================================================================
+
+pub struct RemoteServer {
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RemoteServer {
+    /// Bind the local port and start serving; `None` if the port is taken
+    pub fn start(
+        params: Arc<VoiceParams>,
+        meters: Arc<Meters>,
+        inbox: Arc<RemoteInbox>,
+    ) -> Option<Self> {
+        let port = std::env::var(PORT_ENV)
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(DEFAULT_PORT);
+
+        let listener = match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(listener) => listener,
+            Err(err) => {
+                nih_log!(
+                    "Remote control disabled: cannot bind port {}: {}",
+                    port,
+                    err
+                );
+                return None;
+            }
+        };
+        listener.set_nonblocking(true).ok()?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+        let thread = thread::Builder::new()
+            .name("vxcleaner-remote".into())
+            .spawn(move || accept_loop(listener, params, meters, inbox, thread_shutdown))
+            .ok()?;
+
+        Some(Self {
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for RemoteServer {
+    fn drop(&mut self) {
+        // Client threads see the same flag and exit within one read timeout
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    params: Arc<VoiceParams>,
+    meters: Arc<Meters>,
+    inbox: Arc<RemoteInbox>,
+    shutdown: Arc<AtomicBool>,
+) {
+    let clients = Arc::new(AtomicUsize::new(0));
+
+    while !shutdown.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if clients.load(Ordering::Relaxed) >= MAX_CLIENTS {
+                    continue;
+                }
+                clients.fetch_add(1, Ordering::Relaxed);
+
+                let params = params.clone();
+                let meters = meters.clone();
+                let inbox = inbox.clone();
+                let shutdown = shutdown.clone();
+                let client_count = clients.clone();
+                let spawned = thread::Builder::new()
+                    .name("vxcleaner-remote-client".into())
+                    .spawn(move || {
+                        serve_client(stream, &params, &meters, &inbox, &shutdown);
+                        client_count.fetch_sub(1, Ordering::Relaxed);
+                    });
+                if spawned.is_err() {
+                    clients.fetch_sub(1, Ordering::Relaxed);
+                }
+            }
+            // WouldBlock when idle; other accept errors are transient
+            Err(_) => thread::sleep(ACCEPT_POLL),
+        }
+    }
+}
+
+fn serve_client(
+    stream: TcpStream,
+    params: &VoiceParams,
+    meters: &Meters,
+    inbox: &RemoteInbox,
+    shutdown: &AtomicBool,
+) {
+    // Accepted sockets may inherit non-blocking mode from the listener
+    if stream.set_nonblocking(false).is_err()
+        || stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).is_err()
+    {
+        return;
+    }
+    let mut ws = match tungstenite::accept(stream) {
+        Ok(ws) => ws,
+        Err(_) => return,
+    };
+    if ws.get_ref().set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+        return;
+    }
+
+    let mut last_status: Option<Instant> = None;
+    while !shutdown.load(Ordering::Relaxed) {
+        match ws.read() {
+            Ok(Message::Text(text)) => {
+                let reply = handle_command(&text, params, inbox);
+                if ws.send(Message::Text(reply)).is_err() {
+                    break;
+                }
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => break,
+        }
+
+        if last_status.map_or(true, |t| t.elapsed() >= STATUS_INTERVAL) {
+            last_status = Some(Instant::now());
+            if send_status(&mut ws, params, meters).is_err() {
+                break;
+            }
+        }
+    }
+    let _ = ws.close(None);
+    let _ = ws.flush();
+}
+
+fn send_status(
+    ws: &mut WebSocket<TcpStream>,
+    params: &VoiceParams,
+    meters: &Meters,
+) -> Result<(), tungstenite::Error> {
+    let can_set = params.editor_state.is_open();
+    let status = Status::capture(params, meters, can_set);
+    let text = serde_json::to_string(&status).unwrap_or_default();
+    ws.send(Message::Text(text))
+}
+
+// =============================================================================
+// Parameter Changes
+// =============================================================================
+
+fn handle_command(text: &str, params: &VoiceParams, inbox: &RemoteInbox) -> String {
+    let command: Command = match serde_json::from_str(text) {
+        Ok(command) => command,
+        Err(err) => return error_reply(&format!("invalid command: {}", err)),
+    };
+    if !command.value.is_finite() {
+        return error_reply("value must be a finite number");
+    }
+    if command.set != "macro_mode" && float_param(params, &command.set).is_none() {
+        return error_reply(&format!("unknown or read-only parameter '{}'", command.set));
+    }
+    // Nothing applies the queue while the window is closed
+    if !params.editor_state.is_open() {
+        return error_reply("open the plugin window to enable parameter changes");
+    }
+
+    inbox.push(command.set, command.value);
+    r#"{"ok":true}"#.to_string()
+}
+
+fn error_reply(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// Remotely settable float parameters; values are plain (unnormalized) and
+/// clamped by the parameter's range. `macro_mode` is the one bool.
+fn float_param<'a>(params: &'a VoiceParams, id: &str) -> Option<&'a FloatParam> {
+    Some(match id {
+        "macro_clean" => &params.macro_clean,
+        "macro_enhance" => &params.macro_enhance,
+        "macro_control" => &params.macro_control,
+        "noise_reduction" => &params.noise_reduction,
+        "reverb_reduction" => &params.reverb_reduction,
+        "clarity" => &params.clarity,
+        "proximity" => &params.proximity,
+        "de_esser" => &params.de_esser,
+        "leveler" => &params.leveler,
+        "breath_control" => &params.breath_control,
+        "output_gain" => &params.output_gain,
+        _ => return None,
+    })
+}
+
+fn set_bool(setter: &ParamSetter<'_>, param: &BoolParam, value: bool) {
+    setter.begin_set_parameter(param);
+    setter.set_parameter(param, value);
+    setter.end_set_parameter(param);
+}
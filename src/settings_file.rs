@@ -0,0 +1,124 @@
+//! Shareable Settings Files (`.vxc`)
+//!
+//! Export writes the full plugin state (every parameter plus the persisted
+//! locks, speaker profiles, mic alignment and de-ess threshold) to a small
+//! JSON file, so a podcast team can pass one house cleaning chain between
+//! editors and machines. Editor size, theme and language belong to the
+//! machine, the track name and last editor view to the instance; none of
+//! them are exported or overwritten on import.
+//!
+//! Files live in the per-user settings folder (see `settings_dir`); dropping a
+//! colleague's `.vxc` there makes it show up under Import.
+
+use crate::ui::theme::user_config_dir;
+use nih_plug::prelude::{GuiContext, PluginState};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const EXTENSION: &str = "vxc";
+
+const FORMAT: &str = "vxcleaner-settings";
+const FORMAT_VERSION: u32 = 1;
+
+/// Persisted fields that describe this machine's editor or this instance's
+/// track rather than the chain
+const LOCAL_FIELDS: [&str; 6] = [
+    "editor-state",
+    "editor-view",
+    "ui-theme",
+    "ui-language",
+    "onboarding-seen",
+    "track-name",
+];
+
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    format: String,
+    version: u32,
+    state: PluginState,
+}
+
+/// Drop the machine- and instance-local fields before sharing `state`
+fn strip_local_fields(state: &mut PluginState) {
+    for key in LOCAL_FIELDS {
+        state.fields.remove(key);
+    }
+}
+
+/// Replace the local fields in `state` with the ones from `current`
+fn keep_local_fields(state: &mut PluginState, current: &PluginState) {
+    for key in LOCAL_FIELDS {
+        state.fields.remove(key);
+        if let Some(value) = current.fields.get(key) {
+            state.fields.insert(key.to_string(), value.clone());
+        }
+    }
+}
+
+/// Per-user settings folder, next to the themes folder
+pub fn settings_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("settings"))
+}
+
+/// Names (file stems) of the `.vxc` files in the settings folder, sorted
+pub fn list() -> Vec<String> {
+    let mut names: Vec<String> = settings_dir()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()?.to_str()? != EXTENSION {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// The current state as it is shared (taken on the GUI thread, written by
+/// `export()` on the background thread)
+pub fn shareable_state(gui: &dyn GuiContext) -> PluginState {
+    let mut state = gui.get_state();
+    strip_local_fields(&mut state);
+    state
+}
+
+/// Write `state` to the next free `Settings N.vxc`
+pub fn export(state: PluginState) -> anyhow::Result<PathBuf> {
+    let dir = settings_dir().ok_or_else(|| anyhow::anyhow!("No settings folder"))?;
+    std::fs::create_dir_all(&dir)?;
+
+    let file = SettingsFile {
+        format: FORMAT.to_string(),
+        version: FORMAT_VERSION,
+        state,
+    };
+
+    let path = (1..)
+        .map(|n| dir.join(format!("Settings {}.{}", n, EXTENSION)))
+        .find(|p| !p.exists())
+        .expect("unbounded range");
+    std::fs::write(&path, serde_json::to_string_pretty(&file)?)?;
+    Ok(path)
+}
+
+/// Read `name.vxc` from the settings folder (on the background thread;
+/// `apply()` takes the result on the GUI thread)
+pub fn read(name: &str) -> anyhow::Result<PluginState> {
+    let dir = settings_dir().ok_or_else(|| anyhow::anyhow!("No settings folder"))?;
+    let text = std::fs::read_to_string(dir.join(format!("{}.{}", name, EXTENSION)))?;
+    let file: SettingsFile = serde_json::from_str(&text)?;
+    if file.format != FORMAT || file.version > FORMAT_VERSION {
+        anyhow::bail!("{} is not a supported VxCleaner settings file", name);
+    }
+    Ok(file.state)
+}
+
+/// Apply a shared `state`, keeping this machine's editor size and theme
+pub fn apply(gui: &dyn GuiContext, mut state: PluginState) {
+    keep_local_fields(&mut state, &gui.get_state());
+    gui.set_state(state);
+}
@@ -0,0 +1,141 @@
+//! Background Tasks
+//!
+//! Work that must not run on the audio or GUI thread: allocation, file IO
+//! and network requests. `process()` queues a `Task` with
+//! `execute_background()` (a fixed-size queue, no allocation) and picks up
+//! any result through a `Handover`; the editor queues through a `TaskQueue`.
+//! All tasks run in order on nih-plug's background thread.
+//!
+//! # Design Notes
+//! - STFT stages are rebuilt here on a Quality change or a Reinitialize;
+//!   the audio thread keeps running the old ones until the new set is ready
+//!   and swaps them in with a pointer exchange
+//! - Stages swapped out are sent back as a task, so their buffers are freed
+//!   here too
+//! - Editor tasks carry what they need (a state snapshot taken on the GUI
+//!   thread, a `ContextProxy` for the reply); work whose only result is for
+//!   the editor is skipped once the editor has closed
+
+use crate::noise_floor::NoiseFloorStore;
+use crate::presets::QualityMode;
+use nih_plug::prelude::PluginState;
+use nih_plug_vizia::vizia::prelude::ContextProxy;
+#[cfg(feature = "debug")]
+use std::path::PathBuf;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Arc;
+use voice_studio_core::chain;
+
+/// Jobs for the plugin's background thread (`Plugin::BackgroundTask`)
+pub enum Task {
+    /// Build the STFT stages for `quality` at `sample_rate` and post them to
+    /// the spectral stage handover
+    BuildSpectralStages {
+        quality: QualityMode,
+        sample_rate: f32,
+    },
+    /// Free stages the audio thread has swapped out
+    DropSpectralStages(Box<SpectralStages>),
+    /// Ask GitHub for the latest release and post the result to the editor
+    CheckVersion(ContextProxy),
+    /// Write a `.vxc` settings file from a state snapshot and show its folder
+    ExportSettings(PluginState),
+    /// Write the input profile log as CSV and show its folder
+    ExportProfileHistory,
+    /// Read a `.vxc` settings file and post its state to the editor, which
+    /// applies it
+    ImportSettings { name: String, proxy: ContextProxy },
+    /// Store a state snapshot as the track's profile, then tell the editor
+    /// whether one is stored
+    SaveTrackProfile {
+        track: String,
+        state: PluginState,
+        proxy: ContextProxy,
+    },
+    /// Read the track's stored profile and post it to the editor, which
+    /// applies it
+    RecallTrackProfile { track: String, proxy: ContextProxy },
+    /// Tell the editor whether a profile is stored for the track
+    FindTrackProfile { track: String, proxy: ContextProxy },
+    /// Write the embedded stylesheet to the editable copy in the bundle when
+    /// it is missing, then open it in the system editor if `open` is set
+    #[cfg(feature = "debug")]
+    WriteStylesheet { path: PathBuf, open: bool },
+}
+
+/// Editor side of the task queue (wraps nih-plug's `AsyncExecutor`)
+pub type TaskQueue = Arc<dyn Fn(Task) + Send + Sync>;
+
+/// The chain's STFT stages, built for one Quality window size
+pub struct SpectralStages {
+    pub quality: QualityMode,
+    pub sample_rate: f32,
+    pub stft: chain::SpectralStages,
+}
+
+impl SpectralStages {
+    /// Fresh stages; the denoiser starts from the noise floor saved with the
+    /// project when that was measured at the same rate and window size.
+    pub fn new(quality: QualityMode, sample_rate: f32, noise_floor: &NoiseFloorStore) -> Self {
+        let win = quality.fft_size();
+        let mut stft = chain::SpectralStages::new(win, sample_rate);
+        if let Some((fine, coarse)) = noise_floor.load(sample_rate, win) {
+            stft.denoiser.restore_noise_floors(&fine, &coarse);
+        }
+        Self {
+            quality,
+            sample_rate,
+            stft,
+        }
+    }
+}
+
+/// Single-slot, lock-free mailbox for one boxed value.
+///
+/// `post()` and `take()` are one atomic swap each, so either side may run
+/// on the audio thread. Whoever swaps a value out owns it (and frees it).
+pub struct Handover<T> {
+    slot: AtomicPtr<T>,
+}
+
+impl<T> Handover<T> {
+    pub fn new() -> Self {
+        Self {
+            slot: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Leave `value` for the other side. Returns the previous value if it
+    /// was never taken.
+    pub fn post(&self, value: Box<T>) -> Option<Box<T>> {
+        let prev = self.slot.swap(Box::into_raw(value), Ordering::AcqRel);
+        // SAFETY: non-null pointers in the slot always come from Box::into_raw
+        // and are swapped out exactly once
+        (!prev.is_null()).then(|| unsafe { Box::from_raw(prev) })
+    }
+
+    /// The posted value, if any
+    pub fn take(&self) -> Option<Box<T>> {
+        let value = self.slot.swap(ptr::null_mut(), Ordering::AcqRel);
+        // SAFETY: as in `post()`
+        (!value.is_null()).then(|| unsafe { Box::from_raw(value) })
+    }
+}
+
+impl<T> Default for Handover<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Handover<T> {
+    fn drop(&mut self) {
+        drop(self.take());
+    }
+}
+
+// SAFETY: the value moves between threads as a whole (one swap hands over
+// ownership); it is never shared
+unsafe impl<T: Send> Send for Handover<T> {}
+unsafe impl<T: Send> Sync for Handover<T> {}
@@ -0,0 +1,242 @@
+//! Plugin-level render tests
+//!
+//! These drive the plugin's own `process()` without a host, so what the
+//! plugin adds around the chain (macros, presets, bypass, soft start,
+//! meters) is exercised as the host runs it. `voice_studio_core::Engine`
+//! (and the golden suite built on it) runs the same `VoiceChain`;
+//! `test_engine_matches_plugin` keeps the two sample-identical.
+
+use super::*;
+use crate::dsp::test_signal::LEVEL_DB;
+use crate::dsp::utils::lin_to_db;
+use crate::dsp::TestSignalKind;
+
+const SR: f32 = 48000.0;
+const BLOCK: usize = 512;
+
+/// Host stand-in: background tasks are dropped (no Quality change or
+/// reinitialize happens here) and nothing reads the transport
+struct TestContext;
+
+impl InitContext<VoiceStudioPlugin> for TestContext {
+    fn plugin_api(&self) -> PluginApi {
+        PluginApi::Clap
+    }
+
+    fn execute(&self, _task: Task) {}
+
+    fn set_latency_samples(&self, _samples: u32) {}
+
+    fn set_current_voice_capacity(&self, _capacity: u32) {}
+}
+
+impl ProcessContext<VoiceStudioPlugin> for TestContext {
+    fn plugin_api(&self) -> PluginApi {
+        PluginApi::Clap
+    }
+
+    fn execute_background(&self, _task: Task) {}
+
+    fn execute_gui(&self, _task: Task) {}
+
+    fn transport(&self) -> &Transport {
+        unimplemented!("the plugin does not read the transport")
+    }
+
+    fn next_event(&mut self) -> Option<PluginNoteEvent<VoiceStudioPlugin>> {
+        None
+    }
+
+    fn send_event(&mut self, _event: PluginNoteEvent<VoiceStudioPlugin>) {}
+
+    fn set_latency_samples(&self, _samples: u32) {}
+
+    fn set_current_voice_capacity(&self, _capacity: u32) {}
+}
+
+/// Plugin with default parameters except those `configure` sets, activated
+/// for offline rendering at `SR`
+fn plugin(configure: impl FnOnce(&mut VoiceParams)) -> VoiceStudioPlugin {
+    let mut plugin = VoiceStudioPlugin::default();
+    configure(Arc::get_mut(&mut plugin.params).expect("parameters are not shared yet"));
+    activate(&mut plugin);
+    plugin
+}
+
+/// What a host does when it (re)starts processing, e.g. before a render:
+/// parameter smoothers jump to their values, then initialize and reset
+fn activate(plugin: &mut VoiceStudioPlugin) {
+    for (_, param, _) in plugin.params.param_map() {
+        // SAFETY: the parameters outlive this call
+        unsafe { param.update_smoother(SR, true) };
+    }
+    let config = BufferConfig {
+        sample_rate: SR,
+        min_buffer_size: None,
+        max_buffer_size: BLOCK as u32,
+        process_mode: ProcessMode::Offline,
+    };
+    assert!(plugin.initialize(
+        &VoiceStudioPlugin::AUDIO_IO_LAYOUTS[0],
+        &config,
+        &mut TestContext
+    ));
+    plugin.reset();
+}
+
+/// Process `left`/`right` in place in `BLOCK`-sample host buffers
+fn render(plugin: &mut VoiceStudioPlugin, left: &mut [f32], right: &mut [f32]) {
+    for (l, r) in left.chunks_mut(BLOCK).zip(right.chunks_mut(BLOCK)) {
+        let mut buffer = Buffer::default();
+        // SAFETY: both slices have `l.len()` samples and outlive the buffer
+        unsafe {
+            buffer.set_slices(l.len(), |slices| {
+                slices.clear();
+                slices.push(l);
+                slices.push(r);
+            });
+        }
+        let mut aux = AuxiliaryBuffers {
+            inputs: &mut [],
+            outputs: &mut [],
+        };
+        plugin.process(&mut buffer, &mut aux, &mut TestContext);
+    }
+}
+
+/// `seconds` of a test signal on both channels
+fn test_input(kind: TestSignalKind, seconds: f32, seed: u32) -> (Vec<f32>, Vec<f32>) {
+    let mut generator = TestSignalGenerator::with_seed(SR, seed);
+    let left: Vec<f32> = (0..(seconds * SR) as usize)
+        .map(|_| generator.next(kind))
+        .collect();
+    (left.clone(), left)
+}
+
+#[test]
+fn test_default_chain_passes_a_tone() {
+    let mut plugin = plugin(|_| {});
+    let (mut left, mut right) = test_input(TestSignalKind::Tone, 2.0, 1);
+    render(&mut plugin, &mut left, &mut right);
+
+    assert!(!plugin.dsp_fault);
+    assert!(left
+        .iter()
+        .chain(&right)
+        .all(|v| v.is_finite() && v.abs() <= 1.0));
+    // Past the latency and the soft start the tone comes through, level
+    // moved by the leveler and loudness compensation at most
+    let peak = left[SR as usize..]
+        .iter()
+        .fold(0.0f32, |m, v| m.max(v.abs()));
+    let peak_db = lin_to_db(peak);
+    assert!((peak_db - LEVEL_DB).abs() < 12.0, "{peak_db}");
+}
+
+#[test]
+fn test_engine_matches_plugin() {
+    // Advanced mode at defaults: the amounts the Engine sets are the
+    // sliders' own
+    let mut plugin = plugin(|params| {
+        params.macro_mode = BoolParam::new("Easy Mode", false);
+    });
+    let mut engine = voice_studio_core::Engine::new(SR);
+    assert_eq!(engine.latency_samples() as u32, plugin.reported_latency);
+
+    let (mut left, mut right) = test_input(TestSignalKind::PinkNoise, 3.0, 11);
+    let (mut engine_left, mut engine_right) = (left.clone(), right.clone());
+    render(&mut plugin, &mut left, &mut right);
+    for (l, r) in engine_left
+        .chunks_mut(BLOCK)
+        .zip(engine_right.chunks_mut(BLOCK))
+    {
+        engine.process_block(l, r);
+    }
+
+    // Past the plugin's soft start from dry, sample for sample
+    let start = (SOFT_START_MS * 1e-3 * SR) as usize;
+    assert!(left[start..].iter().any(|v| v.abs() > 1e-4));
+    assert!(left[start..] == engine_left[start..], "left differs");
+    assert!(right[start..] == engine_right[start..], "right differs");
+}
+
+#[test]
+fn test_render_is_repeatable() {
+    let render_noise = || {
+        let mut plugin = plugin(|_| {});
+        let (mut left, mut right) = test_input(TestSignalKind::PinkNoise, 2.0, 7);
+        render(&mut plugin, &mut left, &mut right);
+        (left, right)
+    };
+    let first = render_noise();
+    assert!(first.0.iter().all(|v| v.is_finite()));
+    assert_eq!(first, render_noise());
+}
+
+#[test]
+fn test_frozen_rerender_is_bit_identical() {
+    let mut plugin = plugin(|params| {
+        params.freeze_adaptation = BoolParam::new("Freeze Adaptation", true);
+        params.music_guard = BoolParam::new("Music Guard", true);
+        params.dual_speaker = BoolParam::new("Dual Speaker", true);
+    });
+    let section = test_input(TestSignalKind::PinkNoise, 3.0, 7);
+    let render_section = |plugin: &mut VoiceStudioPlugin| {
+        let (mut left, mut right) = section.clone();
+        render(plugin, &mut left, &mut right);
+        (left, right)
+    };
+
+    let first = render_section(&mut plugin);
+    // Keep playing other material long enough for every adaptive stage to
+    // have moved if it were not frozen, then render the section again
+    let (mut left, mut right) = test_input(TestSignalKind::Sweep, 12.0, 3);
+    render(&mut plugin, &mut left, &mut right);
+    activate(&mut plugin);
+    let second = render_section(&mut plugin);
+
+    assert!(first.0.iter().all(|v| v.is_finite()));
+    assert!(first == second, "frozen re-render differs");
+}
+
+#[test]
+fn test_every_parameter_has_a_host_group() {
+    let plugin = VoiceStudioPlugin::default();
+    let ungrouped: Vec<String> = plugin
+        .params()
+        .param_map()
+        .into_iter()
+        .filter(|(_, _, group)| group.is_empty())
+        .map(|(id, _, _)| id)
+        .collect();
+    assert!(
+        ungrouped.is_empty(),
+        "parameters missing from param_groups: {ungrouped:?}"
+    );
+}
+
+#[test]
+fn test_noise_floor_reads_are_never_torn() {
+    use crate::noise_floor::NoiseFloorStore;
+
+    let store = Arc::new(NoiseFloorStore::default());
+    let writer = {
+        let store = store.clone();
+        std::thread::spawn(move || {
+            for i in 0..20_000 {
+                let level = (i % 7) as f32;
+                store.store(SR, 2048, &[level; 1025], &[level; 257]);
+            }
+        })
+    };
+    // Every save is flat, so any curve mixing two levels was torn
+    while !writer.is_finished() {
+        if let Some((fine, coarse)) = store.load(SR, 2048) {
+            assert!(
+                fine.iter().chain(&coarse).all(|&v| v == fine[0]),
+                "torn read"
+            );
+        }
+    }
+    writer.join().unwrap();
+}
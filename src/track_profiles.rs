@@ -0,0 +1,83 @@
+//! Track Profiles
+//!
+//! Settings saved under a track name ("VO – Sarah") in a small per-user
+//! store, so a new instance put on that track later, in this project or
+//! another, picks up the same chain and speaker profile.
+//!
+//! nih-plug does not pass the host's track name to the plugin, so the name
+//! is entered once per instance and persisted with the project. Entering a
+//! name that has a stored profile recalls it; Save replaces the stored
+//! profile with the current settings. Like `.vxc` files, the stored state
+//! leaves out editor size, theme and the track name itself.
+//!
+//! The store is read and written on the background thread (`Task`); the
+//! editor takes a state snapshot for Save and applies a recalled one.
+
+use crate::ui::theme::user_config_dir;
+use nih_plug::prelude::PluginState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const FORMAT: &str = "vxcleaner-track-profiles";
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct TrackProfiles {
+    format: String,
+    version: u32,
+    tracks: BTreeMap<String, PluginState>,
+}
+
+impl Default for TrackProfiles {
+    fn default() -> Self {
+        Self {
+            format: FORMAT.to_string(),
+            version: FORMAT_VERSION,
+            tracks: BTreeMap::new(),
+        }
+    }
+}
+
+/// Store file, next to the settings and themes folders
+pub fn store_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("track-profiles.json"))
+}
+
+/// Stored profiles; a missing or unreadable store reads as empty
+fn read() -> TrackProfiles {
+    store_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|text| serde_json::from_str::<TrackProfiles>(&text).ok())
+        .filter(|store| store.format == FORMAT && store.version <= FORMAT_VERSION)
+        .unwrap_or_default()
+}
+
+/// A profile is stored for `track`
+pub fn contains(track: &str) -> bool {
+    read().tracks.contains_key(track.trim())
+}
+
+/// Store `state` (a `settings_file::shareable_state()` snapshot) under
+/// `track`, replacing any earlier profile
+pub fn save(state: PluginState, track: &str) -> anyhow::Result<()> {
+    let track = track.trim();
+    if track.is_empty() {
+        anyhow::bail!("No track name");
+    }
+    let path = store_path().ok_or_else(|| anyhow::anyhow!("No settings folder"))?;
+
+    let mut store = read();
+    store.tracks.insert(track.to_string(), state);
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&store)?)?;
+    Ok(())
+}
+
+/// The profile stored for `track`, if any (`settings_file::apply()` applies it)
+pub fn load(track: &str) -> Option<PluginState> {
+    read().tracks.remove(track.trim())
+}
@@ -3,13 +3,23 @@
 //! Provides tab-based UI for detailed parameter control.
 //!
 //! Tabs:
-//! - Clean & Repair: Static and adaptive noise reduction
-//! - Shape & Polish: Proximity and clarity shaping
+//! - Clean & Repair: Static and adaptive noise reduction, dual-mic alignment
+//! - Shape & Polish: Proximity and clarity shaping, codec repair, modulation
+//!   routes, speaker profiles
 
+use crate::dsp::GuardStage;
 use crate::meters::Meters;
-use crate::ui::components::{create_momentary_button, create_slider, create_toggle_button};
-use crate::ui::state::VoiceStudioData;
-use crate::ui::ParamId;
+use crate::modulation::ModTarget;
+use crate::presets::{BreathMode, DenoiseEngine};
+use crate::ui::components::{
+    create_button, create_chain_order_dropdown, create_guardrails_dropdown,
+    create_low_cut_dropdown, create_low_cut_slope_dropdown, create_mod_source_dropdown,
+    create_momentary_button, create_response_dropdown, create_slider, create_toggle_button,
+    live_tooltip,
+};
+use crate::ui::locale::tr;
+use crate::ui::state::{LiveControl, SpeakerProfileEvent, TrackProfileEvent, VoiceStudioData};
+use crate::ui::{ArtifactGuardMeter, ParamId, SpeechLossMeter};
 use crate::VoiceParams;
 use nih_plug::prelude::{GuiContext, ParamSetter};
 use nih_plug_vizia::vizia::prelude::*;
@@ -30,8 +40,48 @@ pub fn build_clean_repair_tab(
         let meters_left = meters_root.clone();
         let params_right = params_root.clone();
         let gui_right = gui_root.clone();
+        let meters_right = meters_root.clone();
         // Column 1: Static Cleanup
         VStack::new(cx, |cx| {
+            create_low_cut_dropdown(cx, "LOW CUT", params_left.clone(), gui_left.clone());
+            create_low_cut_slope_dropdown(cx, "SLOPE", params_left.clone(), gui_left.clone());
+
+            let params_linear = params_left.clone();
+            let gui_linear = gui_left.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.low_cut_linear_phase.value()),
+                move |cx, lens| {
+                    let linear = lens.get(cx);
+                    let p = params_linear.clone();
+                    let g = gui_linear.clone();
+
+                    HStack::new(cx, move |cx| {
+                        create_toggle_button(
+                            cx,
+                            "Linear Phase",
+                            linear,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.low_cut_linear_phase;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !linear);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Runs the low cut as a linear-phase filter: no phase shift around the corner, but 10 ms more latency."),
+                            );
+                        });
+                    })
+                    .class("output-row");
+                },
+            );
+
             create_slider(
                 cx,
                 "Rumble",
@@ -43,7 +93,7 @@ pub fn build_clean_repair_tab(
             .tooltip(|cx| {
                 Label::new(
                     cx,
-                    "Removes low-frequency rumble and vibration below the voice.",
+                    &tr("Removes low-frequency rumble and vibration below the voice."),
                 );
             });
 
@@ -58,7 +108,31 @@ pub fn build_clean_repair_tab(
             .tooltip(|cx| {
                 Label::new(
                     cx,
-                    "Reduces high-frequency hiss and air noise without affecting speech clarity.",
+                    &tr("Reduces high-frequency hiss and air noise without affecting speech clarity."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::noise_corners.map(|&(rumble_hz, hiss_hz)| {
+                    let rumble = if rumble_hz > 0.0 {
+                        format!("{:.0} Hz", rumble_hz)
+                    } else {
+                        "--".to_string()
+                    };
+                    let hiss = if hiss_hz > 0.0 {
+                        format!("{:.1} kHz", hiss_hz / 1000.0)
+                    } else {
+                        "--".to_string()
+                    };
+                    format!("{} {}  {} {}", tr("Rumble below"), rumble, tr("Hiss from"), hiss)
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Corners measured in the pauses: the Rumble high-pass rises no further than the rumble reaches, and the Hiss shelf starts where the hiss does. -- = not found, the default corners apply."),
                 );
             });
 
@@ -74,7 +148,7 @@ pub fn build_clean_repair_tab(
                 .tooltip(|cx| {
                     Label::new(
                         cx,
-                        "Blends learned static noise removal in/out. Learning is automatic when enabled.",
+                        &tr("Blends learned static noise removal in/out. Capture the room tone with Re-learn, or let Auto keep it up to date."),
                     );
                 });
 
@@ -96,7 +170,7 @@ pub fn build_clean_repair_tab(
                         .tooltip(|cx| {
                             Label::new(
                                 cx,
-                                "Clears the profile and latches a short re-learn window during playback.",
+                                &tr("Clears the profile and captures 2 s of non-speech during playback, then stops by itself. The bar shows the capture progress; talking during the capture is flagged."),
                             );
                         });
 
@@ -111,7 +185,7 @@ pub fn build_clean_repair_tab(
                     .class("output-actions");
 
                     VStack::new(cx, |cx| {
-                        Label::new(cx, "Quality").class("mini-label");
+                        Label::new(cx, VoiceStudioData::noise_learn_text).class("mini-label");
                         crate::ui::meters::NoiseLearnQualityMeter::new(cx, meters_actions.clone())
                             .height(Pixels(8.0)) // Slightly taller for visibility
                             .width(Pixels(60.0));
@@ -120,221 +194,1740 @@ pub fn build_clean_repair_tab(
                 })
                 .class("output-row");
 
-            })
-            .class("group-container");
-        })
-        .class("tab-column")
-        .class("adv-column");
-
-        // Column 2: Adaptive Cleanup
-        VStack::new(cx, |cx| {
-            create_slider(
-                cx,
-                "Noise Reduction",
-                params_right.clone(),
-                gui_right.clone(),
-                ParamId::NoiseReduction,
-                |p| &p.noise_reduction,
-            )
-            .tooltip(|cx| {
-                Label::new(
+                let params_auto = params_left.clone();
+                let gui_auto = gui_left.clone();
+                Binding::new(
                     cx,
-                    "Reduces steady background noise using adaptive hybrid suppression.",
-                );
-            });
+                    VoiceStudioData::params
+                        .map(|p| (p.noise_learn_auto.value(), p.noise_learn_freeze.value())),
+                    move |cx, lens| {
+                        let (auto_on, freeze_on) = lens.get(cx);
+                        let p = params_auto.clone();
+                        let g = gui_auto.clone();
 
-            create_slider(
-                cx,
-                "De-Verb",
-                params_right.clone(),
-                gui_right.clone(),
-                ParamId::ReverbReduction,
-                |p| &p.reverb_reduction,
-            )
-            .tooltip(|cx| {
-                Label::new(cx, "Reduces room reflections and resonant coloration.");
-            });
+                        HStack::new(cx, move |cx| {
+                            let p1 = p.clone();
+                            let g1 = g.clone();
+                            create_toggle_button(
+                                cx,
+                                "Auto",
+                                auto_on,
+                                "small-button-active",
+                                "small-button",
+                                move |_| {
+                                    let s = ParamSetter::new(g1.as_ref());
+                                    let param = &p1.noise_learn_auto;
+                                    s.begin_set_parameter(param);
+                                    s.set_parameter(param, !auto_on);
+                                    s.end_set_parameter(param);
+                                },
+                            )
+                            .tooltip(|cx| {
+                                Label::new(
+                                    cx,
+                                    &tr("Keeps refining the noise profile in pauses (after 400 ms without speech), slowly enough that speech never gets learned."),
+                                );
+                            });
+
+                            let p2 = p.clone();
+                            let g2 = g.clone();
+                            create_toggle_button(
+                                cx,
+                                "Freeze",
+                                freeze_on,
+                                "small-button-active",
+                                "small-button",
+                                move |_| {
+                                    let s = ParamSetter::new(g2.as_ref());
+                                    let param = &p2.noise_learn_freeze;
+                                    s.begin_set_parameter(param);
+                                    s.set_parameter(param, !freeze_on);
+                                    s.end_set_parameter(param);
+                                },
+                            )
+                            .tooltip(|cx| {
+                                Label::new(
+                                    cx,
+                                    &tr("Holds the current noise profile: Auto stops updating it until Freeze is released."),
+                                );
+                            });
+                        })
+                        .class("output-actions");
+                    },
+                );
+            })
+            .class("group-container");
 
             create_slider(
                 cx,
-                "Breath Control",
-                params_right.clone(),
-                gui_right.clone(),
-                ParamId::BreathControl,
-                |p| &p.breath_control,
+                "Comfort Level",
+                params_left.clone(),
+                gui_left.clone(),
+                ParamId::ComfortNoiseLevel,
+                |p| &p.comfort_noise_level,
             )
             .tooltip(|cx| {
                 Label::new(
                     cx,
-                    "Automatically attenuates breaths and mouth noise between words.",
+                    &tr("Level of the comfort noise bed relative to the original noise floor."),
                 );
             });
 
-            let params_toggles = params_right.clone();
-            let gui_toggles = gui_right.clone();
+            let params_comfort = params_left.clone();
+            let gui_comfort = gui_left.clone();
             Binding::new(
                 cx,
-                VoiceStudioData::params.map(|p| {
-                    (
-                        p.post_noise_hf_bias.value(),
-                        p.hidden_tone_fx_bypass.value(),
-                        p.low_end_protect.value(),
-                    )
-                }),
+                VoiceStudioData::params.map(|p| p.comfort_noise.value()),
                 move |cx, lens| {
-                    let (hf_bias, bypass_hidden, low_end_protect) = lens.get(cx);
-                    let hidden_on = !bypass_hidden;
-                    let p = params_toggles.clone();
-                    let g = gui_toggles.clone();
+                    let comfort_on = lens.get(cx);
+                    let p = params_comfort.clone();
+                    let g = gui_comfort.clone();
 
                     HStack::new(cx, move |cx| {
-                        let p1 = p.clone();
-                        let g1 = g.clone();
                         create_toggle_button(
                             cx,
-                            "HF Bias",
-                            hf_bias,
+                            "Comfort Noise",
+                            comfort_on,
                             "small-button-active",
                             "small-button",
                             move |_| {
-                                let s = ParamSetter::new(g1.as_ref());
-                                let param = &p1.post_noise_hf_bias;
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.comfort_noise;
                                 s.begin_set_parameter(param);
-                                s.set_parameter(param, !hf_bias);
+                                s.set_parameter(param, !comfort_on);
                                 s.end_set_parameter(param);
                             },
                         )
-                        .class("hf-bias-toggle")
+                        .class("comfort-noise-toggle")
                         .tooltip(|cx| {
                             Label::new(
                                 cx,
-                                "Applies gentle HF-focused cleanup in the post-noise pass.",
+                                &tr("Fills pauses with faint noise shaped like the learned room tone, so heavy reduction doesn't leave dead silence."),
                             );
                         });
+                    })
+                    .class("output-actions");
+                },
+            );
 
-                        let p2 = p.clone();
-                        let g2 = g.clone();
+            let params_bridge = params_left.clone();
+            let gui_bridge = gui_left.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.room_tone_bridge.value()),
+                move |cx, lens| {
+                    let bridge_on = lens.get(cx);
+                    let p = params_bridge.clone();
+                    let g = gui_bridge.clone();
+
+                    HStack::new(cx, move |cx| {
                         create_toggle_button(
                             cx,
-                            "Hidden FX",
-                            hidden_on,
+                            "Room Tone Bridge",
+                            bridge_on,
                             "small-button-active",
                             "small-button",
                             move |_| {
-                                let s = ParamSetter::new(g2.as_ref());
-                                let param = &p2.hidden_tone_fx_bypass;
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.room_tone_bridge;
                                 s.begin_set_parameter(param);
-                                s.set_parameter(param, hidden_on);
+                                s.set_parameter(param, !bridge_on);
                                 s.end_set_parameter(param);
                             },
                         )
-                        .class("hidden-fx-toggle")
+                        .class("room-tone-bridge-toggle")
                         .tooltip(|cx| {
                             Label::new(
                                 cx,
-                                "Hidden tone stages on. Toggle off to bypass (pink bias, recovery, post-cleanup, guardrails).",
+                                &tr("Smooths edits between takes recorded with different room tone: after a cut, the new room is blended in from the old one over a second and a half instead of switching."),
                             );
                         });
+                    })
+                    .class("output-actions");
+                },
+            );
 
-                        let p3 = p.clone();
-                        let g3 = g.clone();
+            let params_music = params_left.clone();
+            let gui_music = gui_left.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.music_guard.value()),
+                move |cx, lens| {
+                    let guard_on = lens.get(cx);
+                    let p = params_music.clone();
+                    let g = gui_music.clone();
+
+                    HStack::new(cx, move |cx| {
                         create_toggle_button(
                             cx,
-                            "Low End",
-                            low_end_protect,
+                            "Music Guard",
+                            guard_on,
                             "small-button-active",
                             "small-button",
                             move |_| {
-                                let s = ParamSetter::new(g3.as_ref());
-                                let param = &p3.low_end_protect;
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.music_guard;
                                 s.begin_set_parameter(param);
-                                s.set_parameter(param, !low_end_protect);
+                                s.set_parameter(param, !guard_on);
                                 s.end_set_parameter(param);
                             },
                         )
-                        .class("low-end-toggle")
+                        .class("music-guard-toggle")
                         .tooltip(|cx| {
                             Label::new(
                                 cx,
-                                "Protects low-end voiced energy inside the denoiser (disable to avoid bass bump).",
+                                &tr("Recognizes music running through the track (intros, stingers, beds): after a few seconds of it, Noise Reduction and De-Verb ease off and the noise models stop learning. A voice coming in brings full cleanup back at once."),
                             );
                         });
+
+                        Label::new(
+                            cx,
+                            VoiceStudioData::music_detected.map(move |&music| {
+                                if !guard_on {
+                                    String::new()
+                                } else if music {
+                                    tr("Music: cleanup eased")
+                                } else {
+                                    tr("No music")
+                                }
+                            }),
+                        )
+                        .class("mini-label");
                     })
                     .class("output-actions");
                 },
             );
-        })
-        .class("tab-column")
-        .class("adv-column");
-    })
-    .class("adv-columns")
-    .class("tab-content")
-    .class("tab-clean-repair")
-}
 
-pub fn build_shape_polish_tab(
-    cx: &mut Context,
-    params: Arc<VoiceParams>,
-    gui: Arc<dyn GuiContext>,
-) -> Handle<'_, HStack> {
-    HStack::new(cx, move |cx| {
-        VStack::new(cx, |cx| {
             create_slider(
                 cx,
-                "Proximity",
-                params.clone(),
-                gui.clone(),
-                ParamId::Proximity,
-                |p| &p.proximity,
+                "Key Clicks",
+                params_left.clone(),
+                gui_left.clone(),
+                ParamId::TransientAmount,
+                |p| &p.transient_amount,
             )
             .tooltip(|cx| {
                 Label::new(
                     cx,
-                    "Adjusts perceived microphone distance and vocal warmth.",
+                    &tr("Ducks keyboard clacks, mouse clicks and desk thumps that land in pauses. Speech is never ducked."),
                 );
             });
 
             create_slider(
                 cx,
-                "Clarity",
-                params.clone(),
-                gui.clone(),
-                ParamId::Clarity,
-                |p| &p.clarity,
+                "Click Sens.",
+                params_left.clone(),
+                gui_left.clone(),
+                ParamId::TransientSensitivity,
+                |p| &p.transient_sensitivity,
             )
             .tooltip(|cx| {
                 Label::new(
                     cx,
-                    "Reduces low-mid muddiness to improve speech definition.",
+                    &tr("How small a sudden jump over the background counts as a click. Raise it for soft typing."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::transient_events.map(|n| format!("{} {:.0} / min", tr("Clicks:"), n)),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Clicks detected in pauses during the last minute (counted even at 0% Key Clicks)."),
                 );
             });
         })
         .class("tab-column")
         .class("adv-column");
 
+        // Column 2: Adaptive Cleanup
         VStack::new(cx, |cx| {
             create_slider(
                 cx,
-                "De-Ess",
-                params.clone(),
-                gui.clone(),
-                ParamId::DeEsser,
-                |p| &p.de_esser,
-            );
+                "Noise Reduction",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::NoiseReduction,
+                |p| &p.noise_reduction,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "Noise Reduction",
+                    "Reduces steady background noise using adaptive hybrid suppression.",
+                    LiveControl::NoiseReduction,
+                );
+            });
 
             create_slider(
                 cx,
-                "Leveler",
-                params.clone(),
-                gui.clone(),
-                ParamId::Leveler,
-                |p| &p.leveler,
-            );
-        })
-        .class("tab-column")
-        .class("adv-column");
-    })
-    .class("adv-columns")
-    .class("tab-content")
-    .class("tab-shape-polish")
+                "Character",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::NoiseCharacter,
+                |p| &p.noise_character,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Which way leftover noise leans. Darker removes more hiss and leaves some rumble; Brighter does the opposite."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Floor Depth",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::NoiseFloorDepth,
+                |p| &p.noise_floor_depth,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("How far the denoiser may pull noise down. Lower is smoother; higher removes more but can warble on difficult material."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Learn Time",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::NoiseLearnTime,
+                |p| &p.noise_learn_time,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("How long the denoiser learns the noise quickly after starting. Longer settles better on noisy openings but can absorb speech that starts straight away."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Stereo Link",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::DenoiseStereoLink,
+                |p| &p.denoise_stereo_link,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("100% treats both channels as one. Lower lets each channel keep its own noise reduction, so wide stereo sources stay wide. No effect on mono."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::denoise_width.map(|&(input, output)| {
+                    format!(
+                        "{} {:.0}% > {:.0}%",
+                        tr("Width:"),
+                        input * 100.0,
+                        output * 100.0
+                    )
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Stereo width into and out of the denoiser (side share of the energy: 0% mono, 50% fully decorrelated). A large drop means the image is collapsing; lower Stereo Link."),
+                );
+            });
+
+            let params_freeze = params_right.clone();
+            let gui_freeze = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.noise_model_freeze.value()),
+                move |cx, lens| {
+                    let frozen = lens.get(cx);
+                    let p = params_freeze.clone();
+                    let g = gui_freeze.clone();
+
+                    HStack::new(cx, move |cx| {
+                        create_toggle_button(
+                            cx,
+                            "Freeze Noise Model",
+                            frozen,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.noise_model_freeze;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !frozen);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("noise-freeze-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Stops the denoiser adapting its noise estimate. Turn on (or automate) over music beds and long non-speech passages so they are not learned as noise."),
+                            );
+                        });
+                    })
+                    .class("output-row");
+                },
+            );
+
+            create_slider(
+                cx,
+                "De-Verb",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::ReverbReduction,
+                |p| &p.reverb_reduction,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "De-Verb",
+                    "Reduces room reflections and resonant coloration.",
+                    LiveControl::DeVerb,
+                );
+            });
+
+            create_slider(
+                cx,
+                "Early Refl.",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::DeverbEarly,
+                |p| &p.deverb_early,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Share of De-Verb applied to short reflections that make a room sound boxy."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Room Tail",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::DeverbTail,
+                |p| &p.deverb_tail,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Share of De-Verb applied to the late room decay between words."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Tail Character",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::DeverbCharacter,
+                |p| &p.deverb_character,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("0% Tight: removes the room tail as fast as it can. Towards 100% Natural: keeps some low-frequency tail and lets it fade out, so the room sounds smaller rather than gated."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Tail Release",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::TailRelease,
+                |p| &p.tail_release,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("How long the pause expander waits before closing after a word. It already follows the speech pace (longer in fast dialogue, shorter in slow narration); this scales it from 50% to 200%."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::room_rt60_ms.map(|ms| {
+                    if *ms > 0.0 {
+                        format!("{} {:.0} ms", tr("Room RT60:"), ms)
+                    } else {
+                        tr("Room RT60: --")
+                    }
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Estimated room decay time. Room Tail adapts to it so the same setting suits small and large rooms."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Breath Control",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::BreathControl,
+                |p| &p.breath_control,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "Breath Control",
+                    "Automatically attenuates breaths and mouth noise between words.",
+                    LiveControl::BreathControl,
+                );
+            });
+
+            let params_breath = params_right.clone();
+            let gui_breath = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.breath_mode.value() == BreathMode::Shape),
+                move |cx, lens| {
+                    let shape_on = lens.get(cx);
+                    let p = params_breath.clone();
+                    let g = gui_breath.clone();
+
+                    HStack::new(cx, move |cx| {
+                        create_toggle_button(
+                            cx,
+                            "Breath Shape",
+                            shape_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.breath_mode;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(
+                                    param,
+                                    if shape_on {
+                                        BreathMode::Reduce
+                                    } else {
+                                        BreathMode::Shape
+                                    },
+                                );
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("breath-shape-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Keep breaths but make them consistent: breaths louder than Breath Level are brought down to it and softened, quieter ones are left alone. Breath Control sets how firmly."),
+                            );
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            create_slider(
+                cx,
+                "Breath Level",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::BreathLevel,
+                |p| &p.breath_level,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Peak level Breath Shape holds breaths at. No effect in the default Reduce mode."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Dead Air After",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::DeadAirThreshold,
+                |p| &p.dead_air_threshold,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Pauses longer than this count as dead air (and are the ones Pause Trim acts on)."),
+                );
+            });
+
+            let params_pause = params_right.clone();
+            let gui_pause = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.pause_trim.value()),
+                move |cx, lens| {
+                    let trim_on = lens.get(cx);
+                    let p = params_pause.clone();
+                    let g = gui_pause.clone();
+
+                    HStack::new(cx, move |cx| {
+                        create_toggle_button(
+                            cx,
+                            "Pause Trim",
+                            trim_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g.as_ref());
+                                let param = &p.pause_trim;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !trim_on);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("pause-trim-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Audiobook mode: eases long pauses down to the Pause Level room tone over about a second, and lets go as soon as speech returns."),
+                            );
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            create_slider(
+                cx,
+                "Pause Level",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::PauseLevel,
+                |p| &p.pause_level,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Room tone level (RMS) that Pause Trim aims for. Quieter pauses are left alone."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::dead_air.map(|&(sec, pauses)| {
+                    format!(
+                        "{} {}:{:02} ({} {})",
+                        tr("Dead air:"),
+                        sec / 60,
+                        sec % 60,
+                        pauses,
+                        tr("pauses")
+                    )
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Total length of pauses over the threshold since the plugin started or Reset Analysis was pressed."),
+                );
+            });
+
+            let params_toggles = params_right.clone();
+            let gui_toggles = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| {
+                    (
+                        p.post_noise_hf_bias.value(),
+                        p.hidden_tone_fx_bypass.value(),
+                        p.low_end_protect.value(),
+                    )
+                }),
+                move |cx, lens| {
+                    let (hf_bias, bypass_hidden, low_end_protect) = lens.get(cx);
+                    let hidden_on = !bypass_hidden;
+                    let p = params_toggles.clone();
+                    let g = gui_toggles.clone();
+
+                    HStack::new(cx, move |cx| {
+                        let p1 = p.clone();
+                        let g1 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "HF Bias",
+                            hf_bias,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g1.as_ref());
+                                let param = &p1.post_noise_hf_bias;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !hf_bias);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("hf-bias-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Applies gentle HF-focused cleanup in the post-noise pass."),
+                            );
+                        });
+
+                        let p2 = p.clone();
+                        let g2 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Hidden FX",
+                            hidden_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g2.as_ref());
+                                let param = &p2.hidden_tone_fx_bypass;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, hidden_on);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("hidden-fx-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Hidden tone stages on. Toggle off to bypass (pink bias, recovery, post-cleanup, guardrails)."),
+                            );
+                        });
+
+                        let p3 = p.clone();
+                        let g3 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Low End",
+                            low_end_protect,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g3.as_ref());
+                                let param = &p3.low_end_protect;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !low_end_protect);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("low-end-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Protects low-end voiced energy inside the denoiser (disable to avoid bass bump)."),
+                            );
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            let params_guard = params_right.clone();
+            let gui_guard = gui_right.clone();
+            let meters_guard = meters_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| {
+                    (
+                        p.artifact_guard.value(),
+                        p.denoise_engine.value() == DenoiseEngine::Rescue,
+                    )
+                }),
+                move |cx, lens| {
+                    let (guard_on, rescue_on) = lens.get(cx);
+                    let p = params_guard.clone();
+                    let g = gui_guard.clone();
+                    let m = meters_guard.clone();
+
+                    HStack::new(cx, move |cx| {
+                        let p1 = p.clone();
+                        let g1 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Artifact Guard",
+                            guard_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g1.as_ref());
+                                let param = &p1.artifact_guard;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !guard_on);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("artifact-guard-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Backs off noise reduction while the denoiser's gains flicker (musical noise / warbling)."),
+                            );
+                        });
+
+                        VStack::new(cx, |cx| {
+                            Label::new(cx, &tr("Artifacts")).class("mini-label");
+                            ArtifactGuardMeter::new(cx, m.clone())
+                                .height(Pixels(8.0))
+                                .width(Pixels(60.0));
+                        })
+                        .class("artifact-meter-container")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Musical-noise activity. Amber while the guard is reducing the denoise amount."),
+                            );
+                        });
+
+                        let p2 = p.clone();
+                        let g2 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Rescue",
+                            rescue_on,
+                            "rescue-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g2.as_ref());
+                                let param = &p2.denoise_engine;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(
+                                    param,
+                                    if rescue_on {
+                                        DenoiseEngine::Standard
+                                    } else {
+                                        DenoiseEngine::Rescue
+                                    },
+                                );
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("rescue-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Last resort for extreme noise (street, construction): hard spectral gating instead of the standard denoiser. Destructive - expect a gated sound. Speech protection still applies."),
+                            );
+                        });
+                    })
+                    .class("output-row");
+                },
+            );
+
+            let meters_loss = meters_right.clone();
+            HStack::new(cx, move |cx| {
+                Label::new(cx, &tr("Speech Loss")).class("mini-label");
+                SpeechLossMeter::new(cx, meters_loss.clone())
+                    .height(Pixels(8.0))
+                    .width(Pixels(80.0));
+                Label::new(
+                    cx,
+                    VoiceStudioData::speech_loss
+                        .map(|&(loss, budget)| format!("-{:.1} / {:.1} dB", loss, budget)),
+                )
+                .class("speech-loss-readout");
+            })
+            .class("speech-loss-row")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Speech-band level (Band Low to Band High) lost through denoise and de-verb, against the Loss Budget (tick). Amber while noise reduction and de-verb are being scaled back to protect the voice."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Loss Budget",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::SpeechLossBudget,
+                |p| &p.speech_loss_budget,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("How much speech-band level denoise and de-verb may remove before they are scaled back. Lower protects the voice sooner; higher allows deeper cleanup."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Band Low",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::SpeechBandLow,
+                |p| &p.speech_band_low,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Lower edge of the protected speech band. Lower it to 120-150 Hz for deep voices, so the chest energy they carry below 300 Hz counts towards the Loss Budget."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Band High",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::SpeechBandHigh,
+                |p| &p.speech_band_high,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Upper edge of the protected speech band."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Balance",
+                params_right.clone(),
+                gui_right.clone(),
+                ParamId::InputBalance,
+                |p| &p.input_balance,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Level trim between the input channels, applied before anything else. Positive raises R and lowers L; use it when one recorder channel came in hotter."),
+                );
+            });
+
+            let params_input = params_right.clone();
+            let gui_input = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| {
+                    (
+                        p.input_swap.value(),
+                        p.input_invert_l.value(),
+                        p.input_invert_r.value(),
+                    )
+                }),
+                move |cx, lens| {
+                    let (swap, invert_l, invert_r) = lens.get(cx);
+                    let p = params_input.clone();
+                    let g = gui_input.clone();
+
+                    HStack::new(cx, move |cx| {
+                        let p1 = p.clone();
+                        let g1 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Swap L/R",
+                            swap,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g1.as_ref());
+                                let param = &p1.input_swap;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !swap);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .tooltip(|cx| {
+                            Label::new(cx, &tr("Exchanges the left and right input channels."));
+                        });
+
+                        let p2 = p.clone();
+                        let g2 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Ø L",
+                            invert_l,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g2.as_ref());
+                                let param = &p2.input_invert_l;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !invert_l);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .tooltip(|cx| {
+                            Label::new(cx, &tr("Flips the polarity of the left input."));
+                        });
+
+                        let p3 = p.clone();
+                        let g3 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Ø R",
+                            invert_r,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g3.as_ref());
+                                let param = &p3.input_invert_r;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !invert_r);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .tooltip(|cx| {
+                            Label::new(cx, &tr("Flips the polarity of the right input."));
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            let params_align = params_right.clone();
+            let gui_align = gui_right.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.mic_align.value()),
+                move |cx, lens| {
+                    let align_on = lens.get(cx);
+                    let p = params_align.clone();
+                    let g = gui_align.clone();
+
+                    HStack::new(cx, move |cx| {
+                        let p1 = p.clone();
+                        let g1 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Mic Align",
+                            align_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g1.as_ref());
+                                let param = &p1.mic_align;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !align_on);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .class("mic-align-toggle")
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("For two mics on one voice recorded as L/R: applies the measured delay and polarity to line the channels up before processing."),
+                            );
+                        });
+
+                        create_momentary_button(cx, "Align", p.clone(), g.clone(), |p| {
+                            &p.mic_align_trigger
+                        })
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Measures the offset (up to 10 ms) and polarity between L and R. Press during playback while someone is talking."),
+                            );
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            Label::new(cx, VoiceStudioData::mic_align_text)
+                .class("mini-label")
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Stored alignment, saved with the project: which channel is delayed, by how much, and whether R is flipped."),
+                    );
+                });
+        })
+        .class("tab-column")
+        .class("adv-column");
+    })
+    .class("adv-columns")
+    .class("tab-content")
+    .class("tab-clean-repair")
+}
+
+pub fn build_shape_polish_tab(
+    cx: &mut Context,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'_, HStack> {
+    HStack::new(cx, move |cx| {
+        VStack::new(cx, |cx| {
+            create_slider(
+                cx,
+                "Proximity",
+                params.clone(),
+                gui.clone(),
+                ParamId::Proximity,
+                |p| &p.proximity,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "Proximity",
+                    "Adjusts perceived microphone distance and vocal warmth.",
+                    LiveControl::Proximity,
+                );
+            });
+
+            let params_track = params.clone();
+            let gui_track = gui.clone();
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.proximity_track_room.value()),
+                move |cx, lens| {
+                    let track_on = lens.get(cx);
+                    let p = params_track.clone();
+                    let g = gui_track.clone();
+
+                    HStack::new(cx, move |cx| {
+                        let p1 = p.clone();
+                        let g1 = g.clone();
+                        create_toggle_button(
+                            cx,
+                            "Track Room",
+                            track_on,
+                            "small-button-active",
+                            "small-button",
+                            move |_| {
+                                let s = ParamSetter::new(g1.as_ref());
+                                let param = &p1.proximity_track_room;
+                                s.begin_set_parameter(param);
+                                s.set_parameter(param, !track_on);
+                                s.end_set_parameter(param);
+                            },
+                        )
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Scales Proximity by the detected mic distance: up to 1.5x warmth on a distant mic, down to half on a close one."),
+                            );
+                        });
+                    })
+                    .class("output-actions");
+                },
+            );
+
+            Label::new(
+                cx,
+                VoiceStudioData::mic_distance.map(|d| {
+                    let place = if *d < 0.33 {
+                        "close"
+                    } else if *d < 0.66 {
+                        "medium"
+                    } else {
+                        "distant"
+                    };
+                    format!("{} {} ({:.0}%)", tr("Mic:"), tr(place), d * 100.0)
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Distance read from the input's early/late energy ratio and decay slope during speech."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Clarity",
+                params.clone(),
+                gui.clone(),
+                ParamId::Clarity,
+                |p| &p.clarity,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "Clarity",
+                    "Reduces low-mid muddiness to improve speech definition.",
+                    LiveControl::Clarity,
+                );
+            });
+
+            create_slider(
+                cx,
+                "Clarity Freq",
+                params.clone(),
+                gui.clone(),
+                ParamId::ClarityFreq,
+                |p| &p.clarity_freq,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Center of the low-mid band Clarity cuts. Lower for boomy rooms, higher for boxy ones."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Clarity Q",
+                params.clone(),
+                gui.clone(),
+                ParamId::ClarityQ,
+                |p| &p.clarity_q,
+            )
+            .tooltip(|cx| {
+                Label::new(cx, &tr("Width of the Clarity cut. Higher values make it narrower."));
+            });
+
+            create_slider(
+                cx,
+                "De-Harsh",
+                params.clone(),
+                gui.clone(),
+                ParamId::DeHarsh,
+                |p| &p.de_harsh,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Tames the 2.5-5 kHz glare of budget USB mics, only while that band is louder than a natural voice tilt. The Presence lift backs off while it is working."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Presence",
+                params.clone(),
+                gui.clone(),
+                ParamId::PresenceGain,
+                |p| &p.presence_gain,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Optional lift in the 2-5 kHz presence band, applied only while speech is detected. 0 dB is off."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Presence Freq",
+                params.clone(),
+                gui.clone(),
+                ParamId::PresenceFreq,
+                |p| &p.presence_freq,
+            )
+            .tooltip(|cx| {
+                Label::new(cx, &tr("Center of the presence lift."));
+            });
+
+            create_slider(
+                cx,
+                "Codec Repair",
+                params.clone(),
+                gui.clone(),
+                ParamId::CodecRepair,
+                |p| &p.bandwidth_extend,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("For Zoom-quality guests: adds gentle high band above the detected codec cutoff and calms swishy highs. Speech only."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::codec_cutoff_hz.map(|hz| {
+                    if *hz > 0.0 {
+                        format!("{} {:.1} kHz", tr("Cutoff:"), hz / 1000.0)
+                    } else {
+                        tr("Cutoff: full band")
+                    }
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Band limit detected in the speech. Synthesis only runs when a cutoff is found."),
+                );
+            });
+        })
+        .class("tab-column")
+        .class("adv-column");
+
+        VStack::new(cx, |cx| {
+            create_slider(
+                cx,
+                "De-Ess",
+                params.clone(),
+                gui.clone(),
+                ParamId::DeEsser,
+                |p| &p.de_esser,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "De-Ess",
+                    "Limits harsh sibilance in the band where the current speaker's S sounds sit.",
+                    LiveControl::DeEsser,
+                );
+            });
+
+            create_slider(
+                cx,
+                "Treat",
+                params.clone(),
+                gui.clone(),
+                ParamId::DeEssTreat,
+                |p| &p.de_ess_treat,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Share of sibilant events the Auto threshold lets the de-esser act on. Set before pressing Auto."),
+                );
+            });
+
+            HStack::new(cx, |cx| {
+                create_momentary_button(cx, "Auto", params.clone(), gui.clone(), |p| {
+                    &p.de_ess_auto_trigger
+                })
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Analyses the next 10 s of program and sets the de-ess threshold from the measured sibilance. Press during playback."),
+                    );
+                });
+            })
+            .class("output-actions");
+
+            Label::new(cx, VoiceStudioData::de_ess_auto_text)
+                .class("mini-label")
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Threshold shift found by Auto, saved with the project, and the 95th-percentile sibilance level relative to the voice."),
+                    );
+                });
+
+            Label::new(
+                cx,
+                VoiceStudioData::de_ess_events.map(|&(events, rate, depth)| {
+                    format!(
+                        "{} {} ({:.1}/s, {} {:.1} dB)",
+                        tr("De-ess events:"),
+                        events,
+                        rate,
+                        tr("avg"),
+                        depth
+                    )
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("How often the de-esser acted (events per second of speech) and how deep on average, since the plugin started or Reset Analysis was pressed. Play the whole episode to check a setting."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::de_ess_center_hz
+                    .map(|hz| format!("{} {:.0} Hz", tr("De-ess band:"), hz)),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Where the de-esser's band sits now. It follows this speaker's sibilance between 5 and 9 kHz."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Leveler",
+                params.clone(),
+                gui.clone(),
+                ParamId::Leveler,
+                |p| &p.leveler,
+            )
+            .tooltip(|cx| {
+                live_tooltip(
+                    cx,
+                    "Leveler",
+                    "Smooths loudness changes between phrases with a linked stereo compressor.",
+                    LiveControl::Leveler,
+                );
+            });
+
+            create_slider(
+                cx,
+                "Target",
+                params.clone(),
+                gui.clone(),
+                ParamId::LevelerTarget,
+                |p| &p.leveler_target,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Level the Leveler rides the voice towards. Higher leaves louder phrases alone; lower holds them down harder."),
+                );
+            });
+
+            create_slider(
+                cx,
+                "Density",
+                params.clone(),
+                gui.clone(),
+                ParamId::Density,
+                |p| &p.density,
+            )
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Mixes a heavily compressed copy of the voice under the Leveler for a dense broadcast sound. Lifts soft syllables; pauses are left alone."),
+                );
+            });
+
+            create_response_dropdown(cx, "RESPONSE", params.clone(), gui.clone());
+            create_guardrails_dropdown(cx, "GUARDRAILS", params.clone(), gui.clone());
+            create_chain_order_dropdown(cx, "ORDER", params.clone(), gui.clone());
+
+            Label::new(
+                cx,
+                VoiceStudioData::pump_guard.map(|&on| {
+                    if on {
+                        tr("Pump guard engaged")
+                    } else {
+                        String::new()
+                    }
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Pumping kept repeating, so the Leveler is held back and loudness compensation slowed until the level settles."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::numeric_fault.map(|&(code, count)| {
+                    match GuardStage::from_code(code) {
+                        Some(stage) => format!("{} {} ({})", tr("NaN reset:"), stage.name(), count),
+                        None => String::new(),
+                    }
+                }),
+            )
+            .class("mini-label")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("A processing stage produced an invalid sample and was reset on its own. Shows the first stage it happened to and how many resets followed; include it when reporting dropouts."),
+                );
+            });
+        })
+        .class("tab-column")
+        .class("adv-column");
+
+        build_modulation_column(cx, params.clone(), gui.clone());
+
+        build_speaker_profile_column(cx, params.clone(), gui.clone());
+    })
+    .class("adv-columns")
+    .class("tab-content")
+    .class("tab-shape-polish")
+}
+
+/// Modulation routes: a source and a bipolar depth for Clarity, Proximity
+/// and De-Ess
+fn build_modulation_column(cx: &mut Context, params: Arc<VoiceParams>, gui: Arc<dyn GuiContext>) {
+    VStack::new(cx, move |cx| {
+        Label::new(cx, &tr("Modulation")).class("mini-label");
+
+        create_mod_source_dropdown(
+            cx,
+            "CLARITY",
+            params.clone(),
+            gui.clone(),
+            ModTarget::Clarity,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Signal that moves Clarity. Speech: more clarity when the voice is uncertain or buried in noise (positive depth)."),
+            );
+        });
+        create_slider(
+            cx,
+            "Depth",
+            params.clone(),
+            gui.clone(),
+            ParamId::ModClarityDepth,
+            |p| &p.mod_clarity_depth,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("How far the source moves Clarity (negative inverts). 0% turns the route off."),
+            );
+        });
+
+        create_mod_source_dropdown(
+            cx,
+            "PROXIMITY",
+            params.clone(),
+            gui.clone(),
+            ModTarget::Proximity,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Signal that moves Proximity. Loudness: more warmth on quiet passages, less on loud close speech (positive depth)."),
+            );
+        });
+        create_slider(
+            cx,
+            "Depth",
+            params.clone(),
+            gui.clone(),
+            ParamId::ModProximityDepth,
+            |p| &p.mod_proximity_depth,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("How far the source moves Proximity (negative inverts). 0% turns the route off."),
+            );
+        });
+
+        create_mod_source_dropdown(
+            cx,
+            "DE-ESS",
+            params.clone(),
+            gui.clone(),
+            ModTarget::DeEsser,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Signal that moves De-Ess. Loudness with a negative depth de-esses loud, close speech harder."),
+            );
+        });
+        create_slider(
+            cx,
+            "Depth",
+            params.clone(),
+            gui.clone(),
+            ParamId::ModDeEssDepth,
+            |p| &p.mod_deess_depth,
+        )
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("How far the source moves De-Ess (negative inverts). 0% turns the route off."),
+            );
+        });
+    })
+    .class("tab-column")
+    .class("adv-column")
+    .class("mod-column");
+}
+
+/// Speaker profile save/recall (de-esser band, proximity and leveler target
+/// adapt to the loaded voice) and dual-speaker switching
+fn build_speaker_profile_column(
+    cx: &mut Context,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) {
+    VStack::new(cx, move |cx| {
+        Label::new(cx, &tr("Speaker Profile")).class("mini-label");
+
+        Dropdown::new(
+            cx,
+            |cx| {
+                Label::new(
+                    cx,
+                    VoiceStudioData::active_speaker.map(|name| {
+                        if name.is_empty() {
+                            tr("No profile")
+                        } else {
+                            name.clone()
+                        }
+                    }),
+                )
+                .class("dropdown-selected")
+            },
+            |cx| {
+                Binding::new(cx, VoiceStudioData::speaker_profile_names, |cx, names| {
+                    let names = names.get(cx);
+                    VStack::new(cx, move |cx| {
+                        Label::new(cx, &tr("No profile"))
+                            .class("dropdown-option")
+                            .on_press(|cx| {
+                                cx.emit(SpeakerProfileEvent::Unload);
+                                cx.emit(PopupEvent::Close);
+                            });
+                        for (index, name) in names.iter().enumerate() {
+                            let name = name.clone();
+                            HStack::new(cx, move |cx| {
+                                Label::new(cx, name.clone())
+                                    .class("dropdown-option")
+                                    .width(Stretch(1.0))
+                                    .on_press(move |cx| {
+                                        cx.emit(SpeakerProfileEvent::Load(index));
+                                        cx.emit(PopupEvent::Close);
+                                    });
+                                Label::new(cx, "\u{2715}")
+                                    .class("dropdown-remove")
+                                    .on_press(move |cx| {
+                                        cx.emit(SpeakerProfileEvent::Remove(index));
+                                        cx.emit(PopupEvent::Close);
+                                    })
+                                    .tooltip(|cx| {
+                                        Label::new(cx, &tr("Delete this speaker profile"));
+                                    });
+                            })
+                            .height(Auto);
+                        }
+                    })
+                    .class("dropdown-options");
+                });
+            },
+        )
+        .class("dropdown-box")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Recall a saved voice. De-Ess band, Proximity and Leveler target adapt to it."),
+            );
+        });
+
+        Textbox::new(cx, VoiceStudioData::speaker_name)
+            .on_edit(|cx, text| cx.emit(SpeakerProfileEvent::SetName(text)))
+            .class("speaker-name");
+
+        HStack::new(cx, |cx| {
+            create_button(cx, "Save", "small-button", |cx| {
+                cx.emit(SpeakerProfileEvent::Save);
+            })
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Store the voice statistics learned so far under the typed name (needs 10 s of speech)."),
+                );
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::speaker_voiced_sec.map(|sec| format!("{} {:.0} s", tr("Learned:"), sec)),
+            )
+            .class("mini-label");
+        })
+        .class("output-actions");
+
+        Label::new(cx, VoiceStudioData::speaker_status).class("mini-label");
+
+        Label::new(cx, &tr("Track Profile")).class("mini-label");
+
+        // Entering a name with a stored profile recalls it
+        let params_track = params.clone();
+        Textbox::new(cx, VoiceStudioData::track_name)
+            .on_submit(move |cx, text, _| {
+                if let Ok(mut track) = params_track.track_name.write() {
+                    *track = text.trim().to_string();
+                }
+                cx.emit(TrackProfileEvent::Recall);
+            })
+            .class("speaker-name")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Name of the track this instance sits on (the host does not tell the plugin). A name with a saved profile recalls it."),
+                );
+            });
+
+        HStack::new(cx, move |cx| {
+            create_button(cx, "Save", "small-button", move |cx| {
+                cx.emit(TrackProfileEvent::Save);
+            })
+            .tooltip(|cx| {
+                let hint = crate::track_profiles::store_path()
+                    .map(|path| {
+                        format!(
+                            "{} {}",
+                            tr("Store the current settings for this track name in"),
+                            path.display()
+                        )
+                    })
+                    .unwrap_or_else(|| tr("Store the current settings for this track name"));
+                Label::new(cx, &hint);
+            });
+
+            Label::new(
+                cx,
+                VoiceStudioData::track_stored.map(|&stored| {
+                    if stored {
+                        tr("Saved for track")
+                    } else {
+                        tr("Not saved")
+                    }
+                }),
+            )
+            .class("mini-label");
+        })
+        .class("output-actions");
+
+        let params_dual = params.clone();
+        let gui_dual = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.dual_speaker.value()),
+            move |cx, lens| {
+                let dual_on = lens.get(cx);
+                let p = params_dual.clone();
+                let g = gui_dual.clone();
+
+                HStack::new(cx, move |cx| {
+                    create_toggle_button(
+                        cx,
+                        "Dual",
+                        dual_on,
+                        "small-button-active",
+                        "small-button",
+                        move |_| {
+                            let s = ParamSetter::new(g.as_ref());
+                            let param = &p.dual_speaker;
+                            s.begin_set_parameter(param);
+                            s.set_parameter(param, !dual_on);
+                            s.end_set_parameter(param);
+                        },
+                    )
+                    .tooltip(|cx| {
+                        Label::new(
+                            cx,
+                            &tr("Interview mode: detects two alternating voices and switches De-Ess band and Leveler target between them."),
+                        );
+                    });
+
+                    Label::new(
+                        cx,
+                        VoiceStudioData::dual_active_speaker.map(|speaker| match speaker {
+                            0 => tr("Speaker A"),
+                            1 => tr("Speaker B"),
+                            _ => tr("Speaker --"),
+                        }),
+                    )
+                    .class("mini-label");
+                })
+                .class("output-actions");
+            },
+        );
+    })
+    .class("tab-column")
+    .class("adv-column");
 }
@@ -5,17 +5,24 @@
 //! - Sliders: horizontal parameter controls
 //! - Knobs: macro dials
 //! - Dropdowns: preset selection
+//! - Tooltips: static description plus live resolved value
 //!
-//! All builders use consistent patterns with nih_plug's ParamSlider for binding
-//! to plugin parameters. Styling is handled via CSS classes defined in ui.css.
+//! Sliders and dials bind to plugin parameters through `ParamInput`, which adds
+//! keyboard nudging and typed value entry. Styling is handled via CSS classes defined in ui.css.
 
-use crate::ui::state::set_macro_mode;
+use crate::macro_controller::{MacroKind, MacroParams, AUTO_OFFSET_CENTER};
+use crate::meters::Meters;
+use crate::modulation::ModTarget;
+use crate::param_locks::LockId;
+use crate::ui::draw::Dpi;
+use crate::ui::locale::tr;
+use crate::ui::param_input::ParamInput;
+use crate::ui::state::{set_macro_mode, LiveControl, ParamLockEvent};
 use crate::VoiceParams;
 use nih_plug::params::Param;
 use nih_plug::prelude::{GuiContext, ParamSetter};
 use nih_plug_vizia::vizia::prelude::*;
 use nih_plug_vizia::widgets::param_base::ParamWidgetBase;
-use nih_plug_vizia::widgets::*;
 use std::sync::Arc;
 
 #[derive(Clone, Copy, PartialEq, Data)]
@@ -25,17 +32,115 @@ pub enum ParamId {
     HissAmount,
     NoiseLearnAmount,
     ReverbReduction,
+    DeverbEarly,
+    DeverbTail,
     Clarity,
     Proximity,
     DeEsser,
     Leveler,
     OutputGain,
     BreathControl,
+    ComfortNoiseLevel,
+    TransientAmount,
+    TransientSensitivity,
+    NoiseCharacter,
+    NoiseFloorDepth,
+    NoiseLearnTime,
+    DeverbCharacter,
+    TailRelease,
+    DenoiseStereoLink,
+    SpeechLossBudget,
+    SpeechBandLow,
+    SpeechBandHigh,
+    InputBalance,
+    LevelerTarget,
+    Density,
+    CodecRepair,
+    DeadAirThreshold,
+    PauseLevel,
+    BreathLevel,
+    DeEssTreat,
+    ClarityFreq,
+    ClarityQ,
+    DeHarsh,
+    ClipDrive,
+    ClipCeiling,
+    LoudnessTrim,
+    PresenceGain,
+    PresenceFreq,
+    ModClarityDepth,
+    ModProximityDepth,
+    ModDeEssDepth,
     MacroDistance,
     MacroClarity,
     MacroConsistency,
 }
 
+impl ParamId {
+    pub fn macro_kind(self) -> Option<MacroKind> {
+        match self {
+            ParamId::MacroDistance => Some(MacroKind::Clean),
+            ParamId::MacroClarity => Some(MacroKind::Enhance),
+            ParamId::MacroConsistency => Some(MacroKind::Control),
+            _ => None,
+        }
+    }
+
+    /// Lock slot for the slider; only sliders that macros, DSP presets or
+    /// Reset would move can be locked
+    pub fn lock_id(self) -> Option<LockId> {
+        Some(match self {
+            ParamId::NoiseReduction => LockId::NoiseReduction,
+            ParamId::RumbleAmount => LockId::Rumble,
+            ParamId::HissAmount => LockId::Hiss,
+            ParamId::NoiseLearnAmount => LockId::StaticNoise,
+            ParamId::ReverbReduction => LockId::DeVerb,
+            ParamId::DeverbEarly => LockId::DeverbEarly,
+            ParamId::DeverbTail => LockId::DeverbTail,
+            ParamId::Clarity => LockId::Clarity,
+            ParamId::Proximity => LockId::Proximity,
+            ParamId::DeEsser => LockId::DeEsser,
+            ParamId::Leveler => LockId::Leveler,
+            ParamId::OutputGain => LockId::OutputGain,
+            ParamId::BreathControl => LockId::BreathControl,
+            ParamId::ComfortNoiseLevel
+            | ParamId::TransientAmount
+            | ParamId::TransientSensitivity
+            | ParamId::NoiseCharacter
+            | ParamId::NoiseFloorDepth
+            | ParamId::NoiseLearnTime
+            | ParamId::DeverbCharacter
+            | ParamId::TailRelease
+            | ParamId::DenoiseStereoLink
+            | ParamId::SpeechLossBudget
+            | ParamId::SpeechBandLow
+            | ParamId::SpeechBandHigh
+            | ParamId::InputBalance
+            | ParamId::LevelerTarget
+            | ParamId::Density
+            | ParamId::CodecRepair
+            | ParamId::DeadAirThreshold
+            | ParamId::PauseLevel
+            | ParamId::BreathLevel
+            | ParamId::DeEssTreat
+            | ParamId::ClarityFreq
+            | ParamId::ClarityQ
+            | ParamId::DeHarsh
+            | ParamId::ClipDrive
+            | ParamId::ClipCeiling
+            | ParamId::LoudnessTrim
+            | ParamId::PresenceGain
+            | ParamId::PresenceFreq
+            | ParamId::ModClarityDepth
+            | ParamId::ModProximityDepth
+            | ParamId::ModDeEssDepth
+            | ParamId::MacroDistance
+            | ParamId::MacroClarity
+            | ParamId::MacroConsistency => return None,
+        })
+    }
+}
+
 // BUTTON HELPERS
 pub fn create_button<'a>(
     cx: &'a mut Context,
@@ -43,7 +148,7 @@ pub fn create_button<'a>(
     class: &'static str,
     callback: impl Fn(&mut EventContext) + 'static,
 ) -> Handle<'a, Button> {
-    Button::new(cx, callback, |cx| Label::new(cx, label)).class(class)
+    Button::new(cx, callback, |cx| Label::new(cx, &tr(label))).class(class)
 }
 
 pub fn create_toggle_button<'a>(
@@ -54,165 +159,906 @@ pub fn create_toggle_button<'a>(
     inactive_class: &'static str,
     callback: impl Fn(&mut EventContext) + 'static,
 ) -> Handle<'a, Button> {
-    Button::new(cx, callback, |cx| Label::new(cx, label)).class(if is_active {
+    Button::new(cx, callback, |cx| Label::new(cx, &tr(label))).class(if is_active {
         active_class
     } else {
         inactive_class
     })
 }
 
-pub fn create_momentary_button<'a, P>(
-    cx: &'a mut Context,
-    label: &'static str,
-    params: Arc<VoiceParams>,
-    gui: Arc<dyn GuiContext>,
-    param_getter: impl Fn(&VoiceParams) -> &P + Copy + Send + Sync + 'static,
-) -> Handle<'a, HStack>
-where
-    P: Param<Plain = bool> + 'static,
-{
-    let params_down = params.clone();
-    let gui_down = gui.clone();
-    let params_up = params;
-    let gui_up = gui;
+/// Safe Live Mode is on: greys out the actions it locks
+pub fn live_locked() -> impl Lens<Target = bool> {
+    crate::ui::state::VoiceStudioData::params.map(|p| p.safe_live_mode.value())
+}
+
+pub fn create_momentary_button<'a, P>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+    param_getter: impl Fn(&VoiceParams) -> &P + Copy + Send + Sync + 'static,
+) -> Handle<'a, HStack>
+where
+    P: Param<Plain = bool> + 'static,
+{
+    let params_down = params.clone();
+    let gui_down = gui.clone();
+    let params_up = params;
+    let gui_up = gui;
+
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).hoverable(false);
+    })
+    .class("small-button")
+    .toggle_class("live-locked", live_locked())
+    .on_mouse_down(move |cx, btn| {
+        // One-shot actions are locked while Safe Live Mode is on
+        if btn == MouseButton::Left && !params_down.safe_live_mode.value() {
+            let s = ParamSetter::new(gui_down.as_ref());
+            let param = param_getter(params_down.as_ref());
+            s.begin_set_parameter(param);
+            s.set_parameter(param, true);
+            s.end_set_parameter(param);
+            cx.capture();
+        }
+    })
+    .on_mouse_up(move |cx, btn| {
+        if btn == MouseButton::Left {
+            let s = ParamSetter::new(gui_up.as_ref());
+            let param = param_getter(params_up.as_ref());
+            s.begin_set_parameter(param);
+            s.set_parameter(param, false);
+            s.end_set_parameter(param);
+            cx.release();
+        }
+    })
+}
+
+// SLIDER HELPERS
+pub fn create_slider<'a, P>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+    id: ParamId,
+    map: impl Fn(&Arc<VoiceParams>) -> &P + Copy + 'static,
+) -> Handle<'a, HStack>
+where
+    P: Param + 'static,
+{
+    let p_m = params.clone();
+    let g_m = gui.clone();
+    let m_m = crate::ui::state::VoiceStudioData::meters.get(cx);
+    let disable_macros = !matches!(
+        id,
+        ParamId::MacroDistance
+            | ParamId::MacroClarity
+            | ParamId::MacroConsistency
+            | ParamId::ComfortNoiseLevel
+            | ParamId::TransientAmount
+            | ParamId::TransientSensitivity
+            | ParamId::NoiseCharacter
+            | ParamId::NoiseFloorDepth
+            | ParamId::NoiseLearnTime
+            | ParamId::DeverbCharacter
+            | ParamId::TailRelease
+            | ParamId::DenoiseStereoLink
+            | ParamId::SpeechLossBudget
+            | ParamId::SpeechBandLow
+            | ParamId::SpeechBandHigh
+            | ParamId::InputBalance
+            | ParamId::LevelerTarget
+            | ParamId::Density
+            | ParamId::CodecRepair
+            | ParamId::DeadAirThreshold
+            | ParamId::PauseLevel
+            | ParamId::BreathLevel
+            | ParamId::DeEssTreat
+            | ParamId::ClarityFreq
+            | ParamId::ClarityQ
+            | ParamId::DeHarsh
+            | ParamId::ClipDrive
+            | ParamId::ClipCeiling
+            | ParamId::LoudnessTrim
+            | ParamId::PresenceGain
+            | ParamId::PresenceFreq
+            | ParamId::ModClarityDepth
+            | ParamId::ModProximityDepth
+            | ParamId::ModDeEssDepth
+    );
+
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label))
+            .class("slider-label")
+            .class("adv-label")
+            .text_wrap(false);
+
+        let p_lock = params.clone();
+        ZStack::new(cx, move |cx| {
+            SliderVisuals::new(cx, params.clone(), id).class("fill-both");
+
+            // Value display (centered in slider)
+            let lens = ParamWidgetBase::make_lens(
+                crate::ui::state::VoiceStudioData::params,
+                map,
+                |p: &P| p.normalized_value_to_string(p.unmodulated_normalized_value(), true),
+            );
+            Label::new(cx, lens)
+                .class("slider-value")
+                .class("adv-value")
+                .hoverable(false);
+
+            ParamInput::new(cx, crate::ui::state::VoiceStudioData::params, move |p| {
+                map(p)
+            })
+            .class("fill-both");
+        })
+        .class("slider-visual")
+        .class("adv-slider")
+        .class("fill-width")
+        .on_mouse_down(move |_, _| {
+            // A locked slider is set by hand alongside the macros, so Easy
+            // Mode stays on
+            let locked = id
+                .lock_id()
+                .map_or(false, |lock| p_m.param_locks.is_locked(lock));
+            if disable_macros && !locked {
+                set_macro_mode(&p_m, &m_m, &g_m, false);
+            }
+        });
+
+        if let Some(lock) = id.lock_id() {
+            Binding::new(
+                cx,
+                crate::ui::state::VoiceStudioData::param_locks,
+                move |cx, _| {
+                    let locked = p_lock.param_locks.is_locked(lock);
+                    LockToggle::new(cx, lock, locked)
+                        .class("lock-toggle")
+                        .toggle_class("lock-toggle-active", locked)
+                        .tooltip(|cx| {
+                            Label::new(
+                                cx,
+                                &tr("Lock: macros, DSP presets and Reset leave this slider alone."),
+                            );
+                        });
+                },
+            );
+        }
+    })
+    .class("slider-container")
+    .class("adv-row")
+}
+
+/// Tooltip content for a slider: the static description followed by the
+/// value the engine is currently running, refreshed with the meters
+pub fn live_tooltip(
+    cx: &mut Context,
+    name: &'static str,
+    description: &'static str,
+    control: LiveControl,
+) {
+    VStack::new(cx, move |cx| {
+        Label::new(cx, &tr(description));
+        Label::new(
+            cx,
+            crate::ui::state::VoiceStudioData::live.map(move |live| live.describe(name, control)),
+        )
+        .class("tooltip-live");
+    })
+    .class("tooltip-content");
+}
+
+/// Macro dial with its Auto switch. With Auto on the dial shows the
+/// calibration-driven amount and dragging it sets an offset.
+pub fn create_macro_dial<'a, P>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+    meters: Arc<Meters>,
+    id: ParamId,
+    map: impl Fn(&Arc<VoiceParams>) -> &P + Copy + 'static,
+) -> Handle<'a, VStack>
+where
+    P: Param + 'static,
+{
+    let kind = id.macro_kind().unwrap_or(MacroKind::Clean);
+    let p_auto = params.clone();
+    let m_auto = meters.clone();
+
+    VStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dial-label");
+
+        // Use ZStack to layer visuals behind the interactive slider
+        ZStack::new(cx, move |cx| {
+            // Visual representation (behind)
+            DialVisuals::new(cx, params.clone(), meters.clone(), id).class("fill-both");
+
+            // Value display (centered in dial): the effective amount in Auto
+            Binding::new(
+                cx,
+                crate::ui::state::VoiceStudioData::params
+                    .map(move |p| (kind.auto(p).value(), kind.dial(p).value())),
+                move |cx, state| {
+                    let (auto, offset) = state.get(cx);
+                    if auto {
+                        Label::new(
+                            cx,
+                            crate::ui::state::VoiceStudioData::live.map(move |live| {
+                                format!("{:.0}%", live.macro_effective(kind, offset))
+                            }),
+                        )
+                        .class("dial-value")
+                        .class("dial-value-auto")
+                        .hoverable(false);
+                    } else {
+                        let lens = ParamWidgetBase::make_lens(
+                            crate::ui::state::VoiceStudioData::params,
+                            map,
+                            |p: &P| {
+                                p.normalized_value_to_string(p.unmodulated_normalized_value(), true)
+                            },
+                        );
+                        Label::new(cx, lens).class("dial-value").hoverable(false);
+                    }
+                },
+            );
+
+            // Interactive layer (in front, invisible; keyboard + text entry)
+            ParamInput::for_macro(
+                cx,
+                crate::ui::state::VoiceStudioData::params,
+                move |p| map(p),
+                kind,
+            )
+            .class("fill-both")
+            .z_index(1);
+        })
+        .class("dial-visual");
+
+        Binding::new(
+            cx,
+            crate::ui::state::VoiceStudioData::params.map(move |p| kind.auto(p).value()),
+            move |cx, auto| {
+                let auto = auto.get(cx);
+                let p = p_auto.clone();
+                let m = m_auto.clone();
+                let g = gui.clone();
+                create_toggle_button(cx, "Auto", auto, "dial-auto-active", "dial-auto", move |_| {
+                    let s = ParamSetter::new(g.as_ref());
+                    // Keep the sound where it is: entering Auto starts with no
+                    // offset, leaving it parks the dial on the amount in use
+                    let dial = if auto {
+                        kind.effective(&p, &m.snapshot().macro_auto)
+                    } else {
+                        AUTO_OFFSET_CENTER
+                    };
+                    s.begin_set_parameter(kind.auto(&p));
+                    s.set_parameter(kind.auto(&p), !auto);
+                    s.end_set_parameter(kind.auto(&p));
+                    s.begin_set_parameter(kind.dial(&p));
+                    s.set_parameter(kind.dial(&p), dial);
+                    s.end_set_parameter(kind.dial(&p));
+                })
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Let the input analysis set this macro. The dial then offsets the automatic amount (12 o'clock = no offset)."),
+                    );
+                });
+            },
+        );
+    })
+    .class("dial-container")
+}
+
+// DROPDOWN HELPERS
+pub fn create_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.final_output_preset,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for preset in crate::presets::OutputPreset::all_presets().iter() {
+                        let preset_value = *preset;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(preset_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.final_output_preset);
+                                setter
+                                    .set_parameter(&params_item.final_output_preset, preset_value);
+                                setter.end_set_parameter(&params_item.final_output_preset);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("output-preset-dropdown")
+}
+
+pub fn create_routing_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.output_routing,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for routing in crate::presets::OutputRouting::all_routings().iter() {
+                        let routing_value = *routing;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(routing_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.output_routing);
+                                setter.set_parameter(&params_item.output_routing, routing_value);
+                                setter.end_set_parameter(&params_item.output_routing);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("routing-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Output channels: Stereo, Mono fold-down (-3 dB pan law) or the left channel on both sides."),
+        );
+    })
+}
+
+pub fn create_final_stage_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.final_stage,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for stage in crate::presets::FinalStage::all_modes().iter() {
+                        let stage_value = *stage;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(stage_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.final_stage);
+                                setter.set_parameter(&params_item.final_stage, stage_value);
+                                setter.end_set_parameter(&params_item.final_stage);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("routing-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Last stage: the transparent limiter, a 4x oversampled soft clipper for a denser sound, or the clipper followed by the limiter. Soft Clip adds 32 samples of latency."),
+        );
+    })
+}
+
+/// Limiter release character (Transparent, Smooth, Aggressive)
+pub fn create_limiter_character_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.limiter_character,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for character in crate::presets::LimiterCharacter::all_modes().iter() {
+                        let character_value = *character;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(character_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.limiter_character);
+                                setter.set_parameter(
+                                    &params_item.limiter_character,
+                                    character_value,
+                                );
+                                setter.end_set_parameter(&params_item.limiter_character);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("routing-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("How the limiter lets go. Transparent: one slow, even release. Smooth: recovers quickly after a single peak but slowly after a loud passage, so speech does not pump. Aggressive: fast release for a denser, louder sound."),
+        );
+    })
+}
+
+/// Built-in test signal (pink noise, sweep, 1 kHz tone) in place of the input
+pub fn create_test_signal_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.test_signal,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for signal in crate::presets::TestSignal::all_modes().iter() {
+                        let signal_value = *signal;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(signal_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.test_signal);
+                                setter.set_parameter(&params_item.test_signal, signal_value);
+                                setter.end_set_parameter(&params_item.test_signal);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("test-signal-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Replace the input with a known signal to check the meters or hear what each stage does: pink noise at -18 dBFS RMS, a 20 Hz-20 kHz sweep or a 1 kHz tone at -18 dBFS (reads about -18 LUFS). Set back to Off before recording."),
+        );
+    })
+}
+
+/// Source selector for one modulation route
+pub fn create_mod_source_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+    target: ModTarget,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            move |p| target.source(p),
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for source in crate::presets::ModSource::all_sources().iter() {
+                        let source_value = *source;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(source_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let param = target.source(&params_item);
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(param);
+                                setter.set_parameter(param, source_value);
+                                setter.end_set_parameter(param);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("mod-source-dropdown")
+}
+
+pub fn create_dither_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.output_dither,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for mode in crate::presets::DitherMode::all_modes().iter() {
+                        let mode_value = *mode;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(mode_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.output_dither);
+                                setter.set_parameter(&params_item.output_dither, mode_value);
+                                setter.end_set_parameter(&params_item.output_dither);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("dither-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("TPDF dither for fixed-point delivery. Use 16-bit (noise-shaped) when VxCleaner is the last insert before a 16-bit bounce."),
+        );
+    })
+}
+
+pub fn create_low_cut_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.low_cut,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for mode in crate::presets::LowCut::all_modes().iter() {
+                        let mode_value = *mode;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(mode_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.low_cut);
+                                setter.set_parameter(&params_item.low_cut, mode_value);
+                                setter.end_set_parameter(&params_item.low_cut);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("low-cut-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("High-pass ahead of all processing. Lower it (60-80 Hz) to keep the chest of deep voices, raise it for rumbly rooms. Off passes the full low end."),
+        );
+    })
+}
+
+pub fn create_low_cut_slope_dropdown<'a>(
+    cx: &'a mut Context,
+    label: &'static str,
+    params: Arc<VoiceParams>,
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
+
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.low_cut_slope,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
+
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
+
+                VStack::new(cx, move |cx| {
+                    for mode in crate::presets::LowCutSlope::all_modes().iter() {
+                        let mode_value = *mode;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
 
-    HStack::new(cx, move |cx| {
-        Label::new(cx, label).hoverable(false);
-    })
-    .class("small-button")
-    .on_mouse_down(move |cx, btn| {
-        if btn == MouseButton::Left {
-            let s = ParamSetter::new(gui_down.as_ref());
-            let param = param_getter(params_down.as_ref());
-            s.begin_set_parameter(param);
-            s.set_parameter(param, true);
-            s.end_set_parameter(param);
-            cx.capture();
-        }
+                        Label::new(cx, &tr(mode_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.low_cut_slope);
+                                setter.set_parameter(&params_item.low_cut_slope, mode_value);
+                                setter.end_set_parameter(&params_item.low_cut_slope);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
     })
-    .on_mouse_up(move |cx, btn| {
-        if btn == MouseButton::Left {
-            let s = ParamSetter::new(gui_up.as_ref());
-            let param = param_getter(params_up.as_ref());
-            s.begin_set_parameter(param);
-            s.set_parameter(param, false);
-            s.end_set_parameter(param);
-            cx.release();
-        }
+    .class("dropdown-row")
+    .class("low-cut-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Low Cut steepness: 12 dB/oct is gentle, 24 dB/oct clears heavy rumble and handling noise right below the corner."),
+        );
     })
 }
 
-// SLIDER HELPERS
-pub fn create_slider<'a, P>(
+pub fn create_response_dropdown<'a>(
     cx: &'a mut Context,
     label: &'static str,
     params: Arc<VoiceParams>,
     gui: Arc<dyn GuiContext>,
-    id: ParamId,
-    map: impl Fn(&Arc<VoiceParams>) -> &P + Copy + 'static,
-) -> Handle<'a, HStack>
-where
-    P: Param + 'static,
-{
-    let p_m = params.clone();
-    let g_m = gui.clone();
-    let disable_macros = !matches!(
-        id,
-        ParamId::MacroDistance | ParamId::MacroClarity | ParamId::MacroConsistency
-    );
-
+) -> Handle<'a, HStack> {
     HStack::new(cx, move |cx| {
-        Label::new(cx, label)
-            .class("slider-label")
-            .class("adv-label")
-            .text_wrap(false);
+        Label::new(cx, &tr(label)).class("dropdown-label");
 
-        ZStack::new(cx, move |cx| {
-            SliderVisuals::new(cx, params.clone(), id).class("fill-both");
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.dynamics_response,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
 
-            // Value display (centered in slider)
-            let lens = ParamWidgetBase::make_lens(
-                crate::ui::state::VoiceStudioData::params,
-                map,
-                |p: &P| p.normalized_value_to_string(p.unmodulated_normalized_value(), true),
-            );
-            Label::new(cx, lens)
-                .class("slider-value")
-                .class("adv-value")
-                .hoverable(false);
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
 
-            ParamSlider::new(cx, crate::ui::state::VoiceStudioData::params, move |p| {
-                map(p)
-            })
-            .class("fill-both")
-            .class("input-hidden");
-        })
-        .class("slider-visual")
-        .class("adv-slider")
-        .class("fill-width")
-        .on_mouse_down(move |_, _| {
-            if disable_macros {
-                set_macro_mode(&p_m, &g_m, false);
-            }
-        });
+                VStack::new(cx, move |cx| {
+                    for mode in crate::presets::DynamicsResponse::all_modes().iter() {
+                        let mode_value = *mode;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(mode_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.dynamics_response);
+                                setter.set_parameter(&params_item.dynamics_response, mode_value);
+                                setter.end_set_parameter(&params_item.dynamics_response);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("low-cut-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Speed of the Leveler, expander and Breath Control together. Fast (half the times) suits quick conversation, Relaxed (double) slow narration."),
+        );
     })
-    .class("slider-container")
-    .class("adv-row")
 }
 
-pub fn create_macro_dial<'a, P>(
+/// Processing order of the shaping and dynamics stages (allowed orders only)
+pub fn create_chain_order_dropdown<'a>(
     cx: &'a mut Context,
     label: &'static str,
     params: Arc<VoiceParams>,
-    id: ParamId,
-    map: impl Fn(&Arc<VoiceParams>) -> &P + Copy + 'static,
-) -> Handle<'a, VStack>
-where
-    P: Param + 'static,
-{
-    VStack::new(cx, move |cx| {
-        Label::new(cx, label).class("dial-label");
+    gui: Arc<dyn GuiContext>,
+) -> Handle<'a, HStack> {
+    HStack::new(cx, move |cx| {
+        Label::new(cx, &tr(label)).class("dropdown-label");
 
-        // Use ZStack to layer visuals behind the interactive slider
-        ZStack::new(cx, move |cx| {
-            // Visual representation (behind)
-            DialVisuals::new(cx, params.clone(), id).class("fill-both");
+        let lens = ParamWidgetBase::make_lens(
+            crate::ui::state::VoiceStudioData::params,
+            |p| &p.chain_order,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
+        );
 
-            // Value display (centered in dial)
-            let lens = ParamWidgetBase::make_lens(
-                crate::ui::state::VoiceStudioData::params,
-                map,
-                |p: &P| p.normalized_value_to_string(p.unmodulated_normalized_value(), true),
-            );
-            Label::new(cx, lens).class("dial-value").hoverable(false);
+        Dropdown::new(
+            cx,
+            move |cx| Label::new(cx, lens).class("dropdown-selected"),
+            move |cx| {
+                let params_list = params.clone();
+                let gui_list = gui.clone();
 
-            // Interactive slider (in front, invisible)
-            ParamSlider::new(cx, crate::ui::state::VoiceStudioData::params, move |p| {
-                map(p)
-            })
-            .class("fill-both")
-            .class("input-hidden")
-            .z_index(1);
-        })
-        .class("dial-visual");
+                VStack::new(cx, move |cx| {
+                    for order in crate::presets::ChainOrder::all_modes().iter() {
+                        let order_value = *order;
+                        let params_item = params_list.clone();
+                        let gui_item = gui_list.clone();
+
+                        Label::new(cx, &tr(order_value.name()))
+                            .class("dropdown-option")
+                            .on_press(move |cx| {
+                                let setter = ParamSetter::new(gui_item.as_ref());
+                                setter.begin_set_parameter(&params_item.chain_order);
+                                setter.set_parameter(&params_item.chain_order, order_value);
+                                setter.end_set_parameter(&params_item.chain_order);
+                                cx.emit(PopupEvent::Close);
+                            });
+                    }
+                })
+                .class("dropdown-options");
+            },
+        )
+        .class("dropdown-box");
+    })
+    .class("dropdown-row")
+    .class("low-cut-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Stage order. Standard: Proximity before Clarity, De-Esser before Leveler. Clarity First judges mud and glare before the warmth is added; De-Ess Last lets the de-esser follow the leveled voice, so boosted quiet phrases are treated as much as loud ones. Switching crossfades."),
+        );
     })
-    .class("dial-container")
 }
 
-// DROPDOWN HELPERS
-pub fn create_dropdown<'a>(
+pub fn create_guardrails_dropdown<'a>(
     cx: &'a mut Context,
     label: &'static str,
     params: Arc<VoiceParams>,
     gui: Arc<dyn GuiContext>,
 ) -> Handle<'a, HStack> {
     HStack::new(cx, move |cx| {
-        Label::new(cx, label).class("dropdown-label");
+        Label::new(cx, &tr(label)).class("dropdown-label");
 
         let lens = ParamWidgetBase::make_lens(
             crate::ui::state::VoiceStudioData::params,
-            |p| &p.final_output_preset,
-            |p| p.normalized_value_to_string(p.unmodulated_normalized_value(), true),
+            |p| &p.guardrails,
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
         );
 
         Dropdown::new(
@@ -223,19 +1069,18 @@ pub fn create_dropdown<'a>(
                 let gui_list = gui.clone();
 
                 VStack::new(cx, move |cx| {
-                    for preset in crate::presets::OutputPreset::all_presets().iter() {
-                        let preset_value = *preset;
+                    for mode in crate::presets::GuardrailStrength::all_modes().iter() {
+                        let mode_value = *mode;
                         let params_item = params_list.clone();
                         let gui_item = gui_list.clone();
 
-                        Label::new(cx, preset_value.name())
+                        Label::new(cx, &tr(mode_value.name()))
                             .class("dropdown-option")
                             .on_press(move |cx| {
                                 let setter = ParamSetter::new(gui_item.as_ref());
-                                setter.begin_set_parameter(&params_item.final_output_preset);
-                                setter
-                                    .set_parameter(&params_item.final_output_preset, preset_value);
-                                setter.end_set_parameter(&params_item.final_output_preset);
+                                setter.begin_set_parameter(&params_item.guardrails);
+                                setter.set_parameter(&params_item.guardrails, mode_value);
+                                setter.end_set_parameter(&params_item.guardrails);
                                 cx.emit(PopupEvent::Close);
                             });
                     }
@@ -246,7 +1091,13 @@ pub fn create_dropdown<'a>(
         .class("dropdown-box");
     })
     .class("dropdown-row")
-    .class("output-preset-dropdown")
+    .class("low-cut-dropdown")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Safety EQ ahead of the limiter that pulls back boomy low-mids and harsh highs when extreme settings push them out of balance. Strict steps in earlier and cuts deeper; Off leaves the tone alone. The GUARD readout under the meters shows what it is cutting."),
+        );
+    })
 }
 
 pub fn create_dsp_preset_dropdown<'a>(
@@ -256,12 +1107,12 @@ pub fn create_dsp_preset_dropdown<'a>(
     gui: Arc<dyn GuiContext>,
 ) -> Handle<'a, HStack> {
     HStack::new(cx, move |cx| {
-        Label::new(cx, label).class("dropdown-label");
+        Label::new(cx, &tr(label)).class("dropdown-label");
 
         let lens = ParamWidgetBase::make_lens(
             crate::ui::state::VoiceStudioData::params,
             |p| &p.dsp_preset,
-            |p| p.normalized_value_to_string(p.unmodulated_normalized_value(), true),
+            |p| tr(&p.normalized_value_to_string(p.unmodulated_normalized_value(), true)),
         );
 
         Dropdown::new(
@@ -286,7 +1137,7 @@ pub fn create_dsp_preset_dropdown<'a>(
                         let params_item = params_list.clone();
                         let gui_item = gui_list.clone();
 
-                        Label::new(cx, preset_value.name())
+                        Label::new(cx, &tr(preset_value.name()))
                             .class("dropdown-option")
                             .on_press(move |cx| {
                                 let setter = ParamSetter::new(gui_item.as_ref());
@@ -297,66 +1148,11 @@ pub fn create_dsp_preset_dropdown<'a>(
                                 setter.end_set_parameter(&params_item.dsp_preset);
 
                                 // Apply preset values to DSP parameters
-                                if let Some(values) = preset_value.get_values() {
-                                    // Set advanced parameters
-                                    setter.begin_set_parameter(&params_item.noise_reduction);
-                                    setter.set_parameter(
-                                        &params_item.noise_reduction,
-                                        values.noise_reduction,
-                                    );
-                                    setter.end_set_parameter(&params_item.noise_reduction);
-
-                                    setter.begin_set_parameter(&params_item.reverb_reduction);
-                                    setter.set_parameter(
-                                        &params_item.reverb_reduction,
-                                        values.reverb_reduction,
-                                    );
-                                    setter.end_set_parameter(&params_item.reverb_reduction);
-
-                                    setter.begin_set_parameter(&params_item.proximity);
-                                    setter.set_parameter(&params_item.proximity, values.proximity);
-                                    setter.end_set_parameter(&params_item.proximity);
-
-                                    setter.begin_set_parameter(&params_item.clarity);
-                                    setter.set_parameter(&params_item.clarity, values.clarity);
-                                    setter.end_set_parameter(&params_item.clarity);
-
-                                    setter.begin_set_parameter(&params_item.de_esser);
-                                    setter.set_parameter(&params_item.de_esser, values.de_esser);
-                                    setter.end_set_parameter(&params_item.de_esser);
-
-                                    setter.begin_set_parameter(&params_item.leveler);
-                                    setter.set_parameter(&params_item.leveler, values.leveler);
-                                    setter.end_set_parameter(&params_item.leveler);
-
-                                    setter.begin_set_parameter(&params_item.breath_control);
-                                    setter.set_parameter(
-                                        &params_item.breath_control,
-                                        values.breath_control,
-                                    );
-                                    setter.end_set_parameter(&params_item.breath_control);
-
-                                    setter.begin_set_parameter(&params_item.macro_clean);
-                                    setter.set_parameter(
-                                        &params_item.macro_clean,
-                                        values.macro_clean,
-                                    );
-                                    setter.end_set_parameter(&params_item.macro_clean);
-
-                                    setter.begin_set_parameter(&params_item.macro_enhance);
-                                    setter.set_parameter(
-                                        &params_item.macro_enhance,
-                                        values.macro_enhance,
-                                    );
-                                    setter.end_set_parameter(&params_item.macro_enhance);
-
-                                    setter.begin_set_parameter(&params_item.macro_control);
-                                    setter.set_parameter(
-                                        &params_item.macro_control,
-                                        values.macro_control,
-                                    );
-                                    setter.end_set_parameter(&params_item.macro_control);
-                                }
+                                crate::ui::state::apply_dsp_preset_values(
+                                    &params_item,
+                                    &setter,
+                                    preset_value,
+                                );
 
                                 cx.emit(PopupEvent::Close);
                             });
@@ -405,19 +1201,70 @@ impl View for SliderVisuals {
                 self.params.noise_learn_amount.modulated_normalized_value()
             }
             ParamId::ReverbReduction => self.params.reverb_reduction.modulated_normalized_value(),
+            ParamId::DeverbEarly => self.params.deverb_early.modulated_normalized_value(),
+            ParamId::DeverbTail => self.params.deverb_tail.modulated_normalized_value(),
+            ParamId::DeverbCharacter => self.params.deverb_character.modulated_normalized_value(),
+            ParamId::TailRelease => self.params.tail_release.modulated_normalized_value(),
             ParamId::Clarity => self.params.clarity.modulated_normalized_value(),
             ParamId::Proximity => self.params.proximity.modulated_normalized_value(),
             ParamId::DeEsser => self.params.de_esser.modulated_normalized_value(),
             ParamId::Leveler => self.params.leveler.modulated_normalized_value(),
             ParamId::OutputGain => self.params.output_gain.modulated_normalized_value(),
             ParamId::BreathControl => self.params.breath_control.modulated_normalized_value(),
+            ParamId::ComfortNoiseLevel => {
+                self.params.comfort_noise_level.modulated_normalized_value()
+            }
+            ParamId::TransientAmount => self.params.transient_amount.modulated_normalized_value(),
+            ParamId::TransientSensitivity => self
+                .params
+                .transient_sensitivity
+                .modulated_normalized_value(),
+            ParamId::NoiseCharacter => self.params.noise_character.modulated_normalized_value(),
+            ParamId::NoiseFloorDepth => self.params.noise_floor_depth.modulated_normalized_value(),
+            ParamId::NoiseLearnTime => self.params.noise_learn_time.modulated_normalized_value(),
+            ParamId::DenoiseStereoLink => {
+                self.params.denoise_stereo_link.modulated_normalized_value()
+            }
+            ParamId::SpeechLossBudget => {
+                self.params.speech_loss_budget.modulated_normalized_value()
+            }
+            ParamId::SpeechBandLow => self.params.speech_band_low.modulated_normalized_value(),
+            ParamId::SpeechBandHigh => self.params.speech_band_high.modulated_normalized_value(),
+            ParamId::InputBalance => self.params.input_balance.modulated_normalized_value(),
+            ParamId::LevelerTarget => self.params.leveler_target.modulated_normalized_value(),
+            ParamId::Density => self.params.density.modulated_normalized_value(),
+            ParamId::CodecRepair => self.params.bandwidth_extend.modulated_normalized_value(),
+            ParamId::DeadAirThreshold => {
+                self.params.dead_air_threshold.modulated_normalized_value()
+            }
+            ParamId::PauseLevel => self.params.pause_level.modulated_normalized_value(),
+            ParamId::BreathLevel => self.params.breath_level.modulated_normalized_value(),
+            ParamId::DeEssTreat => self.params.de_ess_treat.modulated_normalized_value(),
+            ParamId::ClarityFreq => self.params.clarity_freq.modulated_normalized_value(),
+            ParamId::ClarityQ => self.params.clarity_q.modulated_normalized_value(),
+            ParamId::DeHarsh => self.params.de_harsh.modulated_normalized_value(),
+            ParamId::ClipDrive => self.params.clip_drive.modulated_normalized_value(),
+            ParamId::ClipCeiling => self.params.clip_ceiling.modulated_normalized_value(),
+            ParamId::LoudnessTrim => self.params.loudness_comp_trim.modulated_normalized_value(),
+            ParamId::PresenceGain => self.params.presence_gain.modulated_normalized_value(),
+            ParamId::PresenceFreq => self.params.presence_freq.modulated_normalized_value(),
+            ParamId::ModClarityDepth => self.params.mod_clarity_depth.modulated_normalized_value(),
+            ParamId::ModProximityDepth => {
+                self.params.mod_proximity_depth.modulated_normalized_value()
+            }
+            ParamId::ModDeEssDepth => self.params.mod_deess_depth.modulated_normalized_value(),
             ParamId::MacroDistance => self.params.macro_clean.modulated_normalized_value(),
             ParamId::MacroClarity => self.params.macro_enhance.modulated_normalized_value(),
             ParamId::MacroConsistency => self.params.macro_control.modulated_normalized_value(),
         };
 
+        let dpi = Dpi::new(cx);
+        let radius = dpi.px(3.0);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
+
         let mut bg = nih_plug_vizia::vizia::vg::Path::new();
-        bg.rounded_rect(b.x, b.y, b.w, b.h, 3.0);
+        bg.rounded_rect(x, y, w, h, radius);
         canvas.fill_path(
             &bg,
             &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
@@ -425,16 +1272,16 @@ impl View for SliderVisuals {
             )),
         );
         canvas.stroke_path(
-            &bg,
+            &dpi.border_path(b, radius, border),
             &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
                 51, 65, 85,
             ))
-            .with_line_width(1.0),
+            .with_line_width(border),
         );
 
         if val > 0.0 {
             let mut f = nih_plug_vizia::vizia::vg::Path::new();
-            f.rounded_rect(b.x, b.y, b.w * val, b.h, 3.0);
+            f.rounded_rect(x, y, (w * val).round(), h, radius);
             canvas.fill_path(
                 &f,
                 &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgba(
@@ -447,12 +1294,23 @@ impl View for SliderVisuals {
 
 pub struct DialVisuals {
     params: Arc<VoiceParams>,
+    meters: Arc<Meters>,
     param_id: ParamId,
 }
 
 impl DialVisuals {
-    pub fn new(cx: &mut Context, params: Arc<VoiceParams>, param_id: ParamId) -> Handle<'_, Self> {
-        Self { params, param_id }.build(cx, |_| {})
+    pub fn new(
+        cx: &mut Context,
+        params: Arc<VoiceParams>,
+        meters: Arc<Meters>,
+        param_id: ParamId,
+    ) -> Handle<'_, Self> {
+        Self {
+            params,
+            meters,
+            param_id,
+        }
+        .build(cx, |_| {})
     }
 }
 
@@ -464,14 +1322,16 @@ impl View for DialVisuals {
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
         let b = cx.bounds();
 
-        let val = match self.param_id {
-            ParamId::MacroDistance => self.params.macro_clean.modulated_normalized_value(),
-            ParamId::MacroClarity => self.params.macro_enhance.modulated_normalized_value(),
-            ParamId::MacroConsistency => self.params.macro_control.modulated_normalized_value(),
-            _ => 0.0,
+        let kind = self.param_id.macro_kind();
+        let auto = kind.map_or(false, |k| k.auto(&self.params).value());
+        let val = match kind {
+            Some(k) if auto => k.effective(&self.params, &self.meters.snapshot().macro_auto),
+            Some(k) => k.dial(&self.params).modulated_normalized_value(),
+            None => 0.0,
         }
         .clamp(0.0, 1.0);
 
+        let dpi = Dpi::new(cx);
         let size = b.w.min(b.h);
         let radius = size * 0.35;
         let cx0 = b.x + b.w * 0.5;
@@ -495,7 +1355,7 @@ impl View for DialVisuals {
             &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
                 30, 41, 59,
             ))
-            .with_line_width(8.0)
+            .with_line_width(dpi.px(8.0))
             .with_line_cap(nih_plug_vizia::vizia::vg::LineCap::Round),
         );
 
@@ -508,13 +1368,17 @@ impl View for DialVisuals {
             current_angle,
             nih_plug_vizia::vizia::vg::Solidity::Hole,
         );
+        // Green arc while the calibration drives the macro
+        let active_color = if auto {
+            nih_plug_vizia::vizia::vg::Color::rgb(34, 197, 94)
+        } else {
+            nih_plug_vizia::vizia::vg::Color::rgb(59, 130, 246)
+        };
         canvas.stroke_path(
             &active,
-            &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
-                59, 130, 246,
-            ))
-            .with_line_width(8.0)
-            .with_line_cap(nih_plug_vizia::vizia::vg::LineCap::Round),
+            &nih_plug_vizia::vizia::vg::Paint::color(active_color)
+                .with_line_width(dpi.px(8.0))
+                .with_line_cap(nih_plug_vizia::vizia::vg::LineCap::Round),
         );
 
         let knob_radius = size * 0.32;
@@ -531,11 +1395,11 @@ impl View for DialVisuals {
             &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
                 51, 65, 85,
             ))
-            .with_line_width(2.0),
+            .with_line_width(dpi.px(2.0)),
         );
 
-        let marker_radius = 3.0;
-        let marker_r = knob_radius - 6.0;
+        let marker_radius = dpi.px(3.0);
+        let marker_r = knob_radius - dpi.px(6.0);
         let mx = cx0 + current_angle.cos() * marker_r;
         let my = cy0 + current_angle.sin() * marker_r;
 
@@ -547,3 +1411,139 @@ impl View for DialVisuals {
         );
     }
 }
+
+/// Padlock toggle next to a slider
+pub struct LockToggle {
+    lock: LockId,
+    locked: bool,
+}
+
+impl LockToggle {
+    pub fn new(cx: &mut Context, lock: LockId, locked: bool) -> Handle<'_, Self> {
+        Self { lock, locked }.build(cx, |_| {})
+    }
+}
+
+impl View for LockToggle {
+    fn element(&self) -> Option<&'static str> {
+        Some("lock-toggle")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            if let WindowEvent::MouseDown(MouseButton::Left) = window_event {
+                cx.emit(ParamLockEvent::Toggle(self.lock));
+                meta.consume();
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let b = cx.bounds();
+        let dpi = Dpi::new(cx);
+        let color = if self.locked {
+            nih_plug_vizia::vizia::vg::Color::rgb(245, 158, 11)
+        } else {
+            nih_plug_vizia::vizia::vg::Color::rgb(71, 85, 105)
+        };
+
+        // Body: lower half of the box
+        let body_w = b.w * 0.7;
+        let body_h = b.h * 0.45;
+        let body_x = b.x + (b.w - body_w) / 2.0;
+        let body_y = b.y + b.h - body_h - b.h * 0.05;
+        let mut body = nih_plug_vizia::vizia::vg::Path::new();
+        body.rounded_rect(body_x, body_y, body_w, body_h, dpi.px(1.5));
+        canvas.fill_path(&body, &nih_plug_vizia::vizia::vg::Paint::color(color));
+
+        // Shackle: closed when locked, right leg lifted when open
+        let left = body_x + body_w * 0.2;
+        let right = body_x + body_w * 0.8;
+        let mid = (left + right) / 2.0;
+        let top = b.y + b.h * 0.1;
+        let radius = (right - left) / 2.0;
+        let right_end = if self.locked { body_y } else { top + radius };
+        let mut shackle = nih_plug_vizia::vizia::vg::Path::new();
+        shackle.move_to(left, body_y);
+        shackle.line_to(left, top + radius);
+        shackle.quad_to(left, top, mid, top);
+        shackle.quad_to(right, top, right, top + radius);
+        shackle.line_to(right, right_end);
+        canvas.stroke_path(
+            &shackle,
+            &nih_plug_vizia::vizia::vg::Paint::color(color).with_line_width(dpi.px(1.5)),
+        );
+    }
+}
+
+/// Bottom-right drag handle. Unlike nih_plug_vizia's `ResizeHandle` (which
+/// rescales), this changes the logical window size so the layout reflows.
+pub struct WindowResizeHandle {
+    /// Cursor position (physical px) and window size when the drag started
+    drag_start: Option<(f32, f32, WindowSize)>,
+}
+
+impl WindowResizeHandle {
+    pub fn new(cx: &mut Context) -> Handle<'_, Self> {
+        Self { drag_start: None }.build(cx, |_| {})
+    }
+}
+
+impl View for WindowResizeHandle {
+    fn element(&self) -> Option<&'static str> {
+        Some("window-resize-handle")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                cx.capture();
+                cx.set_active(true);
+                self.drag_start = Some((cx.mouse().cursorx, cx.mouse().cursory, cx.window_size()));
+                meta.consume();
+            }
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+            WindowEvent::MouseMove(x, y) => {
+                if let Some((start_x, start_y, start_size)) = self.drag_start {
+                    // Cursor deltas are physical; the window size is logical
+                    let scale = cx.scale_factor().max(0.1);
+                    let width = (start_size.width as f32 + (x - start_x) / scale).round() as u32;
+                    let height = (start_size.height as f32 + (y - start_y) / scale).round() as u32;
+                    let size = WindowSize::new(
+                        width.clamp(super::EDITOR_MIN_SIZE.0, super::EDITOR_MAX_SIZE.0),
+                        height.clamp(super::EDITOR_MIN_SIZE.1, super::EDITOR_MAX_SIZE.1),
+                    );
+                    if size != cx.window_size() {
+                        cx.set_window_size(size);
+                    }
+                }
+            }
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let b = cx.bounds();
+
+        // Three diagonal grip lines in the corner
+        let mut grip = nih_plug_vizia::vizia::vg::Path::new();
+        for i in 1..=3 {
+            let offset = b.w * i as f32 / 4.0;
+            grip.move_to(b.x + b.w - offset, b.y + b.h);
+            grip.line_to(b.x + b.w, b.y + b.h - offset);
+        }
+        canvas.stroke_path(
+            &grip,
+            &nih_plug_vizia::vizia::vg::Paint::color(nih_plug_vizia::vizia::vg::Color::rgb(
+                100, 116, 139,
+            ))
+            .with_line_width(Dpi::new(cx).px(1.5)),
+        );
+    }
+}
@@ -3,21 +3,35 @@
 //! This module provides functions for building the high-level UI structure:
 //! - Header with title and mode toggle
 //! - Body with levels, macro/advanced sections, and output
-//! - Footer with help, reset, and debug buttons
+//! - Footer with help, reset, settings export/import, and debug buttons
 
+use crate::advisor::Advisor;
+use crate::dsp::GuardStage;
 use crate::meters::Meters;
+use crate::param_locks::{set_unlocked, LockId};
+use crate::tasks::{Task, TaskQueue};
 use crate::ui::advanced::{build_clean_repair_tab, build_shape_polish_tab};
 use crate::ui::components::{
-    create_button, create_dropdown, create_dsp_preset_dropdown, create_macro_dial, create_slider,
-    create_toggle_button,
+    create_button, create_dither_dropdown, create_dropdown, create_dsp_preset_dropdown,
+    create_final_stage_dropdown, create_limiter_character_dropdown, create_macro_dial,
+    create_momentary_button, create_routing_dropdown, create_slider, create_test_signal_dropdown,
+    create_toggle_button, live_locked,
 };
-use crate::ui::state::{AdvancedTab, AdvancedTabEvent, VoiceStudioData};
-use crate::ui::ParamId;
-use crate::VoiceParams;
+use crate::ui::locale::{self, tr};
+use crate::ui::onboarding::{build_onboarding, OnboardingEvent};
+use crate::ui::state::{
+    AdvancedTab, AdvancedTabEvent, AdvisorEvent, DragTip, LanguageEvent, LiveValues,
+    MeterModeEvent, SettingsFileEvent, ThemeEvent, TrackProfileEvent, UiScaleEvent,
+    VoiceStudioData, METER_POLL_MS, MODE_WATCH_MS,
+};
+use crate::ui::{theme, ParamId, WindowResizeHandle, UI_SCALE_OPTIONS};
+use crate::{DetectedConditions, VoiceParams};
+use nih_plug::params::persist::PersistentField;
 use nih_plug::prelude::GuiContext;
-use nih_plug_vizia::vizia::prelude::ContextProxy;
 use nih_plug_vizia::vizia::prelude::*;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, OnceLock};
 
 pub fn build_header<'a>(
     cx: &'a mut Context,
@@ -26,11 +40,49 @@ pub fn build_header<'a>(
 ) -> Handle<'a, HStack> {
     HStack::new(cx, move |cx| {
         VStack::new(cx, move |cx| {
-            Label::new(cx, "VxCLEANER").class("header-title");
-            Label::new(cx, "Vocal Restoration").class("header-sub");
+            Label::new(cx, &tr("VxCLEANER")).class("header-title");
+            Label::new(cx, &tr("Vocal Restoration")).class("header-sub");
         })
         .class("header-title-stack");
 
+        // Shown only after the audio thread caught a panic. A click latches
+        // Reinitialize; it is cleared here once the fault meter reads clean,
+        // so the audio thread sees it however long the click or the host's
+        // parameter round trip takes.
+        let params_fault = params.clone();
+        let gui_fault = gui.clone();
+        Binding::new(cx, VoiceStudioData::dsp_fault, move |cx, lens| {
+            if !lens.get(cx) {
+                if params_fault.dsp_reinit.value() {
+                    let s = nih_plug::prelude::ParamSetter::new(gui_fault.as_ref());
+                    s.begin_set_parameter(&params_fault.dsp_reinit);
+                    s.set_parameter(&params_fault.dsp_reinit, false);
+                    s.end_set_parameter(&params_fault.dsp_reinit);
+                }
+                return;
+            }
+            let params_down = params_fault.clone();
+            let gui_down = gui_fault.clone();
+            HStack::new(cx, |cx| {
+                Label::new(cx, &tr("DSP error – bypassed, click to reinitialize")).hoverable(false);
+            })
+            .class("dsp-fault-banner")
+            .on_mouse_down(move |_, btn| {
+                if btn == MouseButton::Left {
+                    let s = nih_plug::prelude::ParamSetter::new(gui_down.as_ref());
+                    s.begin_set_parameter(&params_down.dsp_reinit);
+                    s.set_parameter(&params_down.dsp_reinit, true);
+                    s.end_set_parameter(&params_down.dsp_reinit);
+                }
+            })
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("The processing hit an internal error and the plugin switched to a clean, latency-aligned bypass. Click to rebuild the DSP; your settings are kept."),
+                );
+            });
+        });
+
         Element::new(cx).class("fill-width");
 
         Binding::new(
@@ -42,29 +94,32 @@ pub fn build_header<'a>(
                 // Clone inside Binding so we do not move captured Arcs into nested move closures
                 let params_local = params.clone();
                 let gui_local = gui.clone();
+                let meters_local = VoiceStudioData::meters.get(cx);
 
                 HStack::new(cx, move |cx| {
                     // Each button gets its own clones so nothing is consumed
                     let p1 = params_local.clone();
                     let g1 = gui_local.clone();
+                    let m1 = meters_local.clone();
                     create_toggle_button(
                         cx,
                         "Simple",
                         m,
                         "mode-button-active",
                         "mode-button",
-                        move |_| crate::ui::state::set_macro_mode(&p1, &g1, true),
+                        move |_| crate::ui::state::set_macro_mode(&p1, &m1, &g1, true),
                     );
 
                     let p2 = params_local.clone();
                     let g2 = gui_local.clone();
+                    let m2 = meters_local.clone();
                     create_toggle_button(
                         cx,
                         "Advanced",
                         !m,
                         "mode-button-active",
                         "mode-button",
-                        move |_| crate::ui::state::set_macro_mode(&p2, &g2, false),
+                        move |_| crate::ui::state::set_macro_mode(&p2, &m2, &g2, false),
                     );
                 })
                 .class("mode-group");
@@ -78,6 +133,7 @@ pub fn build_footer<'a>(
     cx: &'a mut Context,
     params: Arc<VoiceParams>,
     gui: Arc<dyn GuiContext>,
+    tasks: TaskQueue,
 ) -> Handle<'a, HStack> {
     HStack::new(cx, move |cx| {
         Binding::new(
@@ -116,33 +172,187 @@ pub fn build_footer<'a>(
 
         Element::new(cx).class("fill-width");
 
+        // Calibration / audition signal in place of the input
+        create_test_signal_dropdown(cx, "TEST", params.clone(), gui.clone());
+
+        // Theme selector (built-in + user themes from the config folder)
+        Dropdown::new(
+            cx,
+            |cx| Label::new(cx, VoiceStudioData::theme).class("dropdown-selected"),
+            |cx| {
+                Binding::new(cx, VoiceStudioData::theme_names, |cx, names| {
+                    let names = names.get(cx);
+                    VStack::new(cx, move |cx| {
+                        for name in names.iter().cloned() {
+                            Label::new(cx, name.clone())
+                                .class("dropdown-option")
+                                .on_press(move |cx| {
+                                    cx.emit(ThemeEvent::Set(name.clone()));
+                                    cx.emit(PopupEvent::Close);
+                                });
+                        }
+                    })
+                    .class("dropdown-options");
+                });
+            },
+        )
+        .class("dropdown-box")
+        .class("theme-dropdown")
+        .tooltip(|cx| {
+            let hint = theme::user_theme_dir()
+                .map(|dir| format!("{} {}", tr("Add your own .css themes to"), dir.display()))
+                .unwrap_or_else(|| tr("Choose the editor theme"));
+            Label::new(cx, &hint);
+        });
+
+        // Language selector (English + language files from the config folder)
+        Dropdown::new(
+            cx,
+            |cx| Label::new(cx, VoiceStudioData::language).class("dropdown-selected"),
+            |cx| {
+                Binding::new(cx, VoiceStudioData::language_names, |cx, names| {
+                    let names = names.get(cx);
+                    VStack::new(cx, move |cx| {
+                        for name in names.iter().cloned() {
+                            Label::new(cx, name.clone())
+                                .class("dropdown-option")
+                                .on_press(move |cx| {
+                                    cx.emit(LanguageEvent::Set(name.clone()));
+                                    cx.emit(PopupEvent::Close);
+                                });
+                        }
+                    })
+                    .class("dropdown-options");
+                });
+            },
+        )
+        .class("dropdown-box")
+        .class("theme-dropdown")
+        .tooltip(|cx| {
+            let hint = locale::user_language_dir()
+                .map(|dir| {
+                    format!(
+                        "{} {}",
+                        tr("Interface language, applied when the editor is next opened. Add translations as .json files to"),
+                        dir.display()
+                    )
+                })
+                .unwrap_or_else(|| tr("Interface language, applied when the editor is next opened"));
+            Label::new(cx, &hint);
+        });
+
+        // UI scale (the wrapper persists it in the editor state)
+        Binding::new(cx, VoiceStudioData::ui_scale, |cx, lens| {
+            let current = lens.get(cx);
+            HStack::new(cx, move |cx| {
+                for (scale, label) in UI_SCALE_OPTIONS {
+                    create_toggle_button(
+                        cx,
+                        label,
+                        (current - scale).abs() < 0.01,
+                        "scale-button-active",
+                        "scale-button",
+                        move |cx| cx.emit(UiScaleEvent::Set(scale)),
+                    );
+                }
+            })
+            .class("scale-group");
+        });
+
+        // Processing quality (CPU vs fidelity)
+        let params_quality = params.clone();
+        let gui_quality = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.quality.value()),
+            move |cx, lens| {
+                let current = lens.get(cx);
+                let params = params_quality.clone();
+                let gui = gui_quality.clone();
+                HStack::new(cx, move |cx| {
+                    for mode in crate::presets::QualityMode::all_modes() {
+                        let params = params.clone();
+                        let gui = gui.clone();
+                        create_toggle_button(
+                            cx,
+                            mode.name(),
+                            current == mode,
+                            "scale-button-active",
+                            "scale-button",
+                            move |_| {
+                                // Latency changes glitch a live stream
+                                if params.safe_live_mode.value() {
+                                    return;
+                                }
+                                let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                                s.begin_set_parameter(&params.quality);
+                                s.set_parameter(&params.quality, mode);
+                                s.end_set_parameter(&params.quality);
+                            },
+                        );
+                    }
+                })
+                .class("quality-group")
+                .toggle_class("live-locked", live_locked())
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Eco saves CPU with smaller FFTs and lighter analysis; High uses larger FFTs for finer frequency detail. Changing it clears a learned static noise profile."),
+                    );
+                });
+            },
+        );
+
         // Split clones for the footer buttons
         let params_reset = params.clone();
         let gui_reset = gui.clone();
+        let params_analysis = params.clone();
+        let gui_analysis = gui.clone();
+        let gui_export = gui.clone();
+        let tasks_export = tasks.clone();
+        let tasks_history = tasks.clone();
+        #[cfg(feature = "debug")]
+        let tasks_css = tasks.clone();
 
         HStack::new(cx, move |cx| {
             create_button(cx, "Help", "footer-button", move |_| {
                 open_url("https://www.marczewski.me.uk/vxcleaner/help.html");
             });
 
+            create_button(cx, "Tour", "footer-button", |cx| {
+                cx.emit(OnboardingEvent::Start)
+            })
+            .tooltip(|cx| {
+                Label::new(cx, &tr("Replay the first-run walkthrough"));
+            });
+
             create_button(cx, "Reset", "footer-button", move |_| {
+                if params_reset.safe_live_mode.value() {
+                    return;
+                }
                 let s = nih_plug::prelude::ParamSetter::new(gui_reset.as_ref());
-                s.begin_set_parameter(&params_reset.noise_reduction);
-                s.set_parameter(&params_reset.noise_reduction, 0.0);
-                s.end_set_parameter(&params_reset.noise_reduction);
+                // Locked sliders keep their value
+                let locks = params_reset.param_locks.as_ref();
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::NoiseReduction,
+                    &params_reset.noise_reduction,
+                    0.0,
+                );
 
-                s.begin_set_parameter(&params_reset.rumble_amount);
-                s.set_parameter(&params_reset.rumble_amount, 0.0);
-                s.end_set_parameter(&params_reset.rumble_amount);
+                set_unlocked(&s, locks, LockId::Rumble, &params_reset.rumble_amount, 0.0);
 
-                s.begin_set_parameter(&params_reset.hiss_amount);
-                s.set_parameter(&params_reset.hiss_amount, 0.0);
-                s.end_set_parameter(&params_reset.hiss_amount);
+                set_unlocked(&s, locks, LockId::Hiss, &params_reset.hiss_amount, 0.0);
 
                 // Reset Static Noise Params
-                s.begin_set_parameter(&params_reset.noise_learn_amount);
-                s.set_parameter(&params_reset.noise_learn_amount, 0.0);
-                s.end_set_parameter(&params_reset.noise_learn_amount);
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::StaticNoise,
+                    &params_reset.noise_learn_amount,
+                    0.0,
+                );
 
                 s.begin_set_parameter(&params_reset.noise_learn_trigger);
                 s.set_parameter(&params_reset.noise_learn_trigger, false);
@@ -152,6 +362,14 @@ pub fn build_footer<'a>(
                 s.set_parameter(&params_reset.noise_learn_clear, false);
                 s.end_set_parameter(&params_reset.noise_learn_clear);
 
+                s.begin_set_parameter(&params_reset.noise_learn_auto);
+                s.set_parameter(&params_reset.noise_learn_auto, false);
+                s.end_set_parameter(&params_reset.noise_learn_auto);
+
+                s.begin_set_parameter(&params_reset.noise_learn_freeze);
+                s.set_parameter(&params_reset.noise_learn_freeze, false);
+                s.end_set_parameter(&params_reset.noise_learn_freeze);
+
                 s.begin_set_parameter(&params_reset.post_noise_hf_bias);
                 s.set_parameter(&params_reset.post_noise_hf_bias, true);
                 s.end_set_parameter(&params_reset.post_noise_hf_bias);
@@ -164,33 +382,175 @@ pub fn build_footer<'a>(
                 s.set_parameter(&params_reset.low_end_protect, true);
                 s.end_set_parameter(&params_reset.low_end_protect);
 
-                s.begin_set_parameter(&params_reset.reverb_reduction);
-                s.set_parameter(&params_reset.reverb_reduction, 0.0);
-                s.end_set_parameter(&params_reset.reverb_reduction);
+                s.begin_set_parameter(&params_reset.denoise_engine);
+                s.set_parameter(
+                    &params_reset.denoise_engine,
+                    crate::presets::DenoiseEngine::Standard,
+                );
+                s.end_set_parameter(&params_reset.denoise_engine);
+
+                for param in [
+                    &params_reset.low_cut_linear_phase,
+                    &params_reset.input_swap,
+                    &params_reset.input_invert_l,
+                    &params_reset.input_invert_r,
+                    &params_reset.freeze_adaptation,
+                ] {
+                    s.begin_set_parameter(param);
+                    s.set_parameter(param, false);
+                    s.end_set_parameter(param);
+                }
+
+                s.begin_set_parameter(&params_reset.loudness_comp);
+                s.set_parameter(&params_reset.loudness_comp, true);
+                s.end_set_parameter(&params_reset.loudness_comp);
+
+                s.begin_set_parameter(&params_reset.noise_model_freeze);
+                s.set_parameter(&params_reset.noise_model_freeze, false);
+                s.end_set_parameter(&params_reset.noise_model_freeze);
+
+                s.begin_set_parameter(&params_reset.artifact_guard);
+                s.set_parameter(&params_reset.artifact_guard, true);
+                s.end_set_parameter(&params_reset.artifact_guard);
+
+                s.begin_set_parameter(&params_reset.comfort_noise);
+                s.set_parameter(&params_reset.comfort_noise, false);
+                s.end_set_parameter(&params_reset.comfort_noise);
+
+                s.begin_set_parameter(&params_reset.comfort_noise_level);
+                s.set_parameter(&params_reset.comfort_noise_level, -15.0);
+                s.end_set_parameter(&params_reset.comfort_noise_level);
+
+                s.begin_set_parameter(&params_reset.room_tone_bridge);
+                s.set_parameter(&params_reset.room_tone_bridge, false);
+                s.end_set_parameter(&params_reset.room_tone_bridge);
+
+                s.begin_set_parameter(&params_reset.music_guard);
+                s.set_parameter(&params_reset.music_guard, false);
+                s.end_set_parameter(&params_reset.music_guard);
+
+                s.begin_set_parameter(&params_reset.bandwidth_extend);
+                s.set_parameter(&params_reset.bandwidth_extend, 0.0);
+                s.end_set_parameter(&params_reset.bandwidth_extend);
+
+                s.begin_set_parameter(&params_reset.transient_amount);
+                s.set_parameter(&params_reset.transient_amount, 0.0);
+                s.end_set_parameter(&params_reset.transient_amount);
+
+                s.begin_set_parameter(&params_reset.transient_sensitivity);
+                s.set_parameter(&params_reset.transient_sensitivity, 0.5);
+                s.end_set_parameter(&params_reset.transient_sensitivity);
+
+                s.begin_set_parameter(&params_reset.dead_air_threshold);
+                s.set_parameter(&params_reset.dead_air_threshold, 2.0);
+                s.end_set_parameter(&params_reset.dead_air_threshold);
+
+                s.begin_set_parameter(&params_reset.pause_trim);
+                s.set_parameter(&params_reset.pause_trim, false);
+                s.end_set_parameter(&params_reset.pause_trim);
+
+                s.begin_set_parameter(&params_reset.pause_level);
+                s.set_parameter(&params_reset.pause_level, -60.0);
+                s.end_set_parameter(&params_reset.pause_level);
+
+                s.begin_set_parameter(&params_reset.de_ess_treat);
+                s.set_parameter(&params_reset.de_ess_treat, 0.25);
+                s.end_set_parameter(&params_reset.de_ess_treat);
+                params_reset.de_ess_threshold.set(None);
+
+                for (param, value) in [
+                    (&params_reset.noise_character, 0.0),
+                    (&params_reset.noise_floor_depth, 0.5),
+                    (&params_reset.noise_learn_time, 0.5),
+                    (&params_reset.deverb_character, 0.0),
+                    (&params_reset.tail_release, 1.0),
+                    (&params_reset.denoise_stereo_link, 1.0),
+                    (&params_reset.speech_loss_budget, 2.0),
+                    (&params_reset.speech_band_low, 300.0),
+                    (&params_reset.speech_band_high, 3000.0),
+                    (&params_reset.input_balance, 0.0),
+                    (&params_reset.leveler_target, -24.0),
+                    (&params_reset.density, 0.0),
+                    (&params_reset.clarity_freq, 250.0),
+                    (&params_reset.clarity_q, 0.7),
+                    (&params_reset.de_harsh, 0.0),
+                    (&params_reset.presence_gain, 0.0),
+                    (&params_reset.presence_freq, 3000.0),
+                    (&params_reset.clip_drive, 0.0),
+                    (&params_reset.clip_ceiling, -1.0),
+                    (&params_reset.loudness_comp_trim, 0.0),
+                ] {
+                    s.begin_set_parameter(param);
+                    s.set_parameter(param, value);
+                    s.end_set_parameter(param);
+                }
+
+                // Modulation routes off
+                for depth in [
+                    &params_reset.mod_clarity_depth,
+                    &params_reset.mod_proximity_depth,
+                    &params_reset.mod_deess_depth,
+                ] {
+                    s.begin_set_parameter(depth);
+                    s.set_parameter(depth, 0.0);
+                    s.end_set_parameter(depth);
+                }
+
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::DeVerb,
+                    &params_reset.reverb_reduction,
+                    0.0,
+                );
+
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::DeverbEarly,
+                    &params_reset.deverb_early,
+                    1.0,
+                );
+
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::DeverbTail,
+                    &params_reset.deverb_tail,
+                    1.0,
+                );
+
+                set_unlocked(&s, locks, LockId::Clarity, &params_reset.clarity, 0.0);
 
-                s.begin_set_parameter(&params_reset.clarity);
-                s.set_parameter(&params_reset.clarity, 0.0);
-                s.end_set_parameter(&params_reset.clarity);
+                set_unlocked(&s, locks, LockId::Proximity, &params_reset.proximity, 0.0);
 
-                s.begin_set_parameter(&params_reset.proximity);
-                s.set_parameter(&params_reset.proximity, 0.0);
-                s.end_set_parameter(&params_reset.proximity);
+                set_unlocked(&s, locks, LockId::DeEsser, &params_reset.de_esser, 0.0);
 
-                s.begin_set_parameter(&params_reset.de_esser);
-                s.set_parameter(&params_reset.de_esser, 0.0);
-                s.end_set_parameter(&params_reset.de_esser);
+                set_unlocked(&s, locks, LockId::Leveler, &params_reset.leveler, 0.0);
 
-                s.begin_set_parameter(&params_reset.leveler);
-                s.set_parameter(&params_reset.leveler, 0.0);
-                s.end_set_parameter(&params_reset.leveler);
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::OutputGain,
+                    &params_reset.output_gain,
+                    0.0,
+                );
 
-                s.begin_set_parameter(&params_reset.output_gain);
-                s.set_parameter(&params_reset.output_gain, 0.0);
-                s.end_set_parameter(&params_reset.output_gain);
+                set_unlocked(
+                    &s,
+                    locks,
+                    LockId::BreathControl,
+                    &params_reset.breath_control,
+                    0.25,
+                );
 
-                s.begin_set_parameter(&params_reset.breath_control);
-                s.set_parameter(&params_reset.breath_control, 0.25);
-                s.end_set_parameter(&params_reset.breath_control);
+                s.begin_set_parameter(&params_reset.breath_mode);
+                s.set_parameter(&params_reset.breath_mode, crate::presets::BreathMode::Reduce);
+                s.end_set_parameter(&params_reset.breath_mode);
+
+                s.begin_set_parameter(&params_reset.breath_level);
+                s.set_parameter(&params_reset.breath_level, -40.0);
+                s.end_set_parameter(&params_reset.breath_level);
 
                 s.begin_set_parameter(&params_reset.use_ml);
                 s.set_parameter(&params_reset.use_ml, true);
@@ -212,6 +572,16 @@ pub fn build_footer<'a>(
                 s.set_parameter(&params_reset.macro_control, 0.0);
                 s.end_set_parameter(&params_reset.macro_control);
 
+                for auto in [
+                    &params_reset.macro_clean_auto,
+                    &params_reset.macro_enhance_auto,
+                    &params_reset.macro_control_auto,
+                ] {
+                    s.begin_set_parameter(auto);
+                    s.set_parameter(auto, false);
+                    s.end_set_parameter(auto);
+                }
+
                 s.begin_set_parameter(&params_reset.final_output_preset);
                 s.set_parameter(
                     &params_reset.final_output_preset,
@@ -219,6 +589,75 @@ pub fn build_footer<'a>(
                 );
                 s.end_set_parameter(&params_reset.final_output_preset);
 
+                s.begin_set_parameter(&params_reset.output_dither);
+                s.set_parameter(&params_reset.output_dither, crate::presets::DitherMode::Off);
+                s.end_set_parameter(&params_reset.output_dither);
+
+                s.begin_set_parameter(&params_reset.output_routing);
+                s.set_parameter(
+                    &params_reset.output_routing,
+                    crate::presets::OutputRouting::Stereo,
+                );
+                s.end_set_parameter(&params_reset.output_routing);
+
+                s.begin_set_parameter(&params_reset.final_stage);
+                s.set_parameter(
+                    &params_reset.final_stage,
+                    crate::presets::FinalStage::Limiter,
+                );
+                s.end_set_parameter(&params_reset.final_stage);
+
+                s.begin_set_parameter(&params_reset.limiter_character);
+                s.set_parameter(
+                    &params_reset.limiter_character,
+                    crate::presets::LimiterCharacter::Transparent,
+                );
+                s.end_set_parameter(&params_reset.limiter_character);
+
+                s.begin_set_parameter(&params_reset.chain_order);
+                s.set_parameter(
+                    &params_reset.chain_order,
+                    crate::presets::ChainOrder::Standard,
+                );
+                s.end_set_parameter(&params_reset.chain_order);
+
+                s.begin_set_parameter(&params_reset.test_signal);
+                s.set_parameter(&params_reset.test_signal, crate::presets::TestSignal::Off);
+                s.end_set_parameter(&params_reset.test_signal);
+
+                s.begin_set_parameter(&params_reset.mono_fix);
+                s.set_parameter(&params_reset.mono_fix, false);
+                s.end_set_parameter(&params_reset.mono_fix);
+
+                s.begin_set_parameter(&params_reset.proximity_track_room);
+                s.set_parameter(&params_reset.proximity_track_room, false);
+                s.end_set_parameter(&params_reset.proximity_track_room);
+
+                s.begin_set_parameter(&params_reset.low_cut);
+                s.set_parameter(&params_reset.low_cut, crate::presets::LowCut::Hz90);
+                s.end_set_parameter(&params_reset.low_cut);
+
+                s.begin_set_parameter(&params_reset.dynamics_response);
+                s.set_parameter(
+                    &params_reset.dynamics_response,
+                    crate::presets::DynamicsResponse::Natural,
+                );
+                s.end_set_parameter(&params_reset.dynamics_response);
+
+                s.begin_set_parameter(&params_reset.guardrails);
+                s.set_parameter(
+                    &params_reset.guardrails,
+                    crate::presets::GuardrailStrength::Normal,
+                );
+                s.end_set_parameter(&params_reset.guardrails);
+
+                s.begin_set_parameter(&params_reset.low_cut_slope);
+                s.set_parameter(
+                    &params_reset.low_cut_slope,
+                    crate::presets::LowCutSlope::Db12,
+                );
+                s.end_set_parameter(&params_reset.low_cut_slope);
+
                 s.begin_set_parameter(&params_reset.reset_all);
                 s.set_parameter(&params_reset.reset_all, true);
                 s.end_set_parameter(&params_reset.reset_all);
@@ -226,6 +665,95 @@ pub fn build_footer<'a>(
                 s.begin_set_parameter(&params_reset.reset_all);
                 s.set_parameter(&params_reset.reset_all, false);
                 s.end_set_parameter(&params_reset.reset_all);
+            })
+            .toggle_class("live-locked", live_locked());
+
+            // Clears learned noise/loudness state only; slider values are kept
+            create_momentary_button(
+                cx,
+                "Reset Analysis",
+                params_analysis.clone(),
+                gui_analysis.clone(),
+                |p| &p.reset_analysis,
+            )
+            .class("footer-button")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Clear learned noise, room and loudness analysis without changing any controls"),
+                );
+            });
+
+            // Shareable .vxc settings files in the per-user settings folder
+            // Files are written and read on the background thread; Import
+            // rescans the folder when it is opened
+            create_button(cx, "Export…", "footer-button", move |_| {
+                let state = crate::settings_file::shareable_state(gui_export.as_ref());
+                tasks_export(Task::ExportSettings(state));
+            })
+            .tooltip(|cx| {
+                let hint = crate::settings_file::settings_dir()
+                    .map(|dir| {
+                        format!(
+                            "{} {}",
+                            tr("Save every control, lock and speaker profile as a .vxc file in"),
+                            dir.display()
+                        )
+                    })
+                    .unwrap_or_else(|| tr("Save every control as a .vxc file"));
+                Label::new(cx, &hint);
+            });
+
+            Dropdown::new(
+                cx,
+                |cx| Label::new(cx, &tr("Import…")).class("dropdown-selected"),
+                move |cx| {
+                    Binding::new(cx, VoiceStudioData::settings_files, move |cx, names| {
+                        let names = names.get(cx);
+                        VStack::new(cx, move |cx| {
+                            if names.is_empty() {
+                                Label::new(cx, &tr("No .vxc files")).class("dropdown-option");
+                            }
+                            for name in names.iter().cloned() {
+                                Label::new(cx, name.clone())
+                                    .class("dropdown-option")
+                                    .on_press(move |cx| {
+                                        cx.emit(SettingsFileEvent::Import(name.clone()));
+                                        cx.emit(PopupEvent::Close);
+                                    });
+                            }
+                        })
+                        .class("dropdown-options");
+                    });
+                },
+            )
+            .class("dropdown-box")
+            .class("settings-dropdown")
+            .on_press_down(|cx| cx.emit(SettingsFileEvent::Rescan))
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Apply a .vxc settings file from the settings folder. Editor size and theme are kept."),
+                );
+            });
+
+            // Input profile log (last ten minutes) for tuning target ranges
+            create_button(cx, "Profile CSV", "footer-button", move |_| {
+                tasks_history(Task::ExportProfileHistory);
+            })
+            .tooltip(|cx| {
+                let hint = crate::profile_history::history_dir()
+                    .map(|dir| {
+                        format!(
+                            "{} {}",
+                            tr("Save the input analysis of the last ten minutes (ten rows a second: level, noise, room and tone metrics with speech confidence) as a CSV file in"),
+                            dir.display()
+                        )
+                    })
+                    .unwrap_or_else(|| {
+                        tr("Save the input analysis of the last ten minutes as a CSV file")
+                    });
+                Label::new(cx, &hint);
             });
 
             #[cfg(feature = "debug")]
@@ -254,53 +782,8 @@ pub fn build_footer<'a>(
 
             #[cfg(feature = "debug")]
             create_button(cx, "Edit CSS", "footer-button", move |_| {
-                // Get CSS file path in bundle folder
-                if let Ok(exe_path) = std::env::current_exe() {
-                    #[cfg(target_os = "macos")]
-                    let css_path = {
-                        if let Some(macos_dir) = exe_path.parent() {
-                            if let Some(contents_dir) = macos_dir.parent() {
-                                if let Some(vst_bundle) = contents_dir.parent() {
-                                    Some(vst_bundle.join("ui.css"))
-                                } else {
-                                    None
-                                }
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    };
-
-                    #[cfg(not(target_os = "macos"))]
-                    let css_path = exe_path.parent().map(|p| p.join("ui.css"));
-
-                    if let Some(path) = css_path {
-                        // Create file if it doesn't exist
-                        if !path.exists() {
-                            let _ = std::fs::write(&path, STYLE);
-                        }
-
-                        // Open in system editor
-                        #[cfg(target_os = "macos")]
-                        {
-                            let _ = std::process::Command::new("open")
-                                .arg("-t")
-                                .arg(&path)
-                                .spawn();
-                        }
-
-                        #[cfg(target_os = "linux")]
-                        {
-                            let _ = std::process::Command::new("xdg-open").arg(&path).spawn();
-                        }
-
-                        #[cfg(target_os = "windows")]
-                        {
-                            let _ = std::process::Command::new("notepad").arg(&path).spawn();
-                        }
-                    }
+                if let Some(path) = stylesheet_path() {
+                    tasks_css(Task::WriteStylesheet { path, open: true });
                 }
             });
         })
@@ -341,7 +824,12 @@ pub fn build_body<'a>(
                     let gui_local = g.clone();
 
                     if simple {
-                        build_macro(cx, params_local.clone(), gui_local.clone());
+                        build_macro(
+                            cx,
+                            params_local.clone(),
+                            gui_local.clone(),
+                            meters_local.clone(),
+                        );
                         Element::new(cx).class("fill-width");
                     } else {
                         // Tab Headers
@@ -406,22 +894,53 @@ pub fn build_body<'a>(
     .class("main-view")
 }
 
+/// Numeric peak-hold readout under a meter pair (dBFS)
+fn peak_readout(cx: &mut Context, hold_db: impl Lens<Target = f32>) {
+    Label::new(
+        cx,
+        hold_db.map(|&db| {
+            if db <= -79.95 {
+                "-inf".to_string()
+            } else {
+                format!("{:.1}", db)
+            }
+        }),
+    )
+    .class("peak-readout")
+    .tooltip(|cx| {
+        Label::new(
+            cx,
+            &tr("Highest peak of the last 3 seconds (dBFS, louder channel). Click the meter to clear it."),
+        );
+    });
+}
+
 pub fn build_levels<'a>(cx: &'a mut Context, meters: Arc<Meters>) -> Handle<'a, VStack> {
     // IMPORTANT: break Arc<Meters> into independent clones so nested move closures don't "consume" it
     let meters_in = meters.clone();
     let meters_gr = meters.clone();
     let meters_out = meters.clone();
     let meters_floor = meters.clone();
+    let meters_phase = meters.clone();
 
     VStack::new(cx, move |cx| {
-        Label::new(cx, "LEVELS")
-            .class("column-header")
-            .class("col-levels");
+        Label::new(
+            cx,
+            VoiceStudioData::meter_mode.map(|mode| format!("{} {}", tr("LEVELS"), mode.name())),
+        )
+        .class("column-header")
+        .class("col-levels")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Right-click the meters to switch between Peak, RMS, K-12 and K-14. K-12 and K-14 are sine-calibrated RMS with 0 on the scale at -12 or -14 dBFS: keep speech around the 0 mark."),
+            );
+        });
 
         HStack::new(cx, move |cx| {
             let mi = meters_in.clone();
             VStack::new(cx, move |cx| {
-                Label::new(cx, "IN").class("meter-label");
+                Label::new(cx, &tr("IN")).class("meter-label");
                 let mi2 = mi.clone();
                 HStack::new(cx, |cx| {
                     crate::ui::meters::LevelMeter::new(
@@ -438,25 +957,38 @@ pub fn build_levels<'a>(cx: &'a mut Context, meters: Arc<Meters>) -> Handle<'a,
                     .class("meter-track");
                 })
                 .class("meter-pair");
+                peak_readout(cx, VoiceStudioData::peak_hold.map(|&(input, _)| input));
             })
             .class("meter-col");
 
             let mg = meters_gr.clone();
             VStack::new(cx, move |cx| {
-                Label::new(cx, "GR").class("meter-label");
-                crate::ui::meters::LevelMeter::new(
-                    cx,
-                    mg.clone(),
-                    crate::ui::meters::MeterType::GainReduction,
-                )
-                .class("meter-track")
+                Label::new(cx, &tr("GR")).class("meter-label");
+                let mg2 = mg.clone();
+                HStack::new(cx, |cx| {
+                    for meter_type in [
+                        crate::ui::meters::MeterType::LevelerGr,
+                        crate::ui::meters::MeterType::DeEsserGr,
+                        crate::ui::meters::MeterType::LimiterGr,
+                    ] {
+                        crate::ui::meters::LevelMeter::new(cx, mg2.clone(), meter_type)
+                            .class("gr-track");
+                    }
+                })
+                .class("meter-pair")
                 .class("fill-height");
             })
-            .class("meter-col");
+            .class("meter-col")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Gain reduction per dynamics stage, 0 to 20 dB: Leveler (orange), De-Esser (blue) and Limiter (red)."),
+                );
+            });
 
             let mo = meters_out.clone();
             VStack::new(cx, move |cx| {
-                Label::new(cx, "OUT").class("meter-label");
+                Label::new(cx, &tr("OUT")).class("meter-label");
                 let mo2 = mo.clone();
                 HStack::new(cx, |cx| {
                     crate::ui::meters::LevelMeter::new(
@@ -473,6 +1005,7 @@ pub fn build_levels<'a>(cx: &'a mut Context, meters: Arc<Meters>) -> Handle<'a,
                     .class("meter-track");
                 })
                 .class("meter-pair");
+                peak_readout(cx, VoiceStudioData::peak_hold.map(|&(_, output)| output));
             })
             .class("meter-col");
         })
@@ -482,47 +1015,153 @@ pub fn build_levels<'a>(cx: &'a mut Context, meters: Arc<Meters>) -> Handle<'a,
 
         let mf = meters_floor.clone();
         HStack::new(cx, move |cx| {
-            Label::new(cx, "ACTIVITY").class("meter-label");
+            Label::new(cx, &tr("ACTIVITY")).class("meter-label");
             crate::ui::meters::NoiseFloorLeds::new(cx, mf.clone()).class("noise-floor-leds");
         })
         .class("noise-floor-row");
+
+        let mp = meters_phase.clone();
+        HStack::new(cx, move |cx| {
+            Label::new(cx, &tr("PHASE")).class("meter-label");
+            crate::ui::meters::CorrelationMeter::new(cx, mp.clone()).class("correlation-meter");
+        })
+        .class("correlation-row")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Output L/R correlation. Red: the mix cancels when folded to mono. Amber: Mono Fix is narrowing the image."),
+            );
+        });
+
+        HStack::new(cx, |cx| {
+            Label::new(cx, &tr("GUARD")).class("meter-label");
+            Label::new(
+                cx,
+                VoiceStudioData::guardrail_cuts
+                    .map(|&(low, high)| format!("LM -{:.1}  HF -{:.1} dB", low, high)),
+            )
+            .class("guardrails-readout");
+        })
+        .class("guardrails-row")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Spectral guardrails at work: low-mid (boom) and high (harshness) cut applied right now. Set the strength with Guardrails on the Shape & Polish tab."),
+            );
+        });
+
+        HStack::new(cx, |cx| {
+            let badge = |cx: &mut Context, text: &str, lit: fn(&DetectedConditions) -> bool| {
+                Label::new(cx, &tr(text))
+                    .class("condition-badge")
+                    .toggle_class("condition-badge-on", VoiceStudioData::input_conditions.map(lit));
+            };
+            badge(cx, "WHISPER", |c| c.whisper);
+            badge(cx, "DISTANT", |c| c.distant_mic);
+            badge(cx, "NOISY", |c| c.noisy_environment);
+            badge(cx, "CLEAN", |c| c.clean_audio);
+        })
+        .class("condition-row")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("What the input sounds like right now: whispered, a distant mic, a noisy room, or already clean. Read from the unprocessed input."),
+            );
+        });
+
+        // Session share under each badge
+        HStack::new(cx, |cx| {
+            for k in 0..4 {
+                Label::new(
+                    cx,
+                    VoiceStudioData::condition_shares.map(move |shares| format!("{}%", shares[k])),
+                )
+                .class("condition-share");
+            }
+        })
+        .class("condition-share-row")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("Share of the session (since the plugin loaded or Reset Analysis) each badge was lit while the input had signal. A condition that stays high, like a distant mic most of the time, is better fixed at the recording setup than with more processing."),
+            );
+        });
     })
     .class("levels-column")
+    .on_mouse_down(|cx, btn| {
+        if btn == MouseButton::Right {
+            cx.emit(MeterModeEvent::Next);
+        }
+    })
+}
+
+/// Advisor warnings as dismissible toasts; builds nothing while there are
+/// none
+pub fn build_advice_toasts(cx: &mut Context) {
+    Binding::new(cx, VoiceStudioData::advice, |cx, lens| {
+        let advice = lens.get(cx);
+        if advice.is_empty() {
+            return;
+        }
+        VStack::new(cx, move |cx| {
+            for item in advice.iter().copied() {
+                HStack::new(cx, move |cx| {
+                    Label::new(cx, &item.message()).class("advice-text");
+                    create_button(cx, "×", "advice-dismiss", move |cx| {
+                        cx.emit(AdvisorEvent::Dismiss(item))
+                    });
+                })
+                .class("advice-toast");
+            }
+        })
+        .class("advice-stack");
+    });
+}
+
+/// Gap between the cursor and the drag tooltip (logical px)
+const DRAG_TIP_OFFSET: f32 = 14.0;
+
+/// Value tooltip next to the cursor while a slider or dial is dragged;
+/// builds nothing otherwise
+pub fn build_drag_tip(cx: &mut Context) {
+    Binding::new(
+        cx,
+        VoiceStudioData::drag_tip.map(Option::is_some),
+        |cx, shown| {
+            if !shown.get(cx) {
+                return;
+            }
+            let tip = |f: fn(&DragTip) -> f32| {
+                VoiceStudioData::drag_tip.map(move |tip| Pixels(tip.as_ref().map_or(0.0, f)))
+            };
+            Label::new(
+                cx,
+                VoiceStudioData::drag_tip
+                    .map(|tip| tip.as_ref().map(|tip| tip.text.clone()).unwrap_or_default()),
+            )
+            .class("drag-tip")
+            .left(tip(|tip| tip.x + DRAG_TIP_OFFSET))
+            .top(tip(|tip| tip.y + DRAG_TIP_OFFSET))
+            .hoverable(false);
+        },
+    );
 }
 
 pub fn build_macro<'a>(
     cx: &'a mut Context,
     params: Arc<VoiceParams>,
     gui: Arc<dyn GuiContext>,
+    meters: Arc<Meters>,
 ) -> Handle<'a, VStack> {
     // Split clones to avoid "use after move" across multiple nested closures
     let params_dials = params.clone();
     let params_dropdown = params.clone();
     let gui_dropdown = gui.clone();
-    let params_sync = params.clone();
-    let gui_sync = gui.clone();
+    let params_write = params.clone();
+    let gui_write = gui.clone();
 
     VStack::new(cx, move |cx| {
-        Binding::new(
-            cx,
-            VoiceStudioData::params.map(|p| {
-                (
-                    p.macro_mode.value(),
-                    p.macro_clean.value(),
-                    p.macro_enhance.value(),
-                    p.macro_control.value(),
-                )
-            }),
-            move |cx, lens| {
-                let (macro_mode, _, _, _) = lens.get(cx);
-                if macro_mode {
-                    crate::ui::state::sync_advanced_from_macros(&params_sync, gui_sync.clone());
-                }
-                Element::new(cx).height(Pixels(0.0)).width(Pixels(0.0));
-            },
-        );
-
-        Label::new(cx, "EASY CONTROLS")
+        Label::new(cx, &tr("EASY CONTROLS"))
             .class("column-header")
             .class("col-clean");
 
@@ -537,18 +1176,68 @@ pub fn build_macro<'a>(
 
         HStack::new(cx, move |cx| {
             let p = params_dials.clone();
-            create_macro_dial(cx, "CLEAN", p.clone(), ParamId::MacroDistance, |pp| {
-                &pp.macro_clean
-            });
-            create_macro_dial(cx, "ENHANCE", p.clone(), ParamId::MacroClarity, |pp| {
-                &pp.macro_enhance
-            });
-            create_macro_dial(cx, "CONTROL", p.clone(), ParamId::MacroConsistency, |pp| {
-                &pp.macro_control
-            });
+            let g = gui.clone();
+            let m = meters.clone();
+            create_macro_dial(
+                cx,
+                "CLEAN",
+                p.clone(),
+                g.clone(),
+                m.clone(),
+                ParamId::MacroDistance,
+                |pp| &pp.macro_clean,
+            );
+            create_macro_dial(
+                cx,
+                "ENHANCE",
+                p.clone(),
+                g.clone(),
+                m.clone(),
+                ParamId::MacroClarity,
+                |pp| &pp.macro_enhance,
+            );
+            create_macro_dial(
+                cx,
+                "CONTROL",
+                p.clone(),
+                g.clone(),
+                m.clone(),
+                ParamId::MacroConsistency,
+                |pp| &pp.macro_control,
+            );
         })
         .class("dials-container");
 
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.macro_write_back.value()),
+            move |cx, lens| {
+                let write_back = lens.get(cx);
+                let params = params_write.clone();
+                let gui = gui_write.clone();
+                create_toggle_button(
+                    cx,
+                    "Write to DAW",
+                    write_back,
+                    "small-button-active",
+                    "small-button",
+                    move |_| {
+                        let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                        s.begin_set_parameter(&params.macro_write_back);
+                        s.set_parameter(&params.macro_write_back, !write_back);
+                        s.end_set_parameter(&params.macro_write_back);
+                    },
+                )
+                .class("write-back-toggle")
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Writes the slider values Easy Mode resolves (including Auto) back to the host once a second, so automation lanes reproduce the sound without the macro logic."),
+                    );
+                });
+            },
+        );
+
         Element::new(cx).class("fill-height");
     })
     .class("macro-column")
@@ -561,7 +1250,7 @@ pub fn build_output<'a>(
     gui: Arc<dyn GuiContext>,
 ) -> Handle<'a, VStack> {
     VStack::new(cx, move |cx| {
-        Label::new(cx, "OUTPUT")
+        Label::new(cx, &tr("OUTPUT"))
             .class("column-header")
             .class("output-accent");
 
@@ -573,7 +1262,279 @@ pub fn build_output<'a>(
             ParamId::OutputGain,
             |p| &p.output_gain,
         );
+
+        let params_comp = params.clone();
+        let gui_comp = gui.clone();
+        HStack::new(cx, move |cx| {
+            Binding::new(
+                cx,
+                VoiceStudioData::params.map(|p| p.loudness_comp.value()),
+                move |cx, lens| {
+                    let on = lens.get(cx);
+                    let params = params_comp.clone();
+                    let gui = gui_comp.clone();
+                    create_toggle_button(
+                        cx,
+                        "Loudness Comp",
+                        on,
+                        "small-button-active",
+                        "small-button",
+                        move |_| {
+                            let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                            s.begin_set_parameter(&params.loudness_comp);
+                            s.set_parameter(&params.loudness_comp, !on);
+                            s.end_set_parameter(&params.loudness_comp);
+                        },
+                    )
+                    .tooltip(|cx| {
+                        Label::new(
+                            cx,
+                            &tr("Slowly brings the output back to the input's loudness (within ±10%, 10 s time constant). Switch it off for a fixed gain; the trim still applies."),
+                        );
+                    });
+                },
+            );
+            Label::new(
+                cx,
+                VoiceStudioData::loudness_comp_db.map(|db| format!("{db:+.1} dB")),
+            )
+            .class("mini-label")
+            .class("loudness-comp-readout")
+            .tooltip(|cx| {
+                Label::new(
+                    cx,
+                    &tr("Gain the loudness compensation applies right now, trim included."),
+                );
+            });
+        })
+        .class("output-actions");
+        create_slider(
+            cx,
+            "Loudness Trim",
+            params.clone(),
+            gui.clone(),
+            ParamId::LoudnessTrim,
+            |p| &p.loudness_comp_trim,
+        );
         create_dropdown(cx, "FINAL OUTPUT", params.clone(), gui.clone());
+        create_routing_dropdown(cx, "ROUTING", params.clone(), gui.clone());
+        create_dither_dropdown(cx, "DITHER", params.clone(), gui.clone());
+        create_final_stage_dropdown(cx, "FINAL STAGE", params.clone(), gui.clone());
+        create_limiter_character_dropdown(cx, "LIMITER", params.clone(), gui.clone());
+        create_slider(
+            cx,
+            "Clip Drive",
+            params.clone(),
+            gui.clone(),
+            ParamId::ClipDrive,
+            |p| &p.clip_drive,
+        );
+        create_slider(
+            cx,
+            "Clip Ceiling",
+            params.clone(),
+            gui.clone(),
+            ParamId::ClipCeiling,
+            |p| &p.clip_ceiling,
+        );
+
+        let params_mono = params.clone();
+        let gui_mono = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.mono_fix.value()),
+            move |cx, lens| {
+                let mono_fix = lens.get(cx);
+                let params = params_mono.clone();
+                let gui = gui_mono.clone();
+                create_toggle_button(
+                    cx,
+                    "Mono Fix",
+                    mono_fix,
+                    "small-button-active",
+                    "small-button",
+                    move |_| {
+                        let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                        s.begin_set_parameter(&params.mono_fix);
+                        s.set_parameter(&params.mono_fix, !mono_fix);
+                        s.end_set_parameter(&params.mono_fix);
+                    },
+                )
+                .class("mono-fix-toggle")
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Narrows the stereo image after the limiter whenever L and R would cancel in mono (PHASE meter red). Mono-safe material passes untouched."),
+                    );
+                });
+            },
+        );
+
+        let params_live = params.clone();
+        let gui_live = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.safe_live_mode.value()),
+            move |cx, lens| {
+                let live = lens.get(cx);
+                let params = params_live.clone();
+                let gui = gui_live.clone();
+                create_toggle_button(
+                    cx,
+                    "Safe Live",
+                    live,
+                    "small-button-active",
+                    "small-button",
+                    move |_| {
+                        let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                        s.begin_set_parameter(&params.safe_live_mode);
+                        s.set_parameter(&params.safe_live_mode, !live);
+                        s.end_set_parameter(&params.safe_live_mode);
+                    },
+                )
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("For streaming: caps every amount below where artifacts start (e.g. Noise 70%, De-Verb 60%) and locks Reset, Learn, Clear, Align, Auto and Quality so nothing glitches on air."),
+                    );
+                });
+            },
+        );
+
+        let params_freeze = params.clone();
+        let gui_freeze = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| p.freeze_adaptation.value()),
+            move |cx, lens| {
+                let frozen = lens.get(cx);
+                let params = params_freeze.clone();
+                let gui = gui_freeze.clone();
+                create_toggle_button(
+                    cx,
+                    "Freeze",
+                    frozen,
+                    "small-button-active",
+                    "small-button",
+                    move |_| {
+                        let s = nih_plug::prelude::ParamSetter::new(gui.as_ref());
+                        s.begin_set_parameter(&params.freeze_adaptation);
+                        s.set_parameter(&params.freeze_adaptation, !frozen);
+                        s.end_set_parameter(&params.freeze_adaptation);
+                    },
+                )
+                .tooltip(|cx| {
+                    Label::new(
+                        cx,
+                        &tr("Holds the noise floors, loudness compensation and calibration where they are, so rendering a section again gives a bit-identical result. Automate it on for QC re-renders."),
+                    );
+                });
+            },
+        );
+
+        // A/B: bypass, its level match and the per-stage gain trim
+        let params_bypass = params.clone();
+        let gui_bypass = gui.clone();
+        Binding::new(
+            cx,
+            VoiceStudioData::params.map(|p| {
+                (
+                    p.bypass.value(),
+                    p.bypass_level_match.value(),
+                    p.auto_gain_trim.value(),
+                )
+            }),
+            move |cx, lens| {
+                let (bypass, level_match, gain_trim) = lens.get(cx);
+                let params = params_bypass.clone();
+                let gui = gui_bypass.clone();
+                HStack::new(cx, move |cx| {
+                    let p1 = params.clone();
+                    let g1 = gui.clone();
+                    create_toggle_button(
+                        cx,
+                        "Bypass",
+                        bypass,
+                        "small-button-active",
+                        "small-button",
+                        move |_| {
+                            let s = nih_plug::prelude::ParamSetter::new(g1.as_ref());
+                            s.begin_set_parameter(&p1.bypass);
+                            s.set_parameter(&p1.bypass, !bypass);
+                            s.end_set_parameter(&p1.bypass);
+                        },
+                    )
+                    .tooltip(|cx| {
+                        Label::new(
+                            cx,
+                            &tr("Play the unprocessed input, aligned to the plugin latency."),
+                        );
+                    });
+
+                    let p2 = params.clone();
+                    let g2 = gui.clone();
+                    create_toggle_button(
+                        cx,
+                        "Match Level",
+                        level_match,
+                        "small-button-active",
+                        "small-button",
+                        move |_| {
+                            let s = nih_plug::prelude::ParamSetter::new(g2.as_ref());
+                            s.begin_set_parameter(&p2.bypass_level_match);
+                            s.set_parameter(&p2.bypass_level_match, !level_match);
+                            s.end_set_parameter(&p2.bypass_level_match);
+                        },
+                    )
+                    .tooltip(|cx| {
+                        Label::new(
+                            cx,
+                            &tr("Bypass plays the input at the processed loudness (average gain change, up to 12 dB), so A/B compares quality rather than level."),
+                        );
+                    });
+
+                    let p3 = params.clone();
+                    let g3 = gui.clone();
+                    create_toggle_button(
+                        cx,
+                        "Stage Trim",
+                        gain_trim,
+                        "small-button-active",
+                        "small-button",
+                        move |_| {
+                            let s = nih_plug::prelude::ParamSetter::new(g3.as_ref());
+                            s.begin_set_parameter(&p3.auto_gain_trim);
+                            s.set_parameter(&p3.auto_gain_trim, !gain_trim);
+                            s.end_set_parameter(&p3.auto_gain_trim);
+                        },
+                    )
+                    .tooltip(|cx| {
+                        Label::new(
+                            cx,
+                            &tr("Trims the long-term level each stage adds or removes during speech (up to 6 dB) back to unity, so switching a stage on or off does not change the loudness. Leveler and Limiter keep their gain."),
+                        );
+                    });
+                })
+                .class("output-actions");
+            },
+        );
+
+        Label::new(
+            cx,
+            VoiceStudioData::stage_gain_offset.map(|&(code, db)| {
+                match GuardStage::from_code(code) {
+                    Some(stage) => format!("{} {} {:+.1} dB", tr("Largest stage offset:"), stage.name(), db),
+                    None => String::new(),
+                }
+            }),
+        )
+        .class("mini-label")
+        .tooltip(|cx| {
+            Label::new(
+                cx,
+                &tr("The stage whose long-term speech level differs most from its input. Stage Trim evens it out."),
+            );
+        });
     })
     .class("output-section")
 }
@@ -598,6 +1559,139 @@ fn open_url(url: &str) {
     }
 }
 
+/// Background side of `Task::ExportSettings`: write the file and show its
+/// folder
+pub(crate) fn export_settings(state: nih_plug::prelude::PluginState) {
+    match crate::settings_file::export(state) {
+        Ok(path) => {
+            if let Some(dir) = path.parent() {
+                open_url(&dir.to_string_lossy());
+            }
+        }
+        Err(e) => nih_plug::nih_log!("Settings export failed: {}", e),
+    }
+}
+
+/// Background side of `Task::ExportProfileHistory`: write the CSV and show
+/// its folder
+pub(crate) fn export_profile_history(history: &crate::profile_history::ProfileHistory) {
+    match crate::profile_history::export(history) {
+        Ok(path) => {
+            if let Some(dir) = path.parent() {
+                open_url(&dir.to_string_lossy());
+            }
+        }
+        Err(e) => nih_plug::nih_log!("Profile history export failed: {}", e),
+    }
+}
+
+/// Background side of `Task::ImportSettings`: read the file and hand its
+/// state to the editor
+pub(crate) fn import_settings(name: &str, mut proxy: ContextProxy) {
+    match crate::settings_file::read(name) {
+        Ok(state) => {
+            let _ = proxy.emit(SettingsFileEvent::Loaded(state));
+        }
+        Err(e) => nih_plug::nih_log!("Settings import failed: {}", e),
+    }
+}
+
+/// Background side of `Task::SaveTrackProfile`: store the snapshot, then
+/// report whether the track has a profile
+pub(crate) fn save_track_profile(
+    state: nih_plug::prelude::PluginState,
+    track: &str,
+    proxy: ContextProxy,
+) {
+    if let Err(e) = crate::track_profiles::save(state, track) {
+        nih_plug::nih_log!("Track profile save failed: {}", e);
+    }
+    find_track_profile(track, proxy);
+}
+
+/// Background side of `Task::RecallTrackProfile`: hand the stored profile,
+/// if any, to the editor
+pub(crate) fn recall_track_profile(track: &str, mut proxy: ContextProxy) {
+    if let Some(state) = crate::track_profiles::load(track) {
+        let _ = proxy.emit(TrackProfileEvent::Recalled(state));
+    }
+}
+
+/// Background side of `Task::FindTrackProfile`
+pub(crate) fn find_track_profile(track: &str, mut proxy: ContextProxy) {
+    let _ = proxy.emit(TrackProfileEvent::Found {
+        track: track.to_string(),
+        stored: crate::track_profiles::contains(track),
+    });
+}
+
+/// `'static` copy of a stylesheet for `add_stylesheet`. Copies are leaked
+/// once per distinct text and reused, so reopening the editor does not leak
+/// every theme again
+fn static_css(css: &str) -> &'static str {
+    static LEAKED: OnceLock<Mutex<HashMap<String, &'static str>>> = OnceLock::new();
+    let mut leaked = LEAKED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(css) = leaked.get(css) {
+        return css;
+    }
+    let copy: &'static str = Box::leak(css.to_owned().into_boxed_str());
+    leaked.insert(css.to_owned(), copy);
+    copy
+}
+
+/// Editable copy of the stylesheet next to the plugin binary (inside the
+/// bundle on macOS)
+#[cfg(feature = "debug")]
+fn stylesheet_path() -> Option<std::path::PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+
+    #[cfg(target_os = "macos")]
+    let dir = exe_path.parent()?.parent()?.parent()?;
+
+    #[cfg(not(target_os = "macos"))]
+    let dir = exe_path.parent()?;
+
+    Some(dir.join("ui.css"))
+}
+
+/// Background side of `Task::WriteStylesheet`
+#[cfg(feature = "debug")]
+pub(crate) fn write_stylesheet(path: &std::path::Path, open: bool) {
+    // Create file if it doesn't exist
+    if !path.exists() {
+        if let Err(e) = std::fs::write(path, STYLE) {
+            vs_log!("Failed to write CSS file: {}", e);
+            return;
+        }
+        vs_log!("CSS file written to: {:?}", path);
+    }
+    if !open {
+        return;
+    }
+
+    // Open in system editor
+    #[cfg(target_os = "macos")]
+    {
+        let _ = std::process::Command::new("open")
+            .arg("-t")
+            .arg(path)
+            .spawn();
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let _ = std::process::Command::new("xdg-open").arg(path).spawn();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let _ = std::process::Command::new("notepad").arg(path).spawn();
+    }
+}
+
 // ============================================================================
 // MAIN UI ENTRY POINT
 // ============================================================================
@@ -605,9 +1699,7 @@ fn open_url(url: &str) {
 #[cfg(feature = "debug")]
 use crate::vs_log;
 
-use crate::version::{spawn_version_check, VersionUiState};
-use std::sync::Mutex;
-#[cfg(feature = "debug")]
+use crate::version::{request_version_check, VersionUiState};
 use std::time::Duration;
 
 // Include the CSS style
@@ -617,8 +1709,8 @@ pub fn build_ui(
     cx: &mut Context,
     params: Arc<VoiceParams>,
     meters: Arc<Meters>,
-    _ui_proxy: Arc<Mutex<Option<ContextProxy>>>,
     gui_context: Arc<dyn GuiContext>,
+    tasks: TaskQueue,
 ) {
     #[cfg(feature = "debug")]
     {
@@ -633,53 +1725,24 @@ pub fn build_ui(
 
     // In debug mode, try to load CSS from disk first (for live editing)
     #[cfg(feature = "debug")]
-    let css_to_load: &'static str = {
-        if let Ok(exe_path) = std::env::current_exe() {
-            #[cfg(target_os = "macos")]
-            let css_path = {
-                if let Some(macos_dir) = exe_path.parent() {
-                    if let Some(contents_dir) = macos_dir.parent() {
-                        if let Some(vst_bundle) = contents_dir.parent() {
-                            Some(vst_bundle.join("ui.css"))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            };
-
-            #[cfg(not(target_os = "macos"))]
-            let css_path = exe_path.parent().map(|p| p.join("ui.css"));
-
-            if let Some(path) = &css_path {
-                // Try to read from disk
-                if let Ok(disk_css) = std::fs::read_to_string(path) {
-                    vs_log!(
-                        "✅ CSS loaded from disk: {:?} ({} bytes)",
-                        path,
-                        disk_css.len()
-                    );
-                    // Leak the string to get 'static lifetime (acceptable for stylesheets)
-                    Box::leak(disk_css.into_boxed_str())
-                } else {
-                    // File doesn't exist, write embedded CSS and use it
-                    if let Err(e) = std::fs::write(path, STYLE) {
-                        vs_log!("Failed to write CSS file: {}", e);
-                    } else {
-                        vs_log!("CSS file written to: {:?}", path);
-                    }
-                    STYLE
-                }
-            } else {
+    let css_to_load: &'static str = match stylesheet_path() {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(disk_css) => {
+                vs_log!(
+                    "✅ CSS loaded from disk: {:?} ({} bytes)",
+                    path,
+                    disk_css.len()
+                );
+                static_css(&disk_css)
+            }
+            Err(_) => {
+                // File doesn't exist: use the embedded CSS and write it out
+                // for editing
+                tasks(Task::WriteStylesheet { path, open: false });
                 STYLE
             }
-        } else {
-            STYLE
-        }
+        },
+        None => STYLE,
     };
 
     #[cfg(not(feature = "debug"))]
@@ -696,19 +1759,159 @@ pub fn build_ui(
         }
     }
 
-    if let Ok(mut guard) = _ui_proxy.lock() {
-        *guard = Some(cx.get_proxy());
+    // Theme overrides are scoped to a root class, so all of them load up front
+    let themes = theme::load_themes();
+    for t in themes.iter().filter(|t| !t.css.is_empty()) {
+        if let Err(e) = cx.add_stylesheet(static_css(&t.css)) {
+            eprintln!("Theme '{}' failed to load: {:?}", t.name, e);
+        }
+    }
+    let selected_theme = params
+        .ui_theme
+        .read()
+        .map(|name| name.clone())
+        .ok()
+        .filter(|name| themes.iter().any(|t| &t.name == name))
+        .unwrap_or_else(|| theme::DEFAULT_THEME.to_string());
+
+    // Strings are translated as the views are built, so the language is
+    // picked before anything else is
+    let language_names = locale::language_names();
+    let mut selected_language = params
+        .ui_language
+        .read()
+        .map(|name| name.clone())
+        .unwrap_or_else(|_| locale::DEFAULT_LANGUAGE.to_string());
+    if !locale::select_language(&selected_language) {
+        selected_language = locale::DEFAULT_LANGUAGE.to_string();
     }
-    spawn_version_check(_ui_proxy.clone());
 
-    crate::ui::state::VoiceStudioData {
+    request_version_check(&tasks, cx.get_proxy());
+
+    let meter_mode = params
+        .editor_view
+        .read()
+        .map(|view| view.meter_mode)
+        .unwrap_or_default();
+    meters.set_mode(meter_mode);
+
+    let mut data = crate::ui::state::VoiceStudioData {
         params: params.clone(),
-        advanced_tab: crate::ui::state::AdvancedTab::CleanRepair,
+        gui: gui_context.clone(),
+        tasks: tasks.clone(),
+        proxy: cx.get_proxy(),
+        advanced_tab: params
+            .editor_view
+            .read()
+            .map(|view| view.advanced_tab)
+            .unwrap_or(crate::ui::state::AdvancedTab::CleanRepair),
         version_info: VersionUiState::checking(),
-    }
-    .build(cx);
+        meters: meters.clone(),
+        meter_mode,
+        room_rt60_ms: 0.0,
+        transient_events: 0.0,
+        codec_cutoff_hz: 0.0,
+        noise_corners: (0.0, 0.0),
+        denoise_width: (0.0, 0.0),
+        mic_distance: 0.5,
+        noise_learn_text: String::new(),
+        mic_align_text: String::new(),
+        dead_air: (0, 0),
+        music_detected: false,
+        de_ess_events: (0, 0.0, 0.0),
+        de_ess_center_hz: 0.0,
+        guardrail_cuts: (0.0, 0.0),
+        speech_loss: (0.0, 2.0),
+        peak_hold: (-80.0, -80.0),
+        loudness_comp_db: 0.0,
+        pump_guard: false,
+        input_conditions: DetectedConditions::default(),
+        condition_shares: [0; 4],
+        onboarding_step: (!params.onboarding_seen.load(Ordering::Relaxed)).then_some(0),
+        advisor: Advisor::default(),
+        advice: Vec::new(),
+        dsp_fault: false,
+        numeric_fault: (0, 0),
+        stage_gain_offset: (0, 0.0),
+        de_ess_auto_text: String::new(),
+        speaker_name: String::new(),
+        speaker_status: String::new(),
+        speaker_profile_names: Vec::new(),
+        active_speaker: String::new(),
+        speaker_voiced_sec: 0.0,
+        track_name: String::new(),
+        track_stored: false,
+        dual_active_speaker: -1,
+        ui_scale: params.editor_state.user_scale_factor(),
+        theme: selected_theme,
+        theme_names: themes.iter().map(|t| t.name.clone()).collect(),
+        language: selected_language,
+        language_names,
+        settings_files: crate::settings_file::list(),
+        live: LiveValues::default(),
+        param_locks: params.param_locks.bits(),
+        drag_tip: None,
+        easy_mode: params.macro_mode.value(),
+    };
+    data.sync_speaker_profiles();
+    data.sync_track_profile();
+    data.build(cx);
 
-    VStack::new(cx, move |cx| {
+    let meter_poll = cx.add_timer(Duration::from_millis(METER_POLL_MS), None, |cx, action| {
+        if let TimerAction::Tick(_) = action {
+            cx.emit(crate::ui::state::MeterPollEvent::Refresh);
+        }
+    });
+    cx.start_timer(meter_poll);
+
+    // Easy Mode turned off by anything (automation, presets, remote
+    // control, the buttons): hand the macro values to the sliders
+    let mode_watch = cx.add_timer(Duration::from_millis(MODE_WATCH_MS), None, |cx, action| {
+        if let TimerAction::Tick(_) = action {
+            cx.emit(crate::ui::state::MacroModeEvent::Watch);
+        }
+    });
+    cx.start_timer(mode_watch);
+
+    // Easy Mode write-back: Auto macros move the engine without touching the
+    // sliders, so mirror the resolved values to the host once a second
+    let params_write = params.clone();
+    let meters_write = meters.clone();
+    let gui_write = gui_context.clone();
+    let write_back = cx.add_timer(Duration::from_secs(1), None, move |_, action| {
+        if let TimerAction::Tick(_) = action {
+            if params_write.macro_mode.value() && params_write.macro_write_back.value() {
+                crate::ui::state::sync_advanced_from_macros(
+                    &params_write,
+                    &meters_write,
+                    gui_write.clone(),
+                );
+            }
+        }
+    });
+    cx.start_timer(write_back);
+
+    // DSP Preset changed by the host (automation, or a program change mapped
+    // to the parameter): the engine runs it already, show it on the sliders
+    let params_preset = params.clone();
+    let meters_preset = meters.clone();
+    let gui_preset = gui_context.clone();
+    let mirrored = std::cell::Cell::new(None);
+    let preset_watch = cx.add_timer(Duration::from_millis(100), None, move |_, action| {
+        if let TimerAction::Tick(_) = action {
+            let mut last = mirrored.get();
+            crate::ui::state::mirror_host_dsp_preset(
+                &params_preset,
+                &meters_preset,
+                &gui_preset,
+                &mut last,
+            );
+            mirrored.set(last);
+        }
+    });
+    cx.start_timer(preset_watch);
+
+    let mut root = VStack::new(cx, move |cx| {
         // HEADER
         build_header(cx, params.clone(), gui_context.clone()).class("header");
 
@@ -716,7 +1919,27 @@ pub fn build_ui(
         build_body(cx, params.clone(), meters.clone(), gui_context.clone()).class("body");
 
         // FOOTER
-        build_footer(cx, params.clone(), gui_context.clone()).class("footer");
+        build_footer(cx, params.clone(), gui_context.clone(), tasks.clone()).class("footer");
+
+        // Over-processing warnings, floating above the footer
+        build_advice_toasts(cx);
+
+        // Value of the slider or dial being dragged
+        build_drag_tip(cx);
+
+        // Drag to resize; the layout reflows to the new size
+        WindowResizeHandle::new(cx);
+
+        // First-run tour, over everything else
+        build_onboarding(cx);
     })
     .class("app-root");
+
+    for t in themes {
+        let name = t.name;
+        root = root.toggle_class(
+            &t.class,
+            VoiceStudioData::theme.map(move |selected| selected == &name),
+        );
+    }
 }
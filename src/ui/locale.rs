@@ -0,0 +1,79 @@
+//! UI localization
+//!
+//! English is the source language: UI strings are written in English and
+//! passed through `tr()`, which returns the selected language's translation
+//! or the English text when there is none. A missing or partial translation
+//! therefore never leaves a blank label.
+//!
+//! Languages are `*.json` files in the config directory (see
+//! `user_language_dir`), named after their file stem, each one object mapping
+//! English strings to translations:
+//!
+//! ```text
+//! { "Noise Reduction": "Reducción de ruido", "Save": "Guardar" }
+//! ```
+//!
+//! The table is loaded when the editor opens, so a language change applies
+//! the next time it is opened. Numbers and units in readouts stay as they
+//! are.
+
+use crate::ui::theme::user_config_dir;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+pub const DEFAULT_LANGUAGE: &str = "English";
+
+/// Translations of the selected language (empty for English)
+static TABLE: RwLock<Option<HashMap<String, String>>> = RwLock::new(None);
+
+/// `text` in the selected language
+pub fn tr(text: &str) -> String {
+    TABLE
+        .read()
+        .ok()
+        .and_then(|table| table.as_ref()?.get(text).cloned())
+        .unwrap_or_else(|| text.to_string())
+}
+
+/// Per-user language folder (`languages` in `user_config_dir`)
+pub fn user_language_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("languages"))
+}
+
+/// English followed by the language files found, sorted by name
+pub fn language_names() -> Vec<String> {
+    let mut names: Vec<String> = user_language_dir()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()?.to_str()? != "json" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .filter(|name| !name.eq_ignore_ascii_case(DEFAULT_LANGUAGE))
+        .collect();
+    names.sort();
+    names.insert(0, DEFAULT_LANGUAGE.to_string());
+    names
+}
+
+/// Make `name` the language `tr()` answers in. Returns false (and falls back
+/// to English) when its file is missing or not a JSON object of strings.
+pub fn select_language(name: &str) -> bool {
+    let table = if name == DEFAULT_LANGUAGE {
+        Some(HashMap::new())
+    } else {
+        user_language_dir()
+            .and_then(|dir| std::fs::read_to_string(dir.join(format!("{}.json", name))).ok())
+            .and_then(|text| serde_json::from_str::<HashMap<String, String>>(&text).ok())
+    };
+    let found = table.is_some();
+    if let Ok(mut slot) = TABLE.write() {
+        *slot = table.filter(|t| !t.is_empty());
+    }
+    found
+}
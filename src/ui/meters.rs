@@ -3,7 +3,9 @@
 //! This module provides custom Vizia widgets for displaying meter data.
 //! The underlying data storage is defined in `crate::meters`.
 
-use crate::meters::Meters;
+use crate::dsp::artifact_detector::BACKOFF_FULL;
+use crate::meters::{MeterMode, Meters};
+use crate::ui::draw::Dpi;
 use nih_plug_vizia::vizia::prelude::*;
 use nih_plug_vizia::vizia::vg;
 use std::sync::Arc;
@@ -12,13 +14,27 @@ use std::sync::Arc;
 // CUSTOM METER WIDGETS
 // ============================================================================
 
+/// Range of the level meter scale below its top (dB)
+const LEVEL_RANGE_DB: f32 = 60.0;
+/// K-System zones above the 0 dB mark: amber up to here, red beyond (dB)
+const K_AMBER_DB: f32 = 4.0;
+
 #[derive(Clone, Copy)]
 pub enum MeterType {
     InputL,
     InputR,
     OutputL,
     OutputR,
-    GainReduction,
+    /// Gain reduction of one dynamics stage
+    LevelerGr,
+    DeEsserGr,
+    LimiterGr,
+}
+
+impl MeterType {
+    fn is_gain_reduction(self) -> bool {
+        matches!(self, Self::LevelerGr | Self::DeEsserGr | Self::LimiterGr)
+    }
 }
 
 pub struct LevelMeter {
@@ -37,58 +53,108 @@ impl View for LevelMeter {
         Some("level-meter")
     }
 
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            if let WindowEvent::MouseDown(MouseButton::Left) = window_event {
+                if !self.meter_type.is_gain_reduction() {
+                    self.meters.reset_peak_hold();
+                    cx.needs_redraw();
+                    meta.consume();
+                }
+            }
+        });
+    }
+
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
         let b = cx.bounds();
-        let is_gr = matches!(self.meter_type, MeterType::GainReduction);
-
-        let level = match self.meter_type {
-            MeterType::InputL => self.meters.get_input_peak_l(),
-            MeterType::InputR => self.meters.get_input_peak_r(),
-            MeterType::OutputL => self.meters.get_output_peak_l(),
-            MeterType::OutputR => self.meters.get_output_peak_r(),
-            MeterType::GainReduction => {
-                0.5 * (self.meters.get_gain_reduction_l() + self.meters.get_gain_reduction_r())
-            }
+        let is_gr = self.meter_type.is_gain_reduction();
+
+        let m = self.meters.snapshot();
+        let mode = self.meters.mode();
+        // Level meters read on the mode's scale, whose top is full scale
+        let (level, hold) = match self.meter_type {
+            MeterType::InputL => (
+                mode.reading(m.input_peak_l, m.input_rms_l),
+                Some(m.input_hold_l),
+            ),
+            MeterType::InputR => (
+                mode.reading(m.input_peak_r, m.input_rms_r),
+                Some(m.input_hold_r),
+            ),
+            MeterType::OutputL => (
+                mode.reading(m.output_peak_l, m.output_rms_l),
+                Some(m.output_hold_l),
+            ),
+            MeterType::OutputR => (
+                mode.reading(m.output_peak_r, m.output_rms_r),
+                Some(m.output_hold_r),
+            ),
+            MeterType::LevelerGr => (0.5 * (m.gain_reduction_l + m.gain_reduction_r), None),
+            MeterType::DeEsserGr => (m.debug_deesser_gr_db, None),
+            MeterType::LimiterGr => (m.debug_limiter_gr_db, None),
         };
+        let top = mode.headroom_db();
+        let scale = |db: f32| ((db - top + LEVEL_RANGE_DB) / LEVEL_RANGE_DB).clamp(0.0, 1.0);
 
         let norm = if is_gr {
             (level / 20.0).clamp(0.0, 1.0)
         } else {
-            ((level + 60.0) / 60.0).clamp(0.0, 1.0)
+            scale(level)
+        };
+        let k_scale = !is_gr && mode.headroom_db() > 0.0;
+
+        let dpi = Dpi::new(cx);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
+        // Horizontal line across the bar at `norm`, on the pixel grid
+        let level_line = |norm: f32, inset: f32, width: f32| {
+            let ly = dpi.snap(y + h * (1.0 - norm), width);
+            let mut line = vg::Path::new();
+            line.move_to(x + inset, ly);
+            line.line_to(x + w - inset, ly);
+            line
         };
 
         // background
         let mut bg = vg::Path::new();
-        bg.rect(b.x, b.y, b.w, b.h);
+        bg.rect(x, y, w, h);
         canvas.fill_path(&bg, &vg::Paint::color(vg::Color::rgb(15, 23, 42)));
         canvas.stroke_path(
-            &bg,
-            &vg::Paint::color(vg::Color::rgb(51, 65, 85)).with_line_width(1.0),
+            &dpi.border_path(b, 0.0, border),
+            &vg::Paint::color(vg::Color::rgb(51, 65, 85)).with_line_width(border),
         );
 
         // fill
         if norm > 0.001 {
-            let fh = b.h * norm;
-            let fy = b.y + (b.h - fh);
+            let fy = (y + h * (1.0 - norm)).round();
 
             let mut f = vg::Path::new();
-            f.rect(b.x + 1.0, fy, b.w - 2.0, fh);
-
-            let paint = if is_gr {
-                vg::Paint::linear_gradient(
-                    b.x,
-                    b.y,
-                    b.x,
-                    b.y + b.h,
-                    vg::Color::rgb(239, 68, 68),
-                    vg::Color::rgb(249, 115, 22),
-                )
+            f.rect(x + border, fy, w - 2.0 * border, y + h - fy);
+
+            let paint = if k_scale {
+                // K-System zones: green below 0, amber up to +4, red above
+                let color = if level < 0.0 {
+                    vg::Color::rgb(34, 197, 94)
+                } else if level < K_AMBER_DB {
+                    vg::Color::rgb(245, 158, 11)
+                } else {
+                    vg::Color::rgb(239, 68, 68)
+                };
+                vg::Paint::color(color)
+            } else if is_gr {
+                // One color per stage, as in the GR tooltip
+                let color = match self.meter_type {
+                    MeterType::DeEsserGr => vg::Color::rgb(56, 189, 248), // Sky-400
+                    MeterType::LimiterGr => vg::Color::rgb(239, 68, 68),  // Red-500
+                    _ => vg::Color::rgb(249, 115, 22),                    // Orange-500
+                };
+                vg::Paint::color(color)
             } else {
                 vg::Paint::linear_gradient(
-                    b.x,
-                    b.y + b.h,
-                    b.x,
-                    b.y,
+                    x,
+                    y + h,
+                    x,
+                    y,
                     vg::Color::rgb(34, 197, 94),
                     vg::Color::rgb(239, 68, 68),
                 )
@@ -99,17 +165,40 @@ impl View for LevelMeter {
 
         // ticks
         let mut l = vg::Path::new();
-        let step = b.h / 20.0;
         for i in 1..20 {
-            let y = b.y + i as f32 * step;
-            l.move_to(b.x, y);
-            l.line_to(b.x + b.w, y);
+            let ty = dpi.snap(y + h * i as f32 / 20.0, border);
+            l.move_to(x, ty);
+            l.line_to(x + w, ty);
         }
 
         canvas.stroke_path(
             &l,
-            &vg::Paint::color(vg::Color::rgba(0, 0, 0, 100)).with_line_width(1.0),
+            &vg::Paint::color(vg::Color::rgba(0, 0, 0, 100)).with_line_width(border),
         );
+
+        // K-System 0 dB mark
+        if k_scale {
+            canvas.stroke_path(
+                &level_line(scale(0.0), 0.0, border),
+                &vg::Paint::color(vg::Color::rgb(148, 163, 184)).with_line_width(border),
+            );
+        }
+
+        // Peak hold line (click the meter to clear it), a sample peak on
+        // every scale
+        let hold_norm = hold.map_or(0.0, |db| scale(db + top));
+        if hold_norm > 0.001 {
+            let color = if hold.is_some_and(|db| db > -1.0) {
+                vg::Color::rgb(239, 68, 68) // Red-500: at full scale
+            } else {
+                vg::Color::rgb(226, 232, 240) // Slate-200
+            };
+            let width = dpi.stroke(1.5);
+            canvas.stroke_path(
+                &level_line(hold_norm, border, width),
+                &vg::Paint::color(color).with_line_width(width),
+            );
+        }
     }
 }
 
@@ -134,20 +223,35 @@ impl View for NoiseLearnQualityMeter {
 
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
         let b = cx.bounds();
-        let quality = self.meters.get_noise_learn_quality().clamp(0.0, 1.0);
+        let m = self.meters.snapshot();
+        let quality = m.noise_learn_quality.clamp(0.0, 1.0);
+        // A running Re-learn shows its capture progress instead of the quality
+        let capturing = m.noise_capture_status == 1;
+        let fill_level = if capturing {
+            m.noise_capture_progress.clamp(0.0, 1.0)
+        } else {
+            quality
+        };
+
+        let dpi = Dpi::new(cx);
+        let radius = dpi.px(2.0);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
 
         // Background
         let mut bg = vg::Path::new();
-        bg.rounded_rect(b.x, b.y, b.w, b.h, 2.0);
+        bg.rounded_rect(x, y, w, h, radius);
         canvas.fill_path(&bg, &vg::Paint::color(vg::Color::rgb(30, 41, 59)));
 
-        // Fill based on quality
-        if quality > 0.01 {
+        // Fill based on quality (or capture progress)
+        if fill_level > 0.01 {
             let mut fill = vg::Path::new();
-            fill.rounded_rect(b.x, b.y, b.w * quality, b.h, 2.0);
+            fill.rounded_rect(x, y, (w * fill_level).round(), h, radius);
 
-            // Color logic: < 0.3 grey, 0.3-0.7 yellow, > 0.7 green
-            let color = if quality < 0.3 {
+            // Color logic: capturing blue, else < 0.3 grey, 0.3-0.7 yellow, > 0.7 green
+            let color = if capturing {
+                vg::Color::rgb(56, 189, 248) // Sky-400
+            } else if quality < 0.3 {
                 vg::Color::rgb(100, 116, 139) // Slate-500
             } else if quality < 0.7 {
                 vg::Color::rgb(234, 179, 8) // Yellow-500
@@ -160,8 +264,211 @@ impl View for NoiseLearnQualityMeter {
 
         // Border
         canvas.stroke_path(
-            &bg,
-            &vg::Paint::color(vg::Color::rgb(71, 85, 105)).with_line_width(1.0),
+            &dpi.border_path(b, radius, border),
+            &vg::Paint::color(vg::Color::rgb(71, 85, 105)).with_line_width(border),
+        );
+    }
+}
+
+// ============================================================================
+// ARTIFACT GUARD METER
+// ============================================================================
+
+/// Musical-noise metric bar; turns amber while the Artifact Guard backs off
+pub struct ArtifactGuardMeter {
+    meters: Arc<Meters>,
+}
+
+impl ArtifactGuardMeter {
+    pub fn new(cx: &mut Context, meters: Arc<Meters>) -> Handle<'_, Self> {
+        Self { meters }.build(cx, |_| {})
+    }
+}
+
+impl View for ArtifactGuardMeter {
+    fn element(&self) -> Option<&'static str> {
+        Some("artifact-guard-meter")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let b = cx.bounds();
+        let m = self.meters.snapshot();
+        let level = (m.artifact_level / BACKOFF_FULL).clamp(0.0, 1.0);
+        let backoff = m.artifact_backoff;
+
+        let dpi = Dpi::new(cx);
+        let radius = dpi.px(2.0);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
+
+        // Background
+        let mut bg = vg::Path::new();
+        bg.rounded_rect(x, y, w, h, radius);
+        canvas.fill_path(&bg, &vg::Paint::color(vg::Color::rgb(30, 41, 59)));
+
+        if level > 0.01 {
+            let mut fill = vg::Path::new();
+            fill.rounded_rect(x, y, (w * level).round(), h, radius);
+
+            let color = if backoff > 0.01 {
+                vg::Color::rgb(245, 158, 11) // Amber-500: guard is backing off
+            } else {
+                vg::Color::rgb(100, 116, 139) // Slate-500
+            };
+
+            canvas.fill_path(&fill, &vg::Paint::color(color));
+        }
+
+        // Border
+        canvas.stroke_path(
+            &dpi.border_path(b, radius, border),
+            &vg::Paint::color(vg::Color::rgb(71, 85, 105)).with_line_width(border),
+        );
+    }
+}
+
+// ============================================================================
+// SPEECH LOSS METER
+// ============================================================================
+
+/// Full scale of the speech-band loss bar (dB)
+const SPEECH_LOSS_SCALE_DB: f32 = 6.0;
+
+/// Speech-band loss through restoration against the allowed budget (tick);
+/// amber while the speech protection is scaling denoise/de-verb back
+pub struct SpeechLossMeter {
+    meters: Arc<Meters>,
+}
+
+impl SpeechLossMeter {
+    pub fn new(cx: &mut Context, meters: Arc<Meters>) -> Handle<'_, Self> {
+        Self { meters }.build(cx, |_| {})
+    }
+}
+
+impl View for SpeechLossMeter {
+    fn element(&self) -> Option<&'static str> {
+        Some("speech-loss-meter")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let b = cx.bounds();
+        let m = self.meters.snapshot();
+        let loss = (-m.speech_band_loss_db / SPEECH_LOSS_SCALE_DB).clamp(0.0, 1.0);
+        let budget = (m.speech_loss_budget_db / SPEECH_LOSS_SCALE_DB).clamp(0.0, 1.0);
+
+        let dpi = Dpi::new(cx);
+        let radius = dpi.px(2.0);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
+
+        // Background
+        let mut bg = vg::Path::new();
+        bg.rounded_rect(x, y, w, h, radius);
+        canvas.fill_path(&bg, &vg::Paint::color(vg::Color::rgb(30, 41, 59)));
+
+        if loss > 0.01 {
+            let mut fill = vg::Path::new();
+            fill.rounded_rect(x, y, (w * loss).round(), h, radius);
+
+            let color = if m.speech_protection_active {
+                vg::Color::rgb(245, 158, 11) // Amber-500: protection is scaling back
+            } else {
+                vg::Color::rgb(100, 116, 139) // Slate-500
+            };
+
+            canvas.fill_path(&fill, &vg::Paint::color(color));
+        }
+
+        // Budget tick
+        let tick_x = dpi.snap(x + w * budget, border);
+        let mut tick = vg::Path::new();
+        tick.move_to(tick_x, y);
+        tick.line_to(tick_x, y + h);
+        canvas.stroke_path(
+            &tick,
+            &vg::Paint::color(vg::Color::rgb(148, 163, 184)).with_line_width(border),
+        );
+
+        // Border
+        canvas.stroke_path(
+            &dpi.border_path(b, radius, border),
+            &vg::Paint::color(vg::Color::rgb(71, 85, 105)).with_line_width(border),
+        );
+    }
+}
+
+// ============================================================================
+// CORRELATION METER
+// ============================================================================
+
+/// Phase correlation bar (-1 left, +1 right); red below zero, amber while
+/// the mono fix is narrowing the image
+pub struct CorrelationMeter {
+    meters: Arc<Meters>,
+}
+
+impl CorrelationMeter {
+    pub fn new(cx: &mut Context, meters: Arc<Meters>) -> Handle<'_, Self> {
+        Self { meters }.build(cx, |_| {})
+    }
+}
+
+impl View for CorrelationMeter {
+    fn element(&self) -> Option<&'static str> {
+        Some("correlation-meter")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let b = cx.bounds();
+        let m = self.meters.snapshot();
+        let corr = m.stereo_correlation.clamp(-1.0, 1.0);
+
+        let dpi = Dpi::new(cx);
+        let radius = dpi.px(2.0);
+        let border = dpi.stroke(1.0);
+        let BoundingBox { x, y, w, h } = dpi.snap_bounds(b);
+
+        // Background
+        let mut bg = vg::Path::new();
+        bg.rounded_rect(x, y, w, h, radius);
+        canvas.fill_path(&bg, &vg::Paint::color(vg::Color::rgb(30, 41, 59)));
+
+        // Bar grows from the centre towards the reading
+        let center = x + (w * 0.5).round();
+        let end = (center + corr * w * 0.5).round();
+        let (x0, x1) = if end < center {
+            (end, center)
+        } else {
+            (center, end)
+        };
+        if x1 - x0 > 0.5 {
+            let color = if m.mono_fix_width < 0.99 {
+                vg::Color::rgb(245, 158, 11) // Amber-500: mono fix is acting
+            } else if corr < 0.0 {
+                vg::Color::rgb(239, 68, 68) // Red-500: cancels in mono
+            } else {
+                vg::Color::rgb(34, 197, 94) // Green-500
+            };
+            let mut fill = vg::Path::new();
+            fill.rect(x0, y, x1 - x0, h);
+            canvas.fill_path(&fill, &vg::Paint::color(color));
+        }
+
+        // Centre tick (zero correlation)
+        let tick_x = dpi.snap(center, border);
+        let mut tick = vg::Path::new();
+        tick.move_to(tick_x, y);
+        tick.line_to(tick_x, y + h);
+        canvas.stroke_path(
+            &tick,
+            &vg::Paint::color(vg::Color::rgb(148, 163, 184)).with_line_width(border),
+        );
+
+        // Border
+        canvas.stroke_path(
+            &dpi.border_path(b, radius, border),
+            &vg::Paint::color(vg::Color::rgb(71, 85, 105)).with_line_width(border),
         );
     }
 }
@@ -190,12 +497,13 @@ impl View for NoiseFloorLeds {
 
         // Read gain reduction from compressor/leveler (in dB, positive values)
         // Shows how much the plugin is actively processing/reducing the signal
-        let gr_l = self.meters.get_gain_reduction_l();
-        let gr_r = self.meters.get_gain_reduction_r();
+        let m = self.meters.snapshot();
+        let (gr_l, gr_r) = (m.gain_reduction_l, m.gain_reduction_r);
         let gr_db = gr_l.max(gr_r); // Use max for linked stereo
 
-        let radius = b.h / 2.0 - 1.0;
-        let spacing = 6.0;
+        let dpi = Dpi::new(cx);
+        let radius = b.h / 2.0 - dpi.px(1.0);
+        let spacing = dpi.px(6.0);
         let start_x = b.x + (b.w - (radius * 2.0 * 3.0 + spacing * 2.0)) / 2.0 + radius;
         let cy = b.y + b.h / 2.0;
 
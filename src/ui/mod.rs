@@ -7,27 +7,43 @@
 //! - `advanced`: Advanced mode panels
 //! - `simple`: Simple mode panels
 //! - `meters`: Custom meter widgets
+//! - `draw`: Scale-factor and pixel-grid helpers for custom drawing
+//! - `param_input`: Keyboard/text-entry interaction for sliders and dials
+//! - `theme`: Built-in and user stylesheets
+//! - `locale`: UI string translations
+//! - `onboarding`: First-run walkthrough overlay
 
 pub mod advanced;
 pub mod components;
+pub mod draw;
 pub mod layout;
+pub mod locale;
 pub mod meters;
+pub mod onboarding;
+pub mod param_input;
 pub mod simple;
 pub mod state;
+pub mod theme;
 
 // Re-export public items for convenience
 #[allow(unused_imports)]
 pub use advanced::{build_clean_repair_tab, build_shape_polish_tab};
 #[allow(unused_imports)]
 pub use components::{
-    create_button, create_dropdown, create_dsp_preset_dropdown, create_macro_dial,
-    create_momentary_button, create_slider, create_toggle_button, DialVisuals, ParamId,
-    SliderVisuals,
+    create_button, create_chain_order_dropdown, create_dither_dropdown, create_dropdown,
+    create_dsp_preset_dropdown, create_final_stage_dropdown, create_guardrails_dropdown,
+    create_limiter_character_dropdown, create_low_cut_dropdown, create_low_cut_slope_dropdown,
+    create_macro_dial, create_mod_source_dropdown, create_momentary_button,
+    create_response_dropdown, create_routing_dropdown, create_slider, create_test_signal_dropdown,
+    create_toggle_button, DialVisuals, ParamId, SliderVisuals, WindowResizeHandle,
 };
 #[allow(unused_imports)]
 pub use layout::{build_body, build_footer, build_header, build_levels, build_macro, build_output};
 #[allow(unused_imports)]
-pub use meters::{LevelMeter, MeterType, NoiseFloorLeds, NoiseLearnQualityMeter};
+pub use meters::{
+    ArtifactGuardMeter, CorrelationMeter, LevelMeter, MeterType, NoiseFloorLeds,
+    NoiseLearnQualityMeter, SpeechLossMeter,
+};
 #[allow(unused_imports)]
 pub use state::{
     set_macro_mode, sync_advanced_from_macros, AdvancedTab, AdvancedTabEvent, VoiceStudioData,
@@ -35,3 +51,12 @@ pub use state::{
 
 // Main UI entry point
 pub use layout::build_ui;
+
+// Window geometry (logical pixels, before the user scale factor)
+pub const EDITOR_DEFAULT_SIZE: (u32, u32) = (900, 550);
+pub const EDITOR_MIN_SIZE: (u32, u32) = (760, 480);
+pub const EDITOR_MAX_SIZE: (u32, u32) = (2400, 1600);
+
+/// User scale factors offered in the footer
+pub const UI_SCALE_OPTIONS: [(f64, &str); 4] =
+    [(1.0, "100%"), (1.25, "125%"), (1.5, "150%"), (2.0, "200%")];
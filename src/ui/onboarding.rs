@@ -0,0 +1,107 @@
+//! First-Run Walkthrough
+//!
+//! A short overlay tour for a new user: play some audio, read the condition
+//! badges, learn the noise during silence and pick a Final Output preset.
+//! One dismissible panel per step, floating over the editor beside the
+//! controls it talks about; only the panel covers anything, so each step can
+//! be tried while it is up.
+//!
+//! # Design Notes
+//! - Shown when the editor opens until the tour is finished or skipped; that
+//!   is remembered in the `onboarding-seen` persisted field, which settings
+//!   files leave alone like the rest of the editor state
+//! - The footer's Tour button replays it at any time
+//! - The current step lives in `VoiceStudioData::onboarding_step` (`None`
+//!   when hidden) and moves with `OnboardingEvent`
+
+use crate::ui::components::create_button;
+use crate::ui::locale::tr;
+use crate::ui::state::VoiceStudioData;
+use nih_plug_vizia::vizia::prelude::*;
+
+/// One panel of the tour
+pub struct Step {
+    pub title: &'static str,
+    pub text: &'static str,
+    /// Panel placement class (next to the controls it talks about)
+    pub class: &'static str,
+}
+
+pub static STEPS: [Step; 4] = [
+    Step {
+        title: "Play some audio",
+        text: "Start playback or talk into the mic. The meters on the left show what comes in and what goes out.",
+        class: "onboarding-at-levels",
+    },
+    Step {
+        title: "Watch the condition badges",
+        text: "Under the meters, badges light up for what the input sounds like: whispered, distant, noisy or already clean. The adaptive stages react to the same readings.",
+        class: "onboarding-at-levels",
+    },
+    Step {
+        title: "Learn the noise during silence",
+        text: "Switch to Advanced and press Re-learn on the Clean & Repair tab while nobody is talking. Two seconds of room noise are captured and removed from then on.",
+        class: "onboarding-at-controls",
+    },
+    Step {
+        title: "Choose a Final Output preset",
+        text: "Pick where the voice is going (Broadcast, YouTube or Spotify) under Final Output. The output is then held at that platform's loudness target.",
+        class: "onboarding-at-output",
+    },
+];
+
+/// Index of the step whose panel sends the Advanced view to Clean & Repair
+pub const LEARN_STEP: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnboardingEvent {
+    /// Show the tour from the first step
+    Start,
+    Next,
+    Back,
+    /// Hide the tour and remember it was seen
+    Dismiss,
+}
+
+/// The current step's panel, floating over the editor; builds nothing while
+/// the tour is hidden
+pub fn build_onboarding(cx: &mut Context) {
+    Binding::new(cx, VoiceStudioData::onboarding_step, |cx, lens| {
+        let Some(index) = lens.get(cx) else {
+            return;
+        };
+        let step = &STEPS[index];
+        let last = index + 1 == STEPS.len();
+
+        VStack::new(cx, move |cx| {
+            Label::new(
+                cx,
+                &format!("{} {} / {}", tr("Step"), index + 1, STEPS.len()),
+            )
+            .class("onboarding-count");
+            Label::new(cx, &tr(step.title)).class("onboarding-title");
+            Label::new(cx, &tr(step.text)).class("onboarding-text");
+
+            HStack::new(cx, move |cx| {
+                create_button(cx, "Skip", "footer-button", |cx| {
+                    cx.emit(OnboardingEvent::Dismiss)
+                });
+                Element::new(cx).class("fill-width");
+                if index > 0 {
+                    create_button(cx, "Back", "footer-button", |cx| {
+                        cx.emit(OnboardingEvent::Back)
+                    });
+                }
+                create_button(
+                    cx,
+                    if last { "Done" } else { "Next" },
+                    "footer-button",
+                    |cx| cx.emit(OnboardingEvent::Next),
+                );
+            })
+            .class("onboarding-actions");
+        })
+        .class("onboarding-panel")
+        .class(step.class);
+    });
+}
@@ -0,0 +1,259 @@
+//! Keyboard-accessible parameter input
+//!
+//! Invisible interaction layer placed over the custom slider/dial visuals
+//! (replaces nih_plug_vizia's `ParamSlider`, whose text entry is hidden by
+//! the transparent styling):
+//! - Drag horizontally to change the value, Shift for fine control
+//! - Ctrl/Cmd+click resets to the default
+//! - Arrow keys nudge by 1% (Shift: 0.1%), Page Up/Down by 10%,
+//!   Home/End jump to the range ends
+//! - Double-click or Enter opens a text field for an exact value; Enter
+//!   applies it, Escape cancels
+//! - While dragging, a tooltip next to the cursor shows the value (the
+//!   label inside the control is under the finger or thumb); a macro dial's
+//!   tooltip also lists the values it drives
+
+use crate::macro_controller::MacroKind;
+use crate::ui::state::DragTipEvent;
+use nih_plug::prelude::Param;
+use nih_plug_vizia::vizia::prelude::*;
+use nih_plug_vizia::widgets::param_base::ParamWidgetBase;
+
+const NUDGE_STEP: f32 = 0.01;
+const NUDGE_STEP_FINE: f32 = 0.001;
+const PAGE_STEP: f32 = 0.1;
+/// Drag sensitivity while Shift is held
+const FINE_DRAG_SCALE: f32 = 0.1;
+
+#[derive(Lens)]
+pub struct ParamInput {
+    #[lens(ignore)]
+    param_base: ParamWidgetBase,
+    editing: bool,
+    #[lens(ignore)]
+    drag: Option<DragState>,
+    /// Macro behind a dial, for the drag tooltip
+    #[lens(ignore)]
+    macro_kind: Option<MacroKind>,
+}
+
+#[derive(Clone, Copy)]
+struct DragState {
+    start_x: f32,
+    start_value: f32,
+    fine: bool,
+}
+
+enum ParamInputEvent {
+    BeginTextEntry,
+    TextEntry(String),
+    CancelTextEntry,
+}
+
+impl ParamInput {
+    pub fn new<L, Params, P, FMap>(
+        cx: &mut Context,
+        params: L,
+        params_to_param: FMap,
+    ) -> Handle<Self>
+    where
+        L: Lens<Target = Params> + Clone,
+        Params: 'static,
+        P: Param + 'static,
+        FMap: Fn(&Params) -> &P + Copy + 'static,
+    {
+        Self::build_input(cx, params, params_to_param, None)
+    }
+
+    /// Input layer of a macro dial: the drag tooltip also lists the values
+    /// the macro drives
+    pub fn for_macro<L, Params, P, FMap>(
+        cx: &mut Context,
+        params: L,
+        params_to_param: FMap,
+        kind: MacroKind,
+    ) -> Handle<Self>
+    where
+        L: Lens<Target = Params> + Clone,
+        Params: 'static,
+        P: Param + 'static,
+        FMap: Fn(&Params) -> &P + Copy + 'static,
+    {
+        Self::build_input(cx, params, params_to_param, Some(kind))
+    }
+
+    fn build_input<L, Params, P, FMap>(
+        cx: &mut Context,
+        params: L,
+        params_to_param: FMap,
+        macro_kind: Option<MacroKind>,
+    ) -> Handle<Self>
+    where
+        L: Lens<Target = Params> + Clone,
+        Params: 'static,
+        P: Param + 'static,
+        FMap: Fn(&Params) -> &P + Copy + 'static,
+    {
+        Self {
+            param_base: ParamWidgetBase::new(cx, params.clone(), params_to_param),
+            editing: false,
+            drag: None,
+            macro_kind,
+        }
+        .build(cx, move |cx| {
+            Binding::new(cx, ParamInput::editing, move |cx, editing| {
+                if !editing.get(cx) {
+                    return;
+                }
+                // Unit-less text so the entry can be edited and parsed as-is
+                let text = ParamWidgetBase::make_lens(params.clone(), params_to_param, |p| {
+                    p.normalized_value_to_string(p.unmodulated_normalized_value(), false)
+                });
+                Textbox::new(cx, text)
+                    .class("param-entry")
+                    .on_submit(|cx, text, success| {
+                        if success {
+                            cx.emit(ParamInputEvent::TextEntry(text));
+                        } else {
+                            cx.emit(ParamInputEvent::CancelTextEntry);
+                        }
+                    })
+                    .on_build(|cx| {
+                        cx.emit(TextEvent::StartEdit);
+                        cx.emit(TextEvent::SelectAll);
+                    });
+            });
+        })
+        .navigable(true)
+    }
+
+    fn set_value(&self, cx: &mut EventContext, normalized: f32) {
+        self.param_base.begin_set_parameter(cx);
+        self.param_base
+            .set_normalized_value(cx, normalized.clamp(0.0, 1.0));
+        self.param_base.end_set_parameter(cx);
+    }
+
+    fn end_drag(&mut self, cx: &mut EventContext) {
+        if self.drag.take().is_some() {
+            cx.release();
+            cx.set_active(false);
+            self.param_base.end_set_parameter(cx);
+            cx.emit(DragTipEvent::Hide);
+        }
+    }
+
+    /// Show the current value next to the cursor
+    fn show_drag_tip(&self, cx: &mut EventContext) {
+        // Cursor is in physical pixels, the overlay is laid out in logical ones
+        let scale = cx.scale_factor().max(0.1);
+        let value = self
+            .param_base
+            .normalized_value_to_string(self.param_base.unmodulated_normalized_value(), true);
+        cx.emit(DragTipEvent::Show {
+            text: format!("{}: {}", self.param_base.name(), value),
+            macro_kind: self.macro_kind,
+            x: cx.mouse().cursorx / scale,
+            y: cx.mouse().cursory / scale,
+        });
+    }
+}
+
+impl View for ParamInput {
+    fn element(&self) -> Option<&'static str> {
+        Some("param-input")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|input_event, meta| match input_event {
+            ParamInputEvent::BeginTextEntry => {
+                self.editing = true;
+                meta.consume();
+            }
+            ParamInputEvent::TextEntry(text) => {
+                if let Some(normalized) = self.param_base.string_to_normalized_value(text) {
+                    self.set_value(cx, normalized);
+                }
+                self.editing = false;
+                cx.focus();
+                meta.consume();
+            }
+            ParamInputEvent::CancelTextEntry => {
+                self.editing = false;
+                cx.focus();
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            // Not consumed: the row still sees the press (e.g. to leave Simple mode)
+            WindowEvent::MouseDown(MouseButton::Left) if !self.editing => {
+                cx.focus();
+                if cx.modifiers().command() {
+                    self.set_value(cx, self.param_base.default_normalized_value());
+                } else {
+                    cx.capture();
+                    cx.set_active(true);
+                    self.param_base.begin_set_parameter(cx);
+                    self.drag = Some(DragState {
+                        start_x: cx.mouse().cursorx,
+                        start_value: self.param_base.unmodulated_normalized_value(),
+                        fine: cx.modifiers().shift(),
+                    });
+                    self.show_drag_tip(cx);
+                }
+            }
+            WindowEvent::MouseDoubleClick(MouseButton::Left) if !self.editing => {
+                self.end_drag(cx);
+                cx.emit(ParamInputEvent::BeginTextEntry);
+                meta.consume();
+            }
+            WindowEvent::MouseUp(MouseButton::Left) => self.end_drag(cx),
+            WindowEvent::MouseMove(x, _) => {
+                let Some(mut drag) = self.drag else {
+                    return;
+                };
+                // Re-anchor when Shift changes so the value does not jump
+                let fine = cx.modifiers().shift();
+                if fine != drag.fine {
+                    drag = DragState {
+                        start_x: *x,
+                        start_value: self.param_base.unmodulated_normalized_value(),
+                        fine,
+                    };
+                    self.drag = Some(drag);
+                }
+                let scale = if fine { FINE_DRAG_SCALE } else { 1.0 };
+                let delta = (*x - drag.start_x) / cx.bounds().w.max(1.0) * scale;
+                self.param_base
+                    .set_normalized_value(cx, (drag.start_value + delta).clamp(0.0, 1.0));
+                self.show_drag_tip(cx);
+            }
+            WindowEvent::KeyDown(code, _) if !self.editing => {
+                let current = self.param_base.unmodulated_normalized_value();
+                let nudge = if cx.modifiers().shift() {
+                    NUDGE_STEP_FINE
+                } else {
+                    NUDGE_STEP
+                };
+                let target = match code {
+                    Code::ArrowUp | Code::ArrowRight => current + nudge,
+                    Code::ArrowDown | Code::ArrowLeft => current - nudge,
+                    Code::PageUp => current + PAGE_STEP,
+                    Code::PageDown => current - PAGE_STEP,
+                    Code::Home => 0.0,
+                    Code::End => 1.0,
+                    Code::Enter | Code::NumpadEnter => {
+                        cx.emit(ParamInputEvent::BeginTextEntry);
+                        meta.consume();
+                        return;
+                    }
+                    _ => return,
+                };
+                self.set_value(cx, target);
+                meta.consume();
+            }
+            _ => {}
+        });
+    }
+}
@@ -3,36 +3,846 @@
 //! This module contains the data model, custom events, and synchronization logic
 //! for the UI state.
 
-use crate::macro_controller;
+use crate::advisor::{Advice, Advisor};
+use crate::dsp::GuardStage;
+use crate::macro_controller::{self, MacroKind, MacroParams, AUTO_OFFSET_CENTER};
+use crate::meters::{MeterMode, MeterSnapshot, Meters};
+use crate::param_locks::{set_unlocked, LockId};
+use crate::presets::DspPreset;
+use crate::speaker_profile::{SpeakerProfileStore, MIN_VOICED_SEC};
+use crate::tasks::{Task, TaskQueue};
+use crate::ui::locale::tr;
+use crate::ui::onboarding::{OnboardingEvent, LEARN_STEP, STEPS};
 use crate::version::{VersionEvent, VersionUiState};
-use crate::VoiceParams;
-use nih_plug::prelude::{GuiContext, ParamSetter};
+use crate::{DetectedConditions, VoiceParams};
+use nih_plug::prelude::{GuiContext, Param, ParamSetter, PluginState};
 use nih_plug_vizia::vizia::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 #[derive(Lens, Clone)]
 pub struct VoiceStudioData {
     pub params: Arc<VoiceParams>,
+    pub gui: Arc<dyn GuiContext>,
+    /// Background tasks (file IO) queued from editor events, and where
+    /// their results come back to
+    pub tasks: TaskQueue,
+    pub proxy: ContextProxy,
     pub advanced_tab: AdvancedTab,
     pub version_info: VersionUiState,
+    pub meters: Arc<Meters>,
+    /// Level meter ballistics and scale
+    pub meter_mode: MeterMode,
+    /// Last polled mic distance estimate (0.0 close - 1.0 distant)
+    pub mic_distance: f32,
+    /// Last polled room decay estimate (ms, 0.0 = not yet measured)
+    pub room_rt60_ms: f32,
+    /// Last polled transient event count (events in the last minute)
+    pub transient_events: f32,
+    /// Last polled codec cutoff (Hz, 0.0 = full band)
+    pub codec_cutoff_hz: f32,
+    /// Last polled rumble and hiss corners (Hz, 0.0 = not found)
+    pub noise_corners: (f32, f32),
+    /// Stereo width into and out of the denoiser (side share, 1% steps)
+    pub denoise_width: (f32, f32),
+    /// Static noise profile readout (learning / frozen and the profile quality)
+    pub noise_learn_text: String,
+    /// Mic alignment readout (measuring, no match, or the stored offset)
+    pub mic_align_text: String,
+    /// Dead air in whole seconds and the number of long pauses
+    pub dead_air: (u32, u32),
+    /// Music Guard has detected music in the input
+    pub music_detected: bool,
+    /// De-ess events, events per second of speech and average depth (dB,
+    /// 0.1 steps)
+    pub de_ess_events: (u32, f32, f32),
+    /// Tracked sibilance center of the de-esser band (Hz, 10 Hz steps)
+    pub de_ess_center_hz: f32,
+    /// Spectral guardrails low-mid and high cuts (dB, 0.1 dB steps)
+    pub guardrail_cuts: (f32, f32),
+    /// Speech-band loss through restoration and the allowed budget (dB, 0.1 dB steps)
+    pub speech_loss: (f32, f32),
+    /// Held input and output peaks, louder channel (dBFS, 0.1 dB steps)
+    pub peak_hold: (f32, f32),
+    /// Loudness compensation gain applied, trim included (dB, 0.1 dB steps)
+    pub loudness_comp_db: f32,
+    /// Pump guard is holding the Leveler back after repeated pumping
+    pub pump_guard: bool,
+    /// Conditions detected in the input (the badges under the meters)
+    pub input_conditions: DetectedConditions,
+    /// Session share of whisper, distant, noisy and clean (whole percent)
+    pub condition_shares: [u32; 4],
+    /// First-run tour step on screen (`None` = hidden)
+    pub onboarding_step: Option<usize>,
+    /// Over-processing rules fed from the meter poll
+    pub advisor: Advisor,
+    /// Advisor warnings on screen, oldest rule first
+    pub advice: Vec<Advice>,
+    /// The audio thread caught a panic and is playing the dry bypass
+    pub dsp_fault: bool,
+    /// First stage reset for a NaN/Inf (meter code) and the reset count
+    pub numeric_fault: (u8, u32),
+    /// Stage with the largest long-term speech gain (meter code, 0 = none
+    /// above 0.5 dB) and that gain in dB
+    pub stage_gain_offset: (u8, f32),
+    /// De-ess auto threshold readout (analysing, not enough, or the result)
+    pub de_ess_auto_text: String,
+    /// Name typed for the next speaker profile save
+    pub speaker_name: String,
+    /// Outcome of the last speaker profile save or removal
+    pub speaker_status: String,
+    /// Saved speaker profile names (mirrors the persisted store)
+    pub speaker_profile_names: Vec<String>,
+    /// Name of the applied speaker profile, empty when none
+    pub active_speaker: String,
+    /// Track name of this instance (mirrors the persisted `track_name`)
+    pub track_name: String,
+    /// A track profile is stored under `track_name`
+    pub track_stored: bool,
+    /// Speech heard by the speaker learner so far (seconds)
+    pub speaker_voiced_sec: f32,
+    /// Dual-speaker detector output: -1 = off/one voice, 0 = A, 1 = B
+    pub dual_active_speaker: i32,
+    /// User scale factor (persisted in the editor state)
+    pub ui_scale: f64,
+    /// Selected theme (mirrors the persisted `ui_theme`)
+    pub theme: String,
+    /// Themes loaded when the editor opened
+    pub theme_names: Vec<String>,
+    /// Selected language (mirrors the persisted `ui_language`; applies when
+    /// the editor is next opened)
+    pub language: String,
+    /// Language files found when the editor opened
+    pub language_names: Vec<String>,
+    /// `.vxc` files in the settings folder
+    pub settings_files: Vec<String>,
+    /// Requested vs applied control values shown in tooltips
+    pub live: LiveValues,
+    /// Mirror of the persisted lock mask, rebinds the padlocks
+    pub param_locks: u32,
+    /// Value tooltip of the slider or dial being dragged
+    pub drag_tip: Option<DragTip>,
+    /// Easy Mode as last seen by the mode watch
+    pub easy_mode: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+impl VoiceStudioData {
+    /// Refresh the speaker profile mirror from the persisted store
+    pub fn sync_speaker_profiles(&mut self) {
+        if let Ok(store) = self.params.speaker_profiles.read() {
+            self.speaker_profile_names = store.profiles.iter().map(|p| p.name.clone()).collect();
+            self.active_speaker = store
+                .active_profile()
+                .map(|p| p.name.clone())
+                .unwrap_or_default();
+        }
+    }
+
+    /// Refresh the track name mirror and look up (on the background thread)
+    /// whether a profile is stored for it
+    pub fn sync_track_profile(&mut self) {
+        if let Ok(name) = self.params.track_name.read() {
+            if *name != self.track_name {
+                self.track_name = name.clone();
+                self.track_stored = false;
+            }
+        }
+        if !self.track_name.is_empty() {
+            (self.tasks)(Task::FindTrackProfile {
+                track: self.track_name.clone(),
+                proxy: self.proxy.clone(),
+            });
+        }
+    }
+
+    /// Mirrors of the persisted stores a newly applied state may have
+    /// replaced
+    fn sync_applied_state(&mut self) {
+        self.param_locks = self.params.param_locks.bits();
+        // The state brought its own slider values, keep them
+        self.easy_mode = self.params.macro_mode.value();
+        self.sync_speaker_profiles();
+        self.sync_track_profile();
+    }
+
+    /// Switch the Advanced tab and remember it for the next time the editor
+    /// opens
+    fn set_advanced_tab(&mut self, tab: AdvancedTab) {
+        self.advanced_tab = tab;
+        if let Ok(mut view) = self.params.editor_view.write() {
+            view.advanced_tab = tab;
+        }
+    }
+
+    /// Switch the level meter mode and remember it for the next time the
+    /// editor opens
+    fn set_meter_mode(&mut self, mode: MeterMode) {
+        self.meter_mode = mode;
+        self.meters.set_mode(mode);
+        if let Ok(mut view) = self.params.editor_view.write() {
+            view.meter_mode = mode;
+        }
+    }
+
+    fn with_speaker_store(&mut self, f: impl FnOnce(&mut SpeakerProfileStore)) {
+        if let Ok(mut store) = self.params.speaker_profiles.write() {
+            f(&mut store);
+        }
+        self.sync_speaker_profiles();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data, Serialize, Deserialize)]
 pub enum AdvancedTab {
     CleanRepair,
     ShapePolish,
 }
 
+/// The editor view of this instance, restored when the editor reopens
+/// (persisted as `editor-view`). Easy/Advanced is the `macro_mode` parameter
+/// and is restored with the other parameters.
+///
+/// Missing fields read as their default, so later panel sizes can be added
+/// without breaking older sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorView {
+    pub advanced_tab: AdvancedTab,
+    pub meter_mode: MeterMode,
+}
+
+impl Default for EditorView {
+    fn default() -> Self {
+        Self {
+            advanced_tab: AdvancedTab::CleanRepair,
+            meter_mode: MeterMode::Peak,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AdvancedTabEvent {
     SetTab(AdvancedTab),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeterModeEvent {
+    /// Right-click on the LEVELS column: the next mode
+    Next,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpeakerProfileEvent {
+    SetName(String),
+    /// Store the statistics learned so far under `speaker_name`
+    Save,
+    Load(usize),
+    Unload,
+    Remove(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UiScaleEvent {
+    Set(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamLockEvent {
+    Toggle(LockId),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeEvent {
+    Set(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LanguageEvent {
+    Set(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum TrackProfileEvent {
+    /// A track name was entered: recall its profile if one is stored
+    Recall,
+    /// Store the current settings as the track's profile
+    Save,
+    /// Background lookup result: whether `track` has a stored profile
+    Found { track: String, stored: bool },
+    /// Background read of the track's profile, to apply
+    Recalled(PluginState),
+}
+
+#[derive(Debug, Clone)]
+pub enum SettingsFileEvent {
+    /// Re-read the settings folder
+    Rescan,
+    /// Apply the named `.vxc` file (read on the background thread)
+    Import(String),
+    /// Background read of a settings file, to apply
+    Loaded(PluginState),
+}
+
+/// Controls whose tooltips show live resolved values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveControl {
+    NoiseReduction,
+    DeVerb,
+    Clarity,
+    Proximity,
+    DeEsser,
+    Leveler,
+    BreathControl,
+}
+
+/// Whole-percent snapshot of the Layer 1 meters, in slider units.
+/// "Requested" is after macro blending, "applied" after the safeguards.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Data)]
+pub struct LiveValues {
+    pub noise_requested: f32,
+    pub noise_applied: f32,
+    pub deverb_requested: f32,
+    pub deverb_applied: f32,
+    pub clarity_requested: f32,
+    pub clarity_applied: f32,
+    pub proximity_requested: f32,
+    pub proximity_applied: f32,
+    pub deesser_requested: f32,
+    pub deesser_applied: f32,
+    pub leveler: f32,
+    pub breath: f32,
+    pub speech_protection: bool,
+    pub energy_budget: bool,
+    /// Calibration-chosen macro amounts (Auto macros)
+    pub macro_auto_clean: f32,
+    pub macro_auto_enhance: f32,
+    pub macro_auto_control: f32,
+}
+
+impl LiveValues {
+    /// Engine amounts run 0..2 for the spectral/dynamics stages
+    const ENGINE_SCALE: f32 = 2.0;
+    /// De-verb is clamped to 0..1 (full strength from 50% on the slider)
+    const DEVERB_SCALE: f32 = 1.0;
+
+    pub fn capture(m: &MeterSnapshot) -> Self {
+        let pct = |value: f32, scale: f32| (value / scale * 100.0).round();
+        let auto = m.macro_auto;
+        let engine = |value: f32| pct(value, Self::ENGINE_SCALE);
+        Self {
+            noise_requested: engine(m.noise_reduction_resolved),
+            noise_applied: engine(m.noise_reduction_applied),
+            deverb_requested: pct(m.deverb_resolved, Self::DEVERB_SCALE),
+            deverb_applied: pct(m.deverb_applied, Self::DEVERB_SCALE),
+            clarity_requested: engine(m.clarity_resolved),
+            clarity_applied: engine(m.clarity_applied),
+            proximity_requested: engine(m.proximity_resolved),
+            proximity_applied: engine(m.proximity_applied),
+            deesser_requested: engine(m.deesser_resolved),
+            deesser_applied: engine(m.deesser_applied),
+            leveler: engine(m.leveler_resolved),
+            breath: pct(m.breath_reduction_resolved, 1.0),
+            speech_protection: m.speech_protection_active,
+            energy_budget: m.energy_budget_active,
+            macro_auto_clean: pct(auto.clean, 1.0),
+            macro_auto_enhance: pct(auto.enhance, 1.0),
+            macro_auto_control: pct(auto.control, 1.0),
+        }
+    }
+
+    /// Percent an Auto macro drives with the dial at `offset` (0..1)
+    pub fn macro_effective(&self, kind: MacroKind, offset: f32) -> f32 {
+        let auto = match kind {
+            MacroKind::Clean => self.macro_auto_clean,
+            MacroKind::Enhance => self.macro_auto_enhance,
+            MacroKind::Control => self.macro_auto_control,
+        };
+        (auto + (offset - AUTO_OFFSET_CENTER) * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// e.g. "Noise Reduction: 58% requested → 43% applied (speech protection)"
+    pub fn describe(&self, name: &str, control: LiveControl) -> String {
+        let (requested, applied) = match control {
+            LiveControl::NoiseReduction => (self.noise_requested, self.noise_applied),
+            LiveControl::DeVerb => (self.deverb_requested, self.deverb_applied),
+            LiveControl::Clarity => (self.clarity_requested, self.clarity_applied),
+            LiveControl::Proximity => (self.proximity_requested, self.proximity_applied),
+            LiveControl::DeEsser => (self.deesser_requested, self.deesser_applied),
+            // No safeguards act on these; the blended value is what runs
+            LiveControl::Leveler => (self.leveler, self.leveler),
+            LiveControl::BreathControl => (self.breath, self.breath),
+        };
+
+        // Within a percent is slew settling, not an intervention
+        if (requested - applied).abs() < 1.0 {
+            return format!("{}: {:.0}% applied", name, applied);
+        }
+        let reason = if self.speech_protection {
+            "speech protection"
+        } else if self.energy_budget {
+            "energy budget"
+        } else {
+            "module interaction"
+        };
+        format!(
+            "{}: {:.0}% requested \u{2192} {:.0}% applied ({})",
+            name, requested, applied, reason
+        )
+    }
+}
+
+/// e.g. "Capturing 1.2 / 2.0 s", "Learning 64%", "Frozen 91%"
+fn noise_learn_text(m: &MeterSnapshot, params: &VoiceParams) -> String {
+    match m.noise_capture_status {
+        1 if m.noise_capture_progress <= 0.0 => return tr("Waiting for a pause"),
+        1 => {
+            let sec = (m.noise_capture_progress.clamp(0.0, 1.0) * 20.0).floor() / 10.0;
+            return format!("{} {:.1} / 2.0 s", tr("Capturing"), sec);
+        }
+        3 => return tr("Speech in capture - re-learn"),
+        4 => return tr("Too little quiet - re-learn"),
+        _ => {}
+    }
+    let quality = (m.noise_learn_quality.clamp(0.0, 1.0) * 100.0).round();
+    let state = if m.noise_learn_active {
+        "Learning"
+    } else if params.noise_learn_freeze.value() {
+        "Frozen"
+    } else {
+        "Quality"
+    };
+    format!("{} {:.0}%", tr(state), quality)
+}
+
+/// e.g. "Aligning...", "Mics: R +1.25 ms, flipped"
+fn mic_align_text(m: &MeterSnapshot, params: &VoiceParams) -> String {
+    match m.mic_align_status {
+        1 => tr("Aligning... (keep talking)"),
+        2 => tr("No match - align during speech"),
+        _ => {
+            let (offset_us, invert_right) = params.mic_alignment.get();
+            if offset_us == 0 && !invert_right {
+                return tr("Mics: not aligned");
+            }
+            let channel = if offset_us < 0 { "L" } else { "R" };
+            let flip = if invert_right { ", R flipped" } else { "" };
+            format!(
+                "{} {} +{:.2} ms{}",
+                tr("Mics:"),
+                channel,
+                offset_us.unsigned_abs() as f32 / 1000.0,
+                flip
+            )
+        }
+    }
+}
+
+/// e.g. "Analyzing...", "Threshold +2.5 dB (p95 -9 dB)"
+fn de_ess_auto_text(m: &MeterSnapshot, params: &VoiceParams) -> String {
+    match m.de_ess_auto_status {
+        1 => tr("Analyzing... (keep talking)"),
+        2 => tr("Not enough sibilance"),
+        _ => match params.de_ess_threshold.get() {
+            Some((offset_db, p95_rel_db)) => {
+                format!(
+                    "{} {:+.1} dB (p95 {:.0} dB)",
+                    tr("Threshold"),
+                    offset_db,
+                    p95_rel_db
+                )
+            }
+            None => tr("Threshold: default"),
+        },
+    }
+}
+
+/// Stage furthest from unity gain, if more than half a dB off
+fn largest_stage_offset(gains: &[f32; GuardStage::COUNT]) -> (u8, f32) {
+    let (stage, db) = GuardStage::ALL
+        .iter()
+        .zip(gains)
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(&stage, &db)| (stage, db))
+        .unwrap_or((GuardStage::MicAlign, 0.0));
+    if db.abs() < 0.5 {
+        return (0, 0.0);
+    }
+    (GuardStage::code(Some(stage)), (db * 10.0).round() / 10.0)
+}
+
+/// Periodic poll of audio-thread meters that are shown as text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeterPollEvent {
+    Refresh,
+}
+
+/// Time between meter polls (ms)
+pub const METER_POLL_MS: u64 = 250;
+
+/// Periodic check of the Easy Mode parameter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MacroModeEvent {
+    Watch,
+}
+
+/// Time between Easy Mode checks (ms), well inside the engine's mode
+/// crossfade so the sliders arrive while it still glides
+pub const MODE_WATCH_MS: u64 = 20;
+
+/// Floating value tooltip while a slider or dial is dragged
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct DragTip {
+    /// One line per value
+    pub text: String,
+    /// Cursor position (logical pixels)
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DragTipEvent {
+    /// `text` is "Name: value"; a macro dial adds the values it drives
+    Show {
+        text: String,
+        macro_kind: Option<MacroKind>,
+        x: f32,
+        y: f32,
+    },
+    Hide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdvisorEvent {
+    /// Hide a warning until its rule clears
+    Dismiss(Advice),
+}
+
 impl Model for VoiceStudioData {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|advanced_tab_event, _| match advanced_tab_event {
-            AdvancedTabEvent::SetTab(tab) => self.advanced_tab = *tab,
+            AdvancedTabEvent::SetTab(tab) => self.set_advanced_tab(*tab),
+        });
+
+        event.map(|meter_mode_event, _| match meter_mode_event {
+            MeterModeEvent::Next => self.set_meter_mode(self.meter_mode.next()),
+        });
+
+        event.map(|scale_event, _| match scale_event {
+            UiScaleEvent::Set(scale) => {
+                // The editor wrapper stores the new factor and asks the host to resize
+                cx.set_user_scale_factor(*scale);
+                self.ui_scale = *scale;
+            }
+        });
+
+        event.map(|lock_event, _| match lock_event {
+            ParamLockEvent::Toggle(lock) => {
+                self.params.param_locks.toggle(*lock);
+                self.param_locks = self.params.param_locks.bits();
+            }
+        });
+
+        event.map(|theme_event, _| match theme_event {
+            ThemeEvent::Set(name) => {
+                if let Ok(mut theme) = self.params.ui_theme.write() {
+                    *theme = name.clone();
+                }
+                self.theme = name.clone();
+            }
+        });
+
+        event.map(|language_event, _| match language_event {
+            LanguageEvent::Set(name) => {
+                if let Ok(mut language) = self.params.ui_language.write() {
+                    *language = name.clone();
+                }
+                self.language = name.clone();
+            }
+        });
+
+        event.map(|onboarding_event, _| match onboarding_event {
+            OnboardingEvent::Start => self.onboarding_step = Some(0),
+            OnboardingEvent::Next => {
+                let next = self.onboarding_step.map_or(0, |step| step + 1);
+                if next < STEPS.len() {
+                    // Re-learn is on Clean & Repair once Advanced is open
+                    if next == LEARN_STEP {
+                        self.set_advanced_tab(AdvancedTab::CleanRepair);
+                    }
+                    self.onboarding_step = Some(next);
+                } else {
+                    cx.emit(OnboardingEvent::Dismiss);
+                }
+            }
+            OnboardingEvent::Back => {
+                self.onboarding_step = self.onboarding_step.map(|step| step.saturating_sub(1));
+            }
+            OnboardingEvent::Dismiss => {
+                self.params.onboarding_seen.store(true, Ordering::Relaxed);
+                self.onboarding_step = None;
+            }
+        });
+
+        event.map(|drag_tip_event, _| match drag_tip_event {
+            DragTipEvent::Show {
+                text,
+                macro_kind,
+                x,
+                y,
+            } => {
+                let mut lines = vec![text.clone()];
+                if let Some(kind) = *macro_kind {
+                    let auto = self.meters.snapshot().macro_auto;
+                    // In Auto the dial is an offset; lead with the amount in use
+                    if kind.auto(&self.params).value() {
+                        lines[0] = format!(
+                            "{}: {:.0}% ({})",
+                            kind.dial(&self.params).name(),
+                            kind.effective(&self.params, &auto) * 100.0,
+                            tr("Auto")
+                        );
+                    }
+                    lines.extend(macro_controller::driven_value_lines(
+                        &self.params,
+                        &auto,
+                        kind,
+                    ));
+                }
+                self.drag_tip = Some(DragTip {
+                    text: lines.join("\n"),
+                    x: *x,
+                    y: *y,
+                });
+            }
+            DragTipEvent::Hide => self.drag_tip = None,
+        });
+
+        event.map(|advisor_event, _| match advisor_event {
+            AdvisorEvent::Dismiss(advice) => {
+                self.advisor.dismiss(*advice);
+                self.advice = self.advisor.warnings();
+            }
+        });
+
+        event.map(|settings_event, _| match settings_event {
+            SettingsFileEvent::Rescan => self.settings_files = crate::settings_file::list(),
+            SettingsFileEvent::Import(name) => (self.tasks)(Task::ImportSettings {
+                name: name.clone(),
+                proxy: self.proxy.clone(),
+            }),
+            SettingsFileEvent::Loaded(state) => {
+                crate::settings_file::apply(self.gui.as_ref(), state.clone());
+                self.sync_applied_state();
+            }
+        });
+
+        event.map(|track_event, _| match track_event {
+            TrackProfileEvent::Recall => {
+                self.sync_track_profile();
+                if !self.track_name.is_empty() {
+                    (self.tasks)(Task::RecallTrackProfile {
+                        track: self.track_name.clone(),
+                        proxy: self.proxy.clone(),
+                    });
+                }
+            }
+            TrackProfileEvent::Save => (self.tasks)(Task::SaveTrackProfile {
+                track: self
+                    .params
+                    .track_name
+                    .read()
+                    .map(|name| name.clone())
+                    .unwrap_or_default(),
+                state: crate::settings_file::shareable_state(self.gui.as_ref()),
+                proxy: self.proxy.clone(),
+            }),
+            TrackProfileEvent::Found { track, stored } => {
+                if *track == self.track_name {
+                    self.track_stored = *stored;
+                }
+            }
+            TrackProfileEvent::Recalled(state) => {
+                crate::settings_file::apply(self.gui.as_ref(), state.clone());
+                self.sync_applied_state();
+            }
+        });
+
+        event.map(|mode_event, _| match mode_event {
+            MacroModeEvent::Watch => {
+                follow_macro_mode(&self.params, &self.meters, &self.gui, &mut self.easy_mode);
+            }
+        });
+
+        event.map(|meter_event, _| match meter_event {
+            MeterPollEvent::Refresh => {
+                // One coherent frame for every readout
+                let m = self.meters.snapshot();
+                let rt60 = m.room_rt60_ms;
+                // Only rebind when the readout would change
+                let distance = m.mic_distance;
+                if (distance - self.mic_distance).abs() >= 0.05 {
+                    self.mic_distance = distance;
+                }
+                if (rt60 - self.room_rt60_ms).abs() >= 1.0 {
+                    self.room_rt60_ms = rt60;
+                }
+                let transients = m.transient_events_per_min;
+                if transients != self.transient_events {
+                    self.transient_events = transients;
+                }
+                let cutoff = m.codec_cutoff_hz;
+                if cutoff != self.codec_cutoff_hz {
+                    self.codec_cutoff_hz = cutoff;
+                }
+                let corners = (m.rumble_cutoff_hz, m.hiss_onset_hz);
+                if corners != self.noise_corners {
+                    self.noise_corners = corners;
+                }
+                let percent = |x: f32| (x * 100.0).round() / 100.0;
+                let width = (percent(m.denoise_width_in), percent(m.denoise_width_out));
+                if width != self.denoise_width {
+                    self.denoise_width = width;
+                }
+                let learn_text = noise_learn_text(&m, &self.params);
+                if learn_text != self.noise_learn_text {
+                    self.noise_learn_text = learn_text;
+                }
+                let align_text = mic_align_text(&m, &self.params);
+                if align_text != self.mic_align_text {
+                    self.mic_align_text = align_text;
+                }
+                let de_ess_text = de_ess_auto_text(&m, &self.params);
+                if de_ess_text != self.de_ess_auto_text {
+                    self.de_ess_auto_text = de_ess_text;
+                }
+                let dead_air = (m.dead_air_sec as u32, m.long_pauses);
+                if dead_air != self.dead_air {
+                    self.dead_air = dead_air;
+                }
+                if m.music_detected != self.music_detected {
+                    self.music_detected = m.music_detected;
+                }
+                let de_ess_events = (
+                    m.de_ess_events,
+                    (m.de_ess_rate * 10.0).round() / 10.0,
+                    (m.de_ess_depth_db * 10.0).round() / 10.0,
+                );
+                if de_ess_events != self.de_ess_events {
+                    self.de_ess_events = de_ess_events;
+                }
+                let center = (m.debug_deesser_freq_hz / 10.0).round() * 10.0;
+                if center != self.de_ess_center_hz {
+                    self.de_ess_center_hz = center;
+                }
+                let round = |db: f32| (db * 10.0).round() / 10.0;
+                let guardrails = (
+                    round(m.debug_guardrails_low_cut),
+                    round(m.debug_guardrails_high_cut),
+                );
+                if guardrails != self.guardrail_cuts {
+                    self.guardrail_cuts = guardrails;
+                }
+                let speech_loss = (
+                    round(-m.speech_band_loss_db),
+                    round(m.speech_loss_budget_db),
+                );
+                if speech_loss != self.speech_loss {
+                    self.speech_loss = speech_loss;
+                }
+                let peak_hold = (
+                    round(m.input_hold_l.max(m.input_hold_r)),
+                    round(m.output_hold_l.max(m.output_hold_r)),
+                );
+                if peak_hold != self.peak_hold {
+                    self.peak_hold = peak_hold;
+                }
+                let loudness_comp = round(m.loudness_comp_db);
+                if loudness_comp != self.loudness_comp_db {
+                    self.loudness_comp_db = loudness_comp;
+                }
+                if m.dsp_fault != self.dsp_fault {
+                    self.dsp_fault = m.dsp_fault;
+                }
+                let numeric = (m.numeric_fault_stage, m.numeric_fault_count);
+                if numeric != self.numeric_fault {
+                    self.numeric_fault = numeric;
+                }
+                let offset = largest_stage_offset(&m.stage_gain_db);
+                if offset != self.stage_gain_offset {
+                    self.stage_gain_offset = offset;
+                }
+                if m.pump_guard_active != self.pump_guard {
+                    self.pump_guard = m.pump_guard_active;
+                }
+                if m.input_conditions != self.input_conditions {
+                    self.input_conditions = m.input_conditions;
+                }
+                let stats = &m.condition_stats;
+                let shares = [
+                    stats.whisper_sec,
+                    stats.distant_mic_sec,
+                    stats.noisy_environment_sec,
+                    stats.clean_audio_sec,
+                ]
+                .map(|sec| (stats.share(sec) * 100.0).round() as u32);
+                if shares != self.condition_shares {
+                    self.condition_shares = shares;
+                }
+                self.advisor.update(&m, METER_POLL_MS as f32 * 0.001);
+                let advice = self.advisor.warnings();
+                if advice != self.advice {
+                    self.advice = advice;
+                }
+                let voiced = m.speaker_stats.voiced_sec.floor();
+                if voiced != self.speaker_voiced_sec {
+                    self.speaker_voiced_sec = voiced;
+                }
+                let dual = m.active_speaker;
+                if dual != self.dual_active_speaker {
+                    self.dual_active_speaker = dual;
+                }
+                let live = LiveValues::capture(&m);
+                if live != self.live {
+                    self.live = live;
+                }
+            }
+        });
+
+        event.map(|speaker_event, _| match speaker_event {
+            SpeakerProfileEvent::SetName(name) => self.speaker_name = name.clone(),
+            SpeakerProfileEvent::Save => {
+                let stats = self.meters.snapshot().speaker_stats;
+                let name = self.speaker_name.trim().to_string();
+                self.speaker_status = if name.is_empty() {
+                    tr("Type a name first")
+                } else if !stats.is_usable() {
+                    format!(
+                        "{} ({:.0} / {:.0} s)",
+                        tr("Needs more speech"),
+                        stats.voiced_sec,
+                        MIN_VOICED_SEC
+                    )
+                } else {
+                    self.with_speaker_store(|store| {
+                        store.save(&name, stats);
+                    });
+                    format!("{} {}", tr("Saved"), name)
+                };
+            }
+            SpeakerProfileEvent::Load(index) => {
+                let index = *index;
+                self.with_speaker_store(|store| store.load(index));
+            }
+            SpeakerProfileEvent::Unload => self.with_speaker_store(|store| store.unload()),
+            SpeakerProfileEvent::Remove(index) => {
+                let index = *index;
+                if let Some(name) = self.speaker_profile_names.get(index) {
+                    self.speaker_status = format!("{} {}", tr("Removed"), name);
+                }
+                self.with_speaker_store(|store| store.remove(index));
+            }
         });
 
         event.map(|version_event, _| match version_event {
@@ -45,12 +855,116 @@ impl Model for VoiceStudioData {
 }
 
 // Sync functions
-pub fn sync_advanced_from_macros(params: &Arc<VoiceParams>, gui: Arc<dyn GuiContext>) {
+
+/// Write the values the macros resolve to (Auto amounts included) to the
+/// sliders through the host. Locked sliders keep theirs.
+pub fn sync_advanced_from_macros(
+    params: &Arc<VoiceParams>,
+    meters: &Meters,
+    gui: Arc<dyn GuiContext>,
+) {
     let setter = ParamSetter::new(gui.as_ref());
-    macro_controller::apply_simple_macros(params.as_ref(), &setter);
+    macro_controller::apply_simple_macros(params.as_ref(), &meters.snapshot().macro_auto, &setter);
+}
+
+/// Move the sliders and macros to `preset`'s values (locked sliders keep
+/// theirs); `Manual` leaves everything as it is
+pub fn apply_dsp_preset_values(params: &VoiceParams, setter: &ParamSetter<'_>, preset: DspPreset) {
+    let Some(values) = preset.get_values() else {
+        return;
+    };
+    let locks = params.param_locks.as_ref();
+    for (lock, param, value) in [
+        (
+            LockId::NoiseReduction,
+            &params.noise_reduction,
+            values.noise_reduction,
+        ),
+        (
+            LockId::DeVerb,
+            &params.reverb_reduction,
+            values.reverb_reduction,
+        ),
+        (LockId::Proximity, &params.proximity, values.proximity),
+        (LockId::Clarity, &params.clarity, values.clarity),
+        (LockId::DeEsser, &params.de_esser, values.de_esser),
+        (LockId::Leveler, &params.leveler, values.leveler),
+        (
+            LockId::BreathControl,
+            &params.breath_control,
+            values.breath_control,
+        ),
+    ] {
+        set_unlocked(setter, locks, lock, param, value);
+    }
+
+    for (param, value) in [
+        (&params.macro_clean, values.macro_clean),
+        (&params.macro_enhance, values.macro_enhance),
+        (&params.macro_control, values.macro_control),
+    ] {
+        setter.begin_set_parameter(param);
+        setter.set_parameter(param, value);
+        setter.end_set_parameter(param);
+    }
 }
 
-pub fn set_macro_mode(params: &Arc<VoiceParams>, gui_context: &Arc<dyn GuiContext>, enabled: bool) {
+/// Mirror a DSP preset picked by the host (automation or a program change
+/// mapped to the DSP Preset parameter) onto the sliders. The engine already
+/// runs the preset's amounts and reports them as pending until the
+/// parameters match; `mirrored` keeps one write per switch.
+pub fn mirror_host_dsp_preset(
+    params: &VoiceParams,
+    meters: &Meters,
+    gui: &Arc<dyn GuiContext>,
+    mirrored: &mut Option<DspPreset>,
+) {
+    if !meters.snapshot().dsp_preset_pending {
+        *mirrored = None;
+        return;
+    }
+    let preset = params.dsp_preset.value();
+    if *mirrored != Some(preset) {
+        *mirrored = Some(preset);
+        let setter = ParamSetter::new(gui.as_ref());
+        apply_dsp_preset_values(params, &setter, preset);
+    }
+}
+
+/// Hand the macro values to the sliders when Easy Mode turns off, whatever
+/// turned it off: the mode buttons, a control that needs Advanced,
+/// automation, a host preset or a remote change. `easy` is the mode last
+/// seen; the sliders are not touched while Easy Mode runs (the engine
+/// applies the macros itself), so Advanced starts from the sound that was
+/// playing and the engine's mode crossfade and the parameter smoothers
+/// glide over the handover.
+pub fn follow_macro_mode(
+    params: &Arc<VoiceParams>,
+    meters: &Meters,
+    gui: &Arc<dyn GuiContext>,
+    easy: &mut bool,
+) {
+    let now = params.macro_mode.value();
+    if *easy && !now {
+        sync_advanced_from_macros(params, meters, gui.clone());
+    }
+    *easy = now;
+}
+
+/// Switch Easy Mode on or off.
+///
+/// Leaving it writes the macro values to the sliders first, so the engine
+/// switches straight onto them; the mode watch (`follow_macro_mode`) then
+/// finds the sliders there already.
+pub fn set_macro_mode(
+    params: &Arc<VoiceParams>,
+    meters: &Meters,
+    gui_context: &Arc<dyn GuiContext>,
+    enabled: bool,
+) {
+    if !enabled && params.macro_mode.value() {
+        sync_advanced_from_macros(params, meters, gui_context.clone());
+    }
     let setter = ParamSetter::new(gui_context.as_ref());
     setter.begin_set_parameter(&params.macro_mode);
     setter.set_parameter(&params.macro_mode, enabled);
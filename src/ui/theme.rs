@@ -0,0 +1,157 @@
+//! UI themes
+//!
+//! Dark is the base stylesheet (`ui.css`). Every other theme is a stylesheet of
+//! overrides whose selectors get scoped to a `theme-<name>` class on the app
+//! root, so all themes can be loaded once and switched by toggling that class.
+//!
+//! Built-in themes live in `src/themes/`. User themes are `*.css` files in the
+//! config directory (see `user_theme_dir`), written like `ui.css` and named
+//! after their file stem.
+
+use std::path::PathBuf;
+
+pub const DEFAULT_THEME: &str = "Dark";
+
+const LIGHT_CSS: &str = include_str!("../themes/light.css");
+
+/// A loaded theme; `css` is already scoped and empty for the base theme
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    pub class: String,
+    pub css: String,
+}
+
+impl Theme {
+    fn new(name: &str, css: &str) -> Self {
+        let class = theme_class(name);
+        let css = if css.is_empty() {
+            String::new()
+        } else {
+            scope_css(css, &class)
+        };
+        Self {
+            name: name.to_string(),
+            class,
+            css,
+        }
+    }
+}
+
+/// Built-in themes followed by user themes, sorted by name. User themes that
+/// reuse a built-in name are skipped.
+pub fn load_themes() -> Vec<Theme> {
+    let mut themes = vec![
+        Theme::new(DEFAULT_THEME, ""),
+        Theme::new("Light", LIGHT_CSS),
+    ];
+
+    let mut user: Vec<(String, String)> = user_theme_dir()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()?.to_str()? != "css" {
+                return None;
+            }
+            let name = path.file_stem()?.to_str()?.to_string();
+            let css = std::fs::read_to_string(&path).ok()?;
+            Some((name, css))
+        })
+        .collect();
+    user.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, css) in user {
+        if themes.iter().all(|t| !t.name.eq_ignore_ascii_case(&name)) {
+            themes.push(Theme::new(&name, &css));
+        }
+    }
+    themes
+}
+
+/// Per-user theme folder (`themes` in `user_config_dir`)
+pub fn user_theme_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("themes"))
+}
+
+/// Per-user VxCleaner folder:
+/// - macOS: `~/Library/Application Support/VxCleaner`
+/// - Windows: `%APPDATA%\VxCleaner`
+/// - Linux: `$XDG_CONFIG_HOME/vxcleaner` (default `~/.config`)
+pub fn user_config_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "macos")]
+    let base = std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join("Library/Application Support/VxCleaner"));
+
+    #[cfg(target_os = "windows")]
+    let base = std::env::var_os("APPDATA").map(|dir| PathBuf::from(dir).join("VxCleaner"));
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("vxcleaner"));
+
+    base
+}
+
+/// CSS class put on the app root while `name` is selected
+pub fn theme_class(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("theme-{}", slug)
+}
+
+/// Prefix every selector with `.class` (descendant), plus the compound form
+/// for class selectors so rules on `.app-root` itself still apply
+fn scope_css(css: &str, class: &str) -> String {
+    let css = strip_comments(css);
+    let mut out = String::with_capacity(css.len() * 2);
+
+    for block in css.split('}') {
+        let Some((selectors, body)) = block.split_once('{') else {
+            continue;
+        };
+        let mut scoped: Vec<String> = Vec::new();
+        for selector in selectors
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            scoped.push(format!(".{} {}", class, selector));
+            if selector.starts_with('.') {
+                scoped.push(format!(".{}{}", class, selector));
+            }
+        }
+        if !scoped.is_empty() {
+            out.push_str(&scoped.join(",\n"));
+            out.push_str(" {");
+            out.push_str(body);
+            out.push_str("}\n");
+        }
+    }
+    out
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
@@ -1,6 +1,4 @@
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
-use std::thread;
 use std::time::Duration;
 
 use semver::Version;
@@ -8,6 +6,7 @@ use serde::Deserialize;
 use serde_json;
 use ureq;
 
+use crate::tasks::{Task, TaskQueue};
 use nih_plug_vizia::vizia::prelude::{ContextProxy, Data};
 
 const GITHUB_RELEASE_ENDPOINT: &str =
@@ -93,49 +92,56 @@ pub fn current_version() -> &'static str {
     env!("CARGO_PKG_VERSION")
 }
 
-pub fn spawn_version_check(proxy: Arc<Mutex<Option<ContextProxy>>>) {
+/// Queue the once-per-session release check; the result is sent to `proxy`
+pub fn request_version_check(tasks: &TaskQueue, proxy: ContextProxy) {
     if VERSION_CHECK_STARTED.swap(true, Ordering::SeqCst) {
         return;
     }
+    tasks(Task::CheckVersion(proxy));
+}
 
-    thread::spawn(move || match fetch_latest_release() {
+/// Background side of `Task::CheckVersion`. A check whose editor has closed
+/// (`editor_open` false before or after the request) is dropped and runs
+/// again the next time the editor opens.
+pub fn run_version_check(proxy: ContextProxy, editor_open: impl Fn() -> bool) {
+    if !editor_open() {
+        VERSION_CHECK_STARTED.store(false, Ordering::SeqCst);
+        return;
+    }
+
+    let info = match fetch_latest_release() {
         Ok(release) => {
             let current =
                 Version::parse(current_version()).unwrap_or_else(|_| Version::new(0, 0, 0));
             if release.version > current {
-                let info = VersionUiState::update_available(&release);
-                let _ = crate::vs_log!(
+                crate::vs_log!(
                     "Version check: latest release {} is newer than current {}",
                     release.version,
                     current
                 );
-                notify_ui(proxy.clone(), info);
+                VersionUiState::update_available(&release)
             } else {
-                let info = VersionUiState::up_to_date(&release);
-                notify_ui(proxy.clone(), info);
+                VersionUiState::up_to_date(&release)
             }
         }
-        Err(err) => {
-            let info = VersionUiState::error(&err.to_string());
-            notify_ui(proxy.clone(), info);
-        }
-    });
-}
+        Err(err) => VersionUiState::error(&err.to_string()),
+    };
 
-fn notify_ui(proxy: Arc<Mutex<Option<ContextProxy>>>, state: VersionUiState) {
-    if let Ok(mut guard) = proxy.lock() {
-        if let Some(context_proxy) = guard.as_mut() {
-            let mut emitter = context_proxy.clone();
-            let _ = emitter.emit(VersionEvent::Update(state));
-        }
+    if editor_open() {
+        notify_ui(proxy, info);
+    } else {
+        VERSION_CHECK_STARTED.store(false, Ordering::SeqCst);
     }
 }
 
+fn notify_ui(mut proxy: ContextProxy, state: VersionUiState) {
+    let _ = proxy.emit(VersionEvent::Update(state));
+}
+
 fn fetch_latest_release() -> anyhow::Result<RemoteRelease> {
+    // One overall limit: the request holds up the other background tasks
     let agent: ureq::Agent = ureq::Agent::config_builder()
-        .timeout_connect(Some(Duration::from_secs(5)))
-        .timeout_recv_response(Some(Duration::from_secs(5)))
-        .timeout_recv_body(Some(Duration::from_secs(5)))
+        .timeout_global(Some(Duration::from_secs(5)))
         .build()
         .into();
     let mut response = agent